@@ -15,8 +15,9 @@ use crate::command::shortcut::ShortcutManager;
 use crate::command::{CommandId, CommandRegistry};
 use crate::config;
 use crate::context::context::EditorContext;
+use crate::dialogs::checkpoint_dialog::{CheckpointAction, CheckpointDialog};
 use crate::dialogs::composition_dialog::CompositionDialog;
-use crate::dialogs::export_dialog::ExportDialog;
+use crate::dialogs::export_dialog::{ExportDialog, ExportRange};
 use crate::dialogs::settings_dialog::SettingsDialog;
 use crate::panels::tab_viewer::{create_initial_dock_state, AppTabViewer};
 use crate::types::Tab;
@@ -36,6 +37,7 @@ pub struct RuViEApp {
     // Dialogs
     pub settings_dialog: SettingsDialog,
     pub composition_dialog: CompositionDialog,
+    pub checkpoint_dialog: CheckpointDialog,
     pub export_dialog: ExportDialog,
     pub command_palette: CommandPalette,
 
@@ -63,11 +65,15 @@ impl RuViEApp {
         let mut editor_context = EditorContext::new(default_comp_id); // Pass default_comp_id
         editor_context.selection.composition_id = Some(default_comp_id); // Select the default composition
         editor_context.available_fonts = library::rendering::skia_utils::get_available_fonts();
+        editor_context.timeline.display_mode = app_config.timeline.timecode_format;
 
         let render_server = Arc::new(RenderServer::new(
             plugin_manager.clone(),
             cache_manager.clone(),
         ));
+        // Wake the UI as soon as a frame is ready so the preview picks it up
+        let repaint_ctx = cc.egui_ctx.clone();
+        render_server.set_progress_callback(Arc::new(move |_, _| repaint_ctx.request_repaint()));
 
         let mut app = Self {
             editor_context,
@@ -85,6 +91,7 @@ impl RuViEApp {
             ),
             triggered_action: None,
             composition_dialog: CompositionDialog::new(),
+            checkpoint_dialog: CheckpointDialog::default(),
             export_dialog: ExportDialog::new(plugin_manager.clone(), cache_manager.clone()),
             command_palette: CommandPalette::new(),
             render_server,
@@ -108,6 +115,8 @@ impl eframe::App for RuViEApp {
         // --- Draw UI and Collect Inputs ---
 
         // 2. Menu Bar
+        let snapshot_names = self.history_manager.snapshot_names();
+        let mut snapshot_action = None;
         egui::TopBottomPanel::top("menu_bar").show(ctx, |ui| {
             let main_ui_enabled = !self.settings_dialog.is_open
                 && !self.settings_dialog.show_close_warning
@@ -119,9 +128,25 @@ impl eframe::App for RuViEApp {
                     &self.command_registry,
                     &mut self.dock_state,
                     &mut self.triggered_action,
+                    self.history_manager.undo_description(),
+                    &snapshot_names,
+                    &mut snapshot_action,
                 );
             });
         });
+        match snapshot_action {
+            Some(crate::menu::SnapshotAction::Take) => {
+                let name = format!("Snapshot {}", snapshot_names.len() + 1);
+                let state = self.project_service.with_project(|p| p.clone());
+                self.history_manager.push_named_snapshot(&name, state);
+            }
+            Some(crate::menu::SnapshotAction::UndoTo(name)) => {
+                if let Some(state) = self.history_manager.undo_to_snapshot(&name) {
+                    self.project_service.set_project(state);
+                }
+            }
+            None => {}
+        }
 
         // 3. Settings Window & Unsaved Changes Dialog
         // 3. Settings Window & Unsaved Changes Dialog
@@ -144,10 +169,26 @@ impl eframe::App for RuViEApp {
             self.composition_dialog.show(ctx);
         }
 
+        if self.checkpoint_dialog.is_open {
+            let checkpoints = self.history_manager.list_checkpoints();
+            match self.checkpoint_dialog.show(ctx, &checkpoints) {
+                Some(CheckpointAction::Create(name)) => {
+                    let state = self.project_service.with_project(|p| p.clone());
+                    self.history_manager.create_checkpoint(name, state);
+                }
+                Some(CheckpointAction::Restore(id)) => {
+                    if let Some(state) = self.history_manager.restore_checkpoint(id) {
+                        self.project_service.set_project(state);
+                    }
+                }
+                None => {}
+            }
+        }
+
         if self.export_dialog.is_open {
+            self.export_dialog.hardware_accel = self.app_config.export.hardware_accel;
             let active_comp_id = self.editor_context.selection.composition_id;
-            self.export_dialog
-                .show(ctx, &self.project, &self.project_service, active_comp_id);
+            self.export_dialog.show(ctx, &self.project, active_comp_id);
         }
 
         if self.editor_context.keyframe_dialog.is_open {
@@ -182,6 +223,7 @@ impl eframe::App for RuViEApp {
                         if let Err(e) = self.project_service.remove_composition_fully(id) {
                             log::error!("Failed to remove composition: {}", e);
                         } else {
+                            self.editor_context.node_editor_states.remove(&id);
                             // Clear selection if needed
                             if self.editor_context.selection.composition_id == Some(id) {
                                 self.editor_context.selection.composition_id = None;
@@ -236,6 +278,7 @@ impl eframe::App for RuViEApp {
             && !self.settings_dialog.show_close_warning
             && !self.composition_dialog.is_open
             && !self.export_dialog.is_open
+            && !self.checkpoint_dialog.is_open
             && !self.editor_context.keyframe_dialog.is_open
             && !self.command_palette.is_open;
         if main_ui_enabled && !is_listening_for_shortcut {
@@ -260,8 +303,13 @@ impl eframe::App for RuViEApp {
 
             if action == CommandId::Export {
                 self.export_dialog.open();
+            } else if action == CommandId::RenderWorkArea {
+                self.export_dialog.open_with_range(ExportRange::WorkArea);
             } else if action == CommandId::ShowCommandPalette {
                 self.command_palette.toggle();
+            } else if action == CommandId::CreateCheckpoint {
+                let existing = context.history_manager.list_checkpoints().len();
+                self.checkpoint_dialog.open(existing);
             }
 
             handle_command(ctx, action, context, &mut trigger_settings);
@@ -281,9 +329,26 @@ impl eframe::App for RuViEApp {
                     "Time: {:.2}",
                     self.editor_context.timeline.current_time
                 ));
+                if let Some(hit_rate) = self.project_service.get_cache_manager().stats().hit_rate()
+                {
+                    ui.separator();
+                    ui.label(format!("Cache hits: {:.0}%", hit_rate * 100.0));
+                }
+                ui.separator();
+                // Serializing every state is costly, so estimate only while hovered
+                ui.label(format!("Undo: {}", self.history_manager.undo_depth()))
+                    .on_hover_ui(|ui| {
+                        let bytes = self.history_manager.memory_estimate_bytes();
+                        ui.label(format!(
+                            "Undo history: ~{:.1} MB",
+                            bytes as f64 / (1024.0 * 1024.0)
+                        ));
+                    });
             });
         });
 
+        self.editor_context.view.safe_areas = self.app_config.preview;
+
         egui::CentralPanel::default().show(ctx, |ui| {
             let main_ui_enabled =
                 !self.settings_dialog.is_open && !self.settings_dialog.show_close_warning;
@@ -308,6 +373,14 @@ impl eframe::App for RuViEApp {
             self.editor_context.interaction.timeline.dragged_item = None;
         }
 
+        // Persist the timecode format chosen in the timeline
+        if self.editor_context.timeline.display_mode != self.app_config.timeline.timecode_format {
+            self.app_config.timeline.timecode_format = self.editor_context.timeline.display_mode;
+            self.settings_dialog.config.timeline.timecode_format =
+                self.editor_context.timeline.display_mode;
+            config::save_config(&self.app_config);
+        }
+
         // Always pump audio to keep buffer full if playing (or pre-buffer)
         if self.editor_context.timeline.is_playing {
             self.project_service.pump_audio();
@@ -389,6 +462,9 @@ fn create_default_project() -> (Arc<RwLock<Project>>, Uuid) {
 
 fn setup_plugin_manager(app_config: &config::AppConfig) -> Arc<library::plugin::PluginManager> {
     let plugin_manager = Arc::new(library::plugin::PluginManager::default());
+    // The wiggle and spring evaluators live in their own plugin crates but ship built in
+    plugin_manager.register_property_plugin(Arc::new(wiggle_property_plugin::WigglePropertyPlugin));
+    plugin_manager.register_property_plugin(Arc::new(spring_property_plugin::SpringPropertyPlugin));
 
     // Load plugins from configured paths
     for path in &app_config.plugins.paths {
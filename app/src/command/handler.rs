@@ -1,5 +1,4 @@
 use std::fs;
-use std::io::Write;
 // use std::sync::{Arc, RwLock};
 use eframe::egui;
 use egui_dock::DockState;
@@ -10,7 +9,7 @@ use library::EditorService;
 use crate::command::history::HistoryManager;
 use crate::command::CommandId;
 use crate::context::context::EditorContext;
-use crate::types::Tab;
+use crate::types::{GizmoHandle, Tab};
 
 pub struct ActionContext<'a> {
     pub editor_context: &'a mut EditorContext,
@@ -31,12 +30,14 @@ pub fn handle_command(
         | CommandId::LoadProject
         | CommandId::Save
         | CommandId::SaveAs
-        | CommandId::Export => {
+        | CommandId::Export
+        | CommandId::RenderWorkArea
+        | CommandId::ExportOtio => {
             handle_file_command(ctx, action, context);
         }
 
         // Edit Operations
-        CommandId::Undo | CommandId::Redo | CommandId::Delete => {
+        CommandId::Undo | CommandId::Redo | CommandId::Delete | CommandId::Duplicate => {
             handle_edit_command(action, context);
         }
 
@@ -48,11 +49,20 @@ pub fn handle_command(
             handle_view_command(action, context);
         }
 
+        // Preview Gizmo Operations
+        CommandId::GizmoGrab
+        | CommandId::GizmoRotate
+        | CommandId::GizmoScale
+        | CommandId::GizmoGrabAxis(_)
+        | CommandId::GizmoScaleAxis(_) => {
+            handle_gizmo_command(action, context);
+        }
+
         // Global / Misc Operations
         CommandId::Settings => {
             *trigger_settings = true;
         }
-        CommandId::ShowCommandPalette => {
+        CommandId::ShowCommandPalette | CommandId::CreateCheckpoint => {
             // Handled in MyApp::update explicitly to open dialog
         }
         CommandId::Quit => {
@@ -90,6 +100,8 @@ fn handle_file_command(_ctx: &egui::Context, action: CommandId, context: ActionC
                     context.history_manager.push_project_state(state);
                     info!("Project loaded from {}", path.display());
                     context.editor_context.timeline.current_time = 0.0;
+                    // Reopen node editors from the layouts stored in the loaded project
+                    context.editor_context.node_editor_states.clear();
                 }
             }
         }
@@ -99,24 +111,41 @@ fn handle_file_command(_ctx: &egui::Context, action: CommandId, context: ActionC
                 .set_file_name("project.json")
                 .save_file()
             {
-                match context.project_service.save_project() {
-                    Ok(json_str) => match fs::File::create(&path) {
-                        Ok(mut file) => {
-                            if let Err(e) = file.write_all(json_str.as_bytes()) {
-                                error!("Failed to write project to file: {}", e);
-                            } else {
-                                info!("Project saved to {}", path.display());
-                            }
-                        }
-                        Err(e) => error!("Failed to create file: {}", e),
-                    },
+                crate::panels::node_editor::store_node_layouts(
+                    &context.editor_context.node_editor_states,
+                    context.project_service,
+                );
+                match context.project_service.save_project_to_path(&path) {
+                    Ok(()) => info!("Project saved to {}", path.display()),
                     Err(e) => error!("Failed to save project: {}", e),
                 }
             }
         }
-        CommandId::Export => {
+        CommandId::Export | CommandId::RenderWorkArea => {
             // Handled elsewhere or placeholder
         }
+        CommandId::ExportOtio => {
+            let Some(comp_id) = context.editor_context.selection.composition_id else {
+                warn!("No composition selected for OpenTimelineIO export");
+                return;
+            };
+            if let Some(path) = rfd::FileDialog::new()
+                .add_filter("OpenTimelineIO", &["otio"])
+                .set_file_name("timeline.otio")
+                .save_file()
+            {
+                match context
+                    .project_service
+                    .with_project(|p| p.export_otio_json(comp_id))
+                {
+                    Ok(json_str) => match fs::write(&path, json_str) {
+                        Ok(()) => info!("Timeline exported to {}", path.display()),
+                        Err(e) => error!("Failed to write OpenTimelineIO file: {}", e),
+                    },
+                    Err(e) => error!("Failed to export OpenTimelineIO: {}", e),
+                }
+            }
+        }
         _ => {}
     }
 }
@@ -162,6 +191,21 @@ fn handle_edit_command(action: CommandId, context: ActionContext) {
                 }
             }
         }
+        CommandId::Duplicate => {
+            if let Some(entity_id) = context.editor_context.selection.last_selected_entity_id {
+                match context.project_service.duplicate_layer(entity_id) {
+                    Ok(new_id) => {
+                        let selection = &mut context.editor_context.selection;
+                        selection.selected_entities.clear();
+                        selection.selected_entities.insert(new_id);
+                        selection.last_selected_entity_id = Some(new_id);
+                        let current_state = context.project_service.with_project(|p| p.clone());
+                        context.history_manager.push_project_state(current_state);
+                    }
+                    Err(e) => error!("Failed to duplicate entity: {:?}", e),
+                }
+            }
+        }
         _ => {}
     }
 }
@@ -205,3 +249,41 @@ fn handle_view_command(action: CommandId, context: ActionContext) {
         _ => {}
     }
 }
+
+fn handle_gizmo_command(action: CommandId, context: ActionContext) {
+    let (handle, axis) = match action {
+        CommandId::GizmoGrab => (GizmoHandle::Move, None),
+        CommandId::GizmoRotate => (GizmoHandle::Rotation, None),
+        CommandId::GizmoScale => (GizmoHandle::Scale, None),
+        CommandId::GizmoGrabAxis(axis) => (GizmoHandle::Move, Some(axis)),
+        CommandId::GizmoScaleAxis(axis) => (GizmoHandle::Scale, Some(axis)),
+        _ => return,
+    };
+
+    // Over the timeline clips, S toggles snapping instead of scaling
+    if context
+        .editor_context
+        .interaction
+        .timeline
+        .pointer_in_layer_area
+    {
+        return;
+    }
+
+    let preview = &mut context.editor_context.interaction.preview;
+    // An axis key while the same operation is running only locks the axis
+    if let Some(state) = &mut preview.gizmo_state {
+        if state.active_handle == handle {
+            state.axis_constraint = axis;
+        }
+        return;
+    }
+    if context
+        .editor_context
+        .selection
+        .last_selected_entity_id
+        .is_some()
+    {
+        preview.pending_gizmo_op = Some((handle, axis));
+    }
+}
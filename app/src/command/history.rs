@@ -1,30 +1,218 @@
+use std::collections::{BTreeMap, HashMap};
 use std::sync::{Arc, RwLock};
 
+use egui::Id;
+use uuid::Uuid;
+
+use library::project::node::Node;
 use library::project::project::Project;
+use library::project::property::PropertyMap;
+
+/// A project state on the undo/redo stacks, optionally tagged as a named snapshot.
+struct HistoryEntry {
+    project: Project,
+    snapshot_name: Option<String>,
+    /// Human-readable summary of what changed relative to the previous state.
+    description: Option<String>,
+}
+
+impl HistoryEntry {
+    fn new(project: Project, description: Option<String>) -> Self {
+        Self {
+            project,
+            snapshot_name: None,
+            description,
+        }
+    }
+}
+
+/// A compound action open for one widget, e.g. while it is being dragged.
+struct Compound {
+    owner: Id,
+    /// Latest state pushed for the owner inside the compound action.
+    state: Option<Project>,
+}
+
+/// Undo states kept by [`HistoryManager::new`].
+pub(crate) const DEFAULT_MAX_DEPTH: usize = 50;
 
 pub(crate) struct HistoryManager {
-    undo_stack: Vec<Project>,
-    redo_stack: Vec<Project>,
+    undo_stack: Vec<HistoryEntry>,
+    redo_stack: Vec<HistoryEntry>,
+    /// Maximum number of states on the undo stack; the oldest are dropped first.
+    max_depth: usize,
+    /// Named project states kept outside the undo stack, so the depth limit never evicts them.
+    checkpoints: BTreeMap<Uuid, (String, Project)>,
+    /// The open compound action, if any. Only its owner's pushes are grouped.
+    compound: Option<Compound>,
 }
 
 impl HistoryManager {
     pub(crate) fn new() -> Self {
+        Self::with_max_depth(DEFAULT_MAX_DEPTH)
+    }
+
+    /// A history that keeps at most `max` undo states (at least one).
+    pub(crate) fn with_max_depth(max: usize) -> Self {
         Self {
             undo_stack: Vec::new(),
             redo_stack: Vec::new(),
+            max_depth: max.max(1),
+            checkpoints: BTreeMap::new(),
+            compound: None,
         }
     }
 
     /// Pushes a new project state onto the undo stack. Clears the redo stack.
     /// If the new state is identical to the current top of the stack, the push is ignored (heuristically deduplicated).
+    /// The description is computed by diffing properties against the previous state.
+    /// Closes any open compound action first, since the push comes from another source.
     pub(crate) fn push_project_state(&mut self, project: Project) {
+        self.close_compound();
+        let description = self.describe_change_to(&project);
+        self.push_project_state_with_description(project, description);
+    }
+
+    /// Summary of what changed from the current top of the undo stack to `project`.
+    pub(crate) fn describe_change_to(&self, project: &Project) -> Option<String> {
+        self.undo_stack
+            .last()
+            .and_then(|last| describe_changes(&last.project, project))
+    }
+
+    /// Starts grouping `owner`'s pushes into one undo step, e.g. for a drag gesture.
+    /// A compound action open for another widget is closed first.
+    pub(crate) fn begin_compound(&mut self, owner: Id) {
+        if self.compound_owner() == Some(owner) {
+            return;
+        }
+        self.close_compound();
+        self.compound = Some(Compound { owner, state: None });
+    }
+
+    /// Records `project` for `owner`: buffered while `owner`'s compound action
+    /// is open, otherwise pushed as usual.
+    pub(crate) fn push_compound_state(&mut self, owner: Id, project: Project) {
+        match &mut self.compound {
+            Some(compound) if compound.owner == owner => compound.state = Some(project),
+            _ => self.push_project_state(project),
+        }
+    }
+
+    /// Closes `owner`'s compound action, recording the last state pushed inside
+    /// it as a single undo step described by `label`. Does nothing for other owners.
+    pub(crate) fn end_compound(&mut self, owner: Id, label: String) {
+        if self.compound_owner() != Some(owner) {
+            return;
+        }
+        if let Some(project) = self.compound.take().and_then(|c| c.state) {
+            self.push_project_state_with_description(project, Some(label));
+        }
+    }
+
+    /// Closes the open compound action, if any, recording what it buffered.
+    /// Used when the owner went away without ending it, e.g. a drag lost mid-way.
+    pub(crate) fn close_compound(&mut self) {
+        if let Some(project) = self.compound.take().and_then(|c| c.state) {
+            let description = self.describe_change_to(&project);
+            self.push_project_state_with_description(project, description);
+        }
+    }
+
+    /// The widget whose compound action is open.
+    pub(crate) fn compound_owner(&self) -> Option<Id> {
+        self.compound.as_ref().map(|c| c.owner)
+    }
+
+    /// Like [`Self::push_project_state`], but with an explicit change description.
+    pub(crate) fn push_project_state_with_description(
+        &mut self,
+        project: Project,
+        description: Option<String>,
+    ) {
+        self.close_compound();
         if let Some(last) = self.undo_stack.last() {
-            if last == &project {
+            if last.project == project {
                 return;
             }
         }
-        self.undo_stack.push(project);
+        self.undo_stack
+            .push(HistoryEntry::new(project, description));
         self.redo_stack.clear();
+
+        if self.undo_stack.len() > self.max_depth {
+            let excess = self.undo_stack.len() - self.max_depth;
+            self.undo_stack.drain(..excess);
+        }
+    }
+
+    /// Number of states on the undo stack, including the current one.
+    pub(crate) fn undo_depth(&self) -> usize {
+        self.undo_stack.len()
+    }
+
+    /// Approximate memory held by the undo stack: the total length of each state serialized as JSON.
+    pub(crate) fn memory_estimate_bytes(&self) -> usize {
+        self.undo_stack
+            .iter()
+            .filter_map(|entry| serde_json::to_string(&entry.project).ok())
+            .map(|json| json.len())
+            .sum()
+    }
+
+    /// Description of the change the next undo would revert, if known.
+    pub(crate) fn undo_description(&self) -> Option<&str> {
+        if self.undo_stack.len() <= 1 {
+            return None;
+        }
+        self.undo_stack.last()?.description.as_deref()
+    }
+
+    /// Pushes a project state tagged with `name` so it can be restored with [`Self::undo_to_snapshot`].
+    /// If the state is identical to the current top of the stack, the top entry is tagged instead.
+    pub(crate) fn push_named_snapshot(&mut self, name: &str, project: Project) {
+        self.push_project_state(project);
+        if let Some(last) = self.undo_stack.last_mut() {
+            last.snapshot_name = Some(name.to_string());
+        }
+    }
+
+    /// Names of the snapshots currently on the undo stack, most recent first.
+    pub(crate) fn snapshot_names(&self) -> Vec<String> {
+        self.undo_stack
+            .iter()
+            .rev()
+            .filter_map(|entry| entry.snapshot_name.clone())
+            .collect()
+    }
+
+    /// Stores `project` as a checkpoint named `name` and returns its id.
+    pub(crate) fn create_checkpoint(&mut self, name: String, project: Project) -> Uuid {
+        let id = Uuid::new_v4();
+        self.checkpoints.insert(id, (name, project));
+        id
+    }
+
+    /// Returns the state saved in checkpoint `id` and pushes it onto the undo stack,
+    /// so the restore itself can be undone.
+    pub(crate) fn restore_checkpoint(&mut self, id: Uuid) -> Option<Project> {
+        let (name, project) = self.checkpoints.get(&id)?.clone();
+        self.push_project_state_with_description(
+            project.clone(),
+            Some(format!("Restore checkpoint {}", name)),
+        );
+        Some(project)
+    }
+
+    /// Checkpoint ids and names, sorted by name.
+    pub(crate) fn list_checkpoints(&self) -> Vec<(Uuid, String)> {
+        let mut list: Vec<(Uuid, String)> = self
+            .checkpoints
+            .iter()
+            .map(|(id, (name, _))| (*id, name.clone()))
+            .collect();
+        list.sort_by(|a, b| a.1.cmp(&b.1));
+        list
     }
 
     /// Returns an RAII guard that auto-pushes the current project state on drop.
@@ -45,6 +233,7 @@ impl HistoryManager {
     /// Returns the *new* top of the undo stack (the state before the action), without popping it.
     /// If the undo stack has 1 or 0 elements, returns None (cannot undo initial state).
     pub(crate) fn undo(&mut self) -> Option<Project> {
+        self.close_compound();
         if self.undo_stack.len() <= 1 {
             return None;
         }
@@ -52,26 +241,117 @@ impl HistoryManager {
         if let Some(current_state) = self.undo_stack.pop() {
             self.redo_stack.push(current_state);
             // Return a clone of the new top (the previous state)
-            self.undo_stack.last().cloned()
+            self.undo_stack.last().map(|entry| entry.project.clone())
         } else {
             None
         }
     }
 
+    /// Undoes back to the most recent snapshot named `name`.
+    /// Pops states until that snapshot is on top (or only the initial state remains),
+    /// pushing each popped state to the redo stack. Returns the new top state, or None if nothing was undone.
+    pub(crate) fn undo_to_snapshot(&mut self, name: &str) -> Option<Project> {
+        let target = self
+            .undo_stack
+            .iter()
+            .rposition(|entry| entry.snapshot_name.as_deref() == Some(name))
+            .unwrap_or(0);
+
+        let mut result = None;
+        while self.undo_stack.len() > target + 1 {
+            result = self.undo();
+        }
+        result
+    }
+
     /// Redoes the last undone action.
     /// Pops from redo stack, pushes to undo stack, and returns the new current state.
     pub(crate) fn redo(&mut self) -> Option<Project> {
+        self.close_compound();
         if let Some(next_state) = self.redo_stack.pop() {
-            self.undo_stack.push(next_state.clone());
-            Some(next_state)
+            let project = next_state.project.clone();
+            self.undo_stack.push(next_state);
+            Some(project)
         } else {
             None
         }
     }
 
+    /// Forgets all history of the current project, including checkpoints.
     pub(crate) fn clear(&mut self) {
         self.undo_stack.clear();
         self.redo_stack.clear();
+        self.checkpoints.clear();
+        self.compound = None;
+    }
+}
+
+/// Summarize property changes between two project states,
+/// e.g. "Changed: opacity, position on clip Title".
+fn describe_changes(prev: &Project, next: &Project) -> Option<String> {
+    // Layer name of each clip, built once rather than searched per node
+    let layer_names: HashMap<Uuid, &str> = next
+        .nodes
+        .values()
+        .filter_map(|node| match node {
+            Node::Layer(layer) => Some(layer),
+            _ => None,
+        })
+        .flat_map(|layer| {
+            layer
+                .child_ids
+                .iter()
+                .map(move |id| (*id, layer.name.as_str()))
+        })
+        .collect();
+
+    let mut parts: Vec<String> = next
+        .nodes
+        .values()
+        .filter_map(|node| {
+            let properties = node_properties(node)?;
+            let prev_properties = prev.get_node(node.id()).and_then(node_properties)?;
+            let keys: Vec<String> = prev_properties
+                .diff(properties)
+                .into_iter()
+                .map(|d| d.key)
+                .collect();
+            if keys.is_empty() {
+                None
+            } else {
+                Some(format!(
+                    "{} on {}",
+                    keys.join(", "),
+                    node_label(node, &layer_names)
+                ))
+            }
+        })
+        .collect();
+    if parts.is_empty() {
+        return None;
+    }
+    parts.sort();
+    Some(format!("Changed: {}", parts.join("; ")))
+}
+
+/// Property map of a clip or graph node.
+fn node_properties(node: &Node) -> Option<&PropertyMap> {
+    match node {
+        Node::Source(source) => Some(&source.properties),
+        Node::Graph(graph) => Some(&graph.properties),
+        _ => None,
+    }
+}
+
+/// Label for a clip (its layer's name) or graph node in a change summary.
+fn node_label(node: &Node, layer_names: &HashMap<Uuid, &str>) -> String {
+    match node {
+        Node::Source(source) => match layer_names.get(&source.id) {
+            Some(name) => format!("clip {}", name),
+            None => format!("clip {}", source.kind),
+        },
+        Node::Graph(graph) => format!("node {}", graph.type_id),
+        _ => String::new(),
     }
 }
 
@@ -94,3 +374,156 @@ impl Drop for HistoryGuard<'_> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undo_to_snapshot_pops_to_named_state() {
+        let mut history = HistoryManager::new();
+        history.push_project_state(Project::new("initial"));
+        history.push_named_snapshot("checkpoint", Project::new("checkpoint"));
+        history.push_project_state(Project::new("edit 1"));
+        history.push_project_state(Project::new("edit 2"));
+
+        // 名前付きスナップショットまで一度に戻る
+        let restored = history.undo_to_snapshot("checkpoint").unwrap();
+        assert_eq!(restored.name, "checkpoint");
+        assert_eq!(history.snapshot_names(), vec!["checkpoint".to_string()]);
+
+        // 戻した状態はすべてredoで復元できる
+        assert_eq!(history.redo().unwrap().name, "edit 1");
+        assert_eq!(history.redo().unwrap().name, "edit 2");
+        assert!(history.redo().is_none());
+    }
+
+    #[test]
+    fn push_describes_changed_properties() {
+        use library::project::graph_node::GraphNode;
+        use library::project::property::{Property, PropertyMap, PropertyValue};
+
+        let mut project = Project::new("p");
+        let mut properties = PropertyMap::new();
+        properties.set(
+            "opacity".into(),
+            Property::constant(PropertyValue::from(1.0)),
+        );
+        let node = GraphNode::new("effect.blur", properties);
+        let node_id = node.id;
+        project.add_node(Node::Graph(node));
+
+        let mut history = HistoryManager::new();
+        history.push_project_state(project.clone());
+        // 初期状態はundoできないため説明なし
+        assert_eq!(history.undo_description(), None);
+
+        if let Some(Node::Graph(g)) = project.nodes.get_mut(&node_id) {
+            g.properties.set(
+                "opacity".into(),
+                Property::constant(PropertyValue::from(0.5)),
+            );
+        }
+        history.push_project_state(project);
+        assert_eq!(
+            history.undo_description(),
+            Some("Changed: opacity on node effect.blur")
+        );
+    }
+
+    #[test]
+    fn push_drops_oldest_states_beyond_max_depth() {
+        let mut history = HistoryManager::with_max_depth(3);
+        for i in 0..5 {
+            history.push_project_state(Project::new(&format!("edit {}", i)));
+        }
+        assert_eq!(history.undo_depth(), 3);
+
+        // 古い状態から捨てられるので、最後まで戻っても edit 2 まで
+        assert_eq!(history.undo().unwrap().name, "edit 3");
+        assert_eq!(history.undo().unwrap().name, "edit 2");
+        assert!(history.undo().is_none());
+
+        assert!(history.memory_estimate_bytes() > 0);
+    }
+
+    #[test]
+    fn checkpoints_survive_depth_limit() {
+        let mut history = HistoryManager::with_max_depth(2);
+        history.push_project_state(Project::new("initial"));
+        let id = history.create_checkpoint("before edits".to_string(), Project::new("initial"));
+        for i in 0..5 {
+            history.push_project_state(Project::new(&format!("edit {}", i)));
+        }
+
+        // undo では戻れない状態でもチェックポイントから復元できる
+        assert_eq!(
+            history.list_checkpoints(),
+            vec![(id, "before edits".to_string())]
+        );
+        let restored = history.restore_checkpoint(id).unwrap();
+        assert_eq!(restored.name, "initial");
+
+        // 復元自体も undo できる
+        assert_eq!(history.undo().unwrap().name, "edit 4");
+        assert!(history.restore_checkpoint(Uuid::new_v4()).is_none());
+    }
+
+    #[test]
+    fn compound_records_only_final_state() {
+        let mut history = HistoryManager::new();
+        history.push_project_state(Project::new("initial"));
+        let slider = Id::new("opacity");
+
+        history.begin_compound(slider);
+        for i in 0..10 {
+            history.push_compound_state(slider, Project::new(&format!("drag {}", i)));
+        }
+        // 別のウィジェットの end_compound では閉じない
+        history.end_compound(Id::new("other"), "other".to_string());
+        assert_eq!(history.undo_depth(), 1);
+        history.end_compound(slider, "Drag opacity".to_string());
+        assert_eq!(history.compound_owner(), None);
+
+        // ドラッグ全体が 1 ステップになる
+        assert_eq!(history.undo_depth(), 2);
+        assert_eq!(history.undo_description(), Some("Drag opacity"));
+        assert_eq!(history.undo().unwrap().name, "initial");
+    }
+
+    #[test]
+    fn compound_closes_on_outside_push_and_undo() {
+        let mut history = HistoryManager::new();
+        history.push_project_state(Project::new("initial"));
+        let slider = Id::new("opacity");
+
+        // 他の操作からの push で開いたままの compound は閉じられる
+        history.begin_compound(slider);
+        history.push_compound_state(slider, Project::new("drag"));
+        history.push_project_state(Project::new("timeline edit"));
+        assert_eq!(history.compound_owner(), None);
+        assert_eq!(history.undo_depth(), 3);
+
+        // undo も compound を閉じてから戻る
+        history.begin_compound(slider);
+        history.push_compound_state(slider, Project::new("drag again"));
+        assert_eq!(history.undo().unwrap().name, "timeline edit");
+        assert_eq!(history.compound_owner(), None);
+
+        // 閉じた後の end_compound は何も記録しない
+        history.end_compound(slider, "Drag opacity".to_string());
+        assert_eq!(history.undo_depth(), 3);
+    }
+
+    #[test]
+    fn undo_to_unknown_snapshot_returns_to_initial_state() {
+        let mut history = HistoryManager::new();
+        history.push_project_state(Project::new("initial"));
+        history.push_project_state(Project::new("edit"));
+
+        // 見つからない場合はスタックの底まで戻る
+        let restored = history.undo_to_snapshot("missing").unwrap();
+        assert_eq!(restored.name, "initial");
+        assert!(history.undo_to_snapshot("missing").is_none());
+    }
+}
@@ -1,5 +1,6 @@
+use crate::command::shortcut::ChordShortcut;
 use crate::config::AppConfig;
-use crate::types::Tab;
+use crate::types::{GizmoAxis, Tab};
 use eframe::egui::{Key, Modifiers};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
@@ -10,12 +11,16 @@ pub(crate) enum CommandId {
     Save,
     SaveAs,
     Export,
+    RenderWorkArea,
+    ExportOtio,
     Quit,
 
     // Edit Menu
     Undo,
     Redo,
     Delete,
+    Duplicate,
+    CreateCheckpoint,
     Settings,
 
     // View Menu
@@ -28,6 +33,13 @@ pub(crate) enum CommandId {
     // Tools
     HandTool,
     ShowCommandPalette,
+
+    // Preview Gizmo
+    GizmoGrab,
+    GizmoRotate,
+    GizmoScale,
+    GizmoGrabAxis(GizmoAxis),
+    GizmoScaleAxis(GizmoAxis),
 }
 
 #[derive(Clone, PartialEq)]
@@ -43,6 +55,7 @@ pub(crate) struct Command {
 #[derive(Clone)]
 pub(crate) struct CommandRegistry {
     pub(crate) commands: Vec<Command>,
+    pub(crate) chords: Vec<ChordShortcut>,
 }
 
 fn get_shortcut_text(shortcut: &Option<(Modifiers, Key)>) -> String {
@@ -116,7 +129,7 @@ impl CommandRegistry {
             Command::new(
                 CommandId::SaveAs,
                 "Save As...",
-                Some((Modifiers::COMMAND | Modifiers::SHIFT, Key::S)),
+                Some((Modifiers::COMMAND | Modifiers::ALT, Key::S)),
                 true,
                 false,
             ),
@@ -127,6 +140,20 @@ impl CommandRegistry {
                 true,
                 false,
             ),
+            Command::new(
+                CommandId::RenderWorkArea,
+                "Render Work Area...",
+                None,
+                true,
+                false,
+            ),
+            Command::new(
+                CommandId::ExportOtio,
+                "Export OpenTimelineIO JSON...",
+                None,
+                true,
+                false,
+            ),
             Command::new(
                 CommandId::Quit,
                 "Quit",
@@ -156,6 +183,20 @@ impl CommandRegistry {
                 false,
                 false,
             ),
+            Command::new(
+                CommandId::Duplicate,
+                "Duplicate",
+                Some((Modifiers::COMMAND, Key::D)),
+                false,
+                false,
+            ),
+            Command::new(
+                CommandId::CreateCheckpoint,
+                "Create Checkpoint...",
+                Some((Modifiers::COMMAND | Modifiers::SHIFT, Key::S)),
+                true,
+                false,
+            ),
             Command::new(
                 CommandId::Settings,
                 "Settings...",
@@ -188,8 +229,79 @@ impl CommandRegistry {
                 true,
                 false,
             ),
+            // Preview Gizmo
+            Command::new(
+                CommandId::GizmoGrab,
+                "Grab",
+                Some((Modifiers::NONE, Key::G)),
+                false,
+                false,
+            ),
+            Command::new(
+                CommandId::GizmoRotate,
+                "Rotate",
+                Some((Modifiers::NONE, Key::R)),
+                false,
+                false,
+            ),
+            Command::new(
+                CommandId::GizmoScale,
+                "Scale",
+                Some((Modifiers::NONE, Key::S)),
+                false,
+                false,
+            ),
+            Command::new(
+                CommandId::GizmoGrabAxis(GizmoAxis::X),
+                "Grab Along X",
+                None,
+                false,
+                false,
+            ),
+            Command::new(
+                CommandId::GizmoGrabAxis(GizmoAxis::Y),
+                "Grab Along Y",
+                None,
+                false,
+                false,
+            ),
+            Command::new(
+                CommandId::GizmoScaleAxis(GizmoAxis::X),
+                "Scale Along X",
+                None,
+                false,
+                false,
+            ),
+            Command::new(
+                CommandId::GizmoScaleAxis(GizmoAxis::Y),
+                "Scale Along Y",
+                None,
+                false,
+                false,
+            ),
         ];
 
+        // Two-key sequences: the first key starts the operation, the second locks an axis
+        let mut chords = Vec::new();
+        for (first, make) in [
+            (
+                Key::G,
+                CommandId::GizmoGrabAxis as fn(GizmoAxis) -> CommandId,
+            ),
+            (
+                Key::S,
+                CommandId::GizmoScaleAxis as fn(GizmoAxis) -> CommandId,
+            ),
+        ] {
+            for (second, axis) in [(Key::X, GizmoAxis::X), (Key::Y, GizmoAxis::Y)] {
+                chords.push(ChordShortcut {
+                    first: (Modifiers::NONE, first),
+                    second,
+                    command: make(axis),
+                });
+            }
+        }
+
         // Register TogglePanel commands
         for tab in Tab::all() {
             commands.push(Command::new(
@@ -210,11 +322,46 @@ impl CommandRegistry {
                 cmd.shortcut_text = get_shortcut_text(&cmd.shortcut);
             }
         }
+        // Chord-only commands show their key sequence instead
+        for chord in &chords {
+            if let Some(cmd) = commands
+                .iter_mut()
+                .find(|cmd| cmd.id == chord.command && cmd.shortcut.is_none())
+            {
+                cmd.shortcut_text = format!(
+                    "{} {:?}",
+                    get_shortcut_text(&Some(chord.first)),
+                    chord.second
+                );
+            }
+        }
 
-        Self { commands }
+        Self { commands, chords }
     }
 
     pub(crate) fn find(&self, id: CommandId) -> Option<&Command> {
         self.commands.iter().find(|&cmd| cmd.id == id)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gizmo_axis_chords_are_registered() {
+        let registry = CommandRegistry::new(&AppConfig::new());
+
+        // G → X で X 軸拘束の移動になる
+        assert!(registry.chords.contains(&ChordShortcut {
+            first: (Modifiers::NONE, Key::G),
+            second: Key::X,
+            command: CommandId::GizmoGrabAxis(GizmoAxis::X),
+        }));
+        // コードのみのコマンドはキー列を表示する
+        let cmd = registry
+            .find(CommandId::GizmoScaleAxis(GizmoAxis::Y))
+            .unwrap();
+        assert_eq!(cmd.shortcut_text, "S Y");
+    }
+}
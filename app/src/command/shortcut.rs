@@ -1,22 +1,39 @@
 use crate::command::{CommandId, CommandRegistry};
 use crate::context::context::EditorContext;
-use eframe::egui::{Context, Modifiers};
+use eframe::egui::{Context, Event, Key, Modifiers};
 
-pub struct ShortcutManager;
+/// A two-key sequence, e.g. `G` then `X`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ChordShortcut {
+    pub(crate) first: (Modifiers, Key),
+    pub(crate) second: Key,
+    pub(crate) command: CommandId,
+}
+
+pub struct ShortcutManager {
+    /// First key of a chord, waiting for the second keypress.
+    pending_chord: Option<(Modifiers, Key)>,
+}
 
 impl ShortcutManager {
     pub fn new() -> Self {
-        Self
+        Self {
+            pending_chord: None,
+        }
     }
 
     pub fn handle_shortcuts(
-        &self,
+        &mut self,
         ctx: &Context,
         registry: &CommandRegistry,
         editor_ctx: &mut EditorContext,
     ) -> Option<CommandId> {
         let wants_keyboard_input = ctx.wants_keyboard_input();
 
+        if let Some(command) = self.handle_chords(ctx, registry, wants_keyboard_input) {
+            return Some(command);
+        }
+
         for cmd in &registry.commands {
             // If the UI wants input (e.g. typing in text box),
             // ONLY trigger commands that are:
@@ -68,6 +85,62 @@ impl ShortcutManager {
         }
         None
     }
+
+    /// Complete a pending chord, or remember the first key of a new one.
+    ///
+    /// The first key is not consumed, so a command bound to it alone still fires
+    /// (`G` starts a grab, `G` → `X` then locks it to the X axis).
+    fn handle_chords(
+        &mut self,
+        ctx: &Context,
+        registry: &CommandRegistry,
+        wants_keyboard_input: bool,
+    ) -> Option<CommandId> {
+        // Chords are plain keys, so never intercept typing
+        if wants_keyboard_input {
+            self.pending_chord = None;
+            return None;
+        }
+
+        let (key_pressed, pointer_pressed) = ctx.input(|i| {
+            (
+                i.events.iter().any(|e| {
+                    matches!(
+                        e,
+                        Event::Key {
+                            pressed: true,
+                            repeat: false,
+                            ..
+                        }
+                    )
+                }),
+                i.pointer.any_pressed(),
+            )
+        });
+
+        if let Some(first) = self.pending_chord {
+            if key_pressed || pointer_pressed {
+                // Any other input ends the chord
+                self.pending_chord = None;
+            }
+            if key_pressed {
+                let completed = registry.chords.iter().find(|chord| {
+                    chord.first == first && ctx.input(|i| i.key_pressed(chord.second))
+                });
+                if let Some(chord) = completed {
+                    return Some(chord.command);
+                }
+            }
+        }
+
+        if let Some(chord) = registry.chords.iter().find(|chord| {
+            let (modifiers, key) = chord.first;
+            ctx.input(|i| i.key_pressed(key) && modifiers_match(i.modifiers, modifiers))
+        }) {
+            self.pending_chord = Some(chord.first);
+        }
+        None
+    }
 }
 
 fn modifiers_match(event_modifiers: Modifiers, expected_modifiers: Modifiers) -> bool {
@@ -1,6 +1,8 @@
 use crate::command::CommandId;
+use crate::types::TimecodeFormat;
 use directories::ProjectDirs;
 use eframe::egui::{Key, Modifiers};
+use library::plugin::HwAccelBackend;
 use log::{error, info, warn};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -157,6 +159,73 @@ pub struct ThemeConfig {
     pub theme_type: ThemeType,
 }
 
+/// Hardware encoder preference for ffmpeg exports.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub enum HardwareAccel {
+    #[default]
+    Software,
+    /// Use the first hardware encoder ffmpeg reports, if any.
+    AutoDetect,
+    Backend(HwAccelBackend),
+}
+
+impl HardwareAccel {
+    pub fn label(&self) -> &'static str {
+        match self {
+            HardwareAccel::Software => "Software",
+            HardwareAccel::AutoDetect => "Auto-detect",
+            HardwareAccel::Backend(backend) => backend.label(),
+        }
+    }
+
+    /// Backend to request from the exporter; auto-detection runs `ffmpeg_binary`.
+    pub fn resolve(&self, ffmpeg_binary: &str) -> Option<HwAccelBackend> {
+        match self {
+            HardwareAccel::Software => None,
+            HardwareAccel::AutoDetect => HwAccelBackend::detect(ffmpeg_binary),
+            HardwareAccel::Backend(backend) => Some(*backend),
+        }
+    }
+}
+
+/// Safe area overlays of the preview, as a percentage of the frame size.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct PreviewConfig {
+    #[serde(default = "default_action_safe_percent")]
+    pub action_safe_percent: f32,
+    #[serde(default = "default_title_safe_percent")]
+    pub title_safe_percent: f32,
+}
+
+fn default_action_safe_percent() -> f32 {
+    90.0
+}
+
+fn default_title_safe_percent() -> f32 {
+    80.0
+}
+
+impl Default for PreviewConfig {
+    fn default() -> Self {
+        Self {
+            action_safe_percent: default_action_safe_percent(),
+            title_safe_percent: default_title_safe_percent(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct TimelineConfig {
+    #[serde(default)]
+    pub timecode_format: TimecodeFormat,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct ExportConfig {
+    #[serde(default)]
+    pub hardware_accel: HardwareAccel,
+}
+
 #[derive(Serialize, Deserialize)]
 struct ShortcutDefWrapper {
     #[serde(with = "ModifiersDef")]
@@ -171,6 +240,12 @@ pub struct AppConfig {
     pub shortcuts: HashMap<CommandId, Option<(Modifiers, Key)>>,
     pub plugins: PluginConfig,
     pub theme: ThemeConfig,
+    #[serde(default)]
+    pub export: ExportConfig,
+    #[serde(default)]
+    pub timeline: TimelineConfig,
+    #[serde(default)]
+    pub preview: PreviewConfig,
 }
 
 impl AppConfig {
@@ -180,6 +255,9 @@ impl AppConfig {
             plugins: PluginConfig {
                 paths: vec!["./assets/plugins/sksl".to_string()],
                 loader_priority: vec![
+                    "native_svg_loader".to_string(),
+                    "pdf_loader".to_string(),
+                    "exr_loader".to_string(),
                     "native_image_loader".to_string(),
                     "ffmpeg_video_loader".to_string(),
                 ],
@@ -187,6 +265,9 @@ impl AppConfig {
             theme: ThemeConfig {
                 theme_type: ThemeType::Dark,
             },
+            export: ExportConfig::default(),
+            timeline: TimelineConfig::default(),
+            preview: PreviewConfig::default(),
         }
     }
 }
@@ -1,6 +1,7 @@
 use library::project::project::{Composition, Project};
 use library::runtime::frame::Region;
 use library::EditorService;
+use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 
 use serde::{Deserialize, Serialize};
@@ -18,8 +19,8 @@ pub(crate) struct PanelContext<'a> {
 }
 
 use crate::context::context_types::{
-    GraphEditorState, InteractionState, KeyframeDialogState, SelectionState, TimelineState,
-    ViewState,
+    GraphEditorState, InteractionState, KeyframeCopy, KeyframeDialogState, NodeOutputState,
+    SelectionState, TimelineState, ViewState,
 };
 
 #[derive(Serialize, Deserialize)]
@@ -33,9 +34,11 @@ pub(crate) struct EditorContext {
     // Added keyframe_dialog state
     pub(crate) keyframe_dialog: KeyframeDialogState,
 
-    // Node Editor State
+    // Node Editor State, one per composition so pan/zoom and selection survive switching
     #[serde(skip)]
-    pub(crate) node_editor_state: egui_node_editor::NodeEditorState,
+    pub(crate) node_editor_states: HashMap<Uuid, egui_node_editor::NodeEditorState>,
+    #[serde(skip)]
+    pub(crate) node_output: NodeOutputState,
 
     #[serde(skip)]
     pub(crate) interaction: InteractionState,
@@ -53,6 +56,10 @@ pub(crate) struct EditorContext {
 
     #[serde(skip)]
     pub(crate) available_fonts: Vec<String>,
+
+    /// Keyframes copied in the graph editor.
+    #[serde(skip)]
+    pub(crate) keyframe_clipboard: Vec<KeyframeCopy>,
 }
 
 pub(crate) use crate::context::context_types::GizmoState; // Re-export for compatibility if needed, though better to import from context_types
@@ -68,7 +75,8 @@ impl EditorContext {
             selection,
             graph_editor: GraphEditorState::default(),
             keyframe_dialog: KeyframeDialogState::default(),
-            node_editor_state: Default::default(),
+            node_editor_states: HashMap::new(),
+            node_output: NodeOutputState::default(),
             interaction: InteractionState::default(),
             preview_texture: None,
             preview_texture_id: None,
@@ -76,6 +84,7 @@ impl EditorContext {
             preview_texture_height: 0,
             preview_region: None,
             available_fonts: Vec::new(),
+            keyframe_clipboard: Vec::new(),
         }
     }
 
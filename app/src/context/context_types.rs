@@ -1,10 +1,12 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::config::PreviewConfig;
 use crate::types::VectorEditorState;
-use crate::types::{DraggedItem, GizmoHandle, TimelineDisplayMode, Vec2Def};
+use crate::types::{DraggedItem, GizmoAxis, GizmoHandle, TimecodeFormat, Vec2Def};
 
 use library::animation::EasingFunction; // Added import
+use library::project::property::PropertyValue;
 
 #[derive(Clone, Serialize, Deserialize)]
 pub(crate) struct KeyframeDialogState {
@@ -38,7 +40,8 @@ pub(crate) struct TimelineState {
     pub(crate) current_time: f32,
     pub(crate) is_playing: bool,
     pub(crate) pixels_per_second: f32,
-    pub(crate) display_mode: TimelineDisplayMode,
+    #[serde(default)]
+    pub(crate) display_mode: TimecodeFormat,
     pub(crate) v_zoom: f32,
     pub(crate) h_zoom: f32,
     #[serde(skip)]
@@ -47,6 +50,16 @@ pub(crate) struct TimelineState {
     pub(crate) scroll_offset: egui::Vec2,
     #[serde(default)]
     pub(crate) expanded_tracks: HashSet<Uuid>,
+    /// Snap dragged clips to other clips' edges and the playhead.
+    #[serde(default = "default_snap_to_frames")]
+    pub(crate) snap_enabled: bool,
+    /// Distance in screen pixels within which a dragged clip edge snaps.
+    #[serde(default = "default_snap_threshold")]
+    pub(crate) snap_threshold: f32,
+}
+
+fn default_snap_threshold() -> f32 {
+    8.0
 }
 
 impl Default for TimelineState {
@@ -55,12 +68,14 @@ impl Default for TimelineState {
             current_time: 0.0,
             is_playing: false,
             pixels_per_second: 50.0,
-            display_mode: TimelineDisplayMode::Seconds,
+            display_mode: TimecodeFormat::default(),
             v_zoom: 1.0,
             h_zoom: 1.0,
             playback_accumulator: 0.0,
             scroll_offset: egui::Vec2::ZERO,
             expanded_tracks: HashSet::new(),
+            snap_enabled: true,
+            snap_threshold: default_snap_threshold(),
         }
     }
 }
@@ -89,6 +104,13 @@ pub(crate) struct ViewState {
     pub(crate) preview_resolution: f32,
     #[serde(default)]
     pub(crate) active_tool: PreviewTool,
+    #[serde(default)]
+    pub(crate) show_action_safe: bool,
+    #[serde(default)]
+    pub(crate) show_title_safe: bool,
+    /// Safe area sizes from the app config, applied every frame.
+    #[serde(skip)]
+    pub(crate) safe_areas: PreviewConfig,
 }
 
 fn default_preview_resolution() -> f32 {
@@ -102,6 +124,9 @@ impl Default for ViewState {
             zoom: 0.3,
             preview_resolution: 1.0,
             active_tool: PreviewTool::default(),
+            show_action_safe: false,
+            show_title_safe: false,
+            safe_areas: PreviewConfig::default(),
         }
     }
 }
@@ -115,6 +140,13 @@ pub(crate) struct GraphEditorState {
     pub(crate) zoom_y: f32, // Pixels per unit value
     #[serde(default)]
     pub(crate) visible_properties: HashSet<String>,
+    /// Snap dragged keyframes to frames and to neighbouring keyframes.
+    #[serde(default = "default_snap_to_frames")]
+    pub(crate) snap_to_frames: bool,
+}
+
+fn default_snap_to_frames() -> bool {
+    true
 }
 
 impl Default for GraphEditorState {
@@ -124,6 +156,7 @@ impl Default for GraphEditorState {
             zoom_x: 100.0, // Default 100 pixels per second
             zoom_y: 1.0,   // Default 1 pixel per unit
             visible_properties: HashSet::new(),
+            snap_to_frames: true,
         }
     }
 }
@@ -136,6 +169,9 @@ pub(crate) struct SelectionState {
     pub(crate) selected_entities: HashSet<Uuid>,
     pub(crate) last_selected_entity_id: Option<Uuid>,
     pub(crate) last_selected_track_id: Option<Uuid>,
+    /// Assets selected in the asset panel, dragged together to the timeline.
+    #[serde(default)]
+    pub(crate) selected_assets: HashSet<Uuid>,
 }
 
 // --- Sub-states split by panel responsibility ---
@@ -144,6 +180,8 @@ pub(crate) struct SelectionState {
 #[derive(Default, Clone)]
 pub(crate) struct PreviewInteractionState {
     pub(crate) gizmo_state: Option<GizmoState>,
+    /// Gizmo operation requested by a shortcut, started on the next preview frame.
+    pub(crate) pending_gizmo_op: Option<(GizmoHandle, Option<GizmoAxis>)>,
     pub(crate) vector_editor_state: Option<VectorEditorState>,
     pub(crate) body_drag_state: Option<BodyDragState>,
     pub(crate) preview_selection_drag_start: Option<egui::Pos2>,
@@ -161,7 +199,13 @@ pub(crate) struct TimelineInteractionState {
     pub(crate) dragged_entity_original_track_id: Option<Uuid>,
     pub(crate) dragged_entity_hovered_track_id: Option<Uuid>,
     pub(crate) dragged_entity_has_moved: bool,
+    /// in_frame of the clip under the pointer when its drag started.
+    pub(crate) dragged_entity_start_frame: Option<u64>,
+    /// Whether the pointer was over the clip area last frame.
+    pub(crate) pointer_in_layer_area: bool,
     pub(crate) is_resizing_entity: bool,
+    /// Trimming a clip's right edge shifts the later clips on its track along with it.
+    pub(crate) ripple_edit: bool,
     pub(crate) timeline_selection_drag_start: Option<egui::Pos2>,
     pub(crate) current_time_text_input: String,
     pub(crate) is_editing_current_time: bool,
@@ -173,11 +217,40 @@ pub(crate) struct TimelineInteractionState {
 /// Graph editor interaction state (keyframe selection)
 #[derive(Default, Clone)]
 pub(crate) struct GraphEditorInteractionState {
-    pub(crate) selected_keyframe: Option<(String, usize)>,
+    /// Selected keyframes as (plotted property name, keyframe index).
+    pub(crate) selected_keyframes: HashSet<(String, usize)>,
+    pub(crate) box_select_start: Option<egui::Pos2>,
+    pub(crate) move_drag: Option<KeyframeMoveDrag>,
+    /// Plotted property row that pasted keyframes go into.
+    pub(crate) highlighted_property: Option<String>,
     #[allow(dead_code)]
     pub(crate) editing_keyframe: Option<(String, usize)>,
 }
 
+/// Keyframes being moved together, with graph-space (time, value) positions
+/// taken when the drag started so every keyframe moves by the same delta.
+/// Keyframe indices are remapped after each move so they follow the
+/// keyframes through re-sorting by time.
+#[derive(Debug, Clone)]
+pub(crate) struct KeyframeMoveDrag {
+    /// Keyframe under the pointer; snapping aligns this one.
+    pub(crate) anchor: (String, usize),
+    pub(crate) start: (f64, f64),
+    pub(crate) origins: Vec<((String, usize), (f64, f64))>,
+}
+
+/// A keyframe on the graph editor clipboard.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct KeyframeCopy {
+    /// Seconds after the earliest copied keyframe.
+    pub(crate) offset: f64,
+    pub(crate) value: PropertyValue,
+    /// Row the keyframe was copied from, selecting the component of a Vec2 value.
+    pub(crate) component: crate::panels::graph_editor::PropertyComponent,
+    pub(crate) ease_in: Option<EasingFunction>,
+    pub(crate) ease_out: Option<EasingFunction>,
+}
+
 /// General interaction state (dialogs, modals, import reports)
 #[derive(Default, Clone)]
 pub(crate) struct GeneralInteractionState {
@@ -186,6 +259,19 @@ pub(crate) struct GeneralInteractionState {
     pub(crate) import_report: Option<ImportReport>,
 }
 
+/// Pinned output of the node editor, rendered on the render server.
+#[derive(Default)]
+pub(crate) struct NodeOutputState {
+    /// (composition_id, node_id, pin_name) whose output is pinned.
+    pub(crate) pin: Option<(Uuid, Uuid, String)>,
+    /// Node output request awaited from the render server.
+    pub(crate) in_flight: Option<u64>,
+    /// The pinned image has not arrived yet; the awaited result becomes it.
+    pub(crate) awaiting_pin: bool,
+    /// Current output of the pinned node, drawn beside the frozen one.
+    pub(crate) live_preview: Option<egui::TextureHandle>,
+}
+
 /// Combined interaction state that holds all sub-states.
 /// Individual panels should access only their relevant sub-state.
 #[derive(Default, Clone)]
@@ -231,4 +317,6 @@ pub(crate) struct GizmoState {
     pub(crate) original_height: f32,
     /// The compositing.transform graph node ID (if any).
     pub(crate) transform_node_id: Option<uuid::Uuid>,
+    /// Axis lock for `Move` / `Scale` operations.
+    pub(crate) axis_constraint: Option<GizmoAxis>,
 }
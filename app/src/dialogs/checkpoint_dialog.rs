@@ -0,0 +1,87 @@
+use uuid::Uuid;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum CheckpointAction {
+    Create(String),
+    Restore(Uuid),
+}
+
+/// Names a new checkpoint and lists the existing ones for restoring.
+#[derive(Default)]
+pub struct CheckpointDialog {
+    pub is_open: bool,
+    name: String,
+    focus_name: bool,
+}
+
+impl CheckpointDialog {
+    /// Open with a default name for the next checkpoint and focus the name field.
+    pub fn open(&mut self, existing: usize) {
+        self.name = format!("Checkpoint {}", existing + 1);
+        self.focus_name = true;
+        self.is_open = true;
+    }
+
+    pub fn show(
+        &mut self,
+        ctx: &egui::Context,
+        checkpoints: &[(Uuid, String)],
+    ) -> Option<CheckpointAction> {
+        let mut action = None;
+        let mut is_open = self.is_open;
+        let mut close = false;
+
+        egui::Window::new("Checkpoints")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .open(&mut is_open)
+            .show(ctx, |ui| {
+                ui.label("Checkpoints are kept until the project is closed, even after older undo steps are dropped.");
+                ui.add_space(5.0);
+
+                ui.horizontal(|ui| {
+                    ui.label("Name:");
+                    let response = ui.text_edit_singleline(&mut self.name);
+                    if self.focus_name {
+                        response.request_focus();
+                        self.focus_name = false;
+                    }
+                    let submitted =
+                        response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                    let name = self.name.trim();
+                    if (ui
+                        .add_enabled(!name.is_empty(), egui::Button::new("Create"))
+                        .clicked()
+                        || submitted)
+                        && !name.is_empty()
+                    {
+                        action = Some(CheckpointAction::Create(name.to_string()));
+                        close = true;
+                    }
+                });
+
+                if !checkpoints.is_empty() {
+                    ui.separator();
+                    for (id, name) in checkpoints {
+                        ui.horizontal(|ui| {
+                            ui.label(name);
+                            if ui.button("Restore").clicked() {
+                                action = Some(CheckpointAction::Restore(*id));
+                                close = true;
+                            }
+                        });
+                    }
+                }
+
+                super::dialog_footer(ui, |ui| {
+                    if ui.button("Close").clicked() {
+                        close = true;
+                    }
+                });
+            });
+
+        self.is_open = is_open && !close;
+        action
+    }
+}
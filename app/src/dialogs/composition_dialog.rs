@@ -433,6 +433,9 @@ static FPS_PRESETS: Lazy<Vec<CompositionPreset>> = Lazy::new(|| {
     ]
 });
 
+/// Audio sample rates offered in the dialog (Hz).
+const AUDIO_SAMPLE_RATES: [u32; 3] = [44100, 48000, 96000];
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ActivePreset {
     Custom,
@@ -449,6 +452,7 @@ pub struct CompositionDialog {
     pub height: u64,
     pub fps: f64,
     pub duration: f64,
+    pub audio_sample_rate: u32,
     pub confirmed: bool,
     pub edit_mode: bool, // New flag
 
@@ -465,6 +469,7 @@ impl Default for CompositionDialog {
             height: 1080,
             fps: 29.97,
             duration: 60.0,
+            audio_sample_rate: library::project::composition::DEFAULT_AUDIO_SAMPLE_RATE,
             confirmed: false,
             edit_mode: false,
             active_preset: ActivePreset::Custom,
@@ -494,6 +499,7 @@ impl CompositionDialog {
         self.height = composition.height;
         self.fps = composition.fps;
         self.duration = composition.duration;
+        self.audio_sample_rate = composition.audio_sample_rate;
         self.confirmed = false;
         self.edit_mode = true;
         self.update_active_preset();
@@ -734,6 +740,21 @@ impl CompositionDialog {
                                     .suffix("s"),
                             );
                             ui.end_row();
+
+                            // Audio sample rate
+                            ui.label("Sample Rate:");
+                            egui::ComboBox::from_id_salt("audio_sample_rate_combo")
+                                .selected_text(format!("{} Hz", self.audio_sample_rate))
+                                .show_ui(ui, |ui| {
+                                    for rate in AUDIO_SAMPLE_RATES {
+                                        ui.selectable_value(
+                                            &mut self.audio_sample_rate,
+                                            rate,
+                                            format!("{} Hz", rate),
+                                        );
+                                    }
+                                });
+                            ui.end_row();
                         });
 
                     super::dialog_footer(ui, |ui| {
@@ -846,6 +867,7 @@ mod tests {
         assert_eq!(dialog.height, 2160);
         assert_eq!(dialog.fps, 60.0);
         assert_eq!(dialog.duration, 30.0);
+        assert_eq!(dialog.audio_sample_rate, comp.audio_sample_rate);
     }
 
     // ── Domain: Preset Detection ──
@@ -1,18 +1,19 @@
 use eframe::egui;
-use log::error;
+use log::{error, warn};
 use std::collections::HashMap;
-use std::io::Write;
 use std::sync::mpsc::{channel, Receiver};
 use std::sync::{Arc, RwLock};
 use std::thread;
 
 use library::cache::SharedCacheManager;
-use library::plugin::{ExportSettings, PluginManager};
+use library::plugin::{AudioSource, ExportSettings, PluginManager};
 use library::project::project::Project;
 use library::project::property::PropertyUiType;
 use library::project::property::PropertyValue;
 use library::rendering::skia_renderer::SkiaRenderer;
-use library::{EditorService, ExportService};
+use library::ExportService;
+
+use crate::config::HardwareAccel;
 
 pub struct ExportDialog {
     pub is_open: bool,
@@ -41,6 +42,9 @@ pub struct ExportDialog {
     pub override_width: Option<u32>,
     pub override_height: Option<u32>,
     pub override_fps: Option<f64>,
+
+    /// Hardware encoder preference from the app settings.
+    pub hardware_accel: HardwareAccel,
 }
 
 #[derive(PartialEq, Clone, Copy, Debug)]
@@ -71,6 +75,7 @@ impl ExportDialog {
             override_width: None,
             override_height: None,
             override_fps: None,
+            hardware_accel: HardwareAccel::default(),
         }
     }
 
@@ -78,11 +83,16 @@ impl ExportDialog {
         self.is_open = true;
     }
 
+    /// Open with the range preselected, e.g. for "Render Work Area".
+    pub fn open_with_range(&mut self, range: ExportRange) {
+        self.export_range = range;
+        self.open();
+    }
+
     pub fn show(
         &mut self,
         ctx: &egui::Context,
         project: &Arc<RwLock<Project>>,
-        project_service: &EditorService,
         active_composition_id: Option<uuid::Uuid>,
     ) {
         self.active_composition_id = active_composition_id;
@@ -135,7 +145,7 @@ impl ExportDialog {
                 if self.is_exporting {
                     self.show_export_progress(ui);
                 } else {
-                    if self.show_configuration(ui, project, project_service) {
+                    if self.show_configuration(ui, project) {
                         should_close = true;
                     }
                 }
@@ -167,12 +177,7 @@ impl ExportDialog {
         }
     }
 
-    fn show_configuration(
-        &mut self,
-        ui: &mut egui::Ui,
-        project: &Arc<RwLock<Project>>,
-        project_service: &EditorService,
-    ) -> bool {
+    fn show_configuration(&mut self, ui: &mut egui::Ui, project: &Arc<RwLock<Project>>) -> bool {
         let mut close_dialog = false;
         ui.heading("Export Settings");
 
@@ -270,10 +275,11 @@ impl ExportDialog {
             if let Some(comp_id) = self.active_composition_id {
                 if let Some(comp) = project_read.get_composition(comp_id) {
                     let (start, end) = match self.export_range {
-                        ExportRange::EntireComposition => {
-                            (0, (comp.duration * comp.fps).ceil() as u64)
+                        ExportRange::EntireComposition => (0, comp.total_frames()),
+                        ExportRange::WorkArea => {
+                            let range = comp.work_area_range();
+                            (range.start, range.end)
                         }
-                        ExportRange::WorkArea => (comp.work_area_in, comp.work_area_out),
                         _ => (0, 0),
                     };
                     ui.label(format!(
@@ -472,7 +478,7 @@ impl ExportDialog {
                 .add_enabled(enabled, egui::Button::new("Export"))
                 .clicked()
             {
-                self.start_export(project, project_service);
+                self.start_export(project);
             }
 
             if ui.button("Close").clicked() {
@@ -496,11 +502,7 @@ impl ExportDialog {
         Ok(())
     }
 
-    fn start_export(
-        &mut self,
-        project_lock: &Arc<RwLock<Project>>,
-        project_service: &EditorService,
-    ) {
+    fn start_export(&mut self, project_lock: &Arc<RwLock<Project>>) {
         let exporter_id = if let Some(id) = &self.selected_exporter_id {
             id.clone()
         } else {
@@ -533,9 +535,7 @@ impl ExportDialog {
         let override_width = self.override_width;
         let override_height = self.override_height;
         let override_fps = self.override_fps;
-
-        // Capture Audio Engine Sample Rate
-        let engine_sample_rate = project_service.get_audio_engine().get_sample_rate();
+        let hardware_accel = self.hardware_accel;
 
         // Find composition index
         let composition_for_export = match project_snapshot.get_composition(target_comp_id) {
@@ -587,24 +587,22 @@ impl ExportDialog {
             settings.parameters = json_params;
             settings.container = match property_values_owned.get("container") {
                 Some(library::project::property::PropertyValue::String(s)) => s.clone(),
-                _ => {
-                    if exporter_id_owned == "png_export" {
-                        "png".to_string()
-                    } else {
-                        "mp4".to_string()
-                    }
-                }
+                _ => match exporter_id_owned.as_str() {
+                    "png_export" => "png".to_string(),
+                    "webp_export" => "webp".to_string(),
+                    "gif_export" => "gif".to_string(),
+                    _ => "mp4".to_string(),
+                },
             };
 
             settings.codec = match property_values_owned.get("codec") {
                 Some(library::project::property::PropertyValue::String(s)) => s.clone(),
-                _ => {
-                    if exporter_id_owned == "png_export" {
-                        "png".to_string()
-                    } else {
-                        "libx264".to_string()
-                    }
-                }
+                _ => match exporter_id_owned.as_str() {
+                    "png_export" => "png".to_string(),
+                    "webp_export" => "webp".to_string(),
+                    "gif_export" => "gif".to_string(),
+                    _ => "libx264".to_string(),
+                },
             };
 
             settings.pixel_format = match property_values_owned.get("pixel_format") {
@@ -612,12 +610,18 @@ impl ExportDialog {
                 _ => "rgba".to_string(),
             };
 
+            if exporter_id_owned == "ffmpeg_export" {
+                let ffmpeg_binary = settings.ffmpeg_path.as_deref().unwrap_or("ffmpeg");
+                settings.hardware_accel = hardware_accel.resolve(ffmpeg_binary);
+            }
+
             // Range Calculation
             let (start_frame, end_frame_total) = match export_range {
-                ExportRange::EntireComposition => {
-                    (0, (composition.duration * composition.fps).ceil() as u64)
+                ExportRange::EntireComposition => (0, composition.total_frames()),
+                ExportRange::WorkArea => {
+                    let range = composition.work_area_range();
+                    (range.start, range.end)
                 }
-                ExportRange::WorkArea => (composition.work_area_in, composition.work_area_out),
                 ExportRange::Custom => (custom_start, custom_end),
             };
             let duration_frames = end_frame_total.saturating_sub(start_frame).max(1);
@@ -631,11 +635,11 @@ impl ExportDialog {
                 let fps = composition.fps;
                 let start_time = start_frame as f64 / fps;
                 let duration = duration_frames as f64 / fps;
-                let sample_rate = engine_sample_rate; // Use correct rate
+                let sample_rate = composition.audio_sample_rate;
                 let start_sample = (start_time * sample_rate as f64).round() as u64;
                 let frames = (duration * sample_rate as f64).round() as usize;
 
-                let audio_data = library::audio::mixer::mix_samples(
+                let audio = library::audio::mixer::mix_samples_to_stereo_float(
                     &project_snapshot.assets,
                     &project_snapshot,
                     composition,
@@ -643,10 +647,9 @@ impl ExportDialog {
                     start_sample,
                     frames,
                     sample_rate,
-                    2,
                 );
 
-                if !audio_data.is_empty() {
+                if !audio.is_empty() {
                     // Prepare path helpers
                     let mut stem_path = std::path::PathBuf::from(&output_path_owned);
                     if stem_path.extension().is_some() {
@@ -655,23 +658,12 @@ impl ExportDialog {
                     let stem_str = stem_path.to_str().unwrap_or("output");
 
                     let audio_path = format!("{}_audio.raw", stem_str);
-                    if let Ok(mut file) = std::fs::File::create(&audio_path) {
-                        for sample in audio_data {
-                            let _ = file.write_all(&sample.to_le_bytes());
-                        }
-
-                        settings.parameters.insert(
-                            "audio_source".to_string(),
-                            serde_json::Value::String(audio_path.clone()),
-                        );
-                        settings.parameters.insert(
-                            "audio_channels".to_string(),
-                            serde_json::Value::Number(serde_json::Number::from(2)),
-                        );
-                        settings.parameters.insert(
-                            "audio_sample_rate".to_string(),
-                            serde_json::Value::Number(serde_json::Number::from(sample_rate)),
-                        );
+                    if audio.write_f32le(&audio_path).is_ok() {
+                        settings.set_audio_source(&AudioSource {
+                            path: audio_path.clone(),
+                            sample_rate: audio.sample_rate,
+                            channels: audio.channels,
+                        });
                         audio_temp_path = Some(audio_path);
                     }
                 }
@@ -712,6 +704,18 @@ impl ExportDialog {
                 let end = (current_frame + chunk_size).min(end_frame_total);
                 let range = current_frame..end;
 
+                // Two decoders at most, to keep VRAM pressure down during export
+                if let Err(e) = export_service.warm_video_cache(
+                    &project_snapshot,
+                    composition,
+                    &cache_manager,
+                    range.clone(),
+                    2,
+                    &cancel_token,
+                ) {
+                    warn!("Video cache warm-up failed: {}", e);
+                }
+
                 if let Err(e) = export_service.render_range(
                     &project_snapshot,
                     composition,
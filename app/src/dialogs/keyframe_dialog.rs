@@ -1,5 +1,5 @@
 use eframe::egui::{self, Color32, ComboBox, DragValue, TextEdit};
-use library::animation::EasingFunction;
+use library::animation::{EasingFunction, StepPosition};
 use library::project::project::Project;
 use library::EditorService;
 use std::sync::{Arc, RwLock};
@@ -112,6 +112,10 @@ pub fn show_keyframe_dialog(
                         EasingFunction::EaseInBounce { .. } => "Ease In Bounce",
                         EasingFunction::EaseOutBounce { .. } => "Ease Out Bounce",
                         EasingFunction::EaseInOutBounce { .. } => "Ease In Out Bounce",
+                        // Spring
+                        EasingFunction::Spring { .. } => "Spring",
+                        // Step
+                        EasingFunction::Step { .. } => "Steps",
 
                         _ => "Custom",
                     };
@@ -208,6 +212,79 @@ pub fn show_keyframe_dialog(
                         }
                     });
                 }
+                EasingFunction::Spring {
+                    mass,
+                    stiffness,
+                    damping,
+                } => {
+                    if !mass.is_finite() || *mass <= 0.0 {
+                        *mass = 1.0;
+                    }
+                    if !stiffness.is_finite() {
+                        *stiffness = 100.0;
+                    }
+                    *stiffness = stiffness.max(EasingFunction::MIN_SPRING_STIFFNESS);
+                    if !damping.is_finite() {
+                        *damping = 10.0;
+                    }
+
+                    ui.separator();
+                    ui.horizontal(|ui| {
+                        for (label, value, range) in [
+                            ("Mass:", mass, 0.01..=100.0),
+                            (
+                                "Stiffness:",
+                                stiffness,
+                                EasingFunction::MIN_SPRING_STIFFNESS..=10000.0,
+                            ),
+                            ("Damping:", damping, 0.0..=1000.0),
+                        ] {
+                            ui.label(label);
+                            let res = ui.add(DragValue::new(value).speed(0.1).range(range));
+                            if res.changed() {
+                                should_update = true;
+                            }
+                            if res.drag_stopped() {
+                                should_push_history = true;
+                            }
+                            ui.add_space(10.0);
+                        }
+                    });
+                }
+                EasingFunction::Step { count, position } => {
+                    ui.separator();
+                    ui.horizontal(|ui| {
+                        ui.label("Steps:");
+                        let count_res = ui.add(DragValue::new(count).range(1..=1000));
+                        if count_res.changed() {
+                            should_update = true;
+                        }
+                        if count_res.drag_stopped() {
+                            should_push_history = true;
+                        }
+
+                        ui.add_space(10.0);
+                        ui.label("Jump:");
+                        ComboBox::from_id_salt("step_position_selector")
+                            .selected_text(format!("{:?}", position))
+                            .show_ui(ui, |ui| {
+                                for option in [
+                                    StepPosition::Start,
+                                    StepPosition::End,
+                                    StepPosition::Both,
+                                    StepPosition::None,
+                                ] {
+                                    if ui
+                                        .selectable_value(position, option, format!("{:?}", option))
+                                        .changed()
+                                    {
+                                        should_update = true;
+                                        should_push_history = true;
+                                    }
+                                }
+                            });
+                    });
+                }
                 EasingFunction::Expression { text } => {
                     ui.separator();
                     ui.label("Expression (Python):");
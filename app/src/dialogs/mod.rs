@@ -1,3 +1,4 @@
+pub mod checkpoint_dialog;
 pub mod composition_dialog;
 pub mod confirmation;
 pub mod export_dialog;
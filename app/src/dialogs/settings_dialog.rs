@@ -231,7 +231,7 @@ fn settings_panel(
                             state.active_tab = SettingsTab::Shortcuts;
                         }
 
-                        let is_plugin_tab = matches!(state.active_tab, SettingsTab::PluginPaths | SettingsTab::PluginList(_, _));
+                        let is_plugin_tab = matches!(state.active_tab, SettingsTab::PluginPaths | SettingsTab::PluginOverview | SettingsTab::PluginList(_, _));
 
                         egui::collapsing_header::CollapsingHeader::new("Plugins")
                             .default_open(is_plugin_tab)
@@ -240,6 +240,10 @@ fn settings_panel(
                                     state.active_tab = SettingsTab::PluginPaths;
                                 }
 
+                                if ui.selectable_label(matches!(state.active_tab, SettingsTab::PluginOverview), "All Plugins").clicked() {
+                                    state.active_tab = SettingsTab::PluginOverview;
+                                }
+
                                 use library::plugin::PluginCategory;
 
                                 // Loaders
@@ -272,6 +276,14 @@ fn settings_panel(
                         if ui.selectable_label(matches!(state.active_tab, SettingsTab::Theme), "Theme").clicked() {
                             state.active_tab = SettingsTab::Theme;
                         }
+
+                        if ui.selectable_label(matches!(state.active_tab, SettingsTab::Export), "Export").clicked() {
+                            state.active_tab = SettingsTab::Export;
+                        }
+
+                        if ui.selectable_label(matches!(state.active_tab, SettingsTab::Preview), "Preview").clicked() {
+                            state.active_tab = SettingsTab::Preview;
+                        }
                     });
                 });
             });
@@ -293,12 +305,21 @@ fn settings_panel(
                                 SettingsTab::PluginPaths => {
                                     plugins_paths_tab(ui, config);
                                 }
+                                SettingsTab::PluginOverview => {
+                                    plugins_overview_tab(ui, plugin_manager);
+                                }
                                 SettingsTab::PluginList(category, filter) => {
                                     plugins_list_tab(ui, plugin_manager, category.clone(), filter.clone());
                                 }
                                 SettingsTab::Theme => {
                                     theme_tab(ui, config);
                                 }
+                                SettingsTab::Export => {
+                                    export_tab(ui, config);
+                                }
+                                SettingsTab::Preview => {
+                                    preview_tab(ui, config);
+                                }
                             }
                         });
 
@@ -392,13 +413,13 @@ fn plugins_list_tab(
     }
     ui.add_space(10.0);
 
-    let all_plugins = plugin_manager.get_all_plugins();
+    let all_plugins = plugin_manager.list_plugins();
 
     // Filter logic
     let filtered_plugins: Vec<_> = all_plugins
         .iter()
         .filter(|p| {
-            if p.plugin_type != category {
+            if p.plugin_type != Some(category) {
                 return false;
             }
             if let Some(f) = &filter {
@@ -466,6 +487,83 @@ fn plugins_list_tab(
         });
 }
 
+/// Every plugin with where it was loaded from; failed loads are listed in red.
+fn plugins_overview_tab(ui: &mut Ui, plugin_manager: &PluginManager) {
+    use library::plugin::{PluginSource, PluginStatus};
+
+    let plugins = plugin_manager.list_plugins();
+    let failed = plugins
+        .iter()
+        .filter(|p| matches!(p.status, PluginStatus::LoadFailed(_)))
+        .count();
+
+    ui.heading("All Plugins");
+    ui.label(format!(
+        "{} plugins, {} failed to load",
+        plugins.len(),
+        failed
+    ));
+    ui.add_space(10.0);
+
+    let error_color = ui.visuals().error_fg_color;
+    TableBuilder::new(ui)
+        .striped(true)
+        .resizable(true)
+        .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+        .column(Column::auto().at_least(150.0)) // ID
+        .column(Column::auto().at_least(150.0)) // Name
+        .column(Column::auto().at_least(100.0)) // Type
+        .column(Column::auto().at_least(60.0)) // Version
+        .column(Column::auto().at_least(120.0)) // Source
+        .column(Column::remainder()) // Status
+        .header(20.0, |mut header| {
+            for title in ["ID", "Name", "Type", "Version", "Source", "Status"] {
+                header.col(|ui| {
+                    ui.strong(title);
+                });
+            }
+        })
+        .body(|mut body| {
+            for plugin in &plugins {
+                let (status, color) = match &plugin.status {
+                    PluginStatus::Active => ("Active".to_string(), None),
+                    PluginStatus::LoadFailed(error) => {
+                        (format!("Failed: {}", error), Some(error_color))
+                    }
+                };
+                let source = match &plugin.loaded_from {
+                    PluginSource::Builtin => "Built-in".to_string(),
+                    PluginSource::DynamicLibrary(path) | PluginSource::Sksl(path) => {
+                        path.display().to_string()
+                    }
+                };
+                let plugin_type = plugin
+                    .plugin_type
+                    .map(|t| format!("{:?}", t))
+                    .unwrap_or_else(|| "-".to_string());
+
+                body.row(18.0, |mut row| {
+                    for text in [
+                        &plugin.id,
+                        &plugin.name,
+                        &plugin_type,
+                        &plugin.version,
+                        &source,
+                        &status,
+                    ] {
+                        row.col(|ui| {
+                            let text = egui::RichText::new(text);
+                            ui.label(match color {
+                                Some(color) => text.color(color),
+                                None => text,
+                            });
+                        });
+                    }
+                });
+            }
+        });
+}
+
 /// Special tab for loader plugins with priority ordering.
 fn loader_priority_tab(ui: &mut Ui, plugin_manager: &PluginManager) {
     ui.heading("Loader Plugins (Priority Order)");
@@ -684,6 +782,62 @@ fn theme_tab(ui: &mut Ui, config: &mut AppConfig) {
         });
 }
 
+fn export_tab(ui: &mut Ui, config: &mut AppConfig) {
+    use crate::config::HardwareAccel;
+    use library::plugin::HwAccelBackend;
+
+    ui.heading("Export Settings");
+    ui.add_space(10.0);
+
+    let accel = &mut config.export.hardware_accel;
+    egui::ComboBox::from_label("Hardware Acceleration")
+        .selected_text(accel.label())
+        .show_ui(ui, |ui| {
+            let options = [HardwareAccel::Software, HardwareAccel::AutoDetect]
+                .into_iter()
+                .chain(HwAccelBackend::ALL.map(HardwareAccel::Backend));
+            for option in options {
+                ui.selectable_value(accel, option, option.label());
+            }
+        });
+    ui.label(
+        egui::RichText::new(
+            "Used by the FFmpeg exporter. Falls back to software encoding if the hardware encoder fails.",
+        )
+        .weak(),
+    );
+}
+
+fn preview_tab(ui: &mut Ui, config: &mut AppConfig) {
+    ui.heading("Preview Settings");
+    ui.add_space(10.0);
+
+    let preview = &mut config.preview;
+    egui::Grid::new("preview_settings_grid").show(ui, |ui| {
+        ui.label("Action Safe");
+        ui.add(
+            egui::DragValue::new(&mut preview.action_safe_percent)
+                .range(1.0..=100.0)
+                .suffix("%"),
+        );
+        ui.end_row();
+
+        ui.label("Title Safe");
+        ui.add(
+            egui::DragValue::new(&mut preview.title_safe_percent)
+                .range(1.0..=100.0)
+                .suffix("%"),
+        );
+        ui.end_row();
+    });
+    ui.label(
+        egui::RichText::new(
+            "Size of the safe area overlays relative to the frame. Many broadcasters use 90%/80%, some 85%/75%.",
+        )
+        .weak(),
+    );
+}
+
 fn get_shortcut_text(shortcut: &Option<(eframe::egui::Modifiers, Key)>) -> String {
     if let Some((m, k)) = shortcut {
         let mut parts = Vec::new();
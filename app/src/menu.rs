@@ -5,15 +5,31 @@ use egui_phosphor::regular as icons;
 use crate::command::{CommandId, CommandRegistry};
 use crate::types::Tab;
 
+/// History snapshot request raised from the Edit menu.
+pub(crate) enum SnapshotAction {
+    Take,
+    UndoTo(String),
+}
+
 pub fn menu_bar(
     ui: &mut egui::Ui,
     command_registry: &CommandRegistry,
     dock_state: &mut DockState<Tab>,
     triggered_action: &mut Option<CommandId>,
+    undo_description: Option<&str>,
+    snapshot_names: &[String],
+    snapshot_action: &mut Option<SnapshotAction>,
 ) {
     egui::MenuBar::new().ui(ui, |ui| {
         file_menu(ui, command_registry, triggered_action);
-        edit_menu(ui, command_registry, triggered_action);
+        edit_menu(
+            ui,
+            command_registry,
+            triggered_action,
+            undo_description,
+            snapshot_names,
+            snapshot_action,
+        );
         view_menu(ui, dock_state, command_registry, triggered_action);
     });
 }
@@ -30,6 +46,8 @@ fn file_menu(
             CommandId::Save,
             CommandId::SaveAs,
             CommandId::Export,
+            CommandId::RenderWorkArea,
+            CommandId::ExportOtio,
             CommandId::Quit,
         ] {
             if let Some(cmd) = command_registry.find(cmd_id) {
@@ -39,6 +57,8 @@ fn file_menu(
                     CommandId::Save => icons::FLOPPY_DISK,
                     CommandId::SaveAs => icons::FLOPPY_DISK_BACK,
                     CommandId::Export => icons::EXPORT,
+                    CommandId::RenderWorkArea => icons::BRACKETS_SQUARE,
+                    CommandId::ExportOtio => icons::FILM_STRIP,
                     CommandId::Quit => icons::SIGN_OUT,
                     _ => unreachable!(), // Should not happen
                 };
@@ -57,22 +77,52 @@ fn edit_menu(
     ui: &mut egui::Ui,
     command_registry: &CommandRegistry,
     triggered_action: &mut Option<CommandId>,
+    undo_description: Option<&str>,
+    snapshot_names: &[String],
+    snapshot_action: &mut Option<SnapshotAction>,
 ) {
     ui.menu_button("Edit", |ui| {
         for cmd_id in [
             CommandId::Undo,
             CommandId::Redo,
             CommandId::Delete,
+            CommandId::Duplicate,
+            CommandId::CreateCheckpoint,
             CommandId::Settings,
         ] {
             if let Some(cmd) = command_registry.find(cmd_id) {
                 let button = Button::new(&cmd.text).shortcut_text(cmd.shortcut_text.clone());
-                if ui.add(button).clicked() {
+                let mut response = ui.add(button);
+                if let (CommandId::Undo, Some(description)) = (cmd_id, undo_description) {
+                    response = response.on_hover_text(description);
+                }
+                if response.clicked() {
                     *triggered_action = Some(cmd.id);
                     ui.close();
                 }
             }
         }
+
+        ui.separator();
+
+        ui.menu_button("Snapshots", |ui| {
+            if ui.button("Take Snapshot").clicked() {
+                *snapshot_action = Some(SnapshotAction::Take);
+                ui.close();
+            }
+            if !snapshot_names.is_empty() {
+                ui.separator();
+            }
+            for name in snapshot_names {
+                ui.horizontal(|ui| {
+                    ui.label(name);
+                    if ui.button("Undo To This Snapshot").clicked() {
+                        *snapshot_action = Some(SnapshotAction::UndoTo(name.clone()));
+                        ui.close();
+                    }
+                });
+            }
+        });
     });
 }
 
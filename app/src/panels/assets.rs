@@ -4,6 +4,7 @@ use egui_phosphor::regular as icons;
 use library::project::asset::AssetKind;
 
 use crate::context::context::PanelContext;
+use crate::context::context_types::ImportReport;
 use crate::dialogs::composition_dialog::CompositionDialog;
 use crate::types::DraggedItem;
 
@@ -31,6 +32,9 @@ pub(crate) fn assets_panel(
                 composition_dialog.duration,
             )
             .expect("Failed to add composition");
+        project_service
+            .set_composition_audio_sample_rate(new_comp_id, composition_dialog.audio_sample_rate)
+            .expect("Failed to set composition sample rate");
         editor_context.selection.composition_id = Some(new_comp_id);
 
         // No need to add to assets list anymore, as Compositions are separate
@@ -54,6 +58,12 @@ pub(crate) fn assets_panel(
                 composition_dialog.duration,
             )
             .expect("Failed to update composition");
+        project_service
+            .set_composition_audio_sample_rate(
+                composition_dialog.comp_id.unwrap(),
+                composition_dialog.audio_sample_rate,
+            )
+            .expect("Failed to set composition sample rate");
 
         // No need to update assets list manually
 
@@ -64,8 +74,42 @@ pub(crate) fn assets_panel(
         *composition_dialog = CompositionDialog::new(); // Reset dialog state
     }
 
+    // Files dropped onto the window from the OS file manager
+    let dropped_paths: Vec<std::path::PathBuf> = ui.input(|i| {
+        i.raw
+            .dropped_files
+            .iter()
+            .filter_map(|file| file.path.clone())
+            .collect()
+    });
+    if !dropped_paths.is_empty() {
+        let plugin_manager = project_service.get_plugin_manager();
+        let mut imported_any = false;
+        let mut report = ImportReport::default();
+        for path in &dropped_paths {
+            // Reject formats no loader recognizes without opening the file
+            if !plugin_manager.can_load(&path.to_string_lossy()) {
+                report
+                    .errors
+                    .push((file_name(path), "Unsupported file type".to_string()));
+                continue;
+            }
+            imported_any |= import_path(project_service, path, &mut report);
+        }
+
+        if imported_any {
+            let current_state = project_service.with_project(|p| p.clone());
+            history_manager.push_project_state(current_state);
+            needs_refresh = true;
+        }
+        if !report.duplicates.is_empty() || !report.errors.is_empty() {
+            editor_context.interaction.general.import_report = Some(report);
+        }
+    }
+
     let mut comp_to_remove = None;
     let mut asset_to_remove = None;
+    let mut asset_to_reload = None;
 
     // Layout: Controls at the bottom, Content filling the rest
     ui.with_layout(egui::Layout::bottom_up(egui::Align::Min), |ui| {
@@ -86,39 +130,10 @@ pub(crate) fn assets_panel(
             {
                 if let Some(paths) = rfd::FileDialog::new().pick_files() {
                     let mut imported_any = false;
-                    let mut report = crate::context::context_types::ImportReport::default();
+                    let mut report = ImportReport::default();
 
                     for path in paths {
-                        let path_str = path.to_string_lossy().to_string();
-
-                        // 1. Check Duplicates
-                        if project_service.has_asset_with_path(&path_str) {
-                            report.duplicates.push(
-                                path.file_name()
-                                    .unwrap_or_default()
-                                    .to_string_lossy()
-                                    .to_string(),
-                            );
-                            continue;
-                        }
-
-                        // 2. Try Import
-                        match project_service.import_file(&path_str) {
-                            Ok(asset_ids) => {
-                                imported_any = true;
-                                report.successful_count += asset_ids.len();
-                            }
-                            Err(e) => {
-                                report.errors.push((
-                                    path.file_name()
-                                        .unwrap_or_default()
-                                        .to_string_lossy()
-                                        .to_string(),
-                                    e.to_string(),
-                                ));
-                                log::error!("Failed to import asset {}: {}", path_str, e)
-                            }
-                        }
+                        imported_any |= import_path(project_service, &path, &mut report);
                     }
 
                     if imported_any {
@@ -143,7 +158,7 @@ pub(crate) fn assets_panel(
             {
                 if let Some(folder_path) = rfd::FileDialog::new().pick_folder() {
                     let mut imported_any = false;
-                    let mut report = crate::context::context_types::ImportReport::default();
+                    let mut report = ImportReport::default();
 
                     // Recursive directory scanner helper
                     fn visit_dirs(
@@ -164,40 +179,16 @@ pub(crate) fn assets_panel(
                         Ok(())
                     }
 
+                    let plugin_manager = project_service.get_plugin_manager();
                     let _ = visit_dirs(&folder_path, &mut |path| {
                         let path_str = path.to_string_lossy().to_string();
 
-                        // 1. Check Duplicates
-                        if project_service.has_asset_with_path(&path_str) {
-                            report.duplicates.push(
-                                path.file_name()
-                                    .unwrap_or_default()
-                                    .to_string_lossy()
-                                    .to_string(),
-                            );
+                        // Skip files no loader recognizes instead of reporting them as errors
+                        if !plugin_manager.can_load(&path_str) {
                             return;
                         }
 
-                        // 2. Try Import
-                        match project_service.import_file(&path_str) {
-                            Ok(asset_ids) => {
-                                imported_any = true;
-                                report.successful_count += asset_ids.len();
-                            }
-                            Err(e) => {
-                                // Only log errors for supported file types to avoid spamming "unknown format" for random files
-                                // However, import_file currently returns error for unsupported types.
-                                // We can just format the error.
-                                // Filter out "unsupported" if desired, but user might want to know.
-                                report.errors.push((
-                                    path.file_name()
-                                        .unwrap_or_default()
-                                        .to_string_lossy()
-                                        .to_string(),
-                                    e.to_string(),
-                                ));
-                            }
-                        }
+                        imported_any |= import_path(project_service, path, &mut report);
                     });
 
                     if imported_any {
@@ -224,6 +215,26 @@ pub(crate) fn assets_panel(
             {
                 composition_dialog.open_for_new();
             }
+
+            ui.separator();
+
+            // Reload Media Button (all assets)
+            if ui
+                .add(egui::Button::new(
+                    egui::RichText::new(icons::ARROWS_CLOCKWISE).size(18.0),
+                ))
+                .on_hover_text("Reload Media")
+                .clicked()
+            {
+                let asset_ids: Vec<uuid::Uuid> =
+                    project_service.with_project(|p| p.assets.iter().map(|a| a.id).collect());
+                if !asset_ids.is_empty() {
+                    if let Err(e) = project_service.reload_asset_media(&asset_ids) {
+                        log::error!("Failed to reload media: {}", e);
+                    }
+                    needs_refresh = true;
+                }
+            }
         });
 
         ui.separator();
@@ -405,6 +416,9 @@ pub(crate) fn assets_panel(
                                             let _is_dragged =
                                                 match editor_context.interaction.timeline.dragged_item {
                                                     Some(DraggedItem::Asset(id)) => id == asset.id,
+                                                    Some(DraggedItem::Assets(ref ids)) => {
+                                                        ids.contains(&asset.id)
+                                                    }
                                                     _ => false,
                                                 };
 
@@ -412,23 +426,52 @@ pub(crate) fn assets_panel(
                                             // We want it to be selectable? Maybe not strictly "selected" as current selection model is Comp/Track/Entity.
                                             // But we need context menu and drag.
 
+                                            let is_selected = editor_context
+                                                .selection
+                                                .selected_assets
+                                                .contains(&asset.id);
+                                            let mut name = egui::RichText::new(&asset.name);
+                                            if is_selected {
+                                                name = name.background_color(
+                                                    ui.visuals().selection.bg_fill,
+                                                );
+                                            }
                                             let response =
-                                                ui.add(egui::Label::new(&asset.name).sense(
+                                                ui.add(egui::Label::new(name).sense(
                                                     egui::Sense::click().union(egui::Sense::drag()),
                                                 ));
 
+                                            // Ctrl/Cmd+click toggles, a plain click selects only this asset
+                                            if response.clicked() {
+                                                let selected =
+                                                    &mut editor_context.selection.selected_assets;
+                                                if ui.input(|i| i.modifiers.command) {
+                                                    if !selected.remove(&asset.id) {
+                                                        selected.insert(asset.id);
+                                                    }
+                                                } else {
+                                                    selected.clear();
+                                                    selected.insert(asset.id);
+                                                }
+                                            }
+
                                             // Context Menu
                                             response.context_menu(|ui| {
                                                 use crate::widgets::context_menu::{ContextMenuBuilder, show_context_menu};
 
                                                 #[derive(Clone)]
-                                                enum AssetAction { Delete }
+                                                enum AssetAction { Reload, Delete }
 
                                                 let menu = ContextMenuBuilder::new()
+                                                    .action_with_icon(icons::ARROWS_CLOCKWISE, "Reload Media", AssetAction::Reload)
+                                                    .separator()
                                                     .danger_action(icons::TRASH, "Delete Asset", AssetAction::Delete)
                                                     .build();
                                                 if let Some(action) = show_context_menu(ui, &menu) {
                                                     match action {
+                                                        AssetAction::Reload => {
+                                                            asset_to_reload = Some(asset.id);
+                                                        }
                                                         AssetAction::Delete => {
                                                             if project_service.is_asset_used(asset.id) {
                                                                 let mut dialog = crate::dialogs::confirmation::ConfirmationDialog::new();
@@ -448,8 +491,23 @@ pub(crate) fn assets_panel(
 
                                             // Drag
                                             if response.drag_started() {
+                                                // Dragging a selected asset takes the whole selection along
+                                                let selected =
+                                                    &editor_context.selection.selected_assets;
+                                                let item = if is_selected && selected.len() > 1 {
+                                                    DraggedItem::Assets(
+                                                        proj_read
+                                                            .assets
+                                                            .iter()
+                                                            .map(|a| a.id)
+                                                            .filter(|id| selected.contains(id))
+                                                            .collect(),
+                                                    )
+                                                } else {
+                                                    DraggedItem::Asset(asset.id)
+                                                };
                                                 editor_context.interaction.timeline.dragged_item =
-                                                    Some(DraggedItem::Asset(asset.id));
+                                                    Some(item);
                                             }
 
                                             response
@@ -497,6 +555,7 @@ pub(crate) fn assets_panel(
         project_service
             .remove_composition_fully(comp_id)
             .expect("Failed to remove composition");
+        editor_context.node_editor_states.remove(&comp_id);
 
         let current_state = project_service.with_project(|p| p.clone());
         history_manager.push_project_state(current_state);
@@ -513,6 +572,13 @@ pub(crate) fn assets_panel(
         needs_refresh = true;
     }
 
+    if let Some(asset_id) = asset_to_reload {
+        if let Err(e) = project_service.reload_asset_media(&[asset_id]) {
+            log::error!("Failed to reload media: {}", e);
+        }
+        needs_refresh = true;
+    }
+
     // Old modals removed.
 
     // Import Report Modal
@@ -571,3 +637,37 @@ pub(crate) fn assets_panel(
         ui.ctx().request_repaint();
     }
 }
+
+fn file_name(path: &std::path::Path) -> String {
+    path.file_name()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .to_string()
+}
+
+/// Import one file, recording a duplicate, the number of new assets or the
+/// error in `report`. Returns true if any asset was added.
+fn import_path(
+    project_service: &library::EditorService,
+    path: &std::path::Path,
+    report: &mut ImportReport,
+) -> bool {
+    let path_str = path.to_string_lossy().to_string();
+
+    if project_service.has_asset_with_path(&path_str) {
+        report.duplicates.push(file_name(path));
+        return false;
+    }
+
+    match project_service.import_file(&path_str) {
+        Ok(asset_ids) => {
+            report.successful_count += asset_ids.len();
+            !asset_ids.is_empty()
+        }
+        Err(e) => {
+            log::error!("Failed to import asset {}: {}", path_str, e);
+            report.errors.push((file_name(path), e.to_string()));
+            false
+        }
+    }
+}
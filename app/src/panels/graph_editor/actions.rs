@@ -3,7 +3,7 @@ use crate::command::history::HistoryManager;
 use crate::context::context::EditorContext;
 use library::animation::EasingFunction;
 use library::project::project::Project;
-use library::project::property::PropertyValue;
+use library::project::property::{EasingHandle, PropertyValue};
 use library::EditorService;
 use ordered_float::OrderedFloat;
 use std::sync::{Arc, RwLock};
@@ -12,11 +12,12 @@ use uuid::Uuid;
 #[derive(Clone, Debug)]
 pub(super) enum Action {
     Select(String, usize),
-    Move(String, usize, f64, f64, Option<PropertyComponent>), // prop_key, index, new_time, new_value, component
-    Add(String, f64, f64, Option<PropertyComponent>),         // prop_key, time, value, component
-    SetEasing(String, usize, EasingFunction),
+    MoveSelected(Vec<(String, usize, f64, f64)>), // (prop_key, index, new_time, new_value) per keyframe
+    Add(String, f64, f64, Option<PropertyComponent>), // prop_key, time, value, component
+    SetEasing(String, usize, EasingHandle, Option<EasingFunction>), // prop_key, index, side, easing (None = linear)
     Remove(String, usize),
     EditKeyframe(String, usize),
+    Paste, // clipboard keyframes into the highlighted row at the playhead
     None,
 }
 
@@ -61,6 +62,118 @@ fn global_to_source_time(
     global_time
 }
 
+/// Split a plotted row name into its property key and Vec2 component.
+fn split_component(name: &str) -> (&str, Option<PropertyComponent>) {
+    if let Some(base) = name.strip_suffix(".x") {
+        (base, Some(PropertyComponent::X))
+    } else if let Some(base) = name.strip_suffix(".y") {
+        (base, Some(PropertyComponent::Y))
+    } else {
+        (name, None)
+    }
+}
+
+/// Move a group of keyframes to new (global time, value) positions.
+///
+/// Each property is updated in one call so the indices, which refer to the order
+/// before the move, are applied together rather than against a list re-sorted
+/// by an earlier move. Returns the new index of every keyframe per property.
+fn move_keyframes(
+    moves: Vec<(String, usize, f64, f64)>,
+    comp_id: Uuid,
+    entity_id: Uuid,
+    project_service: &EditorService,
+    project: &Arc<RwLock<Project>>,
+) -> Vec<(String, Vec<usize>)> {
+    // X and Y rows of a Vec2 property share keyframes; group moves by property
+    let mut grouped: Vec<(String, Vec<(usize, f64, f64, Option<PropertyComponent>)>)> = Vec::new();
+    for (name, idx, new_time, new_val) in moves {
+        let (base_name, component) = split_component(&name);
+        let entry = match grouped.iter().position(|(b, _)| b == base_name) {
+            Some(i) => &mut grouped[i].1,
+            None => {
+                grouped.push((base_name.to_string(), Vec::new()));
+                &mut grouped.last_mut().unwrap().1
+            }
+        };
+        entry.push((idx, new_time, new_val, component));
+    }
+
+    let mut remaps = Vec::new();
+    for (base_name, group) in grouped {
+        let (target, prop_key) = if let Some((eff_idx, prop_key)) = parse_key(&base_name) {
+            (
+                library::project::property::PropertyTarget::Effect(eff_idx),
+                prop_key,
+            )
+        } else if let Some((style_idx, prop_key)) = parse_style_key(&base_name) {
+            (
+                library::project::property::PropertyTarget::Style(style_idx),
+                prop_key,
+            )
+        } else {
+            (
+                library::project::property::PropertyTarget::Clip,
+                base_name.clone(),
+            )
+        };
+
+        // TODO: Look up current Vec2 values from graph node properties for effects/styles
+        let mut current = Vec::new();
+        if target == library::project::property::PropertyTarget::Clip {
+            if let Ok(proj) = project.read() {
+                if let Some(prop) = proj
+                    .get_source(entity_id)
+                    .and_then(|clip| clip.properties.get(&base_name))
+                {
+                    current = prop.keyframes();
+                }
+            }
+        }
+
+        let mut updates: Vec<(usize, Option<f64>, Option<PropertyValue>)> = Vec::new();
+        for (idx, new_time, new_val, component) in group {
+            let source_time = if let Ok(proj) = project.read() {
+                global_to_source_time(&proj, comp_id, entity_id, new_time)
+            } else {
+                new_time
+            };
+            let pos = match updates.iter().position(|(i, _, _)| *i == idx) {
+                Some(pos) => pos,
+                None => {
+                    let value = current.get(idx).map(|kf| kf.value.clone());
+                    updates.push((idx, None, value));
+                    updates.len() - 1
+                }
+            };
+            let update = &mut updates[pos];
+            update.1 = Some(source_time);
+            update.2 = Some(match (update.2.take(), component) {
+                (Some(PropertyValue::Vec2(old_vec)), Some(PropertyComponent::X)) => {
+                    PropertyValue::Vec2(library::project::property::Vec2 {
+                        x: OrderedFloat(new_val),
+                        y: old_vec.y,
+                    })
+                }
+                (Some(PropertyValue::Vec2(old_vec)), Some(PropertyComponent::Y)) => {
+                    PropertyValue::Vec2(library::project::property::Vec2 {
+                        x: old_vec.x,
+                        y: OrderedFloat(new_val),
+                    })
+                }
+                _ => PropertyValue::Number(OrderedFloat(new_val)),
+            });
+        }
+
+        if let Ok(remap) =
+            project_service.update_target_keyframes_by_index(entity_id, target, &prop_key, &updates)
+        {
+            remaps.push((base_name, remap));
+        }
+    }
+    remaps
+}
+
 pub(super) fn process_action(
     action: Action,
     comp_id: Uuid,
@@ -73,105 +186,39 @@ pub(super) fn process_action(
 ) {
     match action {
         Action::Select(name, idx) => {
-            editor_context.interaction.graph_editor.selected_keyframe = Some((name, idx));
+            let state = &mut editor_context.interaction.graph_editor;
+            state.selected_keyframes.clear();
+            state.selected_keyframes.insert((name.clone(), idx));
+            state.highlighted_property = Some(name);
         }
 
-        Action::Move(name, idx, new_time, new_val, component) => {
-            let base_name = if let Some(c) = component {
-                match c {
-                    PropertyComponent::X => name.trim_end_matches(".x"),
-                    PropertyComponent::Y => name.trim_end_matches(".y"),
-                    _ => name.as_str(),
-                }
-            } else {
-                name.as_str()
-            };
+        Action::MoveSelected(moves) => {
+            let remaps = move_keyframes(moves, comp_id, entity_id, project_service, project);
 
-            if let Some((eff_idx, prop_key)) = parse_key(base_name) {
-                // TODO: Look up current Vec2 value from graph node properties for effect
-                let new_pv = PropertyValue::Number(OrderedFloat(new_val));
-                let source_time = if let Ok(proj) = project.read() {
-                    global_to_source_time(&proj, comp_id, entity_id, new_time)
-                } else {
-                    new_time
-                };
-                let _ = project_service.update_target_keyframe_by_index(
-                    entity_id,
-                    library::project::property::PropertyTarget::Effect(eff_idx),
-                    &prop_key,
-                    idx,
-                    Some(source_time),
-                    Some(new_pv),
-                    None,
-                );
-            } else if let Some((style_idx, prop_key)) = parse_style_key(base_name) {
-                // TODO: Look up current Vec2 value from graph node properties for style
-                let new_pv = PropertyValue::Number(OrderedFloat(new_val));
-                let source_time = if let Ok(proj) = project.read() {
-                    global_to_source_time(&proj, comp_id, entity_id, new_time)
-                } else {
-                    new_time
-                };
-                let _ = project_service.update_target_keyframe_by_index(
-                    entity_id,
-                    library::project::property::PropertyTarget::Style(style_idx),
-                    &prop_key,
-                    idx,
-                    Some(source_time),
-                    Some(new_pv),
-                    None,
-                );
-            } else {
-                // Clip property - use flat lookup
-                let mut current_pv = None;
-                if let Ok(proj) = project.read() {
-                    if let Some(clip) = proj.get_source(entity_id) {
-                        if let Some(prop) = clip.properties.get(base_name) {
-                            let keyframes = prop.keyframes();
-                            let mut sorted_kf = keyframes.clone();
-                            sorted_kf.sort_by(|a, b| a.time.cmp(&b.time));
-                            if let Some(kf) = sorted_kf.get(idx) {
-                                current_pv = Some(kf.value.clone());
-                            }
-                        }
+            // Keep the selection and the in-flight drag pointing at the same
+            // keyframes now that they have been re-sorted
+            let state = &mut editor_context.interaction.graph_editor;
+            let remap_key = |key: &mut (String, usize)| {
+                let base_name = split_component(&key.0).0;
+                if let Some((_, remap)) = remaps.iter().find(|(b, _)| b == base_name) {
+                    if let Some(new_idx) = remap.get(key.1) {
+                        key.1 = *new_idx;
                     }
                 }
-
-                let new_pv = if let Some(PropertyValue::Vec2(old_vec)) = current_pv {
-                    match component {
-                        Some(PropertyComponent::X) => {
-                            PropertyValue::Vec2(library::project::property::Vec2 {
-                                x: OrderedFloat(new_val),
-                                y: old_vec.y,
-                            })
-                        }
-                        Some(PropertyComponent::Y) => {
-                            PropertyValue::Vec2(library::project::property::Vec2 {
-                                x: old_vec.x,
-                                y: OrderedFloat(new_val),
-                            })
-                        }
-                        _ => PropertyValue::Number(OrderedFloat(new_val)),
-                    }
-                } else {
-                    PropertyValue::Number(OrderedFloat(new_val))
-                };
-
-                let source_time = if let Ok(proj) = project.read() {
-                    global_to_source_time(&proj, comp_id, entity_id, new_time)
-                } else {
-                    new_time
-                };
-
-                let _ = project_service.update_target_keyframe_by_index(
-                    entity_id,
-                    library::project::property::PropertyTarget::Clip,
-                    base_name,
-                    idx,
-                    Some(source_time),
-                    Some(new_pv),
-                    None,
-                );
+            };
+            state.selected_keyframes = state
+                .selected_keyframes
+                .drain()
+                .map(|mut key| {
+                    remap_key(&mut key);
+                    key
+                })
+                .collect();
+            if let Some(drag) = &mut state.move_drag {
+                remap_key(&mut drag.anchor);
+                for (key, _) in &mut drag.origins {
+                    remap_key(key);
+                }
             }
             if let Ok(proj_read) = project.read() {
                 history_manager.push_project_state(proj_read.clone());
@@ -267,7 +314,7 @@ pub(super) fn process_action(
                 history_manager.push_project_state(proj_read.clone());
             }
         }
-        Action::SetEasing(name, idx, easing) => {
+        Action::SetEasing(name, idx, handle, easing) => {
             let (base_name, _) = if name.ends_with(".x") {
                 (name.trim_end_matches(".x"), Some(PropertyComponent::X))
             } else if name.ends_with(".y") {
@@ -277,34 +324,31 @@ pub(super) fn process_action(
             };
 
             if let Some((eff_idx, prop_key)) = parse_key(base_name) {
-                let _ = project_service.update_target_keyframe_by_index(
+                let _ = project_service.set_target_keyframe_easing(
                     entity_id,
                     library::project::property::PropertyTarget::Effect(eff_idx),
                     &prop_key,
                     idx,
-                    None,
-                    None,
-                    Some(easing),
+                    handle,
+                    easing,
                 );
             } else if let Some((style_idx, prop_key)) = parse_style_key(base_name) {
-                let _ = project_service.update_target_keyframe_by_index(
+                let _ = project_service.set_target_keyframe_easing(
                     entity_id,
                     library::project::property::PropertyTarget::Style(style_idx),
                     &prop_key,
                     idx,
-                    None,
-                    None,
-                    Some(easing),
+                    handle,
+                    easing,
                 );
             } else {
-                let _ = project_service.update_target_keyframe_by_index(
+                let _ = project_service.set_target_keyframe_easing(
                     entity_id,
                     library::project::property::PropertyTarget::Clip,
                     base_name,
                     idx,
-                    None,
-                    None,
-                    Some(easing),
+                    handle,
+                    easing,
                 );
             }
             if let Ok(proj_read) = project.read() {
@@ -389,13 +433,142 @@ pub(super) fn process_action(
                                             .map_or(0.0, |v| v.y.into_inner()),
                                         _ => kf.value.get_as::<f64>().unwrap_or(0.0),
                                     };
-                                editor_context.keyframe_dialog.easing = kf.easing.clone();
+                                editor_context.keyframe_dialog.easing =
+                                    kf.ease_out.clone().unwrap_or_default();
                             }
                         }
                     }
                 }
             }
         }
+        Action::Paste => {
+            if let Err(message) =
+                paste_keyframes(comp_id, entity_id, project_service, project, editor_context)
+            {
+                editor_context.interaction.general.active_modal_error = Some(message);
+            } else if let Ok(proj_read) = project.read() {
+                history_manager.push_project_state(proj_read.clone());
+            }
+        }
         Action::None => {}
     }
 }
+
+/// Insert the clipboard keyframes into the highlighted row, starting at the playhead.
+///
+/// Every value is checked before anything is inserted, so an incompatible paste
+/// leaves the property untouched.
+fn paste_keyframes(
+    comp_id: Uuid,
+    entity_id: Uuid,
+    project_service: &EditorService,
+    project: &Arc<RwLock<Project>>,
+    editor_context: &mut EditorContext,
+) -> Result<(), String> {
+    if editor_context.keyframe_clipboard.is_empty() {
+        return Ok(());
+    }
+    let Some(name) = editor_context
+        .interaction
+        .graph_editor
+        .highlighted_property
+        .clone()
+    else {
+        return Err("Select a property in the graph editor list to paste into.".to_string());
+    };
+    let (base_name, component) = if name.ends_with(".x") {
+        (name.trim_end_matches(".x"), PropertyComponent::X)
+    } else if name.ends_with(".y") {
+        (name.trim_end_matches(".y"), PropertyComponent::Y)
+    } else {
+        (name.as_str(), PropertyComponent::Scalar)
+    };
+    if parse_key(base_name).is_some() || parse_style_key(base_name).is_some() {
+        return Err(
+            "Pasting keyframes into effect and style properties is not supported yet.".to_string(),
+        );
+    }
+
+    let start = editor_context.timeline.current_time as f64;
+    let mut pastes = Vec::new();
+    {
+        let proj = project
+            .read()
+            .map_err(|_| "Project is locked".to_string())?;
+        let comp = proj
+            .get_composition(comp_id)
+            .ok_or_else(|| "Composition not found".to_string())?;
+        let entity = proj
+            .get_source(entity_id)
+            .ok_or_else(|| "Clip not found".to_string())?;
+        let prop = entity
+            .properties
+            .get(base_name)
+            .ok_or_else(|| format!("Property '{}' not found", base_name))?;
+        for copy in &editor_context.keyframe_clipboard {
+            let source_time = global_to_source_time(&proj, comp_id, entity_id, start + copy.offset);
+            let current = project_service.evaluate_property_value(
+                prop,
+                &entity.properties,
+                source_time,
+                comp.fps,
+            );
+            let value = super::clipboard::paste_value(copy, component, &current)?;
+            pastes.push((
+                source_time,
+                value,
+                copy.ease_in.clone(),
+                copy.ease_out.clone(),
+            ));
+        }
+    }
+
+    let target = library::project::property::PropertyTarget::Clip;
+    for (source_time, value, ease_in, ease_out) in &pastes {
+        project_service
+            .add_target_keyframe(
+                entity_id,
+                target,
+                base_name,
+                *source_time,
+                value.clone(),
+                None,
+            )
+            .map_err(|e| e.to_string())?;
+        let index = project.read().ok().and_then(|proj| {
+            proj.get_source(entity_id)?
+                .properties
+                .get(base_name)?
+                .keyframe_index_at(*source_time, 0.001)
+        });
+        if let Some(index) = index {
+            for (handle, easing) in [(EasingHandle::In, ease_in), (EasingHandle::Out, ease_out)] {
+                let _ = project_service.set_target_keyframe_easing(
+                    entity_id,
+                    target,
+                    base_name,
+                    index,
+                    handle,
+                    easing.clone(),
+                );
+            }
+        }
+    }
+
+    // Select what was pasted; indices are final once every keyframe is in
+    if let Ok(proj) = project.read() {
+        if let Some(prop) = proj
+            .get_source(entity_id)
+            .and_then(|e| e.properties.get(base_name))
+        {
+            let selected = &mut editor_context.interaction.graph_editor.selected_keyframes;
+            selected.clear();
+            for (source_time, _, _, _) in &pastes {
+                if let Some(index) = prop.keyframe_index_at(*source_time, 0.001) {
+                    selected.insert((name.clone(), index));
+                }
+            }
+        }
+    }
+    Ok(())
+}
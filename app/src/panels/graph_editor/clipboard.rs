@@ -0,0 +1,128 @@
+use library::project::property::{Keyframe, PropertyValue, Vec2};
+
+use super::utils::PropertyComponent;
+use crate::context::context_types::KeyframeCopy;
+
+/// Build clipboard entries from `(global time, keyframe, row component)`,
+/// with offsets measured from the earliest keyframe.
+pub(super) fn copy_keyframes(keys: &[(f64, &Keyframe, PropertyComponent)]) -> Vec<KeyframeCopy> {
+    let earliest = keys
+        .iter()
+        .map(|(t, _, _)| *t)
+        .fold(f64::INFINITY, f64::min);
+    let mut copies: Vec<KeyframeCopy> = keys
+        .iter()
+        .map(|(t, kf, component)| KeyframeCopy {
+            offset: t - earliest,
+            value: kf.value.clone(),
+            component: *component,
+            ease_in: kf.ease_in.clone(),
+            ease_out: kf.ease_out.clone(),
+        })
+        .collect();
+    copies.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+    copies
+}
+
+fn type_name(value: &PropertyValue) -> &'static str {
+    match value {
+        PropertyValue::Number(_) => "Number",
+        PropertyValue::Vec2(_) => "Vec2",
+        _ => "non-numeric",
+    }
+}
+
+/// Value to store when pasting `copy` into a row showing `component` of a
+/// property whose value at the paste time is `current`.
+///
+/// Numbers paste into Number properties as is. Between Vec2 properties the
+/// copied row's component is written into the target row's component, so an
+/// X curve can be reused on Y while the other axis keeps its value.
+pub(super) fn paste_value(
+    copy: &KeyframeCopy,
+    component: PropertyComponent,
+    current: &PropertyValue,
+) -> Result<PropertyValue, String> {
+    match (&copy.value, current) {
+        (PropertyValue::Number(n), PropertyValue::Number(_)) => Ok(PropertyValue::Number(*n)),
+        (PropertyValue::Vec2(src), PropertyValue::Vec2(dst)) => {
+            let v = match copy.component {
+                PropertyComponent::Y => src.y,
+                _ => src.x,
+            };
+            Ok(PropertyValue::Vec2(match component {
+                PropertyComponent::Y => Vec2 { x: dst.x, y: v },
+                _ => Vec2 { x: v, y: dst.y },
+            }))
+        }
+        (from, to) => Err(format!(
+            "Cannot paste {} keyframes into a {} property.",
+            type_name(from),
+            type_name(to)
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use library::animation::EasingFunction;
+    use ordered_float::OrderedFloat;
+
+    fn kf(time: f64, value: PropertyValue) -> Keyframe {
+        Keyframe {
+            time: OrderedFloat(time),
+            value,
+            ease_in: None,
+            ease_out: Some(EasingFunction::EaseOutQuad),
+        }
+    }
+
+    fn number(v: f64) -> PropertyValue {
+        PropertyValue::Number(OrderedFloat(v))
+    }
+
+    fn vec2(x: f64, y: f64) -> PropertyValue {
+        PropertyValue::Vec2(Vec2 {
+            x: OrderedFloat(x),
+            y: OrderedFloat(y),
+        })
+    }
+
+    // ── Domain: Copy ──
+
+    #[test]
+    fn copy_offsets_from_earliest_keyframe() {
+        let a = kf(0.0, number(1.0));
+        let b = kf(0.0, number(2.0));
+        let copies = copy_keyframes(&[
+            (3.5, &b, PropertyComponent::Scalar),
+            (2.0, &a, PropertyComponent::Scalar),
+        ]);
+        let offsets: Vec<f64> = copies.iter().map(|c| c.offset).collect();
+        assert_eq!(offsets, vec![0.0, 1.5]);
+        assert_eq!(copies[0].value, number(1.0));
+        assert_eq!(copies[0].ease_out, Some(EasingFunction::EaseOutQuad));
+    }
+
+    // ── Domain: Paste ──
+
+    #[test]
+    fn pastes_x_curve_onto_y_keeping_x() {
+        let source = kf(0.0, vec2(10.0, 20.0));
+        let copy = &copy_keyframes(&[(0.0, &source, PropertyComponent::X)])[0];
+        let pasted = paste_value(copy, PropertyComponent::Y, &vec2(1.0, 2.0)).unwrap();
+        assert_eq!(pasted, vec2(1.0, 10.0));
+    }
+
+    #[test]
+    fn rejects_number_into_vec2() {
+        let source = kf(0.0, number(5.0));
+        let copy = &copy_keyframes(&[(0.0, &source, PropertyComponent::Scalar)])[0];
+        assert!(paste_value(copy, PropertyComponent::X, &vec2(0.0, 0.0)).is_err());
+        assert_eq!(
+            paste_value(copy, PropertyComponent::Scalar, &number(0.0)),
+            Ok(number(5.0))
+        );
+    }
+}
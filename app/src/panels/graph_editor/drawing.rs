@@ -1,10 +1,14 @@
 use crate::context::context::EditorContext;
+use crate::context::context_types::KeyframeMoveDrag;
 use egui::{Color32, Painter, Pos2, Rect, Response, Sense, Stroke, Ui, UiKind, Vec2};
-use library::project::property::{Property, PropertyMap, PropertyValue};
+use library::project::property::{EasingHandle, Property, PropertyMap, PropertyValue};
 use library::EditorService;
 
 use super::actions::Action;
-use super::utils::{GraphTransform, PropertyComponent, TimeMapper};
+use super::utils::{snap_time, GraphTransform, PropertyComponent, TimeMapper};
+
+/// Keyframes within this many pixels of a dragged one attract it.
+const SNAP_DISTANCE_PX: f32 = 8.0;
 
 pub(super) fn draw_background(
     painter: &Painter,
@@ -191,6 +195,11 @@ pub(super) fn draw_properties(
     let graph_rect = transform.graph_rect;
     let pixels_per_second = transform.zoom_x;
 
+    // Every plotted keyframe: (name, index), graph-space (time, value), screen position
+    let mut keyframe_points: Vec<((String, usize), (f64, f64), Pos2)> = Vec::new();
+    let mut drag_started_on = None;
+    let mut is_dragging_keyframe = false;
+
     let mut color_cycle = [
         Color32::RED,
         Color32::GREEN,
@@ -359,6 +368,8 @@ pub(super) fn draw_properties(
                         let val = val_f64.unwrap_or(0.0);
                         let global_t = time_mapper.to_global_time(t);
                         let kf_pos = transform.to_screen(global_t, val);
+                        let key = (name.clone(), i);
+                        keyframe_points.push((key.clone(), (global_t, val), kf_pos));
 
                         // Skip if out of view (optimization)
                         if !graph_rect.expand(10.0).contains(kf_pos) {
@@ -374,9 +385,8 @@ pub(super) fn draw_properties(
                         let is_selected = editor_context
                             .interaction
                             .graph_editor
-                            .selected_keyframe
-                            .as_ref()
-                            .map_or(false, |(s_name, s_idx)| s_name == name && *s_idx == i);
+                            .selected_keyframes
+                            .contains(&key);
 
                         // Draw Dot
                         let dot_color = if is_selected { Color32::WHITE } else { color };
@@ -388,6 +398,13 @@ pub(super) fn draw_properties(
                             *action = Action::Select(name.clone(), i);
                         }
 
+                        if point_response.drag_started() {
+                            drag_started_on = Some(key.clone());
+                        }
+                        if point_response.dragged() {
+                            is_dragging_keyframe = true;
+                        }
+
                         // History: drag stopped
                         if point_response.drag_stopped() {
                             *should_push_history = true;
@@ -398,13 +415,32 @@ pub(super) fn draw_properties(
                         point_response.context_menu(|ui| {
                             ui.label(format!("Keyframe {} - {}", i, name_for_menu));
                             ui.separator();
+                            // Each side of the keyframe eases its own segment
                             let mut chosen_easing = None;
-                            crate::widgets::easing_menus::show_easing_menu(ui, None, |easing| {
-                                chosen_easing = Some(easing);
-                            });
+                            for (label, handle) in [
+                                ("Ease In", EasingHandle::In),
+                                ("Ease Out", EasingHandle::Out),
+                            ] {
+                                let current = match handle {
+                                    EasingHandle::In => kf.ease_in.as_ref(),
+                                    EasingHandle::Out => kf.ease_out.as_ref(),
+                                };
+                                ui.menu_button(label, |ui| {
+                                    if ui.selectable_label(current.is_none(), "Default").clicked() {
+                                        chosen_easing = Some((handle, None));
+                                    }
+                                    ui.separator();
+                                    crate::widgets::easing_menus::show_easing_menu(
+                                        ui,
+                                        current,
+                                        |easing| chosen_easing = Some((handle, Some(easing))),
+                                    );
+                                });
+                            }
 
-                            if let Some(easing) = chosen_easing {
-                                *action = Action::SetEasing(name_for_menu.clone(), i, easing);
+                            if let Some((handle, easing)) = chosen_easing {
+                                *action =
+                                    Action::SetEasing(name_for_menu.clone(), i, handle, easing);
                                 *should_push_history = true;
                                 ui.close_kind(UiKind::Menu);
                             }
@@ -425,22 +461,6 @@ pub(super) fn draw_properties(
                                 ui.close_kind(UiKind::Menu);
                             }
                         });
-
-                        // Dragging
-                        if is_selected && point_response.dragged() {
-                            let (new_t, new_val) =
-                                transform.from_screen(kf_pos + point_response.drag_delta());
-                            *action = Action::Move(
-                                name.clone(),
-                                i,
-                                new_t.max(0.0),
-                                new_val,
-                                match *component {
-                                    PropertyComponent::Scalar => None,
-                                    c => Some(c),
-                                },
-                            );
-                        }
                     }
 
                     // Add Keyframe (Double Click) logic constraint
@@ -498,4 +518,163 @@ pub(super) fn draw_properties(
             _ => {}
         }
     }
+
+    move_selected_keyframes(
+        ui,
+        painter,
+        transform,
+        composition_fps,
+        &keyframe_points,
+        drag_started_on,
+        is_dragging_keyframe,
+        editor_context,
+        action,
+    );
+    box_select_keyframes(ui, painter, response, &keyframe_points, editor_context);
+}
+
+/// Drag every selected keyframe by the pointer's offset from where the drag
+/// started, each relative to its own position at that moment.
+#[allow(clippy::too_many_arguments)]
+fn move_selected_keyframes(
+    ui: &Ui,
+    painter: &Painter,
+    transform: &GraphTransform,
+    composition_fps: f64,
+    keyframe_points: &[((String, usize), (f64, f64), Pos2)],
+    drag_started_on: Option<(String, usize)>,
+    is_dragging_keyframe: bool,
+    editor_context: &mut EditorContext,
+    action: &mut Action,
+) {
+    let state = &mut editor_context.interaction.graph_editor;
+
+    if let Some(key) = drag_started_on {
+        // Dragging an unselected keyframe moves just that one
+        if !state.selected_keyframes.contains(&key) {
+            state.selected_keyframes.clear();
+            state.selected_keyframes.insert(key.clone());
+        }
+        let origins = keyframe_points
+            .iter()
+            .filter(|(key, _, _)| state.selected_keyframes.contains(key))
+            .map(|(key, graph_pos, _)| (key.clone(), *graph_pos))
+            .collect();
+        state.move_drag = ui
+            .input(|i| i.pointer.press_origin())
+            .map(|pos| KeyframeMoveDrag {
+                anchor: key,
+                start: transform.from_screen(pos),
+                origins,
+            });
+    }
+
+    if !is_dragging_keyframe {
+        state.move_drag = None;
+        return;
+    }
+
+    if let (Some(drag), Some(pos)) = (&state.move_drag, ui.input(|i| i.pointer.interact_pos())) {
+        let (t, v) = transform.from_screen(pos);
+        let (mut dt, dv) = (t - drag.start.0, v - drag.start.1);
+
+        let anchor_origin = drag
+            .origins
+            .iter()
+            .find(|(key, _)| *key == drag.anchor)
+            .map(|(_, (t0, _))| *t0);
+        if let (true, Some(t0)) = (editor_context.graph_editor.snap_to_frames, anchor_origin) {
+            // Other keyframes on the anchor's row that are not being moved
+            let neighbours: Vec<f64> = keyframe_points
+                .iter()
+                .filter(|((name, _), _, _)| *name == drag.anchor.0)
+                .filter(|(key, _, _)| !state.selected_keyframes.contains(key))
+                .map(|(_, (t, _), _)| *t)
+                .collect();
+            let threshold = SNAP_DISTANCE_PX as f64 / transform.zoom_x as f64;
+            let snapped = snap_time(t0 + dt, composition_fps, &neighbours, threshold);
+            dt = snapped - t0;
+
+            let x = transform.to_screen(snapped, 0.0).x;
+            let graph_rect = transform.graph_rect;
+            painter.line_segment(
+                [
+                    Pos2::new(x, graph_rect.min.y),
+                    Pos2::new(x, graph_rect.max.y),
+                ],
+                Stroke::new(1.0, Color32::from_rgba_unmultiplied(255, 255, 0, 120)),
+            );
+        }
+
+        *action = Action::MoveSelected(
+            drag.origins
+                .iter()
+                .map(|((name, idx), (t0, v0))| (name.clone(), *idx, (t0 + dt).max(0.0), v0 + dv))
+                .collect(),
+        );
+    }
+}
+
+/// Rubber-band selection: a primary drag on empty graph space selects the
+/// keyframes inside the rectangle on release, honouring Shift/Ctrl like the timeline.
+fn box_select_keyframes(
+    ui: &Ui,
+    painter: &Painter,
+    response: &Response,
+    keyframe_points: &[((String, usize), (f64, f64), Pos2)],
+    editor_context: &mut EditorContext,
+) {
+    if editor_context.interaction.preview.handled_hand_tool_drag {
+        editor_context.interaction.graph_editor.box_select_start = None;
+        return;
+    }
+    if response.drag_started_by(egui::PointerButton::Primary) && !ui.input(|i| i.modifiers.alt) {
+        editor_context.interaction.graph_editor.box_select_start =
+            ui.input(|i| i.pointer.press_origin());
+    }
+
+    let Some(start_pos) = editor_context.interaction.graph_editor.box_select_start else {
+        return;
+    };
+    let Some(current_pos) = ui.input(|i| i.pointer.interact_pos()) else {
+        return;
+    };
+    let selection_rect = Rect::from_two_pos(start_pos, current_pos);
+
+    if ui.input(|i| i.pointer.primary_down()) {
+        painter.rect_stroke(
+            selection_rect,
+            0.0,
+            Stroke::new(1.0, Color32::from_rgb(100, 200, 255)),
+            egui::StrokeKind::Middle,
+        );
+        painter.rect_filled(
+            selection_rect,
+            0.0,
+            Color32::from_rgba_premultiplied(100, 200, 255, 30),
+        );
+        return;
+    }
+
+    // Released - commit box selection
+    let found: Vec<(String, usize)> = keyframe_points
+        .iter()
+        .filter(|(_, _, screen_pos)| selection_rect.contains(*screen_pos))
+        .map(|(key, _, _)| key.clone())
+        .collect();
+    let state = &mut editor_context.interaction.graph_editor;
+    match crate::widgets::selection::get_box_action(&ui.input(|i| i.modifiers), found) {
+        crate::widgets::selection::BoxAction::Replace(keys) => {
+            state.selected_keyframes = keys.into_iter().collect();
+        }
+        crate::widgets::selection::BoxAction::Add(keys) => {
+            state.selected_keyframes.extend(keys);
+        }
+        crate::widgets::selection::BoxAction::Remove(keys) => {
+            for key in keys {
+                state.selected_keyframes.remove(&key);
+            }
+        }
+    }
+    state.box_select_start = None;
 }
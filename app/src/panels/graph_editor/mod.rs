@@ -1,4 +1,5 @@
 mod actions;
+mod clipboard;
 mod drawing;
 mod utils;
 
@@ -157,7 +158,11 @@ pub(crate) fn graph_editor_panel(ui: &mut Ui, ctx: &mut PanelContext, registry:
                 .resizable(true)
                 .default_width(sidebar_width)
                 .show_inside(ui, |ui| {
-                    ui.heading("Properties");
+                    ui.horizontal(|ui| {
+                        ui.heading("Properties");
+                        ui.toggle_value(&mut editor_context.graph_editor.snap_to_frames, "Snap")
+                            .on_hover_text("Snap dragged keyframes to frames and nearby keyframes");
+                    });
                     ui.separator();
                     egui::ScrollArea::vertical().show(ui, |ui| {
                         let mut color_cycle = [
@@ -178,13 +183,19 @@ pub(crate) fn graph_editor_panel(ui: &mut Ui, ctx: &mut PanelContext, registry:
                                 .graph_editor
                                 .visible_properties
                                 .contains(name);
+                            let is_highlighted = editor_context
+                                .interaction
+                                .graph_editor
+                                .highlighted_property
+                                .as_ref()
+                                == Some(name);
 
                             ui.horizontal(|ui| {
                                 let (rect, _response) =
                                     ui.allocate_exact_size(Vec2::splat(12.0), Sense::hover());
                                 ui.painter().circle_filled(rect.center(), 5.0, color);
 
-                                if ui.checkbox(&mut is_visible, name).changed() {
+                                if ui.checkbox(&mut is_visible, "").changed() {
                                     if is_visible {
                                         editor_context
                                             .graph_editor
@@ -194,6 +205,11 @@ pub(crate) fn graph_editor_panel(ui: &mut Ui, ctx: &mut PanelContext, registry:
                                         editor_context.graph_editor.visible_properties.remove(name);
                                     }
                                 }
+                                // The highlighted row receives pasted keyframes
+                                if ui.selectable_label(is_highlighted, name).clicked() {
+                                    editor_context.interaction.graph_editor.highlighted_property =
+                                        Some(name.clone());
+                                }
                             });
                         }
                     });
@@ -237,6 +253,7 @@ pub(crate) fn graph_editor_panel(ui: &mut Ui, ctx: &mut PanelContext, registry:
                             zoom_uniform: false,
                             allow_zoom_x: true,
                             allow_zoom_y: true,
+                            enable_inertia: true,
                             ..Default::default()
                         },
                     );
@@ -293,6 +310,26 @@ pub(crate) fn graph_editor_panel(ui: &mut Ui, ctx: &mut PanelContext, registry:
                     composition.fps,
                 );
 
+                // Ctrl+C / Ctrl+V arrive as clipboard events, not key presses
+                if ui.rect_contains_pointer(available_rect) && !ui.ctx().wants_keyboard_input() {
+                    let (copy, paste) = ui.input(|i| {
+                        (
+                            i.events.iter().any(|e| matches!(e, egui::Event::Copy)),
+                            i.events.iter().any(|e| matches!(e, egui::Event::Paste(_))),
+                        )
+                    });
+                    if copy {
+                        copy_selected_keyframes(
+                            ui,
+                            &properties_to_plot,
+                            &time_mapper,
+                            editor_context,
+                        );
+                    } else if paste {
+                        action = Action::Paste;
+                    }
+                }
+
                 drawing::draw_playhead(
                     &painter,
                     &transform,
@@ -314,3 +351,39 @@ pub(crate) fn graph_editor_panel(ui: &mut Ui, ctx: &mut PanelContext, registry:
         history_manager,
     );
 }
+
+/// Put the selected keyframes on the clipboard.
+///
+/// They are also written to the system clipboard as JSON: the windowing
+/// backend only reports Ctrl+V while the system clipboard holds text.
+fn copy_selected_keyframes(
+    ui: &Ui,
+    properties: &[(String, &Property, &PropertyMap, PropertyComponent)],
+    time_mapper: &TimeMapper,
+    editor_context: &mut crate::context::context::EditorContext,
+) {
+    let selected = &editor_context.interaction.graph_editor.selected_keyframes;
+    let mut keyframes = Vec::new();
+    for (name, property, _, component) in properties {
+        let mut sorted_kf = property.keyframes();
+        sorted_kf.sort_by(|a, b| a.time.cmp(&b.time));
+        for (i, kf) in sorted_kf.into_iter().enumerate() {
+            if selected.contains(&(name.clone(), i)) {
+                keyframes.push((
+                    time_mapper.to_global_time(kf.time.into_inner()),
+                    kf,
+                    *component,
+                ));
+            }
+        }
+    }
+    if keyframes.is_empty() {
+        return;
+    }
+
+    let refs: Vec<_> = keyframes.iter().map(|(t, kf, c)| (*t, kf, *c)).collect();
+    editor_context.keyframe_clipboard = clipboard::copy_keyframes(&refs);
+    if let Ok(json) = serde_json::to_string(&editor_context.keyframe_clipboard) {
+        ui.ctx().copy_text(json);
+    }
+}
@@ -1,6 +1,7 @@
 use egui::{Pos2, Rect, Vec2};
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
 pub(crate) enum PropertyComponent {
     Scalar,
     X,
@@ -63,3 +64,37 @@ impl TimeMapper {
         in_time + (source_time - source_start_time)
     }
 }
+
+/// Snap `time` to the nearest keyframe in `neighbours` within `threshold`
+/// seconds, otherwise to the nearest frame at `fps`.
+pub(super) fn snap_time(time: f64, fps: f64, neighbours: &[f64], threshold: f64) -> f64 {
+    let nearest = neighbours
+        .iter()
+        .copied()
+        .min_by(|a, b| (a - time).abs().total_cmp(&(b - time).abs()));
+    match nearest {
+        Some(t) if (t - time).abs() <= threshold => t,
+        _ if fps > 0.0 => (time * fps).round() / fps,
+        _ => time,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ── Domain: Keyframe Snapping ──
+
+    #[test]
+    fn snaps_to_nearest_frame() {
+        assert_eq!(snap_time(1.01, 30.0, &[], 0.1), 1.0);
+        assert_eq!(snap_time(0.52, 10.0, &[], 0.1), 0.5);
+    }
+
+    #[test]
+    fn nearby_keyframe_wins_over_frame_grid() {
+        assert_eq!(snap_time(2.04, 30.0, &[2.05, 3.0], 0.02), 2.05);
+        // Out of range of the keyframe, fall back to the frame grid
+        assert_eq!(snap_time(2.04, 10.0, &[2.2], 0.02), 2.0);
+    }
+}
@@ -64,6 +64,18 @@ impl<'a> ActionContext<'a> {
         self.history_manager.push_project_state(current_state);
     }
 
+    /// Handle an EndCompound action - records the drag on `owner` as one undo step.
+    fn handle_end_compound(&mut self, owner: egui::Id) {
+        let current_state = self.project_service.with_project(|p| p.clone());
+        let label = self
+            .history_manager
+            .describe_change_to(&current_state)
+            .unwrap_or_else(|| "Edit property".to_string());
+        self.history_manager
+            .push_compound_state(owner, current_state);
+        self.history_manager.end_compound(owner, label);
+    }
+
     /// Handle a ToggleKeyframe action - adds or removes a keyframe at current time.
     fn handle_toggle_keyframe(
         &mut self,
@@ -139,6 +151,12 @@ impl<'a> ActionContext<'a> {
                     self.handle_update(target, &name, val, &get_property);
                     needs_refresh = true;
                 }
+                PropertyAction::BeginCompound(owner) => {
+                    self.history_manager.begin_compound(owner);
+                }
+                PropertyAction::EndCompound(owner) => {
+                    self.handle_end_compound(owner);
+                }
                 PropertyAction::Commit => {
                     self.handle_commit();
                 }
@@ -146,6 +164,14 @@ impl<'a> ActionContext<'a> {
                     self.handle_toggle_keyframe(target, &name, val, &get_property);
                     needs_refresh = true;
                 }
+                PropertyAction::PickFile(name, path) => {
+                    let stored = self
+                        .project_service
+                        .with_project(|p| p.store_file_path(&path));
+                    self.handle_update(target, &name, PropertyValue::String(stored), &get_property);
+                    self.handle_commit();
+                    needs_refresh = true;
+                }
                 PropertyAction::SetAttribute(name, key, val) => {
                     self.handle_set_attribute(target, &name, &key, val);
                     needs_refresh = true;
@@ -0,0 +1,156 @@
+use super::action_handler::{ActionContext, PropertyTarget};
+use super::properties::{render_inspector_properties_grid, PropertyRenderContext};
+use crate::command::history::HistoryManager;
+use crate::context::context::EditorContext;
+
+use egui::collapsing_header::CollapsingState;
+use egui::Ui;
+use library::plugin::Plugin;
+use library::project::effect::EffectConfig;
+use library::EditorService as ProjectService;
+use std::sync::{Arc, RwLock};
+use uuid::Uuid;
+
+/// Render the audio effect chain of an audio source (applied by the mixer).
+#[allow(clippy::too_many_arguments)]
+pub(super) fn render_audio_effects_section(
+    ui: &mut Ui,
+    project_service: &mut ProjectService,
+    history_manager: &mut HistoryManager,
+    editor_context: &mut EditorContext,
+    selected_entity_id: Uuid,
+    current_time: f64,
+    fps: f64,
+    project: &Arc<RwLock<library::project::project::Project>>,
+    needs_refresh: &mut bool,
+) {
+    ui.add_space(10.0);
+    ui.heading("Audio Effects");
+    ui.separator();
+
+    let audio_effects: Vec<EffectConfig> = project
+        .read()
+        .ok()
+        .and_then(|proj| {
+            proj.get_source(selected_entity_id)
+                .map(|source| source.audio_effects.clone())
+        })
+        .unwrap_or_default();
+
+    use super::properties::render_add_button;
+    render_add_button(ui, |ui| {
+        use crate::widgets::context_menu::{show_searchable_context_menu, SearchableItem};
+
+        let plugin_manager = project_service.get_plugin_manager();
+        let items: Vec<SearchableItem<String>> = plugin_manager
+            .get_available_audio_effects()
+            .into_iter()
+            .filter_map(|id| {
+                let plugin = plugin_manager.get_audio_effect_plugin(&id)?;
+                Some(SearchableItem {
+                    label: plugin.name(),
+                    category: Some(plugin.category()),
+                    icon: None,
+                    action: id,
+                    enabled: true,
+                    keywords: vec![],
+                })
+            })
+            .collect();
+
+        if let Some(effect_type) = show_searchable_context_menu(ui, "add_audio_effect_menu", &items)
+        {
+            match project_service.add_audio_effect(selected_entity_id, &effect_type) {
+                Ok(_) => {
+                    drop(history_manager.begin_mutation(project));
+                    *needs_refresh = true;
+                }
+                Err(e) => log::error!("Failed to add audio effect: {}", e),
+            }
+        }
+    });
+
+    if audio_effects.is_empty() {
+        return;
+    }
+
+    let mut local_effects = audio_effects.clone();
+    let list_id = egui::Id::new(format!("audio_effects_{}", selected_entity_id));
+
+    crate::widgets::collection_editor::CollectionEditor::new(
+        list_id,
+        &mut local_effects,
+        |e| egui::Id::new(e.id),
+        |ui, visual_index, effect, handle, history_manager, project_service, needs_refresh| {
+            let effect_index = audio_effects
+                .iter()
+                .position(|e| e.id == effect.id)
+                .unwrap_or(visual_index);
+            let id = ui.make_persistent_id(format!("audio_effect_{}", effect.id));
+            let state = CollapsingState::load_with_default_open(ui.ctx(), id, true);
+
+            let label = project_service
+                .get_plugin_manager()
+                .get_audio_effect_plugin(&effect.effect_type)
+                .map(|p| p.name())
+                .unwrap_or_else(|| effect.effect_type.clone());
+
+            let mut remove_clicked = false;
+            let header_res = state.show_header(ui, |ui| {
+                ui.horizontal(|ui| {
+                    handle.ui(ui, |ui| {
+                        ui.label("::");
+                    });
+                    ui.label(egui::RichText::new(&label).strong());
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.button("X").clicked() {
+                            remove_clicked = true;
+                        }
+                    });
+                });
+            });
+
+            header_res.body(|ui| {
+                let defs = project_service
+                    .get_plugin_manager()
+                    .get_audio_effect_properties(&effect.effect_type);
+
+                let context = PropertyRenderContext {
+                    available_fonts: &editor_context.available_fonts,
+                    in_grid: true,
+                    current_time,
+                };
+
+                let pending_actions = render_inspector_properties_grid(
+                    ui,
+                    format!("audio_effect_grid_{}", effect.id),
+                    &effect.properties,
+                    &defs,
+                    project_service,
+                    &context,
+                    fps,
+                );
+                let effect_props = effect.properties.clone();
+                let mut ctx = ActionContext::new(
+                    project_service,
+                    history_manager,
+                    selected_entity_id,
+                    current_time,
+                );
+                if ctx.handle_actions(
+                    pending_actions,
+                    PropertyTarget::AudioEffect(effect_index),
+                    |n| effect_props.get(n).cloned(),
+                ) {
+                    *needs_refresh = true;
+                }
+            });
+
+            remove_clicked
+        },
+        |new_effects, project_service| {
+            project_service.set_audio_effects(selected_entity_id, new_effects)
+        },
+    )
+    .show(ui, history_manager, project_service, needs_refresh);
+}
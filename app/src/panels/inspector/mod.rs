@@ -4,8 +4,10 @@ use egui::Ui;
 use crate::context::context::PanelContext;
 
 use library::project::property::{PropertyMap, PropertyUiType};
+use library::runtime::draw_type::BlendMode;
 
 mod action_handler;
+mod audio_effects;
 mod effects;
 mod ensemble;
 mod graph_items;
@@ -13,6 +15,7 @@ mod properties;
 mod styles;
 
 use action_handler::{ActionContext, PropertyTarget};
+use audio_effects::render_audio_effects_section;
 use effects::render_effects_section;
 use ensemble::render_ensemble_section;
 use properties::{render_property_rows, PropertyRenderContext};
@@ -30,6 +33,14 @@ pub(crate) fn inspector_panel(ui: &mut Ui, ctx: &mut PanelContext) {
     } = ctx;
     let mut needs_refresh = false;
 
+    // A drag that ended without its EndCompound (e.g. the widget disappeared)
+    // must not keep grouping later edits
+    if let Some(owner) = history_manager.compound_owner() {
+        if ui.ctx().dragged_id() != Some(owner) {
+            history_manager.close_compound();
+        }
+    }
+
     // Display properties of selected entity
     if let (Some(selected_entity_id), Some(comp_id), Some(track_id)) = (
         editor_context.selection.last_selected_entity_id,
@@ -66,6 +77,9 @@ pub(crate) fn inspector_panel(ui: &mut Ui, ctx: &mut PanelContext) {
                     .unwrap_or_default();
 
                 let has_shape_output = source.has_shape_output();
+                let has_image_output = source.has_image_output();
+                let blend_mode = source.blend_mode;
+                let speed = source.speed;
 
                 (
                     source.kind.to_string(),
@@ -77,6 +91,9 @@ pub(crate) fn inspector_panel(ui: &mut Ui, ctx: &mut PanelContext) {
                     transform_node_id,
                     transform_props,
                     has_shape_output,
+                    has_image_output,
+                    blend_mode,
+                    speed,
                 )
             })
         } else {
@@ -93,6 +110,9 @@ pub(crate) fn inspector_panel(ui: &mut Ui, ctx: &mut PanelContext) {
             transform_node_id,
             transform_props,
             has_shape_output,
+            has_image_output,
+            blend_mode,
+            speed,
         )) = entity_data
         {
             if editor_context.selection.selected_entities.len() > 1 {
@@ -210,19 +230,33 @@ pub(crate) fn inspector_panel(ui: &mut Ui, ctx: &mut PanelContext) {
                 );
             }
 
-            // --- Effects (image chain) ---
-            render_effects_section(
-                ui,
-                project_service,
-                history_manager,
-                editor_context,
-                selected_entity_id,
-                track_id,
-                current_time,
-                fps,
-                project,
-                &mut needs_refresh,
-            );
+            // --- Effects (image chain, or audio chain for audio sources) ---
+            if has_image_output {
+                render_effects_section(
+                    ui,
+                    project_service,
+                    history_manager,
+                    editor_context,
+                    selected_entity_id,
+                    track_id,
+                    current_time,
+                    fps,
+                    project,
+                    &mut needs_refresh,
+                );
+            } else {
+                render_audio_effects_section(
+                    ui,
+                    project_service,
+                    history_manager,
+                    editor_context,
+                    selected_entity_id,
+                    current_time,
+                    fps,
+                    project,
+                    &mut needs_refresh,
+                );
+            }
 
             // --- Transform (final output, closest to render) ---
             if !transform_defs.is_empty() && transform_node_id.is_some() {
@@ -255,6 +289,42 @@ pub(crate) fn inspector_panel(ui: &mut Ui, ctx: &mut PanelContext) {
                 });
             }
 
+            // --- Compositing (how the clip is blended onto the clips below) ---
+            if has_image_output {
+                ui.add_space(5.0);
+                let compositing_section_id = ui.make_persistent_id("inspector_compositing");
+                let compositing_state =
+                    CollapsingState::load_with_default_open(ui.ctx(), compositing_section_id, true);
+                let compositing_header = compositing_state.show_header(ui, |ui| {
+                    ui.label(egui::RichText::new("Compositing").strong());
+                });
+                compositing_header.body(|ui| {
+                    egui::Grid::new("inspector_compositing_grid").show(ui, |ui| {
+                        ui.label("Blend Mode");
+                        let mut selected = blend_mode;
+                        egui::ComboBox::from_id_salt("inspector_blend_mode")
+                            .selected_text(format!("{:?}", selected))
+                            .show_ui(ui, |ui| {
+                                for mode in BlendMode::ALL {
+                                    ui.selectable_value(&mut selected, mode, format!("{:?}", mode));
+                                }
+                            });
+                        if selected != blend_mode {
+                            match project_service
+                                .set_source_blend_mode(selected_entity_id, selected)
+                            {
+                                Ok(()) => {
+                                    drop(history_manager.begin_mutation(project));
+                                    needs_refresh = true;
+                                }
+                                Err(e) => log::error!("Failed to set blend mode: {}", e),
+                            }
+                        }
+                        ui.end_row();
+                    });
+                });
+            }
+
             // ===== Timing Section =====
             ui.add_space(10.0);
             ui.heading("Timing");
@@ -334,6 +404,29 @@ pub(crate) fn inspector_panel(ui: &mut Ui, ctx: &mut PanelContext) {
                     }
                     ui.end_row();
 
+                    // Speed
+                    ui.label("Speed");
+                    let mut current_speed = speed;
+                    let response = ui.add(
+                        egui::DragValue::new(&mut current_speed)
+                            .speed(0.01)
+                            .range(0.01..=10.0)
+                            // Keep reverse speeds set outside the inspector
+                            .clamp_existing_to_range(false)
+                            .suffix("x"),
+                    );
+                    if response.changed() {
+                        project_service
+                            .set_source_speed(selected_entity_id, current_speed)
+                            .ok();
+                        needs_refresh = true;
+                    }
+                    if response.drag_stopped() || response.lost_focus() {
+                        let current_state = project.read().unwrap().clone();
+                        history_manager.push_project_state(current_state);
+                    }
+                    ui.end_row();
+
                     // Duration Frame
                     let duration_text = if let Some(d) = duration_frame {
                         format!("{} fr", d)
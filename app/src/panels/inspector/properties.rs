@@ -5,8 +5,11 @@ use egui_phosphor::regular::TIMER as ICON_TIMER;
 use library::project::property::{Property, PropertyValue, Vec2, Vec3, Vec4};
 use library::project::property::{PropertyDefinition, PropertyUiType};
 use library::runtime::color::Color;
+use library::runtime::curve::ColorCurve;
 use ordered_float::OrderedFloat;
 
+use crate::widgets::curve_editor::curve_editor;
+
 pub(super) struct PropertyRenderContext<'a> {
     pub(super) available_fonts: &'a [String],
     pub(super) in_grid: bool,
@@ -16,9 +19,15 @@ pub(super) struct PropertyRenderContext<'a> {
 #[derive(Debug)]
 pub(super) enum PropertyAction {
     Update(String, PropertyValue),
+    /// A drag started on the widget; its edits become one undo step.
+    BeginCompound(egui::Id),
+    /// The drag on the widget ended; records the result as that undo step.
+    EndCompound(egui::Id),
     Commit,
     ToggleKeyframe(String, PropertyValue),
     SetAttribute(String, String, PropertyValue), // name, attr_key, attr_val
+    /// A file chosen in a FilePicker's dialog; stored relative to the project file when inside its directory.
+    PickFile(String, std::path::PathBuf),
 }
 
 // Helper function to handle common property events
@@ -29,6 +38,9 @@ fn handle_prop_response(
     new_value: Option<PropertyValue>,
     default_value: &PropertyValue,
 ) {
+    if response.drag_started() {
+        actions.push(PropertyAction::BeginCompound(response.id));
+    }
     if response.changed() {
         if let Some(val) = new_value {
             actions.push(PropertyAction::Update(name.to_string(), val));
@@ -41,7 +53,9 @@ fn handle_prop_response(
         ));
         actions.push(PropertyAction::Commit);
     }
-    if response.drag_stopped() || response.lost_focus() {
+    if response.drag_stopped() {
+        actions.push(PropertyAction::EndCompound(response.id));
+    } else if response.lost_focus() {
         actions.push(PropertyAction::Commit);
     }
 }
@@ -208,7 +222,13 @@ where
                     ui.end_row();
                 }
             }
-            PropertyUiType::Integer { suffix, .. } => {
+            PropertyUiType::Integer {
+                min,
+                max,
+                suffix,
+                min_hard_limit,
+                max_hard_limit,
+            } => {
                 let val_opt = get_value(prop_def.name());
                 if val_opt.is_none() {
                     log::warn!(
@@ -216,12 +236,22 @@ where
                         prop_def.name()
                     );
                 }
+                // Fractional values (e.g. from expressions) are rounded to the nearest integer
                 let current_val = val_opt
-                    .and_then(|v| v.get_as::<i64>())
+                    .and_then(|v| {
+                        v.get_as::<i64>()
+                            .or_else(|| v.get_as::<f64>().map(|f| f.round() as i64))
+                    })
                     .unwrap_or(prop_def.default_value().get_as::<i64>().unwrap_or(0));
 
                 let mut val_mut = current_val;
                 let response = ui.add(egui::DragValue::new(&mut val_mut).speed(1.0).suffix(suffix));
+                if *min_hard_limit {
+                    val_mut = val_mut.max(*min);
+                }
+                if *max_hard_limit {
+                    val_mut = val_mut.min(*max);
+                }
 
                 handle_prop_response(
                     &mut actions,
@@ -475,6 +505,79 @@ where
                     ui.end_row();
                 }
             }
+            PropertyUiType::Curve => {
+                let val_opt = get_value(prop_def.name());
+                if val_opt.is_none() {
+                    log::warn!(
+                        "[WARN] Missing value for Curve property '{}'",
+                        prop_def.name()
+                    );
+                }
+                let current_val = val_opt
+                    .and_then(|v| v.get_as::<String>())
+                    .unwrap_or(prop_def.default_value().get_as().unwrap_or_default());
+                let mut curve = ColorCurve::parse(&current_val).unwrap_or_default();
+
+                let response = curve_editor(ui, prop_def.name(), &mut curve);
+                if response.changed {
+                    actions.push(PropertyAction::Update(
+                        prop_def.name().to_string(),
+                        PropertyValue::String(curve.to_json()),
+                    ));
+                }
+                if response.committed {
+                    actions.push(PropertyAction::Commit);
+                }
+
+                if context.in_grid {
+                    ui.end_row();
+                }
+            }
+            PropertyUiType::FilePicker { .. } => {
+                let val_opt = get_value(prop_def.name());
+                if val_opt.is_none() {
+                    log::warn!(
+                        "[WARN] Missing value for FilePicker property '{}'",
+                        prop_def.name()
+                    );
+                }
+                let current_val = val_opt
+                    .and_then(|v| v.get_as::<String>())
+                    .unwrap_or(prop_def.default_value().get_as().unwrap_or_default());
+                let mut text = current_val.clone();
+
+                ui.horizontal(|ui| {
+                    let response = ui.text_edit_singleline(&mut text);
+                    let new_val = if response.changed() {
+                        Some(PropertyValue::String(text.clone()))
+                    } else {
+                        None
+                    };
+                    handle_prop_response(
+                        &mut actions,
+                        &response,
+                        prop_def.name(),
+                        new_val,
+                        prop_def.default_value(),
+                    );
+
+                    if ui.button("…").on_hover_text("Browse").clicked() {
+                        let extensions = prop_def.ui_type().file_extensions();
+                        let mut dialog = rfd::FileDialog::new();
+                        if !extensions.is_empty() {
+                            dialog = dialog.add_filter(prop_def.label(), extensions.as_slice());
+                        }
+                        if let Some(path) = dialog.pick_file() {
+                            actions
+                                .push(PropertyAction::PickFile(prop_def.name().to_string(), path));
+                        }
+                    }
+                });
+
+                if context.in_grid {
+                    ui.end_row();
+                }
+            }
             PropertyUiType::Vec2 { suffix } => {
                 let val_opt = get_value(prop_def.name());
                 if val_opt.is_none() {
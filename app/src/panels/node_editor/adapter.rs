@@ -1,15 +1,24 @@
 //! Adapter connecting library types to egui_node_editor traits.
 
 use egui_node_editor::{
-    ConnectionView, ContainerKind, NodeDisplay, NodeEditorDataSource, NodeEditorMutator,
-    NodeTypeInfo, PinDataType, PinEditValue, PinInfo, PinPropertyInfo,
+    are_types_compatible, boundary_pin_name, parse_boundary_pin, ConnectionView, ContainerKind,
+    NodeDisplay, NodeEditorDataSource, NodeEditorMutator, NodeTypeInfo, PinDataType, PinDirection,
+    PinEditValue, PinInfo, PinPropertyInfo, RerouteNodeData,
 };
+use library::cache::CacheManager;
 use library::plugin::PluginManager;
 use library::project::connection::PinDataType as LibPinDataType;
+use library::project::graph_node::{GraphNode, REROUTE_INPUT_PIN};
 use library::project::node::Node;
 use library::project::project::Project;
+use library::rendering::render_server::NodeOutputRenderParams;
+use library::service::handlers::graph_handler::GraphNodeSnapshot;
+use library::RenderServer;
+use std::collections::HashMap;
 use uuid::Uuid;
 
+use crate::context::context_types::NodeOutputState;
+
 /// Convert library PinDataType to editor PinDataType.
 fn convert_pin_data_type(lib_type: &LibPinDataType) -> PinDataType {
     match lib_type {
@@ -33,18 +42,146 @@ fn convert_pin_data_type(lib_type: &LibPinDataType) -> PinDataType {
     }
 }
 
+/// Data type of a node pin: graph node definitions first, then clip property
+/// definitions (which only have inputs). Unknown pins are `Any`.
+fn pin_data_type(
+    project: &Project,
+    plugin_manager: &PluginManager,
+    node_id: Uuid,
+    pin_name: &str,
+    is_output: bool,
+) -> PinDataType {
+    // Subgraph boundary pins take the type of the grouped node's pin
+    if let Some((inner_id, inner_pin)) = parse_boundary_pin(pin_name) {
+        return pin_data_type(project, plugin_manager, inner_id, inner_pin, is_output);
+    }
+    // Reroutes carry whatever type flows into them
+    if project
+        .get_graph_node(node_id)
+        .is_some_and(GraphNode::is_reroute)
+    {
+        return project
+            .connections
+            .iter()
+            .find(|c| c.to.node_id == node_id && c.to.pin_name == REROUTE_INPUT_PIN)
+            .map(|c| {
+                pin_data_type(
+                    project,
+                    plugin_manager,
+                    c.from.node_id,
+                    &c.from.pin_name,
+                    true,
+                )
+            })
+            .unwrap_or(PinDataType::Any);
+    }
+    // Try graph node definition
+    if let Some(def) = project
+        .get_graph_node(node_id)
+        .and_then(|graph_node| plugin_manager.get_node_type(&graph_node.type_id))
+    {
+        let pins = if is_output { &def.outputs } else { &def.inputs };
+        if let Some(p) = pins.iter().find(|p| p.name == pin_name) {
+            return convert_pin_data_type(&p.data_type);
+        }
+    }
+    // Try clip property definitions
+    if let (false, Some(clip)) = (is_output, project.get_source(node_id)) {
+        let defs = library::project::source::SourceData::get_definitions_for_kind(&clip.kind);
+        if let Some(def) = defs.iter().find(|def| def.name() == pin_name) {
+            return convert_pin_data_type(&def.ui_type().pin_data_type());
+        }
+    }
+    PinDataType::Any
+}
+
+/// Whether `from_node.from_pin` (output) may feed `to_node.to_pin` (input).
+fn can_connect_pins(
+    project: &Project,
+    plugin_manager: &PluginManager,
+    from: (Uuid, &str),
+    to: (Uuid, &str),
+) -> bool {
+    from.0 != to.0
+        && are_types_compatible(
+            &pin_data_type(project, plugin_manager, from.0, from.1, true),
+            &pin_data_type(project, plugin_manager, to.0, to.1, false),
+        )
+}
+
+fn subgraph_name(graph_node: &GraphNode) -> String {
+    graph_node
+        .properties
+        .get_string("name")
+        .unwrap_or_else(|| "Subgraph".to_string())
+}
+
 /// Read-only data source backed by a Project + PluginManager.
 pub(super) struct VideoEditorDataSource<'a> {
     pub(super) project: &'a Project,
     pub(super) plugin_manager: &'a PluginManager,
+    /// Shared with the render thread, which records per-node evaluation errors.
+    pub(super) cache_manager: &'a CacheManager,
     pub(super) current_frame: u64,
+    /// Composition selected in the editor, if any.
+    pub(super) composition_id: Option<Uuid>,
+    /// Latest render of the pinned node's output.
+    pub(super) live_output: Option<(Uuid, egui::TextureId)>,
+}
+
+impl VideoEditorDataSource<'_> {
+    /// Pins of a folded subgraph: one per pin of a grouped node that is
+    /// connected to a node outside the group.
+    fn subgraph_boundary_pins(&self, group_id: Uuid) -> Vec<PinInfo> {
+        let inside = |id: Uuid| id != group_id && self.project.is_node_in_tree(group_id, id);
+        let mut pins: Vec<PinInfo> = Vec::new();
+        for conn in &self.project.connections {
+            let (node_id, pin, is_output) =
+                match (inside(conn.from.node_id), inside(conn.to.node_id)) {
+                    (false, true) => (conn.to.node_id, &conn.to.pin_name, false),
+                    (true, false) => (conn.from.node_id, &conn.from.pin_name, true),
+                    _ => continue,
+                };
+            let name = boundary_pin_name(node_id, pin);
+            if pins
+                .iter()
+                .any(|p| p.name == name && p.is_output == is_output)
+            {
+                continue;
+            }
+            let data_type =
+                pin_data_type(self.project, self.plugin_manager, node_id, pin, is_output);
+            pins.push(if is_output {
+                PinInfo::output(&name, pin, data_type)
+            } else {
+                PinInfo::input(&name, pin, data_type)
+            });
+        }
+        pins
+    }
 }
 
 impl NodeEditorDataSource for VideoEditorDataSource<'_> {
+    fn has_container(&self) -> bool {
+        self.composition_id
+            .is_some_and(|id| self.project.get_composition(id).is_some())
+    }
+
     fn get_container_children(&self, container_id: Uuid) -> Vec<Uuid> {
+        // Reroutes are drawn separately, see `reroute_nodes`
         self.project
             .get_container_child_ids(container_id)
-            .cloned()
+            .map(|ids| {
+                ids.iter()
+                    .copied()
+                    .filter(|id| {
+                        !self
+                            .project
+                            .get_graph_node(*id)
+                            .is_some_and(GraphNode::is_reroute)
+                    })
+                    .collect()
+            })
             .unwrap_or_default()
     }
 
@@ -56,6 +193,9 @@ impl NodeEditorDataSource for VideoEditorDataSource<'_> {
         match self.project.get_node(id) {
             Some(Node::Track(track)) => Some(track.name.clone()),
             Some(Node::Layer(layer)) => Some(layer.name.clone()),
+            Some(Node::Graph(graph_node)) if graph_node.is_subgraph() => {
+                Some(subgraph_name(graph_node))
+            }
             _ => None,
         }
     }
@@ -66,6 +206,12 @@ impl NodeEditorDataSource for VideoEditorDataSource<'_> {
 
     fn get_node_display(&self, id: Uuid) -> Option<NodeDisplay> {
         match self.project.get_node(id)? {
+            Node::Graph(graph_node) if graph_node.is_subgraph() => Some(NodeDisplay::Container {
+                kind: ContainerKind::Subgraph,
+                name: subgraph_name(graph_node),
+                child_ids: graph_node.child_ids.clone(),
+                pins: self.subgraph_boundary_pins(id),
+            }),
             Node::Graph(graph_node) => {
                 let display_name = self
                     .plugin_manager
@@ -208,6 +354,54 @@ impl NodeEditorDataSource for VideoEditorDataSource<'_> {
         }
     }
 
+    fn get_node_error_state(&self, id: Uuid) -> Option<String> {
+        self.cache_manager.get_node_error(id)
+    }
+
+    fn node_output_preview(&self, node_id: Uuid) -> Option<egui::TextureId> {
+        self.live_output
+            .filter(|(id, _)| *id == node_id)
+            .map(|(_, texture)| texture)
+    }
+
+    fn reroute_nodes(&self) -> Vec<RerouteNodeData> {
+        self.project
+            .all_graph_nodes()
+            .filter(|g| g.is_reroute())
+            .filter_map(|g| {
+                Some(RerouteNodeData {
+                    id: g.id,
+                    container_id: self.project.find_parent_container(g.id)?,
+                    data_type: pin_data_type(
+                        self.project,
+                        self.plugin_manager,
+                        g.id,
+                        REROUTE_INPUT_PIN,
+                        false,
+                    ),
+                    // Saved positions are restored into the editor state with the layout
+                    position: egui::Pos2::ZERO,
+                })
+            })
+            .collect()
+    }
+
+    fn get_pin_value(&self, node_id: Uuid, pin_name: &str) -> Option<String> {
+        let (node_id, pin_name) = parse_boundary_pin(pin_name).unwrap_or((node_id, pin_name));
+        // Outputs are recorded as they are evaluated; inputs show their upstream output
+        self.cache_manager
+            .get_pin_value(node_id, pin_name)
+            .or_else(|| {
+                let conn = self
+                    .project
+                    .connections
+                    .iter()
+                    .find(|c| c.to.node_id == node_id && c.to.pin_name == pin_name)?;
+                self.cache_manager
+                    .get_pin_value(conn.from.node_id, &conn.from.pin_name)
+            })
+    }
+
     fn is_pin_connected(&self, node_id: Uuid, pin_name: &str) -> bool {
         self.project
             .connections
@@ -277,32 +471,17 @@ impl NodeEditorDataSource for VideoEditorDataSource<'_> {
 
 impl VideoEditorDataSource<'_> {
     fn get_pin_data_type(&self, node_id: Uuid, pin_name: &str) -> PinDataType {
-        // Try graph node definition
-        if let Some(graph_node) = self.project.get_graph_node(node_id) {
-            if let Some(def) = self.plugin_manager.get_node_type(&graph_node.type_id) {
-                for p in &def.inputs {
-                    if p.name == pin_name {
-                        return convert_pin_data_type(&p.data_type);
-                    }
-                }
-            }
-        }
-        // Try clip property definitions
-        if let Some(clip) = self.project.get_source(node_id) {
-            let defs = library::project::source::SourceData::get_definitions_for_kind(&clip.kind);
-            for def in &defs {
-                if def.name() == pin_name {
-                    return convert_pin_data_type(&def.ui_type().pin_data_type());
-                }
-            }
-        }
-        PinDataType::Any
+        pin_data_type(self.project, self.plugin_manager, node_id, pin_name, false)
     }
 }
 
-/// Read-only adapter for render phase (only get_available_node_types is used).
+/// Read-only adapter for render phase (node types for the context menu and
+/// connection checks while dragging a wire).
 pub(super) struct ReadOnlyMutator<'a> {
     pub(super) project_service: &'a library::EditorService,
+    /// Already read-locked by the caller; re-locking here could deadlock.
+    pub(super) project: &'a Project,
+    pub(super) plugin_manager: &'a PluginManager,
 }
 
 impl NodeEditorMutator for ReadOnlyMutator<'_> {
@@ -318,6 +497,14 @@ impl NodeEditorMutator for ReadOnlyMutator<'_> {
     fn remove_connection(&mut self, _: Uuid) -> Result<(), String> {
         Err("read-only".into())
     }
+    fn can_connect(&self, from_node: Uuid, from_pin: &str, to_node: Uuid, to_pin: &str) -> bool {
+        can_connect_pins(
+            self.project,
+            self.plugin_manager,
+            (from_node, from_pin),
+            (to_node, to_pin),
+        )
+    }
     fn get_available_node_types(&self) -> Vec<NodeTypeInfo> {
         let pm = self.project_service.get_plugin_manager();
         pm.get_available_node_types()
@@ -334,6 +521,13 @@ impl NodeEditorMutator for ReadOnlyMutator<'_> {
 /// Mutation adapter backed by EditorService.
 pub(super) struct VideoEditorMutator<'a> {
     pub(super) project_service: &'a mut library::EditorService,
+    /// Composition used when rendering pinned node outputs and storing layouts.
+    pub(super) composition_id: Option<Uuid>,
+    pub(super) current_frame: u64,
+    pub(super) render_server: &'a RenderServer,
+    /// Scale of pinned output renders, the preview resolution.
+    pub(super) render_scale: f64,
+    pub(super) node_output: &'a mut NodeOutputState,
 }
 
 impl NodeEditorMutator for VideoEditorMutator<'_> {
@@ -384,6 +578,110 @@ impl NodeEditorMutator for VideoEditorMutator<'_> {
             .map_err(|e| e.to_string())
     }
 
+    fn can_connect(&self, from_node: Uuid, from_pin: &str, to_node: Uuid, to_pin: &str) -> bool {
+        let plugin_manager = self.project_service.get_plugin_manager();
+        self.project_service.with_project(|project| {
+            can_connect_pins(
+                project,
+                &plugin_manager,
+                (from_node, from_pin),
+                (to_node, to_pin),
+            )
+        })
+    }
+
+    fn disconnect_node_pins(
+        &mut self,
+        node_id: Uuid,
+        direction: PinDirection,
+    ) -> Result<(), String> {
+        let connection_ids: Vec<Uuid> = self.project_service.with_project(|project| {
+            project
+                .connections
+                .iter()
+                .filter(|c| match direction {
+                    PinDirection::Input => c.to.node_id == node_id,
+                    PinDirection::Output => c.from.node_id == node_id,
+                })
+                .map(|c| c.id)
+                .collect()
+        });
+        for connection_id in connection_ids {
+            self.project_service
+                .remove_graph_connection(connection_id)
+                .map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    }
+
+    fn copy_nodes(&mut self, node_ids: &[Uuid]) -> Result<String, String> {
+        let snapshot = self
+            .project_service
+            .copy_graph_nodes(node_ids)
+            .map_err(|e| e.to_string())?;
+        serde_json::to_string(&snapshot).map_err(|e| e.to_string())
+    }
+
+    fn paste_nodes(&mut self, container_id: Uuid, data: &str) -> Result<Vec<(Uuid, Uuid)>, String> {
+        let snapshot: GraphNodeSnapshot = serde_json::from_str(data).map_err(|e| e.to_string())?;
+        self.project_service
+            .paste_graph_nodes(container_id, &snapshot)
+            .map_err(|e| e.to_string())
+    }
+
+    fn insert_reroute(&mut self, container_id: Uuid, connection_id: Uuid) -> Result<Uuid, String> {
+        self.project_service
+            .insert_reroute(container_id, connection_id)
+            .map_err(|e| e.to_string())
+    }
+
+    fn group_nodes(&mut self, container_id: Uuid, node_ids: &[Uuid]) -> Result<Uuid, String> {
+        self.project_service
+            .group_graph_nodes(container_id, node_ids, "Subgraph")
+            .map_err(|e| e.to_string())
+    }
+
+    fn commit_layout_positions(
+        &mut self,
+        positions: HashMap<Uuid, egui::Pos2>,
+    ) -> Result<(), String> {
+        let comp_id = self.composition_id.ok_or("no composition selected")?;
+        let mut layout = self
+            .project_service
+            .with_project(|p| {
+                p.get_composition(comp_id)
+                    .and_then(|c| c.node_layout.clone())
+            })
+            .unwrap_or_default();
+        layout
+            .positions
+            .extend(positions.into_iter().map(|(id, pos)| (id, [pos.x, pos.y])));
+        self.project_service
+            .set_node_layout(comp_id, layout)
+            .map_err(|e| e.to_string())
+    }
+
+    fn pin_node_output(&mut self, node_id: Uuid, pin_name: &str) -> Result<(), String> {
+        let comp_id = self.composition_id.ok_or("no composition selected")?;
+        let request_id = self
+            .render_server
+            .send_node_output_request(NodeOutputRenderParams {
+                project: self.project_service.with_project(|p| p.clone()),
+                composition_id: comp_id,
+                node_id,
+                pin_name: pin_name.to_string(),
+                frame_number: self.current_frame,
+                render_scale: self.render_scale,
+            });
+        *self.node_output = NodeOutputState {
+            pin: Some((comp_id, node_id, pin_name.to_string())),
+            in_flight: Some(request_id),
+            awaiting_pin: true,
+            live_preview: None,
+        };
+        Ok(())
+    }
+
     fn get_available_node_types(&self) -> Vec<NodeTypeInfo> {
         let pm = self.project_service.get_plugin_manager();
         pm.get_available_node_types()
@@ -1,13 +1,24 @@
 mod adapter;
 
-use crate::context::context::PanelContext;
+use crate::context::context::{EditorContext, PanelContext};
 
 use adapter::{VideoEditorDataSource, VideoEditorMutator};
-use egui_node_editor::{NodeEditorTheme, NodeEditorWidget};
+use egui_node_editor::{NodeEditorState, NodeEditorTheme, NodeEditorWidget, NodeLayoutMap};
+use library::project::composition::NodeLayout;
 use library::project::node::Node;
+use library::rendering::render_server::NodeOutputRenderParams;
+use library::RenderServer;
+use std::collections::HashMap;
+use uuid::Uuid;
 
 /// Main node editor panel function.
-pub(crate) fn node_editor_panel(ui: &mut egui::Ui, ctx: &mut PanelContext) {
+pub(crate) fn node_editor_panel(
+    ui: &mut egui::Ui,
+    ctx: &mut PanelContext,
+    render_server: &RenderServer,
+) {
+    receive_node_outputs(ui.ctx(), render_server, ctx.editor_context);
+
     let project = ctx.project.clone();
     let Ok(proj_read) = project.read() else {
         ui.label("Failed to read project");
@@ -15,19 +26,55 @@ pub(crate) fn node_editor_panel(ui: &mut egui::Ui, ctx: &mut PanelContext) {
     };
 
     let plugin_manager = ctx.project_service.get_plugin_manager();
-    let state = &mut ctx.editor_context.node_editor_state;
+    let cache_manager = ctx.project_service.get_cache_manager();
+    let theme = NodeEditorTheme::default();
 
-    // Resolve current container from selected composition (use composition ID as container)
-    if state.current_container.is_none() {
-        if let Some(comp) = ctx
-            .editor_context
-            .selection
-            .composition_id
-            .and_then(|id| proj_read.get_composition(id))
-        {
-            state.current_container = Some(comp.id);
-        }
-    }
+    let Some(comp_id) = ctx
+        .editor_context
+        .selection
+        .composition_id
+        .filter(|id| proj_read.get_composition(*id).is_some())
+    else {
+        // Without a composition the widget draws its own placeholder
+        let source = VideoEditorDataSource {
+            project: &proj_read,
+            plugin_manager: &plugin_manager,
+            cache_manager: &cache_manager,
+            current_frame: 0,
+            composition_id: None,
+            live_output: None,
+        };
+        let temp_mutator = adapter::ReadOnlyMutator {
+            project_service: &*ctx.project_service,
+            project: &proj_read,
+            plugin_manager: &plugin_manager,
+        };
+        NodeEditorWidget::new(&mut NodeEditorState::default(), &theme).show(
+            ui,
+            &source,
+            &temp_mutator,
+        );
+        cache_manager.set_pin_value_recording(false);
+        return;
+    };
+    // Each composition keeps its own view; the composition ID doubles as the root container
+    let state = ctx
+        .editor_context
+        .node_editor_states
+        .entry(comp_id)
+        .or_insert_with(|| {
+            let mut state = NodeEditorState {
+                current_container: Some(comp_id),
+                ..Default::default()
+            };
+            if let Some(layout) = proj_read
+                .get_composition(comp_id)
+                .and_then(|comp| comp.node_layout.as_ref())
+            {
+                state.apply_layout_map(&layout_map_from_project(layout));
+            }
+            state
+        });
 
     // Convert current_time (f32 seconds) to frame number
     let fps = proj_read
@@ -36,22 +83,60 @@ pub(crate) fn node_editor_panel(ui: &mut egui::Ui, ctx: &mut PanelContext) {
         .map(|c| c.fps)
         .unwrap_or(30.0);
     let current_frame = (ctx.editor_context.timeline.current_time * fps as f32) as u64;
+    let render_scale = ctx.editor_context.view.preview_resolution as f64;
+
+    // Keep the live side of the pinned comparison following the current frame
+    let node_output = &mut ctx.editor_context.node_output;
+    if let Some((pin_comp_id, node_id, pin_name)) = &node_output.pin {
+        if *pin_comp_id == comp_id && node_output.in_flight.is_none() {
+            node_output.in_flight = Some(render_server.send_node_output_request(
+                NodeOutputRenderParams {
+                    project: proj_read.clone(),
+                    composition_id: comp_id,
+                    node_id: *node_id,
+                    pin_name: pin_name.clone(),
+                    frame_number: current_frame,
+                    render_scale,
+                },
+            ));
+        }
+    }
+    let live_output = node_output
+        .pin
+        .as_ref()
+        .zip(node_output.live_preview.as_ref())
+        .map(|((_, node_id, _), texture)| (*node_id, texture.id()));
 
     let source = VideoEditorDataSource {
         project: &proj_read,
         plugin_manager: &plugin_manager,
+        cache_manager: &cache_manager,
         current_frame,
+        composition_id: Some(comp_id),
+        live_output,
     };
 
-    let theme = NodeEditorTheme::default();
     let mut widget = NodeEditorWidget::new(state, &theme);
 
-    // Create a temporary mutator just for get_available_node_types (used by context menu)
+    // Read-only mutator for the context menu and wire validation during render
     let temp_mutator = adapter::ReadOnlyMutator {
         project_service: &*ctx.project_service,
+        project: &proj_read,
+        plugin_manager: &plugin_manager,
     };
 
     let pending = widget.show(ui, &source, &temp_mutator);
+    cache_manager.set_pin_value_recording(state.show_live_values);
+
+    // "Unpin Output" only clears the widget state; stop rendering the pin too
+    let node_output = &mut ctx.editor_context.node_output;
+    let unpinned = state.pinned_node_output.is_none() && state.pinned_output_error.is_none();
+    if unpinned
+        && !node_output.awaiting_pin
+        && node_output.pin.as_ref().is_some_and(|p| p.0 == comp_id)
+    {
+        *node_output = Default::default();
+    }
 
     // Sync selected node to inspector
     if let Some(selected_id) = pending.selected_node {
@@ -73,8 +158,109 @@ pub(crate) fn node_editor_panel(ui: &mut egui::Ui, ctx: &mut PanelContext) {
     if !pending.is_empty() {
         let mut mutator = VideoEditorMutator {
             project_service: ctx.project_service,
+            composition_id: ctx.editor_context.selection.composition_id,
+            current_frame,
+            render_server,
+            render_scale,
+            node_output: &mut ctx.editor_context.node_output,
+        };
+        let applied = pending.apply(&mut mutator);
+        if let Some((node_id, message)) = applied.pin_error {
+            state.set_pinned_output_error(node_id, message);
+        }
+        if let Some((node_ids, data)) = applied.copied_nodes {
+            state.set_clipboard(&node_ids, data);
+        }
+        state.place_pasted_nodes(&applied.pasted_nodes);
+        if let Some((group_id, members)) = applied.grouped_nodes {
+            state.place_group(group_id, &members);
+        }
+        if let Some((reroute_id, pos)) = applied.inserted_reroute {
+            state.place_reroute(reroute_id, pos);
+        }
+    }
+}
+
+/// Apply finished node output renders. The awaited render of a new pin becomes
+/// the pinned image, or its error; later renders refresh the live output.
+fn receive_node_outputs(
+    egui_ctx: &egui::Context,
+    render_server: &RenderServer,
+    editor_context: &mut EditorContext,
+) {
+    let node_output = &mut editor_context.node_output;
+    while let Ok(output) = render_server.poll_node_output() {
+        // Results of replaced pins are stale
+        if node_output.in_flight != Some(output.request_id) {
+            continue;
+        }
+        node_output.in_flight = None;
+        let Some((comp_id, node_id, _)) = node_output.pin.clone() else {
+            continue;
+        };
+        let Some(state) = editor_context.node_editor_states.get_mut(&comp_id) else {
+            continue;
+        };
+        match output.result {
+            Ok(image) => {
+                let size = [image.width as usize, image.height as usize];
+                let color_image = egui::ColorImage::from_rgba_unmultiplied(size, &image.data);
+                if node_output.awaiting_pin {
+                    node_output.awaiting_pin = false;
+                    state.set_pinned_output(egui_ctx, node_id, color_image);
+                } else if let Some(texture) = &mut node_output.live_preview {
+                    texture.set(color_image, egui::TextureOptions::LINEAR);
+                } else {
+                    node_output.live_preview = Some(egui_ctx.load_texture(
+                        "node_editor_live_output",
+                        color_image,
+                        egui::TextureOptions::LINEAR,
+                    ));
+                }
+            }
+            // Interrupted by the preview; the next frame asks again
+            Err(library::LibraryError::Cancelled) => {}
+            Err(e) if node_output.awaiting_pin => {
+                state.set_pinned_output_error(node_id, e.to_string());
+                node_output.pin = None;
+            }
+            // The node's error badge already reports failures of live renders
+            Err(_) => {}
+        }
+    }
+}
+
+fn layout_map_from_project(layout: &NodeLayout) -> NodeLayoutMap {
+    NodeLayoutMap {
+        positions: layout
+            .positions
+            .iter()
+            .map(|(id, [x, y])| (*id, egui::Pos2::new(*x, *y)))
+            .collect(),
+        collapsed_groups: layout.collapsed_groups.clone(),
+    }
+}
+
+/// Copy the node layout of every open node editor into the project, so it is saved.
+pub(crate) fn store_node_layouts(
+    states: &HashMap<Uuid, NodeEditorState>,
+    project_service: &library::EditorService,
+) {
+    for (comp_id, state) in states {
+        // Nodes deleted since they were placed are not saved
+        let map =
+            project_service.with_project(|p| state.to_layout_map(|id| p.nodes.contains_key(&id)));
+        let positions = map
+            .positions
+            .into_iter()
+            .map(|(id, pos)| (id, [pos.x, pos.y]))
+            .collect();
+        let layout = NodeLayout {
+            positions,
+            collapsed_groups: map.collapsed_groups,
         };
-        pending.apply(&mut mutator);
+        // Compositions removed since the editor was opened have nothing to store
+        let _ = project_service.set_node_layout(*comp_id, layout);
     }
 }
 
@@ -1,6 +1,6 @@
 use crate::context::context::EditorContext;
 use crate::panels::preview::{action::PreviewAction, clip::PreviewClip};
-use crate::types::GizmoHandle;
+use crate::types::{GizmoAxis, GizmoHandle};
 use egui::{CursorIcon, Pos2, Rect, Sense, Ui, Vec2};
 use library::project::project::Project;
 use library::project::property::{PropertyValue, Vec2 as PropVec2};
@@ -32,6 +32,7 @@ pub(super) fn handle_gizmo_interaction(
             state.original_anchor_x,
             state.original_anchor_y,
             state.transform_node_id,
+            state.axis_constraint,
         ))
     } else {
         None
@@ -49,8 +50,10 @@ pub(super) fn handle_gizmo_interaction(
         _orig_ax,
         _orig_ay,
         transform_node_id,
+        axis_constraint,
     )) = gizmo_drag_data
     {
+        let cancelled = ui.input(|i| i.key_pressed(egui::Key::Escape));
         if ui.input(|i| i.pointer.any_released()) {
             editor_context.interaction.preview.gizmo_state = None;
             interacted_with_gizmo = true; // Prevent click-through to selection logic on release
@@ -61,6 +64,9 @@ pub(super) fn handle_gizmo_interaction(
             }
         } else if let Some(mouse_pos) = pointer_pos {
             interacted_with_gizmo = true;
+            if cancelled {
+                editor_context.interaction.preview.gizmo_state = None;
+            }
 
             // Re-acquire selected entity data
             if let Some(selected_id) = editor_context.selection.last_selected_entity_id {
@@ -90,7 +96,12 @@ pub(super) fn handle_gizmo_interaction(
                 {
                     // Calculate Delta (World Space)
                     let start_world = to_world(start_mouse_pos);
-                    let current_world = to_world(mouse_pos);
+                    // Cancelling writes the original transform back
+                    let current_world = if cancelled {
+                        start_world
+                    } else {
+                        to_world(mouse_pos)
+                    };
                     let delta_world = current_world - start_world;
 
                     let modifiers = ui.input(|i| i.modifiers);
@@ -98,8 +109,9 @@ pub(super) fn handle_gizmo_interaction(
                     let center_scale = modifiers.alt;
 
                     // Logic depends on handle
-                    let mut new_scale_x = orig_sx;
-                    let mut new_scale_y = orig_sy;
+                    // Scale property is in percent, the clip transform in factors
+                    let mut new_scale_x = orig_sx * 100.0;
+                    let mut new_scale_y = orig_sy * 100.0;
                     let mut new_pos_x = orig_pos[0];
                     let mut new_pos_y = orig_pos[1];
                     let mut new_rotation = orig_rot;
@@ -128,6 +140,28 @@ pub(super) fn handle_gizmo_interaction(
 
                             new_rotation = orig_rot + (angle_current - angle_start);
                         }
+                        GizmoHandle::Move => match axis_constraint {
+                            Some(GizmoAxis::X) => new_pos_x += delta_world.x,
+                            Some(GizmoAxis::Y) => new_pos_y += delta_world.y,
+                            None => {
+                                new_pos_x += delta_world.x;
+                                new_pos_y += delta_world.y;
+                            }
+                        },
+                        GizmoHandle::Scale => {
+                            // Scale by the change in distance from the clip position
+                            let center = egui::pos2(orig_pos[0], orig_pos[1]);
+                            let start_dist = (start_world - center).length();
+                            if start_dist > f32::EPSILON {
+                                let factor = (current_world - center).length() / start_dist;
+                                if axis_constraint != Some(GizmoAxis::Y) {
+                                    new_scale_x = orig_sx * 100.0 * factor;
+                                }
+                                if axis_constraint != Some(GizmoAxis::X) {
+                                    new_scale_y = orig_sy * 100.0 * factor;
+                                }
+                            }
+                        }
                         _ => {
                             // Resize Logic
                             let rad = orig_rot.to_radians();
@@ -271,6 +305,8 @@ pub(super) fn draw_gizmo(
     gui_clips: &[PreviewClip],
     to_screen: impl Fn(Pos2) -> Pos2,
 ) {
+    let requested_op = editor_context.interaction.preview.pending_gizmo_op.take();
+
     // Draw outlines for ALL selected entities to show multi-selection
     for selected_id in &editor_context.selection.selected_entities {
         // Skip the primary one if it will be drawn by the main gizmo logic.
@@ -300,6 +336,40 @@ pub(super) fn draw_gizmo(
             let (corners, _center, rotation_rad, s_t) =
                 draw_clip_box(ui, gc, |p| to_screen(p), gizmo_color, 2.0);
 
+            // Start a shortcut-requested operation from the current pointer position
+            if let Some((handle, axis)) = requested_op {
+                if let Some(pointer) = ui.input(|i| i.pointer.latest_pos()) {
+                    if ui.ui_contains_pointer() {
+                        let mut state = start_gizmo_state(gc, handle, pointer);
+                        state.axis_constraint = axis;
+                        editor_context.interaction.preview.gizmo_state = Some(state);
+                    }
+                }
+            }
+
+            // Draw the locked axis through the original position
+            if let Some(state) = &editor_context.interaction.preview.gizmo_state {
+                if let Some(axis) = state.axis_constraint {
+                    let origin = to_screen(egui::pos2(
+                        state.original_position[0],
+                        state.original_position[1],
+                    ));
+                    let (dir, color) = match axis {
+                        GizmoAxis::X => {
+                            (egui::vec2(1.0, 0.0), egui::Color32::from_rgb(230, 80, 80))
+                        }
+                        GizmoAxis::Y => {
+                            (egui::vec2(0.0, 1.0), egui::Color32::from_rgb(120, 200, 90))
+                        }
+                    };
+                    let reach = ui.clip_rect().size().max_elem() * 2.0;
+                    ui.painter().line_segment(
+                        [origin - dir * reach, origin + dir * reach],
+                        egui::Stroke::new(1.0, color),
+                    );
+                }
+            }
+
             // Draw Rotation Stick
             let painter = ui.painter();
             let rot_handle_dist = 10.0 / editor_context.view.zoom;
@@ -342,32 +412,45 @@ pub(super) fn draw_gizmo(
                 }
 
                 if response.drag_started() {
-                    let base_w = gc.content_bounds.map(|b| b.2).unwrap_or(1920.0);
-                    let base_h = gc.content_bounds.map(|b| b.3).unwrap_or(1080.0);
-
-                    editor_context.interaction.preview.gizmo_state =
-                        Some(crate::context::context::GizmoState {
-                            start_mouse_pos: response.hover_pos().unwrap_or(pos),
-                            active_handle: handle,
-                            original_position: [
-                                gc.transform.position.x as f32,
-                                gc.transform.position.y as f32,
-                            ],
-                            original_scale_x: gc.transform.scale.x as f32,
-                            original_scale_y: gc.transform.scale.y as f32,
-                            original_rotation: gc.transform.rotation as f32,
-                            original_anchor_x: gc.transform.anchor.x as f32,
-                            original_anchor_y: gc.transform.anchor.y as f32,
-                            original_width: base_w,
-                            original_height: base_h,
-                            transform_node_id: gc.transform_node_id,
-                        });
+                    editor_context.interaction.preview.gizmo_state = Some(start_gizmo_state(
+                        gc,
+                        handle,
+                        response.hover_pos().unwrap_or(pos),
+                    ));
                 }
             }
         }
     }
 }
 
+/// Snapshot the clip transform at the start of a gizmo operation.
+fn start_gizmo_state(
+    gc: &PreviewClip,
+    handle: GizmoHandle,
+    start_mouse_pos: Pos2,
+) -> crate::context::context::GizmoState {
+    let base_w = gc.content_bounds.map(|b| b.2).unwrap_or(1920.0);
+    let base_h = gc.content_bounds.map(|b| b.3).unwrap_or(1080.0);
+
+    crate::context::context::GizmoState {
+        start_mouse_pos,
+        active_handle: handle,
+        original_position: [
+            gc.transform.position.x as f32,
+            gc.transform.position.y as f32,
+        ],
+        original_scale_x: gc.transform.scale.x as f32,
+        original_scale_y: gc.transform.scale.y as f32,
+        original_rotation: gc.transform.rotation as f32,
+        original_anchor_x: gc.transform.anchor.x as f32,
+        original_anchor_y: gc.transform.anchor.y as f32,
+        original_width: base_w,
+        original_height: base_h,
+        transform_node_id: gc.transform_node_id,
+        axis_constraint: None,
+    }
+}
+
 fn draw_clip_box(
     ui: &Ui,
     gc: &PreviewClip,
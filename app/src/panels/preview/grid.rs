@@ -1,5 +1,7 @@
 use egui::{Painter, Rect, Vec2};
 
+use crate::context::context_types::ViewState;
+
 pub(super) fn draw_grid(painter: &Painter, rect: Rect, pan: Vec2, zoom: f32) {
     let grid_size = 100.0 * zoom;
 
@@ -35,3 +37,24 @@ pub(super) fn draw_grid(painter: &Painter, rect: Rect, pan: Vec2, zoom: f32) {
         }
     }
 }
+
+/// Draw the enabled safe area outlines, centered in the frame's screen rect.
+pub(super) fn draw_safe_areas(painter: &Painter, frame_rect: Rect, view: &ViewState) {
+    let areas = [
+        (view.show_action_safe, view.safe_areas.action_safe_percent),
+        (view.show_title_safe, view.safe_areas.title_safe_percent),
+    ];
+    let stroke = egui::Stroke::new(1.0, egui::Color32::from_white_alpha(160));
+    for (_, percent) in areas.iter().filter(|(enabled, _)| *enabled) {
+        let safe =
+            Rect::from_center_size(frame_rect.center(), frame_rect.size() * (percent / 100.0));
+        let corners = [
+            safe.left_top(),
+            safe.right_top(),
+            safe.right_bottom(),
+            safe.left_bottom(),
+            safe.left_top(),
+        ];
+        painter.extend(egui::Shape::dashed_line(&corners, stroke, 6.0, 4.0));
+    }
+}
@@ -129,6 +129,36 @@ pub(crate) fn preview_panel(
                 editor_context.view.active_tool = PreviewTool::Shape;
             }
             shape_btn.on_hover_text("Shape Tool");
+
+            ui.separator();
+
+            let view = &mut editor_context.view;
+            if ui
+                .add(
+                    egui::Button::new(egui::RichText::new(icons::FRAME_CORNERS).size(18.0))
+                        .selected(view.show_action_safe),
+                )
+                .on_hover_text(format!(
+                    "Action Safe ({}%)",
+                    view.safe_areas.action_safe_percent
+                ))
+                .clicked()
+            {
+                view.show_action_safe = !view.show_action_safe;
+            }
+            if ui
+                .add(
+                    egui::Button::new(egui::RichText::new(icons::SUBTITLES).size(18.0))
+                        .selected(view.show_title_safe),
+                )
+                .on_hover_text(format!(
+                    "Title Safe ({}%)",
+                    view.safe_areas.title_safe_percent
+                ))
+                .clicked()
+            {
+                view.show_title_safe = !view.show_title_safe;
+            }
         });
     });
 
@@ -152,13 +182,14 @@ pub(crate) fn preview_panel(
     )
     .with_config(ViewportConfig {
         zoom_uniform: true,
+        enable_inertia: true,
         ..Default::default()
     })
     .with_pan_tool_active(editor_context.view.active_tool == PreviewTool::Pan)
     .with_zoom_tool_active(editor_context.view.active_tool == PreviewTool::Zoom);
 
     // Provide specific rect to controller (excluding bottom bar)
-    let (_changed, response) = controller.interact_with_rect(
+    let (viewport_changed, response) = controller.interact_with_rect(
         preview_rect,
         &mut state,
         &mut editor_context.interaction.preview.handled_hand_tool_drag,
@@ -251,7 +282,20 @@ pub(crate) fn preview_panel(
                     None
                 };
 
+                // Renders still queued for the previous composition are stale
+                let last_comp_id = ui.id().with("last_rendered_composition");
+                if let Some(previous) = ui.data(|d| d.get_temp::<uuid::Uuid>(last_comp_id)) {
+                    if previous != comp.id {
+                        render_server.cancel_composition(previous);
+                    }
+                }
+                ui.data_mut(|d| d.insert_temp(last_comp_id, comp.id));
+
                 if let Some(valid_region) = region {
+                    // The in-flight render targets the old viewport
+                    if viewport_changed {
+                        render_server.cancel_current();
+                    }
                     render_server.send_composition_request(
                         library::rendering::render_server::CompositionRenderParams {
                             project: proj_read.clone(),
@@ -265,6 +309,15 @@ pub(crate) fn preview_panel(
             }
         }
 
+        // Busy indicator while renders are queued behind the current one
+        if render_server.current_load() > 0 {
+            let spinner_rect = egui::Rect::from_min_size(
+                rect.right_top() + egui::vec2(-24.0, 8.0),
+                egui::vec2(16.0, 16.0),
+            );
+            ui.put(spinner_rect, egui::Spinner::new());
+        }
+
         // 2. Poll for results and update texture
         let mut latest_result = None;
         while let Ok(result) = render_server.poll_result() {
@@ -418,6 +471,12 @@ pub(crate) fn preview_panel(
             ui.painter().add(callback);
         }
 
+        grid::draw_safe_areas(
+            ui.painter(),
+            egui::Rect::from_min_max(screen_frame_min, screen_frame_max),
+            &editor_context.view,
+        );
+
         let mut gui_clips: Vec<clip::PreviewClip> = Vec::new();
 
         if let Some(comp) = editor_context.get_current_composition(&proj_read) {
@@ -496,10 +555,8 @@ pub(crate) fn preview_panel(
 
                 let current_frame_i64 =
                     (editor_context.timeline.current_time as f64 * comp.fps).round() as i64;
-                let delta_frames = current_frame_i64 - entity.in_frame as i64;
-                let time_offset = delta_frames as f64 / comp.fps;
-                let source_start_time = entity.source_begin_frame as f64 / entity.fps;
-                let local_time = source_start_time + time_offset;
+                let local_time =
+                    entity.compute_local_time(current_frame_i64.max(0) as u64, comp.fps);
 
                 // Log Gizmo Time Calculation (throttle slightly if possible, or just spam per user request)
                 if editor_context.timeline.current_time.fract() < 0.1 {
@@ -668,6 +725,34 @@ pub(crate) fn preview_panel(
         }
     }
 
+    // Context menu: export the current frame
+    response.context_menu(|ui| {
+        if ui.button("Save Frame as PNG...").clicked() {
+            ui.close();
+            let current = project.read().ok().and_then(|proj| {
+                editor_context.get_current_composition(&proj).map(|comp| {
+                    let frame =
+                        (editor_context.timeline.current_time as f64 * comp.fps).round() as u64;
+                    (comp.id, frame, format!("{}_{:05}.png", comp.name, frame))
+                })
+            });
+
+            if let Some((comp_id, frame, file_name)) = current {
+                if let Some(path) = rfd::FileDialog::new()
+                    .add_filter("PNG Image", &["png"])
+                    .set_file_name(&file_name)
+                    .save_file()
+                {
+                    let path_str = path.to_string_lossy();
+                    match project_service.export_frame_as_image(comp_id, frame, &path_str) {
+                        Ok(()) => log::info!("Frame {} saved to {}", frame, path.display()),
+                        Err(e) => log::error!("Failed to save frame {}: {}", frame, e),
+                    }
+                }
+            }
+        }
+    });
+
     // Info text
     let info_text = format!(
         "Time: {:.2}\nZoom: {:.0}%",
@@ -4,6 +4,8 @@ use library::project::project::Project;
 use library::EditorService as ProjectService;
 use std::sync::{Arc, RwLock};
 
+use super::timecode::format_timecode;
+use crate::types::TimecodeFormat;
 use crate::{command::history::HistoryManager, context::context::EditorContext};
 
 pub(super) fn show_timeline_controls(
@@ -11,7 +13,7 @@ pub(super) fn show_timeline_controls(
     editor_context: &mut EditorContext,
     _history_manager: &mut HistoryManager,
     project_service: &ProjectService,
-    _project: &Arc<RwLock<Project>>,
+    project: &Arc<RwLock<Project>>,
 ) {
     ui.horizontal(|ui| {
         // Play button
@@ -49,10 +51,13 @@ pub(super) fn show_timeline_controls(
         }
 
         // Time display
-        let minutes = (editor_context.timeline.current_time / 60.0).floor();
-        let seconds = (editor_context.timeline.current_time % 60.0).floor();
-        let ms = ((editor_context.timeline.current_time % 1.0) * 100.0).floor();
-        let time_text = format!("{:02}:{:02}.{:02}", minutes, seconds, ms);
+        let fps = project
+            .read()
+            .ok()
+            .and_then(|p| editor_context.get_current_composition(&p).map(|c| c.fps))
+            .unwrap_or(30.0);
+        let frame = (editor_context.timeline.current_time as f64 * fps).round() as i64;
+        let time_text = format_timecode(frame, fps, editor_context.timeline.display_mode);
         ui.label(egui::RichText::new(time_text).monospace());
 
         // Spacer
@@ -69,6 +74,28 @@ pub(super) fn show_timeline_controls(
             // Zoom information
             let zoom_text = format!("H-Zoom: {:.1}x", editor_context.timeline.h_zoom);
             ui.label(zoom_text);
+
+            egui::ComboBox::from_id_salt("timeline_timecode_format")
+                .selected_text(editor_context.timeline.display_mode.label())
+                .show_ui(ui, |ui| {
+                    for format in TimecodeFormat::ALL {
+                        ui.selectable_value(
+                            &mut editor_context.timeline.display_mode,
+                            format,
+                            format.label(),
+                        );
+                    }
+                })
+                .response
+                .on_hover_text("Timecode format");
+
+            ui.toggle_value(&mut editor_context.timeline.snap_enabled, "Snap")
+                .on_hover_text("Snap dragged clips to clip edges and the playhead (S)");
+            ui.toggle_value(
+                &mut editor_context.interaction.timeline.ripple_edit,
+                "Ripple",
+            )
+            .on_hover_text("Trimming a clip's end moves the clips after it on the track");
         });
     });
 }
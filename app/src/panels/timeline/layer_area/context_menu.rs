@@ -15,6 +15,7 @@ enum LayerAreaAction {
     AddTextLayer,
     AddShapeLayer,
     AddSkSLLayer,
+    AddGradientLayer,
 }
 
 pub(super) fn handle_context_menu(
@@ -40,7 +41,7 @@ pub(super) fn handle_context_menu(
         }
     }
 
-    // Context Menu for adding Text/Shape/SkSL
+    // Context Menu for adding Text/Shape/SkSL/Gradient
     response.context_menu(|ui| {
         let mut drop_in_frame =
             (editor_context.timeline.current_time * composition_fps as f32).round() as u64;
@@ -78,6 +79,7 @@ pub(super) fn handle_context_menu(
             .action("Add Text Layer", LayerAreaAction::AddTextLayer)
             .action("Add Shape Layer", LayerAreaAction::AddShapeLayer)
             .action("Add SkSL Layer", LayerAreaAction::AddSkSLLayer)
+            .action("Add Gradient Layer", LayerAreaAction::AddGradientLayer)
             .build();
 
         if let Some(action) = show_context_menu(ui, &menu) {
@@ -108,6 +110,13 @@ pub(super) fn handle_context_menu(
                     comp_height as u32,
                     composition_fps,
                 ),
+                LayerAreaAction::AddGradientLayer => project_service.build_gradient_source(
+                    drop_in_frame,
+                    drop_out_frame,
+                    comp_width as u32,
+                    comp_height as u32,
+                    composition_fps,
+                ),
             };
 
             if let Ok(source) = source_result {
@@ -1,10 +1,12 @@
 use egui::Ui;
-use library::project::asset::AssetKind;
+use library::project::asset::{Asset, AssetKind};
 use library::project::project::Project;
-use library::project::property::PropertyValue;
+use library::project::property::{Property, PropertyValue, Vec2};
 use library::project::source::SourceData;
 use library::project::source::SourceKind;
+use library::service::NewClipSpec;
 use library::EditorService as ProjectService;
+use ordered_float::OrderedFloat;
 use std::sync::{Arc, RwLock};
 use uuid::Uuid;
 
@@ -48,8 +50,7 @@ pub(super) fn handle_drag_and_drop(
                     let mut comp_width = 1920u64;
                     let mut comp_height = 1080u64;
                     let mut target_track_id_opt: Option<Uuid> = None;
-                    let mut new_source_opt: Option<SourceData> = None;
-                    let mut drop_out_frame_opt: Option<u64> = None;
+                    let mut clips: Vec<NewClipSpec> = Vec::new();
                     let mut calculated_insert_index: Option<usize> = None;
 
                     {
@@ -91,123 +92,55 @@ pub(super) fn handle_drag_and_drop(
                             }
                         }
 
-                        // Build the source based on dragged item
+                        // Build the sources based on dragged item
                         match dragged_item {
-                            DraggedItem::Asset(asset_id) => {
-                                if let Some(asset) =
-                                    proj_read.assets.iter().find(|a| a.id == *asset_id)
-                                {
-                                    if visible_row_index < display_rows.len() {
-                                        let row = &display_rows[visible_row_index];
-                                        match row {
-                                            super::super::utils::flatten::DisplayRow::TrackHeader {
-                                                track,
-                                                ..
-                                            } => {
-                                                target_track_id_opt = Some(track.id);
-                                            }
-                                            super::super::utils::flatten::DisplayRow::SourceRow {
-                                                parent_track,
-                                                ..
-                                            } => {
-                                                target_track_id_opt = Some(parent_track.id);
-                                            }
-                                        }
+                            DraggedItem::Asset(_) | DraggedItem::Assets(_) => {
+                                let asset_ids = match dragged_item {
+                                    DraggedItem::Assets(ids) => ids.as_slice(),
+                                    DraggedItem::Asset(id) => std::slice::from_ref(id),
+                                    DraggedItem::Composition(_) => &[],
+                                };
 
-                                        // Calculate Index
-                                        if let Some(tid) = target_track_id_opt {
-                                            if let Some(header_idx) = display_rows.iter().position(|r| r.track_id() == tid && matches!(r, super::super::utils::flatten::DisplayRow::TrackHeader{..})) {
-                                                 let raw_index = visible_row_index as isize - header_idx as isize - 1;
-                                                 if let Some(track) = proj_read.get_track(tid) {
-                                                     let source_count = track.child_ids.iter().filter(|id| matches!(proj_read.get_node(**id), Some(library::project::node::Node::Source(_)))).count();
-                                                     let max_index = source_count as isize;
-                                                     let inverted = max_index - raw_index;
-                                                     calculated_insert_index = Some(inverted.clamp(0, max_index) as usize);
-                                                 }
-                                            }
-                                        }
+                                // Calculate Index
+                                if let Some(tid) = target_track_id_opt {
+                                    if let Some(header_idx) = display_rows.iter().position(|r| r.track_id() == tid && matches!(r, super::super::utils::flatten::DisplayRow::TrackHeader{..})) {
+                                         let raw_index = visible_row_index as isize - header_idx as isize - 1;
+                                         if let Some(track) = proj_read.get_track(tid) {
+                                             let source_count = track.child_ids.iter().filter(|id| matches!(proj_read.get_node(**id), Some(library::project::node::Node::Source(_)))).count();
+                                             let max_index = source_count as isize;
+                                             let inverted = max_index - raw_index;
+                                             calculated_insert_index = Some(inverted.clamp(0, max_index) as usize);
+                                         }
                                     }
+                                }
+
+                                // Several dropped assets are laid end to end from the drop point
+                                let mut in_frame = drop_in_frame;
+                                for asset in asset_ids
+                                    .iter()
+                                    .filter_map(|id| proj_read.assets.iter().find(|a| a.id == *id))
+                                {
                                     let duration_sec = asset.duration.unwrap_or(5.0);
                                     let duration_frames =
                                         (duration_sec * composition_fps).round() as u64;
-                                    let drop_out = drop_in_frame + duration_frames;
-                                    drop_out_frame_opt = Some(drop_out);
-
-                                    new_source_opt = match asset.kind {
-                                        AssetKind::Video => {
-                                            let video_source_res = project_service
-                                                .build_video_source(
-                                                    Some(asset.id),
-                                                    &asset.path,
-                                                    drop_in_frame,
-                                                    drop_out,
-                                                    0,
-                                                    duration_frames,
-                                                    asset.fps.unwrap_or(30.0),
-                                                    comp_width as u32,
-                                                    comp_height as u32,
-                                                );
-                                            video_source_res.ok().map(|mut video_source| {
-                                                if let (Some(w), Some(h)) = (asset.width, asset.height)
-                                                {
-                                                    video_source.properties.set(
-                                                        "anchor".to_string(),
-                                                        library::project::property::Property::constant(
-                                                            library::project::property::PropertyValue::Vec2(
-                                                                library::project::property::Vec2 {
-                                                                    x: ordered_float::OrderedFloat(w as f64 / 2.0),
-                                                                    y: ordered_float::OrderedFloat(h as f64 / 2.0),
-                                                                },
-                                                            ),
-                                                        ),
-                                                    );
-                                                }
-                                                video_source
-                                            })
-                                        }
-                                        AssetKind::Image => {
-                                            let image_source_res = project_service
-                                                .build_image_source(
-                                                    Some(asset.id),
-                                                    &asset.path,
-                                                    drop_in_frame,
-                                                    drop_out,
-                                                    comp_width as u32,
-                                                    comp_height as u32,
-                                                    composition_fps,
-                                                );
-                                            image_source_res.ok().map(|mut image_source| {
-                                                image_source.source_begin_frame = 0;
-                                                if let (Some(w), Some(h)) = (asset.width, asset.height)
-                                                {
-                                                    image_source.properties.set(
-                                                        "anchor".to_string(),
-                                                        library::project::property::Property::constant(
-                                                            library::project::property::PropertyValue::Vec2(
-                                                                library::project::property::Vec2 {
-                                                                    x: ordered_float::OrderedFloat(w as f64 / 2.0),
-                                                                    y: ordered_float::OrderedFloat(h as f64 / 2.0),
-                                                                },
-                                                            ),
-                                                        ),
-                                                    );
-                                                }
-                                                image_source
-                                            })
-                                        }
-                                        AssetKind::Audio => {
-                                            Some(project_service.build_audio_source(
-                                                Some(asset.id),
-                                                &asset.path,
-                                                drop_in_frame,
-                                                drop_out,
-                                                0,
-                                                duration_frames,
-                                                composition_fps,
-                                            ))
-                                        }
-                                        _ => None,
-                                    };
+                                    let out_frame = in_frame + duration_frames;
+                                    if let Some(source) = build_asset_source(
+                                        project_service,
+                                        asset,
+                                        in_frame,
+                                        out_frame,
+                                        duration_frames,
+                                        (comp_width as u32, comp_height as u32),
+                                        composition_fps,
+                                    ) {
+                                        clips.push(NewClipSpec {
+                                            source,
+                                            in_frame,
+                                            out_frame,
+                                            insert_index: calculated_insert_index,
+                                        });
+                                        in_frame = out_frame;
+                                    }
                                 }
                             }
                             DraggedItem::Composition(target_comp_id) => {
@@ -221,7 +154,6 @@ pub(super) fn handle_drag_and_drop(
                                 let duration_frames =
                                     (duration_sec * composition_fps).round() as u64;
                                 let drop_out = drop_in_frame + duration_frames;
-                                drop_out_frame_opt = Some(drop_out);
 
                                 let mut comp_entity = SourceData::new(
                                     Uuid::new_v4(),
@@ -241,26 +173,24 @@ pub(super) fn handle_drag_and_drop(
                                     "composition_id",
                                     PropertyValue::String(target_comp_id.to_string()),
                                 );
-                                new_source_opt = Some(comp_entity);
+                                clips.push(NewClipSpec {
+                                    source: comp_entity,
+                                    in_frame: drop_in_frame,
+                                    out_frame: drop_out,
+                                    insert_index: None,
+                                });
                             }
                         }
                     } // proj_read is now dropped
 
                     // ===== PHASE 2: Call service methods (needs write lock) =====
-                    if let (Some(new_source), Some(_drop_out)) =
-                        (new_source_opt, drop_out_frame_opt)
-                    {
+                    if !clips.is_empty() {
                         let mut success = false;
 
                         if let Some(parent_track_id) = target_track_id_opt {
-                            if let Err(e) = project_service.add_layer_to_track(
-                                comp_id,
-                                parent_track_id,
-                                new_source,
-                                drop_in_frame,
-                                drop_in_frame + (drop_out_frame_opt.unwrap() - drop_in_frame),
-                                calculated_insert_index,
-                            ) {
+                            if let Err(e) =
+                                project_service.batch_add_clips(comp_id, parent_track_id, clips)
+                            {
                                 log::error!("Failed to add layer: {:?}", e);
                                 editor_context.interaction.general.active_modal_error =
                                     Some(e.to_string());
@@ -275,14 +205,9 @@ pub(super) fn handle_drag_and_drop(
                             if let Ok(new_track_id) =
                                 project_service.add_track(comp_id, "New Track")
                             {
-                                if let Err(e) = project_service.add_layer_to_track(
-                                    comp_id,
-                                    new_track_id,
-                                    new_source,
-                                    drop_in_frame,
-                                    drop_in_frame + (drop_out_frame_opt.unwrap() - drop_in_frame),
-                                    calculated_insert_index,
-                                ) {
+                                if let Err(e) =
+                                    project_service.batch_add_clips(comp_id, new_track_id, clips)
+                                {
                                     log::error!("Failed to add layer to new track: {:?}", e);
                                     project_service.remove_track(comp_id, new_track_id).ok();
                                 } else {
@@ -302,3 +227,74 @@ pub(super) fn handle_drag_and_drop(
         }
     }
 }
+
+/// Build the source for a clip of `asset` spanning `in_frame..out_frame`.
+/// Returns `None` for asset kinds that can't be placed on the timeline.
+fn build_asset_source(
+    project_service: &ProjectService,
+    asset: &Asset,
+    in_frame: u64,
+    out_frame: u64,
+    duration_frames: u64,
+    (comp_width, comp_height): (u32, u32),
+    composition_fps: f64,
+) -> Option<SourceData> {
+    // Center the anchor on the media so transforms pivot around its middle
+    let set_anchor = |source: &mut SourceData| {
+        if let (Some(w), Some(h)) = (asset.width, asset.height) {
+            source.properties.set(
+                "anchor".to_string(),
+                Property::constant(PropertyValue::Vec2(Vec2 {
+                    x: OrderedFloat(w as f64 / 2.0),
+                    y: OrderedFloat(h as f64 / 2.0),
+                })),
+            );
+        }
+    };
+
+    match asset.kind {
+        AssetKind::Video => {
+            let mut source = project_service
+                .build_video_source(
+                    Some(asset.id),
+                    &asset.path,
+                    in_frame,
+                    out_frame,
+                    0,
+                    duration_frames,
+                    asset.fps.unwrap_or(30.0),
+                    comp_width,
+                    comp_height,
+                )
+                .ok()?;
+            set_anchor(&mut source);
+            Some(source)
+        }
+        AssetKind::Image => {
+            let mut source = project_service
+                .build_image_source(
+                    Some(asset.id),
+                    &asset.path,
+                    in_frame,
+                    out_frame,
+                    comp_width,
+                    comp_height,
+                    composition_fps,
+                )
+                .ok()?;
+            source.source_begin_frame = 0;
+            set_anchor(&mut source);
+            Some(source)
+        }
+        AssetKind::Audio => Some(project_service.build_audio_source(
+            Some(asset.id),
+            &asset.path,
+            in_frame,
+            out_frame,
+            0,
+            duration_frames,
+            composition_fps,
+        )),
+        _ => None,
+    }
+}
@@ -11,24 +11,52 @@ use crate::context::context::EditorContext;
 
 use super::super::geometry::TimelineGeometry;
 use super::super::utils::flatten::DisplayRow;
-use super::layers::{calculate_insert_index, calculate_layer_rect, draw_waveform};
+use super::layers::{
+    calculate_insert_index, calculate_layer_rect, collect_descendant_sources, draw_waveform,
+};
 
 const EDGE_DRAG_WIDTH: f32 = 5.0;
 
+/// Waveform peaks requested per clip at most, however wide it is drawn.
+const MAX_WAVEFORM_PEAKS: usize = 8192;
+
+/// Preset swatches offered by "Set Label Color".
+const LABEL_COLORS: [(&str, [u8; 3]); 7] = [
+    ("Red", [230, 70, 70]),
+    ("Orange", [240, 140, 50]),
+    ("Yellow", [230, 200, 60]),
+    ("Green", [90, 190, 90]),
+    ("Teal", [60, 180, 170]),
+    ("Blue", [70, 120, 220]),
+    ("Purple", [160, 100, 220]),
+];
+
 // ── Pure frame-calculation helpers (testable without UI) ──
 
 /// Compute the maximum allowed out_frame based on source media duration.
-/// Returns `u64::MAX` when the source has no known duration (infinite extension).
+/// `speed` is the clip's playback rate: faster clips run out of media sooner,
+/// reversed clips play back towards the media start.
+/// Returns `u64::MAX` when the source has no known duration (infinite extension)
+/// or is frozen on one frame (`speed == 0`).
 pub(super) fn compute_source_max_out_frame(
     in_frame: u64,
     out_frame: u64,
     source_begin_frame: i64,
     duration_frame: Option<u64>,
+    speed: f64,
 ) -> u64 {
     if let Some(duration) = duration_frame {
-        let source_end_offset = duration as i64 - source_begin_frame;
+        let source_end_offset = if speed < 0.0 {
+            source_begin_frame
+        } else {
+            duration as i64 - source_begin_frame
+        };
         if source_end_offset > 0 {
-            in_frame.saturating_add(source_end_offset as u64)
+            if speed == 0.0 {
+                return u64::MAX;
+            }
+            let clip_frames = (source_end_offset as f64 / speed.abs()).floor() as u64;
+            in_frame.saturating_add(clip_frames)
         } else {
             // source_begin_frame >= duration — should not normally happen, but
             // fall back to the current out_frame so the layer stays valid.
@@ -65,6 +93,63 @@ pub(super) fn compute_drag_frames(in_frame: u64, out_frame: u64, dt_frames: i64)
     (new_in_frame, new_out_frame)
 }
 
+/// Snap a clip spanning `in_frame..out_frame` so that the edge nearest to a
+/// candidate frame lands on it, if within `threshold_frames`.
+/// Returns the frame offset to apply and the candidate snapped to.
+pub(super) fn compute_snap(
+    in_frame: u64,
+    out_frame: u64,
+    candidates: &[u64],
+    threshold_frames: f64,
+) -> Option<(i64, u64)> {
+    candidates
+        .iter()
+        .flat_map(|&frame| {
+            [
+                (frame as i64 - in_frame as i64, frame),
+                (frame as i64 - out_frame as i64, frame),
+            ]
+        })
+        .filter(|(offset, _)| {
+            (*offset as f64).abs() <= threshold_frames && in_frame as i64 + offset >= 0
+        })
+        .min_by_key(|(offset, _)| offset.abs())
+}
+
+/// Compute new (in_frame, out_frame, source_begin_frame) when trimming a composition clip.
+///
+/// The left edge moves `source_begin_frame` along with `in_frame` so the nested
+/// content stays put on the timeline. Both edges stop at the bounds of the
+/// referenced composition (`source_total_frames` frames at `source_fps`).
+#[allow(clippy::too_many_arguments)]
+pub(super) fn compute_composition_trim(
+    in_frame: u64,
+    out_frame: u64,
+    source_begin_frame: i64,
+    dt_frames: i64,
+    from_left: bool,
+    composition_fps: f64,
+    source_fps: f64,
+    source_total_frames: u64,
+) -> (u64, u64, i64) {
+    // Source frames per composition frame
+    let ratio = source_fps / composition_fps;
+    if from_left {
+        let earliest_in = (in_frame as f64 - source_begin_frame.max(0) as f64 / ratio)
+            .ceil()
+            .max(0.0) as u64;
+        let new_in = compute_resize_left_frame(in_frame, out_frame, dt_frames).max(earliest_in);
+        let new_begin =
+            source_begin_frame + ((new_in as f64 - in_frame as f64) * ratio).round() as i64;
+        (new_in, out_frame, new_begin.max(0))
+    } else {
+        let remaining = (source_total_frames as i64 - source_begin_frame).max(0) as f64 / ratio;
+        let max_out = in_frame + remaining.floor() as u64;
+        let new_out = compute_resize_right_frame(in_frame, out_frame, dt_frames, max_out);
+        (in_frame, new_out, source_begin_frame)
+    }
+}
+
 /// Deferred actions collected during UI phase, executed after read lock is released
 #[derive(Debug)]
 pub(super) enum DeferredLayerAction {
@@ -73,6 +158,17 @@ pub(super) enum DeferredLayerAction {
         source_id: Uuid,
         new_in_frame: u64,
         new_out_frame: u64,
+        ripple: Option<RippleEdit>,
+    },
+    /// Trim a composition clip (resize that also shifts its source window)
+    TrimCompositionClip {
+        comp_id: Uuid,
+        track_id: Uuid,
+        source_id: Uuid,
+        new_in_frame: u64,
+        new_out_frame: u64,
+        new_source_begin: i64,
+        ripple: Option<RippleEdit>,
     },
     /// Move layer to track at index (reorder/move)
     MoveLayerToTrack {
@@ -85,10 +181,68 @@ pub(super) enum DeferredLayerAction {
     },
     /// Remove layer from track
     RemoveLayer { track_id: Uuid, source_id: Uuid },
+    /// Set or clear a clip's label color
+    SetLabelColor {
+        source_id: Uuid,
+        label_color: Option<[u8; 3]>,
+    },
     /// Push history state after changes
     PushHistory,
 }
 
+/// Shift of the later clips on a track that follows a trim in ripple edit mode.
+/// Applied only once the trim succeeded, by the distance the clip's end actually moved.
+#[derive(Debug)]
+pub(super) struct RippleEdit {
+    pub(super) comp_id: Uuid,
+    pub(super) track_id: Uuid,
+    /// End of the trimmed clip before the trim; clips starting here or later move.
+    pub(super) old_out_frame: u64,
+}
+
+/// The ripple edit to attach to a trim, if ripple edit mode is on.
+fn ripple_edit(
+    editor_context: &EditorContext,
+    comp_id: Uuid,
+    track_id: Uuid,
+    old_out_frame: u64,
+) -> Option<RippleEdit> {
+    editor_context
+        .interaction
+        .timeline
+        .ripple_edit
+        .then_some(RippleEdit {
+            comp_id,
+            track_id,
+            old_out_frame,
+        })
+}
+
+/// Frames dragged clips snap to: the edges of every clip not in `exclude`, and the playhead.
+fn collect_snap_frames(
+    project: &Project,
+    root_track_ids: &[Uuid],
+    exclude: &std::collections::HashSet<Uuid>,
+    playhead_frame: u64,
+) -> Vec<u64> {
+    let mut sources = Vec::new();
+    for track in root_track_ids
+        .iter()
+        .filter_map(|id| project.get_track(*id))
+    {
+        collect_descendant_sources(project, track, &mut sources);
+    }
+    let mut frames: Vec<u64> = sources
+        .iter()
+        .filter(|s| !exclude.contains(&s.id))
+        .flat_map(|s| [s.in_frame, s.out_frame])
+        .chain(std::iter::once(playhead_frame))
+        .collect();
+    frames.sort_unstable();
+    frames.dedup();
+    frames
+}
+
 // Helper to find which track contains a source (looking inside Layer containers)
 pub(super) fn find_track_containing_source(
     project: &Project,
@@ -260,6 +414,33 @@ pub(super) fn draw_single_layer(
                     }
                 }
             }
+
+            ui.menu_button(format!("{} Set Label Color", icons::PALETTE), |ui| {
+                let mut picked = None;
+                ui.horizontal(|ui| {
+                    for (name, [r, g, b]) in LABEL_COLORS {
+                        let swatch = egui::Button::new("")
+                            .fill(egui::Color32::from_rgb(r, g, b))
+                            .selected(source.label_color == Some([r, g, b]))
+                            .min_size(egui::vec2(18.0, 18.0));
+                        if ui.add(swatch).on_hover_text(name).clicked() {
+                            picked = Some(Some([r, g, b]));
+                        }
+                    }
+                });
+                if ui.button("None").clicked() {
+                    picked = Some(None);
+                }
+                if let Some(label_color) = picked {
+                    if label_color != source.label_color {
+                        deferred_actions.push(DeferredLayerAction::SetLabelColor {
+                            source_id: source.id,
+                            label_color,
+                        });
+                    }
+                    ui.close();
+                }
+            });
         });
     }
 
@@ -315,6 +496,7 @@ pub(super) fn draw_single_layer(
                 source.out_frame,
                 source.source_begin_frame,
                 source.duration_frame,
+                source.speed,
             );
 
             let delta_x = if left.dragged() {
@@ -328,7 +510,44 @@ pub(super) fn draw_single_layer(
             let dt_frames_f32 = delta_x / pixels_per_unit * composition_fps as f32;
             let dt_frames = dt_frames_f32.round() as i64;
 
-            if left.dragged() {
+            let referenced_comp = source
+                .reference_id
+                .filter(|_| source.kind == SourceKind::Composition)
+                .and_then(|id| project.get_composition(id));
+            if let Some(referenced) = referenced_comp {
+                if left.dragged() || right.dragged() {
+                    let (trim_in, trim_out, trim_begin) = compute_composition_trim(
+                        source.in_frame,
+                        source.out_frame,
+                        source.source_begin_frame,
+                        dt_frames,
+                        left.dragged(),
+                        composition_fps,
+                        referenced.fps,
+                        (referenced.duration * referenced.fps).round() as u64,
+                    );
+                    let changed = (trim_in, trim_out, trim_begin)
+                        != (source.in_frame, source.out_frame, source.source_begin_frame);
+                    if let (true, Some(comp_id)) =
+                        (changed, editor_context.selection.composition_id)
+                    {
+                        deferred_actions.push(DeferredLayerAction::TrimCompositionClip {
+                            comp_id,
+                            track_id: track.id,
+                            source_id: source.id,
+                            new_in_frame: trim_in,
+                            new_out_frame: trim_out,
+                            new_source_begin: trim_begin,
+                            ripple: ripple_edit(
+                                editor_context,
+                                comp_id,
+                                track.id,
+                                source.out_frame,
+                            ),
+                        });
+                    }
+                }
+            } else if left.dragged() {
                 new_in_frame = compute_resize_left_frame(new_in_frame, new_out_frame, dt_frames);
             } else if right.dragged() {
                 new_out_frame = compute_resize_right_frame(
@@ -340,7 +559,7 @@ pub(super) fn draw_single_layer(
             }
 
             if new_in_frame != source.in_frame || new_out_frame != source.out_frame {
-                if let (Some(_comp_id), Some(_tid)) = (
+                if let (Some(comp_id), Some(_tid)) = (
                     editor_context.selection.composition_id,
                     editor_context.selection.last_selected_track_id,
                 ) {
@@ -348,6 +567,7 @@ pub(super) fn draw_single_layer(
                         source_id: source.id,
                         new_in_frame,
                         new_out_frame,
+                        ripple: ripple_edit(editor_context, comp_id, track.id, source.out_frame),
                     });
                 }
             }
@@ -392,33 +612,49 @@ pub(super) fn draw_single_layer(
         );
     }
 
+    // Clips of muted (or soloed-out) tracks are dimmed
+    let is_muted = editor_context
+        .selection
+        .composition_id
+        .and_then(|id| project.get_composition(id))
+        .is_some_and(|comp| project.is_track_silenced(comp, track.id));
+    if is_muted {
+        transparent_color = transparent_color.gamma_multiply(0.4);
+    }
+
     let painter = ui_content.painter_at(content_rect);
     painter.rect_filled(drawing_layer_rect, 4.0, transparent_color);
 
     // Draw Audio Waveform
     if (source.kind == SourceKind::Audio || source.kind == SourceKind::Video) && safe_width > 10.0 {
-        if let Some(asset_id) = source.reference_id {
-            let cache = project_service.get_cache_manager();
-            if let Some(audio_data) = cache.get_audio(asset_id) {
-                let sample_rate = project_service
-                    .get_audio_service()
-                    .get_audio_engine()
-                    .get_sample_rate() as f64;
-                let channels = project_service
-                    .get_audio_service()
-                    .get_audio_engine()
-                    .get_channels() as usize;
-                draw_waveform(
-                    &painter,
-                    drawing_layer_rect,
-                    &audio_data,
-                    source.source_begin_frame,
-                    composition_fps,
-                    pixels_per_unit,
-                    sample_rate,
-                    channels,
-                );
+        let asset = source
+            .reference_id
+            .and_then(|id| project.assets.iter().find(|a| a.id == id));
+        if let Some(asset) = asset {
+            let audio_service = project_service.get_audio_service();
+            let cache_manager = project_service.get_cache_manager();
+            let sample_rate = cache_manager
+                .get_audio_sample_rate(asset.id)
+                .unwrap_or_else(|| audio_service.get_audio_engine().get_sample_rate())
+                as f64;
+            let start_time = source.compute_local_time(source.in_frame, composition_fps);
+            let duration =
+                source.timeline_duration_frames() as f64 / composition_fps * source.speed;
+            // Reverse playback reads the source backwards from its start
+            let range_start = start_time + duration.min(0.0);
+            let num_peaks =
+                (drawing_layer_rect.width().round() as usize).clamp(1, MAX_WAVEFORM_PEAKS);
+            let mut peaks = audio_service.get_waveform_peaks(
+                asset.id,
+                cache_manager.get_audio_modified(asset.id),
+                (range_start * sample_rate).round() as i64,
+                (duration.abs() * sample_rate).round() as usize,
+                num_peaks,
+            );
+            if duration < 0.0 {
+                peaks.reverse();
             }
+            draw_waveform(&painter, drawing_layer_rect, &peaks);
         }
     }
 
@@ -436,6 +672,9 @@ pub(super) fn draw_single_layer(
     if is_summary_layer {
         layer_text = format!("(Ref) {}", layer_text);
     }
+    if is_muted {
+        layer_text = format!("{} (muted)", layer_text);
+    }
 
     painter.text(
         drawing_layer_rect.min + egui::vec2(5.0, 5.0),
@@ -500,6 +739,10 @@ pub(super) fn draw_single_layer(
                 .timeline
                 .dragged_entity_hovered_track_id = Some(track.id);
             editor_context.interaction.timeline.dragged_entity_has_moved = false;
+            editor_context
+                .interaction
+                .timeline
+                .dragged_entity_start_frame = Some(source.in_frame);
         }
     }
 
@@ -512,8 +755,45 @@ pub(super) fn draw_single_layer(
             editor_context.interaction.timeline.dragged_entity_has_moved = true;
         }
 
-        let dt_frames_f32 = layer_resp.drag_delta().x / pixels_per_unit * composition_fps as f32;
-        let dt_frames = dt_frames_f32.round() as i64;
+        // Place the clip from the total drag so snapping can let go again
+        let start_frame = editor_context
+            .interaction
+            .timeline
+            .dragged_entity_start_frame
+            .unwrap_or(source.in_frame);
+        let total_dx = layer_resp.total_drag_delta().map_or(0.0, |d| d.x);
+        let moved_frames = (total_dx / pixels_per_unit * composition_fps as f32).round() as i64;
+        let mut target_in = (start_frame as i64 + moved_frames).max(0) as u64;
+        if editor_context.timeline.snap_enabled {
+            let playhead_frame =
+                (editor_context.timeline.current_time as f64 * composition_fps).round() as u64;
+            let candidates = collect_snap_frames(
+                project,
+                root_track_ids,
+                &editor_context.selection.selected_entities,
+                playhead_frame,
+            );
+            let threshold_frames =
+                (editor_context.timeline.snap_threshold / pixels_per_unit) as f64 * composition_fps;
+            let duration = source.out_frame.saturating_sub(source.in_frame);
+            if let Some((offset, frame)) = compute_snap(
+                target_in,
+                target_in + duration,
+                &candidates,
+                threshold_frames,
+            ) {
+                target_in = (target_in as i64 + offset) as u64;
+                let x = content_rect.min.x
+                    + (frame as f64 / composition_fps) as f32 * pixels_per_unit
+                    - editor_context.timeline.scroll_offset.x;
+                ui_content.painter_at(content_rect).vline(
+                    x,
+                    content_rect.y_range(),
+                    egui::Stroke::new(1.5, egui::Color32::from_rgb(255, 160, 40)),
+                );
+            }
+        }
+        let dt_frames = target_in as i64 - source.in_frame as i64;
 
         if dt_frames != 0 {
             if let Some(_comp_id) = editor_context.selection.composition_id {
@@ -538,6 +818,7 @@ pub(super) fn draw_single_layer(
                                 source_id: c.id,
                                 new_in_frame,
                                 new_out_frame,
+                                ripple: None,
                             });
                         }
                     }
@@ -658,6 +939,10 @@ pub(super) fn draw_single_layer(
             .interaction
             .timeline
             .dragged_entity_hovered_track_id = None;
+        editor_context
+            .interaction
+            .timeline
+            .dragged_entity_start_frame = None;
     }
 }
 
@@ -673,39 +958,98 @@ mod tests {
 
     #[test]
     fn source_max_no_duration_returns_max() {
-        let result = compute_source_max_out_frame(10, 50, 0, None);
+        let result = compute_source_max_out_frame(10, 50, 0, None, 1.0);
         assert_eq!(result, u64::MAX);
     }
 
     #[test]
     fn source_max_with_duration_computes_correctly() {
         // duration=100 frames, source_begin=0, in_frame=10 → max out = 10+100 = 110
-        let result = compute_source_max_out_frame(10, 50, 0, Some(100));
+        let result = compute_source_max_out_frame(10, 50, 0, Some(100), 1.0);
         assert_eq!(result, 110);
     }
 
     #[test]
     fn source_max_with_offset_begin() {
         // duration=100, source_begin=30, in_frame=10 → remaining=70, max out = 10+70 = 80
-        let result = compute_source_max_out_frame(10, 50, 30, Some(100));
+        let result = compute_source_max_out_frame(10, 50, 30, Some(100), 1.0);
         assert_eq!(result, 80);
     }
 
     #[test]
     fn source_max_begin_exceeds_duration_returns_out_frame() {
         // BUG FIX: source_begin_frame >= duration_frame should return out_frame, not in_frame
-        let result = compute_source_max_out_frame(10, 50, 120, Some(100));
+        let result = compute_source_max_out_frame(10, 50, 120, Some(100), 1.0);
         assert_eq!(result, 50); // Should be out_frame (50), not in_frame (10)
     }
 
     #[test]
     fn source_max_begin_equals_duration_returns_out_frame() {
-        let result = compute_source_max_out_frame(10, 50, 100, Some(100));
+        let result = compute_source_max_out_frame(10, 50, 100, Some(100), 1.0);
         assert_eq!(result, 50); // offset = 0, falls into <= 0 branch
     }
 
+    #[test]
+    fn source_max_scales_with_speed() {
+        // remaining=70 at 2x lasts 35 frames, at 0.5x lasts 140 frames
+        assert_eq!(compute_source_max_out_frame(10, 50, 30, Some(100), 2.0), 45);
+        assert_eq!(
+            compute_source_max_out_frame(10, 50, 30, Some(100), 0.5),
+            150
+        );
+    }
+
+    #[test]
+    fn source_max_reverse_and_frozen_clips() {
+        // Reversed from source frame 30 only 30 frames remain; a frozen clip never runs out
+        assert_eq!(
+            compute_source_max_out_frame(10, 50, 30, Some(100), -1.0),
+            40
+        );
+        assert_eq!(
+            compute_source_max_out_frame(10, 50, 30, Some(100), 0.0),
+            u64::MAX
+        );
+    }
+
     // ── Domain: compute_resize_left_frame ──
 
+    #[test]
+    fn composition_trim_left_advances_source_begin() {
+        // 左端を10フレーム詰めるとソース側の開始も10フレーム進む
+        assert_eq!(
+            compute_composition_trim(100, 200, 0, 10, true, 30.0, 30.0, 300),
+            (110, 200, 10)
+        );
+    }
+
+    #[test]
+    fn composition_trim_left_stops_at_source_start() {
+        // ソースの先頭より前には広げられない
+        assert_eq!(
+            compute_composition_trim(100, 200, 5, -20, true, 30.0, 30.0, 300),
+            (95, 200, 0)
+        );
+    }
+
+    #[test]
+    fn composition_trim_left_converts_frame_rates() {
+        // 参照先が60fpsならコンポジションの1フレームはソースの2フレーム
+        assert_eq!(
+            compute_composition_trim(100, 200, 0, 10, true, 30.0, 60.0, 600),
+            (110, 200, 20)
+        );
+    }
+
+    #[test]
+    fn composition_trim_right_clamps_to_source_duration() {
+        // 参照先コンポジションの長さを超えて伸ばせない
+        assert_eq!(
+            compute_composition_trim(100, 200, 50, 500, false, 30.0, 30.0, 300),
+            (100, 350, 50)
+        );
+    }
+
     #[test]
     fn resize_left_shrinks_layer() {
         // Layer at frames 10-50, drag right by 5 → new in_frame = 15
@@ -779,6 +1123,26 @@ mod tests {
         assert_eq!(result, 5); // min(11, 5) = 5 — still clipped by source_max
     }
 
+    // ── Domain: compute_snap ──
+
+    #[test]
+    fn snap_picks_nearest_edge() {
+        // Clip 10-20: in edge is 2 from 8, out edge is 1 from 21 → out edge wins
+        assert_eq!(compute_snap(10, 20, &[8, 21], 3.0), Some((1, 21)));
+    }
+
+    #[test]
+    fn snap_ignores_candidates_beyond_threshold() {
+        assert_eq!(compute_snap(10, 20, &[0, 30], 3.0), None);
+    }
+
+    #[test]
+    fn snap_never_moves_before_zero() {
+        // Snapping the out edge to 2 would put the in edge at -8
+        assert_eq!(compute_snap(0, 10, &[2], 10.0), Some((2, 2)));
+        assert_eq!(compute_snap(1, 11, &[2], 10.0), Some((1, 2)));
+    }
+
     // ── Domain: compute_drag_frames ──
 
     #[test]
@@ -956,7 +1320,7 @@ mod tests {
     fn source_max_negative_begin_frame() {
         // source_begin_frame can be negative (trimmed start before 0)
         // duration=100, source_begin=-10 → offset=110, max = 10+110=120
-        let result = compute_source_max_out_frame(10, 50, -10, Some(100));
+        let result = compute_source_max_out_frame(10, 50, -10, Some(100), 1.0);
         assert_eq!(result, 120);
     }
 
@@ -11,7 +11,7 @@ use crate::{command::history::HistoryManager, context::context::EditorContext};
 
 use super::super::geometry::TimelineGeometry;
 use super::super::utils::flatten::{flatten_tracks_to_rows, DisplayRow};
-use super::layer_interaction::{draw_single_layer, DeferredLayerAction};
+use super::layer_interaction::{draw_single_layer, DeferredLayerAction, RippleEdit};
 
 pub(super) fn calculate_layer_rect(
     in_frame: u64,
@@ -37,60 +37,29 @@ pub(super) fn calculate_layer_rect(
     )
 }
 
-pub(super) fn draw_waveform(
-    painter: &egui::Painter,
-    clip_rect: egui::Rect,
-    audio_data: &[f32],
-    source_begin_frame: i64,
-    composition_fps: f64,
-    pixels_per_unit: f32,
-    sample_rate: f64,
-    channels: usize,
-) {
-    let rect_w = clip_rect.width();
-    let rect_h = clip_rect.height();
+/// Draw `(min, max)` peaks spread evenly across `clip_rect`. Peaks outside
+/// the painter's clip rect are skipped.
+pub(super) fn draw_waveform(painter: &egui::Painter, clip_rect: egui::Rect, peaks: &[(f32, f32)]) {
+    if peaks.is_empty() {
+        return;
+    }
     let center_y = clip_rect.center().y;
-    let max_amp_height = rect_h * 0.4;
-
-    let samples_per_pixel = (sample_rate / pixels_per_unit as f64) * channels as f64;
-    let step_width = if samples_per_pixel > 1000.0 { 2.0 } else { 1.0 };
-    let mut x = 0.0;
-
-    while x < rect_w {
-        let _time_offset = x as f32 / pixels_per_unit;
-        let source_time = (source_begin_frame as f64 / composition_fps) + _time_offset as f64;
-        let start_sample_idx = if source_time >= 0.0 {
-            (source_time * sample_rate) as usize * channels
-        } else {
-            audio_data.len() + 1
-        };
-        let end_sample_idx = start_sample_idx + samples_per_pixel as usize;
-
-        if start_sample_idx < audio_data.len() {
-            let end = end_sample_idx.min(audio_data.len());
-            let mut max_amp = 0.0f32;
-            let stride = if end - start_sample_idx > 100 { 10 } else { 1 };
-
-            for i in (start_sample_idx..end).step_by(stride) {
-                let abs_val = audio_data[i].abs();
-                if abs_val > max_amp {
-                    max_amp = abs_val;
-                }
-            }
-
-            if max_amp > 0.0 {
-                let height = (max_amp * max_amp_height as f32).max(1.0);
-                let x_pos = clip_rect.min.x + x;
-                painter.line_segment(
-                    [
-                        egui::pos2(x_pos, center_y - height),
-                        egui::pos2(x_pos, center_y + height),
-                    ],
-                    egui::Stroke::new(1.0, egui::Color32::from_rgba_premultiplied(0, 0, 0, 100)),
-                );
-            }
+    let max_amp_height = clip_rect.height() * 0.4;
+    let step = clip_rect.width() / peaks.len() as f32;
+    let visible = painter.clip_rect();
+    let stroke = egui::Stroke::new(1.0, egui::Color32::from_rgba_premultiplied(0, 0, 0, 100));
+
+    for (i, &(min, max)) in peaks.iter().enumerate() {
+        if min == 0.0 && max == 0.0 {
+            continue;
+        }
+        let x = clip_rect.min.x + (i as f32 + 0.5) * step;
+        if x < visible.min.x || x > visible.max.x {
+            continue;
         }
-        x += step_width;
+        let top = center_y - max.clamp(-1.0, 1.0) * max_amp_height;
+        let bottom = (center_y - min.clamp(-1.0, 1.0) * max_amp_height).max(top + 1.0);
+        painter.line_segment([egui::pos2(x, top), egui::pos2(x, bottom)], stroke);
     }
 }
 
@@ -161,6 +130,29 @@ pub(in crate::panels::timeline) fn calculate_insert_index(
     None
 }
 
+/// After a successful trim, shift the later clips by how far the clip's end actually moved.
+fn apply_ripple_edit(
+    project_service: &ProjectService,
+    source_id: Uuid,
+    ripple: Option<RippleEdit>,
+) {
+    let Some(ripple) = ripple else {
+        return;
+    };
+    let new_out_frame =
+        project_service.with_project(|p| p.get_source(source_id).map(|s| s.out_frame));
+    if let Some(new_out_frame) = new_out_frame.filter(|f| *f != ripple.old_out_frame) {
+        if let Err(e) = project_service.ripple_shift(
+            ripple.comp_id,
+            ripple.track_id,
+            ripple.old_out_frame,
+            new_out_frame as i64 - ripple.old_out_frame as i64,
+        ) {
+            log::error!("Failed to ripple edit: {:?}", e);
+        }
+    }
+}
+
 pub(super) fn draw_layers(
     ui_content: &mut Ui,
     content_rect: egui::Rect,
@@ -367,11 +359,30 @@ pub(super) fn draw_layers(
                 source_id,
                 new_in_frame,
                 new_out_frame,
+                ripple,
+            } => match project_service.update_source_time(source_id, new_in_frame, new_out_frame) {
+                Ok(()) => apply_ripple_edit(project_service, source_id, ripple),
+                Err(e) => log::error!("Failed to update source time: {:?}", e),
+            },
+            DeferredLayerAction::TrimCompositionClip {
+                comp_id,
+                track_id,
+                source_id,
+                new_in_frame,
+                new_out_frame,
+                new_source_begin,
+                ripple,
             } => {
-                if let Err(e) =
-                    project_service.update_source_time(source_id, new_in_frame, new_out_frame)
-                {
-                    log::error!("Failed to update source time: {:?}", e);
+                match project_service.trim_composition_clip(
+                    comp_id,
+                    track_id,
+                    source_id,
+                    new_in_frame,
+                    new_out_frame,
+                    new_source_begin,
+                ) {
+                    Ok(()) => apply_ripple_edit(project_service, source_id, ripple),
+                    Err(e) => log::error!("Failed to trim composition clip: {:?}", e),
                 }
             }
             DeferredLayerAction::MoveLayerToTrack {
@@ -416,6 +427,16 @@ pub(super) fn draw_layers(
                     needs_history_push = true;
                 }
             }
+            DeferredLayerAction::SetLabelColor {
+                source_id,
+                label_color,
+            } => {
+                if let Err(e) = project_service.set_source_label_color(source_id, label_color) {
+                    log::error!("Failed to set label color: {:?}", e);
+                } else {
+                    needs_history_push = true;
+                }
+            }
             DeferredLayerAction::PushHistory => {
                 needs_history_push = true;
             }
@@ -62,6 +62,16 @@ pub(super) fn show_layer_area(
         ui_content.allocate_at_least(ui_content.available_size(), egui::Sense::hover());
 
     let is_dragging_item = editor_context.interaction.timeline.dragged_item.is_some();
+
+    // S toggles snapping while the pointer is over the clips
+    let pointer_inside = response.contains_pointer();
+    editor_context.interaction.timeline.pointer_in_layer_area = pointer_inside;
+    if pointer_inside
+        && !ui_content.ctx().wants_keyboard_input()
+        && ui_content.input(|i| i.modifiers.is_none() && i.key_pressed(egui::Key::S))
+    {
+        editor_context.timeline.snap_enabled = !editor_context.timeline.snap_enabled;
+    }
     let selected_composition_id = editor_context.selection.composition_id;
 
     // ===== PHASE 1: Extract owned data from project (scoped read lock) =====
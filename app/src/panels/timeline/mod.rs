@@ -2,6 +2,7 @@ mod controls;
 mod geometry;
 mod layer_area;
 mod ruler;
+mod timecode;
 mod track_list;
 mod utils;
 
@@ -38,6 +39,7 @@ pub(crate) fn timeline_panel(ui: &mut Ui, ctx: &mut PanelContext, registry: &Com
             show_timeline_ruler(
                 ui,
                 ctx.editor_context,
+                ctx.history_manager,
                 ctx.project_service,
                 ctx.project,
                 pixels_per_unit,
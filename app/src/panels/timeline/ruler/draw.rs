@@ -1,3 +1,4 @@
+use super::super::timecode::format_timecode;
 use super::ticks;
 use crate::context::context::EditorContext;
 use egui::{Painter, Rect};
@@ -5,13 +6,18 @@ use egui::{Painter, Rect};
 pub(super) fn draw_ruler_marks(
     painter: &Painter,
     rect: Rect,
-    _editor_context: &EditorContext,
+    editor_context: &EditorContext,
+    work_area: (Option<u64>, Option<u64>),
     scroll_offset_x: f32,
     pixels_per_unit: f32,
     composition_fps: f64,
 ) {
     let is_frame_display_mode = pixels_per_unit / composition_fps as f32 > 10.0;
     let pixels_per_frame = pixels_per_unit / composition_fps as f32;
+    let label =
+        |frame: i64| format_timecode(frame, composition_fps, editor_context.timeline.display_mode);
+
+    draw_work_area(painter, rect, work_area, scroll_offset_x, pixels_per_frame);
 
     if is_frame_display_mode {
         // --- Frame Mode: Iterate by Frames ---
@@ -47,19 +53,15 @@ pub(super) fn draw_ruler_marks(
                         rect.min.y,
                         rect.max.y,
                         egui::Color32::WHITE,
-                        Some(format!("{}s", nearest_sec as i64)),
+                        Some(label(current_frame as i64)),
                     )
                 } else if is_major_frame {
                     // It is a major frame tick (e.g. 10f)
-                    let fps_int = composition_fps.round() as i64;
-                    let max_fps_int = if fps_int > 0 { fps_int } else { 1 };
-                    let frame_disp = current_frame as i64 % max_fps_int;
-
                     (
                         rect.min.y + rect.height() * 0.2, // Slightly shorter than second ticks
                         rect.max.y,
                         egui::Color32::from_gray(200),
-                        Some(format!("{}f", frame_disp)),
+                        Some(label(current_frame as i64)),
                     )
                 } else {
                     // Minor frame tick
@@ -127,20 +129,7 @@ pub(super) fn draw_ruler_marks(
 
                 let (line_start_y, line_end_y, stroke_color, label_text) = if is_major {
                     // Major Tick (Round Seconds)
-                    let sec_int = s.round() as i64;
-                    // Only label as "Xs" if it's close to integer second
-                    let is_whole_sec = (s - s.round()).abs() < 0.001;
-                    let text = if is_whole_sec {
-                        format!("{}s", sec_int)
-                    } else {
-                        // Fallback for weird fractional majors? Should default to frames if needed,
-                        // but nice_time_intervals usually returns clean fractions.
-                        // Display as frames if not whole second
-                        let fps_int = composition_fps.round() as i64;
-                        let max_fps_int = if fps_int > 0 { fps_int } else { 1 };
-                        let f_rem = frame % max_fps_int;
-                        format!("{}f", f_rem)
-                    };
+                    let text = label(frame);
 
                     (rect.min.y, rect.max.y, egui::Color32::WHITE, Some(text))
                 } else {
@@ -173,3 +162,47 @@ pub(super) fn draw_ruler_marks(
         }
     }
 }
+
+/// Highlight the work area and draw a bracket at each end that is set.
+/// An unset end extends the band to the edge of the ruler.
+fn draw_work_area(
+    painter: &Painter,
+    rect: Rect,
+    (work_in, work_out): (Option<u64>, Option<u64>),
+    scroll_offset_x: f32,
+    pixels_per_frame: f32,
+) {
+    if work_in.is_none() && work_out.is_none() {
+        return;
+    }
+    let frame_x = |frame: u64| rect.min.x + frame as f32 * pixels_per_frame - scroll_offset_x;
+    let start_x = work_in.map_or(rect.min.x, frame_x);
+    let end_x = work_out.map_or(rect.max.x, frame_x);
+
+    let color = egui::Color32::from_rgb(110, 170, 255);
+    let band = Rect::from_x_y_ranges(
+        start_x.max(rect.min.x)..=end_x.min(rect.max.x),
+        rect.y_range(),
+    );
+    if band.width() > 0.0 {
+        painter.rect_filled(band, 0.0, color.gamma_multiply(0.15));
+    }
+
+    const BRACKET_WIDTH: f32 = 5.0;
+    let stroke = egui::Stroke::new(2.0, color);
+    // `[` at the in point and `]` at the out point
+    for (x, dir) in [(work_in.map(frame_x), 1.0), (work_out.map(frame_x), -1.0)] {
+        let Some(x) = x.filter(|x| rect.x_range().contains(*x)) else {
+            continue;
+        };
+        painter.line(
+            vec![
+                egui::pos2(x + BRACKET_WIDTH * dir, rect.min.y + 1.0),
+                egui::pos2(x, rect.min.y + 1.0),
+                egui::pos2(x, rect.max.y - 1.0),
+                egui::pos2(x + BRACKET_WIDTH * dir, rect.max.y - 1.0),
+            ],
+            stroke,
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::command::history::HistoryManager;
 use crate::context::context::EditorContext;
 use egui::Ui;
 use library::project::project::Project;
@@ -11,6 +12,7 @@ mod time_input;
 pub(super) fn show_timeline_ruler(
     ui: &mut Ui,
     editor_context: &mut EditorContext,
+    history_manager: &mut HistoryManager,
     project_service: &ProjectService,
     project: &Arc<RwLock<Project>>,
     pixels_per_unit: f32,
@@ -19,10 +21,12 @@ pub(super) fn show_timeline_ruler(
 ) {
     // Get Composition Duration
     let mut current_comp_duration = 300.0; // Default fallback
+    let mut work_area = (None, None);
     if let Some(comp_id) = editor_context.selection.composition_id {
         if let Ok(proj_read) = project.read() {
             if let Some(comp) = proj_read.get_composition(comp_id) {
                 current_comp_duration = comp.duration;
+                work_area = (comp.work_area_in, comp.work_area_out);
             }
         }
     }
@@ -39,7 +43,8 @@ pub(super) fn show_timeline_ruler(
         h_ui.separator();
 
         // --- The actual ruler ---
-        let (rect, response) = h_ui.allocate_at_least(h_ui.available_size(), egui::Sense::drag());
+        let (rect, response) =
+            h_ui.allocate_at_least(h_ui.available_size(), egui::Sense::click_and_drag());
         let painter = h_ui.painter_at(rect); // Painter for the allocated rect within h_ui
         painter.rect_filled(
             rect,
@@ -47,7 +52,15 @@ pub(super) fn show_timeline_ruler(
             h_ui.style().visuals.widgets.noninteractive.bg_fill,
         );
 
-        if response.dragged() && response.dragged_by(egui::PointerButton::Primary) {
+        if response.drag_started_by(egui::PointerButton::Primary) {
+            // Measure the cache hit rate of this scrub gesture only
+            project_service.get_cache_manager().reset_stats();
+        }
+        let modifiers = h_ui.input(|i| i.modifiers);
+        let sets_work_area = modifiers.shift || modifiers.alt;
+        let scrubbing = (response.dragged() && response.dragged_by(egui::PointerButton::Primary))
+            || (response.clicked() && !sets_work_area);
+        if scrubbing {
             if let Some(pos) = response.interact_pointer_pos() {
                 const RULER_INPUT_OFFSET_X: f32 = 0.0;
                 let raw_time = ((pos.x - rect.min.x + scroll_offset_x + RULER_INPUT_OFFSET_X)
@@ -60,10 +73,38 @@ pub(super) fn show_timeline_ruler(
             }
         }
 
+        // Shift+click sets the work area in point, Alt+click the out point
+        if response.clicked() && sets_work_area {
+            if let (Some(comp_id), Some(pos)) = (
+                editor_context.selection.composition_id,
+                response.interact_pointer_pos(),
+            ) {
+                let time = ((pos.x - rect.min.x + scroll_offset_x) / pixels_per_unit).max(0.0);
+                let max_frame = (current_comp_duration * composition_fps).ceil() as u64;
+                let frame = ((time as f64 * composition_fps).round() as u64).min(max_frame);
+                let (work_in, work_out) = if modifiers.shift {
+                    // An in point at or past the out point drops the out point
+                    (Some(frame), work_area.1.filter(|&out| out > frame))
+                } else {
+                    (work_area.0.filter(|&start| start < frame), Some(frame))
+                };
+                match project_service.set_work_area(comp_id, work_in, work_out) {
+                    Ok(()) => {
+                        work_area = (work_in, work_out);
+                        if let Ok(proj) = project.read() {
+                            history_manager.push_project_state(proj.clone());
+                        }
+                    }
+                    Err(e) => log::warn!("Failed to set work area: {}", e),
+                }
+            }
+        }
+
         draw::draw_ruler_marks(
             &painter,
             rect,
             editor_context,
+            work_area,
             scroll_offset_x,
             pixels_per_unit,
             composition_fps,
@@ -0,0 +1,196 @@
+use crate::types::TimecodeFormat;
+
+/// Whole frames per second used for SMPTE and frame-remainder display.
+fn frames_per_second(fps: f64) -> i64 {
+    (fps.round() as i64).max(1)
+}
+
+/// Format a frame number in `format`. Negative frames are shown as 0.
+pub(super) fn format_timecode(frame: i64, fps: f64, format: TimecodeFormat) -> String {
+    let frame = frame.max(0);
+    let fps_int = frames_per_second(fps);
+    let (seconds_total, frames) = (frame / fps_int, frame % fps_int);
+    let (hours, minutes, seconds) = (
+        seconds_total / 3600,
+        seconds_total / 60 % 60,
+        seconds_total % 60,
+    );
+    match format {
+        TimecodeFormat::Frames => frame.to_string(),
+        TimecodeFormat::Smpte => {
+            format!("{:02}:{:02}:{:02}:{:02}", hours, minutes, seconds, frames)
+        }
+        TimecodeFormat::Seconds => format!("{:.1}s", frame as f64 / fps),
+        TimecodeFormat::HmsFrames => {
+            if hours > 0 {
+                format!("{}h {}m {}s {}f", hours, minutes, seconds, frames)
+            } else if minutes > 0 {
+                format!("{}m {}s {}f", minutes, seconds, frames)
+            } else {
+                format!("{}s {}f", seconds, frames)
+            }
+        }
+    }
+}
+
+/// Parse text typed in `format` into a frame number.
+///
+/// Besides the displayed form, SMPTE accepts fewer fields (`14:00` is 14
+/// seconds), Seconds accepts `MM:SS.ss`, and H:M:S + Frames accepts any subset
+/// of units in order (`2s`, `1m 5f`).
+pub(super) fn parse_timecode(input: &str, fps: f64, format: TimecodeFormat) -> Option<i64> {
+    let input = input.trim();
+    let fps_int = frames_per_second(fps);
+    let frame = match format {
+        TimecodeFormat::Frames => input.trim_end_matches('f').trim().parse::<i64>().ok()?,
+        TimecodeFormat::Smpte => {
+            let fields = input
+                .split([':', ';'])
+                .map(|f| f.trim().parse::<i64>().ok())
+                .collect::<Option<Vec<_>>>()?;
+            if fields.is_empty() || fields.len() > 4 {
+                return None;
+            }
+            // Fields are right-aligned: the last one is always frames
+            let scales = [fps_int * 3600, fps_int * 60, fps_int, 1];
+            fields
+                .iter()
+                .rev()
+                .zip(scales.iter().rev())
+                .map(|(value, scale)| value * scale)
+                .sum()
+        }
+        TimecodeFormat::Seconds => {
+            let text = input.trim_end_matches('s').trim();
+            let seconds = match text.split_once(':') {
+                Some((minutes, seconds)) => {
+                    minutes.trim().parse::<f64>().ok()? * 60.0
+                        + seconds.trim().parse::<f64>().ok()?
+                }
+                None => text.parse::<f64>().ok()?,
+            };
+            (seconds * fps).round() as i64
+        }
+        TimecodeFormat::HmsFrames => {
+            let mut total = 0;
+            let mut digits = String::new();
+            let mut any_unit = false;
+            for c in input.chars() {
+                if c.is_ascii_digit() {
+                    digits.push(c);
+                    continue;
+                }
+                if c.is_whitespace() {
+                    continue;
+                }
+                let scale = match c {
+                    'h' => fps_int * 3600,
+                    'm' => fps_int * 60,
+                    's' => fps_int,
+                    'f' => 1,
+                    _ => return None,
+                };
+                total += digits.parse::<i64>().ok()? * scale;
+                digits.clear();
+                any_unit = true;
+            }
+            if !digits.is_empty() || !any_unit {
+                return None;
+            }
+            total
+        }
+    };
+    (frame >= 0).then_some(frame)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ── Domain: Format ──
+
+    #[test]
+    fn formats_each_style() {
+        let frame = 14 * 30;
+        assert_eq!(format_timecode(frame, 30.0, TimecodeFormat::Frames), "420");
+        assert_eq!(
+            format_timecode(frame, 30.0, TimecodeFormat::Smpte),
+            "00:00:14:00"
+        );
+        assert_eq!(
+            format_timecode(frame, 30.0, TimecodeFormat::Seconds),
+            "14.0s"
+        );
+        assert_eq!(
+            format_timecode(frame + 5, 30.0, TimecodeFormat::HmsFrames),
+            "14s 5f"
+        );
+    }
+
+    #[test]
+    fn smpte_rolls_over_into_minutes_and_hours() {
+        let frame = (3600 + 62) * 24 + 7;
+        assert_eq!(
+            format_timecode(frame, 24.0, TimecodeFormat::Smpte),
+            "01:01:02:07"
+        );
+        assert_eq!(
+            format_timecode(frame, 24.0, TimecodeFormat::HmsFrames),
+            "1h 1m 2s 7f"
+        );
+    }
+
+    // ── Domain: Parse ──
+
+    #[test]
+    fn parses_displayed_text_back() {
+        for format in TimecodeFormat::ALL {
+            for frame in [0, 29, 420, 108_015] {
+                let text = format_timecode(frame, 30.0, format);
+                let expected = if format == TimecodeFormat::Seconds {
+                    // One decimal place loses sub-0.1s precision
+                    (frame as f64 / 3.0).round() as i64 * 3
+                } else {
+                    frame
+                };
+                assert_eq!(
+                    parse_timecode(&text, 30.0, format),
+                    Some(expected),
+                    "{text}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn parses_shorthand_input() {
+        assert_eq!(
+            parse_timecode("14:00", 30.0, TimecodeFormat::Smpte),
+            Some(420)
+        );
+        assert_eq!(
+            parse_timecode("01:30.5", 30.0, TimecodeFormat::Seconds),
+            Some(2715)
+        );
+        assert_eq!(
+            parse_timecode("1m 5f", 30.0, TimecodeFormat::HmsFrames),
+            Some(1805)
+        );
+        assert_eq!(
+            parse_timecode("12f", 30.0, TimecodeFormat::Frames),
+            Some(12)
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        assert_eq!(parse_timecode("", 30.0, TimecodeFormat::Smpte), None);
+        assert_eq!(
+            parse_timecode("1:2:3:4:5", 30.0, TimecodeFormat::Smpte),
+            None
+        );
+        assert_eq!(parse_timecode("12", 30.0, TimecodeFormat::HmsFrames), None);
+        assert_eq!(parse_timecode("3x", 30.0, TimecodeFormat::HmsFrames), None);
+        assert_eq!(parse_timecode("-5", 30.0, TimecodeFormat::Frames), None);
+    }
+}
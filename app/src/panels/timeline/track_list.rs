@@ -28,6 +28,14 @@ enum DeferredTrackAction {
         track_id: Uuid,
         new_name: String,
     },
+    SetMuted {
+        track_id: Uuid,
+        muted: bool,
+    },
+    SetSoloed {
+        track_id: Uuid,
+        soloed: bool,
+    },
 }
 
 pub(super) fn show_track_list(
@@ -325,6 +333,62 @@ pub(super) fn show_track_list(
                 );
                 text_offset_x += 16.0;
 
+                // Mute / solo toggles at the right edge of the row
+                for (i, (label, is_on, on_color)) in [
+                    ("S", track.soloed, egui::Color32::from_rgb(220, 190, 60)),
+                    ("M", track.muted, egui::Color32::from_rgb(220, 110, 60)),
+                ]
+                .into_iter()
+                .enumerate()
+                {
+                    let button_rect = egui::Rect::from_center_size(
+                        egui::pos2(row_rect.max.x - 14.0 - i as f32 * 20.0, row_rect.center().y),
+                        egui::vec2(16.0, 16.0),
+                    );
+                    let button_response = ui_content
+                        .interact(
+                            button_rect,
+                            egui::Id::new(track.id).with(label),
+                            egui::Sense::click(),
+                        )
+                        .on_hover_text(if label == "M" { "Mute" } else { "Solo" });
+                    track_list_painter.rect_filled(
+                        button_rect,
+                        3.0,
+                        if is_on {
+                            on_color
+                        } else if button_response.hovered() {
+                            egui::Color32::from_gray(90)
+                        } else {
+                            egui::Color32::from_gray(70)
+                        },
+                    );
+                    track_list_painter.text(
+                        button_rect.center(),
+                        egui::Align2::CENTER_CENTER,
+                        label,
+                        egui::FontId::monospace(10.0),
+                        if is_on {
+                            egui::Color32::BLACK
+                        } else {
+                            egui::Color32::LIGHT_GRAY
+                        },
+                    );
+                    if button_response.clicked() {
+                        deferred_actions.push(if label == "M" {
+                            DeferredTrackAction::SetMuted {
+                                track_id: track.id,
+                                muted: !is_on,
+                            }
+                        } else {
+                            DeferredTrackAction::SetSoloed {
+                                track_id: track.id,
+                                soloed: !is_on,
+                            }
+                        });
+                    }
+                }
+
                 // Check if this track is being renamed
                 if editor_context.interaction.timeline.renaming_track_id == Some(track.id) {
                     // Draw inline TextEdit for renaming
@@ -483,6 +547,20 @@ pub(super) fn show_track_list(
                     needs_history_push = true;
                 }
             }
+            DeferredTrackAction::SetMuted { track_id, muted } => {
+                if let Err(e) = project_service.set_track_muted(track_id, muted) {
+                    error!("Failed to mute track: {:?}", e);
+                } else {
+                    needs_history_push = true;
+                }
+            }
+            DeferredTrackAction::SetSoloed { track_id, soloed } => {
+                if let Err(e) = project_service.set_track_soloed(track_id, soloed) {
+                    error!("Failed to solo track: {:?}", e);
+                } else {
+                    needs_history_push = true;
+                }
+            }
         }
     }
 
@@ -18,6 +18,17 @@ pub(crate) enum GizmoHandle {
     Left,
     Right,
     Rotation,
+    /// Free move started from the keyboard (`G`).
+    Move,
+    /// Uniform scale around the clip position started from the keyboard (`S`).
+    Scale,
+}
+
+/// Axis lock for keyboard-driven gizmo operations (`G` → `X`, `S` → `Y`, ...).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub(crate) enum GizmoAxis {
+    X,
+    Y,
 }
 
 // --- Drag & Drop ---
@@ -25,6 +36,8 @@ pub(crate) enum GizmoHandle {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) enum DraggedItem {
     Asset(Uuid),
+    /// Several selected assets, in asset panel order.
+    Assets(Vec<Uuid>),
     Composition(Uuid),
 }
 
@@ -99,11 +112,37 @@ pub struct Vec2Def {
 
 // --- Timeline display ---
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-pub(crate) enum TimelineDisplayMode {
-    Seconds,
+/// How times are shown on the ruler, the time field and the controls bar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub(crate) enum TimecodeFormat {
+    /// Frame count, e.g. `420`.
     Frames,
-    SecondsAndFrames,
+    /// `HH:MM:SS:FF`, non-drop-frame.
+    Smpte,
+    /// Decimal seconds, e.g. `14.0s`.
+    #[default]
+    Seconds,
+    /// Units with frames, e.g. `1m 14s 5f`.
+    #[serde(alias = "SecondsAndFrames")]
+    HmsFrames,
+}
+
+impl TimecodeFormat {
+    pub(crate) const ALL: [TimecodeFormat; 4] = [
+        TimecodeFormat::Frames,
+        TimecodeFormat::Smpte,
+        TimecodeFormat::Seconds,
+        TimecodeFormat::HmsFrames,
+    ];
+
+    pub(crate) fn label(&self) -> &'static str {
+        match self {
+            TimecodeFormat::Frames => "Frames",
+            TimecodeFormat::Smpte => "SMPTE",
+            TimecodeFormat::Seconds => "Seconds",
+            TimecodeFormat::HmsFrames => "H:M:S + Frames",
+        }
+    }
 }
 
 // --- Settings ---
@@ -114,8 +153,12 @@ use library::plugin::PluginCategory;
 pub(crate) enum SettingsTab {
     Shortcuts,
     PluginPaths,
+    /// Every plugin with its source and load status.
+    PluginOverview,
     PluginList(PluginCategory, Option<String>),
     Theme,
+    Export,
+    Preview,
 }
 
 // --- Vector Editor State ---
@@ -0,0 +1,133 @@
+use egui::{Pos2, Sense, Shape, Stroke, Ui, Vec2};
+use library::runtime::curve::ColorCurve;
+
+const SIZE: f32 = 128.0;
+const HANDLE_RADIUS: f32 = 4.0;
+/// Minimum input gap kept between neighbouring points while dragging.
+const MIN_GAP: f64 = 0.01;
+
+#[derive(Default)]
+pub struct CurveEditorResponse {
+    /// The curve was modified this frame.
+    pub changed: bool,
+    /// The edit is finished (drag released, point removed or curve reset).
+    pub committed: bool,
+}
+
+/// Small square curve editor.
+///
+/// Drag a point to move it, drag empty space to add one, right-click a point to
+/// remove it and double-click to reset to the identity curve.
+pub fn curve_editor(
+    ui: &mut Ui,
+    id_salt: impl std::hash::Hash,
+    curve: &mut ColorCurve,
+) -> CurveEditorResponse {
+    let (rect, response) = ui.allocate_exact_size(Vec2::splat(SIZE), Sense::click_and_drag());
+    let drag_id = ui.id().with(id_salt).with("curve_drag");
+
+    let to_screen = |(x, y): (f64, f64)| {
+        Pos2::new(
+            rect.left() + x as f32 * rect.width(),
+            rect.bottom() - y as f32 * rect.height(),
+        )
+    };
+    let from_screen = |p: Pos2| {
+        (
+            ((p.x - rect.left()) / rect.width()).clamp(0.0, 1.0) as f64,
+            ((rect.bottom() - p.y) / rect.height()).clamp(0.0, 1.0) as f64,
+        )
+    };
+
+    let mut points = curve.points().to_vec();
+    let hit = |points: &[(f64, f64)], pos: Pos2| {
+        points
+            .iter()
+            .position(|p| to_screen(*p).distance(pos) <= HANDLE_RADIUS * 2.0)
+    };
+    let mut result = CurveEditorResponse::default();
+    let pointer = response.interact_pointer_pos();
+
+    if response.double_clicked() {
+        points = ColorCurve::identity().points().to_vec();
+        result.changed = true;
+        result.committed = true;
+    } else if response.secondary_clicked() {
+        if let Some(index) = pointer.and_then(|pos| hit(&points, pos)) {
+            if points.len() > 2 {
+                points.remove(index);
+                result.changed = true;
+                result.committed = true;
+            }
+        }
+    }
+
+    if response.drag_started() {
+        if let Some(pos) = pointer {
+            let index = hit(&points, pos).unwrap_or_else(|| {
+                let new_point = from_screen(pos);
+                let index = points.partition_point(|p| p.0 < new_point.0);
+                points.insert(index, new_point);
+                result.changed = true;
+                index
+            });
+            ui.data_mut(|d| d.insert_temp(drag_id, index));
+        }
+    }
+    if response.dragged() {
+        let dragging = ui.data(|d| d.get_temp::<usize>(drag_id));
+        if let (Some(pos), Some(index)) = (pointer, dragging) {
+            if index < points.len() {
+                // Keep the point between its neighbours so indices stay stable
+                let (mut x, y) = from_screen(pos);
+                let min = if index > 0 {
+                    points[index - 1].0 + MIN_GAP
+                } else {
+                    0.0
+                };
+                let max = points.get(index + 1).map_or(1.0, |p| p.0 - MIN_GAP);
+                if min <= max {
+                    x = x.clamp(min, max);
+                }
+                points[index] = (x, y);
+                result.changed = true;
+            }
+        }
+    }
+    if response.drag_stopped() {
+        ui.data_mut(|d| d.remove::<usize>(drag_id));
+        result.committed = true;
+    }
+
+    if result.changed {
+        *curve = ColorCurve::new(points);
+    }
+
+    // Drawing
+    let painter = ui.painter_at(rect);
+    let visuals = ui.visuals();
+    painter.rect_filled(rect, 2.0, visuals.extreme_bg_color);
+    let grid = Stroke::new(1.0, visuals.widgets.noninteractive.bg_stroke.color);
+    for i in 1..4 {
+        let t = i as f64 / 4.0;
+        painter.line_segment([to_screen((t, 0.0)), to_screen((t, 1.0))], grid);
+        painter.line_segment([to_screen((0.0, t)), to_screen((1.0, t))], grid);
+    }
+
+    let line_color = visuals.text_color();
+    let samples: Vec<Pos2> = (0..=64)
+        .map(|i| {
+            let x = i as f64 / 64.0;
+            to_screen((x, curve.evaluate(x)))
+        })
+        .collect();
+    painter.add(Shape::line(samples, Stroke::new(1.5, line_color)));
+    for point in curve.points() {
+        painter.circle_filled(to_screen(*point), HANDLE_RADIUS, visuals.selection.bg_fill);
+    }
+
+    response
+        .on_hover_text("Drag to move or add points, right-click to remove, double-click to reset");
+
+    result
+}
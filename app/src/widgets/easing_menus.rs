@@ -1,5 +1,5 @@
 use eframe::egui::Ui;
-use library::animation::EasingFunction;
+use library::animation::{EasingFunction, StepPosition};
 
 pub fn show_easing_menu(
     ui: &mut Ui,
@@ -115,6 +115,27 @@ pub fn show_easing_menu(
         );
     });
 
+    item(
+        ui,
+        "Spring",
+        EasingFunction::Spring {
+            mass: 1.0,
+            stiffness: 100.0,
+            damping: 10.0,
+        },
+    );
+
+    ui.menu_button("Steps", |ui| {
+        for (label, position) in [
+            ("Jump Start", StepPosition::Start),
+            ("Jump End", StepPosition::End),
+            ("Jump Both", StepPosition::Both),
+            ("Jump None", StepPosition::None),
+        ] {
+            item(ui, label, EasingFunction::Step { count: 4, position });
+        }
+    });
+
     ui.menu_button("Custom", |ui| {
         item(
             ui,
@@ -165,6 +186,7 @@ mod tests {
         assert!(harness.query_by_label("Back").is_some());
         assert!(harness.query_by_label("Elastic").is_some());
         assert!(harness.query_by_label("Bounce").is_some());
+        assert!(harness.query_by_label("Steps").is_some());
         assert!(harness.query_by_label("Custom").is_some());
     }
 
@@ -1,6 +1,7 @@
 pub mod collection_editor;
 pub mod command_palette;
 pub mod context_menu;
+pub mod curve_editor;
 pub mod easing_menus;
 pub mod modal;
 pub mod property;
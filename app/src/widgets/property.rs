@@ -14,12 +14,8 @@ fn get_local_time(
             // Use flat lookup from project.get_clip() instead of nested track traversal
             if let Some(clip) = project.get_source(entity_id) {
                 let fps = comp.fps;
-                let current_frame = (global_time * fps).round() as i64;
-                let delta_frames = current_frame - clip.in_frame as i64;
-                let time_offset = (clip.source_begin_frame as f64) / clip.fps;
-                let delta_seconds = delta_frames as f64 / fps;
-                let local_time = time_offset + delta_seconds;
-                return local_time;
+                let current_frame = (global_time * fps).round().max(0.0) as u64;
+                return clip.compute_local_time(current_frame, fps);
             }
         }
         global_time
@@ -1,5 +1,8 @@
 use eframe::egui;
 
+/// Coasting stops once the per-frame pan speed drops below this (pixels).
+const INERTIA_STOP_SPEED: f32 = 0.1;
+
 pub struct ViewportConfig {
     pub allow_pan_x: bool,
     pub allow_pan_y: bool,
@@ -8,6 +11,10 @@ pub struct ViewportConfig {
     pub zoom_uniform: bool, // If true, all zoom ops affect X&Y uniformly
     pub min_zoom: f32,
     pub max_zoom: f32,
+    /// Keep panning after a pan drag is released, slowing down each frame.
+    pub enable_inertia: bool,
+    /// Fraction of the pan velocity kept per frame while coasting (0–1).
+    pub inertia_decay: f32,
 }
 
 impl Default for ViewportConfig {
@@ -20,6 +27,8 @@ impl Default for ViewportConfig {
             zoom_uniform: false,
             min_zoom: 0.01,
             max_zoom: 1000.0,
+            enable_inertia: false,
+            inertia_decay: 0.9,
         }
     }
 }
@@ -128,6 +137,9 @@ impl<'a> ViewportController<'a> {
             }
         }
 
+        // Per-frame pan delta of an ongoing pan drag (for inertia)
+        let mut drag_velocity = None;
+
         // --- 1. Hand Tool Logic ---
         let mut _is_hand_tool_active = false;
 
@@ -146,6 +158,7 @@ impl<'a> ViewportController<'a> {
 
             if response.dragged_by(egui::PointerButton::Primary) {
                 let delta = response.drag_delta();
+                drag_velocity = Some(-delta);
                 if delta != egui::Vec2::ZERO {
                     self.apply_pan(state, -delta);
                     changed = true;
@@ -162,6 +175,7 @@ impl<'a> ViewportController<'a> {
         // --- 2. Middle Mouse Pan ---
         if response.dragged_by(egui::PointerButton::Middle) {
             let delta = response.drag_delta();
+            drag_velocity = Some(-delta);
             if delta != egui::Vec2::ZERO {
                 self.apply_pan(state, -delta); // Invert delta for "dragging content" feel
                 changed = true;
@@ -170,6 +184,11 @@ impl<'a> ViewportController<'a> {
             }
         }
 
+        // --- 2b. Pan Inertia ---
+        if self.config.enable_inertia && self.update_inertia(state, &response, drag_velocity) {
+            changed = true;
+        }
+
         // --- 3. Wheel Zoom / Scroll ---
         if self.ui.rect_contains_pointer(rect) {
             let scroll_delta = self.ui.input(|i| i.raw_scroll_delta);
@@ -276,6 +295,42 @@ impl<'a> ViewportController<'a> {
         (changed, response)
     }
 
+    /// Remember the drag velocity while panning, and coast with it after release.
+    ///
+    /// The velocity lives in egui memory under this controller's id. Returns true
+    /// if the pan was changed this frame.
+    fn update_inertia(
+        &self,
+        state: &mut impl ViewportState,
+        response: &egui::Response,
+        drag_velocity: Option<egui::Vec2>,
+    ) -> bool {
+        let velocity_id = self.id.with("pan_inertia_velocity");
+        let mut coasted = false;
+
+        let velocity = match drag_velocity {
+            Some(velocity) => velocity,
+            None => {
+                let velocity: egui::Vec2 = self
+                    .ui
+                    .data(|d| d.get_temp(velocity_id))
+                    .unwrap_or_default();
+                // A new press on the viewport catches it
+                if velocity.length() <= INERTIA_STOP_SPEED || response.is_pointer_button_down_on() {
+                    egui::Vec2::ZERO
+                } else {
+                    self.apply_pan(state, velocity);
+                    coasted = true;
+                    self.ui.ctx().request_repaint();
+                    velocity * self.config.inertia_decay.clamp(0.0, 1.0)
+                }
+            }
+        };
+
+        self.ui.data_mut(|d| d.insert_temp(velocity_id, velocity));
+        coasted
+    }
+
     fn apply_pan(&self, state: &mut impl ViewportState, delta: egui::Vec2) {
         let mut pan = state.get_pan();
         if self.config.allow_pan_x {
@@ -91,10 +91,145 @@ pub fn draw_bezier_connection(painter: &egui::Painter, from: Pos2, to: Pos2, col
     }
 }
 
+/// Draw a reroute node as a dot joining its `(input, output)` pins, with an
+/// outline when selected.
+pub fn draw_reroute_node(
+    painter: &egui::Painter,
+    center: Pos2,
+    radius: f32,
+    pins: (Pos2, Pos2),
+    color: Color32,
+    outline: Option<Color32>,
+) {
+    painter.line_segment([pins.0, pins.1], Stroke::new(2.0, color));
+    painter.circle_filled(center, radius, color);
+    if let Some(outline) = outline {
+        painter.circle_stroke(center, radius + 2.0, Stroke::new(1.5, outline));
+    }
+}
+
+/// Uniform mapping between graph space and a minimap rectangle.
+#[derive(Clone, Copy, Debug)]
+pub struct MinimapTransform {
+    graph_center: Pos2,
+    minimap_center: Pos2,
+    scale: f32,
+}
+
+impl MinimapTransform {
+    /// Fit `graph_bounds` inside `minimap_rect`, centred, leaving `padding` pixels on each side.
+    pub fn new(graph_bounds: Rect, minimap_rect: Rect, padding: f32) -> Self {
+        let avail = (minimap_rect.size() - Vec2::splat(padding * 2.0)).max(Vec2::splat(1.0));
+        let size = graph_bounds.size().max(Vec2::splat(1.0));
+        Self {
+            graph_center: graph_bounds.center(),
+            minimap_center: minimap_rect.center(),
+            scale: (avail.x / size.x).min(avail.y / size.y),
+        }
+    }
+
+    pub fn to_minimap(&self, graph_pos: Pos2) -> Pos2 {
+        self.minimap_center + (graph_pos - self.graph_center) * self.scale
+    }
+
+    pub fn to_graph(&self, minimap_pos: Pos2) -> Pos2 {
+        self.graph_center + (minimap_pos - self.minimap_center) / self.scale
+    }
+
+    pub fn rect_to_minimap(&self, graph_rect: Rect) -> Rect {
+        Rect::from_min_max(
+            self.to_minimap(graph_rect.min),
+            self.to_minimap(graph_rect.max),
+        )
+    }
+}
+
+/// Draw a minimap of `nodes` (graph-space rect and colour) in the lower-right
+/// corner of `canvas_rect`, with the visible area outlined in `viewport_color`.
+///
+/// Clicking or dragging in the minimap centres the view on that point; the new
+/// pan offset is returned.
+#[allow(clippy::too_many_arguments)]
+pub fn draw_minimap(
+    ui: &egui::Ui,
+    painter: &egui::Painter,
+    canvas_rect: Rect,
+    nodes: &[(Rect, Color32)],
+    pan: Vec2,
+    zoom: f32,
+    size: Vec2,
+    viewport_color: Color32,
+) -> Option<Vec2> {
+    let margin = 10.0;
+    if nodes.is_empty()
+        || size.x <= 0.0
+        || size.y <= 0.0
+        || canvas_rect.width() < size.x + margin * 2.0
+        || canvas_rect.height() < size.y + margin * 2.0
+    {
+        return None;
+    }
+
+    let minimap_rect = Rect::from_min_size(canvas_rect.max - size - Vec2::splat(margin), size);
+    // Visible part of the graph: screen = canvas.min + graph * zoom + pan
+    let viewport = Rect::from_min_size(((-pan) / zoom).to_pos2(), canvas_rect.size() / zoom);
+    let bounds = nodes
+        .iter()
+        .fold(viewport, |bounds, (rect, _)| bounds.union(*rect));
+    let transform = MinimapTransform::new(bounds, minimap_rect, 6.0);
+
+    let response = ui.interact(
+        minimap_rect,
+        ui.id().with("node_editor_minimap"),
+        egui::Sense::click_and_drag(),
+    );
+
+    let map_painter = painter.with_clip_rect(minimap_rect);
+    map_painter.rect_filled(minimap_rect, 3.0, Color32::from_black_alpha(180));
+    for (rect, color) in nodes {
+        map_painter.rect_filled(transform.rect_to_minimap(*rect), 1.0, *color);
+    }
+    map_painter.rect_stroke(
+        transform.rect_to_minimap(viewport),
+        0.0,
+        Stroke::new(1.0, viewport_color),
+        egui::StrokeKind::Inside,
+    );
+    painter.rect_stroke(
+        minimap_rect,
+        3.0,
+        Stroke::new(1.0, Color32::from_gray(90)),
+        egui::StrokeKind::Inside,
+    );
+
+    if response.clicked() || response.dragged_by(egui::PointerButton::Primary) {
+        let pointer = response.interact_pointer_pos()?;
+        let target = transform.to_graph(pointer);
+        return Some(canvas_rect.size() * 0.5 - target.to_vec2() * zoom);
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_minimap_transform_round_trip_and_fit() {
+        let bounds = Rect::from_min_max(Pos2::new(-100.0, 0.0), Pos2::new(300.0, 100.0));
+        let minimap = Rect::from_min_size(Pos2::new(500.0, 400.0), Vec2::new(200.0, 150.0));
+        let transform = MinimapTransform::new(bounds, minimap, 0.0);
+
+        // Wider bounds fill the width and are centred vertically
+        let mapped = transform.rect_to_minimap(bounds);
+        assert!((mapped.width() - 200.0).abs() < 0.01);
+        assert!((mapped.center().y - minimap.center().y).abs() < 0.01);
+
+        let p = Pos2::new(42.0, 17.0);
+        let back = transform.to_graph(transform.to_minimap(p));
+        assert!((back - p).length() < 0.01);
+    }
+
     #[test]
     fn test_bezier_distance_on_straight_line_midpoint() {
         let from = Pos2::new(0.0, 0.0);
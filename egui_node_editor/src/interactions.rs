@@ -5,13 +5,16 @@ use std::collections::HashMap;
 use uuid::Uuid;
 
 use crate::drawing::bezier_distance_to_point;
+use crate::node_rendering::collapse_chevron_rect;
 use crate::state::{
     BoxSelectState, ConnectingState, ContextMenuState, DragState, EdgeContextMenuState,
     NodeContextMenuState, NodeEditorState, ResizeState,
 };
 use crate::theme::NodeEditorTheme;
 use crate::traits::NodeEditorMutator;
-use crate::types::{ConnectionView, PinDataType, are_types_compatible};
+use crate::types::{
+    ConnectionView, PinDataType, PinDirection, are_types_compatible, parse_boundary_pin,
+};
 use crate::widget::{NodeInteraction, PendingActions, PinScreen};
 
 /// Context passed to interaction handlers (avoids threading many parameters).
@@ -50,6 +53,7 @@ pub(crate) fn handle_interactions(
     render_node_context_menu(state, ctx, &mut pending);
     render_edge_context_menu(state, ctx, &mut pending);
     handle_delete_key(state, ctx, &mut pending);
+    handle_clipboard_keys(state, ctx, &mut pending);
 
     pending
 }
@@ -161,6 +165,64 @@ fn get_pin_container_id(
         .and_then(|ps| ps.container_id)
 }
 
+/// The pin under `pos` for the wire being dragged, as
+/// (from_node, from_pin, to_node, to_pin) with the output first.
+pub(crate) fn connection_target<'a>(
+    connecting: &'a ConnectingState,
+    pin_screens: &'a [PinScreen],
+    pos: Pos2,
+    hit_radius: f32,
+) -> Option<(Uuid, &'a str, Uuid, &'a str)> {
+    let target = find_nearest_pin(
+        pin_screens,
+        pos,
+        hit_radius,
+        Some(connecting.from_node),
+        Some(!connecting.is_output),
+    )?;
+    Some(if connecting.is_output {
+        (
+            connecting.from_node,
+            connecting.from_pin.as_str(),
+            target.node_id,
+            target.name.as_str(),
+        )
+    } else {
+        (
+            target.node_id,
+            target.name.as_str(),
+            connecting.from_node,
+            connecting.from_pin.as_str(),
+        )
+    })
+}
+
+/// Whether a wire from an output pin to an input pin may be created: both pins
+/// must be in the same container scope, have compatible types, and be accepted
+/// by [`NodeEditorMutator::can_connect`].
+pub(crate) fn is_connection_allowed(
+    pin_screens: &[PinScreen],
+    mutator: &dyn NodeEditorMutator,
+    (from_node, from_pin, to_node, to_pin): (Uuid, &str, Uuid, &str),
+) -> bool {
+    let from_type = get_pin_data_type(pin_screens, from_node, from_pin, true);
+    let to_type = get_pin_data_type(pin_screens, to_node, to_pin, false);
+    if !are_types_compatible(&from_type, &to_type)
+        || get_pin_container_id(pin_screens, from_node, from_pin, true)
+            != get_pin_container_id(pin_screens, to_node, to_pin, false)
+    {
+        return false;
+    }
+    let (from_node, from_pin) = resolve_boundary_pin(from_node, from_pin);
+    let (to_node, to_pin) = resolve_boundary_pin(to_node, to_pin);
+    mutator.can_connect(from_node, from_pin, to_node, to_pin)
+}
+
+/// The grouped node's pin behind a subgraph boundary pin; other pins map to themselves.
+fn resolve_boundary_pin(node_id: Uuid, pin: &str) -> (Uuid, &str) {
+    parse_boundary_pin(pin).unwrap_or((node_id, pin))
+}
+
 // ---------------------------------------------------------------------------
 // Individual interaction handlers
 // ---------------------------------------------------------------------------
@@ -227,46 +289,13 @@ fn handle_drag_stop(
     // Finish connection
     if let Some(connecting) = state.connecting.take() {
         if let Some(pos) = pointer_pos {
-            let target = find_nearest_pin(
-                ctx.pin_screens,
-                pos,
-                ctx.hit_radius,
-                Some(connecting.from_node),
-                Some(!connecting.is_output),
-            );
-            if let Some(target) = target {
-                // Determine which is output and which is input
-                let (from_node, from_pin, to_node, to_pin) = if connecting.is_output {
-                    (
-                        connecting.from_node,
-                        connecting.from_pin.as_str(),
-                        target.node_id,
-                        target.name.as_str(),
-                    )
-                } else {
-                    (
-                        target.node_id,
-                        target.name.as_str(),
-                        connecting.from_node,
-                        connecting.from_pin.as_str(),
-                    )
-                };
-
-                // Type validation
-                let from_type = get_pin_data_type(ctx.pin_screens, from_node, from_pin, true);
-                let to_type = get_pin_data_type(ctx.pin_screens, to_node, to_pin, false);
-
-                if are_types_compatible(&from_type, &to_type) {
-                    // Container validation: pins must be in the same container scope
-                    let from_container =
-                        get_pin_container_id(ctx.pin_screens, from_node, from_pin, true);
-                    let to_container =
-                        get_pin_container_id(ctx.pin_screens, to_node, to_pin, false);
-                    if from_container != to_container {
-                        // Pins are in different container scopes — reject
-                        return;
-                    }
-
+            let target = connection_target(&connecting, ctx.pin_screens, pos, ctx.hit_radius);
+            if let Some(target @ (from_node, from_pin, to_node, to_pin)) = target {
+                // Invalid drops are refused; the wire was already drawn red
+                if is_connection_allowed(ctx.pin_screens, ctx.mutator, target) {
+                    // Subgraph boundary pins connect the grouped node behind them
+                    let (from_node, from_pin) = resolve_boundary_pin(from_node, from_pin);
+                    let (to_node, to_pin) = resolve_boundary_pin(to_node, to_pin);
                     // Edge overwrite: remove existing connection to the input pin
                     if let Some(existing_id) =
                         find_existing_connection_to_input(ctx.connections, to_node, to_pin)
@@ -280,6 +309,7 @@ fn handle_drag_stop(
                         to_node,
                         to_pin.to_string(),
                     ));
+                    state.remember_connection(from_node, from_pin);
                 }
             }
         }
@@ -345,6 +375,12 @@ fn handle_drag_start(
     }
     let Some(pos) = pointer_pos else { return };
 
+    // 0. Reroute dots move when dragged by their body; their pins are hit just outside it
+    if let Some(node_id) = reroute_body_at(ctx.nodes, pos) {
+        start_node_drag(state, ctx, node_id, pos, pending);
+        return;
+    }
+
     // 1. Check pin hit
     if let Some(ps) = find_nearest_pin(ctx.pin_screens, pos, ctx.hit_radius, None, None) {
         state.connecting = Some(ConnectingState {
@@ -398,22 +434,7 @@ fn handle_drag_start(
         let header_rect =
             Rect::from_min_size(node.rect.min, Vec2::new(node.rect.width(), header_h));
         if header_rect.contains(pos) {
-            if !ctx.ui.input(|i| i.modifiers.shift) {
-                state.selected_nodes.clear();
-            }
-            state.selected_nodes.insert(node.id);
-            pending.selected_node = Some(node.id);
-
-            let drag_ids: Vec<Uuid> = state.selected_nodes.iter().copied().collect();
-            let start_positions: Vec<Pos2> = drag_ids
-                .iter()
-                .map(|id| state.node_positions.get(id).copied().unwrap_or(Pos2::ZERO))
-                .collect();
-            state.dragging = Some(DragState {
-                node_ids: drag_ids,
-                start_positions,
-                mouse_start: pos,
-            });
+            start_node_drag(state, ctx, node.id, pos, pending);
             return;
         }
     }
@@ -425,6 +446,41 @@ fn handle_drag_start(
     });
 }
 
+/// Topmost reroute whose dot contains `pos`.
+pub(crate) fn reroute_body_at(nodes: &[NodeInteraction], pos: Pos2) -> Option<Uuid> {
+    nodes
+        .iter()
+        .rev()
+        .find(|n| n.is_reroute && n.rect.contains(pos))
+        .map(|n| n.id)
+}
+
+/// Select `node_id` (extending the selection with Shift) and start dragging the selection.
+fn start_node_drag(
+    state: &mut NodeEditorState,
+    ctx: &InteractionContext,
+    node_id: Uuid,
+    pos: Pos2,
+    pending: &mut PendingActions,
+) {
+    if !ctx.ui.input(|i| i.modifiers.shift) {
+        state.selected_nodes.clear();
+    }
+    state.selected_nodes.insert(node_id);
+    pending.selected_node = Some(node_id);
+
+    let drag_ids: Vec<Uuid> = state.selected_nodes.iter().copied().collect();
+    let start_positions: Vec<Pos2> = drag_ids
+        .iter()
+        .map(|id| state.node_positions.get(id).copied().unwrap_or(Pos2::ZERO))
+        .collect();
+    state.dragging = Some(DragState {
+        node_ids: drag_ids,
+        start_positions,
+        mouse_start: pos,
+    });
+}
+
 fn handle_connecting_update(state: &mut NodeEditorState, pointer_pos: Option<Pos2>) {
     if let Some(ref mut connecting) = state.connecting {
         if let Some(pos) = pointer_pos {
@@ -443,22 +499,38 @@ fn handle_double_click(
     }
     let Some(pos) = pointer_pos else { return };
 
-    // Find container whose header was double-clicked (not just anywhere in the body)
+    // Find the node whose header was double-clicked (not just anywhere in the body).
+    // Containers toggle inline expansion, other nodes toggle collapse.
     let header_h = ctx.theme.header_height * ctx.zoom;
     for node in ctx.nodes.iter().rev() {
-        if node.is_container {
-            let header_rect =
-                Rect::from_min_size(node.rect.min, Vec2::new(node.rect.width(), header_h));
-            if header_rect.contains(pos) {
+        if node.is_reroute {
+            continue;
+        }
+        let header_rect =
+            Rect::from_min_size(node.rect.min, Vec2::new(node.rect.width(), header_h));
+        if header_rect.contains(pos) {
+            if state.collapsed_groups.contains(&node.id) {
+                enter_container(state, node.id);
+            } else if node.is_container {
                 if !state.expanded_containers.remove(&node.id) {
                     state.expanded_containers.insert(node.id);
                 }
-                return;
+            } else if !collapse_chevron_rect(node.rect.min, header_h).contains(pos) {
+                // The chevron already toggled on each of the two clicks
+                state.toggle_collapsed(node.id);
             }
+            return;
         }
     }
 }
 
+/// Show only the children of `container_id`.
+fn enter_container(state: &mut NodeEditorState, container_id: Uuid) {
+    state.current_container = Some(container_id);
+    state.selected_nodes.clear();
+    state.selected_connections.clear();
+}
+
 fn handle_single_click(
     state: &mut NodeEditorState,
     ctx: &InteractionContext,
@@ -471,9 +543,16 @@ fn handle_single_click(
     let Some(pos) = pointer_pos else { return };
 
     // Check node hit first
+    let header_h = ctx.theme.header_height * ctx.zoom;
     let mut hit_node = false;
     for node in ctx.nodes.iter().rev() {
         if node.rect.contains(pos) {
+            if !node.is_container
+                && !node.is_reroute
+                && collapse_chevron_rect(node.rect.min, header_h).contains(pos)
+            {
+                state.toggle_collapsed(node.id);
+            }
             if !ctx.ui.input(|i| i.modifiers.shift) {
                 state.selected_nodes.clear();
             }
@@ -665,6 +744,89 @@ fn render_node_context_menu(
                         close = true;
                     }
                 }
+
+                ui.separator();
+                if ui.button("Disconnect All Input Pins").clicked() {
+                    pending
+                        .pins_to_disconnect
+                        .push((menu.node_id, PinDirection::Input));
+                    close = true;
+                }
+                if ui.button("Disconnect All Output Pins").clicked() {
+                    pending
+                        .pins_to_disconnect
+                        .push((menu.node_id, PinDirection::Output));
+                    close = true;
+                }
+
+                ui.separator();
+                if let Some(container_id) = state.current_container
+                    && ui.button("Group into Subgraph").clicked()
+                {
+                    let mut node_ids: Vec<Uuid> = state.selected_nodes.iter().copied().collect();
+                    if node_ids.is_empty() {
+                        node_ids.push(menu.node_id);
+                    }
+                    pending.nodes_to_group = Some((container_id, node_ids));
+                    close = true;
+                }
+                let is_container = ctx
+                    .nodes
+                    .iter()
+                    .any(|n| n.id == menu.node_id && n.is_container);
+                if is_container && ui.button("Open").clicked() {
+                    enter_container(state, menu.node_id);
+                    close = true;
+                }
+
+                ui.separator();
+                let image_output = ctx.pin_screens.iter().find(|ps| {
+                    ps.node_id == menu.node_id && ps.is_output && ps.data_type == PinDataType::Image
+                });
+                if let Some(output) = image_output
+                    && ui.button("Pin Output").clicked()
+                {
+                    pending.output_to_pin = Some((menu.node_id, output.name.clone()));
+                    close = true;
+                }
+                let is_pinned = state
+                    .pinned_node_output
+                    .as_ref()
+                    .is_some_and(|(id, _)| *id == menu.node_id)
+                    || state
+                        .pinned_output_error
+                        .as_ref()
+                        .is_some_and(|(id, _)| *id == menu.node_id);
+                if is_pinned && ui.button("Unpin Output").clicked() {
+                    state.pinned_node_output = None;
+                    state.pinned_output_error = None;
+                    close = true;
+                }
+
+                let candidates = recent_connection_candidates(state, ctx, menu.node_id);
+                ui.add_enabled_ui(!candidates.is_empty(), |ui| {
+                    ui.menu_button("Recently Connected", |ui| {
+                        for (from_node, from_pin, to_pin) in &candidates {
+                            let label = format!("{} \u{2192} {}", from_pin, to_pin);
+                            if ui.button(label).clicked() {
+                                if let Some(existing_id) = find_existing_connection_to_input(
+                                    ctx.connections,
+                                    menu.node_id,
+                                    to_pin,
+                                ) {
+                                    pending.connections_to_remove.push(existing_id);
+                                }
+                                pending.connections_to_add.push((
+                                    *from_node,
+                                    from_pin.clone(),
+                                    menu.node_id,
+                                    to_pin.clone(),
+                                ));
+                                close = true;
+                            }
+                        }
+                    });
+                });
             });
         });
     if close || ctx.ui.input(|i| i.key_pressed(egui::Key::Escape)) {
@@ -672,6 +834,46 @@ fn render_node_context_menu(
     }
 }
 
+/// Recently connected output pins that can feed an input of `node_id`.
+///
+/// Returns (from_node, from_pin, to_pin), pairing each recent output with the
+/// first type-compatible input in the same container, preferring free inputs.
+fn recent_connection_candidates(
+    state: &NodeEditorState,
+    ctx: &InteractionContext,
+    node_id: Uuid,
+) -> Vec<(Uuid, String, String)> {
+    let inputs: Vec<&PinScreen> = ctx
+        .pin_screens
+        .iter()
+        .filter(|ps| ps.node_id == node_id && !ps.is_output)
+        .collect();
+
+    state
+        .recent_connections
+        .iter()
+        .filter(|(from_node, _)| *from_node != node_id)
+        .filter_map(|(from_node, from_pin)| {
+            let output = ctx
+                .pin_screens
+                .iter()
+                .find(|ps| ps.node_id == *from_node && ps.name == *from_pin && ps.is_output)?;
+            let compatible = |ps: &&&PinScreen| {
+                ps.container_id == output.container_id
+                    && are_types_compatible(&output.data_type, &ps.data_type)
+            };
+            let input = inputs
+                .iter()
+                .filter(compatible)
+                .find(|ps| {
+                    find_existing_connection_to_input(ctx.connections, node_id, &ps.name).is_none()
+                })
+                .or_else(|| inputs.iter().find(compatible))?;
+            Some((*from_node, from_pin.clone(), input.name.clone()))
+        })
+        .collect()
+}
+
 fn render_edge_context_menu(
     state: &mut NodeEditorState,
     ctx: &InteractionContext,
@@ -689,6 +891,15 @@ fn render_edge_context_menu(
         .show(ctx.ui.ctx(), |ui| {
             egui::Frame::menu(ui.style()).show(ui, |ui| {
                 ui.set_max_width(180.0);
+                if let Some(container_id) = state.current_container
+                    && ui.button("Insert Reroute").clicked()
+                {
+                    let graph_pos =
+                        (menu.screen_pos - ctx.canvas_response.rect.min - state.pan) / ctx.zoom;
+                    pending.reroute_to_insert =
+                        Some((container_id, menu.connection_id, graph_pos.to_pos2()));
+                    close = true;
+                }
                 if ui.button("Delete Connection").clicked() {
                     pending.connections_to_remove.push(menu.connection_id);
                     state.selected_connections.remove(&menu.connection_id);
@@ -719,6 +930,44 @@ fn handle_delete_key(
     }
 }
 
+/// Ctrl+C copies the selected nodes, Ctrl+V pastes them into the current container.
+fn handle_clipboard_keys(
+    state: &mut NodeEditorState,
+    ctx: &InteractionContext,
+    pending: &mut PendingActions,
+) {
+    if ctx.ui.ctx().wants_keyboard_input()
+        || !ctx.ui.rect_contains_pointer(ctx.canvas_response.rect)
+    {
+        return;
+    }
+    // The windowing backend turns Ctrl+C / Ctrl+V into clipboard events
+    let (copy, paste) = ctx.ui.input(|i| {
+        (
+            i.events.iter().any(|e| matches!(e, egui::Event::Copy))
+                || i.modifiers.command && i.key_pressed(egui::Key::C),
+            i.events.iter().any(|e| matches!(e, egui::Event::Paste(_)))
+                || i.modifiers.command && i.key_pressed(egui::Key::V),
+        )
+    });
+
+    if copy && !state.selected_nodes.is_empty() {
+        let ids: Vec<Uuid> = state.selected_nodes.iter().copied().collect();
+        // Ctrl+V is only reported while the system clipboard holds text
+        ctx.ui.ctx().copy_text(
+            ids.iter()
+                .map(Uuid::to_string)
+                .collect::<Vec<_>>()
+                .join("\n"),
+        );
+        pending.nodes_to_copy = Some(ids);
+    } else if let (true, Some(container_id), Some(clipboard)) =
+        (paste, state.current_container, &state.clipboard)
+    {
+        pending.nodes_to_paste = Some((container_id, clipboard.data.clone()));
+    }
+}
+
 /// Clip a node interaction rect to a visible area.
 /// Returns None if the node is fully outside the clip bounds.
 pub(crate) fn clip_interaction_rect(node_rect: Rect, clip_rect: Rect) -> Option<Rect> {
@@ -818,6 +1067,68 @@ mod tests {
         );
     }
 
+    /// Mutator that only answers `can_connect`.
+    struct FixedMutator(bool);
+
+    impl NodeEditorMutator for FixedMutator {
+        fn add_node(&mut self, _: Uuid, _: &str) -> Result<Uuid, String> {
+            Err("unused".into())
+        }
+        fn remove_node(&mut self, _: Uuid) -> Result<(), String> {
+            Err("unused".into())
+        }
+        fn add_connection(&mut self, _: Uuid, _: &str, _: Uuid, _: &str) -> Result<(), String> {
+            Err("unused".into())
+        }
+        fn remove_connection(&mut self, _: Uuid) -> Result<(), String> {
+            Err("unused".into())
+        }
+        fn can_connect(&self, _: Uuid, _: &str, _: Uuid, _: &str) -> bool {
+            self.0
+        }
+        fn get_available_node_types(&self) -> Vec<crate::NodeTypeInfo> {
+            Vec::new()
+        }
+    }
+
+    #[test]
+    fn test_is_connection_allowed() {
+        let from = Uuid::new_v4();
+        let to = Uuid::new_v4();
+        let pin = |node_id, name: &str, is_output, data_type| PinScreen {
+            pos: Pos2::ZERO,
+            node_id,
+            name: name.to_string(),
+            is_output,
+            data_type,
+            container_id: None,
+        };
+        let pin_screens = vec![
+            pin(from, "image_out", true, PinDataType::Image),
+            pin(to, "image_in", false, PinDataType::Image),
+            pin(to, "value_in", false, PinDataType::Scalar),
+        ];
+        let wire = (from, "image_out", to, "image_in");
+
+        assert!(is_connection_allowed(
+            &pin_screens,
+            &FixedMutator(true),
+            wire
+        ));
+        // The mutator can veto a type-compatible wire
+        assert!(!is_connection_allowed(
+            &pin_screens,
+            &FixedMutator(false),
+            wire
+        ));
+        // Mismatched types are refused regardless of the mutator
+        assert!(!is_connection_allowed(
+            &pin_screens,
+            &FixedMutator(true),
+            (from, "image_out", to, "value_in")
+        ));
+    }
+
     #[test]
     fn test_get_pin_data_type_not_found_returns_any() {
         let pin_screens: Vec<PinScreen> = vec![];
@@ -0,0 +1,230 @@
+//! Automatic node placement.
+//!
+//! A simplified Sugiyama layered layout: nodes are ranked into columns by
+//! their longest path from a source, ordered within each column by the
+//! barycenter of their neighbours to reduce crossings, then stacked.
+//! Wires spanning several columns are not split into dummy nodes.
+
+use egui::{Pos2, Vec2};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Number of down/up barycenter sweeps when ordering columns.
+const ORDERING_SWEEPS: usize = 4;
+
+/// Place `nodes` (id and size) in columns flowing left to right along `edges`
+/// (`from` → `to`). `spacing.x` separates columns and `spacing.y` separates
+/// nodes in a column. The top-left node sits at `origin`.
+///
+/// Edges closing a cycle are ignored, and edges to unknown nodes are skipped.
+pub fn layered_layout(
+    nodes: &[(Uuid, Vec2)],
+    edges: &[(Uuid, Uuid)],
+    origin: Pos2,
+    spacing: Vec2,
+) -> HashMap<Uuid, Pos2> {
+    let index: HashMap<Uuid, usize> = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, (id, _))| (*id, i))
+        .collect();
+    let mut edges: Vec<(usize, usize)> = edges
+        .iter()
+        .filter_map(|(from, to)| Some((*index.get(from)?, *index.get(to)?)))
+        .filter(|(from, to)| from != to)
+        .collect();
+    edges.sort_unstable();
+    edges.dedup();
+
+    let order = topological_order(nodes.len(), &edges);
+    let position_in_order: Vec<usize> = {
+        let mut pos = vec![0; nodes.len()];
+        for (i, &n) in order.iter().enumerate() {
+            pos[n] = i;
+        }
+        pos
+    };
+    edges.retain(|&(from, to)| position_in_order[from] < position_in_order[to]);
+
+    // Longest path from a source
+    let mut rank = vec![0usize; nodes.len()];
+    for &n in &order {
+        for &(from, to) in &edges {
+            if from == n {
+                rank[to] = rank[to].max(rank[n] + 1);
+            }
+        }
+    }
+
+    let column_count = rank.iter().max().map_or(0, |r| r + 1);
+    let mut columns: Vec<Vec<usize>> = vec![Vec::new(); column_count];
+    for &n in &order {
+        columns[rank[n]].push(n);
+    }
+    order_columns(&mut columns, &edges, nodes.len());
+
+    let mut positions = HashMap::with_capacity(nodes.len());
+    let mut x = origin.x;
+    for column in &columns {
+        let width = column.iter().map(|&n| nodes[n].1.x).fold(0.0, f32::max);
+        let mut y = origin.y;
+        for &n in column {
+            positions.insert(nodes[n].0, Pos2::new(x, y));
+            y += nodes[n].1.y + spacing.y;
+        }
+        x += width + spacing.x;
+    }
+    positions
+}
+
+/// Kahn's algorithm, taking ready nodes in input order. Nodes left over by a
+/// cycle are appended in input order.
+fn topological_order(count: usize, edges: &[(usize, usize)]) -> Vec<usize> {
+    let mut in_degree = vec![0usize; count];
+    for &(_, to) in edges {
+        in_degree[to] += 1;
+    }
+    let mut visited = vec![false; count];
+    let mut order = Vec::with_capacity(count);
+    while order.len() < count {
+        let next = (0..count)
+            .find(|&n| !visited[n] && in_degree[n] == 0)
+            .or_else(|| (0..count).find(|&n| !visited[n]));
+        let Some(n) = next else { break };
+        visited[n] = true;
+        order.push(n);
+        for &(from, to) in edges {
+            if from == n && !visited[to] {
+                in_degree[to] = in_degree[to].saturating_sub(1);
+            }
+        }
+    }
+    order
+}
+
+/// Reorder each column by the mean row of its neighbours in the previous
+/// column (down sweep) or next column (up sweep). Nodes without neighbours
+/// there keep their current row.
+fn order_columns(columns: &mut [Vec<usize>], edges: &[(usize, usize)], count: usize) {
+    let mut row = vec![0.0f32; count];
+    let update_rows = |columns: &[Vec<usize>], row: &mut [f32]| {
+        for column in columns {
+            for (i, &n) in column.iter().enumerate() {
+                row[n] = i as f32;
+            }
+        }
+    };
+    update_rows(columns, &mut row);
+
+    for sweep in 0..ORDERING_SWEEPS {
+        let downward = sweep % 2 == 0;
+        let indices: Vec<usize> = if downward {
+            (1..columns.len()).collect()
+        } else {
+            (0..columns.len().saturating_sub(1)).rev().collect()
+        };
+        for c in indices {
+            let barycenter = |n: usize| {
+                let neighbours: Vec<f32> = edges
+                    .iter()
+                    .filter_map(|&(from, to)| {
+                        if downward && to == n {
+                            Some(row[from])
+                        } else if !downward && from == n {
+                            Some(row[to])
+                        } else {
+                            None
+                        }
+                    })
+                    .collect();
+                if neighbours.is_empty() {
+                    row[n]
+                } else {
+                    neighbours.iter().sum::<f32>() / neighbours.len() as f32
+                }
+            };
+            let mut keyed: Vec<(f32, usize)> =
+                columns[c].iter().map(|&n| (barycenter(n), n)).collect();
+            keyed.sort_by(|a, b| a.0.total_cmp(&b.0));
+            columns[c] = keyed.into_iter().map(|(_, n)| n).collect();
+            update_rows(&columns[c..=c], &mut row);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIZE: Vec2 = Vec2::new(100.0, 50.0);
+    const SPACING: Vec2 = Vec2::new(20.0, 10.0);
+
+    fn ids(n: usize) -> Vec<Uuid> {
+        (0..n).map(|_| Uuid::new_v4()).collect()
+    }
+
+    #[test]
+    fn test_chain_is_placed_in_successive_columns() {
+        let id = ids(3);
+        let nodes: Vec<_> = id.iter().rev().map(|&i| (i, SIZE)).collect();
+        let positions = layered_layout(
+            &nodes,
+            &[(id[0], id[1]), (id[1], id[2])],
+            Pos2::ZERO,
+            SPACING,
+        );
+        assert_eq!(positions[&id[0]], Pos2::new(0.0, 0.0));
+        assert_eq!(positions[&id[1]], Pos2::new(120.0, 0.0));
+        assert_eq!(positions[&id[2]], Pos2::new(240.0, 0.0));
+    }
+
+    #[test]
+    fn test_rank_follows_longest_path() {
+        let id = ids(3);
+        let nodes: Vec<_> = id.iter().map(|&i| (i, SIZE)).collect();
+        // 0 -> 2 directly and through 1
+        let positions = layered_layout(
+            &nodes,
+            &[(id[0], id[2]), (id[0], id[1]), (id[1], id[2])],
+            Pos2::ZERO,
+            SPACING,
+        );
+        assert_eq!(positions[&id[2]].x, 240.0);
+    }
+
+    #[test]
+    fn test_barycenter_uncrosses_wires() {
+        let id = ids(4);
+        // a0 -> b1 and a1 -> b0 cross when columns keep input order
+        let nodes: Vec<_> = [id[0], id[1], id[2], id[3]]
+            .iter()
+            .map(|&i| (i, SIZE))
+            .collect();
+        let positions = layered_layout(
+            &nodes,
+            &[(id[0], id[3]), (id[1], id[2])],
+            Pos2::ZERO,
+            SPACING,
+        );
+        assert!(positions[&id[0]].y < positions[&id[1]].y);
+        assert!(positions[&id[3]].y < positions[&id[2]].y);
+    }
+
+    #[test]
+    fn test_cycle_and_isolated_nodes_are_placed() {
+        let id = ids(3);
+        let nodes: Vec<_> = id.iter().map(|&i| (i, SIZE)).collect();
+        let positions = layered_layout(
+            &nodes,
+            &[(id[0], id[1]), (id[1], id[0]), (id[0], Uuid::new_v4())],
+            Pos2::new(5.0, 5.0),
+            SPACING,
+        );
+        assert_eq!(positions.len(), 3);
+        assert_eq!(positions[&id[1]], Pos2::new(125.0, 5.0));
+        // The unconnected node shares the first column without overlapping
+        assert_eq!(positions[&id[0]].x, 5.0);
+        assert_eq!(positions[&id[2]].x, 5.0);
+        assert_eq!((positions[&id[0]].y - positions[&id[2]].y).abs(), 60.0);
+    }
+}
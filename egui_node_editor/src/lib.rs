@@ -6,6 +6,7 @@
 
 pub mod drawing;
 pub mod interactions;
+pub mod layout;
 pub mod node_rendering;
 pub mod state;
 pub mod theme;
@@ -13,8 +14,8 @@ pub mod traits;
 pub mod types;
 pub mod widget;
 
-pub use state::NodeEditorState;
+pub use state::{NodeClipboard, NodeEditorState, NodeLayoutMap};
 pub use theme::NodeEditorTheme;
 pub use traits::{NodeEditorDataSource, NodeEditorMutator, PinEditValue, PinPropertyInfo};
 pub use types::*;
-pub use widget::NodeEditorWidget;
+pub use widget::{AppliedActions, NodeEditorWidget};
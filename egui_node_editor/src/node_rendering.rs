@@ -117,6 +117,83 @@ pub(crate) fn draw_node_chrome(
     );
 }
 
+/// Hit area of the collapse chevron at the left end of a node header.
+pub(crate) fn collapse_chevron_rect(node_min: Pos2, header_h: f32) -> Rect {
+    Rect::from_min_size(node_min, Vec2::splat(header_h))
+}
+
+/// Draw the `\u{25BC}`/`\u{25B6}` chevron that toggles node collapse.
+pub(crate) fn draw_collapse_chevron(
+    painter: &egui::Painter,
+    layout: &NodeLayout,
+    is_collapsed: bool,
+    is_active: bool,
+    zoom: f32,
+) {
+    let dim = if is_active { 1.0 } else { 0.4 };
+    painter.text(
+        collapse_chevron_rect(layout.screen_pos, layout.header_h).center(),
+        egui::Align2::CENTER_CENTER,
+        if is_collapsed { "\u{25B6}" } else { "\u{25BC}" },
+        egui::FontId::proportional(10.0 * zoom),
+        dim_color(Color32::WHITE, dim),
+    );
+}
+
+/// Connection anchors for pins hidden by collapsing a node.
+///
+/// Hidden inputs attach to the middle of the left edge and hidden outputs to
+/// the middle of the right edge. The anchors are not drawn and cannot be
+/// picked; they only keep existing connections visible.
+pub(crate) fn hidden_pin_anchors(
+    layout: &NodeLayout,
+    node_id: Uuid,
+    hidden_pins: &[&PinInfo],
+    container_id: Option<Uuid>,
+) -> Vec<PinScreen> {
+    let y = layout.node_rect.center().y;
+    hidden_pins
+        .iter()
+        .map(|pin| PinScreen {
+            pos: if pin.is_output {
+                Pos2::new(layout.node_rect.max.x, y)
+            } else {
+                Pos2::new(layout.node_rect.min.x, y)
+            },
+            node_id,
+            name: pin.name.clone(),
+            is_output: pin.is_output,
+            data_type: pin.data_type.clone(),
+            container_id,
+        })
+        .collect()
+}
+
+/// Mark a node whose evaluation failed: error outline plus a warning icon in the header.
+pub(crate) fn draw_node_error(
+    painter: &egui::Painter,
+    layout: &NodeLayout,
+    theme: &NodeEditorTheme,
+    zoom: f32,
+) {
+    painter.rect_stroke(
+        layout.node_rect,
+        layout.rounding,
+        Stroke::new(2.0 * zoom, theme.error_color),
+        StrokeKind::Outside,
+    );
+    painter.text(
+        Pos2::new(
+            layout.screen_pos.x + layout.node_w - 6.0 * zoom,
+            layout.screen_pos.y + layout.header_h / 2.0,
+        ),
+        egui::Align2::RIGHT_CENTER,
+        "\u{26A0}",
+        egui::FontId::proportional(12.0 * zoom),
+        theme.error_color,
+    );
+}
+
 /// Draw input and output pins, pushing PinScreen entries.
 ///
 /// For containers (`is_container = true`), paired input/output pins (e.g. `image_in`/`image_out`)
@@ -137,6 +214,7 @@ pub(crate) fn draw_pins(
 ) {
     let dim = if is_active { 1.0 } else { 0.4 };
     let label_color = dim_color(theme.pin_label_color, dim);
+    let show_labels = theme.should_show_pin_labels(zoom);
 
     if is_container {
         // Container mode: pair input/output pins by base name and draw at same position.
@@ -147,13 +225,18 @@ pub(crate) fn draw_pins(
             let p = Pos2::new(cx, cy);
             let pin_color = dim_color((theme.pin_type_color)(&out_pin.data_type), dim);
             painter.circle_filled(p, layout.pin_r, pin_color);
-            painter.text(
-                p + Vec2::new(-layout.pin_r - 4.0 * zoom, 0.0),
-                egui::Align2::RIGHT_CENTER,
-                &out_pin.display_name,
-                egui::FontId::proportional(10.0 * zoom),
-                label_color,
-            );
+            if show_labels {
+                draw_pin_label(
+                    painter,
+                    layout,
+                    theme,
+                    p,
+                    &out_pin.display_name,
+                    true,
+                    label_color,
+                    zoom,
+                );
+            }
 
             // Register output PinScreen
             pin_screens.push(PinScreen {
@@ -187,13 +270,18 @@ pub(crate) fn draw_pins(
             let p = Pos2::new(cx, cy);
             let pin_color = dim_color((theme.pin_type_color)(&pin.data_type), dim);
             painter.circle_filled(p, layout.pin_r, pin_color);
-            painter.text(
-                p + Vec2::new(layout.pin_r + 4.0 * zoom, 0.0),
-                egui::Align2::LEFT_CENTER,
-                &pin.display_name,
-                egui::FontId::proportional(10.0 * zoom),
-                label_color,
-            );
+            if show_labels {
+                draw_pin_label(
+                    painter,
+                    layout,
+                    theme,
+                    p,
+                    &pin.display_name,
+                    false,
+                    label_color,
+                    zoom,
+                );
+            }
             pin_screens.push(PinScreen {
                 pos: p,
                 node_id,
@@ -210,13 +298,18 @@ pub(crate) fn draw_pins(
             let p = Pos2::new(cx, cy);
             let pin_color = dim_color((theme.pin_type_color)(&pin.data_type), dim);
             painter.circle_filled(p, layout.pin_r, pin_color);
-            painter.text(
-                p + Vec2::new(-layout.pin_r - 4.0 * zoom, 0.0),
-                egui::Align2::RIGHT_CENTER,
-                &pin.display_name,
-                egui::FontId::proportional(10.0 * zoom),
-                label_color,
-            );
+            if show_labels {
+                draw_pin_label(
+                    painter,
+                    layout,
+                    theme,
+                    p,
+                    &pin.display_name,
+                    true,
+                    label_color,
+                    zoom,
+                );
+            }
             pin_screens.push(PinScreen {
                 pos: p,
                 node_id,
@@ -229,6 +322,61 @@ pub(crate) fn draw_pins(
     }
 }
 
+/// Draw a pin name beside its circle, on the inner side of the node.
+///
+/// Input labels sit to the right of the pin, output labels to the left.
+#[allow(clippy::too_many_arguments)]
+fn draw_pin_label(
+    painter: &egui::Painter,
+    layout: &NodeLayout,
+    theme: &NodeEditorTheme,
+    pin_pos: Pos2,
+    label: &str,
+    is_output: bool,
+    color: Color32,
+    zoom: f32,
+) {
+    let gap = layout.pin_r + 4.0 * zoom;
+    let (offset, align) = if is_output {
+        (Vec2::new(-gap, 0.0), egui::Align2::RIGHT_CENTER)
+    } else {
+        (Vec2::new(gap, 0.0), egui::Align2::LEFT_CENTER)
+    };
+    painter.text(
+        pin_pos + offset,
+        align,
+        label,
+        egui::FontId::proportional(theme.pin_label_font_size * zoom),
+        color,
+    );
+}
+
+/// Draw a pin's live value outside the node: right of outputs, left of inputs.
+pub(crate) fn draw_pin_value(
+    painter: &egui::Painter,
+    theme: &NodeEditorTheme,
+    pin_pos: Pos2,
+    value: &str,
+    is_output: bool,
+    zoom: f32,
+) {
+    let gap = theme.pin_radius * zoom + 4.0 * zoom;
+    let galley = painter.layout_no_wrap(
+        value.to_string(),
+        egui::FontId::monospace(theme.pin_label_font_size * zoom),
+        theme.pin_value_color,
+    );
+    let size = galley.size();
+    let min = if is_output {
+        Pos2::new(pin_pos.x + gap, pin_pos.y - size.y / 2.0)
+    } else {
+        Pos2::new(pin_pos.x - gap - size.x, pin_pos.y - size.y / 2.0)
+    };
+    let rect = Rect::from_min_size(min, size).expand(2.0 * zoom);
+    painter.rect_filled(rect, 2.0 * zoom, Color32::from_black_alpha(180));
+    painter.galley(min, galley, theme.pin_value_color);
+}
+
 /// Draw the resize handle triangle for containers.
 pub(crate) fn draw_resize_handle(painter: &egui::Painter, node_rect: Rect, zoom: f32) {
     let handle_size = 8.0 * zoom;
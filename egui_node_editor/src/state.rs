@@ -1,8 +1,36 @@
 //! UI state for the node editor.
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use uuid::Uuid;
 
+/// Minimum zoom level.
+pub const MIN_ZOOM: f32 = 0.2;
+/// Maximum zoom level.
+pub const MAX_ZOOM: f32 = 3.0;
+/// Number of recently connected output pins remembered for quick reconnection.
+pub const RECENT_CONNECTIONS_LIMIT: usize = 5;
+/// Offset of pasted nodes from their originals, in screen pixels.
+pub const PASTE_OFFSET: f32 = 20.0;
+
+/// Node positions of an editor, kept with the project so the layout survives a reload.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NodeLayoutMap {
+    /// Graph-space position per node.
+    pub positions: HashMap<Uuid, egui::Pos2>,
+    /// Subgraphs shown folded, see [`NodeEditorState::collapsed_groups`].
+    pub collapsed_groups: HashSet<Uuid>,
+}
+
+/// Nodes copied with Ctrl+C, snapshotted at copy time.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NodeClipboard {
+    /// Opaque copy of the nodes and their internal connections from
+    /// [`crate::NodeEditorMutator::copy_nodes`].
+    pub data: String,
+    /// Graph-space positions of the copied nodes, used to place the copies.
+    pub positions: HashMap<Uuid, egui::Pos2>,
+}
+
 /// UI state for the node editor panel.
 #[derive(Default)]
 pub struct NodeEditorState {
@@ -28,6 +56,11 @@ pub struct NodeEditorState {
     pub current_container: Option<Uuid>,
     /// Containers expanded inline.
     pub expanded_containers: HashSet<Uuid>,
+    /// Nodes collapsed to their header and first input/output pins.
+    pub collapsed_nodes: HashSet<Uuid>,
+    /// Subgraphs drawn as a single node with boundary pins. Double-clicking one
+    /// drills into it instead of expanding it inline.
+    pub collapsed_groups: HashSet<Uuid>,
     /// Search text for context menu.
     pub context_search: String,
     /// Box selection state.
@@ -38,6 +71,178 @@ pub struct NodeEditorState {
     pub resizing: Option<ResizeState>,
     /// Edge-specific context menu (right-click on a connection).
     pub edge_context_menu: Option<EdgeContextMenuState>,
+    /// Recently connected output pins as (node_id, pin_name), most recent first.
+    pub recent_connections: VecDeque<(Uuid, String)>,
+    /// Frozen output of one node, shown next to its live output for A/B comparison.
+    pub pinned_node_output: Option<(Uuid, egui::TextureHandle)>,
+    /// Why the last pin request failed, drawn under the node instead of an output.
+    pub pinned_output_error: Option<(Uuid, String)>,
+    /// Draw each pin's last evaluated value next to it.
+    pub show_live_values: bool,
+    /// Nodes copied with Ctrl+C.
+    pub clipboard: Option<NodeClipboard>,
+}
+
+impl NodeEditorState {
+    /// Change the zoom level while keeping the graph point under `anchor` fixed.
+    /// `anchor` is relative to the canvas origin.
+    pub fn zoom_around(&mut self, anchor: egui::Vec2, new_zoom: f32) {
+        let old_zoom = if self.zoom > 0.0 { self.zoom } else { 1.0 };
+        let new_zoom = new_zoom.clamp(MIN_ZOOM, MAX_ZOOM);
+        let graph_pos = (anchor - self.pan) / old_zoom;
+        self.pan = anchor - graph_pos * new_zoom;
+        self.zoom = new_zoom;
+    }
+
+    /// Snapshot of the node positions and folded subgraphs, for saving.
+    /// Entries of nodes for which `exists` returns false are left out.
+    pub fn to_layout_map(&self, exists: impl Fn(Uuid) -> bool) -> NodeLayoutMap {
+        NodeLayoutMap {
+            positions: self
+                .node_positions
+                .iter()
+                .filter(|(id, _)| exists(**id))
+                .map(|(id, pos)| (*id, *pos))
+                .collect(),
+            collapsed_groups: self
+                .collapsed_groups
+                .iter()
+                .copied()
+                .filter(|id| exists(*id))
+                .collect(),
+        }
+    }
+
+    /// Restore saved node positions and folded subgraphs. Nodes not in `map`
+    /// keep their current position.
+    pub fn apply_layout_map(&mut self, map: &NodeLayoutMap) {
+        self.node_positions
+            .extend(map.positions.iter().map(|(id, pos)| (*id, *pos)));
+        self.collapsed_groups
+            .extend(map.collapsed_groups.iter().copied());
+    }
+
+    /// Remember an output pin that was just connected, keeping the most recent
+    /// `RECENT_CONNECTIONS_LIMIT` unique entries.
+    pub fn remember_connection(&mut self, node_id: Uuid, pin_name: &str) {
+        self.recent_connections
+            .retain(|(id, pin)| !(*id == node_id && pin == pin_name));
+        self.recent_connections
+            .push_front((node_id, pin_name.to_string()));
+        self.recent_connections.truncate(RECENT_CONNECTIONS_LIMIT);
+    }
+
+    /// Collapse `node_id` if it is expanded, expand it otherwise.
+    pub fn toggle_collapsed(&mut self, node_id: Uuid) {
+        if !self.collapsed_nodes.remove(&node_id) {
+            self.collapsed_nodes.insert(node_id);
+        }
+    }
+
+    /// Freeze `image` as the pinned output of `node_id`, replacing any previous pin.
+    pub fn set_pinned_output(
+        &mut self,
+        ctx: &egui::Context,
+        node_id: Uuid,
+        image: egui::ColorImage,
+    ) {
+        let texture = ctx.load_texture(
+            format!("node_editor_pinned_{}", node_id),
+            image,
+            egui::TextureOptions::LINEAR,
+        );
+        self.pinned_node_output = Some((node_id, texture));
+        self.pinned_output_error = None;
+    }
+
+    /// Record that pinning the output of `node_id` failed, dropping any previous pin.
+    pub fn set_pinned_output_error(&mut self, node_id: Uuid, message: String) {
+        self.pinned_node_output = None;
+        self.pinned_output_error = Some((node_id, message));
+    }
+
+    /// Place a subgraph that was just created from `members` at their top-left
+    /// corner, fold it and select it.
+    pub fn place_group(&mut self, group_id: Uuid, members: &[Uuid]) {
+        let corner = members
+            .iter()
+            .filter_map(|id| self.node_positions.get(id))
+            .copied()
+            .reduce(|a, b| a.min(b));
+        if let Some(pos) = corner {
+            self.node_positions.insert(group_id, pos);
+        }
+        self.collapsed_groups.insert(group_id);
+        self.selected_nodes.clear();
+        self.selected_connections.clear();
+        self.selected_nodes.insert(group_id);
+    }
+
+    /// Center a reroute that was just inserted at `pos` (graph space) and select it.
+    pub fn place_reroute(&mut self, reroute_id: Uuid, pos: egui::Pos2) {
+        self.node_positions.insert(reroute_id, pos);
+        self.selected_nodes.clear();
+        self.selected_connections.clear();
+        self.selected_nodes.insert(reroute_id);
+    }
+
+    /// Store a copy made by [`crate::NodeEditorMutator::copy_nodes`] together with
+    /// the current positions of the copied nodes.
+    pub fn set_clipboard(&mut self, node_ids: &[Uuid], data: String) {
+        let positions = node_ids
+            .iter()
+            .filter_map(|id| self.node_positions.get(id).map(|pos| (*id, *pos)))
+            .collect();
+        self.clipboard = Some(NodeClipboard { data, positions });
+    }
+
+    /// Place pasted nodes `PASTE_OFFSET` pixels from where their originals were
+    /// copied and select them. `pasted` holds `(original_id, copy_id)` pairs.
+    pub fn place_pasted_nodes(&mut self, pasted: &[(Uuid, Uuid)]) {
+        if pasted.is_empty() {
+            return;
+        }
+        let zoom = if self.zoom > 0.0 { self.zoom } else { 1.0 };
+        let offset = egui::Vec2::splat(PASTE_OFFSET / zoom);
+        self.selected_nodes.clear();
+        self.selected_connections.clear();
+        for (original, copy) in pasted {
+            let pos = self
+                .clipboard
+                .as_ref()
+                .and_then(|clipboard| clipboard.positions.get(original))
+                .or_else(|| self.node_positions.get(original))
+                .copied();
+            if let Some(pos) = pos {
+                self.node_positions.insert(*copy, pos + offset);
+            }
+            self.selected_nodes.insert(*copy);
+        }
+    }
+
+    /// Reset pan and zoom to the default view.
+    pub fn reset_view(&mut self) {
+        self.pan = egui::Vec2::ZERO;
+        self.zoom = 1.0;
+    }
+
+    /// Pan and zoom so that `graph_bounds` (in graph space) fills a canvas of `canvas_size`.
+    pub fn fit_to_bounds(
+        &mut self,
+        graph_bounds: egui::Rect,
+        canvas_size: egui::Vec2,
+        margin: f32,
+    ) {
+        if graph_bounds.width() <= 0.0 || graph_bounds.height() <= 0.0 {
+            return;
+        }
+        let avail = (canvas_size - egui::Vec2::splat(margin * 2.0)).max(egui::Vec2::splat(1.0));
+        let zoom = (avail.x / graph_bounds.width())
+            .min(avail.y / graph_bounds.height())
+            .clamp(MIN_ZOOM, MAX_ZOOM);
+        self.zoom = zoom;
+        self.pan = canvas_size * 0.5 - graph_bounds.center().to_vec2() * zoom;
+    }
 }
 
 pub struct DragState {
@@ -82,3 +287,160 @@ pub struct EdgeContextMenuState {
     pub screen_pos: egui::Pos2,
     pub connection_id: Uuid,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::{Pos2, Rect, Vec2};
+
+    #[test]
+    fn test_zoom_around_keeps_anchor_fixed() {
+        let mut state = NodeEditorState {
+            zoom: 1.0,
+            pan: Vec2::new(30.0, -20.0),
+            ..Default::default()
+        };
+        let anchor = Vec2::new(200.0, 150.0);
+        let graph_before = (anchor - state.pan) / state.zoom;
+        state.zoom_around(anchor, 1.1);
+        let graph_after = (anchor - state.pan) / state.zoom;
+        assert!((graph_before - graph_after).length() < 1e-3);
+        assert!((state.zoom - 1.1).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_zoom_around_clamps() {
+        let mut state = NodeEditorState {
+            zoom: 1.0,
+            ..Default::default()
+        };
+        state.zoom_around(Vec2::ZERO, 100.0);
+        assert_eq!(state.zoom, MAX_ZOOM);
+        state.zoom_around(Vec2::ZERO, 0.0);
+        assert_eq!(state.zoom, MIN_ZOOM);
+    }
+
+    #[test]
+    fn test_fit_to_bounds_centers_graph() {
+        let mut state = NodeEditorState {
+            zoom: 1.0,
+            ..Default::default()
+        };
+        let bounds = Rect::from_min_max(Pos2::new(0.0, 0.0), Pos2::new(400.0, 200.0));
+        let canvas = Vec2::new(800.0, 600.0);
+        state.fit_to_bounds(bounds, canvas, 0.0);
+        assert!((state.zoom - 2.0).abs() < 1e-6);
+        let center_on_screen = bounds.center().to_vec2() * state.zoom + state.pan;
+        assert!((center_on_screen - canvas * 0.5).length() < 1e-3);
+    }
+
+    #[test]
+    fn test_place_pasted_nodes_offsets_and_selects_copies() {
+        let mut state = NodeEditorState {
+            zoom: 2.0,
+            ..Default::default()
+        };
+        let (original, copy) = (Uuid::new_v4(), Uuid::new_v4());
+        state
+            .node_positions
+            .insert(original, Pos2::new(100.0, 50.0));
+        state.selected_nodes.insert(original);
+
+        state.place_pasted_nodes(&[(original, copy)]);
+
+        // 20 screen pixels at zoom 2.0 is 10 graph units
+        assert_eq!(state.node_positions[&copy], Pos2::new(110.0, 60.0));
+        assert_eq!(state.selected_nodes.len(), 1);
+        assert!(state.selected_nodes.contains(&copy));
+    }
+
+    #[test]
+    fn test_pasted_nodes_use_positions_from_copy_time() {
+        let mut state = NodeEditorState {
+            zoom: 1.0,
+            ..Default::default()
+        };
+        let (original, copy) = (Uuid::new_v4(), Uuid::new_v4());
+        state.node_positions.insert(original, Pos2::new(0.0, 0.0));
+        state.set_clipboard(&[original], "snapshot".into());
+
+        // The original moves after the copy; the paste follows the copy-time position
+        state
+            .node_positions
+            .insert(original, Pos2::new(500.0, 500.0));
+        state.place_pasted_nodes(&[(original, copy)]);
+
+        assert_eq!(state.clipboard.as_ref().unwrap().data, "snapshot");
+        assert_eq!(state.node_positions[&copy], Pos2::new(20.0, 20.0));
+    }
+
+    #[test]
+    fn test_place_group_at_members_corner() {
+        let mut state = NodeEditorState::default();
+        let (a, b, group) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        state.node_positions.insert(a, Pos2::new(100.0, 20.0));
+        state.node_positions.insert(b, Pos2::new(40.0, 80.0));
+        state.selected_nodes.extend([a, b]);
+
+        state.place_group(group, &[a, b]);
+
+        assert_eq!(state.node_positions[&group], Pos2::new(40.0, 20.0));
+        assert!(state.collapsed_groups.contains(&group));
+        assert_eq!(state.selected_nodes, HashSet::from([group]));
+    }
+
+    #[test]
+    fn test_layout_map_round_trip() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let removed = Uuid::new_v4();
+        let mut saved = NodeEditorState::default();
+        saved.node_positions.insert(a, Pos2::new(10.0, 20.0));
+        saved.node_positions.insert(removed, Pos2::new(1.0, 1.0));
+        saved.collapsed_groups.extend([a, removed]);
+        let map = saved.to_layout_map(|id| id != removed);
+        assert!(!map.positions.contains_key(&removed));
+
+        let mut loaded = NodeEditorState::default();
+        loaded.node_positions.insert(a, Pos2::ZERO);
+        loaded.node_positions.insert(b, Pos2::new(5.0, 5.0));
+        loaded.apply_layout_map(&map);
+
+        assert_eq!(loaded.node_positions[&a], Pos2::new(10.0, 20.0));
+        assert_eq!(loaded.node_positions[&b], Pos2::new(5.0, 5.0));
+        assert_eq!(loaded.collapsed_groups, HashSet::from([a]));
+    }
+
+    #[test]
+    fn test_toggle_collapsed_round_trips() {
+        let mut state = NodeEditorState::default();
+        let id = Uuid::new_v4();
+        state.toggle_collapsed(id);
+        assert!(state.collapsed_nodes.contains(&id));
+        state.toggle_collapsed(id);
+        assert!(state.collapsed_nodes.is_empty());
+    }
+
+    #[test]
+    fn test_remember_connection_dedupes_and_caps() {
+        let mut state = NodeEditorState::default();
+        let ids: Vec<Uuid> = (0..RECENT_CONNECTIONS_LIMIT + 2)
+            .map(|_| Uuid::new_v4())
+            .collect();
+        for id in &ids {
+            state.remember_connection(*id, "out");
+        }
+        state.remember_connection(ids[RECENT_CONNECTIONS_LIMIT], "out");
+
+        assert_eq!(state.recent_connections.len(), RECENT_CONNECTIONS_LIMIT);
+        assert_eq!(
+            state.recent_connections.front(),
+            Some(&(ids[RECENT_CONNECTIONS_LIMIT], "out".to_string()))
+        );
+        let count = state
+            .recent_connections
+            .iter()
+            .filter(|(id, _)| *id == ids[RECENT_CONNECTIONS_LIMIT])
+            .count();
+        assert_eq!(count, 1);
+    }
+}
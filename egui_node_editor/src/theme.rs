@@ -38,10 +38,30 @@ pub struct NodeEditorTheme {
     pub selection_color: Color32,
     /// Pin label color.
     pub pin_label_color: Color32,
+    /// Live pin value text, drawn outside the node next to the pin.
+    pub pin_value_color: Color32,
+    /// Pin label font size (at zoom 1.0).
+    pub pin_label_font_size: f32,
+    /// Pin labels are hidden at or below this zoom level to avoid clutter.
+    pub pin_label_min_zoom: f32,
     /// Connection color (default).
     pub connection_color: Color32,
     /// Connection color (selected).
     pub connection_selected_color: Color32,
+    /// Wire being dragged over a pin it may connect to.
+    pub valid_connection_color: Color32,
+    /// Wire being dragged over a pin it may not connect to.
+    pub invalid_connection_color: Color32,
+    /// Pan distance in screen pixels per arrow key press.
+    pub keyboard_pan_step: f32,
+    /// Reroute node dot radius (at zoom 1.0).
+    pub reroute_radius: f32,
+    /// Outline and icon color for nodes whose evaluation failed.
+    pub error_color: Color32,
+    /// Size of the minimap in the lower-right corner. Zero hides it.
+    pub minimap_size: egui::Vec2,
+    /// Gap between columns (x) and between nodes in a column (y) for auto-layout.
+    pub auto_layout_spacing: egui::Vec2,
 }
 
 impl Default for NodeEditorTheme {
@@ -63,12 +83,29 @@ impl Default for NodeEditorTheme {
             node_body_selected_color: Color32::from_rgb(55, 55, 65),
             selection_color: Color32::from_rgb(100, 150, 255),
             pin_label_color: Color32::from_rgb(200, 200, 200),
+            pin_value_color: Color32::from_rgb(240, 200, 90),
+            pin_label_font_size: 10.0,
+            pin_label_min_zoom: 0.5,
             connection_color: Color32::from_rgb(180, 180, 180),
             connection_selected_color: Color32::WHITE,
+            valid_connection_color: Color32::from_rgb(110, 220, 120),
+            invalid_connection_color: Color32::from_rgb(230, 80, 80),
+            keyboard_pan_step: 50.0,
+            reroute_radius: 6.0,
+            error_color: Color32::from_rgb(230, 70, 60),
+            minimap_size: egui::Vec2::new(180.0, 120.0),
+            auto_layout_spacing: egui::Vec2::new(80.0, 40.0),
         }
     }
 }
 
+impl NodeEditorTheme {
+    /// Whether pin names should be drawn next to pin circles at the given zoom.
+    pub fn should_show_pin_labels(&self, zoom: f32) -> bool {
+        zoom > self.pin_label_min_zoom
+    }
+}
+
 fn default_pin_type_color(data_type: &PinDataType) -> Color32 {
     match data_type {
         PinDataType::Image => Color32::from_rgb(238, 180, 109), // Orange
@@ -1,8 +1,11 @@
 //! Trait definitions for decoupling the node editor from domain-specific types.
 
+use std::collections::HashMap;
 use uuid::Uuid;
 
-use crate::types::{ConnectionView, NodeDisplay, NodeTypeInfo, PinDataType};
+use crate::types::{
+    ConnectionView, NodeDisplay, NodeTypeInfo, PinDataType, PinDirection, RerouteNodeData,
+};
 
 /// Editable value for an input pin (displayed inline in the node editor).
 #[derive(Clone, Debug)]
@@ -30,6 +33,12 @@ pub struct PinPropertyInfo {
 
 /// Read-only data source for the node editor.
 pub trait NodeEditorDataSource {
+    /// Returns whether there is a container (e.g. a composition) to edit.
+    /// When false, the widget shows a placeholder instead of the canvas.
+    fn has_container(&self) -> bool {
+        true
+    }
+
     /// Get direct child node IDs of a container.
     fn get_container_children(&self, container_id: Uuid) -> Vec<Uuid>;
 
@@ -72,6 +81,32 @@ pub trait NodeEditorDataSource {
         let _ = (node_id, pin_name);
         None
     }
+
+    /// Get a texture of the node's current output, drawn beside its pinned output.
+    fn node_output_preview(&self, node_id: Uuid) -> Option<egui::TextureId> {
+        let _ = node_id;
+        None
+    }
+
+    /// Get the most recently evaluated value flowing through a pin, as display text.
+    /// Shown next to the pin while live values are turned on.
+    fn get_pin_value(&self, node_id: Uuid, pin_name: &str) -> Option<String> {
+        let _ = (node_id, pin_name);
+        None
+    }
+
+    /// Get the most recent evaluation error of a node, if it failed.
+    /// Failing nodes get a red outline and a warning icon; the message is shown on hover.
+    fn get_node_error_state(&self, id: Uuid) -> Option<String> {
+        let _ = id;
+        None
+    }
+
+    /// Get reroute nodes. These are placed without a registered node type and
+    /// connect through the `REROUTE_INPUT_PIN` / `REROUTE_OUTPUT_PIN` pins.
+    fn reroute_nodes(&self) -> Vec<RerouteNodeData> {
+        Vec::new()
+    }
 }
 
 /// Mutation interface for the node editor.
@@ -94,6 +129,10 @@ pub trait NodeEditorMutator {
     /// Remove a connection by ID.
     fn remove_connection(&mut self, connection_id: Uuid) -> Result<(), String>;
 
+    /// Whether an output pin may be wired to an input pin.
+    /// Checked while dragging a wire (to colour it) and before the drop is accepted.
+    fn can_connect(&self, from_node: Uuid, from_pin: &str, to_node: Uuid, to_pin: &str) -> bool;
+
     /// Move a node from one container to another.
     fn move_node(
         &mut self,
@@ -105,6 +144,61 @@ pub trait NodeEditorMutator {
         Err("not supported".into())
     }
 
+    /// Remove every connection attached to the node's input or output pins.
+    fn disconnect_node_pins(
+        &mut self,
+        node_id: Uuid,
+        direction: PinDirection,
+    ) -> Result<(), String> {
+        let _ = (node_id, direction);
+        Err("not supported".into())
+    }
+
+    /// Request the output pin of the node to be pinned for comparison.
+    /// The image is rendered asynchronously and handed back through
+    /// [`crate::NodeEditorState::set_pinned_output`].
+    fn pin_node_output(&mut self, node_id: Uuid, pin_name: &str) -> Result<(), String> {
+        let _ = (node_id, pin_name);
+        Err("not supported".into())
+    }
+
+    /// Snapshot nodes and the connections among them for the clipboard.
+    /// The returned data is opaque to the editor and handed back to [`Self::paste_nodes`].
+    fn copy_nodes(&mut self, node_ids: &[Uuid]) -> Result<String, String> {
+        let _ = node_ids;
+        Err("not supported".into())
+    }
+
+    /// Create the nodes of a [`Self::copy_nodes`] snapshot in a container with
+    /// fresh IDs. Returns `(original_id, copy_id)` pairs.
+    fn paste_nodes(&mut self, container_id: Uuid, data: &str) -> Result<Vec<(Uuid, Uuid)>, String> {
+        let _ = (container_id, data);
+        Err("not supported".into())
+    }
+
+    /// Split a connection with a new reroute node in `container_id`.
+    /// Returns the reroute's ID.
+    fn insert_reroute(&mut self, container_id: Uuid, connection_id: Uuid) -> Result<Uuid, String> {
+        let _ = (container_id, connection_id);
+        Err("not supported".into())
+    }
+
+    /// Group nodes of a container into a new subgraph node in that container.
+    /// Returns the subgraph's ID.
+    fn group_nodes(&mut self, container_id: Uuid, node_ids: &[Uuid]) -> Result<Uuid, String> {
+        let _ = (container_id, node_ids);
+        Err("not supported".into())
+    }
+
+    /// Store node positions produced by auto-layout, keyed by node ID.
+    fn commit_layout_positions(
+        &mut self,
+        positions: HashMap<Uuid, egui::Pos2>,
+    ) -> Result<(), String> {
+        let _ = positions;
+        Err("not supported".into())
+    }
+
     /// Set a pin's value from a string representation (for inline editing).
     fn set_pin_value(
         &mut self,
@@ -60,6 +60,44 @@ impl PinInfo {
     }
 }
 
+/// Which side of a node a pin is on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PinDirection {
+    Input,
+    Output,
+}
+
+/// Pin name of a reroute node's single input.
+pub const REROUTE_INPUT_PIN: &str = "in";
+/// Pin name of a reroute node's single output.
+pub const REROUTE_OUTPUT_PIN: &str = "out";
+
+/// A reroute (cable junction) node: one input and one output of the same type,
+/// drawn as a small dot. Connections through it render as two segments.
+#[derive(Clone, Debug)]
+pub struct RerouteNodeData {
+    pub id: Uuid,
+    /// Container the reroute lives in.
+    pub container_id: Uuid,
+    pub data_type: PinDataType,
+    /// Initial canvas position, used until the node is moved in the editor.
+    pub position: egui::Pos2,
+}
+
+/// Name of a subgraph pin that stands in for `pin` of the grouped node `node_id`.
+///
+/// Connections crossing the subgraph boundary stay attached to the grouped
+/// node; the subgraph exposes them under this name while it is folded.
+pub fn boundary_pin_name(node_id: Uuid, pin: &str) -> String {
+    format!("{}:{}", node_id, pin)
+}
+
+/// Grouped node and pin behind a [`boundary_pin_name`], if `name` is one.
+pub fn parse_boundary_pin(name: &str) -> Option<(Uuid, &str)> {
+    let (node_id, pin) = name.split_once(':')?;
+    Some((Uuid::parse_str(node_id).ok()?, pin))
+}
+
 /// A connection between two pins (view data).
 #[derive(Clone, Debug)]
 pub struct ConnectionView {
@@ -76,6 +114,8 @@ pub enum ContainerKind {
     Composition,
     Track,
     Layer,
+    /// Graph nodes grouped into a subgraph.
+    Subgraph,
 }
 
 /// How a node should be displayed.
@@ -151,6 +191,15 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_boundary_pin_name_round_trip() {
+        let node_id = Uuid::new_v4();
+        let name = boundary_pin_name(node_id, "image_out");
+        assert_eq!(parse_boundary_pin(&name), Some((node_id, "image_out")));
+        assert_eq!(parse_boundary_pin("image_out"), None);
+        assert_eq!(parse_boundary_pin("not-a-uuid:image_out"), None);
+    }
+
     #[test]
     fn test_pin_info_requires_data_type() {
         let pin = PinInfo::input("test", "Test", PinDataType::Scalar);
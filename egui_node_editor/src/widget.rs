@@ -4,13 +4,17 @@ use egui::{self, Color32, Pos2, Rect, Stroke, StrokeKind, Vec2};
 use std::collections::HashMap;
 use uuid::Uuid;
 
-use crate::drawing::{draw_bezier_connection, draw_grid};
+use crate::drawing::{draw_bezier_connection, draw_grid, draw_minimap, draw_reroute_node};
 use crate::interactions::{self, InteractionContext};
+use crate::layout;
 use crate::node_rendering::{self, NodeLayout};
 use crate::state::NodeEditorState;
 use crate::theme::NodeEditorTheme;
 use crate::traits::{NodeEditorDataSource, NodeEditorMutator};
-use crate::types::{ConnectionView, ContainerKind, NodeDisplay, PinDataType, PinInfo};
+use crate::types::{
+    ConnectionView, ContainerKind, NodeDisplay, PinDataType, PinDirection, PinInfo,
+    REROUTE_INPUT_PIN, REROUTE_OUTPUT_PIN, RerouteNodeData, parse_boundary_pin,
+};
 
 // ---------------------------------------------------------------------------
 // PendingActions
@@ -27,18 +31,59 @@ pub struct PendingActions {
     pub nodes_to_move: Vec<(Uuid, Uuid, Uuid)>,
     /// (node_id, pin_name, new_value_string)
     pub pin_value_changes: Vec<(Uuid, String, String)>,
+    /// (node_id, direction) whose pins should be disconnected.
+    pub pins_to_disconnect: Vec<(Uuid, PinDirection)>,
+    /// (node_id, output_pin) whose output should be rendered and pinned.
+    pub output_to_pin: Option<(Uuid, String)>,
+    /// Nodes to snapshot onto the clipboard.
+    pub nodes_to_copy: Option<Vec<Uuid>>,
+    /// (container_id, clipboard data) to paste.
+    pub nodes_to_paste: Option<(Uuid, String)>,
+    /// (container_id, node_ids) to group into a subgraph.
+    pub nodes_to_group: Option<(Uuid, Vec<Uuid>)>,
+    /// (container_id, connection_id, graph position) of a reroute to insert.
+    pub reroute_to_insert: Option<(Uuid, Uuid, Pos2)>,
+    /// Node positions computed by auto-layout, to persist through the mutator.
+    pub layout_positions: Option<HashMap<Uuid, Pos2>>,
     /// Optional: node selected in editor (for inspector sync).
     pub selected_node: Option<Uuid>,
 }
 
+/// Results of [`PendingActions::apply`] that the caller feeds back into the state.
+#[derive(Default)]
+pub struct AppliedActions {
+    /// Node whose pin request was rejected, with the reason, for
+    /// [`NodeEditorState::set_pinned_output_error`].
+    pub pin_error: Option<(Uuid, String)>,
+    /// Copied node IDs and their snapshot, for [`NodeEditorState::set_clipboard`].
+    pub copied_nodes: Option<(Vec<Uuid>, String)>,
+    /// `(original_id, copy_id)` pairs, for [`NodeEditorState::place_pasted_nodes`].
+    pub pasted_nodes: Vec<(Uuid, Uuid)>,
+    /// `(group_id, member_ids)` of a new subgraph, for [`NodeEditorState::place_group`].
+    pub grouped_nodes: Option<(Uuid, Vec<Uuid>)>,
+    /// `(reroute_id, graph position)` of an inserted reroute, for [`NodeEditorState::place_reroute`].
+    pub inserted_reroute: Option<(Uuid, Pos2)>,
+}
+
 impl PendingActions {
-    pub fn apply(self, mutator: &mut dyn NodeEditorMutator) {
+    /// Apply the actions through `mutator`.
+    pub fn apply(self, mutator: &mut dyn NodeEditorMutator) -> AppliedActions {
+        // Snapshot before anything this frame changes the copied nodes
+        let copied_nodes = self.nodes_to_copy.and_then(|node_ids| {
+            mutator
+                .copy_nodes(&node_ids)
+                .ok()
+                .map(|data| (node_ids, data))
+        });
         for node_id in self.nodes_to_remove {
             let _ = mutator.remove_node(node_id);
         }
         for conn_id in self.connections_to_remove {
             let _ = mutator.remove_connection(conn_id);
         }
+        for (node_id, direction) in self.pins_to_disconnect {
+            let _ = mutator.disconnect_node_pins(node_id, direction);
+        }
         for (from_node, from_pin, to_node, to_pin) in self.connections_to_add {
             let _ = mutator.add_connection(from_node, &from_pin, to_node, &to_pin);
         }
@@ -51,6 +96,40 @@ impl PendingActions {
         for (node_id, pin_name, value_str) in self.pin_value_changes {
             let _ = mutator.set_pin_value(node_id, &pin_name, &value_str);
         }
+        if let Some(positions) = self.layout_positions {
+            let _ = mutator.commit_layout_positions(positions);
+        }
+        let pasted_nodes = self
+            .nodes_to_paste
+            .and_then(|(container_id, data)| mutator.paste_nodes(container_id, &data).ok())
+            .unwrap_or_default();
+        let grouped_nodes = self.nodes_to_group.and_then(|(container_id, node_ids)| {
+            mutator
+                .group_nodes(container_id, &node_ids)
+                .ok()
+                .map(|group_id| (group_id, node_ids))
+        });
+        let inserted_reroute =
+            self.reroute_to_insert
+                .and_then(|(container_id, connection_id, pos)| {
+                    mutator
+                        .insert_reroute(container_id, connection_id)
+                        .ok()
+                        .map(|reroute_id| (reroute_id, pos))
+                });
+        let pin_error = self.output_to_pin.and_then(|(node_id, pin_name)| {
+            mutator
+                .pin_node_output(node_id, &pin_name)
+                .err()
+                .map(|err| (node_id, err))
+        });
+        AppliedActions {
+            pin_error,
+            copied_nodes,
+            pasted_nodes,
+            grouped_nodes,
+            inserted_reroute,
+        }
     }
 
     pub fn is_empty(&self) -> bool {
@@ -60,6 +139,13 @@ impl PendingActions {
             && self.nodes_to_add.is_empty()
             && self.nodes_to_move.is_empty()
             && self.pin_value_changes.is_empty()
+            && self.pins_to_disconnect.is_empty()
+            && self.output_to_pin.is_none()
+            && self.nodes_to_copy.is_none()
+            && self.nodes_to_paste.is_none()
+            && self.nodes_to_group.is_none()
+            && self.reroute_to_insert.is_none()
+            && self.layout_positions.is_none()
     }
 }
 
@@ -87,6 +173,8 @@ pub(crate) struct NodeInteraction {
     pub id: Uuid,
     pub rect: Rect,
     pub is_container: bool,
+    /// Reroute dots are dragged by their body rather than a header.
+    pub is_reroute: bool,
 }
 
 // ---------------------------------------------------------------------------
@@ -115,6 +203,13 @@ impl<'a> NodeEditorWidget<'a> {
             self.state.zoom = 1.0;
         }
 
+        if !source.has_container() {
+            ui.centered_and_justified(|ui| {
+                ui.label("Select a composition to edit its node graph");
+            });
+            return PendingActions::default();
+        }
+
         let container_id = match self.state.current_container {
             Some(id) => id,
             None => {
@@ -150,18 +245,7 @@ impl<'a> NodeEditorWidget<'a> {
                 });
         }
 
-        // Breadcrumb bar
-        ui.horizontal(|ui| {
-            ui.label("Container:");
-            if let Some(name) = source.get_container_name(container_id) {
-                ui.strong(&name);
-            }
-            if let Some(parent) = source.find_parent_container(container_id) {
-                if ui.small_button("\u{2191} Up").clicked() {
-                    self.state.current_container = Some(parent);
-                }
-            }
-        });
+        let auto_layout = self.draw_toolbar(ui, source, container_id);
         ui.separator();
 
         // Main canvas
@@ -170,6 +254,12 @@ impl<'a> NodeEditorWidget<'a> {
             ui.allocate_painter(available.size(), egui::Sense::click_and_drag());
         let canvas_rect = canvas_response.rect;
 
+        let layout_positions = auto_layout.then(|| {
+            let positions = self.auto_layout(source);
+            self.zoom_to_fit(source, &child_ids, canvas_rect.size());
+            positions
+        });
+
         // Zoom via scroll wheel (skip when context menus are open to prevent scroll leak)
         let any_menu_open = self.state.context_menu.is_some()
             || self.state.node_context_menu.is_some()
@@ -179,15 +269,23 @@ impl<'a> NodeEditorWidget<'a> {
                 if canvas_rect.contains(hover) {
                     let scroll = ui.input(|i| i.smooth_scroll_delta.y);
                     if scroll != 0.0 {
-                        let old_zoom = self.state.zoom;
-                        let new_zoom = (old_zoom + scroll * 0.002).clamp(0.2, 3.0);
-                        let graph_pos = (hover - canvas_rect.min - self.state.pan) / old_zoom;
-                        self.state.pan = hover - canvas_rect.min - graph_pos * new_zoom;
-                        self.state.zoom = new_zoom;
+                        let new_zoom = self.state.zoom + scroll * 0.002;
+                        self.state.zoom_around(hover - canvas_rect.min, new_zoom);
                     }
                 }
             }
         }
+
+        // Keyboard navigation (only while the canvas has focus)
+        if canvas_response.clicked()
+            || canvas_response.secondary_clicked()
+            || canvas_response.drag_started()
+        {
+            canvas_response.request_focus();
+        }
+        if canvas_response.has_focus() && !any_menu_open {
+            self.handle_keyboard_navigation(ui, &canvas_response, source, &child_ids);
+        }
         let zoom = self.state.zoom;
 
         // Panning
@@ -207,6 +305,7 @@ impl<'a> NodeEditorWidget<'a> {
 
         // ---- Phase 1: Draw nodes & collect pin positions ----
         let mut pin_screens: Vec<PinScreen> = Vec::new();
+        let mut hidden_pin_anchors: Vec<PinScreen> = Vec::new();
         let mut node_interactions: Vec<NodeInteraction> = Vec::new();
 
         for &child_id in &child_ids {
@@ -214,7 +313,7 @@ impl<'a> NodeEditorWidget<'a> {
                 continue;
             };
             let is_active = source.is_node_active(child_id);
-            let is_expanded = self.state.expanded_containers.contains(&child_id);
+            let is_expanded = self.is_expanded(child_id);
 
             self.draw_node(
                 source,
@@ -226,16 +325,36 @@ impl<'a> NodeEditorWidget<'a> {
                 is_active,
                 is_expanded,
                 &mut pin_screens,
+                &mut hidden_pin_anchors,
                 &mut node_interactions,
                 Some(container_id), // Top-level nodes are inside the current container
                 canvas_rect,        // Top-level nodes are clipped to canvas
             );
         }
 
+        self.draw_reroute_nodes(
+            source,
+            &painter,
+            canvas_rect,
+            container_id,
+            &mut pin_screens,
+            &mut node_interactions,
+        );
+        self.draw_pinned_output(source, &painter, &node_interactions);
+
         // Resize cursor for container edges
         let edge_width = 6.0 * zoom;
         let resize_handle_size = 16.0 * zoom;
         let header_h = self.theme.header_height * zoom;
+
+        // Error message of the node whose header is under the pointer
+        if let Some(message) = canvas_response
+            .hover_pos()
+            .and_then(|hover| node_header_at(&node_interactions, hover, header_h))
+            .and_then(|node_id| source.get_node_error_state(node_id))
+        {
+            canvas_response.clone().on_hover_text_at_pointer(message);
+        }
         if let Some(hover_pos) = ui.input(|i| i.pointer.hover_pos()) {
             let is_resizing = self.state.resizing.is_some();
             let mut on_resize_area = is_resizing;
@@ -274,20 +393,59 @@ impl<'a> NodeEditorWidget<'a> {
             }
         }
 
-        // Build lookup: (node_id, pin_name, is_output) -> screen pos
-        let pin_pos_map: HashMap<(Uuid, &str, bool), Pos2> = pin_screens
+        // Build lookup: (node_id, pin_name, is_output) -> screen pos.
+        // Pins hidden by collapsed nodes still anchor their connections at the node edge.
+        let mut pin_pos_map: HashMap<(Uuid, &str, bool), Pos2> = pin_screens
             .iter()
+            .chain(&hidden_pin_anchors)
             .map(|p| ((p.node_id, p.name.as_str(), p.is_output), p.pos))
             .collect();
+        // Pins of nodes inside folded subgraphs anchor at the subgraph's boundary pins
+        for p in &pin_screens {
+            if let Some((node_id, pin)) = parse_boundary_pin(&p.name) {
+                pin_pos_map
+                    .entry((node_id, pin, p.is_output))
+                    .or_insert(p.pos);
+            }
+        }
 
         // ---- Phase 2: Draw connections ON TOP of nodes ----
         let connections = source.get_connections();
         self.draw_connections(&painter, source, &connections, &pin_pos_map, &pin_screens);
-        self.draw_connecting_line(&painter, &pin_pos_map);
+        if self.state.show_live_values {
+            self.draw_live_values(&painter, source, &pin_screens, canvas_rect, zoom);
+        }
+        let hit_radius = self.theme.pin_radius * zoom * 4.0;
+        self.draw_connecting_line(&painter, &pin_pos_map, &pin_screens, mutator, hit_radius);
         self.draw_box_selection(&painter);
 
+        // Minimap sits above the canvas, so it takes clicks before the interactions below
+        let minimap_nodes: Vec<(Rect, Color32)> = child_ids
+            .iter()
+            .filter_map(|&id| {
+                let pos = *self.state.node_positions.get(&id)?;
+                let display = source.get_node_display(id)?;
+                let height = self.collapsed_node_height(id, &display);
+                Some((
+                    Rect::from_min_size(pos, Vec2::new(self.theme.node_width, height)),
+                    (self.theme.header_color)(&minimap_type_id(&display)),
+                ))
+            })
+            .collect();
+        if let Some(pan) = draw_minimap(
+            ui,
+            &painter,
+            canvas_rect,
+            &minimap_nodes,
+            self.state.pan,
+            zoom,
+            self.theme.minimap_size,
+            self.theme.selection_color,
+        ) {
+            self.state.pan = pan;
+        }
+
         // ---- Phase 3: Handle interactions ----
-        let hit_radius = self.theme.pin_radius * zoom * 4.0;
         let ctx = InteractionContext {
             ui,
             canvas_response: &canvas_response,
@@ -301,6 +459,7 @@ impl<'a> NodeEditorWidget<'a> {
             hit_radius,
         };
         let mut pending = interactions::handle_interactions(self.state, &ctx);
+        pending.layout_positions = layout_positions;
 
         // ---- Phase 3.5: Inline editors for unconnected input pins ----
         self.draw_inline_editors(ui, source, &pin_screens, &connections, zoom, &mut pending);
@@ -308,6 +467,133 @@ impl<'a> NodeEditorWidget<'a> {
         pending
     }
 
+    // -----------------------------------------------------------------------
+    // Keyboard navigation
+    // -----------------------------------------------------------------------
+
+    /// Handle `+`/`-` zoom, arrow-key panning, `Home` reset and `F` zoom-to-fit.
+    fn handle_keyboard_navigation(
+        &mut self,
+        ui: &egui::Ui,
+        canvas_response: &egui::Response,
+        source: &dyn NodeEditorDataSource,
+        child_ids: &[Uuid],
+    ) {
+        // Keep arrow keys from moving egui focus to another widget
+        ui.memory_mut(|m| {
+            m.set_focus_lock_filter(
+                canvas_response.id,
+                egui::EventFilter {
+                    horizontal_arrows: true,
+                    vertical_arrows: true,
+                    ..Default::default()
+                },
+            )
+        });
+
+        let canvas_rect = canvas_response.rect;
+        let center = canvas_rect.size() * 0.5;
+        let step = self.theme.keyboard_pan_step;
+
+        let (zoom_in, zoom_out, reset, fit, pan) = ui.input(|i| {
+            let mut pan = Vec2::ZERO;
+            if i.key_pressed(egui::Key::ArrowLeft) {
+                pan.x += step;
+            }
+            if i.key_pressed(egui::Key::ArrowRight) {
+                pan.x -= step;
+            }
+            if i.key_pressed(egui::Key::ArrowUp) {
+                pan.y += step;
+            }
+            if i.key_pressed(egui::Key::ArrowDown) {
+                pan.y -= step;
+            }
+            (
+                i.key_pressed(egui::Key::Plus) || i.key_pressed(egui::Key::Equals),
+                i.key_pressed(egui::Key::Minus),
+                i.key_pressed(egui::Key::Home),
+                i.key_pressed(egui::Key::F),
+                pan,
+            )
+        });
+
+        if zoom_in {
+            self.state.zoom_around(center, self.state.zoom * 1.1);
+        }
+        if zoom_out {
+            self.state.zoom_around(center, self.state.zoom / 1.1);
+        }
+        self.state.pan += pan;
+        if reset {
+            self.state.reset_view();
+        }
+        if fit {
+            self.zoom_to_fit(source, child_ids, canvas_rect.size());
+        }
+    }
+
+    /// Arrange the current container's nodes in columns following their
+    /// connections. Returns the new positions, which are also applied to the state.
+    pub fn auto_layout(&mut self, source: &dyn NodeEditorDataSource) -> HashMap<Uuid, Pos2> {
+        let Some(container_id) = self.state.current_container else {
+            return HashMap::new();
+        };
+        let child_ids = source.get_container_children(container_id);
+        let nodes: Vec<(Uuid, Vec2)> = child_ids
+            .iter()
+            .filter_map(|&id| {
+                let display = source.get_node_display(id)?;
+                let height = self.collapsed_node_height(id, &display);
+                Some((id, Vec2::new(self.theme.node_width, height)))
+            })
+            .collect();
+        let edges: Vec<(Uuid, Uuid)> = source
+            .get_connections()
+            .iter()
+            .map(|c| (c.from_node, c.to_node))
+            .collect();
+        // Keep the graph's top-left corner where it was
+        let origin = nodes
+            .iter()
+            .filter_map(|(id, _)| self.state.node_positions.get(id))
+            .fold(None, |min: Option<Pos2>, p| {
+                Some(min.map_or(*p, |m| m.min(*p)))
+            })
+            .unwrap_or(Pos2::new(50.0, 50.0));
+
+        let positions =
+            layout::layered_layout(&nodes, &edges, origin, self.theme.auto_layout_spacing);
+        self.state
+            .node_positions
+            .extend(positions.iter().map(|(id, p)| (*id, *p)));
+        positions
+    }
+
+    /// Pan and zoom so that all given top-level nodes are visible.
+    pub fn zoom_to_fit(
+        &mut self,
+        source: &dyn NodeEditorDataSource,
+        node_ids: &[Uuid],
+        canvas_size: Vec2,
+    ) {
+        let mut bounds: Option<Rect> = None;
+        for &id in node_ids {
+            let Some(pos) = self.state.node_positions.get(&id).copied() else {
+                continue;
+            };
+            let height = source
+                .get_node_display(id)
+                .map(|d| self.collapsed_node_height(id, &d))
+                .unwrap_or(self.theme.header_height + 8.0);
+            let rect = Rect::from_min_size(pos, Vec2::new(self.theme.node_width, height));
+            bounds = Some(bounds.map_or(rect, |b| b.union(rect)));
+        }
+        if let Some(bounds) = bounds {
+            self.state.fit_to_bounds(bounds, canvas_size, 40.0);
+        }
+    }
+
     // -----------------------------------------------------------------------
     // Drawing helpers (extracted from show)
     // -----------------------------------------------------------------------
@@ -350,12 +636,212 @@ impl<'a> NodeEditorWidget<'a> {
         }
     }
 
+    /// Draw the reroute nodes of the current container and register their pins.
+    ///
+    /// The pins sit just left and right of the dot and are joined through it, so a
+    /// connection into and out of a reroute renders as two bezier segments meeting
+    /// at the reroute point. The dot itself is the drag handle.
+    fn draw_reroute_nodes(
+        &mut self,
+        source: &dyn NodeEditorDataSource,
+        painter: &egui::Painter,
+        canvas_rect: Rect,
+        container_id: Uuid,
+        pin_screens: &mut Vec<PinScreen>,
+        interactions: &mut Vec<NodeInteraction>,
+    ) {
+        let zoom = self.state.zoom;
+        let radius = self.theme.reroute_radius * zoom;
+        for reroute in source.reroute_nodes() {
+            if reroute.container_id != container_id {
+                continue;
+            }
+            let pos = *self
+                .state
+                .node_positions
+                .entry(reroute.id)
+                .or_insert(reroute.position);
+            let center = canvas_rect.min + pos.to_vec2() * zoom + self.state.pan;
+
+            let body = Rect::from_center_size(center, Vec2::splat(radius * 2.0));
+            if let Some(rect) = interactions::clip_interaction_rect(body, canvas_rect) {
+                interactions.push(NodeInteraction {
+                    id: reroute.id,
+                    rect,
+                    is_container: false,
+                    is_reroute: true,
+                });
+            }
+            let pins = reroute_pin_screens(&reroute, center, radius);
+            let outline = self
+                .state
+                .selected_nodes
+                .contains(&reroute.id)
+                .then_some(self.theme.selection_color);
+            let color = (self.theme.pin_type_color)(&reroute.data_type);
+            draw_reroute_node(
+                painter,
+                center,
+                radius,
+                (pins[0].pos, pins[1].pos),
+                color,
+                outline,
+            );
+            pin_screens.extend(pins);
+        }
+    }
+
+    /// Draw the pinned output below its node, split against the live output when available.
+    /// A failed pin shows its error there instead.
+    fn draw_pinned_output(
+        &self,
+        source: &dyn NodeEditorDataSource,
+        painter: &egui::Painter,
+        interactions: &[NodeInteraction],
+    ) {
+        if let Some((node_id, message)) = &self.state.pinned_output_error
+            && let Some(node) = interactions.iter().find(|n| n.id == *node_id)
+        {
+            painter.text(
+                node.rect.left_bottom() + Vec2::new(0.0, 4.0 * self.state.zoom),
+                egui::Align2::LEFT_TOP,
+                message,
+                egui::FontId::proportional(11.0 * self.state.zoom),
+                self.theme.error_color,
+            );
+        }
+        let Some((node_id, texture)) = &self.state.pinned_node_output else {
+            return;
+        };
+        let Some(node) = interactions.iter().find(|n| n.id == *node_id) else {
+            return;
+        };
+        let rect = pinned_output_rect(node.rect, texture.size_vec2(), 4.0 * self.state.zoom);
+        let full_uv = Rect::from_min_max(Pos2::ZERO, Pos2::new(1.0, 1.0));
+
+        match source.node_output_preview(*node_id) {
+            Some(live) => {
+                let (left, right) = rect.split_left_right_at_fraction(0.5);
+                let (left_uv, right_uv) = full_uv.split_left_right_at_fraction(0.5);
+                painter.image(texture.id(), left, left_uv, Color32::WHITE);
+                painter.image(live, right, right_uv, Color32::WHITE);
+                painter.vline(
+                    rect.center().x,
+                    rect.y_range(),
+                    Stroke::new(1.0, self.theme.selection_color),
+                );
+            }
+            None => {
+                painter.image(texture.id(), rect, full_uv, Color32::WHITE);
+            }
+        }
+        painter.rect_stroke(
+            rect,
+            2.0,
+            Stroke::new(1.0, self.theme.selection_color),
+            StrokeKind::Outside,
+        );
+    }
+
+    fn draw_live_values(
+        &self,
+        painter: &egui::Painter,
+        source: &dyn NodeEditorDataSource,
+        pin_screens: &[PinScreen],
+        canvas_rect: Rect,
+        zoom: f32,
+    ) {
+        if !self.theme.should_show_pin_labels(zoom) {
+            return;
+        }
+        for ps in pin_screens.iter().filter(|ps| canvas_rect.contains(ps.pos)) {
+            if let Some(value) = source.get_pin_value(ps.node_id, &ps.name) {
+                node_rendering::draw_pin_value(
+                    painter,
+                    self.theme,
+                    ps.pos,
+                    &value,
+                    ps.is_output,
+                    zoom,
+                );
+            }
+        }
+    }
+
+    /// Whether `node_id` is a container expanded inline. Folded subgraphs never are.
+    fn is_expanded(&self, node_id: Uuid) -> bool {
+        self.state.expanded_containers.contains(&node_id)
+            && !self.state.collapsed_groups.contains(&node_id)
+    }
+
+    /// Auto-layout button and live value toggle, then the path from the root
+    /// container to `container_id`. Ancestors in the path jump back up when clicked.
+    /// Returns whether auto-layout was requested.
+    fn draw_toolbar(
+        &mut self,
+        ui: &mut egui::Ui,
+        source: &dyn NodeEditorDataSource,
+        container_id: Uuid,
+    ) -> bool {
+        let mut path = vec![container_id];
+        while let Some(parent) = path.last().and_then(|&id| source.find_parent_container(id)) {
+            path.push(parent);
+        }
+        path.reverse();
+
+        let mut auto_layout = false;
+        ui.horizontal(|ui| {
+            auto_layout = ui
+                .button("Auto-layout")
+                .on_hover_text("Arrange nodes in columns following their connections")
+                .clicked();
+            ui.toggle_value(&mut self.state.show_live_values, "Live Values")
+                .on_hover_text("Show the last evaluated value next to each pin");
+            ui.separator();
+            ui.label("Container:");
+            for (i, &id) in path.iter().enumerate() {
+                if i > 0 {
+                    ui.label("\u{203A}");
+                }
+                let name = source
+                    .get_container_name(id)
+                    .unwrap_or_else(|| "?".to_string());
+                if id == container_id {
+                    ui.strong(name);
+                } else if ui.link(name).clicked() {
+                    self.state.current_container = Some(id);
+                    self.state.selected_nodes.clear();
+                    self.state.selected_connections.clear();
+                }
+            }
+        });
+        auto_layout
+    }
+
     fn draw_connecting_line(
         &self,
         painter: &egui::Painter,
         pin_pos_map: &HashMap<(Uuid, &str, bool), Pos2>,
+        pin_screens: &[PinScreen],
+        mutator: &dyn NodeEditorMutator,
+        hit_radius: f32,
     ) {
         if let Some(ref connecting) = self.state.connecting {
+            // Green/red once the wire hovers a pin it would (not) connect to
+            let color = match interactions::connection_target(
+                connecting,
+                pin_screens,
+                connecting.mouse_pos,
+                hit_radius,
+            ) {
+                Some(target)
+                    if interactions::is_connection_allowed(pin_screens, mutator, target) =>
+                {
+                    self.theme.valid_connection_color
+                }
+                Some(_) => self.theme.invalid_connection_color,
+                None => Color32::from_rgb(200, 200, 200),
+            };
             let start = pin_pos_map
                 .get(&(
                     connecting.from_node,
@@ -370,12 +856,7 @@ impl<'a> NodeEditorWidget<'a> {
                     ))
                 });
             if let Some(&start_pos) = start {
-                draw_bezier_connection(
-                    painter,
-                    start_pos,
-                    connecting.mouse_pos,
-                    Color32::from_rgb(200, 200, 200),
-                );
+                draw_bezier_connection(painter, start_pos, connecting.mouse_pos, color);
             }
         }
     }
@@ -647,6 +1128,7 @@ impl<'a> NodeEditorWidget<'a> {
         is_active: bool,
         is_expanded: bool,
         pin_screens: &mut Vec<PinScreen>,
+        hidden_pin_anchors: &mut Vec<PinScreen>,
         interactions: &mut Vec<NodeInteraction>,
         parent_container_id: Option<Uuid>,
         visible_clip_rect: Rect,
@@ -664,6 +1146,7 @@ impl<'a> NodeEditorWidget<'a> {
                     ContainerKind::Composition => ("composition", "Composition"),
                     ContainerKind::Track => ("track", "Track"),
                     ContainerKind::Layer => ("layer", "Layer"),
+                    ContainerKind::Subgraph => ("subgraph", "Subgraph"),
                 };
                 (
                     type_id.to_string(),
@@ -685,11 +1168,20 @@ impl<'a> NodeEditorWidget<'a> {
             .unwrap_or(Pos2::ZERO);
         let screen_pos = canvas_min + (pos.to_vec2() * zoom) + self.state.pan;
 
-        let input_pins: Vec<&PinInfo> = pins.iter().filter(|p| !p.is_output).collect();
-        let output_pins: Vec<&PinInfo> = pins.iter().filter(|p| p.is_output).collect();
+        let mut input_pins: Vec<&PinInfo> = pins.iter().filter(|p| !p.is_output).collect();
+        let mut output_pins: Vec<&PinInfo> = pins.iter().filter(|p| p.is_output).collect();
         let is_container = matches!(display, NodeDisplay::Container { .. });
+        // Containers fold via inline expansion instead
+        let is_collapsed = !is_container && self.state.collapsed_nodes.contains(&node_id);
+        let mut hidden_pins: Vec<&PinInfo> = Vec::new();
+        if is_collapsed {
+            hidden_pins.extend(input_pins.drain(input_pins.len().min(1)..));
+            hidden_pins.extend(output_pins.drain(output_pins.len().min(1)..));
+        }
+        // Subgraph boundary pins are laid out like a regular node's pins
+        let paired_pins = is_container && !is_subgraph(display);
         // For containers, paired pins share rows — count only output pins.
-        let pin_count = if is_container {
+        let pin_count = if paired_pins {
             output_pins.len()
         } else {
             input_pins.len().max(output_pins.len())
@@ -766,6 +1258,7 @@ impl<'a> NodeEditorWidget<'a> {
                 id: node_id,
                 rect: clipped_rect,
                 is_container,
+                is_reroute: false,
             });
         }
 
@@ -795,6 +1288,18 @@ impl<'a> NodeEditorWidget<'a> {
             is_active,
             zoom,
         );
+        if !is_container {
+            node_rendering::draw_collapse_chevron(painter, &layout, is_collapsed, is_active, zoom);
+        }
+        if source.get_node_error_state(node_id).is_some() {
+            node_rendering::draw_node_error(painter, &layout, self.theme, zoom);
+        }
+        hidden_pin_anchors.extend(node_rendering::hidden_pin_anchors(
+            &layout,
+            node_id,
+            &hidden_pins,
+            parent_container_id,
+        ));
 
         // Draw pins
         node_rendering::draw_pins(
@@ -805,7 +1310,7 @@ impl<'a> NodeEditorWidget<'a> {
             node_id,
             &input_pins,
             &output_pins,
-            is_container,
+            paired_pins,
             is_active,
             zoom,
             pin_screens,
@@ -846,7 +1351,7 @@ impl<'a> NodeEditorWidget<'a> {
                         continue;
                     };
                     let child_active = source.is_node_active(child_id);
-                    let child_expanded = self.state.expanded_containers.contains(&child_id);
+                    let child_expanded = self.is_expanded(child_id);
 
                     self.draw_node(
                         source,
@@ -858,6 +1363,7 @@ impl<'a> NodeEditorWidget<'a> {
                         child_active,
                         child_expanded,
                         pin_screens,
+                        hidden_pin_anchors,
                         interactions,
                         Some(node_id), // Children are inside this container
                         clip_rect,     // Children are clipped to container interior
@@ -872,7 +1378,7 @@ impl<'a> NodeEditorWidget<'a> {
         }
 
         // Port pins for expanded containers
-        if is_expanded && is_container {
+        if is_expanded && paired_pins {
             node_rendering::draw_port_pins(
                 painter,
                 &layout,
@@ -904,18 +1410,7 @@ impl<'a> NodeEditorWidget<'a> {
                 .copied()
                 .unwrap_or(Pos2::ZERO);
             let node_h = if let Some(d) = source.get_node_display(cid) {
-                let pin_count = match &d {
-                    NodeDisplay::Container { pins, .. } => {
-                        // Containers: paired pins share rows
-                        pins.iter().filter(|p| p.is_output).count()
-                    }
-                    NodeDisplay::Graph { pins, .. } | NodeDisplay::Leaf { pins, .. } => {
-                        let ic = pins.iter().filter(|p| !p.is_output).count();
-                        let oc = pins.iter().filter(|p| p.is_output).count();
-                        ic.max(oc)
-                    }
-                };
-                self.theme.header_height + pin_count as f32 * self.theme.pin_row_height + 8.0
+                self.collapsed_node_height(cid, &d)
             } else {
                 self.theme.header_height + 8.0
             };
@@ -924,4 +1419,175 @@ impl<'a> NodeEditorWidget<'a> {
 
         (max_bottom * zoom + 20.0 * zoom).max(min_h)
     }
+
+    /// Height of a non-expanded node in graph space (unscaled by zoom).
+    fn collapsed_node_height(&self, node_id: Uuid, display: &NodeDisplay) -> f32 {
+        let mut pin_count = match display {
+            NodeDisplay::Container { pins, .. } if !is_subgraph(display) => {
+                // Containers: paired pins share rows
+                pins.iter().filter(|p| p.is_output).count()
+            }
+            NodeDisplay::Graph { pins, .. }
+            | NodeDisplay::Leaf { pins, .. }
+            | NodeDisplay::Container { pins, .. } => {
+                let ic = pins.iter().filter(|p| !p.is_output).count();
+                let oc = pins.iter().filter(|p| p.is_output).count();
+                ic.max(oc)
+            }
+        };
+        if self.state.collapsed_nodes.contains(&node_id) {
+            pin_count = pin_count.min(1);
+        }
+        self.theme.header_height + pin_count as f32 * self.theme.pin_row_height + 8.0
+    }
+}
+
+fn is_subgraph(display: &NodeDisplay) -> bool {
+    matches!(
+        display,
+        NodeDisplay::Container {
+            kind: ContainerKind::Subgraph,
+            ..
+        }
+    )
+}
+
+/// Type id used to colour a node's header, for the minimap.
+fn minimap_type_id(display: &NodeDisplay) -> String {
+    match display {
+        NodeDisplay::Graph { type_id, .. } => type_id.clone(),
+        NodeDisplay::Container { kind, .. } => match kind {
+            ContainerKind::Composition => "composition",
+            ContainerKind::Track => "track",
+            ContainerKind::Layer => "layer",
+            ContainerKind::Subgraph => "subgraph",
+        }
+        .to_string(),
+        NodeDisplay::Leaf { kind_label, .. } => format!("source.{}", kind_label),
+    }
+}
+
+/// Rect for a pinned output thumbnail: directly below `node_rect`, as wide as the
+/// node and keeping the image's aspect ratio.
+fn pinned_output_rect(node_rect: Rect, image_size: Vec2, gap: f32) -> Rect {
+    let width = node_rect.width();
+    let height = if image_size.x > 0.0 {
+        width * image_size.y / image_size.x
+    } else {
+        0.0
+    };
+    Rect::from_min_size(
+        Pos2::new(node_rect.min.x, node_rect.max.y + gap),
+        Vec2::new(width, height),
+    )
+}
+
+/// Topmost node whose header contains `pos`.
+fn node_header_at(nodes: &[NodeInteraction], pos: Pos2, header_h: f32) -> Option<Uuid> {
+    nodes
+        .iter()
+        .rev()
+        .find(|node| {
+            !node.is_reroute && node.rect.contains(pos) && pos.y <= node.rect.min.y + header_h
+        })
+        .map(|node| node.id)
+}
+
+/// Input and output pins of a reroute node, one dot diameter left and right of
+/// its screen center so they can be hit apart from the dot.
+fn reroute_pin_screens(reroute: &RerouteNodeData, center: Pos2, radius: f32) -> [PinScreen; 2] {
+    let pin = |name: &str, is_output: bool| PinScreen {
+        pos: center + Vec2::new(if is_output { 2.0 } else { -2.0 } * radius, 0.0),
+        node_id: reroute.id,
+        name: name.to_string(),
+        is_output,
+        data_type: reroute.data_type.clone(),
+        container_id: Some(reroute.container_id),
+    };
+    [pin(REROUTE_INPUT_PIN, false), pin(REROUTE_OUTPUT_PIN, true)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pinned_output_rect_keeps_aspect_below_node() {
+        let node = Rect::from_min_size(Pos2::new(10.0, 20.0), Vec2::new(160.0, 80.0));
+        let rect = pinned_output_rect(node, Vec2::new(1920.0, 1080.0), 4.0);
+        assert_eq!(rect.min, Pos2::new(10.0, 104.0));
+        assert_eq!(rect.width(), 160.0);
+        assert_eq!(rect.height(), 90.0);
+    }
+
+    #[test]
+    fn test_reroute_body_and_pins_have_separate_hit_areas() {
+        let reroute = RerouteNodeData {
+            id: Uuid::new_v4(),
+            container_id: Uuid::new_v4(),
+            data_type: PinDataType::Image,
+            position: Pos2::ZERO,
+        };
+        let (center, radius, hit_radius) = (Pos2::new(40.0, 25.0), 6.0, 20.0);
+        let pins = reroute_pin_screens(&reroute, center, radius);
+        let nodes = [NodeInteraction {
+            id: reroute.id,
+            rect: Rect::from_center_size(center, Vec2::splat(radius * 2.0)),
+            is_container: false,
+            is_reroute: true,
+        }];
+
+        assert_eq!(
+            (pins[0].name.as_str(), pins[0].is_output),
+            (REROUTE_INPUT_PIN, false)
+        );
+        assert_eq!(
+            (pins[1].name.as_str(), pins[1].is_output),
+            (REROUTE_OUTPUT_PIN, true)
+        );
+        assert_eq!(pins[1].data_type, PinDataType::Image);
+        assert_eq!(pins[0].container_id, Some(reroute.container_id));
+
+        // ドットの上ではピンより先にノードのドラッグが始まる
+        assert_eq!(
+            interactions::reroute_body_at(&nodes, center),
+            Some(reroute.id)
+        );
+        // ピンの上ではドラッグせず、そのピンから配線が始まる
+        for pin in &pins {
+            assert_eq!(interactions::reroute_body_at(&nodes, pin.pos), None);
+            let hit = interactions::find_nearest_pin(&pins, pin.pos, hit_radius, None, None);
+            assert_eq!(
+                hit.map(|p| (p.name.as_str(), p.is_output)),
+                Some((pin.name.as_str(), pin.is_output))
+            );
+        }
+    }
+
+    #[test]
+    fn test_node_header_at_prefers_topmost_header() {
+        let node = |id: Uuid, min: Pos2| NodeInteraction {
+            id,
+            rect: Rect::from_min_size(min, Vec2::new(100.0, 80.0)),
+            is_container: false,
+            is_reroute: false,
+        };
+        let (below, above) = (Uuid::new_v4(), Uuid::new_v4());
+        let nodes = [
+            node(below, Pos2::new(0.0, 0.0)),
+            node(above, Pos2::new(0.0, 10.0)),
+        ];
+
+        // 後から描画されたノードのヘッダーが優先される
+        assert_eq!(
+            node_header_at(&nodes, Pos2::new(50.0, 20.0), 24.0),
+            Some(above)
+        );
+        assert_eq!(
+            node_header_at(&nodes, Pos2::new(50.0, 5.0), 24.0),
+            Some(below)
+        );
+        // ボディ部分はヘッダーではない
+        assert_eq!(node_header_at(&nodes, Pos2::new(50.0, 60.0), 24.0), None);
+    }
 }
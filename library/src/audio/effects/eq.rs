@@ -0,0 +1,266 @@
+//! 3-band parametric EQ (low shelf, peak, high shelf) built from RBJ biquads.
+
+use super::AudioEffect;
+use crate::plugin::Plugin;
+use crate::project::property::{PropertyDefinition, PropertyUiType, PropertyValue};
+use ordered_float::OrderedFloat;
+use std::collections::HashMap;
+use std::f64::consts::PI;
+
+pub struct EqEffectPlugin;
+
+impl EqEffectPlugin {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Plugin for EqEffectPlugin {
+    fn id(&self) -> &'static str {
+        "eq"
+    }
+
+    fn name(&self) -> String {
+        "EQ".to_string()
+    }
+
+    fn category(&self) -> String {
+        "Audio".to_string()
+    }
+
+    fn version(&self) -> (u32, u32, u32) {
+        (0, 1, 0)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum BandKind {
+    LowShelf,
+    Peak,
+    HighShelf,
+}
+
+/// Normalized biquad coefficients (a0 == 1).
+#[derive(Debug, Clone, Copy)]
+struct Biquad {
+    b0: f64,
+    b1: f64,
+    b2: f64,
+    a1: f64,
+    a2: f64,
+}
+
+impl Biquad {
+    fn new(kind: BandKind, sample_rate: u32, freq: f64, gain_db: f64, q: f64) -> Self {
+        let nyquist = sample_rate as f64 / 2.0;
+        let freq = freq.clamp(1.0, nyquist * 0.99);
+        let q = q.max(0.01);
+
+        let a = 10f64.powf(gain_db / 40.0);
+        let w0 = 2.0 * PI * freq / sample_rate as f64;
+        let (sin_w0, cos_w0) = w0.sin_cos();
+
+        let (b0, b1, b2, a0, a1, a2) = match kind {
+            BandKind::Peak => {
+                let alpha = sin_w0 / (2.0 * q);
+                (
+                    1.0 + alpha * a,
+                    -2.0 * cos_w0,
+                    1.0 - alpha * a,
+                    1.0 + alpha / a,
+                    -2.0 * cos_w0,
+                    1.0 - alpha / a,
+                )
+            }
+            BandKind::LowShelf => {
+                // Shelf slope S = 1
+                let two_sqrt_a_alpha = 2.0 * a.sqrt() * (sin_w0 / 2.0 * 2f64.sqrt());
+                (
+                    a * ((a + 1.0) - (a - 1.0) * cos_w0 + two_sqrt_a_alpha),
+                    2.0 * a * ((a - 1.0) - (a + 1.0) * cos_w0),
+                    a * ((a + 1.0) - (a - 1.0) * cos_w0 - two_sqrt_a_alpha),
+                    (a + 1.0) + (a - 1.0) * cos_w0 + two_sqrt_a_alpha,
+                    -2.0 * ((a - 1.0) + (a + 1.0) * cos_w0),
+                    (a + 1.0) + (a - 1.0) * cos_w0 - two_sqrt_a_alpha,
+                )
+            }
+            BandKind::HighShelf => {
+                let two_sqrt_a_alpha = 2.0 * a.sqrt() * (sin_w0 / 2.0 * 2f64.sqrt());
+                (
+                    a * ((a + 1.0) + (a - 1.0) * cos_w0 + two_sqrt_a_alpha),
+                    -2.0 * a * ((a - 1.0) + (a + 1.0) * cos_w0),
+                    a * ((a + 1.0) + (a - 1.0) * cos_w0 - two_sqrt_a_alpha),
+                    (a + 1.0) - (a - 1.0) * cos_w0 + two_sqrt_a_alpha,
+                    2.0 * ((a - 1.0) - (a + 1.0) * cos_w0),
+                    (a + 1.0) - (a - 1.0) * cos_w0 - two_sqrt_a_alpha,
+                )
+            }
+        };
+
+        Self {
+            b0: b0 / a0,
+            b1: b1 / a0,
+            b2: b2 / a0,
+            a1: a1 / a0,
+            a2: a2 / a0,
+        }
+    }
+
+    /// Filter the buffer in place (Direct Form I, zero initial state).
+    fn process(&self, samples: &mut [f32]) {
+        let (mut x1, mut x2, mut y1, mut y2) = (0.0f64, 0.0f64, 0.0f64, 0.0f64);
+        for sample in samples.iter_mut() {
+            let x0 = *sample as f64;
+            let y0 = self.b0 * x0 + self.b1 * x1 + self.b2 * x2 - self.a1 * y1 - self.a2 * y2;
+            x2 = x1;
+            x1 = x0;
+            y2 = y1;
+            y1 = y0;
+            *sample = y0 as f32;
+        }
+    }
+}
+
+impl AudioEffect for EqEffectPlugin {
+    fn process(&self, samples: &mut Vec<f32>, sample_rate: u32, params: &HashMap<String, f64>) {
+        if sample_rate == 0 {
+            return;
+        }
+        let param = |key: &str, default: f64| params.get(key).copied().unwrap_or(default);
+
+        let bands = [
+            (
+                BandKind::LowShelf,
+                param("low_freq", 200.0),
+                param("low_gain", 0.0),
+                std::f64::consts::FRAC_1_SQRT_2,
+            ),
+            (
+                BandKind::Peak,
+                param("mid_freq", 1000.0),
+                param("mid_gain", 0.0),
+                param("mid_q", 1.0),
+            ),
+            (
+                BandKind::HighShelf,
+                param("high_freq", 5000.0),
+                param("high_gain", 0.0),
+                std::f64::consts::FRAC_1_SQRT_2,
+            ),
+        ];
+
+        for (kind, freq, gain_db, q) in bands {
+            // A 0 dB band is an identity filter
+            if gain_db.abs() < 1e-6 {
+                continue;
+            }
+            Biquad::new(kind, sample_rate, freq, gain_db, q).process(samples);
+        }
+    }
+
+    fn properties(&self) -> Vec<PropertyDefinition> {
+        let freq = |name: &str, label: &str, default: f64| {
+            PropertyDefinition::new(
+                name,
+                PropertyUiType::Float {
+                    min: 20.0,
+                    max: 20000.0,
+                    step: 1.0,
+                    suffix: "Hz".to_string(),
+                    min_hard_limit: true,
+                    max_hard_limit: true,
+                },
+                label,
+                PropertyValue::Number(OrderedFloat(default)),
+            )
+        };
+        let gain = |name: &str, label: &str| {
+            PropertyDefinition::new(
+                name,
+                PropertyUiType::Float {
+                    min: -24.0,
+                    max: 24.0,
+                    step: 0.1,
+                    suffix: "dB".to_string(),
+                    min_hard_limit: true,
+                    max_hard_limit: true,
+                },
+                label,
+                PropertyValue::Number(OrderedFloat(0.0)),
+            )
+        };
+
+        vec![
+            freq("low_freq", "Low Frequency", 200.0),
+            gain("low_gain", "Low Gain"),
+            freq("mid_freq", "Mid Frequency", 1000.0),
+            gain("mid_gain", "Mid Gain"),
+            PropertyDefinition::new(
+                "mid_q",
+                PropertyUiType::Float {
+                    min: 0.1,
+                    max: 10.0,
+                    step: 0.01,
+                    suffix: "".to_string(),
+                    min_hard_limit: true,
+                    max_hard_limit: true,
+                },
+                "Mid Q",
+                PropertyValue::Number(OrderedFloat(1.0)),
+            ),
+            freq("high_freq", "High Frequency", 5000.0),
+            gain("high_gain", "High Gain"),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sine(freq: f64, sample_rate: u32, len: usize) -> Vec<f32> {
+        (0..len)
+            .map(|i| (2.0 * PI * freq * i as f64 / sample_rate as f64).sin() as f32)
+            .collect()
+    }
+
+    fn peak_after(samples: &[f32], skip: usize) -> f32 {
+        samples[skip..].iter().fold(0.0f32, |m, s| m.max(s.abs()))
+    }
+
+    #[test]
+    fn test_eq_flat_is_passthrough() {
+        // 全バンド0dBなら入力がそのまま残る
+        let input = sine(440.0, 48000, 4800);
+        let mut samples = input.clone();
+        EqEffectPlugin::new().process(&mut samples, 48000, &HashMap::new());
+        assert_eq!(samples, input);
+    }
+
+    #[test]
+    fn test_eq_low_shelf_boosts_low_frequencies() {
+        // +6dBのローシェルフで低域の振幅がおよそ2倍になる
+        let mut samples = sine(50.0, 48000, 48000);
+        let params = HashMap::from([
+            ("low_freq".to_string(), 1000.0),
+            ("low_gain".to_string(), 6.0),
+        ]);
+        EqEffectPlugin::new().process(&mut samples, 48000, &params);
+        let peak = peak_after(&samples, 24000);
+        assert!((peak - 1.995).abs() < 0.05, "peak = {}", peak);
+    }
+
+    #[test]
+    fn test_eq_peak_cut_leaves_distant_band() {
+        // 1kHzのカットは10kHzの信号にほぼ影響しない
+        let mut samples = sine(10000.0, 48000, 9600);
+        let params = HashMap::from([
+            ("mid_freq".to_string(), 1000.0),
+            ("mid_gain".to_string(), -12.0),
+            ("mid_q".to_string(), 2.0),
+        ]);
+        EqEffectPlugin::new().process(&mut samples, 48000, &params);
+        let peak = peak_after(&samples, 4800);
+        assert!((peak - 1.0).abs() < 0.05, "peak = {}", peak);
+    }
+}
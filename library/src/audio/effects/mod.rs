@@ -0,0 +1,90 @@
+//! Audio effect plugins applied to audio clips during mixing.
+
+pub mod eq;
+
+pub use self::eq::EqEffectPlugin;
+
+use crate::plugin::{Plugin, PluginCategory};
+use crate::project::effect::EffectConfig;
+use crate::project::property::PropertyDefinition;
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+
+/// Plugin trait for audio effects.
+///
+/// `samples` holds a single (non-interleaved) channel.
+pub trait AudioEffect: Plugin {
+    #[allow(clippy::ptr_arg)]
+    fn process(&self, samples: &mut Vec<f32>, sample_rate: u32, params: &HashMap<String, f64>);
+
+    fn properties(&self) -> Vec<PropertyDefinition>;
+
+    fn plugin_type(&self) -> PluginCategory {
+        PluginCategory::AudioEffect
+    }
+}
+
+/// Built-in audio effects, shared by the plugin manager and the mixer.
+pub fn builtin_audio_effects() -> &'static [Arc<dyn AudioEffect>] {
+    static EFFECTS: OnceLock<Vec<Arc<dyn AudioEffect>>> = OnceLock::new();
+    EFFECTS.get_or_init(|| vec![Arc::new(EqEffectPlugin::new()) as Arc<dyn AudioEffect>])
+}
+
+/// Look up a built-in audio effect by id.
+pub fn get_builtin_audio_effect(id: &str) -> Option<Arc<dyn AudioEffect>> {
+    builtin_audio_effects()
+        .iter()
+        .find(|effect| effect.id() == id)
+        .cloned()
+}
+
+/// Apply a chain of audio effect configs to an interleaved buffer in place.
+///
+/// Only constant property values are used; unknown effect types are skipped.
+pub fn apply_audio_effects(
+    samples: &mut [f32],
+    channels: usize,
+    sample_rate: u32,
+    effects: &[EffectConfig],
+) {
+    if channels == 0 || effects.is_empty() {
+        return;
+    }
+
+    for config in effects {
+        let Some(effect) = get_builtin_audio_effect(&config.effect_type) else {
+            log::warn!("Audio effect '{}' not found", config.effect_type);
+            continue;
+        };
+
+        let params: HashMap<String, f64> = effect
+            .properties()
+            .iter()
+            .filter_map(|def| {
+                let value = config
+                    .properties
+                    .get_f64(def.name())
+                    .or_else(|| def.default_value().get_as::<f64>())?;
+                Some((def.name().to_string(), value))
+            })
+            .collect();
+
+        for channel in 0..channels {
+            let mut channel_samples: Vec<f32> = samples
+                .iter()
+                .skip(channel)
+                .step_by(channels)
+                .copied()
+                .collect();
+            effect.process(&mut channel_samples, sample_rate, &params);
+            for (dst, src) in samples
+                .iter_mut()
+                .skip(channel)
+                .step_by(channels)
+                .zip(channel_samples)
+            {
+                *dst = src;
+            }
+        }
+    }
+}
@@ -115,40 +115,13 @@ impl AudioLoader {
 
         // Resample if needed using Linear Interpolation
         if source_sample_rate != target_sample_rate && target_sample_rate > 0 {
-            let ratio = source_sample_rate as f32 / target_sample_rate as f32;
-            let num_channels = 2; // We forced Stereo above
-
-            // Calculate new length
-            // input frames = audio_data.len() / 2
-            let input_frames = audio_data.len() / num_channels;
-            let output_frames = (input_frames as f32 / ratio).ceil() as usize;
-
-            let mut resampled_data = Vec::with_capacity(output_frames * num_channels);
-
-            for i in 0..output_frames {
-                let src_idx_float = i as f32 * ratio;
-                let src_idx_floor = src_idx_float.floor() as usize;
-                let t = src_idx_float - src_idx_float.floor(); // Fractional part for interpolation (0.0..1.0)
-
-                // Clamp to valid range
-                let idx0 = src_idx_floor.min(input_frames - 1);
-                let idx1 = (src_idx_floor + 1).min(input_frames - 1);
-
-                // Interleaved access: [L0, R0, L1, R1, ...]
-                let l0 = audio_data[idx0 * 2];
-                let r0 = audio_data[idx0 * 2 + 1];
-                let l1 = audio_data[idx1 * 2];
-                let r1 = audio_data[idx1 * 2 + 1];
-
-                // Linear Interpolation
-                let l_out = l0 + (l1 - l0) * t;
-                let r_out = r0 + (r1 - r0) * t;
-
-                resampled_data.push(l_out);
-                resampled_data.push(r_out);
-            }
-
-            audio_data = resampled_data;
+            // We forced Stereo above
+            audio_data = crate::audio::resample::resample_linear(
+                &audio_data,
+                2,
+                source_sample_rate,
+                target_sample_rate,
+            );
         }
 
         // Add proper handling for Stereo/Mono conversion
@@ -1,3 +1,9 @@
+use std::borrow::Cow;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+use crate::audio::effects::apply_audio_effects;
+use crate::audio::resample::{remap_window, resample_window};
 use crate::cache::CacheManager;
 use crate::project::asset::Asset;
 use crate::project::node::Node;
@@ -5,19 +11,54 @@ use crate::project::project::{Composition, Project};
 use crate::project::source::SourceData;
 use uuid::Uuid;
 
-/// Recursively collect all sources from the project starting at a given node
-fn collect_sources_recursive<'a>(project: &'a Project, node_id: Uuid) -> Vec<&'a SourceData> {
+/// Mixed interleaved float audio together with its format.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StereoAudioBuffer {
+    pub sample_rate: u32,
+    pub channels: u16,
+    /// Interleaved samples (`channels` values per frame).
+    pub samples: Vec<f32>,
+}
+
+impl StereoAudioBuffer {
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Write the samples as raw little-endian f32 (FFmpeg's `f32le` input format).
+    pub fn write_f32le(&self, path: &str) -> std::io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        for sample in &self.samples {
+            writer.write_all(&sample.to_le_bytes())?;
+        }
+        writer.flush()
+    }
+}
+
+/// Pre-roll length for clip audio effects, as a fraction of a second (1/20 s).
+const EFFECT_PRE_ROLL_DIVISOR: usize = 20;
+
+/// Recursively collect all sources from the project starting at a given node,
+/// skipping tracks silenced by mute/solo
+fn collect_sources_recursive<'a>(
+    project: &'a Project,
+    composition: &Composition,
+    node_id: Uuid,
+) -> Vec<&'a SourceData> {
     let mut sources = Vec::new();
     match project.get_node(node_id) {
         Some(Node::Source(s)) => sources.push(s),
         Some(Node::Track(t)) => {
+            if project.is_track_silenced(composition, t.id) {
+                return sources;
+            }
             for child_id in &t.child_ids {
-                sources.extend(collect_sources_recursive(project, *child_id));
+                sources.extend(collect_sources_recursive(project, composition, *child_id));
             }
         }
         Some(Node::Layer(l)) => {
             for child_id in &l.child_ids {
-                sources.extend(collect_sources_recursive(project, *child_id));
+                sources.extend(collect_sources_recursive(project, composition, *child_id));
             }
         }
         _ => {}
@@ -25,6 +66,32 @@ fn collect_sources_recursive<'a>(project: &'a Project, node_id: Uuid) -> Vec<&'a
     sources
 }
 
+/// Mix a composition to interleaved stereo float samples at `sample_rate`.
+pub fn mix_samples_to_stereo_float(
+    assets: &[Asset],
+    project: &Project,
+    composition: &Composition,
+    cache_manager: &CacheManager,
+    start_sample: u64,
+    frames_to_mix: usize,
+    sample_rate: u32,
+) -> StereoAudioBuffer {
+    StereoAudioBuffer {
+        sample_rate,
+        channels: 2,
+        samples: mix_samples(
+            assets,
+            project,
+            composition,
+            cache_manager,
+            start_sample,
+            frames_to_mix,
+            sample_rate,
+            2,
+        ),
+    }
+}
+
 pub fn mix_samples(
     assets: &[Asset],
     project: &Project,
@@ -42,7 +109,7 @@ pub fn mix_samples(
     let all_sources: Vec<&SourceData> = composition
         .child_ids
         .iter()
-        .flat_map(|child_id| collect_sources_recursive(project, *child_id))
+        .flat_map(|child_id| collect_sources_recursive(project, composition, *child_id))
         .collect();
 
     for source in all_sources {
@@ -51,8 +118,11 @@ pub fn mix_samples(
                 if asset.kind != crate::project::asset::AssetKind::Audio {
                     continue;
                 }
-
                 if let Some(audio_data) = cache_manager.get_audio(asset_id) {
+                    // Audio decoded at another rate is resampled on the fly.
+                    let source_rate = cache_manager
+                        .get_audio_sample_rate(asset_id)
+                        .unwrap_or(sample_rate);
                     let source_in_time = source.in_frame as f64 / fps;
                     let source_out_time = source.out_frame as f64 / fps;
 
@@ -72,6 +142,43 @@ pub fn mix_samples(
                             ((overlap_start - start_time_s) * sample_rate as f64).round() as usize;
                         let render_len_samples =
                             ((overlap_end - overlap_start) * sample_rate as f64).round() as usize;
+                        let channels_usize = channels as usize;
+
+                        // Speed-changed clips read each output sample at the source time the
+                        // clip maps it to, so they stay in sync with the remapped video.
+                        if source.speed != 1.0 {
+                            let dest_start = render_offset_samples * channels_usize;
+                            let len = render_len_samples * channels_usize;
+                            if dest_start + len > mix_buffer.len() {
+                                continue;
+                            }
+                            let pre_roll_frames = if source.audio_effects.is_empty() {
+                                0
+                            } else {
+                                sample_rate as usize / EFFECT_PRE_ROLL_DIVISOR
+                            };
+                            let window_start =
+                                overlap_start - pre_roll_frames as f64 / sample_rate as f64;
+                            let window = remap_window(
+                                &audio_data,
+                                channels_usize,
+                                source_rate,
+                                pre_roll_frames + render_len_samples,
+                                |i| {
+                                    let time = window_start + i as f64 / sample_rate as f64;
+                                    source_offset + (time - source_in_time) * source.speed
+                                },
+                            );
+                            mix_window(
+                                &mut mix_buffer[dest_start..dest_start + len],
+                                Cow::Owned(window),
+                                pre_roll_frames * channels_usize,
+                                source,
+                                channels_usize,
+                                sample_rate,
+                            );
+                            continue;
+                        }
 
                         let source_start_time = (overlap_start - source_in_time) + source_offset;
 
@@ -94,23 +201,50 @@ pub fn mix_samples(
                             (fixed_source_start_time * sample_rate as f64).round() as usize;
 
                         // Optimized mixing loop using iterators/slices
-                        let channels_usize = channels as usize;
                         // Adjust dest_start by render_offset + skipped silence
                         let dest_start = (render_offset_samples + skip_samples) * channels_usize;
                         let len = (render_len_samples - skip_samples) * channels_usize;
                         let src_start = source_start_sample * channels_usize;
 
-                        // Bounds check once
-                        if dest_start + len <= mix_buffer.len()
-                            && src_start + len <= audio_data.len()
-                        {
-                            let dest_slice = &mut mix_buffer[dest_start..dest_start + len];
-                            let src_slice = &audio_data[src_start..src_start + len];
+                        if dest_start + len > mix_buffer.len() {
+                            continue;
+                        }
+                        let dest_slice = &mut mix_buffer[dest_start..dest_start + len];
+                        let frames = len / channels_usize;
+
+                        // Effects are stateless per call, so include a short pre-roll
+                        // of preceding source samples to let filters settle.
+                        let pre_roll_frames = if source.audio_effects.is_empty() {
+                            0
+                        } else {
+                            source_start_sample.min(sample_rate as usize / EFFECT_PRE_ROLL_DIVISOR)
+                        };
+                        let pre_roll = pre_roll_frames * channels_usize;
 
-                            for (d, s) in dest_slice.iter_mut().zip(src_slice.iter()) {
-                                *d += s;
+                        let window: Cow<[f32]> = if source_rate == sample_rate {
+                            if src_start + len > audio_data.len() {
+                                continue;
                             }
-                        }
+                            Cow::Borrowed(&audio_data[src_start - pre_roll..src_start + len])
+                        } else {
+                            Cow::Owned(resample_window(
+                                &audio_data,
+                                channels_usize,
+                                source_rate,
+                                sample_rate,
+                                source_start_sample - pre_roll_frames,
+                                pre_roll_frames + frames,
+                            ))
+                        };
+
+                        mix_window(
+                            dest_slice,
+                            window,
+                            pre_roll,
+                            source,
+                            channels_usize,
+                            sample_rate,
+                        );
                     }
                 }
             }
@@ -118,3 +252,31 @@ pub fn mix_samples(
     }
     mix_buffer
 }
+
+/// Add a clip window into `dest`, applying the clip's audio effects first.
+/// `pre_roll` leading samples only let the effects settle and are not mixed.
+fn mix_window(
+    dest: &mut [f32],
+    window: Cow<[f32]>,
+    pre_roll: usize,
+    source: &SourceData,
+    channels: usize,
+    sample_rate: u32,
+) {
+    if source.audio_effects.is_empty() {
+        for (d, s) in dest.iter_mut().zip(window.iter()) {
+            *d += s;
+        }
+    } else {
+        let mut clip_samples = window.into_owned();
+        apply_audio_effects(
+            &mut clip_samples,
+            channels,
+            sample_rate,
+            &source.audio_effects,
+        );
+        for (d, s) in dest.iter_mut().zip(&clip_samples[pre_roll..]) {
+            *d += s;
+        }
+    }
+}
@@ -1,3 +1,6 @@
+pub mod effects;
 pub mod engine;
 pub mod loader;
 pub mod mixer;
+pub mod resample;
+pub mod waveform;
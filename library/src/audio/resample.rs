@@ -0,0 +1,128 @@
+//! Sample rate conversion for interleaved audio buffers.
+
+/// Resample a whole interleaved buffer from `from_rate` to `to_rate` using linear interpolation.
+pub fn resample_linear(samples: &[f32], channels: usize, from_rate: u32, to_rate: u32) -> Vec<f32> {
+    if channels == 0 || from_rate == 0 || to_rate == 0 {
+        return Vec::new();
+    }
+    let input_frames = samples.len() / channels;
+    let output_frames = (input_frames as f64 * to_rate as f64 / from_rate as f64).ceil() as usize;
+    resample_window(samples, channels, from_rate, to_rate, 0, output_frames)
+}
+
+/// Resample `frames` output frames starting at output frame `start_frame`.
+///
+/// Only the source region that is needed is read, so a short window of a long clip can be
+/// converted without resampling the whole buffer. Positions past the end of the source are silent.
+pub fn resample_window(
+    samples: &[f32],
+    channels: usize,
+    from_rate: u32,
+    to_rate: u32,
+    start_frame: usize,
+    frames: usize,
+) -> Vec<f32> {
+    let mut output = vec![0.0; frames * channels];
+    if channels == 0 || from_rate == 0 || to_rate == 0 {
+        return output;
+    }
+    let input_frames = samples.len() / channels;
+    let ratio = from_rate as f64 / to_rate as f64;
+
+    for (i, frame) in output.chunks_mut(channels).enumerate() {
+        let src_pos = (start_frame + i) as f64 * ratio;
+        let idx0 = src_pos.floor() as usize;
+        if idx0 >= input_frames {
+            break;
+        }
+        let idx1 = (idx0 + 1).min(input_frames - 1);
+        let t = (src_pos - idx0 as f64) as f32;
+        for (ch, out) in frame.iter_mut().enumerate() {
+            let s0 = samples[idx0 * channels + ch];
+            let s1 = samples[idx1 * channels + ch];
+            *out = s0 + (s1 - s0) * t;
+        }
+    }
+    output
+}
+
+/// Read `frames` frames whose source positions come from `source_time`, in seconds.
+///
+/// Used for time-remapped clips: each output frame is linearly interpolated at the time
+/// the clip maps it to. Positions before the start or past the end of the source are silent.
+pub fn remap_window(
+    samples: &[f32],
+    channels: usize,
+    from_rate: u32,
+    frames: usize,
+    source_time: impl Fn(usize) -> f64,
+) -> Vec<f32> {
+    let mut output = vec![0.0; frames * channels];
+    if channels == 0 || from_rate == 0 {
+        return output;
+    }
+    let input_frames = samples.len() / channels;
+
+    for (i, frame) in output.chunks_mut(channels).enumerate() {
+        let src_pos = source_time(i) * from_rate as f64;
+        if src_pos < 0.0 {
+            continue;
+        }
+        let idx0 = src_pos.floor() as usize;
+        if idx0 >= input_frames {
+            continue;
+        }
+        let idx1 = (idx0 + 1).min(input_frames - 1);
+        let t = (src_pos - idx0 as f64) as f32;
+        for (ch, out) in frame.iter_mut().enumerate() {
+            let s0 = samples[idx0 * channels + ch];
+            let s1 = samples[idx1 * channels + ch];
+            *out = s0 + (s1 - s0) * t;
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resample_same_rate_is_identity() {
+        let input = vec![0.0, 1.0, 0.5, -0.5, 1.0, 0.0];
+        assert_eq!(resample_linear(&input, 2, 48000, 48000), input);
+    }
+
+    #[test]
+    fn test_resample_upsample_interpolates_between_frames() {
+        // モノラル 0,1,2 を 2倍にアップサンプリング
+        let output = resample_linear(&[0.0, 1.0, 2.0], 1, 24000, 48000);
+        assert_eq!(output, vec![0.0, 0.5, 1.0, 1.5, 2.0, 2.0]);
+    }
+
+    #[test]
+    fn test_resample_window_matches_full_resample() {
+        let input: Vec<f32> = (0..200).map(|i| (i as f32 * 0.1).sin()).collect();
+        let full = resample_linear(&input, 2, 44100, 48000);
+        let window = resample_window(&input, 2, 44100, 48000, 10, 20);
+        assert_eq!(window, full[20..60]);
+    }
+
+    #[test]
+    fn test_resample_window_past_end_is_silent() {
+        let window = resample_window(&[1.0, 1.0], 2, 48000, 48000, 5, 2);
+        assert_eq!(window, vec![0.0; 4]);
+    }
+
+    #[test]
+    fn test_remap_window_reads_at_mapped_times() {
+        // 2倍速: 出力フレーム i はソースの 2i 番目を読む。範囲外は無音
+        let input = [0.0, 1.0, 2.0, 3.0, 4.0];
+        let window = remap_window(&input, 1, 10, 4, |i| i as f64 * 0.2);
+        assert_eq!(window, vec![0.0, 2.0, 4.0, 0.0]);
+
+        // 0.5倍速は中間を補間し、開始前は無音
+        let window = remap_window(&input, 1, 10, 3, |i| (i as f64 - 1.0) * 0.05);
+        assert_eq!(window, vec![0.0, 0.0, 0.5]);
+    }
+}
@@ -0,0 +1,41 @@
+//! Min/max peak summaries of decoded audio for waveform display.
+
+/// Most sample frames read per peak. Longer buckets are sampled evenly, which
+/// keeps zoomed-out summaries of long files cheap at the cost of exactness.
+const MAX_FRAMES_PER_PEAK: usize = 1024;
+
+/// Split `num_frames` sample frames of interleaved `data`, starting at frame
+/// `start_frame`, into `num_peaks` buckets and return the `(min, max)` sample
+/// of each bucket across all channels. Frames outside `data` are silent.
+pub fn compute_peaks(
+    data: &[f32],
+    channels: usize,
+    start_frame: i64,
+    num_frames: usize,
+    num_peaks: usize,
+) -> Vec<(f32, f32)> {
+    let channels = channels.max(1);
+    let total_frames = (data.len() / channels) as i64;
+    let frames_per_peak = num_frames as f64 / num_peaks.max(1) as f64;
+
+    (0..num_peaks)
+        .map(|i| {
+            let begin = start_frame + (i as f64 * frames_per_peak) as i64;
+            let end = (start_frame + ((i + 1) as f64 * frames_per_peak) as i64).max(begin + 1);
+            let (begin, end) = (begin.max(0), end.min(total_frames));
+            if begin >= end {
+                return (0.0, 0.0);
+            }
+            let step = ((end - begin) as usize)
+                .div_ceil(MAX_FRAMES_PER_PEAK)
+                .max(1);
+            let mut peak = (f32::MAX, f32::MIN);
+            for frame in (begin as usize..end as usize).step_by(step) {
+                for &sample in &data[frame * channels..(frame + 1) * channels] {
+                    peak = (peak.0.min(sample), peak.1.max(sample));
+                }
+            }
+            peak
+        })
+        .collect()
+}
@@ -0,0 +1,132 @@
+use crate::error::LibraryError;
+use crate::plugin::EffectPlugin;
+use crate::project::property::PropertyValue;
+use crate::rendering::renderer::RenderOutput;
+use crate::rendering::skia_utils::GpuContext;
+use skia_safe::{BlendMode, ColorFilter, ImageFilter, Matrix, color_filters, image_filters};
+use std::collections::HashMap;
+
+super::define_effect_plugin!(
+    ChromaticAberrationEffectPlugin,
+    id: "chromatic_aberration",
+    name: "Chromatic Aberration",
+    category: "Stylize",
+    version: (0, 1, 0)
+);
+
+/// Color matrix keeping a single RGB channel (0 = red, 1 = green, 2 = blue) plus alpha.
+fn channel_filter(channel: usize) -> ColorFilter {
+    let mut matrix = [0.0f32; 20];
+    matrix[channel * 6] = 1.0;
+    matrix[18] = 1.0;
+    color_filters::matrix_row_major(&matrix, None)
+}
+
+impl EffectPlugin for ChromaticAberrationEffectPlugin {
+    fn apply(
+        &self,
+        input: &RenderOutput,
+        params: &HashMap<String, PropertyValue>,
+        gpu_context: Option<&mut GpuContext>,
+    ) -> Result<RenderOutput, LibraryError> {
+        let offset_x = params
+            .get("offset_x")
+            .and_then(|pv| pv.get_as::<f64>())
+            .unwrap_or(0.0);
+        let offset_y = params
+            .get("offset_y")
+            .and_then(|pv| pv.get_as::<f64>())
+            .unwrap_or(0.0);
+        let use_radial = params
+            .get("use_radial")
+            .and_then(|pv| pv.get_as::<bool>())
+            .unwrap_or(false);
+
+        if offset_x == 0.0 && offset_y == 0.0 {
+            return Ok(input.clone());
+        }
+
+        use crate::builtin::effects::utils::apply_skia_filter;
+
+        apply_skia_filter(input, gpu_context, |_image, width, height| {
+            // Red moves by the offset, blue by the opposite amount, green stays put
+            let shifted = |channel: usize, sign: f32| -> Option<ImageFilter> {
+                let isolated = image_filters::color_filter(channel_filter(channel), None, None)?;
+                if sign == 0.0 {
+                    return Some(isolated);
+                }
+                if use_radial {
+                    // Scale around the center so the shift reaches the offset at the edges
+                    let half_w = (width as f32 / 2.0).max(1.0);
+                    let half_h = (height as f32 / 2.0).max(1.0);
+                    let mut matrix = Matrix::new_identity();
+                    matrix.set_scale(
+                        (
+                            1.0 + sign * offset_x as f32 / half_w,
+                            1.0 + sign * offset_y as f32 / half_h,
+                        ),
+                        skia_safe::Point::new(half_w, half_h),
+                    );
+                    image_filters::matrix_transform(
+                        &matrix,
+                        skia_safe::SamplingOptions::default(),
+                        isolated,
+                    )
+                } else {
+                    image_filters::offset(
+                        (sign * offset_x as f32, sign * offset_y as f32),
+                        isolated,
+                        None,
+                    )
+                }
+            };
+
+            // Each copy carries one channel, so screening them rebuilds the full color
+            let red_green =
+                image_filters::blend(BlendMode::Screen, shifted(0, 1.0), shifted(1, 0.0), None);
+            image_filters::blend(BlendMode::Screen, red_green, shifted(2, -1.0), None).ok_or(
+                LibraryError::render("Failed to create chromatic aberration filter".to_string()),
+            )
+        })
+    }
+
+    fn properties(&self) -> Vec<crate::project::property::PropertyDefinition> {
+        use crate::project::property::{PropertyDefinition, PropertyUiType};
+        use ordered_float::OrderedFloat;
+
+        vec![
+            PropertyDefinition::new(
+                "offset_x",
+                PropertyUiType::Float {
+                    min: -100.0,
+                    max: 100.0,
+                    step: 0.5,
+                    suffix: "px".to_string(),
+                    min_hard_limit: false,
+                    max_hard_limit: false,
+                },
+                "Offset X",
+                PropertyValue::Number(OrderedFloat(5.0)),
+            ),
+            PropertyDefinition::new(
+                "offset_y",
+                PropertyUiType::Float {
+                    min: -100.0,
+                    max: 100.0,
+                    step: 0.5,
+                    suffix: "px".to_string(),
+                    min_hard_limit: false,
+                    max_hard_limit: false,
+                },
+                "Offset Y",
+                PropertyValue::Number(OrderedFloat(0.0)),
+            ),
+            PropertyDefinition::new(
+                "use_radial",
+                PropertyUiType::Bool,
+                "Radial",
+                PropertyValue::Boolean(false),
+            ),
+        ]
+    }
+}
@@ -0,0 +1,98 @@
+use crate::error::LibraryError;
+use crate::plugin::EffectPlugin;
+use crate::project::property::PropertyValue;
+use crate::rendering::renderer::RenderOutput;
+use crate::rendering::skia_utils::GpuContext;
+use crate::runtime::curve::ColorCurve;
+use skia_safe::{color_filters, image_filters};
+use std::collections::HashMap;
+
+super::define_effect_plugin!(
+    ColorCurvesEffectPlugin,
+    id: "color_curves",
+    name: "Color Curves",
+    category: "Color",
+    version: (0, 1, 0)
+);
+
+/// Curve property keys, in inspector order.
+pub const CURVE_KEYS: [&str; 5] = ["master", "red", "green", "blue", "alpha"];
+
+impl ColorCurvesEffectPlugin {
+    /// Lookup tables as `[a, r, g, b]`; the master curve is applied before each color curve.
+    pub fn build_tables(params: &HashMap<String, PropertyValue>) -> [[u8; 256]; 4] {
+        let curve = |key: &str| {
+            let text = params
+                .get(key)
+                .and_then(|pv| pv.get_as::<String>())
+                .unwrap_or_default();
+            ColorCurve::parse(&text).unwrap_or_else(|e| {
+                log::warn!("ColorCurves: invalid '{}' curve: {}", key, e);
+                ColorCurve::identity()
+            })
+        };
+
+        let master = curve("master").lookup_table();
+        let channel = |key: &str| {
+            let table = curve(key).lookup_table();
+            master.map(|v| table[v as usize])
+        };
+        [
+            curve("alpha").lookup_table(),
+            channel("red"),
+            channel("green"),
+            channel("blue"),
+        ]
+    }
+}
+
+impl EffectPlugin for ColorCurvesEffectPlugin {
+    fn apply(
+        &self,
+        input: &RenderOutput,
+        params: &HashMap<String, PropertyValue>,
+        gpu_context: Option<&mut GpuContext>,
+    ) -> Result<RenderOutput, LibraryError> {
+        let all_identity = CURVE_KEYS.iter().all(|key| {
+            params
+                .get(*key)
+                .and_then(|pv| pv.get_as::<String>())
+                .and_then(|s| ColorCurve::parse(&s).ok())
+                .is_none_or(|c| c.is_identity())
+        });
+        if all_identity {
+            return Ok(input.clone());
+        }
+
+        let [a, r, g, b] = Self::build_tables(params);
+
+        use crate::builtin::effects::utils::apply_skia_filter;
+
+        apply_skia_filter(input, gpu_context, |_image, _width, _height| {
+            let table = color_filters::table_argb(&a, &r, &g, &b).ok_or(LibraryError::render(
+                "Failed to create curves table".to_string(),
+            ))?;
+            image_filters::color_filter(table, None, None).ok_or(LibraryError::render(
+                "Failed to create color curves filter".to_string(),
+            ))
+        })
+    }
+
+    fn properties(&self) -> Vec<crate::project::property::PropertyDefinition> {
+        use crate::project::property::{PropertyDefinition, PropertyUiType};
+
+        let labels = ["Master", "Red", "Green", "Blue", "Alpha"];
+        CURVE_KEYS
+            .iter()
+            .zip(labels)
+            .map(|(key, label)| {
+                PropertyDefinition::new(
+                    key,
+                    PropertyUiType::Curve,
+                    label,
+                    PropertyValue::String(ColorCurve::identity().to_json()),
+                )
+            })
+            .collect()
+    }
+}
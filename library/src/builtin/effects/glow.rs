@@ -0,0 +1,173 @@
+use crate::error::LibraryError;
+use crate::plugin::EffectPlugin;
+use crate::project::property::{PropertyValue, Vec3};
+use crate::rendering::renderer::RenderOutput;
+use crate::rendering::skia_utils::GpuContext;
+use skia_safe::runtime_effect::RuntimeShaderBuilder;
+use skia_safe::{BlendMode, RuntimeEffect, image_filters};
+use std::collections::HashMap;
+
+super::define_effect_plugin!(
+    GlowEffectPlugin,
+    id: "glow",
+    name: "Glow",
+    category: "Stylize",
+    version: (0, 1, 0)
+);
+
+/// Keeps the part of each channel above `threshold`, rescaled to 0..1, tinted by
+/// `boost` and scaled by `intensity`. Alpha follows the brightest channel so dark
+/// areas add nothing when composited.
+const HIGHLIGHT_SKSL: &str = r#"
+uniform shader image;
+uniform float threshold;
+uniform float intensity;
+uniform float3 boost;
+
+half4 main(float2 coord) {
+    half4 c = image.eval(coord);
+    if (c.a <= 0.0) {
+        return half4(0.0);
+    }
+    half3 rgb = c.rgb / c.a;
+    half3 hi = max(rgb - half(threshold), 0.0) / max(1.0 - half(threshold), 0.0001);
+    hi = min(hi * half3(boost) * half(intensity), 1.0) * c.a;
+    return half4(hi, max(hi.r, max(hi.g, hi.b)));
+}
+"#;
+
+thread_local! {
+    /// `HIGHLIGHT_SKSL` compiled once per render thread; runtime effects are not `Send`.
+    static HIGHLIGHT_EFFECT: Result<RuntimeEffect, String> =
+        RuntimeEffect::make_for_shader(HIGHLIGHT_SKSL, None);
+}
+
+impl EffectPlugin for GlowEffectPlugin {
+    fn apply(
+        &self,
+        input: &RenderOutput,
+        params: &HashMap<String, PropertyValue>,
+        gpu_context: Option<&mut GpuContext>,
+    ) -> Result<RenderOutput, LibraryError> {
+        let threshold = params
+            .get("threshold")
+            .and_then(|pv| pv.get_as::<f64>())
+            .unwrap_or(0.7)
+            .clamp(0.0, 1.0);
+        let intensity = params
+            .get("intensity")
+            .and_then(|pv| pv.get_as::<f64>())
+            .unwrap_or(1.0)
+            .max(0.0);
+        let radius = params
+            .get("radius")
+            .and_then(|pv| pv.get_as::<f64>())
+            .unwrap_or(20.0)
+            .max(0.0);
+        let boost = params
+            .get("color_boost")
+            .and_then(|pv| pv.get_as::<Vec3>())
+            .map(|v| {
+                [
+                    v.x.into_inner() as f32,
+                    v.y.into_inner() as f32,
+                    v.z.into_inner() as f32,
+                ]
+            })
+            .unwrap_or([1.0, 1.0, 1.0]);
+
+        if intensity == 0.0 {
+            return Ok(input.clone());
+        }
+
+        use crate::builtin::effects::utils::apply_skia_filter;
+
+        apply_skia_filter(input, gpu_context, |_image, _width, _height| {
+            let effect = HIGHLIGHT_EFFECT
+                .with(Clone::clone)
+                .map_err(|e| LibraryError::render(format!("Glow shader error: {}", e)))?;
+            let mut builder = RuntimeShaderBuilder::new(effect);
+            let uniforms: [(&str, &[f32]); 3] = [
+                ("threshold", &[threshold as f32]),
+                ("intensity", &[intensity as f32]),
+                ("boost", &boost),
+            ];
+            for (name, value) in uniforms {
+                builder
+                    .set_uniform_float(name, value)
+                    .map_err(|e| LibraryError::render(format!("Glow uniform '{}': {}", name, e)))?;
+            }
+
+            // 1. highlights, 2. blur (radius ≈ 3 sigma), 3. add onto the source
+            let highlights = image_filters::runtime_shader(&builder, "image", None);
+            let sigma = (radius / 3.0) as f32;
+            let glow = if sigma > 0.0 {
+                image_filters::blur((sigma, sigma), None, highlights, None)
+            } else {
+                highlights
+            };
+            image_filters::blend(BlendMode::Plus, None, glow, None).ok_or(LibraryError::render(
+                "Failed to create glow filter".to_string(),
+            ))
+        })
+    }
+
+    fn properties(&self) -> Vec<crate::project::property::PropertyDefinition> {
+        use crate::project::property::{PropertyDefinition, PropertyUiType};
+        use ordered_float::OrderedFloat;
+
+        vec![
+            PropertyDefinition::new(
+                "threshold",
+                PropertyUiType::Float {
+                    min: 0.0,
+                    max: 1.0,
+                    step: 0.01,
+                    suffix: "".to_string(),
+                    min_hard_limit: true,
+                    max_hard_limit: true,
+                },
+                "Threshold",
+                PropertyValue::Number(OrderedFloat(0.7)),
+            ),
+            PropertyDefinition::new(
+                "intensity",
+                PropertyUiType::Float {
+                    min: 0.0,
+                    max: 5.0,
+                    step: 0.05,
+                    suffix: "".to_string(),
+                    min_hard_limit: true,
+                    max_hard_limit: false,
+                },
+                "Intensity",
+                PropertyValue::Number(OrderedFloat(1.0)),
+            ),
+            PropertyDefinition::new(
+                "radius",
+                PropertyUiType::Float {
+                    min: 0.0,
+                    max: 200.0,
+                    step: 1.0,
+                    suffix: "px".to_string(),
+                    min_hard_limit: true,
+                    max_hard_limit: false,
+                },
+                "Radius",
+                PropertyValue::Number(OrderedFloat(20.0)),
+            ),
+            PropertyDefinition::new(
+                "color_boost",
+                PropertyUiType::Vec3 {
+                    suffix: "".to_string(),
+                },
+                "Color Boost",
+                PropertyValue::Vec3(Vec3 {
+                    x: OrderedFloat(1.0),
+                    y: OrderedFloat(1.0),
+                    z: OrderedFloat(1.0),
+                }),
+            ),
+        ]
+    }
+}
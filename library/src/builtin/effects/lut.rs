@@ -0,0 +1,294 @@
+use crate::error::LibraryError;
+use crate::plugin::EffectPlugin;
+use crate::project::property::PropertyValue;
+use crate::rendering::renderer::RenderOutput;
+use crate::rendering::skia_utils::GpuContext;
+use crate::runtime::Image;
+use lru::LruCache;
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::SystemTime;
+
+super::define_effect_plugin!(
+    LutEffectPlugin,
+    id: "lut_color_grade",
+    name: "LUT Color Grade",
+    category: "Color",
+    version: (0, 1, 0)
+);
+
+/// Largest 3D LUT edge length accepted from a `.cube` file.
+pub const MAX_LUT_3D_SIZE: usize = 64;
+/// Largest 1D LUT length accepted from a `.cube` file.
+pub const MAX_LUT_1D_SIZE: usize = 65536;
+
+/// Parsed LUTs kept across all render threads.
+const LUT_CACHE_SIZE: usize = 16;
+
+/// Parsed LUTs shared by all render threads, keyed by path and modification
+/// time so a `.cube` file edited on disk is parsed again.
+static LUT_CACHE: OnceLock<Mutex<LruCache<(String, Option<SystemTime>), Arc<CubeLut>>>> =
+    OnceLock::new();
+
+/// Whether a `.cube` table maps channels independently or as a color cube.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LutKind {
+    OneD,
+    ThreeD,
+}
+
+/// A color lookup table parsed from the Adobe/Resolve `.cube` format.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CubeLut {
+    pub kind: LutKind,
+    /// Entries per axis.
+    pub size: usize,
+    pub domain_min: [f32; 3],
+    pub domain_max: [f32; 3],
+    /// `size` entries for 1D tables, `size³` for 3D tables with red varying fastest.
+    pub table: Vec<[f32; 3]>,
+}
+
+impl CubeLut {
+    /// Parse the text of a `.cube` file.
+    pub fn parse(text: &str) -> Result<Self, LibraryError> {
+        let invalid =
+            |msg: String| LibraryError::InvalidArgument(format!("Invalid .cube LUT: {}", msg));
+        let parse_triplet = |parts: &[&str]| -> Result<[f32; 3], LibraryError> {
+            if parts.len() != 3 {
+                return Err(invalid(format!("expected 3 values, got {}", parts.len())));
+            }
+            let mut out = [0.0f32; 3];
+            for (slot, part) in out.iter_mut().zip(parts) {
+                *slot = part
+                    .parse()
+                    .map_err(|_| invalid(format!("bad number '{}'", part)))?;
+            }
+            Ok(out)
+        };
+
+        let mut kind_size = None;
+        let mut domain_min = [0.0; 3];
+        let mut domain_max = [1.0; 3];
+        let mut table = Vec::new();
+
+        for line in text.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            match parts[0] {
+                "TITLE" => {}
+                "LUT_1D_SIZE" | "LUT_3D_SIZE" => {
+                    let size: usize = parts
+                        .get(1)
+                        .and_then(|s| s.parse().ok())
+                        .ok_or_else(|| invalid(format!("bad size line '{}'", line)))?;
+                    let (kind, max) = if parts[0] == "LUT_1D_SIZE" {
+                        (LutKind::OneD, MAX_LUT_1D_SIZE)
+                    } else {
+                        (LutKind::ThreeD, MAX_LUT_3D_SIZE)
+                    };
+                    if !(2..=max).contains(&size) {
+                        return Err(invalid(format!("size {} out of range 2..={}", size, max)));
+                    }
+                    kind_size = Some((kind, size));
+                }
+                "DOMAIN_MIN" => domain_min = parse_triplet(&parts[1..])?,
+                "DOMAIN_MAX" => domain_max = parse_triplet(&parts[1..])?,
+                // Skip keywords we don't use (e.g. LUT_3D_INPUT_RANGE)
+                keyword if keyword.starts_with(|c: char| c.is_ascii_alphabetic()) => {}
+                _ => table.push(parse_triplet(&parts)?),
+            }
+        }
+
+        let (kind, size) = kind_size.ok_or_else(|| invalid("missing LUT size".to_string()))?;
+        let expected = match kind {
+            LutKind::OneD => size,
+            LutKind::ThreeD => size * size * size,
+        };
+        if table.len() != expected {
+            return Err(invalid(format!(
+                "expected {} entries, got {}",
+                expected,
+                table.len()
+            )));
+        }
+        if (0..3).any(|c| domain_max[c] <= domain_min[c]) {
+            return Err(invalid("DOMAIN_MAX must exceed DOMAIN_MIN".to_string()));
+        }
+
+        Ok(Self {
+            kind,
+            size,
+            domain_min,
+            domain_max,
+            table,
+        })
+    }
+
+    /// Map an RGB color (0.0..=1.0) through the table.
+    ///
+    /// 1D tables interpolate each channel linearly; 3D tables interpolate trilinearly.
+    pub fn lookup(&self, rgb: [f32; 3]) -> [f32; 3] {
+        let last = (self.size - 1) as f32;
+        let mut coord = [0.0f32; 3];
+        for c in 0..3 {
+            let t = (rgb[c] - self.domain_min[c]) / (self.domain_max[c] - self.domain_min[c]);
+            coord[c] = t.clamp(0.0, 1.0) * last;
+        }
+
+        match self.kind {
+            LutKind::OneD => {
+                let mut out = [0.0f32; 3];
+                for c in 0..3 {
+                    let i0 = coord[c].floor() as usize;
+                    let i1 = (i0 + 1).min(self.size - 1);
+                    let f = coord[c] - i0 as f32;
+                    out[c] = self.table[i0][c] * (1.0 - f) + self.table[i1][c] * f;
+                }
+                out
+            }
+            LutKind::ThreeD => {
+                let i0 = coord.map(|v| v.floor() as usize);
+                let i1 = i0.map(|i| (i + 1).min(self.size - 1));
+                let f = [
+                    coord[0] - i0[0] as f32,
+                    coord[1] - i0[1] as f32,
+                    coord[2] - i0[2] as f32,
+                ];
+                let at =
+                    |r: usize, g: usize, b: usize| self.table[r + self.size * (g + self.size * b)];
+                let lerp = |a: [f32; 3], b: [f32; 3], t: f32| {
+                    [
+                        a[0] + (b[0] - a[0]) * t,
+                        a[1] + (b[1] - a[1]) * t,
+                        a[2] + (b[2] - a[2]) * t,
+                    ]
+                };
+
+                let c00 = lerp(at(i0[0], i0[1], i0[2]), at(i1[0], i0[1], i0[2]), f[0]);
+                let c10 = lerp(at(i0[0], i1[1], i0[2]), at(i1[0], i1[1], i0[2]), f[0]);
+                let c01 = lerp(at(i0[0], i0[1], i1[2]), at(i1[0], i0[1], i1[2]), f[0]);
+                let c11 = lerp(at(i0[0], i1[1], i1[2]), at(i1[0], i1[1], i1[2]), f[0]);
+                let c0 = lerp(c00, c10, f[1]);
+                let c1 = lerp(c01, c11, f[1]);
+                lerp(c0, c1, f[2])
+            }
+        }
+    }
+
+    /// Apply the table to premultiplied RGBA8 pixels in place.
+    pub fn apply_to_rgba(&self, data: &mut [u8]) {
+        data.par_chunks_mut(4).for_each(|px| {
+            let a = px[3];
+            if a == 0 {
+                return;
+            }
+            let alpha = a as f32 / 255.0;
+            let rgb = [
+                px[0] as f32 / 255.0 / alpha,
+                px[1] as f32 / 255.0 / alpha,
+                px[2] as f32 / 255.0 / alpha,
+            ];
+            let mapped = self.lookup(rgb);
+            for c in 0..3 {
+                px[c] = (mapped[c].clamp(0.0, 1.0) * alpha * 255.0).round() as u8;
+            }
+        });
+    }
+}
+
+/// Load a `.cube` file, reusing the parsed copy while the file is unchanged.
+fn load_lut(path: &str) -> Result<Arc<CubeLut>, LibraryError> {
+    let cache = LUT_CACHE
+        .get_or_init(|| Mutex::new(LruCache::new(NonZeroUsize::new(LUT_CACHE_SIZE).unwrap())));
+    let key = (
+        path.to_string(),
+        std::fs::metadata(path).and_then(|m| m.modified()).ok(),
+    );
+    if let Some(lut) = cache.lock().unwrap().get(&key) {
+        return Ok(lut.clone());
+    }
+
+    // Parse outside the lock so other threads keep using their LUTs meanwhile
+    let text = std::fs::read_to_string(path)?;
+    let lut = Arc::new(CubeLut::parse(&text)?);
+    let mut cache = cache.lock().unwrap();
+    let stale_keys: Vec<_> = cache
+        .iter()
+        .filter(|((cached_path, _), _)| cached_path == path)
+        .map(|(key, _)| key.clone())
+        .collect();
+    for stale in stale_keys {
+        cache.pop(&stale);
+    }
+    cache.put(key, lut.clone());
+    Ok(lut)
+}
+
+/// GPU lookup path. Not implemented yet, so every input goes through the CPU loop.
+fn apply_on_gpu(
+    _lut: &CubeLut,
+    _input: &RenderOutput,
+    _gpu_context: &mut GpuContext,
+) -> Option<RenderOutput> {
+    None
+}
+
+impl EffectPlugin for LutEffectPlugin {
+    fn apply(
+        &self,
+        input: &RenderOutput,
+        params: &HashMap<String, PropertyValue>,
+        mut gpu_context: Option<&mut GpuContext>,
+    ) -> Result<RenderOutput, LibraryError> {
+        let file_path = params
+            .get("file_path")
+            .and_then(|pv| pv.get_as::<String>())
+            .unwrap_or_default();
+        if file_path.trim().is_empty() {
+            return Ok(input.clone());
+        }
+
+        let lut = match load_lut(file_path.trim()) {
+            Ok(lut) => lut,
+            Err(e) => {
+                log::warn!("LutEffectPlugin: failed to load '{}': {}", file_path, e);
+                return Ok(input.clone());
+            }
+        };
+
+        if let Some(ctx) = gpu_context.as_deref_mut() {
+            if let Some(output) = apply_on_gpu(&lut, input, ctx) {
+                return Ok(output);
+            }
+        }
+
+        use crate::builtin::effects::utils::read_output_image;
+
+        let Image {
+            width,
+            height,
+            mut data,
+        } = read_output_image(input, gpu_context)?;
+        lut.apply_to_rgba(&mut data);
+        Ok(RenderOutput::Image(Image::new(width, height, data)))
+    }
+
+    fn properties(&self) -> Vec<crate::project::property::PropertyDefinition> {
+        use crate::project::property::{PropertyDefinition, PropertyUiType};
+
+        vec![PropertyDefinition::new(
+            "file_path",
+            PropertyUiType::FilePicker {
+                filter: "*.cube".to_string(),
+            },
+            "LUT File",
+            PropertyValue::String("".to_string()),
+        )]
+    }
+}
@@ -36,23 +36,35 @@ macro_rules! define_effect_plugin {
 pub(crate) use define_effect_plugin;
 
 pub mod blur;
+pub mod chromatic_aberration;
+pub mod color_curves;
 pub mod dilate;
 pub mod drop_shadow;
 pub mod erode;
+pub mod glow;
+pub mod lut;
 pub mod magnifier;
 pub mod pixel_sorter;
 pub mod sksl_plugin;
 pub mod tile;
+pub mod time_echo;
 pub mod utils;
+pub mod vignette;
 
 pub use self::blur::BlurEffectPlugin;
+pub use self::chromatic_aberration::ChromaticAberrationEffectPlugin;
+pub use self::color_curves::ColorCurvesEffectPlugin;
 pub use self::dilate::DilateEffectPlugin;
 pub use self::drop_shadow::DropShadowEffectPlugin;
 pub use self::erode::ErodeEffectPlugin;
+pub use self::glow::GlowEffectPlugin;
+pub use self::lut::LutEffectPlugin;
 pub use self::magnifier::MagnifierEffectPlugin;
 pub use self::pixel_sorter::PixelSorterPlugin;
 pub use self::sksl_plugin::SkslEffectPlugin;
 pub use self::tile::TileEffectPlugin;
+pub use self::time_echo::TimeEchoEffectPlugin;
+pub use self::vignette::VignetteEffectPlugin;
 
 use crate::error::LibraryError;
 use crate::plugin::{Plugin, PluginCategory};
@@ -69,6 +81,13 @@ pub struct EffectDefinition {
 }
 
 pub trait EffectPlugin: Plugin {
+    /// Apply the effect to `input`.
+    ///
+    /// Besides the plugin's own properties, `params` carries values injected by the
+    /// effect evaluator: `u_time` (seconds), `u_frame` (composition frame number),
+    /// `u_entity_id` (the effect node's ID as a string, stable across frames) and
+    /// `u_render_context` (distinct per evaluation engine, e.g. preview vs export).
+    /// Plugins that keep per-instance state key it by the last two.
     fn apply(
         &self,
         input: &RenderOutput,
@@ -78,6 +97,11 @@ pub trait EffectPlugin: Plugin {
 
     fn properties(&self) -> Vec<PropertyDefinition>;
 
+    /// Drop per-instance state of effect nodes for which `exists` returns false.
+    fn prune_instances(&self, exists: &dyn Fn(uuid::Uuid) -> bool) {
+        let _ = exists;
+    }
+
     fn plugin_type(&self) -> PluginCategory {
         PluginCategory::Effect
     }
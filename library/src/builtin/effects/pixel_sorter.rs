@@ -69,47 +69,7 @@ impl EffectPlugin for PixelSorterPlugin {
             });
 
         // Resolve Image
-        let image = match input {
-            crate::rendering::renderer::RenderOutput::Image(img) => img.clone(),
-            crate::rendering::renderer::RenderOutput::Texture(info) => {
-                if let Some(ctx) = gpu_context {
-                    let sk_image = crate::rendering::skia_utils::create_image_from_texture(
-                        &mut ctx.direct_context,
-                        info.texture_id,
-                        info.width,
-                        info.height,
-                    )?;
-                    let row_bytes = (info.width * 4) as usize;
-                    let mut buffer = vec![0u8; (info.height as usize) * row_bytes];
-                    let image_info = skia_safe::ImageInfo::new(
-                        skia_safe::ISize::new(info.width as i32, info.height as i32),
-                        skia_safe::ColorType::RGBA8888,
-                        skia_safe::AlphaType::Premul,
-                        None,
-                    );
-                    if !sk_image.read_pixels(
-                        &image_info,
-                        &mut buffer,
-                        row_bytes,
-                        (0, 0),
-                        skia_safe::image::CachingHint::Disallow,
-                    ) {
-                        return Err(LibraryError::render(
-                            "Failed to read texture pixels".to_string(),
-                        ));
-                    }
-                    Image {
-                        width: info.width,
-                        height: info.height,
-                        data: buffer,
-                    }
-                } else {
-                    return Err(LibraryError::render(
-                        "Cannot read texture without GPU context".to_string(),
-                    ));
-                }
-            }
-        };
+        let image = super::utils::read_output_image(input, gpu_context)?;
 
         let mut processed_data = image.data.clone(); // Start with a mutable copy of the original data
 
@@ -0,0 +1,252 @@
+use crate::error::LibraryError;
+use crate::plugin::{EffectPlugin, Plugin};
+use crate::project::property::PropertyValue;
+use crate::rendering::renderer::RenderOutput;
+use crate::rendering::skia_utils::GpuContext;
+use crate::runtime::Image;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+/// Upper bound on the pixel data kept across all echo histories.
+const MAX_HISTORY_BYTES: usize = 512 * 1024 * 1024;
+
+/// Past frames of one effect node in one render context.
+#[derive(Default)]
+struct EchoHistory {
+    /// Shared so a render can take the frames it blends and release the lock.
+    frames: VecDeque<(u64, Arc<Image>)>,
+    /// Value of [`EchoState::clock`] when last applied, for eviction.
+    last_used: u64,
+}
+
+#[derive(Default)]
+struct EchoState {
+    /// Keyed by (`u_entity_id`, `u_render_context`), so preview and export
+    /// renders of the same node never mix.
+    histories: HashMap<(String, i64), EchoHistory>,
+    clock: u64,
+}
+
+impl EchoState {
+    fn total_bytes(&self) -> usize {
+        self.histories
+            .values()
+            .flat_map(|h| h.frames.iter())
+            .map(|(_, frame)| frame.data.len())
+            .sum()
+    }
+
+    /// Evict least recently used histories, then the oldest frames of `keep`,
+    /// until the total fits in [`MAX_HISTORY_BYTES`].
+    fn enforce_budget(&mut self, keep: &(String, i64)) {
+        let mut total = self.total_bytes();
+        while total > MAX_HISTORY_BYTES {
+            let oldest = self
+                .histories
+                .iter()
+                .filter(|(key, _)| *key != keep)
+                .min_by_key(|(_, h)| h.last_used)
+                .map(|(key, _)| key.clone());
+            if let Some(key) = oldest {
+                if let Some(history) = self.histories.remove(&key) {
+                    total -= history
+                        .frames
+                        .iter()
+                        .map(|(_, f)| f.data.len())
+                        .sum::<usize>();
+                }
+                continue;
+            }
+            let Some(history) = self.histories.get_mut(keep) else {
+                break;
+            };
+            if history.frames.len() <= 1 {
+                break;
+            }
+            if let Some((_, frame)) = history.frames.pop_front() {
+                total -= frame.data.len();
+            }
+        }
+    }
+}
+
+/// Blends previously rendered frames into the current one with exponentially
+/// decreasing weight.
+///
+/// Past frames are kept per effect node and render context in a ring buffer.
+/// Any jump other than to the next frame (seeking, scrubbing, a size change)
+/// resets the buffer, so echoes only ever come from the frames just before.
+pub struct TimeEchoEffectPlugin {
+    state: Mutex<EchoState>,
+}
+
+impl TimeEchoEffectPlugin {
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(EchoState::default()),
+        }
+    }
+}
+
+impl Plugin for TimeEchoEffectPlugin {
+    fn id(&self) -> &'static str {
+        "time_echo"
+    }
+
+    fn name(&self) -> String {
+        "Time Echo".to_string()
+    }
+
+    fn category(&self) -> String {
+        "Time".to_string()
+    }
+
+    fn version(&self) -> (u32, u32, u32) {
+        (0, 1, 0)
+    }
+}
+
+impl EffectPlugin for TimeEchoEffectPlugin {
+    fn apply(
+        &self,
+        input: &RenderOutput,
+        params: &HashMap<String, PropertyValue>,
+        gpu_context: Option<&mut GpuContext>,
+    ) -> Result<RenderOutput, LibraryError> {
+        let decay = params
+            .get("decay")
+            .and_then(|pv| pv.get_as::<f64>())
+            .unwrap_or(0.5)
+            .clamp(0.0, 1.0);
+        let num_frames = params
+            .get("num_frames")
+            .and_then(|pv| pv.get_as::<i64>())
+            .unwrap_or(4)
+            .clamp(1, 16) as usize;
+        let frame_number = params
+            .get("u_frame")
+            .and_then(|pv| pv.get_as::<i64>())
+            .map(|f| f.max(0) as u64);
+        let entity_id = params
+            .get("u_entity_id")
+            .and_then(|pv| pv.get_as::<String>());
+        let (Some(frame_number), Some(entity_id)) = (frame_number, entity_id) else {
+            // Without a stable key there is no history to blend
+            return Ok(input.clone());
+        };
+        let render_context = params
+            .get("u_render_context")
+            .and_then(|pv| pv.get_as::<i64>())
+            .unwrap_or(0);
+
+        let current = Arc::new(super::utils::read_output_image(input, gpu_context)?);
+
+        // Only the history bookkeeping happens under the lock; blending below
+        // works on the taken frames so other echo nodes are not held up
+        let mut state = self
+            .state
+            .lock()
+            .map_err(|e| LibraryError::Runtime(format!("Time echo history poisoned: {}", e)))?;
+        state.clock += 1;
+        let clock = state.clock;
+        let key = (entity_id, render_context);
+        let history = state.histories.entry(key.clone()).or_default();
+        history.last_used = clock;
+        let frames = &mut history.frames;
+
+        let last = frames
+            .back()
+            .map(|(f, frame)| (*f, frame.width, frame.height));
+        if let Some((last_frame, width, height)) = last {
+            if width != current.width || height != current.height {
+                frames.clear();
+            } else if frame_number == last_frame {
+                // Re-render of the same frame: replace it instead of echoing itself
+                frames.pop_back();
+            } else if frame_number != last_frame + 1 {
+                frames.clear();
+            }
+        }
+
+        let past: Vec<Arc<Image>> = frames
+            .iter()
+            .rev()
+            .take(num_frames - 1)
+            .map(|(_, frame)| frame.clone())
+            .collect();
+
+        frames.push_back((frame_number, current.clone()));
+        while frames.len() > num_frames.saturating_sub(1).max(1) {
+            frames.pop_front();
+        }
+        state.enforce_budget(&key);
+        drop(state);
+
+        // Weighted average of the current frame and up to num_frames - 1 past frames
+        let decay = decay as f32;
+        let mut accum: Vec<f32> = current.data.iter().map(|&v| v as f32).collect();
+        let mut total_weight = 1.0;
+        let mut weight = 1.0;
+        for frame in &past {
+            weight *= decay;
+            if weight <= 0.0 {
+                break;
+            }
+            for (acc, &v) in accum.iter_mut().zip(&frame.data) {
+                *acc += v as f32 * weight;
+            }
+            total_weight += weight;
+        }
+        let data: Vec<u8> = accum
+            .iter()
+            .map(|v| (v / total_weight).round().clamp(0.0, 255.0) as u8)
+            .collect();
+
+        Ok(RenderOutput::Image(Image::new(
+            current.width,
+            current.height,
+            data,
+        )))
+    }
+
+    fn prune_instances(&self, exists: &dyn Fn(uuid::Uuid) -> bool) {
+        if let Ok(mut state) = self.state.lock() {
+            state.histories.retain(|(entity_id, _), _| {
+                uuid::Uuid::parse_str(entity_id).is_ok_and(|id| exists(id))
+            });
+        }
+    }
+
+    fn properties(&self) -> Vec<crate::project::property::PropertyDefinition> {
+        use crate::project::property::{PropertyDefinition, PropertyUiType};
+        use ordered_float::OrderedFloat;
+
+        vec![
+            PropertyDefinition::new(
+                "decay",
+                PropertyUiType::Float {
+                    min: 0.0,
+                    max: 1.0,
+                    step: 0.01,
+                    suffix: "".to_string(),
+                    min_hard_limit: true,
+                    max_hard_limit: true,
+                },
+                "Decay",
+                PropertyValue::Number(OrderedFloat(0.5)),
+            ),
+            PropertyDefinition::new(
+                "num_frames",
+                PropertyUiType::Integer {
+                    min: 1,
+                    max: 16,
+                    suffix: "".to_string(),
+                    min_hard_limit: true,
+                    max_hard_limit: true,
+                },
+                "Frames",
+                PropertyValue::Integer(4),
+            ),
+        ]
+    }
+}
@@ -1,6 +1,7 @@
 use crate::error::LibraryError;
 use crate::rendering::renderer::{RenderOutput, TextureInfo};
 use crate::rendering::skia_utils::{GpuContext, image_to_skia, surface_to_image};
+use crate::runtime::Image;
 use skia_safe::{ImageFilter, Paint};
 
 pub fn apply_skia_filter<F>(
@@ -86,3 +87,47 @@ where
         }
     }
 }
+
+/// Get CPU pixels (premultiplied RGBA8) for an effect input, reading textures back
+/// through the GPU context.
+pub fn read_output_image(
+    input: &RenderOutput,
+    gpu_context: Option<&mut GpuContext>,
+) -> Result<Image, LibraryError> {
+    match input {
+        RenderOutput::Image(img) => Ok(img.clone()),
+        RenderOutput::Texture(info) => {
+            let Some(ctx) = gpu_context else {
+                return Err(LibraryError::render(
+                    "Cannot read texture without GPU context".to_string(),
+                ));
+            };
+            let sk_image = crate::rendering::skia_utils::create_image_from_texture(
+                &mut ctx.direct_context,
+                info.texture_id,
+                info.width,
+                info.height,
+            )?;
+            let row_bytes = (info.width * 4) as usize;
+            let mut buffer = vec![0u8; (info.height as usize) * row_bytes];
+            let image_info = skia_safe::ImageInfo::new(
+                skia_safe::ISize::new(info.width as i32, info.height as i32),
+                skia_safe::ColorType::RGBA8888,
+                skia_safe::AlphaType::Premul,
+                None,
+            );
+            if !sk_image.read_pixels(
+                &image_info,
+                &mut buffer,
+                row_bytes,
+                (0, 0),
+                skia_safe::image::CachingHint::Disallow,
+            ) {
+                return Err(LibraryError::render(
+                    "Failed to read texture pixels".to_string(),
+                ));
+            }
+            Ok(Image::new(info.width, info.height, buffer))
+        }
+    }
+}
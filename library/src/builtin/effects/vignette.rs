@@ -0,0 +1,151 @@
+use crate::error::LibraryError;
+use crate::plugin::EffectPlugin;
+use crate::project::property::PropertyValue;
+use crate::rendering::renderer::RenderOutput;
+use crate::rendering::skia_utils::GpuContext;
+use skia_safe::{BlendMode, Color, Matrix, Point, Shader, TileMode, image_filters, shaders};
+use std::collections::HashMap;
+
+super::define_effect_plugin!(
+    VignetteEffectPlugin,
+    id: "vignette",
+    name: "Vignette",
+    category: "Stylize",
+    version: (0, 1, 0)
+);
+
+impl EffectPlugin for VignetteEffectPlugin {
+    fn apply(
+        &self,
+        input: &RenderOutput,
+        params: &HashMap<String, PropertyValue>,
+        gpu_context: Option<&mut GpuContext>,
+    ) -> Result<RenderOutput, LibraryError> {
+        let strength = params
+            .get("strength")
+            .and_then(|pv| pv.get_as::<f64>())
+            .unwrap_or(0.5)
+            .clamp(0.0, 1.0);
+        let radius = params
+            .get("radius")
+            .and_then(|pv| pv.get_as::<f64>())
+            .unwrap_or(0.75)
+            .clamp(0.0, 1.0);
+        let feather = params
+            .get("feather")
+            .and_then(|pv| pv.get_as::<f64>())
+            .unwrap_or(0.5)
+            .clamp(0.0, 1.0);
+        let color_val = params
+            .get("color")
+            .and_then(|pv| pv.get_as::<crate::runtime::color::Color>())
+            .unwrap_or(crate::runtime::color::Color::black());
+        let shape = params
+            .get("shape")
+            .and_then(|pv| pv.get_as::<String>())
+            .unwrap_or_else(|| "ellipse".to_string());
+
+        if strength <= 0.0 || color_val.a == 0 {
+            return Ok(input.clone());
+        }
+
+        // Mask runs from transparent at the inner edge to the vignette color at `radius`
+        let alpha = (color_val.a as f64 * strength).round() as u8;
+        let colors = [
+            Color::from_argb(0, color_val.r, color_val.g, color_val.b),
+            Color::from_argb(alpha, color_val.r, color_val.g, color_val.b),
+        ];
+        let outer = (radius as f32).max(f32::EPSILON);
+        let inner = outer * (1.0 - feather as f32);
+        let positions = [inner.min(outer - f32::EPSILON).max(0.0), outer];
+
+        use crate::builtin::effects::utils::apply_skia_filter;
+
+        apply_skia_filter(input, gpu_context, |_image, width, height| {
+            let half_w = width as f32 / 2.0;
+            let half_h = (height as f32 / 2.0).max(1.0);
+            let center = Point::new(half_w, half_h);
+
+            let mask: Shader = if shape == "rectangle" {
+                // One mirrored gradient per axis; radius 1.0 reaches the edges
+                let axis = |end: Point| {
+                    Shader::linear_gradient(
+                        (center, end),
+                        colors.as_slice(),
+                        positions.as_slice(),
+                        TileMode::Mirror,
+                        None,
+                        None,
+                    )
+                };
+                let horizontal = axis(Point::new(width as f32, half_h));
+                let vertical = axis(Point::new(half_w, height as f32));
+                match (horizontal, vertical) {
+                    (Some(h), Some(v)) => shaders::blend(BlendMode::SrcOver, h, v),
+                    _ => return Err(LibraryError::render("Failed to create vignette mask")),
+                }
+            } else {
+                // Circle through the corners, squashed to the frame's aspect ratio
+                let mut aspect = Matrix::new_identity();
+                aspect.set_scale((1.0, half_h / half_w.max(1.0)), center);
+                Shader::radial_gradient(
+                    center,
+                    (half_w * std::f32::consts::SQRT_2).max(1.0),
+                    colors.as_slice(),
+                    positions.as_slice(),
+                    TileMode::Clamp,
+                    None,
+                    &aspect,
+                )
+                .ok_or(LibraryError::render("Failed to create vignette mask"))?
+            };
+
+            // SrcATop keeps the vignette inside the input's alpha
+            let mask_filter = image_filters::shader(mask, None);
+            image_filters::blend(BlendMode::SrcATop, None, mask_filter, None).ok_or(
+                LibraryError::render("Failed to create vignette filter".to_string()),
+            )
+        })
+    }
+
+    fn properties(&self) -> Vec<crate::project::property::PropertyDefinition> {
+        use crate::project::property::{PropertyDefinition, PropertyUiType};
+        use ordered_float::OrderedFloat;
+
+        let unit = |name: &str, label: &str, default: f64| {
+            PropertyDefinition::new(
+                name,
+                PropertyUiType::Float {
+                    min: 0.0,
+                    max: 1.0,
+                    step: 0.01,
+                    suffix: "".to_string(),
+                    min_hard_limit: true,
+                    max_hard_limit: true,
+                },
+                label,
+                PropertyValue::Number(OrderedFloat(default)),
+            )
+        };
+
+        vec![
+            unit("strength", "Strength", 0.5),
+            unit("radius", "Radius", 0.75),
+            unit("feather", "Feather", 0.5),
+            PropertyDefinition::new(
+                "color",
+                PropertyUiType::Color,
+                "Color",
+                PropertyValue::Color(crate::runtime::color::Color::black()),
+            ),
+            PropertyDefinition::new(
+                "shape",
+                PropertyUiType::Dropdown {
+                    options: vec!["ellipse".to_string(), "rectangle".to_string()],
+                },
+                "Shape",
+                PropertyValue::String("ellipse".to_string()),
+            ),
+        ]
+    }
+}
@@ -1,4 +1,4 @@
-use super::{ExportPlugin, ExportSettings};
+use super::{ExportPlugin, ExportSettings, HwAccelBackend};
 use crate::error::LibraryError;
 use crate::plugin::Plugin;
 use crate::runtime::Image;
@@ -177,15 +177,18 @@ impl ExportPlugin for FfmpegExportPlugin {
             ),
             PropertyDefinition::new(
                 "audio_bitrate",
-                PropertyUiType::Integer {
-                    min: 64,
-                    max: 320,
-                    suffix: " kbps".to_string(),
-                    min_hard_limit: false,
-                    max_hard_limit: false,
+                PropertyUiType::Dropdown {
+                    options: vec![
+                        "Default".to_string(),
+                        "96".to_string(),
+                        "128".to_string(),
+                        "192".to_string(),
+                        "256".to_string(),
+                        "320".to_string(),
+                    ],
                 },
                 "Audio Bitrate (kbps)",
-                PropertyValue::Number(OrderedFloat(192.0)),
+                PropertyValue::String("192".to_string()),
             ),
         ]
     }
@@ -196,6 +199,10 @@ struct FfmpegSession {
     stdin: Option<ChildStdin>,
 }
 
+/// Encoder selected for a session: the hardware encoder name and its backend,
+/// or `None` for the software codec from the settings.
+type HwEncoder<'a> = Option<(HwAccelBackend, &'a str)>;
+
 impl FfmpegSession {
     fn spawn(path: &str, settings: &ExportSettings) -> Result<Self, LibraryError> {
         let binary = settings
@@ -203,9 +210,134 @@ impl FfmpegSession {
             .as_deref()
             .unwrap_or("ffmpeg")
             .to_string();
+
+        if let Some(backend) = settings.hardware_accel {
+            match backend.encoder_for(&settings.codec) {
+                Some(encoder) if Self::probe(&binary, settings, backend, &encoder) => {
+                    info!("Using hardware encoder {}", encoder);
+                    return Self::spawn_with(&binary, path, settings, Some((backend, &encoder)));
+                }
+                Some(encoder) => warn!(
+                    "Hardware encoder {} failed; falling back to {}",
+                    encoder, settings.codec
+                ),
+                None => warn!(
+                    "{} has no hardware encoder for {}; using software",
+                    backend.label(),
+                    settings.codec
+                ),
+            }
+        }
+        Self::spawn_with(&binary, path, settings, None)
+    }
+
+    /// Encode one black frame with the hardware encoder and the session's video
+    /// options to check the device, driver and options actually work; ffmpeg
+    /// exits non-zero otherwise.
+    fn probe(
+        binary: &str,
+        settings: &ExportSettings,
+        backend: HwAccelBackend,
+        encoder: &str,
+    ) -> bool {
         let mut cmd = Command::new(binary);
-        cmd.arg("-y")
-            .arg("-f")
+        cmd.args(["-hide_banner", "-loglevel", "error"]);
+        Self::hardware_device_args(&mut cmd, backend);
+        cmd.arg("-f")
+            .arg("lavfi")
+            .arg("-i")
+            .arg(format!(
+                "color=c=black:s={}x{}:r={}",
+                settings.width, settings.height, settings.fps
+            ))
+            .arg("-frames:v")
+            .arg("1")
+            .args(Self::video_output_args(settings, Some((backend, encoder))))
+            .args(["-f", "null", "-"])
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null());
+        cmd.status().is_ok_and(|status| status.success())
+    }
+
+    /// Device setup that has to come before the inputs.
+    fn hardware_device_args(cmd: &mut Command, backend: HwAccelBackend) {
+        if backend == HwAccelBackend::Vaapi {
+            cmd.args(["-vaapi_device", "/dev/dri/renderD128"]);
+        }
+    }
+
+    /// Encoder, rate control and pixel format options of the video output,
+    /// shared by the session and its probe so the probe tests what is run.
+    fn video_output_args(settings: &ExportSettings, hw_encoder: HwEncoder) -> Vec<String> {
+        let mut args: Vec<String> = Vec::new();
+        match hw_encoder {
+            Some((backend, encoder)) => {
+                args.extend(["-c:v".to_string(), encoder.to_string()]);
+                // VAAPI encodes from GPU surfaces, so frames are uploaded after conversion
+                if backend == HwAccelBackend::Vaapi {
+                    args.extend(["-vf".to_string(), "format=nv12,hwupload".to_string()]);
+                }
+            }
+            None => args.extend(["-c:v".to_string(), settings.codec.clone()]),
+        }
+
+        if let Some(bitrate) = settings.parameter_u64("bitrate") {
+            args.extend(["-b:v".to_string(), format!("{}k", bitrate)]);
+        }
+
+        let quality = settings
+            .parameter_f64("crf")
+            .or_else(|| settings.parameter_f64("quality"));
+        let preset = settings.parameter_string("preset");
+        match hw_encoder.map(|(backend, _)| backend) {
+            Some(backend) => {
+                if let Some(quality) = quality {
+                    args.extend(backend.quality_args(quality));
+                }
+                if let Some(preset) = preset.and_then(|p| backend.preset_for(&p)) {
+                    args.extend(["-preset".to_string(), preset]);
+                }
+            }
+            None => {
+                if let Some(quality) = quality {
+                    args.extend(["-crf".to_string(), format!("{}", quality)]);
+                }
+                if let Some(preset) = preset {
+                    args.extend(["-preset".to_string(), preset]);
+                }
+            }
+        }
+
+        if let Some(profile) = settings.parameter_string("profile") {
+            args.extend(["-profile:v".to_string(), profile]);
+        }
+
+        // hwupload already picks the surface format for VAAPI, and QSV only takes nv12
+        match hw_encoder.map(|(backend, _)| backend) {
+            Some(HwAccelBackend::Vaapi) => {}
+            Some(HwAccelBackend::Qsv) => {
+                args.extend(["-pix_fmt".to_string(), "nv12".to_string()]);
+            }
+            _ => {
+                args.extend(["-pix_fmt".to_string(), settings.pixel_format.clone()]);
+            }
+        }
+        args
+    }
+
+    fn spawn_with(
+        binary: &str,
+        path: &str,
+        settings: &ExportSettings,
+        hw_encoder: HwEncoder,
+    ) -> Result<Self, LibraryError> {
+        let mut cmd = Command::new(binary);
+        cmd.arg("-y");
+        if let Some((backend, _)) = hw_encoder {
+            Self::hardware_device_args(&mut cmd, backend);
+        }
+        cmd.arg("-f")
             .arg("rawvideo")
             .arg("-pix_fmt")
             .arg("rgba")
@@ -218,56 +350,29 @@ impl FfmpegSession {
 
         // Audio Input
         let mut has_audio = false;
-        if let Some(audio_path) = settings.parameter_string("audio_source") {
-            let channels = settings.parameter_u64("audio_channels").unwrap_or(2);
-            let rate = settings.parameter_u64("audio_sample_rate").unwrap_or(48000);
-
+        if let Some(audio) = settings.audio_source() {
             cmd.arg("-f")
                 .arg("f32le")
                 .arg("-ar")
-                .arg(format!("{}", rate))
+                .arg(format!("{}", audio.sample_rate))
                 .arg("-ac")
-                .arg(format!("{}", channels))
+                .arg(format!("{}", audio.channels))
                 .arg("-i")
-                .arg(audio_path);
+                .arg(audio.path);
             has_audio = true;
         }
 
-        cmd.arg("-c:v").arg(&settings.codec);
-
-        if let Some(bitrate) = settings.parameter_u64("bitrate") {
-            cmd.arg("-b:v").arg(format!("{}k", bitrate));
-        }
-
-        if let Some(crf) = settings.parameter_f64("crf") {
-            cmd.arg("-crf").arg(format!("{}", crf));
-        } else if let Some(quality) = settings.parameter_f64("quality") {
-            cmd.arg("-crf").arg(format!("{}", quality));
-        }
-
-        if let Some(preset) = settings.parameter_string("preset") {
-            cmd.arg("-preset").arg(preset);
-        }
-
-        if let Some(profile) = settings.parameter_string("profile") {
-            cmd.arg("-profile:v").arg(profile);
-        }
+        cmd.args(Self::video_output_args(settings, hw_encoder));
 
         if has_audio {
-            let audio_bitrate = settings.parameter_u64("audio_bitrate").unwrap_or(192);
-            cmd.arg("-c:a")
-                .arg("aac")
-                .arg("-b:a")
-                .arg(format!("{}k", audio_bitrate))
-                .arg("-map")
-                .arg("0:v")
-                .arg("-map")
-                .arg("1:a");
+            cmd.arg("-c:a").arg("aac");
+            if let Some(audio_bitrate) = settings.audio_bitrate() {
+                cmd.arg("-b:a").arg(format!("{}k", audio_bitrate));
+            }
+            cmd.arg("-map").arg("0:v").arg("-map").arg("1:a");
         }
 
-        cmd.arg("-pix_fmt")
-            .arg(&settings.pixel_format)
-            .arg("-f")
+        cmd.arg("-f")
             .arg(&settings.container)
             .arg(path)
             .stdin(Stdio::piped())
@@ -0,0 +1,443 @@
+use super::{ExportPlugin, ExportSettings};
+use crate::error::LibraryError;
+use crate::plugin::Plugin;
+use crate::runtime::Image;
+use gif::{Encoder, Frame, Repeat};
+use log::info;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::sync::Mutex;
+
+/// Animated GIF exporter.
+///
+/// Frames are quantized to at most `palette_size` colors (optionally dithered)
+/// and streamed to the output file as they arrive. `finish_export` writes the
+/// trailer.
+pub struct GifExportPlugin {
+    sessions: Mutex<HashMap<String, GifSession>>,
+}
+
+impl GifExportPlugin {
+    pub fn new() -> Self {
+        Self {
+            sessions: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl Plugin for GifExportPlugin {
+    fn id(&self) -> &'static str {
+        "gif_export"
+    }
+
+    fn name(&self) -> String {
+        "Animated GIF Export".to_string()
+    }
+
+    fn category(&self) -> String {
+        "Export".to_string()
+    }
+
+    fn version(&self) -> (u32, u32, u32) {
+        (0, 1, 0)
+    }
+}
+
+impl ExportPlugin for GifExportPlugin {
+    fn export_image(
+        &self,
+        path: &str,
+        image: &Image,
+        settings: &ExportSettings,
+    ) -> Result<(), LibraryError> {
+        let mut sessions = self.sessions.lock().unwrap();
+        if !sessions.contains_key(path) {
+            info!("Starting GIF export session for {}", path);
+            sessions.insert(path.to_string(), GifSession::new(path, settings));
+        }
+        match sessions.get_mut(path) {
+            Some(session) => session.push_frame(image),
+            None => Err(LibraryError::render(
+                "Failed to start GIF session".to_string(),
+            )),
+        }
+    }
+
+    fn finish_export(&self, path: &str) -> Result<(), LibraryError> {
+        let Some(session) = self.sessions.lock().unwrap().remove(path) else {
+            // It's possible it was never started or already finished
+            return Ok(());
+        };
+        info!(
+            "Finishing GIF export session for {} ({} frames)",
+            path, session.written
+        );
+        session.finish()
+    }
+
+    fn properties(&self) -> Vec<crate::project::property::PropertyDefinition> {
+        use crate::project::property::{PropertyDefinition, PropertyUiType, PropertyValue};
+        use ordered_float::OrderedFloat;
+        vec![
+            PropertyDefinition::new(
+                "fps",
+                PropertyUiType::Float {
+                    min: 0.0,
+                    max: 60.0,
+                    step: 1.0,
+                    suffix: " fps".to_string(),
+                    min_hard_limit: true,
+                    max_hard_limit: false,
+                },
+                "Frame Rate (0 = composition)",
+                PropertyValue::Number(OrderedFloat(0.0)),
+            ),
+            PropertyDefinition::new(
+                "dither",
+                PropertyUiType::Dropdown {
+                    options: vec![
+                        "None".to_string(),
+                        "Bayer".to_string(),
+                        "Floyd-Steinberg".to_string(),
+                    ],
+                },
+                "Dither",
+                PropertyValue::String("Floyd-Steinberg".to_string()),
+            ),
+            PropertyDefinition::new(
+                "palette_size",
+                PropertyUiType::Integer {
+                    min: 2,
+                    max: 256,
+                    suffix: "".to_string(),
+                    min_hard_limit: true,
+                    max_hard_limit: true,
+                },
+                "Palette Size",
+                PropertyValue::Number(OrderedFloat(256.0)),
+            ),
+            PropertyDefinition::new(
+                "loop_count",
+                PropertyUiType::Integer {
+                    min: 0,
+                    max: 65535,
+                    suffix: "".to_string(),
+                    min_hard_limit: true,
+                    max_hard_limit: true,
+                },
+                "Loop Count (0 = infinite)",
+                PropertyValue::Number(OrderedFloat(0.0)),
+            ),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Dither {
+    None,
+    Bayer,
+    FloydSteinberg,
+}
+
+impl Dither {
+    fn from_name(name: &str) -> Self {
+        match name.to_ascii_lowercase().as_str() {
+            "bayer" => Dither::Bayer,
+            "floyd-steinberg" | "floyd_steinberg" => Dither::FloydSteinberg,
+            _ => Dither::None,
+        }
+    }
+}
+
+struct GifSession {
+    path: String,
+    encoder: Option<Encoder<BufWriter<File>>>,
+    /// Global color table, taken from the first frame.
+    global_palette: Vec<u8>,
+    dither: Dither,
+    palette_size: usize,
+    loop_count: u16,
+    /// Output frames kept per incoming frame (1.0 keeps every frame).
+    frame_ratio: f64,
+    output_fps: f64,
+    received: u64,
+    written: u64,
+}
+
+impl GifSession {
+    fn new(path: &str, settings: &ExportSettings) -> Self {
+        let source_fps = if settings.fps > 0.0 {
+            settings.fps
+        } else {
+            30.0
+        };
+        // GIF can only drop frames, so the override never exceeds the source rate
+        let output_fps = settings
+            .parameter_f64("fps")
+            .filter(|fps| *fps > 0.0)
+            .map_or(source_fps, |fps| fps.min(source_fps));
+
+        Self {
+            path: path.to_string(),
+            encoder: None,
+            global_palette: Vec::new(),
+            dither: Dither::from_name(
+                &settings
+                    .parameter_string("dither")
+                    .unwrap_or_else(|| "Floyd-Steinberg".to_string()),
+            ),
+            palette_size: settings
+                .parameter_u64("palette_size")
+                .unwrap_or(256)
+                .clamp(2, 256) as usize,
+            loop_count: settings
+                .parameter_u64("loop_count")
+                .unwrap_or(0)
+                .min(u16::MAX as u64) as u16,
+            frame_ratio: output_fps / source_fps,
+            output_fps,
+            received: 0,
+            written: 0,
+        }
+    }
+
+    fn push_frame(&mut self, image: &Image) -> Result<(), LibraryError> {
+        let index = self.received;
+        self.received += 1;
+        if ((index as f64 * self.frame_ratio).floor() as u64) < self.written {
+            return Ok(());
+        }
+
+        let (width, height) = match (u16::try_from(image.width), u16::try_from(image.height)) {
+            (Ok(w), Ok(h)) => (w, h),
+            _ => {
+                return Err(LibraryError::render(format!(
+                    "GIF exporter: {}x{} exceeds the GIF size limit",
+                    image.width, image.height
+                )));
+            }
+        };
+
+        let mut pixels = image.data.clone();
+        quantize_rgba(
+            &mut pixels,
+            image.width as usize,
+            self.palette_size,
+            self.dither,
+        );
+        // At most palette_size colors remain, so this builds an exact palette
+        let mut frame = Frame::from_rgba_speed(width, height, &mut pixels, 10);
+        frame.delay = self.frame_delay(self.written);
+
+        if self.encoder.is_none() {
+            self.global_palette = frame.palette.clone().unwrap_or_default();
+            self.encoder = Some(self.start_encoder(width, height)?);
+        }
+        // Frames sharing the global color table don't need a local one
+        if frame.palette.as_deref() == Some(self.global_palette.as_slice()) {
+            frame.palette = None;
+        }
+        let encoder = self.encoder.as_mut().unwrap();
+        encoder
+            .write_frame(&frame)
+            .map_err(|e| LibraryError::render(format!("GIF encoding failed: {}", e)))?;
+        self.written += 1;
+        Ok(())
+    }
+
+    /// Create the encoder with the global color table. The loop extension has to
+    /// follow the header directly, so it is written here as well.
+    fn start_encoder(
+        &self,
+        width: u16,
+        height: u16,
+    ) -> Result<Encoder<BufWriter<File>>, LibraryError> {
+        let writer = BufWriter::new(File::create(&self.path)?);
+        let mut encoder = Encoder::new(writer, width, height, &self.global_palette)
+            .map_err(|e| LibraryError::render(format!("GIF encoding failed: {}", e)))?;
+        let repeat = match self.loop_count {
+            0 => Repeat::Infinite,
+            n => Repeat::Finite(n),
+        };
+        encoder
+            .set_repeat(repeat)
+            .map_err(|e| LibraryError::render(format!("GIF encoding failed: {}", e)))?;
+        Ok(encoder)
+    }
+
+    /// Delay of output frame `index` in centiseconds, rounded so the total
+    /// duration does not drift.
+    fn frame_delay(&self, index: u64) -> u16 {
+        let at = |i: u64| (i as f64 * 100.0 / self.output_fps).round();
+        (at(index + 1) - at(index)).max(1.0) as u16
+    }
+
+    fn finish(self) -> Result<(), LibraryError> {
+        if let Some(encoder) = self.encoder {
+            let mut writer = encoder
+                .into_inner()
+                .map_err(|e| LibraryError::render(format!("GIF encoding failed: {}", e)))?;
+            writer.flush()?;
+        }
+        Ok(())
+    }
+}
+
+const BAYER_4X4: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// Reduce `rgba` in place to at most `palette_size` opaque colors.
+///
+/// Fully transparent pixels are left untouched (GIF keeps a single transparent
+/// index); all others become opaque.
+fn quantize_rgba(rgba: &mut [u8], width: usize, palette_size: usize, dither: Dither) {
+    // The transparent color takes one palette slot
+    let has_transparent = rgba.chunks_exact(4).any(|px| px[3] == 0);
+    let palette_size = if has_transparent {
+        (palette_size - 1).max(1)
+    } else {
+        palette_size
+    };
+    let palette = median_cut_palette(rgba, palette_size);
+    let mut nearest_cache: Vec<Option<u8>> = vec![None; 1 << 15];
+    let mut nearest = |c: [f32; 3]| -> [u8; 3] {
+        let c = c.map(|v| v.round().clamp(0.0, 255.0) as u8);
+        let key = ((c[0] as usize >> 3) << 10) | ((c[1] as usize >> 3) << 5) | (c[2] as usize >> 3);
+        let index = *nearest_cache[key].get_or_insert_with(|| {
+            let mut best = (u32::MAX, 0u8);
+            for (i, p) in palette.iter().enumerate() {
+                let d: u32 = (0..3)
+                    .map(|k| (p[k] as i32 - c[k] as i32).pow(2) as u32)
+                    .sum();
+                if d < best.0 {
+                    best = (d, i as u8);
+                }
+            }
+            best.1
+        });
+        palette[index as usize]
+    };
+
+    let height = rgba.len() / 4 / width.max(1);
+    let spread = 255.0 / (palette_size as f32).cbrt();
+    let mut error = vec![
+        [0.0f32; 3];
+        if dither == Dither::FloydSteinberg {
+            width * 2
+        } else {
+            0
+        }
+    ];
+
+    for y in 0..height {
+        if dither == Dither::FloydSteinberg {
+            // Two rows of error: current and next
+            let (current, next) = error.split_at_mut(width);
+            current.copy_from_slice(next);
+            next.fill([0.0; 3]);
+        }
+        for x in 0..width {
+            let px = &mut rgba[(y * width + x) * 4..(y * width + x) * 4 + 4];
+            if px[3] == 0 {
+                continue;
+            }
+            let mut color = [px[0] as f32, px[1] as f32, px[2] as f32];
+            match dither {
+                Dither::None => {}
+                Dither::Bayer => {
+                    let t = (BAYER_4X4[y % 4][x % 4] as f32 + 0.5) / 16.0 - 0.5;
+                    color = color.map(|v| v + t * spread);
+                }
+                Dither::FloydSteinberg => {
+                    for k in 0..3 {
+                        color[k] += error[x][k];
+                    }
+                }
+            }
+
+            let chosen = nearest(color);
+            px[..3].copy_from_slice(&chosen);
+            px[3] = 255;
+
+            if dither == Dither::FloydSteinberg {
+                let diff: [f32; 3] = std::array::from_fn(|k| color[k] - chosen[k] as f32);
+                let mut spread_to = |index: usize, weight: f32| {
+                    for k in 0..3 {
+                        error[index][k] += diff[k] * weight;
+                    }
+                };
+                if x + 1 < width {
+                    spread_to(x + 1, 7.0 / 16.0);
+                    spread_to(width + x + 1, 1.0 / 16.0);
+                }
+                if x > 0 {
+                    spread_to(width + x - 1, 3.0 / 16.0);
+                }
+                spread_to(width + x, 5.0 / 16.0);
+            }
+        }
+    }
+}
+
+/// Median-cut palette over the opaque pixels of `rgba`.
+fn median_cut_palette(rgba: &[u8], palette_size: usize) -> Vec<[u8; 3]> {
+    // Subsample large frames; the palette only needs the color distribution
+    let pixel_count = rgba.len() / 4;
+    let step = (pixel_count / 65536).max(1);
+    let samples: Vec<[u8; 3]> = rgba
+        .chunks_exact(4)
+        .step_by(step)
+        .filter(|px| px[3] != 0)
+        .map(|px| [px[0], px[1], px[2]])
+        .collect();
+    if samples.is_empty() {
+        return vec![[0, 0, 0]];
+    }
+
+    let channel_range = |colors: &[[u8; 3]]| {
+        (0..3)
+            .map(|k| {
+                let (min, max) = colors
+                    .iter()
+                    .fold((u8::MAX, 0), |(lo, hi), c| (lo.min(c[k]), hi.max(c[k])));
+                (max - min, k)
+            })
+            .max()
+            .unwrap_or((0, 0))
+    };
+
+    let mut boxes = vec![samples];
+    while boxes.len() < palette_size {
+        let Some((index, (range, channel))) = boxes
+            .iter()
+            .enumerate()
+            .map(|(i, b)| (i, channel_range(b)))
+            .max_by_key(|(_, (range, _))| *range)
+        else {
+            break;
+        };
+        if range == 0 {
+            break;
+        }
+        let mut colors = boxes.swap_remove(index);
+        colors.sort_unstable_by_key(|c| c[channel]);
+        let upper = colors.split_off(colors.len() / 2);
+        boxes.push(colors);
+        boxes.push(upper);
+    }
+
+    boxes
+        .iter()
+        .map(|colors| {
+            let sum = colors.iter().fold([0u64; 3], |acc, c| {
+                [
+                    acc[0] + c[0] as u64,
+                    acc[1] + c[1] as u64,
+                    acc[2] + c[2] as u64,
+                ]
+            });
+            let n = colors.len() as u64;
+            sum.map(|s| ((s + n / 2) / n) as u8)
+        })
+        .collect()
+}
@@ -1,14 +1,19 @@
 pub mod ffmpeg_export;
+pub mod gif_export;
 pub mod png_export;
+pub mod webp_export;
 
 pub use self::ffmpeg_export::FfmpegExportPlugin;
+pub use self::gif_export::GifExportPlugin;
 pub use self::png_export::PngExportPlugin;
+pub use self::webp_export::WebpExportPlugin;
 
 use crate::error::LibraryError;
 use crate::plugin::{Plugin, PluginCategory};
 use crate::project::project::{Composition, Project};
 use crate::project::property::PropertyDefinition;
 use crate::runtime::Image;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -38,6 +43,155 @@ pub trait ExportPlugin: Plugin {
 pub enum ExportFormat {
     Png,
     Video,
+    /// Single animated WebP file, encoded without ffmpeg.
+    WebP,
+    /// Single animated GIF file, encoded without ffmpeg.
+    Gif,
+}
+
+impl ExportFormat {
+    /// Whether every frame goes into one output file (as opposed to one file per frame).
+    pub fn is_single_file(&self) -> bool {
+        !matches!(self, ExportFormat::Png)
+    }
+}
+
+/// Pre-rendered raw `f32le` audio to mux into a video export.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AudioSource {
+    pub path: String,
+    pub sample_rate: u32,
+    pub channels: u16,
+}
+
+/// Hardware video encoder family used by the ffmpeg exporter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HwAccelBackend {
+    Nvenc,
+    VideoToolbox,
+    Vaapi,
+    Qsv,
+}
+
+impl HwAccelBackend {
+    pub const ALL: [HwAccelBackend; 4] = [
+        HwAccelBackend::Nvenc,
+        HwAccelBackend::VideoToolbox,
+        HwAccelBackend::Vaapi,
+        HwAccelBackend::Qsv,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            HwAccelBackend::Nvenc => "NVENC",
+            HwAccelBackend::VideoToolbox => "VideoToolbox",
+            HwAccelBackend::Vaapi => "VAAPI",
+            HwAccelBackend::Qsv => "Quick Sync",
+        }
+    }
+
+    fn encoder_suffix(&self) -> &'static str {
+        match self {
+            HwAccelBackend::Nvenc => "nvenc",
+            HwAccelBackend::VideoToolbox => "videotoolbox",
+            HwAccelBackend::Vaapi => "vaapi",
+            HwAccelBackend::Qsv => "qsv",
+        }
+    }
+
+    /// Hardware encoder replacing the software `codec`, e.g. `libx264` -> `h264_nvenc`.
+    ///
+    /// `None` when the backend has no equivalent for the codec.
+    pub fn encoder_for(&self, codec: &str) -> Option<String> {
+        let family = match codec {
+            "libx264" | "h264" => "h264",
+            "libx265" | "hevc" => "hevc",
+            _ => return None,
+        };
+        Some(format!("{}_{}", family, self.encoder_suffix()))
+    }
+
+    /// Constant-quality arguments for this backend's encoders, from a CRF-style
+    /// `quality` (0 = best, 51 = worst). Hardware encoders do not take `-crf`.
+    pub fn quality_args(&self, quality: f64) -> Vec<String> {
+        let quality = quality.clamp(0.0, 51.0);
+        match self {
+            HwAccelBackend::Nvenc => vec![
+                "-rc".to_string(),
+                "vbr".to_string(),
+                "-cq".to_string(),
+                format!("{}", quality.round()),
+            ],
+            HwAccelBackend::Qsv => vec![
+                "-global_quality".to_string(),
+                format!("{}", quality.round()),
+            ],
+            HwAccelBackend::Vaapi => vec![
+                "-rc_mode".to_string(),
+                "CQP".to_string(),
+                "-qp".to_string(),
+                format!("{}", quality.round()),
+            ],
+            // VideoToolbox quality runs the other way, 1-100 with 100 the best
+            HwAccelBackend::VideoToolbox => vec![
+                "-q:v".to_string(),
+                format!("{}", ((51.0 - quality) / 51.0 * 99.0 + 1.0).round()),
+            ],
+        }
+    }
+
+    /// This backend's equivalent of an x264 `preset`; `None` when the encoder
+    /// has no presets.
+    pub fn preset_for(&self, preset: &str) -> Option<String> {
+        match self {
+            HwAccelBackend::Nvenc => {
+                let level = match preset {
+                    "ultrafast" | "superfast" => 1,
+                    "veryfast" => 2,
+                    "faster" => 3,
+                    "fast" | "medium" => 4,
+                    "slow" => 5,
+                    "slower" => 6,
+                    "veryslow" => 7,
+                    _ => return None,
+                };
+                Some(format!("p{}", level))
+            }
+            HwAccelBackend::Qsv => match preset {
+                "ultrafast" | "superfast" => Some("veryfast".to_string()),
+                "veryfast" | "faster" | "fast" | "medium" | "slow" | "slower" | "veryslow" => {
+                    Some(preset.to_string())
+                }
+                _ => None,
+            },
+            HwAccelBackend::Vaapi | HwAccelBackend::VideoToolbox => None,
+        }
+    }
+
+    /// First backend whose H.264 encoder is compiled into `ffmpeg_binary`, in
+    /// order of preference for the current platform.
+    pub fn detect(ffmpeg_binary: &str) -> Option<Self> {
+        let output = std::process::Command::new(ffmpeg_binary)
+            .args(["-hide_banner", "-encoders"])
+            .output()
+            .ok()?;
+        let encoders = String::from_utf8_lossy(&output.stdout);
+        let preference: &[HwAccelBackend] = if cfg!(target_os = "macos") {
+            &[HwAccelBackend::VideoToolbox]
+        } else {
+            &[
+                HwAccelBackend::Nvenc,
+                HwAccelBackend::Qsv,
+                HwAccelBackend::Vaapi,
+            ]
+        };
+        preference.iter().copied().find(|backend| {
+            let encoder = format!("h264_{}", backend.encoder_suffix());
+            encoders
+                .lines()
+                .any(|line| line.split_whitespace().nth(1) == Some(encoder.as_str()))
+        })
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -46,6 +200,9 @@ pub struct ExportSettings {
     pub codec: String,
     pub pixel_format: String,
     pub ffmpeg_path: Option<String>,
+    /// Hardware encoder to try first; the ffmpeg exporter falls back to software
+    /// if it fails.
+    pub hardware_accel: Option<HwAccelBackend>,
     pub width: u32,
     pub height: u32,
     pub fps: f64,
@@ -105,6 +262,7 @@ impl ExportSettings {
             codec: "png".into(),
             pixel_format: "rgba".into(),
             ffmpeg_path: None,
+            hardware_accel: None,
             width,
             height,
             fps,
@@ -115,10 +273,33 @@ impl ExportSettings {
     pub fn export_format(&self) -> ExportFormat {
         match self.container.as_str() {
             "png" | "apng" => ExportFormat::Png,
+            "webp" => ExportFormat::WebP,
+            "gif" => ExportFormat::Gif,
             _ => ExportFormat::Video,
         }
     }
 
+    /// Attach pre-rendered audio, stored under the `audio_source` parameter.
+    pub fn set_audio_source(&mut self, source: &AudioSource) {
+        if let Ok(value) = serde_json::to_value(source) {
+            self.parameters.insert("audio_source".to_string(), value);
+        }
+    }
+
+    /// Pre-rendered audio attached with [`Self::set_audio_source`].
+    pub fn audio_source(&self) -> Option<AudioSource> {
+        serde_json::from_value(self.parameters.get("audio_source")?.clone()).ok()
+    }
+
+    /// Audio bitrate in kbps from the `audio_bitrate` parameter.
+    ///
+    /// `None` (unset or "Default") leaves the bitrate to the encoder.
+    pub fn audio_bitrate(&self) -> Option<u32> {
+        self.parameter_u64("audio_bitrate")
+            .filter(|&kbps| kbps > 0)
+            .and_then(|kbps| u32::try_from(kbps).ok())
+    }
+
     pub fn parameter_string(&self, key: &str) -> Option<String> {
         match self.parameters.get(key)? {
             Value::String(value) => Some(value.clone()),
@@ -0,0 +1,176 @@
+use super::{ExportPlugin, ExportSettings};
+use crate::error::LibraryError;
+use crate::plugin::Plugin;
+use crate::runtime::Image;
+use log::{info, warn};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use webp::{AnimEncoder, AnimFrame, WebPConfig};
+
+/// Animated WebP exporter.
+///
+/// Frames are collected per output path and encoded in one go by
+/// `finish_export`, since libwebp's animation encoder needs every frame up front.
+pub struct WebpExportPlugin {
+    sessions: Mutex<HashMap<String, WebpSession>>,
+}
+
+impl WebpExportPlugin {
+    pub fn new() -> Self {
+        Self {
+            sessions: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl Plugin for WebpExportPlugin {
+    fn id(&self) -> &'static str {
+        "webp_export"
+    }
+
+    fn name(&self) -> String {
+        "Animated WebP Export".to_string()
+    }
+
+    fn category(&self) -> String {
+        "Export".to_string()
+    }
+
+    fn version(&self) -> (u32, u32, u32) {
+        (0, 1, 0)
+    }
+}
+
+impl ExportPlugin for WebpExportPlugin {
+    fn export_image(
+        &self,
+        path: &str,
+        image: &Image,
+        settings: &ExportSettings,
+    ) -> Result<(), LibraryError> {
+        let mut sessions = self.sessions.lock().unwrap();
+        let session = sessions.entry(path.to_string()).or_insert_with(|| {
+            info!("Starting WebP export session for {}", path);
+            WebpSession::new(settings)
+        });
+
+        if let Some(first) = session.frames.first() {
+            if first.width != image.width || first.height != image.height {
+                return Err(LibraryError::render(format!(
+                    "WebP exporter: frame size {}x{} does not match {}x{}",
+                    image.width, image.height, first.width, first.height
+                )));
+            }
+        }
+        session.frames.push(image.clone());
+        Ok(())
+    }
+
+    fn finish_export(&self, path: &str) -> Result<(), LibraryError> {
+        let Some(session) = self.sessions.lock().unwrap().remove(path) else {
+            // It's possible it was never started or already finished
+            return Ok(());
+        };
+        if session.frames.is_empty() {
+            warn!("WebP exporter: no frames collected for {}", path);
+            return Ok(());
+        }
+
+        info!(
+            "Encoding {} frames to animated WebP: {}",
+            session.frames.len(),
+            path
+        );
+        let data = session.encode()?;
+        std::fs::write(path, data)?;
+        Ok(())
+    }
+
+    fn properties(&self) -> Vec<crate::project::property::PropertyDefinition> {
+        use crate::project::property::{PropertyDefinition, PropertyUiType, PropertyValue};
+        use ordered_float::OrderedFloat;
+        vec![
+            PropertyDefinition::new(
+                "quality",
+                PropertyUiType::Float {
+                    min: 0.0,
+                    max: 100.0,
+                    step: 1.0,
+                    suffix: "".to_string(),
+                    min_hard_limit: true,
+                    max_hard_limit: true,
+                },
+                "Quality (0-100)",
+                PropertyValue::Number(OrderedFloat(80.0)),
+            ),
+            PropertyDefinition::new(
+                "loop_count",
+                PropertyUiType::Integer {
+                    min: 0,
+                    max: 65535,
+                    suffix: "".to_string(),
+                    min_hard_limit: true,
+                    max_hard_limit: true,
+                },
+                "Loop Count (0 = infinite)",
+                PropertyValue::Number(OrderedFloat(0.0)),
+            ),
+        ]
+    }
+}
+
+struct WebpSession {
+    fps: f64,
+    quality: f32,
+    loop_count: i32,
+    frames: Vec<Image>,
+}
+
+impl WebpSession {
+    fn new(settings: &ExportSettings) -> Self {
+        Self {
+            fps: settings.fps,
+            quality: settings
+                .parameter_f64("quality")
+                .unwrap_or(80.0)
+                .clamp(0.0, 100.0) as f32,
+            loop_count: settings
+                .parameter_u64("loop_count")
+                .unwrap_or(0)
+                .min(u16::MAX as u64) as i32,
+            frames: Vec::new(),
+        }
+    }
+
+    fn encode(&self) -> Result<Vec<u8>, LibraryError> {
+        let mut config = WebPConfig::new()
+            .map_err(|_| LibraryError::render("Failed to initialize WebP config".to_string()))?;
+        config.quality = self.quality;
+
+        let (width, height) = (self.frames[0].width, self.frames[0].height);
+        let mut encoder = AnimEncoder::new(width, height, &config);
+        encoder.set_loop_count(self.loop_count);
+        for (index, frame) in self.frames.iter().enumerate() {
+            let timestamp_ms = frame_timestamp_ms(index, self.fps);
+            encoder.add_frame(AnimFrame::from_rgba(
+                &frame.data,
+                frame.width,
+                frame.height,
+                timestamp_ms,
+            ));
+        }
+
+        let memory = encoder
+            .try_encode()
+            .map_err(|e| LibraryError::render(format!("WebP encoding failed: {:?}", e)))?;
+        Ok(memory.to_vec())
+    }
+}
+
+/// Start time of frame `index` in milliseconds.
+fn frame_timestamp_ms(index: usize, fps: f64) -> i32 {
+    if fps <= 0.0 {
+        return 0;
+    }
+    (index as f64 * 1000.0 / fps).round() as i32
+}
@@ -0,0 +1,267 @@
+use super::{FileChangeTracker, LoadPlugin, LoadRequest, LoadResponse};
+use crate::cache::CacheManager;
+use crate::error::LibraryError;
+use crate::plugin::Plugin;
+use crate::project::asset::AssetKind;
+use crate::runtime::Image;
+use exr::prelude::traits::*;
+use exr::prelude::{FlatSamples, MetaData, Text};
+use std::path::Path;
+
+/// Layer name listed for channels without a layer prefix (`R`, `G`, `B`, `A`).
+pub const BASE_LAYER: &str = "RGBA";
+
+fn is_exr(path: &str) -> bool {
+    Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case("exr"))
+}
+
+/// Channel name including the part name of multi-part files, e.g. `diffuse.R`.
+fn full_channel_name(part_name: Option<&Text>, channel: &Text) -> String {
+    match part_name {
+        Some(part) => format!("{}.{}", part, channel),
+        None => channel.to_string(),
+    }
+}
+
+/// Layer of a full channel name: everything before the last `.`.
+fn layer_of(name: &str) -> &str {
+    name.rsplit_once('.').map_or(BASE_LAYER, |(layer, _)| layer)
+}
+
+fn suffix_of(name: &str) -> &str {
+    name.rsplit_once('.').map_or(name, |(_, suffix)| suffix)
+}
+
+/// List the channel layers of an EXR file in file order, reading only the headers.
+pub fn channel_layers(path: &str) -> Result<Vec<String>, LibraryError> {
+    let meta = MetaData::read_from_file(path, false)
+        .map_err(|e| LibraryError::plugin(format!("Failed to read EXR header: {}", e)))?;
+    let mut layers: Vec<String> = Vec::new();
+    for header in meta.headers.iter() {
+        for channel in header.channels.list.iter() {
+            let name = full_channel_name(header.own_attributes.layer_name.as_ref(), &channel.name);
+            let layer = layer_of(&name);
+            if !layers.iter().any(|l| l == layer) {
+                layers.push(layer.to_string());
+            }
+        }
+    }
+    Ok(layers)
+}
+
+/// Decode `selection` from an EXR file as 8-bit RGBA.
+///
+/// `selection` is either a layer name from [`channel_layers`] or the full
+/// name of a single channel, which is shown as greyscale. Without one the
+/// unprefixed channels are used, or the first layer if there are none.
+/// Samples are scaled linearly by `2^exposure_offset` and clamped to 0..=1;
+/// alpha is only clamped.
+pub fn decode(
+    path: &str,
+    selection: Option<&str>,
+    exposure_offset: f64,
+) -> Result<Image, LibraryError> {
+    let image = read()
+        .no_deep_data()
+        .largest_resolution_level()
+        .all_channels()
+        .all_layers()
+        .all_attributes()
+        .from_file(path)
+        .map_err(|e| LibraryError::plugin(format!("Failed to read EXR: {}", e)))?;
+
+    let all_names = image.layer_data.iter().flat_map(|part| {
+        let part_name = part.attributes.layer_name.as_ref();
+        part.channel_data
+            .list
+            .iter()
+            .map(move |c| full_channel_name(part_name, &c.name))
+    });
+    let selection = match selection {
+        Some(selection) => selection.to_string(),
+        None => {
+            let layers: Vec<String> = all_names.map(|n| layer_of(&n).to_string()).collect();
+            if layers.iter().any(|l| l == BASE_LAYER) {
+                BASE_LAYER.to_string()
+            } else {
+                layers.into_iter().next().unwrap_or_default()
+            }
+        }
+    };
+
+    let gain = 2f32.powf(exposure_offset as f32);
+    for part in &image.layer_data {
+        let part_name = part.attributes.layer_name.as_ref();
+        let named: Vec<(String, &FlatSamples)> = part
+            .channel_data
+            .list
+            .iter()
+            .map(|c| (full_channel_name(part_name, &c.name), &c.sample_data))
+            .collect();
+
+        let selected: Vec<(&str, &FlatSamples)> =
+            if let Some((_, samples)) = named.iter().find(|(name, _)| *name == selection) {
+                vec![("Y", *samples)]
+            } else {
+                named
+                    .iter()
+                    .filter(|(name, _)| layer_of(name) == selection)
+                    .map(|(name, samples)| (suffix_of(name), *samples))
+                    .collect()
+            };
+        if selected.is_empty() {
+            continue;
+        }
+
+        let (width, height) = (part.size.width(), part.size.height());
+        return Ok(Image {
+            width: width as u32,
+            height: height as u32,
+            data: tone_map(&selected, width * height, gain),
+        });
+    }
+
+    Err(LibraryError::plugin(format!(
+        "EXR has no channel layer '{}'",
+        selection
+    )))
+}
+
+/// Interleave the selected channels into RGBA bytes, scaled by `gain`.
+///
+/// R, G and B are used when present; otherwise Y, or failing that the first
+/// channel, fills all three. Missing alpha is opaque.
+fn tone_map(channels: &[(&str, &FlatSamples)], pixel_count: usize, gain: f32) -> Vec<u8> {
+    let find = |suffix: &str| {
+        channels
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(suffix))
+            .map(|(_, samples)| samples.values_as_f32().collect::<Vec<f32>>())
+    };
+    let grey = || find("Y").unwrap_or_else(|| channels[0].1.values_as_f32().collect());
+    let (r, g, b) = match (find("R"), find("G"), find("B")) {
+        (Some(r), Some(g), Some(b)) => (r, g, b),
+        _ => {
+            let y = grey();
+            (y.clone(), y.clone(), y)
+        }
+    };
+    let a = find("A");
+
+    let to_u8 = |v: f32| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+    let encode = |v: f32| to_u8(v * gain);
+    let mut data = Vec::with_capacity(pixel_count * 4);
+    for i in 0..pixel_count {
+        // Subsampled channels hold fewer samples; treat the rest as black
+        let sample = |c: &[f32]| c.get(i).copied().unwrap_or(0.0);
+        data.push(encode(sample(&r)));
+        data.push(encode(sample(&g)));
+        data.push(encode(sample(&b)));
+        data.push(a.as_deref().map_or(255, |a| to_u8(sample(a))));
+    }
+    data
+}
+
+/// Loads OpenEXR images, including multi-part and multi-layer files.
+pub struct ExrLoader {
+    file_changes: FileChangeTracker,
+}
+
+impl ExrLoader {
+    pub fn new() -> Self {
+        Self {
+            file_changes: FileChangeTracker::new(),
+        }
+    }
+}
+
+impl Plugin for ExrLoader {
+    fn id(&self) -> &'static str {
+        "exr_loader"
+    }
+
+    fn name(&self) -> String {
+        "OpenEXR Loader".to_string()
+    }
+
+    fn category(&self) -> String {
+        "Media".to_string()
+    }
+
+    fn version(&self) -> (u32, u32, u32) {
+        (0, 1, 0)
+    }
+}
+
+impl LoadPlugin for ExrLoader {
+    fn open(&self, path: &str) -> Result<Vec<crate::plugin::AssetMetadata>, LibraryError> {
+        if !self.probe_format(path) {
+            return Err(LibraryError::plugin("Unsupported file type".to_string()));
+        }
+
+        self.file_changes.check(path);
+
+        let meta = MetaData::read_from_file(path, false)
+            .map_err(|e| LibraryError::plugin(format!("Failed to read EXR header: {}", e)))?;
+        let size = meta
+            .headers
+            .first()
+            .map(|header| header.layer_size)
+            .ok_or_else(|| LibraryError::plugin("EXR has no layers".to_string()))?;
+        Ok(vec![crate::plugin::AssetMetadata {
+            kind: AssetKind::Image,
+            duration: None,
+            fps: None,
+            width: Some(size.width() as u32),
+            height: Some(size.height() as u32),
+            stream_index: None,
+            icc_profile: None,
+            channel_layers: channel_layers(path)?,
+        }])
+    }
+
+    fn load(
+        &self,
+        request: &LoadRequest,
+        cache: &CacheManager,
+    ) -> Result<LoadResponse, LibraryError> {
+        let LoadRequest::Image {
+            path,
+            channel_layer,
+            exposure_offset,
+            ..
+        } = request
+        else {
+            return Err(LibraryError::plugin(
+                "ExrLoader only loads still images".to_string(),
+            ));
+        };
+        if !self.probe_format(path) {
+            return Err(LibraryError::plugin("Unsupported file type".to_string()));
+        }
+        self.file_changes.invalidate_if_stale(path, cache);
+
+        // Keyed under `path::` so evict_path drops every layer and exposure
+        let key = format!(
+            "{}::{}@{}",
+            path,
+            channel_layer.as_deref().unwrap_or(""),
+            exposure_offset
+        );
+        let image = if let Some(img) = cache.get_image(&key) {
+            img
+        } else {
+            let img = decode(path, channel_layer.as_deref(), *exposure_offset)?;
+            cache.put_image(&key, &img);
+            img
+        };
+        Ok(LoadResponse { image })
+    }
+
+    fn probe_format(&self, path: &str) -> bool {
+        is_exr(path)
+    }
+}
@@ -1,4 +1,4 @@
-use super::{LoadPlugin, LoadRequest, LoadResponse};
+use super::{FileChangeTracker, LoadPlugin, LoadRequest, LoadResponse};
 use crate::cache::CacheManager;
 use crate::error::LibraryError;
 use crate::plugin::Plugin;
@@ -6,7 +6,44 @@ use crate::rendering::color::{ColorSpaceManager, OcioProcessor};
 use crate::runtime::Image;
 use ffmpeg_next as ffmpeg;
 use std::collections::HashMap;
-use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Frames ahead of the decoder position that are reached by decoding forward
+/// instead of seeking.
+pub const DEFAULT_SEEK_WINDOW: u64 = 8;
+
+/// Decoder position, used to choose between seeking and decoding forward.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DecoderState {
+    /// Frame number the decoder produces next; `None` until the first decode.
+    pub next_frame_number: Option<u64>,
+    /// How far ahead of `next_frame_number` a request may be and still be
+    /// decoded sequentially.
+    pub seek_window: u64,
+    /// Seeks performed so far.
+    pub seek_count: u64,
+}
+
+impl DecoderState {
+    pub fn new(seek_window: u64) -> Self {
+        Self {
+            next_frame_number: None,
+            seek_window,
+            seek_count: 0,
+        }
+    }
+
+    /// Seeking (to the keyframe before `frame_number`) is needed when going
+    /// backwards or jumping past the look-ahead window. Within the window,
+    /// decoding the frames in between is cheaper than decoding from a keyframe.
+    pub fn needs_seek(&self, frame_number: u64) -> bool {
+        match self.next_frame_number {
+            Some(next) => frame_number < next || frame_number - next > self.seek_window,
+            None => true,
+        }
+    }
+}
 
 // ============================================================================
 // VideoReader - Decodes video frames using FFmpeg
@@ -16,7 +53,7 @@ pub struct VideoReader {
     input_context: ffmpeg::format::context::Input,
     video_stream_index: usize,
     decoder: ffmpeg::decoder::Video,
-    next_frame_number: Option<u64>,
+    state: DecoderState,
     fps: f64,
     ocio_processor: Option<OcioProcessor>,
     current_color_space: Option<(String, String)>,
@@ -72,7 +109,7 @@ impl VideoReader {
             input_context,
             video_stream_index,
             decoder,
-            next_frame_number: None,
+            state: DecoderState::new(DEFAULT_SEEK_WINDOW),
             fps,
             ocio_processor: None,
             current_color_space: None,
@@ -95,6 +132,15 @@ impl VideoReader {
         (self.decoder.width(), self.decoder.height())
     }
 
+    pub fn decoder_state(&self) -> DecoderState {
+        self.state
+    }
+
+    /// Set the look-ahead window (in frames) decoded sequentially instead of seeking.
+    pub fn set_seek_window(&mut self, frames: u64) {
+        self.state.seek_window = frames;
+    }
+
     pub fn set_color_space(&mut self, src: &str, dst: &str) {
         if let Some((current_src, current_dst)) = &self.current_color_space {
             if current_src == src && current_dst == dst {
@@ -113,15 +159,12 @@ impl VideoReader {
         let time_base = stream.time_base();
         let fps = self.fps;
 
-        let need_seek = match self.next_frame_number {
-            Some(next) => next != frame_number,
-            None => true,
-        };
-        if need_seek {
+        if self.state.needs_seek(frame_number) {
             let timestamp = (frame_number as f64 / fps / f64::from(time_base.numerator())
                 * f64::from(time_base.denominator())) as i64;
             self.input_context.seek(timestamp, ..timestamp)?;
             self.decoder.flush();
+            self.state.seek_count += 1;
         }
 
         let mut decoded_frame = None;
@@ -139,7 +182,7 @@ impl VideoReader {
 
                     if frame_num >= frame_number {
                         decoded_frame = Some(frame.clone());
-                        self.next_frame_number = Some(frame_num + 1);
+                        self.state.next_frame_number = Some(frame_num + 1);
                         break;
                     }
                 }
@@ -160,7 +203,7 @@ impl VideoReader {
                     .round() as u64;
                 if frame_num >= frame_number {
                     decoded_frame = Some(frame.clone());
-                    self.next_frame_number = Some(frame_num + 1);
+                    self.state.next_frame_number = Some(frame_num + 1);
                     break;
                 }
             }
@@ -256,6 +299,7 @@ impl VideoReader {
             let mut fps = None;
             let mut width = None;
             let mut height = None;
+            let mut icc_profile = None;
 
             if kind == crate::project::asset::AssetKind::Video {
                 let avg_frame_rate = stream.avg_frame_rate();
@@ -271,6 +315,11 @@ impl VideoReader {
                         height = Some(decoder.height());
                     }
                 }
+
+                icc_profile = stream
+                    .side_data()
+                    .find(|data| data.kind() == ffmpeg::codec::packet::side_data::Type::ICC_PROFILE)
+                    .map(|data| data.data().to_vec());
             }
 
             streams.push(crate::plugin::AssetMetadata {
@@ -280,6 +329,8 @@ impl VideoReader {
                 width,
                 height,
                 stream_index: Some(stream.index()),
+                icc_profile,
+                channel_layers: Vec::new(),
             });
         }
         streams
@@ -291,20 +342,44 @@ impl VideoReader {
 // ============================================================================
 
 pub struct FfmpegVideoLoader {
-    readers: Mutex<HashMap<u64, VideoReader>>,
+    /// Each reader has its own lock, so different files decode in parallel.
+    readers: Mutex<HashMap<u64, Arc<Mutex<VideoReader>>>>,
+    /// Look-ahead window applied to every reader (see [`DecoderState`]).
+    seek_window: AtomicU64,
     /// Maps path to existing context_id to avoid re-opening the same file.
     path_to_context: Mutex<HashMap<String, u64>>,
-    next_context_id: std::sync::atomic::AtomicU64,
+    next_context_id: AtomicU64,
+    file_changes: FileChangeTracker,
 }
 
 impl FfmpegVideoLoader {
     pub fn new() -> Self {
         Self {
             readers: Mutex::new(HashMap::new()),
+            seek_window: AtomicU64::new(DEFAULT_SEEK_WINDOW),
             path_to_context: Mutex::new(HashMap::new()),
-            next_context_id: std::sync::atomic::AtomicU64::new(1),
+            next_context_id: AtomicU64::new(1),
+            file_changes: FileChangeTracker::new(),
         }
     }
+
+    pub fn seek_window(&self) -> u64 {
+        self.seek_window.load(Ordering::Relaxed)
+    }
+
+    /// Change the look-ahead window for open and future readers.
+    pub fn set_seek_window(&self, frames: u64) {
+        self.seek_window.store(frames, Ordering::Relaxed);
+        for reader in self.readers.lock().unwrap().values() {
+            reader.lock().unwrap().set_seek_window(frames);
+        }
+    }
+
+    fn open_reader(&self, path: &str) -> Result<VideoReader, LibraryError> {
+        let mut reader = VideoReader::new(path)?;
+        reader.set_seek_window(self.seek_window());
+        Ok(reader)
+    }
 }
 
 impl Plugin for FfmpegVideoLoader {
@@ -327,19 +402,27 @@ impl Plugin for FfmpegVideoLoader {
 
 impl LoadPlugin for FfmpegVideoLoader {
     fn open(&self, path: &str) -> Result<Vec<crate::plugin::AssetMetadata>, LibraryError> {
+        // Drop the cached reader if the file changed on disk; its cached
+        // frames are cleared on the next load()
+        if self.file_changes.check(path) {
+            if let Some(context_id) = self.path_to_context.lock().unwrap().remove(path) {
+                self.readers.lock().unwrap().remove(&context_id);
+            }
+        }
+
         // Check if already opened
         {
             let path_map = self.path_to_context.lock().unwrap();
             if let Some(&context_id) = path_map.get(path) {
                 let readers = self.readers.lock().unwrap();
                 if let Some(reader) = readers.get(&context_id) {
-                    return Ok(reader.get_available_streams());
+                    return Ok(reader.lock().unwrap().get_available_streams());
                 }
             }
         }
 
         // Open new reader
-        let reader = VideoReader::new(path)?;
+        let reader = self.open_reader(path)?;
         let streams = reader.get_available_streams();
 
         if streams.is_empty() {
@@ -347,15 +430,16 @@ impl LoadPlugin for FfmpegVideoLoader {
         }
 
         // Generate context ID and store
-        let context_id = self
-            .next_context_id
-            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let context_id = self.next_context_id.fetch_add(1, Ordering::Relaxed);
 
         self.path_to_context
             .lock()
             .unwrap()
             .insert(path.to_string(), context_id);
-        self.readers.lock().unwrap().insert(context_id, reader);
+        self.readers
+            .lock()
+            .unwrap()
+            .insert(context_id, Arc::new(Mutex::new(reader)));
 
         Ok(streams)
     }
@@ -373,6 +457,8 @@ impl LoadPlugin for FfmpegVideoLoader {
             output_color_space,
         } = request
         {
+            self.file_changes.invalidate_if_stale(path, cache);
+
             // Get context_id from path, auto-open if needed
             let context_id = {
                 let mut path_map = self.path_to_context.lock().unwrap();
@@ -380,27 +466,32 @@ impl LoadPlugin for FfmpegVideoLoader {
                     id
                 } else {
                     // Open new reader inline to avoid lock/unlock cycles
-                    let reader = VideoReader::new(path)?;
+                    let reader = self.open_reader(path)?;
                     let streams = reader.get_available_streams();
                     if streams.is_empty() {
                         return Err(LibraryError::plugin("No video or audio stream".to_string()));
                     }
-                    let id = self
-                        .next_context_id
-                        .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                    let id = self.next_context_id.fetch_add(1, Ordering::Relaxed);
                     path_map.insert(path.to_string(), id);
-                    self.readers.lock().unwrap().insert(id, reader);
+                    self.readers
+                        .lock()
+                        .unwrap()
+                        .insert(id, Arc::new(Mutex::new(reader)));
                     id
                 }
             };
 
             let cache_key = path.as_str();
 
+            let reader = self
+                .readers
+                .lock()
+                .unwrap()
+                .get(&context_id)
+                .cloned()
+                .ok_or_else(|| LibraryError::plugin(format!("Reader for {} not found", path)))?;
             let image = {
-                let mut readers = self.readers.lock().unwrap();
-                let reader = readers.get_mut(&context_id).ok_or_else(|| {
-                    LibraryError::plugin(format!("Reader for {} not found", path))
-                })?;
+                let mut reader = reader.lock().unwrap();
 
                 if let (Some(src), Some(dst)) = (input_color_space, output_color_space) {
                     reader.set_color_space(src, dst);
@@ -422,4 +513,31 @@ impl LoadPlugin for FfmpegVideoLoader {
             ))
         }
     }
+
+    fn probe_format(&self, path: &str) -> bool {
+        use std::io::Read;
+
+        // Let FFmpeg guess the container from the file header only,
+        // without opening a demuxer or reading stream info.
+        const PROBE_SIZE: usize = 2048;
+        let mut header = vec![0u8; PROBE_SIZE + ffmpeg::ffi::AVPROBE_PADDING_SIZE as usize];
+        let read =
+            match std::fs::File::open(path).and_then(|mut f| f.read(&mut header[..PROBE_SIZE])) {
+                Ok(n) if n > 0 => n,
+                _ => return false,
+            };
+        let Ok(filename) = std::ffi::CString::new(path) else {
+            return false;
+        };
+        let mut probe_data = ffmpeg::ffi::AVProbeData {
+            filename: filename.as_ptr(),
+            buf: header.as_mut_ptr(),
+            buf_size: read as i32,
+            mime_type: std::ptr::null(),
+        };
+        // SAFETY: probe_data points at buffers that outlive the call, and the
+        // header buffer carries the zeroed padding FFmpeg requires.
+        let format = unsafe { ffmpeg::ffi::av_probe_input_format(&mut probe_data, 1) };
+        !format.is_null()
+    }
 }
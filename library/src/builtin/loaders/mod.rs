@@ -1,21 +1,38 @@
+pub mod exr_loader;
 pub mod ffmpeg_video;
 pub mod native_image;
+pub mod pdf_loader;
+pub mod svg_loader;
 
+pub use self::exr_loader::ExrLoader;
 pub use self::ffmpeg_video::FfmpegVideoLoader;
 pub use self::native_image::NativeImageLoader;
+pub use self::pdf_loader::PdfLoader;
+pub use self::svg_loader::SvgLoader;
 
 use crate::cache::CacheManager;
 use crate::error::LibraryError;
 use crate::plugin::{Plugin, PluginCategory};
 use crate::project::asset::AssetKind;
 use crate::runtime::Image;
-use std::collections::HashMap;
-use std::sync::Arc;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
 
 #[derive(Debug, Clone)]
 pub enum LoadRequest {
     /// Load a static image.
-    Image { path: String },
+    Image {
+        path: String,
+        /// Channel layer to show, for formats that carry several (EXR).
+        channel_layer: Option<String>,
+        /// Exposure adjustment in stops applied when converting HDR data to 8 bits.
+        exposure_offset: f64,
+        /// Rasterization scale for vector formats (SVG); raster formats ignore it.
+        scale: f64,
+        /// Zero-based page for multi-page documents (PDF). `None` shows the first page.
+        page: Option<usize>,
+    },
     /// Load a video frame.
     VideoFrame {
         path: String,
@@ -29,7 +46,7 @@ pub enum LoadRequest {
 impl LoadRequest {
     pub fn path(&self) -> &str {
         match self {
-            LoadRequest::Image { path } => path,
+            LoadRequest::Image { path, .. } => path,
             LoadRequest::VideoFrame { path, .. } => path,
         }
     }
@@ -47,6 +64,10 @@ pub struct AssetMetadata {
     pub width: Option<u32>,
     pub height: Option<u32>,
     pub stream_index: Option<usize>,
+    /// Embedded ICC profile of the stream, if the container carries one.
+    pub icc_profile: Option<Vec<u8>>,
+    /// Named channel layers, for formats that carry several (EXR).
+    pub channel_layers: Vec<String>,
 }
 
 pub trait LoadPlugin: Plugin {
@@ -64,6 +85,12 @@ pub trait LoadPlugin: Plugin {
         cache: &CacheManager,
     ) -> Result<LoadResponse, LibraryError>;
 
+    /// Quickly check whether this plugin can handle the file.
+    /// The default opens the file; plugins should override with a cheaper check.
+    fn probe_format(&self, path: &str) -> bool {
+        self.open(path).is_ok()
+    }
+
     fn plugin_type(&self) -> PluginCategory {
         PluginCategory::Load
     }
@@ -123,3 +150,49 @@ impl LoadRepository {
         self.values_by_priority()
     }
 }
+
+/// Tracks file modification times so loaders can drop cached decodes
+/// of files that changed on disk.
+///
+/// Changes are detected in `open()` (which has no cache access) and the
+/// matching cache entries are cleared on the next `load()`.
+#[derive(Default)]
+pub(crate) struct FileChangeTracker {
+    mtimes: Mutex<HashMap<String, SystemTime>>,
+    stale_paths: Mutex<HashSet<String>>,
+}
+
+impl FileChangeTracker {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the current modification time of `path`.
+    ///
+    /// Returns true if the file changed since it was last recorded; the path
+    /// is then marked stale until `invalidate_if_stale` runs.
+    pub(crate) fn check(&self, path: &str) -> bool {
+        let Ok(mtime) = std::fs::metadata(path).and_then(|m| m.modified()) else {
+            return false;
+        };
+        let previous = self.mtimes.lock().unwrap().insert(path.to_string(), mtime);
+        let changed = previous.is_some_and(|prev| prev != mtime);
+        if changed {
+            log::info!("Media file changed on disk: {}", path);
+            self.stale_paths.lock().unwrap().insert(path.to_string());
+        }
+        changed
+    }
+
+    /// Clear cached entries for `path` if it was marked stale.
+    ///
+    /// Returns true if entries were cleared, so loaders can drop their own
+    /// per-path state as well.
+    pub(crate) fn invalidate_if_stale(&self, path: &str, cache: &CacheManager) -> bool {
+        let stale = self.stale_paths.lock().unwrap().remove(path);
+        if stale {
+            cache.evict_path(path);
+        }
+        stale
+    }
+}
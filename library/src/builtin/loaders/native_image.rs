@@ -1,12 +1,115 @@
-use super::{LoadPlugin, LoadRequest, LoadResponse};
+use super::{FileChangeTracker, LoadPlugin, LoadRequest, LoadResponse};
 use crate::cache::CacheManager;
 use crate::error::LibraryError;
 use crate::plugin::Plugin;
+use crate::project::asset::AssetKind;
 use crate::runtime::Image;
+use image::codecs::hdr::HdrDecoder;
+use image::codecs::png::PngDecoder;
+use image::{AnimationDecoder, DynamicImage};
+use std::cell::Cell;
+use std::collections::HashMap;
 use std::error::Error;
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::Mutex;
+
+/// Fallback frame rate when an animated PNG reports a zero frame delay.
+const DEFAULT_APNG_FPS: f64 = 10.0;
+
+/// Lowercase file extensions handled by the native image decoder.
+const SUPPORTED_EXTENSIONS: &[&str] = &[
+    "png", "apng", "jpg", "jpeg", "bmp", "webp", "tif", "tiff", "tga", "gif", "ico", "pnm", "hdr",
+    "exr",
+];
+
+/// How high dynamic range samples are compressed into the 0..1 display range.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ToneMapMethod {
+    /// `v / (1 + v)`: keeps midtones, rolls off highlights smoothly.
+    #[default]
+    Reinhard,
+    /// `1 - e^-v`: simulated film exposure, brighter than Reinhard.
+    Exposure,
+    /// ACES filmic approximation with a toe and shoulder.
+    Filmic,
+}
+
+impl ToneMapMethod {
+    /// Map a linear HDR sample to linear 0..1.
+    pub fn apply(self, v: f32) -> f32 {
+        let v = v.max(0.0);
+        let mapped = match self {
+            ToneMapMethod::Reinhard => v / (1.0 + v),
+            ToneMapMethod::Exposure => 1.0 - (-v).exp(),
+            ToneMapMethod::Filmic => (v * (2.51 * v + 0.03)) / (v * (2.43 * v + 0.59) + 0.14),
+        };
+        mapped.clamp(0.0, 1.0)
+    }
+}
+
+thread_local! {
+    /// Tone mapping used for HDR files loaded on this thread.
+    static DEFAULT_TONE_MAP: Cell<ToneMapMethod> = const { Cell::new(ToneMapMethod::Reinhard) };
+}
+
+/// Tone mapping used for HDR files loaded on the current thread.
+pub fn default_tone_map() -> ToneMapMethod {
+    DEFAULT_TONE_MAP.with(Cell::get)
+}
+
+/// Change the tone mapping for HDR files loaded on the current thread.
+///
+/// Already decoded images stay in the cache; evict them to see the change.
+pub fn set_default_tone_map(method: ToneMapMethod) {
+    DEFAULT_TONE_MAP.with(|cell| cell.set(method));
+}
+
+fn linear_to_srgb(v: f32) -> f32 {
+    if v <= 0.003_130_8 {
+        v * 12.92
+    } else {
+        1.055 * v.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Decode a Radiance `.hdr` or OpenEXR file and tone map it to sRGB RGBA.
+pub fn load_hdr_image(path: &str, method: ToneMapMethod) -> Result<Image, LibraryError> {
+    let img = if matches!(file_extension(path).as_deref(), Some("hdr")) {
+        let decoder = HdrDecoder::new(BufReader::new(File::open(path)?))?;
+        DynamicImage::from_decoder(decoder)?
+    } else {
+        image::open(path)?
+    };
+    let hdr = img.into_rgba32f();
+    let (width, height) = hdr.dimensions();
+    let to_u8 = |v: f32| (linear_to_srgb(method.apply(v)) * 255.0).round() as u8;
+    let data = hdr
+        .pixels()
+        .flat_map(|p| {
+            let [r, g, b, a] = p.0;
+            [
+                to_u8(r),
+                to_u8(g),
+                to_u8(b),
+                (a.clamp(0.0, 1.0) * 255.0).round() as u8,
+            ]
+        })
+        .collect();
+    Ok(Image {
+        width,
+        height,
+        data,
+    })
+}
 
 /// Load an image from disk and return as RGBA.
+///
+/// HDR formats are tone mapped with the current thread's [`default_tone_map`].
 pub fn load_image(path: &str) -> Result<Image, Box<dyn Error>> {
+    if is_hdr(path) {
+        return Ok(load_hdr_image(path, default_tone_map())?);
+    }
     let img = image::open(path).map_err(|e| format!("Failed to open image file: {}", e))?;
     let rgba_image = img.to_rgba8();
     Ok(Image {
@@ -16,11 +119,299 @@ pub fn load_image(path: &str) -> Result<Image, Box<dyn Error>> {
     })
 }
 
-pub struct NativeImageLoader;
+fn file_extension(path: &str) -> Option<String> {
+    std::path::Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase())
+}
+
+fn is_tiff(path: &str) -> bool {
+    matches!(file_extension(path).as_deref(), Some("tif" | "tiff"))
+}
+
+fn is_hdr(path: &str) -> bool {
+    matches!(file_extension(path).as_deref(), Some("hdr" | "exr"))
+}
+
+fn is_png(path: &str) -> bool {
+    matches!(file_extension(path).as_deref(), Some("png" | "apng"))
+}
+
+fn tiff_decoder(path: &str) -> Result<tiff::decoder::Decoder<BufReader<File>>, LibraryError> {
+    let file = File::open(path)?;
+    tiff::decoder::Decoder::new(BufReader::new(file))
+        .map_err(|e| LibraryError::plugin(format!("Failed to open TIFF: {}", e)))
+}
+
+/// Count the pages (image file directories) in a TIFF file.
+pub fn tiff_page_count(path: &str) -> Result<usize, LibraryError> {
+    let mut decoder = tiff_decoder(path)?;
+    let mut count = 1;
+    while decoder.more_images() {
+        decoder
+            .next_image()
+            .map_err(|e| LibraryError::plugin(format!("Failed to read TIFF page: {}", e)))?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// Decode a single TIFF page as RGBA.
+pub fn load_tiff_page(path: &str, page: usize) -> Result<Image, LibraryError> {
+    use tiff::ColorType;
+    use tiff::decoder::DecodingResult;
+
+    let mut decoder = tiff_decoder(path)?;
+    decoder
+        .seek_to_image(page)
+        .map_err(|e| LibraryError::plugin(format!("TIFF page {} not found: {}", page, e)))?;
+
+    let (width, height) = decoder
+        .dimensions()
+        .map_err(|e| LibraryError::plugin(format!("Failed to read TIFF dimensions: {}", e)))?;
+    let color_type = decoder
+        .colortype()
+        .map_err(|e| LibraryError::plugin(format!("Failed to read TIFF color type: {}", e)))?;
+    let samples = match decoder
+        .read_image()
+        .map_err(|e| LibraryError::plugin(format!("Failed to decode TIFF page: {}", e)))?
+    {
+        DecodingResult::U8(data) => data,
+        DecodingResult::U16(data) => data.into_iter().map(|v| (v >> 8) as u8).collect(),
+        _ => {
+            return Err(LibraryError::plugin(
+                "Unsupported TIFF sample format".to_string(),
+            ));
+        }
+    };
+
+    let data = match color_type {
+        ColorType::RGBA(_) => samples,
+        ColorType::RGB(_) => samples
+            .chunks_exact(3)
+            .flat_map(|p| [p[0], p[1], p[2], 255])
+            .collect(),
+        ColorType::GrayA(_) => samples
+            .chunks_exact(2)
+            .flat_map(|p| [p[0], p[0], p[0], p[1]])
+            .collect(),
+        ColorType::Gray(_) => samples.into_iter().flat_map(|v| [v, v, v, 255]).collect(),
+        other => {
+            return Err(LibraryError::plugin(format!(
+                "Unsupported TIFF color type: {:?}",
+                other
+            )));
+        }
+    };
+
+    Ok(Image {
+        width,
+        height,
+        data,
+    })
+}
+
+/// Frame count and frame rate of an animated PNG, read from its `acTL` and
+/// first `fcTL` chunks without decoding any pixels. Returns `None` for a
+/// regular (non-animated) PNG.
+pub fn apng_info(path: &str) -> Result<Option<(usize, f64)>, LibraryError> {
+    use std::io::Read;
+
+    let mut reader = BufReader::new(File::open(path)?);
+    let mut signature = [0u8; 8];
+    reader.read_exact(&mut signature)?;
+    if signature != *b"\x89PNG\r\n\x1a\n" {
+        return Err(LibraryError::plugin("Not a PNG file".to_string()));
+    }
+
+    let mut frame_count = None;
+    loop {
+        let mut header = [0u8; 8];
+        if reader.read_exact(&mut header).is_err() {
+            return Ok(None);
+        }
+        let length = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as usize;
+        let chunk_type = &header[4..8];
+        match chunk_type {
+            b"acTL" | b"fcTL" => {
+                let mut data = vec![0u8; length + 4];
+                reader.read_exact(&mut data)?;
+                if chunk_type == b"acTL" && length >= 4 {
+                    frame_count = Some(u32::from_be_bytes([data[0], data[1], data[2], data[3]]));
+                } else if chunk_type == b"fcTL" && length >= 24 {
+                    let Some(count) = frame_count else {
+                        return Ok(None);
+                    };
+                    let delay_num = u16::from_be_bytes([data[20], data[21]]);
+                    let delay_den = match u16::from_be_bytes([data[22], data[23]]) {
+                        0 => 100,
+                        den => den,
+                    };
+                    let fps = if delay_num > 0 {
+                        delay_den as f64 / delay_num as f64
+                    } else {
+                        DEFAULT_APNG_FPS
+                    };
+                    return Ok(Some((count as usize, fps)));
+                }
+            }
+            // The acTL chunk must precede the image data
+            b"IDAT" if frame_count.is_none() => return Ok(None),
+            b"IEND" => {
+                return Ok(frame_count.map(|count| (count as usize, DEFAULT_APNG_FPS)));
+            }
+            // Skip pixel data and other chunks (plus their CRC) without reading them
+            _ => reader.seek_relative(length as i64 + 4)?,
+        }
+    }
+}
+
+/// Decode frame `index` of an animated PNG as RGBA.
+///
+/// Frames can only be decoded in order, so the frames before `index` are
+/// decoded on the way and handed to `on_frame` (for caching); decoding stops
+/// at `index`. Indices past the end return the last frame.
+pub fn load_apng_frame(
+    path: &str,
+    index: usize,
+    mut on_frame: impl FnMut(usize, &Image),
+) -> Result<Image, LibraryError> {
+    let decoder = PngDecoder::new(BufReader::new(File::open(path)?))?;
+    if !decoder.is_apng()? {
+        return Err(LibraryError::plugin("PNG is not animated".to_string()));
+    }
+
+    let mut last = None;
+    for (i, frame) in decoder.apng()?.into_frames().enumerate() {
+        let buffer = frame?.into_buffer();
+        let image = Image {
+            width: buffer.width(),
+            height: buffer.height(),
+            data: buffer.into_raw(),
+        };
+        on_frame(i, &image);
+        if i == index {
+            return Ok(image);
+        }
+        last = Some(image);
+    }
+    last.ok_or_else(|| LibraryError::plugin("APNG has no frames".to_string()))
+}
+
+pub struct NativeImageLoader {
+    file_changes: FileChangeTracker,
+    /// TIFF page counts by path, dropped when the file changes on disk.
+    page_counts: Mutex<HashMap<String, usize>>,
+}
 
 impl NativeImageLoader {
     pub fn new() -> Self {
-        Self
+        Self {
+            file_changes: FileChangeTracker::new(),
+            page_counts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn cached_page_count(&self, path: &str) -> Result<usize, LibraryError> {
+        if let Some(&count) = self.page_counts.lock().unwrap().get(path) {
+            return Ok(count);
+        }
+        let count = tiff_page_count(path)?;
+        self.page_counts
+            .lock()
+            .unwrap()
+            .insert(path.to_string(), count);
+        Ok(count)
+    }
+
+    /// Metadata for a multi-page TIFF: one entry per page.
+    fn open_tiff_pages(
+        &self,
+        path: &str,
+        width: u32,
+        height: u32,
+    ) -> Option<Vec<crate::plugin::AssetMetadata>> {
+        let pages = self.cached_page_count(path).ok()?;
+        if pages < 2 {
+            return None;
+        }
+        Some(
+            (0..pages)
+                .map(|page| crate::plugin::AssetMetadata {
+                    kind: AssetKind::Video,
+                    duration: None,
+                    fps: None,
+                    width: Some(width),
+                    height: Some(height),
+                    stream_index: Some(page),
+                    icc_profile: None,
+                    channel_layers: Vec::new(),
+                })
+                .collect(),
+        )
+    }
+
+    /// Metadata for an animated PNG: a single video stream.
+    fn open_apng(path: &str, width: u32, height: u32) -> Option<crate::plugin::AssetMetadata> {
+        let (frame_count, fps) = apng_info(path).ok()??;
+        Some(crate::plugin::AssetMetadata {
+            kind: AssetKind::Video,
+            duration: Some(frame_count as f64 / fps),
+            fps: Some(fps),
+            width: Some(width),
+            height: Some(height),
+            stream_index: None,
+            icc_profile: None,
+            channel_layers: Vec::new(),
+        })
+    }
+
+    /// Load a frame from a multi-page TIFF or animated PNG.
+    ///
+    /// TIFF pages are selected by `stream_index`; without one, `frame_number`
+    /// steps through the pages so the file plays back as a sequence.
+    fn load_frame(
+        &self,
+        path: &str,
+        frame_number: u64,
+        stream_index: Option<usize>,
+        cache: &CacheManager,
+    ) -> Result<Image, LibraryError> {
+        if is_tiff(path) {
+            let page = match stream_index {
+                Some(page) => page,
+                None => frame_number as usize % self.cached_page_count(path)?,
+            };
+            if let Some(img) = cache.get_video_frame(path, page as u64) {
+                return Ok(img);
+            }
+            let img = load_tiff_page(path, page)?;
+            cache.put_video_frame(path, page as u64, &img);
+            return Ok(img);
+        }
+
+        if is_png(path) {
+            if let Some(img) = cache.get_video_frame(path, frame_number) {
+                return Ok(img);
+            }
+            // Decoding is sequential, so cache the frames passed on the way
+            let mut last_index = 0;
+            let img = load_apng_frame(path, frame_number as usize, |i, frame| {
+                last_index = i;
+                cache.put_video_frame(path, i as u64, frame);
+            })?;
+            // Frames past the end hold on the last one; cache it under the
+            // requested number too so holding doesn't decode the file again
+            if last_index as u64 != frame_number {
+                cache.put_video_frame(path, frame_number, &img);
+            }
+            return Ok(img);
+        }
+
+        Err(LibraryError::plugin(
+            "NativeImageLoader only decodes frames from TIFF or APNG files".to_string(),
+        ))
     }
 }
 
@@ -44,29 +435,36 @@ impl Plugin for NativeImageLoader {
 
 impl LoadPlugin for NativeImageLoader {
     fn open(&self, path: &str) -> Result<Vec<crate::plugin::AssetMetadata>, LibraryError> {
-        // Check file extension
-        let ext = std::path::Path::new(path)
-            .extension()
-            .and_then(|e| e.to_str())
-            .map(|e| e.to_lowercase());
-        let is_supported = matches!(
-            ext.as_deref(),
-            Some("png" | "jpg" | "jpeg" | "bmp" | "webp" | "tiff" | "tga" | "gif" | "ico" | "pnm")
-        );
-        if !is_supported {
+        if !self.probe_format(path) {
             return Err(LibraryError::plugin("Unsupported file type".to_string()));
         }
 
+        if self.file_changes.check(path) {
+            self.page_counts.lock().unwrap().remove(path);
+        }
+
         let (w, h) = image::image_dimensions(path)
             .map_err(|e| LibraryError::from(Box::new(e) as Box<dyn std::error::Error>))?;
 
+        if is_tiff(path) {
+            if let Some(pages) = self.open_tiff_pages(path, w, h) {
+                return Ok(pages);
+            }
+        } else if is_png(path) {
+            if let Some(meta) = Self::open_apng(path, w, h) {
+                return Ok(vec![meta]);
+            }
+        }
+
         Ok(vec![crate::plugin::AssetMetadata {
-            kind: crate::project::asset::AssetKind::Image,
+            kind: AssetKind::Image,
             duration: None,
             fps: None,
             width: Some(w),
             height: Some(h),
             stream_index: None,
+            icc_profile: None,
+            channel_layers: Vec::new(),
         }])
     }
 
@@ -75,19 +473,34 @@ impl LoadPlugin for NativeImageLoader {
         request: &LoadRequest,
         cache: &CacheManager,
     ) -> Result<LoadResponse, LibraryError> {
-        if let LoadRequest::Image { path } = request {
-            let image = if let Some(img) = cache.get_image(path) {
-                img
-            } else {
-                let img = load_image(path)?;
-                cache.put_image(path, &img);
-                img
-            };
-            Ok(LoadResponse { image })
-        } else {
-            Err(LibraryError::plugin(
-                "NativeImageLoader received unsupported request".to_string(),
-            ))
+        if self.file_changes.invalidate_if_stale(request.path(), cache) {
+            self.page_counts.lock().unwrap().remove(request.path());
+        }
+
+        match request {
+            LoadRequest::Image { path, .. } => {
+                let image = if let Some(img) = cache.get_image(path) {
+                    img
+                } else {
+                    let img = load_image(path)?;
+                    cache.put_image(path, &img);
+                    img
+                };
+                Ok(LoadResponse { image })
+            }
+            LoadRequest::VideoFrame {
+                path,
+                frame_number,
+                stream_index,
+                ..
+            } => {
+                let image = self.load_frame(path, *frame_number, *stream_index, cache)?;
+                Ok(LoadResponse { image })
+            }
         }
     }
+
+    fn probe_format(&self, path: &str) -> bool {
+        file_extension(path).is_some_and(|ext| SUPPORTED_EXTENSIONS.contains(&ext.as_str()))
+    }
 }
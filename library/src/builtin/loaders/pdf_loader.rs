@@ -0,0 +1,177 @@
+use super::{FileChangeTracker, LoadPlugin, LoadRequest, LoadResponse};
+use crate::cache::CacheManager;
+use crate::error::LibraryError;
+use crate::plugin::Plugin;
+use crate::project::asset::AssetKind;
+use crate::runtime::Image;
+use pdfium_render::prelude::*;
+use std::path::Path;
+use std::sync::Mutex;
+
+/// Resolution pages are rendered at. PDF sizes are in points (1/72 inch).
+const RENDER_DPI: f32 = 144.0;
+
+fn is_pdf(path: &str) -> bool {
+    Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case("pdf"))
+}
+
+fn points_to_pixels(points: f32) -> u32 {
+    (points * RENDER_DPI / 72.0).round().max(1.0) as u32
+}
+
+/// Loads PDF documents through PDFium, one frame per page.
+///
+/// The PDFium shared library is looked up next to the executable first, then
+/// on the system library path. It is bound on first use; without it the loader
+/// simply declines every file.
+pub struct PdfLoader {
+    file_changes: FileChangeTracker,
+    /// PDFium is not thread safe, so every call goes through this lock.
+    pdfium: Mutex<Option<Pdfium>>,
+}
+
+impl PdfLoader {
+    pub fn new() -> Self {
+        Self {
+            file_changes: FileChangeTracker::new(),
+            pdfium: Mutex::new(None),
+        }
+    }
+
+    fn with_pdfium<T>(
+        &self,
+        f: impl FnOnce(&Pdfium) -> Result<T, LibraryError>,
+    ) -> Result<T, LibraryError> {
+        let mut guard = self.pdfium.lock().unwrap();
+        if guard.is_none() {
+            let bindings =
+                Pdfium::bind_to_library(Pdfium::pdfium_platform_library_name_at_path("./"))
+                    .or_else(|_| Pdfium::bind_to_system_library())
+                    .map_err(|e| {
+                        LibraryError::plugin(format!("PDFium library not available: {:?}", e))
+                    })?;
+            *guard = Some(Pdfium::new(bindings));
+        }
+        f(guard.as_ref().unwrap())
+    }
+
+    /// Render page `page` (zero-based, clamped to the last page) as RGBA.
+    pub fn render_page(&self, path: &str, page: usize) -> Result<Image, LibraryError> {
+        self.with_pdfium(|pdfium| {
+            let document = pdfium
+                .load_pdf_from_file(path, None)
+                .map_err(|e| LibraryError::plugin(format!("Failed to open PDF: {:?}", e)))?;
+            let pages = document.pages();
+            let count = pages.len() as usize;
+            if count == 0 {
+                return Err(LibraryError::plugin("PDF has no pages".to_string()));
+            }
+            let index = page.min(count - 1);
+            let page = pages
+                .get(index as PdfPageIndex)
+                .map_err(|e| LibraryError::plugin(format!("PDF page {}: {:?}", index, e)))?;
+
+            let width = points_to_pixels(page.width().value);
+            let height = points_to_pixels(page.height().value);
+            let config = PdfRenderConfig::new()
+                .set_target_width(width as Pixels)
+                .set_target_height(height as Pixels);
+            let bitmap = page.render_with_config(&config).map_err(|e| {
+                LibraryError::plugin(format!("Failed to render PDF page {}: {:?}", index, e))
+            })?;
+
+            Ok(Image {
+                width: bitmap.width() as u32,
+                height: bitmap.height() as u32,
+                data: bitmap.as_rgba_bytes(),
+            })
+        })
+    }
+}
+
+impl Plugin for PdfLoader {
+    fn id(&self) -> &'static str {
+        "pdf_loader"
+    }
+
+    fn name(&self) -> String {
+        "PDF Loader".to_string()
+    }
+
+    fn category(&self) -> String {
+        "Media".to_string()
+    }
+
+    fn version(&self) -> (u32, u32, u32) {
+        (0, 1, 0)
+    }
+}
+
+impl LoadPlugin for PdfLoader {
+    fn open(&self, path: &str) -> Result<Vec<crate::plugin::AssetMetadata>, LibraryError> {
+        if !self.probe_format(path) {
+            return Err(LibraryError::plugin("Unsupported file type".to_string()));
+        }
+
+        self.file_changes.check(path);
+
+        self.with_pdfium(|pdfium| {
+            let document = pdfium
+                .load_pdf_from_file(path, None)
+                .map_err(|e| LibraryError::plugin(format!("Failed to open PDF: {:?}", e)))?;
+            Ok(document
+                .pages()
+                .iter()
+                .enumerate()
+                .map(|(index, page)| crate::plugin::AssetMetadata {
+                    kind: AssetKind::Image,
+                    duration: None,
+                    fps: None,
+                    width: Some(points_to_pixels(page.width().value)),
+                    height: Some(points_to_pixels(page.height().value)),
+                    stream_index: Some(index),
+                    icc_profile: None,
+                    channel_layers: Vec::new(),
+                })
+                .collect())
+        })
+    }
+
+    fn load(
+        &self,
+        request: &LoadRequest,
+        cache: &CacheManager,
+    ) -> Result<LoadResponse, LibraryError> {
+        let path = request.path();
+        if !self.probe_format(path) {
+            return Err(LibraryError::plugin("Unsupported file type".to_string()));
+        }
+        self.file_changes.invalidate_if_stale(path, cache);
+
+        // Stills carry their page. Frames are selected by `stream_index`, falling
+        // back to `frame_number` so the document plays back one page per frame.
+        let page = match request {
+            LoadRequest::Image { page, .. } => page.unwrap_or(0) as u64,
+            LoadRequest::VideoFrame {
+                frame_number,
+                stream_index,
+                ..
+            } => stream_index.map_or(*frame_number, |page| page as u64),
+        };
+        let image = if let Some(img) = cache.get_video_frame(path, page) {
+            img
+        } else {
+            let img = self.render_page(path, page as usize)?;
+            cache.put_video_frame(path, page, &img);
+            img
+        };
+        Ok(LoadResponse { image })
+    }
+
+    fn probe_format(&self, path: &str) -> bool {
+        is_pdf(path)
+    }
+}
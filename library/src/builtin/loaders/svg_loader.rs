@@ -0,0 +1,162 @@
+use super::{FileChangeTracker, LoadPlugin, LoadRequest, LoadResponse};
+use crate::cache::CacheManager;
+use crate::error::LibraryError;
+use crate::plugin::Plugin;
+use crate::project::asset::AssetKind;
+use crate::runtime::Image;
+use resvg::{tiny_skia, usvg};
+use std::path::Path;
+use std::sync::{Arc, OnceLock};
+
+fn is_svg(path: &str) -> bool {
+    Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case("svg") || e.eq_ignore_ascii_case("svgz"))
+}
+
+/// Loads SVG files as images by rasterizing them with resvg.
+pub struct SvgLoader {
+    file_changes: FileChangeTracker,
+    /// System fonts for `<text>`, loaded on first use.
+    fontdb: OnceLock<Arc<usvg::fontdb::Database>>,
+}
+
+impl SvgLoader {
+    pub fn new() -> Self {
+        Self {
+            file_changes: FileChangeTracker::new(),
+            fontdb: OnceLock::new(),
+        }
+    }
+
+    fn parse(&self, path: &str) -> Result<usvg::Tree, LibraryError> {
+        let data = std::fs::read(path)?;
+        let fontdb = self.fontdb.get_or_init(|| {
+            let mut db = usvg::fontdb::Database::new();
+            db.load_system_fonts();
+            Arc::new(db)
+        });
+        let options = usvg::Options {
+            resources_dir: Path::new(path).parent().map(Path::to_path_buf),
+            fontdb: Arc::clone(fontdb),
+            ..usvg::Options::default()
+        };
+        usvg::Tree::from_data(&data, &options)
+            .map_err(|e| LibraryError::plugin(format!("Failed to parse SVG: {}", e)))
+    }
+
+    /// Rasterize `path` at `scale` times its intrinsic size, as straight RGBA.
+    pub fn rasterize(&self, path: &str, scale: f32) -> Result<Image, LibraryError> {
+        let tree = self.parse(path)?;
+        let size = tree
+            .size()
+            .to_int_size()
+            .scale_by(scale)
+            .ok_or_else(|| LibraryError::plugin("SVG has an empty size".to_string()))?;
+        let mut pixmap = tiny_skia::Pixmap::new(size.width(), size.height())
+            .ok_or_else(|| LibraryError::plugin("Failed to allocate SVG pixmap".to_string()))?;
+        resvg::render(
+            &tree,
+            tiny_skia::Transform::from_scale(scale, scale),
+            &mut pixmap.as_mut(),
+        );
+
+        // tiny-skia pixmaps are premultiplied; images are straight alpha
+        let data = pixmap
+            .pixels()
+            .iter()
+            .flat_map(|p| {
+                let c = p.demultiply();
+                [c.red(), c.green(), c.blue(), c.alpha()]
+            })
+            .collect();
+        Ok(Image {
+            width: size.width(),
+            height: size.height(),
+            data,
+        })
+    }
+}
+
+impl Plugin for SvgLoader {
+    fn id(&self) -> &'static str {
+        "native_svg_loader"
+    }
+
+    fn name(&self) -> String {
+        "SVG Loader".to_string()
+    }
+
+    fn category(&self) -> String {
+        "Media".to_string()
+    }
+
+    fn version(&self) -> (u32, u32, u32) {
+        (0, 1, 0)
+    }
+}
+
+impl LoadPlugin for SvgLoader {
+    fn open(&self, path: &str) -> Result<Vec<crate::plugin::AssetMetadata>, LibraryError> {
+        if !self.probe_format(path) {
+            return Err(LibraryError::plugin("Unsupported file type".to_string()));
+        }
+
+        self.file_changes.check(path);
+
+        let size = self.parse(path)?.size().to_int_size();
+        Ok(vec![crate::plugin::AssetMetadata {
+            kind: AssetKind::Image,
+            duration: None,
+            fps: None,
+            width: Some(size.width()),
+            height: Some(size.height()),
+            stream_index: None,
+            icc_profile: None,
+            channel_layers: Vec::new(),
+        }])
+    }
+
+    fn load(
+        &self,
+        request: &LoadRequest,
+        cache: &CacheManager,
+    ) -> Result<LoadResponse, LibraryError> {
+        let LoadRequest::Image { path, scale, .. } = request else {
+            return Err(LibraryError::plugin(
+                "SvgLoader only loads still images".to_string(),
+            ));
+        };
+        if !self.probe_format(path) {
+            return Err(LibraryError::plugin("Unsupported file type".to_string()));
+        }
+        self.file_changes.invalidate_if_stale(path, cache);
+
+        if !(scale.is_finite() && *scale > 0.0) {
+            return Err(LibraryError::plugin(format!(
+                "Invalid SVG rasterization scale: {}",
+                scale
+            )));
+        }
+
+        // Each scale is its own raster; the `path::` prefix keeps evict_path covering it
+        let cache_key = if *scale == 1.0 {
+            path.clone()
+        } else {
+            format!("{}::scale={}", path, scale)
+        };
+        let image = if let Some(img) = cache.get_image(&cache_key) {
+            img
+        } else {
+            let img = self.rasterize(path, *scale as f32)?;
+            cache.put_image(&cache_key, &img);
+            img
+        };
+        Ok(LoadResponse { image })
+    }
+
+    fn probe_format(&self, path: &str) -> bool {
+        is_svg(path)
+    }
+}
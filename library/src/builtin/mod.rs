@@ -12,19 +12,32 @@ pub mod styles;
 pub use decorators::DecoratorPlugin;
 pub use effectors::EffectorPlugin;
 pub use effects::{EffectDefinition, EffectPlugin, EffectRepository};
-pub use exporters::{ExportFormat, ExportPlugin, ExportRepository, ExportSettings};
+pub use exporters::{
+    AudioSource, ExportFormat, ExportPlugin, ExportRepository, ExportSettings, HwAccelBackend,
+};
 pub use loaders::{AssetMetadata, LoadPlugin, LoadRepository, LoadRequest, LoadResponse};
 pub use styles::StylePlugin;
 
 // Re-export concrete implementations
 pub use effects::blur::BlurEffectPlugin;
+pub use effects::chromatic_aberration::ChromaticAberrationEffectPlugin;
+pub use effects::color_curves::ColorCurvesEffectPlugin;
 pub use effects::dilate::DilateEffectPlugin;
 pub use effects::drop_shadow::DropShadowEffectPlugin;
 pub use effects::erode::ErodeEffectPlugin;
+pub use effects::glow::GlowEffectPlugin;
+pub use effects::lut::LutEffectPlugin;
 pub use effects::magnifier::MagnifierEffectPlugin;
 pub use effects::tile::TileEffectPlugin;
+pub use effects::time_echo::TimeEchoEffectPlugin;
+pub use effects::vignette::VignetteEffectPlugin;
 pub use exporters::ffmpeg_export::FfmpegExportPlugin;
+pub use exporters::gif_export::GifExportPlugin;
 pub use exporters::png_export::PngExportPlugin;
+pub use exporters::webp_export::WebpExportPlugin;
+pub use loaders::exr_loader::ExrLoader;
 pub use loaders::ffmpeg_video::FfmpegVideoLoader;
 pub use loaders::native_image::NativeImageLoader;
+pub use loaders::pdf_loader::PdfLoader;
+pub use loaders::svg_loader::SvgLoader;
 pub use properties::{ConstantPropertyPlugin, ExpressionPropertyPlugin, KeyframePropertyPlugin};
@@ -129,8 +129,8 @@ fn evaluate_keyframes(property: &Property, time: f64) -> PropertyValue {
         .and_then(|v| v.get_as::<String>())
         .unwrap_or_else(|| "linear".to_string());
 
-    let interpolated =
-        interpolate_property_values(&current.value, &next.value, t, &current.easing, &mode);
+    let t = segment_easing(current.ease_out.as_ref(), next.ease_in.as_ref(), t);
+    let interpolated = interpolate_property_values(&current.value, &next.value, t, &mode);
     // debug!(
     //     "evaluate_keyframes: interpolated value {:?} for time {}",
     //     interpolated, time
@@ -138,19 +138,48 @@ fn evaluate_keyframes(property: &Property, time: f64) -> PropertyValue {
     interpolated
 }
 
+/// Eased progress through the segment between two keyframes.
+///
+/// With both sides set, the first half of the segment follows the left
+/// keyframe's `ease_out` and the second half the right keyframe's `ease_in`;
+/// with only one set it spans the whole segment. A `Constant` side holds the
+/// left value for the whole segment.
+fn segment_easing(
+    ease_out: Option<&EasingFunction>,
+    ease_in: Option<&EasingFunction>,
+    t: f64,
+) -> f64 {
+    match (ease_out, ease_in) {
+        (Some(EasingFunction::Constant), _) | (_, Some(EasingFunction::Constant)) => {
+            EasingFunction::Constant.apply(t)
+        }
+        (None, None) => t,
+        (Some(easing), None) | (None, Some(easing)) => easing.apply(t),
+        (Some(ease_out), Some(ease_in)) => {
+            if t < 0.5 {
+                0.5 * ease_out.apply(t * 2.0)
+            } else {
+                0.5 + 0.5 * ease_in.apply(t * 2.0 - 1.0)
+            }
+        }
+    }
+}
+
+/// Interpolate between two values at an already eased `t`.
 fn interpolate_property_values(
     start: &PropertyValue,
     end: &PropertyValue,
     t: f64,
-    easing: &EasingFunction,
     mode: &str,
 ) -> PropertyValue {
-    let t = easing.apply(t);
-
     match (start, end) {
         (PropertyValue::Number(s), PropertyValue::Number(e)) => {
             PropertyValue::Number(OrderedFloat(s.0 + (e.0 - s.0) * t))
         }
+        // Integer properties (frame counts etc.) stay whole, rounded to nearest
+        (PropertyValue::Integer(s), PropertyValue::Integer(e)) => {
+            PropertyValue::Integer((*s as f64 + (*e - *s) as f64 * t).round() as i64)
+        }
         (
             PropertyValue::Vec2(Vec2 { x: sx, y: sy }),
             PropertyValue::Vec2(Vec2 { x: ex, y: ey }),
@@ -203,18 +232,13 @@ fn interpolate_property_values(
         (PropertyValue::Array(s), PropertyValue::Array(e)) => PropertyValue::Array(
             s.iter()
                 .zip(e.iter())
-                .map(|(start, end)| interpolate_property_values(start, end, t, easing, mode))
+                .map(|(start, end)| interpolate_property_values(start, end, t, mode))
                 .collect(),
         ),
         (PropertyValue::Map(s), PropertyValue::Map(e)) => PropertyValue::Map(
             s.iter()
                 .zip(e.iter())
-                .map(|((k, sv), (_, ev))| {
-                    (
-                        k.clone(),
-                        interpolate_property_values(sv, ev, t, easing, mode),
-                    )
-                })
+                .map(|((k, sv), (_, ev))| (k.clone(), interpolate_property_values(sv, ev, t, mode)))
                 .collect(),
         ),
         _ => start.clone(),
@@ -1,11 +1,10 @@
 use crate::SkiaRenderer;
 use crate::error::LibraryError;
-use crate::plugin::{ExportSettings, PluginManager};
+use crate::plugin::{AudioSource, ExportSettings, PluginManager};
 use crate::service::export_service::ExportService;
 use crate::service::project_model::ProjectModel;
 use log::info;
 use std::fs;
-use std::io::Write;
 use std::ops::Range;
 use std::sync::Arc;
 
@@ -85,12 +84,12 @@ pub fn run(args: Vec<String>) -> Result<(), LibraryError> {
         let duration_frames = (final_frame_range.end - final_frame_range.start).max(1);
         let duration = duration_frames as f64 / fps;
 
-        let sample_rate = 48000;
+        let sample_rate = composition.audio_sample_rate;
 
         let start_sample = (start_time * sample_rate as f64).round() as u64;
         let frames = (duration * sample_rate as f64).round() as usize;
 
-        let audio_data = crate::audio::mixer::mix_samples(
+        let audio = crate::audio::mixer::mix_samples_to_stereo_float(
             &project_model.project().assets,
             project_model.project().as_ref(),
             project_model.composition(),
@@ -98,29 +97,17 @@ pub fn run(args: Vec<String>) -> Result<(), LibraryError> {
             start_sample,
             frames,
             sample_rate,
-            2,
         );
 
-        if !audio_data.is_empty() {
+        if !audio.is_empty() {
             let audio_path = format!("{}_audio.raw", output_stem);
-            if let Ok(mut file) = std::fs::File::create(&audio_path) {
-                for sample in audio_data {
-                    let _ = file.write_all(&sample.to_le_bytes());
-                }
-
+            if audio.write_f32le(&audio_path).is_ok() {
                 if let Some(settings_mut) = Arc::get_mut(&mut export_settings) {
-                    settings_mut.parameters.insert(
-                        "audio_source".to_string(),
-                        serde_json::Value::String(audio_path.clone()),
-                    );
-                    settings_mut.parameters.insert(
-                        "audio_channels".to_string(),
-                        serde_json::Value::Number(serde_json::Number::from(2)),
-                    );
-                    settings_mut.parameters.insert(
-                        "audio_sample_rate".to_string(),
-                        serde_json::Value::Number(serde_json::Number::from(sample_rate)),
-                    );
+                    settings_mut.set_audio_source(&AudioSource {
+                        path: audio_path.clone(),
+                        sample_rate: audio.sample_rate,
+                        channels: audio.channels,
+                    });
                 }
                 audio_temp_path = Some(audio_path);
             }
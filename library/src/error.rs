@@ -59,6 +59,8 @@ pub enum LibraryError {
     InvalidArgument(String),
     #[error("Runtime error: {0}")]
     Runtime(String),
+    #[error("Evaluation cancelled")]
+    Cancelled,
 }
 
 /// Convenience constructors to minimize call-site changes
@@ -22,6 +22,7 @@ pub use project::animation;
 pub use rendering::cache;
 
 // Commonly used types
+pub use crate::pipeline::ensemble::TextDecomposer;
 pub use crate::plugin::ExportSettings;
 pub use crate::runtime::Image;
 
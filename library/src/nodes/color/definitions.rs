@@ -1,6 +1,10 @@
-use super::{image_filter, inp};
+use crate::nodes::{image_filter, inp};
 use crate::plugin::node_types::{NodeCategory, NodeTypeDefinition};
 use crate::project::connection::PinDataType;
+use crate::project::property::{
+    PropertyDefinition, PropertyUiType, PropertyValue, Vec3 as PropVec3,
+};
+use ordered_float::OrderedFloat;
 
 pub(super) fn color_nodes() -> Vec<NodeTypeDefinition> {
     use PinDataType::*;
@@ -18,6 +22,36 @@ pub(super) fn color_nodes() -> Vec<NodeTypeDefinition> {
             ],
         )
         .with_description("Lift, Gamma, Gain 3-way color correction"),
+        image_filter(
+            "color.grading",
+            "Color Grading",
+            nc,
+            vec![
+                inp("lift", "Lift", Vec3),
+                inp("gamma", "Gamma", Vec3),
+                inp("gain", "Gain", Vec3),
+                inp("saturation", "Saturation", Scalar),
+            ],
+        )
+        .with_description("Primary grade: per-channel lift, gamma, gain and saturation")
+        .with_properties(vec![
+            rgb_property("lift", "Lift", 0.0),
+            rgb_property("gamma", "Gamma", 1.0),
+            rgb_property("gain", "Gain", 1.0),
+            PropertyDefinition::new(
+                "saturation",
+                PropertyUiType::Float {
+                    min: 0.0,
+                    max: 2.0,
+                    step: 0.01,
+                    suffix: "".into(),
+                    min_hard_limit: true,
+                    max_hard_limit: false,
+                },
+                "Saturation",
+                PropertyValue::from(1.0),
+            ),
+        ]),
         image_filter(
             "color.brightness_contrast",
             "Brightness Contrast",
@@ -68,3 +102,17 @@ pub(super) fn color_nodes() -> Vec<NodeTypeDefinition> {
         .with_description("Map luminance to a color gradient"),
     ]
 }
+
+/// An RGB triple property with the same value on every channel.
+fn rgb_property(name: &str, label: &str, value: f64) -> PropertyDefinition {
+    PropertyDefinition::new(
+        name,
+        PropertyUiType::Vec3 { suffix: "".into() },
+        label,
+        PropertyValue::Vec3(PropVec3 {
+            x: OrderedFloat(value),
+            y: OrderedFloat(value),
+            z: OrderedFloat(value),
+        }),
+    )
+}
@@ -0,0 +1,218 @@
+//! Evaluator for the primary color grading node (color.grading).
+
+use uuid::Uuid;
+
+use crate::error::LibraryError;
+use crate::pipeline::context::EvalContext;
+use crate::pipeline::evaluator::NodeEvaluator;
+use crate::pipeline::output::PinValue;
+use crate::project::node::Node;
+use crate::rendering::renderer::{RenderOutput, Renderer};
+use crate::runtime::Image;
+
+/// Smallest gamma accepted, so the `1 / gamma` exponent stays finite.
+const MIN_GAMMA: f64 = 0.01;
+
+/// Resolved lift/gamma/gain/saturation values for one evaluation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GradingParams {
+    pub lift: [f64; 3],
+    pub gamma: [f64; 3],
+    pub gain: [f64; 3],
+    pub saturation: f64,
+}
+
+impl Default for GradingParams {
+    fn default() -> Self {
+        Self {
+            lift: [0.0; 3],
+            gamma: [1.0; 3],
+            gain: [1.0; 3],
+            saturation: 1.0,
+        }
+    }
+}
+
+impl GradingParams {
+    pub fn is_identity(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Grade one straight-alpha RGB triple in 0..1.
+    ///
+    /// Applies `(input * gain + lift) ^ (1 / gamma)` per channel, then scales
+    /// saturation in HSL space.
+    pub fn apply(&self, rgb: [f64; 3]) -> [f64; 3] {
+        let mut out = [0, 1, 2].map(|c| {
+            let v = (rgb[c] * self.gain[c] + self.lift[c]).max(0.0);
+            v.powf(1.0 / self.gamma[c].max(MIN_GAMMA)).clamp(0.0, 1.0)
+        });
+        if self.saturation != 1.0 {
+            let (h, s, l) = rgb_to_hsl(out);
+            out = hsl_to_rgb(h, (s * self.saturation.max(0.0)).clamp(0.0, 1.0), l);
+        }
+        out
+    }
+
+    /// Grade every pixel of a premultiplied RGBA image in place.
+    pub fn apply_to_image(&self, image: &mut Image) {
+        for px in image.data.chunks_exact_mut(4) {
+            let alpha = px[3] as f64 / 255.0;
+            if alpha <= 0.0 {
+                continue;
+            }
+            let rgb = [0, 1, 2].map(|c| (px[c] as f64 / 255.0 / alpha).min(1.0));
+            let graded = self.apply(rgb);
+            for (channel, value) in px.iter_mut().zip(graded) {
+                *channel = (value * alpha * 255.0).round() as u8;
+            }
+        }
+    }
+}
+
+fn rgb_to_hsl([r, g, b]: [f64; 3]) -> (f64, f64, f64) {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (max + min) / 2.0;
+    let d = max - min;
+    if d <= f64::EPSILON {
+        return (0.0, 0.0, l);
+    }
+    let s = d / (1.0 - (2.0 * l - 1.0).abs());
+    let h = if max == r {
+        ((g - b) / d).rem_euclid(6.0)
+    } else if max == g {
+        (b - r) / d + 2.0
+    } else {
+        (r - g) / d + 4.0
+    };
+    (h / 6.0, s, l)
+}
+
+fn hsl_to_rgb(h: f64, s: f64, l: f64) -> [f64; 3] {
+    let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let h6 = h * 6.0;
+    let x = c * (1.0 - (h6.rem_euclid(2.0) - 1.0).abs());
+    let (r, g, b) = match h6 as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let m = l - c / 2.0;
+    [r + m, g + m, b + m]
+}
+
+pub struct ColorGradingEvaluator;
+
+impl ColorGradingEvaluator {
+    /// Read a Vec3 parameter from its input pin, falling back to the node property.
+    fn resolve_rgb(
+        ctx: &mut EvalContext,
+        node_id: Uuid,
+        node: &crate::project::graph_node::GraphNode,
+        key: &str,
+        default: f64,
+    ) -> Result<[f64; 3], LibraryError> {
+        let fallback = ctx.resolve_vec3(&node.properties, key, (default, default, default));
+        let (x, y, z) = ctx.pull_input_value(node_id, key)?.as_vec3(fallback);
+        Ok([x, y, z])
+    }
+}
+
+impl NodeEvaluator for ColorGradingEvaluator {
+    fn handles(&self) -> &[&str] {
+        &["color.grading"]
+    }
+
+    fn evaluate(
+        &self,
+        node_id: Uuid,
+        pin_name: &str,
+        ctx: &mut EvalContext,
+    ) -> Result<PinValue, LibraryError> {
+        if pin_name != "image" {
+            return Ok(PinValue::None);
+        }
+
+        let input_image = match ctx.pull_input_value(node_id, "image")?.into_image() {
+            Some(img) => img,
+            None => return Ok(PinValue::None),
+        };
+        let graph_node = match ctx.project.get_node(node_id) {
+            Some(Node::Graph(gn)) => gn.clone(),
+            _ => return Ok(PinValue::Image(input_image)),
+        };
+
+        let saturation = ctx.resolve_number(&graph_node.properties, "saturation", 1.0);
+        let params = GradingParams {
+            lift: Self::resolve_rgb(ctx, node_id, &graph_node, "lift", 0.0)?,
+            gamma: Self::resolve_rgb(ctx, node_id, &graph_node, "gamma", 1.0)?,
+            gain: Self::resolve_rgb(ctx, node_id, &graph_node, "gain", 1.0)?,
+            saturation: ctx
+                .pull_input_value(node_id, "saturation")?
+                .as_scalar(saturation),
+        };
+        if params.is_identity() {
+            return Ok(PinValue::Image(input_image));
+        }
+
+        let mut image = ctx.renderer.read_surface(&input_image)?;
+        params.apply_to_image(&mut image);
+        Ok(PinValue::Image(RenderOutput::Image(image)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identity_params_leave_color_unchanged() {
+        let params = GradingParams::default();
+        let graded = params.apply([0.2, 0.5, 0.8]);
+        for (a, b) in graded.iter().zip([0.2, 0.5, 0.8]) {
+            assert!((a - b).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_lift_gamma_gain_formula() {
+        let params = GradingParams {
+            lift: [0.1, 0.0, 0.0],
+            gamma: [1.0, 2.0, 1.0],
+            gain: [1.0, 1.0, 0.5],
+            saturation: 1.0,
+        };
+        let graded = params.apply([0.5, 0.25, 0.5]);
+        assert!((graded[0] - 0.6).abs() < 1e-9);
+        assert!((graded[1] - 0.5).abs() < 1e-9);
+        assert!((graded[2] - 0.25).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_zero_saturation_produces_gray() {
+        let params = GradingParams {
+            saturation: 0.0,
+            ..Default::default()
+        };
+        let graded = params.apply([0.9, 0.3, 0.1]);
+        assert!((graded[0] - graded[1]).abs() < 1e-9);
+        assert!((graded[1] - graded[2]).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_apply_to_image_keeps_alpha_and_skips_transparent() {
+        let mut image = Image::new(2, 1, vec![128, 64, 0, 128, 10, 20, 30, 0]);
+        let params = GradingParams {
+            gain: [0.5; 3],
+            ..Default::default()
+        };
+        params.apply_to_image(&mut image);
+        assert_eq!(image.data[3], 128);
+        assert_eq!(&image.data[0..3], &[64, 32, 0]);
+        assert_eq!(&image.data[4..8], &[10, 20, 30, 0]);
+    }
+}
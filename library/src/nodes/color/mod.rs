@@ -0,0 +1,13 @@
+//! Color nodes — color correction, grading, and color mapping.
+
+pub mod definitions;
+pub mod grading;
+
+pub use grading::ColorGradingEvaluator;
+
+use crate::plugin::node_types::NodeTypeDefinition;
+
+/// All color node definitions.
+pub fn definitions() -> Vec<NodeTypeDefinition> {
+    definitions::color_nodes()
+}
@@ -18,6 +18,16 @@ impl NodeEvaluator for BlendEvaluator {
             "compositing.multiply_blend",
             "compositing.screen_blend",
             "compositing.overlay_blend",
+            "compositing.soft_light_blend",
+            "compositing.hard_light_blend",
+            "compositing.color_dodge_blend",
+            "compositing.color_burn_blend",
+            "compositing.difference_blend",
+            "compositing.exclusion_blend",
+            "compositing.hue_blend",
+            "compositing.saturation_blend",
+            "compositing.color_blend",
+            "compositing.luminosity_blend",
         ]
     }
 
@@ -58,13 +68,7 @@ impl NodeEvaluator for BlendEvaluator {
 
         let opacity = ctx.resolve_number(&graph_node.properties, "opacity", 100.0) / 100.0;
 
-        // Determine blend mode from type_id
-        let blend_mode = match graph_node.type_id.as_str() {
-            "compositing.multiply_blend" => BlendMode::Multiply,
-            "compositing.screen_blend" => BlendMode::Screen,
-            "compositing.overlay_blend" => BlendMode::Overlay,
-            _ => BlendMode::Normal,
-        };
+        let blend_mode = blend_mode_for_type(&graph_node.type_id);
 
         let output = ctx
             .renderer
@@ -73,6 +77,26 @@ impl NodeEvaluator for BlendEvaluator {
     }
 }
 
+/// Blend mode applied by a `compositing.*_blend` node type.
+fn blend_mode_for_type(type_id: &str) -> BlendMode {
+    match type_id {
+        "compositing.multiply_blend" => BlendMode::Multiply,
+        "compositing.screen_blend" => BlendMode::Screen,
+        "compositing.overlay_blend" => BlendMode::Overlay,
+        "compositing.soft_light_blend" => BlendMode::SoftLight,
+        "compositing.hard_light_blend" => BlendMode::HardLight,
+        "compositing.color_dodge_blend" => BlendMode::ColorDodge,
+        "compositing.color_burn_blend" => BlendMode::ColorBurn,
+        "compositing.difference_blend" => BlendMode::Difference,
+        "compositing.exclusion_blend" => BlendMode::Exclusion,
+        "compositing.hue_blend" => BlendMode::Hue,
+        "compositing.saturation_blend" => BlendMode::Saturation,
+        "compositing.color_blend" => BlendMode::Color,
+        "compositing.luminosity_blend" => BlendMode::Luminosity,
+        _ => BlendMode::Normal,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -86,5 +110,35 @@ mod tests {
         assert!(handles.contains(&"compositing.multiply_blend"));
         assert!(handles.contains(&"compositing.screen_blend"));
         assert!(handles.contains(&"compositing.overlay_blend"));
+        assert!(handles.contains(&"compositing.luminosity_blend"));
+    }
+
+    #[test]
+    fn test_every_blend_type_maps_to_its_own_mode() {
+        let evaluator = BlendEvaluator;
+        let modes: std::collections::HashSet<_> = evaluator
+            .handles()
+            .iter()
+            .map(|&type_id| blend_mode_for_type(type_id))
+            .collect();
+        assert_eq!(modes.len(), evaluator.handles().len());
+        assert_eq!(
+            blend_mode_for_type("compositing.color_dodge_blend"),
+            BlendMode::ColorDodge
+        );
+    }
+
+    #[test]
+    fn test_every_blend_definition_has_an_evaluator() {
+        let evaluator = BlendEvaluator;
+        for def in crate::nodes::compositing::definitions() {
+            if def.type_id.ends_with("_blend") {
+                assert!(
+                    evaluator.handles().contains(&def.type_id.as_str()),
+                    "No evaluator for {}",
+                    def.type_id
+                );
+            }
+        }
     }
 }
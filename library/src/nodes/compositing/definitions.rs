@@ -94,6 +94,16 @@ pub(super) fn compositing_nodes() -> Vec<NodeTypeDefinition> {
         blend_node("compositing.multiply_blend", "Multiply Blend"),
         blend_node("compositing.screen_blend", "Screen Blend"),
         blend_node("compositing.overlay_blend", "Overlay Blend"),
+        blend_node("compositing.soft_light_blend", "Soft Light Blend"),
+        blend_node("compositing.hard_light_blend", "Hard Light Blend"),
+        blend_node("compositing.color_dodge_blend", "Color Dodge Blend"),
+        blend_node("compositing.color_burn_blend", "Color Burn Blend"),
+        blend_node("compositing.difference_blend", "Difference Blend"),
+        blend_node("compositing.exclusion_blend", "Exclusion Blend"),
+        blend_node("compositing.hue_blend", "Hue Blend"),
+        blend_node("compositing.saturation_blend", "Saturation Blend"),
+        blend_node("compositing.color_blend", "Color Blend"),
+        blend_node("compositing.luminosity_blend", "Luminosity Blend"),
         node("compositing.mask", "Mask", nc)
             .with_inputs(vec![
                 inp("source", "Source", Image),
@@ -7,6 +7,7 @@ use crate::pipeline::context::EvalContext;
 use crate::pipeline::evaluator::NodeEvaluator;
 use crate::pipeline::output::PinValue;
 use crate::project::node::Node;
+use crate::project::property::PropertyValue;
 use crate::rendering::renderer::Renderer;
 use crate::runtime::transform::{Position, Scale, Transform};
 
@@ -40,11 +41,24 @@ impl NodeEvaluator for TransformEvaluator {
             _ => return Ok(PinValue::Image(input_image)),
         };
 
-        let (px, py) = ctx.resolve_vec2(&graph_node.properties, "position", 0.0, 0.0);
-        let (ax, ay) = ctx.resolve_vec2(&graph_node.properties, "anchor", 0.0, 0.0);
-        let (sx, sy) = ctx.resolve_vec2(&graph_node.properties, "scale", 100.0, 100.0);
-        let rotation = ctx.resolve_number(&graph_node.properties, "rotation", 0.0);
-        let opacity = ctx.resolve_number(&graph_node.properties, "opacity", 100.0);
+        let values = ctx.resolve_property_values(
+            &graph_node.properties,
+            &["position", "anchor", "scale", "rotation", "opacity"],
+        );
+        let vec2 = |key: &str, default: (f64, f64)| match values.get(key) {
+            Some(PropertyValue::Vec2(v)) => (v.x.into_inner(), v.y.into_inner()),
+            _ => default,
+        };
+        let number = |key: &str, default: f64| match values.get(key) {
+            Some(PropertyValue::Number(n)) => n.into_inner(),
+            _ => default,
+        };
+
+        let (px, py) = vec2("position", (0.0, 0.0));
+        let (ax, ay) = vec2("anchor", (0.0, 0.0));
+        let (sx, sy) = vec2("scale", (100.0, 100.0));
+        let rotation = number("rotation", 0.0);
+        let opacity = number("opacity", 100.0);
 
         // Apply region offset and render_scale to map composition-space
         // coordinates to the (potentially smaller/offset) renderer surface.
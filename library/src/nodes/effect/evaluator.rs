@@ -9,7 +9,7 @@ use crate::pipeline::context::EvalContext;
 use crate::pipeline::evaluator::NodeEvaluator;
 use crate::pipeline::output::PinValue;
 use crate::project::node::Node;
-use crate::project::property::PropertyValue;
+use crate::project::property::{PropertyUiType, PropertyValue};
 use crate::rendering::renderer::Renderer;
 
 pub struct EffectEvaluator;
@@ -49,18 +49,43 @@ impl NodeEvaluator for EffectEvaluator {
             .unwrap_or(&graph_node.type_id);
 
         // Evaluate all properties
-        let mut params: HashMap<String, PropertyValue> = HashMap::new();
-        for (key, _prop) in graph_node.properties.iter() {
-            let value =
-                ctx.resolve_property_value(&graph_node.properties, key, PropertyValue::from(0.0));
-            params.insert(key.to_string(), value);
+        let keys: Vec<&str> = graph_node
+            .properties
+            .iter()
+            .map(|(key, _)| key.as_str())
+            .collect();
+        let mut params: HashMap<String, PropertyValue> =
+            ctx.resolve_property_values(&graph_node.properties, &keys);
+
+        // File pickers may hold paths relative to the project file
+        if ctx.project.file_dir.is_some() {
+            for def in ctx.plugin_manager.get_effect_properties(effect_name) {
+                if !matches!(def.ui_type(), PropertyUiType::FilePicker { .. }) {
+                    continue;
+                }
+                if let Some(PropertyValue::String(path)) = params.get_mut(def.name()) {
+                    *path = ctx.project.resolve_file_path(path);
+                }
+            }
         }
 
-        // Inject u_time
+        // Inject timing and the keys plugins keeping per-instance state need
         params.insert(
             "u_time".to_string(),
             PropertyValue::Number(ordered_float::OrderedFloat(ctx.time)),
         );
+        params.insert(
+            "u_frame".to_string(),
+            PropertyValue::Integer(ctx.frame_number as i64),
+        );
+        params.insert(
+            "u_entity_id".to_string(),
+            PropertyValue::String(node_id.to_string()),
+        );
+        params.insert(
+            "u_render_context".to_string(),
+            PropertyValue::Integer(ctx.render_context_id() as i64),
+        );
 
         // Apply the effect via plugin manager
         let gpu_context = ctx.renderer.get_gpu_context();
@@ -8,6 +8,22 @@ pub(super) fn effector_nodes() -> Vec<NodeTypeDefinition> {
     let nc = NodeCategory::Effector;
 
     let prop = PropertyDefinition::new;
+    // Blend factor applied to each effector's transform delta
+    let weight = || {
+        prop(
+            "weight",
+            PropertyUiType::Float {
+                min: 0.0,
+                max: 1.0,
+                step: 0.01,
+                suffix: "".into(),
+                min_hard_limit: true,
+                max_hard_limit: true,
+            },
+            "Weight",
+            PropertyValue::from(1.0),
+        )
+    };
     vec![
         node("effector.transform", "Transform Effector", nc)
             .with_description("Per-character transform (translate, rotate, scale)")
@@ -79,6 +95,7 @@ pub(super) fn effector_nodes() -> Vec<NodeTypeDefinition> {
                     "Rotation",
                     PropertyValue::from(0.0),
                 ),
+                weight(),
             ]),
         node("effector.step_delay", "Step Delay", nc)
             .with_description("Staggered reveal per character")
@@ -137,6 +154,7 @@ pub(super) fn effector_nodes() -> Vec<NodeTypeDefinition> {
                     "To Opacity",
                     PropertyValue::from(100.0),
                 ),
+                weight(),
             ]),
         node("effector.randomize", "Randomize", nc)
             .with_description("Random per-character transform jitter")
@@ -208,6 +226,7 @@ pub(super) fn effector_nodes() -> Vec<NodeTypeDefinition> {
                     "Scale Range",
                     PropertyValue::from(0.5),
                 ),
+                weight(),
             ]),
         node("effector.opacity", "Opacity Effector", nc)
             .with_description("Per-character opacity control")
@@ -235,6 +254,7 @@ pub(super) fn effector_nodes() -> Vec<NodeTypeDefinition> {
                     "Mode",
                     PropertyValue::String("Set".into()),
                 ),
+                weight(),
             ]),
     ]
 }
@@ -2,12 +2,14 @@
 //!
 //! Effectors operate on `ShapeData::Grouped` via `shape_in`/`shape_out` pins,
 //! modifying per-element transforms (translate, rotate, scale, opacity).
+//! Each effector's delta is scaled by its `weight` property (0–1).
 
 use uuid::Uuid;
 
 use crate::error::LibraryError;
 use crate::pipeline::context::EvalContext;
 use crate::pipeline::ensemble::effectors::OpacityMode;
+use crate::pipeline::ensemble::types::TransformData;
 use crate::pipeline::evaluator::NodeEvaluator;
 use crate::pipeline::output::{PinValue, ShapeData};
 use crate::project::node::Node;
@@ -58,6 +60,16 @@ impl NodeEvaluator for EffectorEvaluator {
 
                 let current_time = ctx.time as f32;
 
+                // Snapshot pre-effector transforms so the delta can be weighted
+                let weight = ctx
+                    .resolve_number(&graph_node.properties, "weight", 1.0)
+                    .clamp(0.0, 1.0) as f32;
+                let before: Vec<TransformData> = if weight < 1.0 {
+                    groups.iter().map(|g| g.transform.clone()).collect()
+                } else {
+                    Vec::new()
+                };
+
                 match graph_node.type_id.as_str() {
                     "effector.transform" => {
                         let tx = ctx.resolve_number(&graph_node.properties, "tx", 0.0) as f32;
@@ -147,6 +159,12 @@ impl NodeEvaluator for EffectorEvaluator {
                     _ => {}
                 }
 
+                if weight < 1.0 {
+                    for (group, prev) in groups.iter_mut().zip(&before) {
+                        group.transform = prev.lerp(&group.transform, weight);
+                    }
+                }
+
                 return Ok(PinValue::Shape(ShapeData::Grouped {
                     groups,
                     bounds,
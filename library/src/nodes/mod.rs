@@ -3,6 +3,7 @@
 //! Each node category lives in its own submodule containing both the
 //! `NodeTypeDefinition` declarations and the `NodeEvaluator` implementation.
 
+pub mod color;
 pub mod compositing;
 pub mod decorator;
 pub mod effect;
@@ -11,7 +12,6 @@ pub mod source;
 pub mod style;
 
 // Definition-only modules (no evaluator yet)
-mod color;
 mod data;
 mod generators;
 mod image_defs;
@@ -106,7 +106,7 @@ pub fn all_definitions() -> Vec<NodeTypeDefinition> {
         text_defs::text_nodes(),
         math::math_nodes(),
         logic::logic_nodes(),
-        color::color_nodes(),
+        color::definitions(),
         generators::generator_nodes(),
         path::path_nodes(),
         time::time_nodes(),
@@ -128,6 +128,7 @@ pub fn all_evaluators() -> Vec<Box<dyn NodeEvaluator>> {
         Box::new(style::StyleEvaluator),
         Box::new(effector::EffectorEvaluator),
         Box::new(decorator::DecoratorEvaluator),
+        Box::new(color::ColorGradingEvaluator),
     ]
 }
 
@@ -205,6 +206,6 @@ mod tests {
     #[test]
     fn test_all_evaluators_registered() {
         let evaluators = all_evaluators();
-        assert_eq!(evaluators.len(), 8);
+        assert_eq!(evaluators.len(), 9);
     }
 }
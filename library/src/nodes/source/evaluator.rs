@@ -1,4 +1,4 @@
-//! Evaluator for Source nodes (Video, Image, Text, Shape, SkSL).
+//! Evaluator for Source nodes (Video, Image, Text, Shape, SkSL, Gradient).
 
 use uuid::Uuid;
 
@@ -10,7 +10,9 @@ use crate::pipeline::evaluator::NodeEvaluator;
 use crate::pipeline::output::{PinValue, ShapeData};
 use crate::project::node::Node;
 use crate::project::source::SourceKind;
+use crate::project::vector::{PathBooleanOp, boolean_op_svg};
 use crate::rendering::renderer::{RenderOutput, Renderer};
+use crate::runtime::gradient::{GradientStop, GradientType};
 use crate::runtime::transform::Transform;
 
 pub struct SourceEvaluator;
@@ -43,12 +45,15 @@ impl NodeEvaluator for SourceEvaluator {
             // Text/Shape sources produce shape data (deferred rasterization)
             (SourceKind::Text, "shape_out") => self.text_shape(&source, ctx),
             (SourceKind::Shape, "shape_out") => self.path_shape(&source, ctx),
-            // Video/Image/SkSL sources produce images directly
-            (SourceKind::Image, "image_out") => self.evaluate_image(&source.properties, ctx),
+            // Video/Image/SkSL/Gradient sources produce images directly
+            (SourceKind::Image, "image_out") => self.evaluate_image(&source, ctx),
             (SourceKind::Video, "image_out") => self.evaluate_video(&source, ctx),
             (SourceKind::SkSL, "image_out") => {
                 self.evaluate_sksl(&source.properties, eval_time, &identity, ctx)
             }
+            (SourceKind::Gradient, "image_out") => {
+                self.evaluate_gradient(&source.properties, &identity, ctx)
+            }
             _ => Ok(PinValue::None),
         }
     }
@@ -78,27 +83,74 @@ impl SourceEvaluator {
         source: &crate::project::source::SourceData,
         ctx: &mut EvalContext,
     ) -> Result<PinValue, LibraryError> {
-        let path = ctx.resolve_string(&source.properties, "path", "");
+        let mut path = ctx.resolve_string(&source.properties, "path", "");
         if path.is_empty() {
             return Ok(PinValue::None);
         }
+
+        let op_name = ctx.resolve_string(&source.properties, "boolean_op", "None");
+        if let Some(op) = PathBooleanOp::from_name(&op_name) {
+            if let Some(operand) = self.boolean_op_operand(source, ctx) {
+                match boolean_op_svg(&path, &operand, op) {
+                    Some(combined) => path = combined,
+                    None => log::warn!("Boolean {:?} failed for shape {}", op, source.id),
+                }
+            }
+        }
+
         Ok(PinValue::Shape(ShapeData::Path {
             path_data: path,
             path_effects: vec![],
         }))
     }
 
+    /// Path data of the shape source referenced by `boolean_op_source_id`.
+    fn boolean_op_operand(
+        &self,
+        source: &crate::project::source::SourceData,
+        ctx: &EvalContext,
+    ) -> Option<String> {
+        let id_str = ctx.resolve_string(&source.properties, "boolean_op_source_id", "");
+        let other_id = Uuid::parse_str(id_str.trim()).ok()?;
+        if other_id == source.id {
+            return None;
+        }
+        match ctx.project.get_node(other_id) {
+            Some(Node::Source(other)) if other.kind == SourceKind::Shape => {
+                let other_path = ctx.resolve_string(&other.properties, "path", "");
+                (!other_path.is_empty()).then_some(other_path)
+            }
+            _ => None,
+        }
+    }
+
     fn evaluate_image(
         &self,
-        properties: &crate::project::property::PropertyMap,
+        source: &crate::project::source::SourceData,
         ctx: &mut EvalContext,
     ) -> Result<PinValue, LibraryError> {
+        let properties = &source.properties;
         let file_path = ctx.resolve_string(properties, "file_path", "");
         if file_path.is_empty() {
             return Ok(PinValue::None);
         }
 
-        let request = LoadRequest::Image { path: file_path };
+        let channel_layer = ctx.resolve_string(properties, "channel_layer", "");
+        let request = LoadRequest::Image {
+            path: file_path,
+            channel_layer: (!channel_layer.is_empty()).then_some(channel_layer),
+            exposure_offset: ctx.resolve_number(properties, "exposure_offset", 0.0),
+            // Image sources are laid out in composition pixels
+            scale: 1.0,
+            // Each page of a document is imported as its own asset
+            page: source.reference_id.and_then(|asset_id| {
+                ctx.project
+                    .assets
+                    .iter()
+                    .find(|asset| asset.id == asset_id)
+                    .and_then(|asset| asset.stream_index)
+            }),
+        };
         let response = ctx
             .plugin_manager
             .load_resource(&request, ctx.cache_manager)?;
@@ -115,12 +167,8 @@ impl SourceEvaluator {
             return Ok(PinValue::None);
         }
 
-        let time_offset = {
-            let delta_frames = ctx.frame_number as f64 - source.in_frame as f64;
-            delta_frames / ctx.composition.fps
-        };
-        let source_delta_frames = time_offset * source.fps;
-        let source_frame_number = source.source_begin_frame + source_delta_frames.round() as i64;
+        let source_frame_number =
+            source.compute_source_frame(ctx.frame_number, ctx.composition.fps);
         if source_frame_number < 0 {
             return Ok(PinValue::None);
         }
@@ -170,4 +218,38 @@ impl SourceEvaluator {
                 .rasterize_sksl_layer(&shader, resolution, eval_time as f32, transform)?;
         Ok(PinValue::Image(output))
     }
+
+    /// Gradient source: fill the layer rect with the stops in the `stops` JSON property.
+    fn evaluate_gradient(
+        &self,
+        properties: &crate::project::property::PropertyMap,
+        transform: &Transform,
+        ctx: &mut EvalContext,
+    ) -> Result<PinValue, LibraryError> {
+        let stops_json = ctx.resolve_string(properties, "stops", "");
+        let stops = match GradientStop::parse_list(&stops_json) {
+            Ok(stops) => stops,
+            Err(e) => {
+                log::warn!("Invalid gradient stops: {}", e);
+                return Ok(PinValue::None);
+            }
+        };
+
+        let type_name = ctx.resolve_string(properties, "gradient_type", "Linear");
+        let gradient_type = GradientType::from_name(&type_name).unwrap_or_default();
+        let width = ctx.resolve_number(properties, "width", ctx.composition.width as f64);
+        let height = ctx.resolve_number(properties, "height", ctx.composition.height as f64);
+        let (sx, sy) = ctx.resolve_vec2(properties, "start_point", 0.0, height / 2.0);
+        let (ex, ey) = ctx.resolve_vec2(properties, "end_point", width, height / 2.0);
+
+        let output = ctx.renderer.rasterize_gradient_layer(
+            gradient_type,
+            (sx as f32, sy as f32),
+            (ex as f32, ey as f32),
+            &stops,
+            (width as f32, height as f32),
+            transform,
+        )?;
+        Ok(PinValue::Image(output))
+    }
 }
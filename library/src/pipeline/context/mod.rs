@@ -4,6 +4,7 @@ mod property_resolve;
 
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use uuid::Uuid;
 
@@ -13,6 +14,7 @@ use crate::error::LibraryError;
 use crate::plugin::PluginManager;
 use crate::plugin::PropertyEvaluatorRegistry;
 use crate::project::connection::PinId;
+use crate::project::graph_node::{REROUTE_INPUT_PIN, REROUTE_OUTPUT_PIN};
 use crate::project::node::Node;
 use crate::project::project::{Composition, Project};
 use crate::rendering::cache::CacheManager;
@@ -29,6 +31,18 @@ pub trait TrackEvaluator {
         track_id: Uuid,
         ctx: &mut EvalContext,
     ) -> Result<crate::rendering::renderer::RenderOutput, LibraryError>;
+
+    /// Identifies the engine driving the evaluation, so stateful effects keep
+    /// preview and export history apart.
+    fn render_context_id(&self) -> u64 {
+        0
+    }
+
+    /// Whether the engine renders for export rather than preview. Export
+    /// evaluations leave the node editor's live pin values alone.
+    fn is_export(&self) -> bool {
+        false
+    }
 }
 
 /// Context for a single frame evaluation pass.
@@ -55,6 +69,19 @@ pub struct EvalContext<'a> {
 
     /// Per-frame memoization cache: (node_id, pin_name) → evaluated value.
     node_cache: HashMap<(Uuid, String), PinValue>,
+
+    /// Set from outside the render thread to abort this evaluation.
+    cancel_flag: Arc<AtomicBool>,
+
+    /// Latest progress (0–1) reported by long-running nodes.
+    node_progress: HashMap<Uuid, f64>,
+
+    /// Set once the failing node has been recorded, so nodes that merely
+    /// propagate its error are not blamed for it.
+    error_recorded: bool,
+
+    /// Node whose evaluator is running; owner of the properties it resolves.
+    current_node: Option<Uuid>,
 }
 
 impl<'a> EvalContext<'a> {
@@ -70,6 +97,7 @@ impl<'a> EvalContext<'a> {
         frame_number: u64,
         render_scale: f64,
         region: Option<Region>,
+        cancel_flag: Arc<AtomicBool>,
     ) -> Self {
         let time = frame_number as f64 / composition.fps;
         Self {
@@ -86,6 +114,50 @@ impl<'a> EvalContext<'a> {
             render_scale,
             region,
             node_cache: HashMap::new(),
+            cancel_flag,
+            node_progress: HashMap::new(),
+            error_recorded: false,
+            current_node: None,
+        }
+    }
+
+    /// See [`TrackEvaluator::render_context_id`].
+    pub fn render_context_id(&self) -> u64 {
+        self.track_evaluator.render_context_id()
+    }
+
+    /// See [`TrackEvaluator::is_export`].
+    pub fn is_export(&self) -> bool {
+        self.track_evaluator.is_export()
+    }
+
+    /// Report progress (0–1) of a long-running node evaluation.
+    pub fn signal_progress(&mut self, node_id: Uuid, progress: f64) {
+        let progress = progress.clamp(0.0, 1.0);
+        log::trace!(
+            "[EvalCtx] node {} progress {:.0}%",
+            node_id,
+            progress * 100.0
+        );
+        self.node_progress.insert(node_id, progress);
+    }
+
+    /// Latest progress reported by a node via [`Self::signal_progress`].
+    pub fn node_progress(&self, node_id: Uuid) -> Option<f64> {
+        self.node_progress.get(&node_id).copied()
+    }
+
+    /// Whether the caller has asked to abort this evaluation.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel_flag.load(Ordering::Relaxed)
+    }
+
+    /// Return `Err(LibraryError::Cancelled)` if the evaluation was cancelled.
+    pub fn check_cancelled(&self) -> Result<(), LibraryError> {
+        if self.is_cancelled() {
+            Err(LibraryError::Cancelled)
+        } else {
+            Ok(())
         }
     }
 
@@ -102,6 +174,7 @@ impl<'a> EvalContext<'a> {
         if let Some(cached) = self.node_cache.get(&(node_id, pin_name.to_string())) {
             return Ok(cached.clone());
         }
+        self.check_cancelled()?;
 
         let node = self
             .project
@@ -116,8 +189,8 @@ impl<'a> EvalContext<'a> {
             Node::Source(_source) => {
                 log::debug!("[EvalCtx] evaluate_pin source {}.{}", node_id, pin_name);
                 let evaluator = find_evaluator(evaluators, "clip.")
-                    .ok_or_else(|| LibraryError::render("No clip evaluator registered"))?;
-                let val = evaluator.evaluate(node_id, pin_name, self)?;
+                    .ok_or_else(|| LibraryError::render("No clip evaluator registered"));
+                let val = self.run_evaluator(evaluator, node_id, pin_name)?;
                 log::debug!(
                     "[EvalCtx] source {}.{} => {:?}",
                     node_id,
@@ -126,6 +199,13 @@ impl<'a> EvalContext<'a> {
                 );
                 val
             }
+            Node::Graph(graph_node) if graph_node.is_reroute() => {
+                if pin_name == REROUTE_OUTPUT_PIN {
+                    self.pull_input_value(node_id, REROUTE_INPUT_PIN)?
+                } else {
+                    PinValue::None
+                }
+            }
             Node::Graph(graph_node) => {
                 log::debug!(
                     "[EvalCtx] evaluate_pin graph {}.{} type={}",
@@ -133,14 +213,13 @@ impl<'a> EvalContext<'a> {
                     pin_name,
                     graph_node.type_id
                 );
-                let evaluator =
-                    find_evaluator(evaluators, &graph_node.type_id).ok_or_else(|| {
-                        LibraryError::render(format!(
-                            "No evaluator for node type: {}",
-                            graph_node.type_id
-                        ))
-                    })?;
-                let val = evaluator.evaluate(node_id, pin_name, self)?;
+                let evaluator = find_evaluator(evaluators, &graph_node.type_id).ok_or_else(|| {
+                    LibraryError::render(format!(
+                        "No evaluator for node type: {}",
+                        graph_node.type_id
+                    ))
+                });
+                let val = self.run_evaluator(evaluator, node_id, pin_name)?;
                 log::debug!(
                     "[EvalCtx] graph {}.{} => {:?}",
                     node_id,
@@ -192,11 +271,44 @@ impl<'a> EvalContext<'a> {
         };
 
         // Cache the result
+        if !self.is_export() && self.cache_manager.records_pin_values() {
+            self.cache_manager
+                .record_pin_value(node_id, pin_name, result.summary());
+        }
         self.node_cache
             .insert((node_id, pin_name.to_string()), result.clone());
         Ok(result)
     }
 
+    /// Run a node's evaluator, keeping its error state in the cache manager up to date.
+    ///
+    /// Only the node where an error originates is recorded; a successful evaluation
+    /// clears any earlier error of that node.
+    fn run_evaluator(
+        &mut self,
+        evaluator: Result<&dyn NodeEvaluator, LibraryError>,
+        node_id: Uuid,
+        pin_name: &str,
+    ) -> Result<PinValue, LibraryError> {
+        let previous_node = self.current_node.replace(node_id);
+        let result = evaluator.and_then(|evaluator| evaluator.evaluate(node_id, pin_name, self));
+        self.current_node = previous_node;
+        match result {
+            Ok(value) => {
+                self.cache_manager.clear_node_error(node_id);
+                Ok(value)
+            }
+            Err(LibraryError::Cancelled) => Err(LibraryError::Cancelled),
+            Err(e) => {
+                if !self.error_recorded {
+                    self.cache_manager.record_node_error(node_id, e.to_string());
+                    self.error_recorded = true;
+                }
+                Err(e)
+            }
+        }
+    }
+
     /// Pull the evaluated value from an input pin by following connections backwards.
     ///
     /// Finds the connection where `to == (node_id, pin_name)`, evaluates the
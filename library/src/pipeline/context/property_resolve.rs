@@ -1,5 +1,7 @@
 //! Property resolution convenience methods for EvalContext.
 
+use std::collections::HashMap;
+
 use super::EvalContext;
 use crate::plugin::EvaluationContext;
 use crate::project::property::{PropertyMap, PropertyValue};
@@ -18,6 +20,7 @@ impl<'a> EvalContext<'a> {
             let eval_ctx = EvaluationContext {
                 property_map: properties,
                 fps: self.composition.fps,
+                owner_id: self.current_node,
             };
             self.property_evaluators
                 .evaluate(prop, self.time, &eval_ctx)
@@ -26,6 +29,23 @@ impl<'a> EvalContext<'a> {
         }
     }
 
+    /// Resolve several properties of a node at the current time.
+    ///
+    /// Keys missing from `properties` are omitted from the result.
+    pub fn resolve_property_values(
+        &self,
+        properties: &PropertyMap,
+        keys: &[&str],
+    ) -> HashMap<String, PropertyValue> {
+        let eval_ctx = EvaluationContext {
+            property_map: properties,
+            fps: self.composition.fps,
+            owner_id: self.current_node,
+        };
+        self.property_evaluators
+            .evaluate_batch(properties, keys, self.time, &eval_ctx)
+    }
+
     /// Convenience: resolve a property as f64.
     pub fn resolve_number(&self, properties: &PropertyMap, key: &str, default: f64) -> f64 {
         match self.resolve_property_value(properties, key, PropertyValue::from(default)) {
@@ -79,6 +99,26 @@ impl<'a> EvalContext<'a> {
         }
     }
 
+    /// Convenience: resolve a property as Vec3 (returns (x, y, z) as f64 tuple).
+    pub fn resolve_vec3(
+        &self,
+        properties: &PropertyMap,
+        key: &str,
+        default: (f64, f64, f64),
+    ) -> (f64, f64, f64) {
+        use crate::project::property::Vec3 as PropVec3;
+        use ordered_float::OrderedFloat;
+        let default_val = PropertyValue::Vec3(PropVec3 {
+            x: OrderedFloat(default.0),
+            y: OrderedFloat(default.1),
+            z: OrderedFloat(default.2),
+        });
+        match self.resolve_property_value(properties, key, default_val) {
+            PropertyValue::Vec3(v) => (v.x.into_inner(), v.y.into_inner(), v.z.into_inner()),
+            _ => default,
+        }
+    }
+
     /// Convenience: resolve a property as bool.
     pub fn resolve_bool(&self, properties: &PropertyMap, key: &str, default: bool) -> bool {
         match self.resolve_property_value(properties, key, PropertyValue::Boolean(default)) {
@@ -101,9 +141,6 @@ impl<'a> EvalContext<'a> {
     ///
     /// Accounts for clip's `in_frame`, `source_begin_frame`, and `fps`.
     pub fn clip_eval_time(&self, clip: &crate::project::source::SourceData) -> f64 {
-        let delta_frames = self.frame_number as f64 - clip.in_frame as f64;
-        let time_offset = delta_frames / self.composition.fps;
-        let source_start_time = clip.source_begin_frame as f64 / clip.fps;
-        source_start_time + time_offset
+        clip.compute_local_time(self.frame_number, self.composition.fps)
     }
 }
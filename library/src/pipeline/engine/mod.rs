@@ -3,6 +3,7 @@
 mod image_chain;
 
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 
 use uuid::Uuid;
 
@@ -17,14 +18,22 @@ use crate::project::source::SourceKind;
 use crate::rendering::cache::CacheManager;
 use crate::rendering::renderer::{RenderOutput, Renderer};
 use crate::rendering::skia_renderer::SkiaRenderer;
+use crate::runtime::draw_type::BlendMode;
 use crate::runtime::frame::Region;
 
 /// The evaluation engine holds all registered node evaluators and drives
 /// the pull-based rendering pipeline.
 pub struct EvalEngine {
     evaluators: Vec<Box<dyn NodeEvaluator>>,
+    cancel_flag: Arc<AtomicBool>,
+    /// Unique per engine; see [`TrackEvaluator::render_context_id`].
+    context_id: u64,
+    /// See [`TrackEvaluator::is_export`].
+    export: bool,
 }
 
+static NEXT_CONTEXT_ID: AtomicU64 = AtomicU64::new(1);
+
 impl TrackEvaluator for EvalEngine {
     fn evaluate_track(
         &self,
@@ -33,15 +42,36 @@ impl TrackEvaluator for EvalEngine {
     ) -> Result<RenderOutput, LibraryError> {
         EvalEngine::evaluate_track(self, track_id, ctx)
     }
+
+    fn render_context_id(&self) -> u64 {
+        self.context_id
+    }
+
+    fn is_export(&self) -> bool {
+        self.export
+    }
 }
 
 impl EvalEngine {
     pub fn new() -> Self {
         Self {
             evaluators: Vec::new(),
+            cancel_flag: Arc::new(AtomicBool::new(false)),
+            context_id: NEXT_CONTEXT_ID.fetch_add(1, Ordering::Relaxed),
+            export: false,
         }
     }
 
+    /// Mark the engine as rendering for export.
+    pub fn set_export(&mut self, export: bool) {
+        self.export = export;
+    }
+
+    /// Share a flag that aborts in-flight evaluations when set.
+    pub fn set_cancel_flag(&mut self, flag: Arc<AtomicBool>) {
+        self.cancel_flag = flag;
+    }
+
     /// Create an engine with all built-in evaluators registered.
     pub fn with_default_evaluators() -> Self {
         let mut engine = Self::new();
@@ -86,6 +116,7 @@ impl EvalEngine {
             frame_number,
             render_scale,
             region,
+            Arc::clone(&self.cancel_flag),
         );
 
         // Look for a preview output node in the project graph.
@@ -121,11 +152,10 @@ impl EvalEngine {
 
         // Fallback: composite all direct children of the composition
         for child_id in &composition.child_ids {
+            ctx.check_cancelled()?;
             match ctx.project.get_node(*child_id).cloned() {
                 Some(Node::Track(_)) | Some(Node::Layer(_)) => {
-                    let sub_output = self.evaluate_track(*child_id, &mut ctx)?;
-                    let identity = crate::runtime::transform::Transform::default();
-                    ctx.renderer.draw_layer(&sub_output, &identity)?;
+                    self.composite_child_track(*child_id, &mut ctx)?;
                 }
                 _ => {}
             }
@@ -133,6 +163,46 @@ impl EvalEngine {
         ctx.renderer.finalize()
     }
 
+    /// Evaluate a single node output pin for one frame, ignoring the preview output node.
+    ///
+    /// Used to snapshot an intermediate result of the graph (e.g. for A/B comparison).
+    pub fn evaluate_node_output(
+        &self,
+        project: &Project,
+        composition: &Composition,
+        plugin_manager: &PluginManager,
+        renderer: &mut SkiaRenderer,
+        cache_manager: &CacheManager,
+        property_evaluators: Arc<PropertyEvaluatorRegistry>,
+        node_id: Uuid,
+        pin_name: &str,
+        frame_number: u64,
+        render_scale: f64,
+    ) -> Result<RenderOutput, LibraryError> {
+        let mut ctx = EvalContext::new(
+            project,
+            composition,
+            plugin_manager,
+            renderer,
+            cache_manager,
+            property_evaluators,
+            &self.evaluators,
+            self,
+            frame_number,
+            render_scale,
+            None,
+            Arc::clone(&self.cancel_flag),
+        );
+        ctx.evaluate_pin(node_id, pin_name)?
+            .into_image()
+            .ok_or_else(|| {
+                LibraryError::render(format!(
+                    "Pin {}.{} did not produce an image",
+                    node_id, pin_name
+                ))
+            })
+    }
+
     /// Find the first `compositing.preview_output` node in the project graph.
     fn find_preview_output_node(project: &Project) -> Option<Uuid> {
         project
@@ -170,6 +240,12 @@ impl EvalEngine {
             log::debug!("[EvalEngine] Track {} '{}' hidden, skip", track_id, name);
             return ctx.renderer.finalize();
         }
+        if ctx.project.get_track(track_id).is_some()
+            && ctx.project.is_track_silenced(ctx.composition, track_id)
+        {
+            log::debug!("[EvalEngine] Track {} '{}' muted, skip", track_id, name);
+            return ctx.renderer.finalize();
+        }
 
         // Layer container output: if there's a connection TO this track's image_out
         // (e.g. transform.image_out → layer.image_out), pull from the connected source.
@@ -205,7 +281,9 @@ impl EvalEngine {
             ctx.frame_number
         );
 
-        for child_id in &child_ids {
+        for (index, child_id) in child_ids.iter().enumerate() {
+            ctx.check_cancelled()?;
+            ctx.signal_progress(track_id, index as f64 / child_ids.len() as f64);
             match ctx.project.get_node(*child_id).cloned() {
                 Some(Node::Source(clip)) => {
                     if clip.kind == SourceKind::Audio {
@@ -234,17 +312,25 @@ impl EvalEngine {
                     let output = self.resolve_image_chain(*child_id, &clip.kind, ctx)?;
                     if let Some(image) = output {
                         log::debug!("[EvalEngine] Clip {} produced image", child_id);
-                        let identity = crate::runtime::transform::Transform::default();
-                        ctx.renderer.draw_layer(&image, &identity)?;
+                        let icc_profile = clip
+                            .reference_id
+                            .and_then(|id| ctx.project.assets.iter().find(|a| a.id == id))
+                            .and_then(|asset| asset.icc_profile.as_deref());
+                        if clip.blend_mode != BlendMode::Normal {
+                            ctx.renderer.begin_layer(1.0, Some(clip.blend_mode));
+                            let result = draw_clip_output(ctx.renderer, &image, icc_profile);
+                            ctx.renderer.end_layer();
+                            result?;
+                        } else {
+                            draw_clip_output(ctx.renderer, &image, icc_profile)?;
+                        }
                     } else {
                         log::warn!("[EvalEngine] Clip {} produced no image", child_id);
                     }
                 }
                 Some(Node::Track(_)) => {
                     log::debug!("[EvalEngine] Evaluating sub-track {}", child_id);
-                    let sub_output = self.evaluate_track(*child_id, ctx)?;
-                    let identity = crate::runtime::transform::Transform::default();
-                    ctx.renderer.draw_layer(&sub_output, &identity)?;
+                    self.composite_child_track(*child_id, ctx)?;
                 }
                 Some(Node::Layer(layer)) => {
                     // Check Layer timing before evaluating
@@ -264,9 +350,7 @@ impl EvalEngine {
                         layer.in_frame,
                         layer.out_frame
                     );
-                    let sub_output = self.evaluate_track(*child_id, ctx)?;
-                    let identity = crate::runtime::transform::Transform::default();
-                    ctx.renderer.draw_layer(&sub_output, &identity)?;
+                    self.composite_child_track(*child_id, ctx)?;
                 }
                 Some(Node::Graph(g)) => {
                     log::trace!("[EvalEngine] Skip graph node {} ({})", child_id, g.type_id);
@@ -279,7 +363,53 @@ impl EvalEngine {
                 }
             }
         }
+        ctx.signal_progress(track_id, 1.0);
 
         ctx.renderer.finalize()
     }
+
+    /// Evaluate a child track/layer directly onto the current surface.
+    ///
+    /// A semi-transparent or non-normal blend child is rendered inside an isolated
+    /// layer, so its overlapping clips are composited first and the result is faded
+    /// or blended as a whole.
+    fn composite_child_track(
+        &self,
+        child_id: Uuid,
+        ctx: &mut EvalContext,
+    ) -> Result<(), LibraryError> {
+        let (opacity, blend_mode) = if let Some(track) = ctx.project.get_track(child_id) {
+            (track.opacity, track.blend_mode)
+        } else if let Some(layer) = ctx.project.get_layer(child_id) {
+            (layer.opacity, layer.blend_mode)
+        } else {
+            (1.0, BlendMode::Normal)
+        };
+
+        if opacity < 1.0 || blend_mode != BlendMode::Normal {
+            ctx.renderer.begin_layer(opacity, Some(blend_mode));
+            let result = self.evaluate_track(child_id, ctx);
+            ctx.renderer.end_layer();
+            result?;
+        } else {
+            // Children have already been drawn onto the surface; the snapshot is not redrawn
+            self.evaluate_track(child_id, ctx)?;
+        }
+        Ok(())
+    }
+}
+
+/// Draw a clip's final image, converting it from its asset's ICC profile when it has one.
+fn draw_clip_output(
+    renderer: &mut SkiaRenderer,
+    output: &RenderOutput,
+    icc_profile: Option<&[u8]>,
+) -> Result<(), LibraryError> {
+    let identity = crate::runtime::transform::Transform::default();
+    match (output, icc_profile) {
+        (RenderOutput::Image(image), Some(profile)) => {
+            renderer.draw_image_with_color_profile(image, Some(profile), &identity)
+        }
+        _ => renderer.draw_layer(output, &identity),
+    }
 }
@@ -59,6 +59,19 @@ impl EnsembleData {
             patches: std::collections::HashMap::new(),
         }
     }
+
+    /// Set the color and opacity patch of the character at `index`.
+    ///
+    /// Any transform already patched for that character is kept.
+    /// `None` keeps the text's base color.
+    pub fn set_patch_color(&mut self, index: usize, color: Option<Color>, opacity: f32) {
+        let patch = self
+            .patches
+            .entry(index)
+            .or_insert_with(TransformData::identity);
+        patch.color_override = color;
+        patch.opacity = opacity.clamp(0.0, 1.0);
+    }
 }
 
 impl Default for EnsembleData {
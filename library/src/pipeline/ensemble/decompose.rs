@@ -0,0 +1,164 @@
+use crate::error::LibraryError;
+use crate::runtime::color::Color;
+use skia_safe::{Font, FontMgr, FontStyle, Point, Rect, Size};
+
+use super::types::{EnsembleChar, EnsembleLine, EnsembleText};
+
+/// Resolve a font by family name, falling back to the system default typeface.
+pub(crate) fn make_font(font_family: &str, font_size: f32) -> Option<Font> {
+    let font_mgr = FontMgr::default();
+    let typeface = font_mgr
+        .match_family_style(font_family, FontStyle::default())
+        .or_else(|| font_mgr.legacy_make_typeface(None, FontStyle::default()))?;
+    Some(Font::from_typeface(typeface, font_size))
+}
+
+/// A character placed by [`layout_text`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct CharLayout {
+    pub ch: char,
+    pub line: usize,
+    /// Left edge of the advance box.
+    pub x: f32,
+    /// Top of the character's line.
+    pub top: f32,
+    pub advance: f32,
+}
+
+/// Per-character layout shared by the decomposer and the ensemble text renderer.
+pub(crate) struct TextLayout {
+    /// Every character except the `\n` separators, in reading order.
+    pub chars: Vec<CharLayout>,
+    /// Advance width of each line.
+    pub line_widths: Vec<f32>,
+    pub line_height: f32,
+}
+
+/// Lay out `text` with `font`: lines are split on `\n`, each starting at
+/// x = 0 and one line height below the previous one.
+pub(crate) fn layout_text(text: &str, font: &Font) -> TextLayout {
+    let (_, metrics) = font.metrics();
+    let line_height = metrics.descent - metrics.ascent + metrics.leading;
+
+    let mut chars = Vec::new();
+    let mut line_widths = Vec::new();
+    for (line, line_text) in text.split('\n').enumerate() {
+        let top = line as f32 * line_height;
+        let mut x = 0.0f32;
+        for ch in line_text.chars() {
+            let (advance, _bounds) = font.measure_str(ch.to_string(), None);
+            chars.push(CharLayout {
+                ch,
+                line,
+                x,
+                top,
+                advance,
+            });
+            x += advance;
+        }
+        line_widths.push(x);
+    }
+
+    TextLayout {
+        chars,
+        line_widths,
+        line_height,
+    }
+}
+
+/// Splits text into per-character glyphs with positions, lines and bounding boxes.
+pub struct TextDecomposer;
+
+impl TextDecomposer {
+    /// Decompose `text` into lines and characters laid out from the origin.
+    ///
+    /// Lines are split on `\n`; each character's `base_pos` is the top-left of
+    /// its advance box and `size` spans the advance width and line height.
+    pub fn decompose(
+        text: &str,
+        font_family: &str,
+        font_size: f64,
+    ) -> Result<EnsembleText, LibraryError> {
+        let font = make_font(font_family, font_size as f32).ok_or_else(|| {
+            LibraryError::render(format!("No typeface available for '{}'", font_family))
+        })?;
+
+        let layout = layout_text(text, &font);
+        let line_height = layout.line_height;
+        let mut line_chars: Vec<Vec<EnsembleChar>> = vec![Vec::new(); layout.line_widths.len()];
+        for c in &layout.chars {
+            line_chars[c.line].push(EnsembleChar::new(
+                font.unichar_to_glyph(c.ch as i32),
+                Point::new(c.x, c.top),
+                Size::new(c.advance, line_height),
+            ));
+        }
+        let lines = line_chars
+            .into_iter()
+            .zip(&layout.line_widths)
+            .enumerate()
+            .map(|(i, (chars, &width))| {
+                let bounds = Rect::from_xywh(0.0, i as f32 * line_height, width, line_height);
+                EnsembleLine::new(chars, bounds)
+            })
+            .collect();
+
+        let mut ensemble = EnsembleText::new(
+            text.to_string(),
+            font,
+            Color {
+                r: 255,
+                g: 255,
+                b: 255,
+                a: 255,
+            },
+        );
+        ensemble.lines = lines;
+        Ok(ensemble)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decompose_splits_lines_and_chars() {
+        let ensemble = TextDecomposer::decompose("ab\nc", "sans-serif", 32.0).unwrap();
+        assert_eq!(ensemble.lines.len(), 2);
+        assert_eq!(ensemble.total_char_count(), 3);
+
+        // 同じ行の文字は左から右へ並ぶ
+        let first = &ensemble.lines[0];
+        assert_eq!(first.chars[0].base_pos.x, 0.0);
+        assert!(first.chars[1].base_pos.x > 0.0);
+        assert_eq!(
+            first.base_bounds.width(),
+            first.chars[1].base_pos.x + first.chars[1].size.width
+        );
+
+        // 2行目は1行目の下に配置される
+        let second = &ensemble.lines[1];
+        assert!(second.chars[0].base_pos.y > first.chars[0].base_pos.y);
+    }
+
+    #[test]
+    fn test_layout_matches_decomposed_positions() {
+        let font = make_font("sans-serif", 32.0).unwrap();
+        let layout = layout_text("ab\ncd", &font);
+        let ensemble = TextDecomposer::decompose("ab\ncd", "sans-serif", 32.0).unwrap();
+
+        // 改行文字は配置されず、描画と分解で同じ位置を使う
+        assert_eq!(layout.chars.len(), 4);
+        assert_eq!(layout.line_widths.len(), 2);
+        let decomposed: Vec<(f32, f32)> = ensemble
+            .lines
+            .iter()
+            .flat_map(|line| line.chars.iter().map(|c| (c.base_pos.x, c.base_pos.y)))
+            .collect();
+        let laid_out: Vec<(f32, f32)> = layout.chars.iter().map(|c| (c.x, c.top)).collect();
+        assert_eq!(decomposed, laid_out);
+        assert_eq!(layout.chars[2].x, 0.0);
+        assert_eq!(layout.chars[2].top, layout.line_height);
+    }
+}
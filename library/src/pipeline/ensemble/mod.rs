@@ -1,10 +1,12 @@
 pub mod config;
+pub mod decompose;
 pub mod decorators;
 pub mod effectors;
 pub mod target;
 pub mod types;
 
 pub use config::{DecoratorConfig, EffectorConfig, EnsembleData};
+pub use decompose::TextDecomposer;
 pub use decorators::{BackplateDecorator, BackplateShape, BackplateTarget, Decorator};
 pub use effectors::{
     Effector, OpacityEffector, RandomizeEffector, StepDelayEffector, TransformEffector,
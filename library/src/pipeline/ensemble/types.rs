@@ -61,6 +61,37 @@ impl TransformData {
             color_override: other.color_override.clone().or(self.color_override.clone()),
         }
     }
+
+    /// 文字の描画色（`color_override`があればそれを使い、アルファに`opacity`を掛ける）
+    pub fn paint_color(&self, base_color: &Color) -> Color {
+        let color = self.color_override.as_ref().unwrap_or(base_color);
+        Color {
+            a: (color.a as f32 * self.opacity).clamp(0.0, 255.0) as u8,
+            ..color.clone()
+        }
+    }
+
+    /// `self`から`other`への線形補間（t=0で`self`、t=1で`other`）
+    pub fn lerp(&self, other: &TransformData, t: f32) -> TransformData {
+        let mix = |a: f32, b: f32| a + (b - a) * t;
+        TransformData {
+            translate: (
+                mix(self.translate.0, other.translate.0),
+                mix(self.translate.1, other.translate.1),
+            ),
+            rotate: mix(self.rotate, other.rotate),
+            scale: (
+                mix(self.scale.0, other.scale.0),
+                mix(self.scale.1, other.scale.1),
+            ),
+            opacity: mix(self.opacity, other.opacity),
+            color_override: if t >= 0.5 {
+                other.color_override.clone()
+            } else {
+                self.color_override.clone()
+            },
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -224,6 +255,54 @@ mod tests {
         assert!(combined.color_override.is_some());
     }
 
+    #[test]
+    fn test_transform_lerp() {
+        let from = TransformData::identity();
+        let to = TransformData {
+            translate: (10.0, -20.0),
+            rotate: 1.0,
+            scale: (3.0, 1.0),
+            opacity: 0.0,
+            color_override: None,
+        };
+
+        let half = from.lerp(&to, 0.5);
+        assert_eq!(half.translate, (5.0, -10.0));
+        assert_eq!(half.rotate, 0.5);
+        assert_eq!(half.scale, (2.0, 1.0));
+        assert_eq!(half.opacity, 0.5);
+        assert_eq!(from.lerp(&to, 1.0), to);
+    }
+
+    #[test]
+    fn test_paint_color_uses_override_and_opacity() {
+        let base = Color {
+            r: 255,
+            g: 255,
+            b: 255,
+            a: 255,
+        };
+        let mut t = TransformData::identity();
+        assert_eq!(t.paint_color(&base), base);
+
+        t.color_override = Some(Color {
+            r: 255,
+            g: 0,
+            b: 0,
+            a: 200,
+        });
+        t.opacity = 0.5;
+        assert_eq!(
+            t.paint_color(&base),
+            Color {
+                r: 255,
+                g: 0,
+                b: 0,
+                a: 100,
+            }
+        );
+    }
+
     #[test]
     fn test_ensemble_char_center() {
         let ch = EnsembleChar::new(42, Point::new(10.0, 20.0), Size::new(8.0, 12.0));
@@ -65,6 +65,14 @@ impl PinValue {
         }
     }
 
+    /// Extract as Vec3, returning default if not a Vec3.
+    pub fn as_vec3(&self, default: (f64, f64, f64)) -> (f64, f64, f64) {
+        match self {
+            PinValue::Vec3(x, y, z) => (*x, *y, *z),
+            _ => default,
+        }
+    }
+
     /// Extract as Color.
     pub fn as_color(&self, default: Color) -> Color {
         match self {
@@ -113,6 +121,36 @@ impl PinValue {
             _ => None,
         }
     }
+
+    /// Short human-readable form, for showing live values in the node editor.
+    /// Bulk data (images, shapes, audio) is only named.
+    pub fn summary(&self) -> String {
+        match self {
+            PinValue::Image(_) => "Image".to_string(),
+            PinValue::Scalar(v) => format!("{:.3}", v),
+            PinValue::Vec2(x, y) => format!("({:.2}, {:.2})", x, y),
+            PinValue::Vec3(x, y, z) => format!("({:.2}, {:.2}, {:.2})", x, y, z),
+            PinValue::Color(c) => format!("#{:02X}{:02X}{:02X}{:02X}", c.r, c.g, c.b, c.a),
+            PinValue::String(s) | PinValue::Path(s) => {
+                const MAX_CHARS: usize = 24;
+                if s.chars().count() > MAX_CHARS {
+                    format!(
+                        "\"{}\u{2026}\"",
+                        s.chars().take(MAX_CHARS).collect::<String>()
+                    )
+                } else {
+                    format!("\"{}\"", s)
+                }
+            }
+            PinValue::Boolean(b) => b.to_string(),
+            PinValue::Integer(i) => i.to_string(),
+            PinValue::Style(_) => "Style".to_string(),
+            PinValue::StyleChain(chain) => format!("Styles ({})", chain.len()),
+            PinValue::Shape(_) => "Shape".to_string(),
+            PinValue::Audio(_) => "Audio".to_string(),
+            PinValue::None => "\u{2014}".to_string(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -122,6 +160,26 @@ mod tests {
     use crate::runtime::draw_type::DrawStyle;
     use uuid::Uuid;
 
+    #[test]
+    fn summary_formats_values() {
+        assert_eq!(PinValue::Scalar(0.5).summary(), "0.500");
+        assert_eq!(PinValue::Vec2(1.0, -2.0).summary(), "(1.00, -2.00)");
+        assert_eq!(PinValue::Integer(3).summary(), "3");
+        let color = Color {
+            r: 255,
+            g: 0,
+            b: 16,
+            a: 255,
+        };
+        assert_eq!(PinValue::Color(color).summary(), "#FF0010FF");
+    }
+
+    #[test]
+    fn summary_truncates_long_strings() {
+        let pv = PinValue::String("a".repeat(40));
+        assert_eq!(pv.summary(), format!("\"{}\u{2026}\"", "a".repeat(24)));
+    }
+
     #[test]
     fn as_scalar_returns_value_for_scalar() {
         let pv = PinValue::Scalar(42.0);
@@ -47,6 +47,25 @@ impl PropertyEvaluatorRegistry {
             }
         }
     }
+
+    /// Evaluate several properties of a map in one call.
+    ///
+    /// Keys missing from `props` are omitted from the result.
+    pub fn evaluate_batch(
+        &self,
+        props: &PropertyMap,
+        keys: &[&str],
+        time: f64,
+        ctx: &EvaluationContext,
+    ) -> HashMap<String, PropertyValue> {
+        keys.iter()
+            .filter_map(|key| {
+                props
+                    .get(key)
+                    .map(|prop| (key.to_string(), self.evaluate(prop, time, ctx)))
+            })
+            .collect()
+    }
 }
 
 /// Trait for property evaluators.
@@ -58,4 +77,7 @@ pub trait PropertyEvaluator: Send + Sync {
 pub struct EvaluationContext<'a> {
     pub property_map: &'a PropertyMap,
     pub fps: f64,
+    /// Node that owns `property_map`, when evaluated as part of a render.
+    /// Stateful evaluators key their state by it.
+    pub owner_id: Option<uuid::Uuid>,
 }
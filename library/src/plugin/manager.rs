@@ -7,6 +7,7 @@ use std::sync::{Arc, RwLock};
 use libloading::{Library, Symbol};
 use log::debug;
 
+use crate::audio::effects::AudioEffect;
 use crate::cache::CacheManager;
 use crate::error::LibraryError;
 use crate::project::asset::AssetKind;
@@ -21,20 +22,26 @@ use crate::builtin::exporters::{ExportPlugin, ExportSettings};
 use crate::builtin::loaders::{
     AssetMetadata, LoadPlugin, LoadRepository, LoadRequest, LoadResponse,
 };
-use crate::plugin::PluginCategory;
 use crate::plugin::evaluator::PropertyEvaluatorRegistry;
 use crate::plugin::node_types::{NodeCategory, NodeTypeDefinition};
 use crate::plugin::repository::{PluginRegistry, PluginRepository};
+use crate::plugin::{PluginCategory, PluginSource, PluginStatus};
 
 use crate::plugin::traits::{Plugin, PropertyPlugin};
 use crate::plugin::{DecoratorPlugin, EffectorPlugin, StylePlugin};
 
 use crate::builtin::effects::{
-    BlurEffectPlugin, DilateEffectPlugin, DropShadowEffectPlugin, ErodeEffectPlugin,
-    MagnifierEffectPlugin, PixelSorterPlugin, TileEffectPlugin,
+    BlurEffectPlugin, ChromaticAberrationEffectPlugin, ColorCurvesEffectPlugin, DilateEffectPlugin,
+    DropShadowEffectPlugin, ErodeEffectPlugin, GlowEffectPlugin, LutEffectPlugin,
+    MagnifierEffectPlugin, PixelSorterPlugin, TileEffectPlugin, TimeEchoEffectPlugin,
+    VignetteEffectPlugin,
+};
+use crate::builtin::exporters::{
+    FfmpegExportPlugin, GifExportPlugin, PngExportPlugin, WebpExportPlugin,
+};
+use crate::builtin::loaders::{
+    ExrLoader, FfmpegVideoLoader, NativeImageLoader, PdfLoader, SvgLoader,
 };
-use crate::builtin::exporters::{FfmpegExportPlugin, PngExportPlugin};
-use crate::builtin::loaders::{FfmpegVideoLoader, NativeImageLoader};
 use crate::builtin::properties::{
     ConstantPropertyPlugin, ExpressionPropertyPlugin, KeyframePropertyPlugin,
 };
@@ -56,14 +63,25 @@ impl Default for PluginManager {
         manager.register_effect(Arc::new(DropShadowEffectPlugin::new()));
         manager.register_effect(Arc::new(MagnifierEffectPlugin::new()));
         manager.register_effect(Arc::new(TileEffectPlugin::new()));
+        manager.register_effect(Arc::new(ChromaticAberrationEffectPlugin::new()));
+        manager.register_effect(Arc::new(VignetteEffectPlugin::new()));
+        manager.register_effect(Arc::new(LutEffectPlugin::new()));
+        manager.register_effect(Arc::new(GlowEffectPlugin::new()));
+        manager.register_effect(Arc::new(TimeEchoEffectPlugin::new()));
+        manager.register_effect(Arc::new(ColorCurvesEffectPlugin::new()));
 
         // Standard Loaders
+        manager.register_load_plugin(Arc::new(SvgLoader::new()));
+        manager.register_load_plugin(Arc::new(PdfLoader::new()));
+        manager.register_load_plugin(Arc::new(ExrLoader::new()));
         manager.register_load_plugin(Arc::new(NativeImageLoader::new()));
         manager.register_load_plugin(Arc::new(FfmpegVideoLoader::new()));
 
         // Standard Exporters
         manager.register_export_plugin(Arc::new(PngExportPlugin::new()));
         manager.register_export_plugin(Arc::new(FfmpegExportPlugin::new()));
+        manager.register_export_plugin(Arc::new(WebpExportPlugin::new()));
+        manager.register_export_plugin(Arc::new(GifExportPlugin::new()));
 
         // Standard Property Plugins
         manager.register_property_plugin(Arc::new(ConstantPropertyPlugin::new()));
@@ -89,6 +107,11 @@ impl Default for PluginManager {
         manager.register_style_plugin(Arc::new(crate::builtin::styles::FillStylePlugin));
         manager.register_style_plugin(Arc::new(crate::builtin::styles::StrokeStylePlugin));
 
+        // Standard Audio Effects
+        for effect in crate::audio::effects::builtin_audio_effects() {
+            manager.register_audio_effect_plugin(effect.clone());
+        }
+
         // Auto-register NodeTypeDefinitions from existing plugins
         manager.auto_register_node_types();
 
@@ -162,6 +185,14 @@ impl_plugin_accessors! {
         get_properties: get_style_properties,
         field: style_plugins,
         trait_type: dyn StylePlugin
+    },
+    {
+        register: register_audio_effect_plugin,
+        get: get_audio_effect_plugin,
+        get_available: get_available_audio_effects,
+        get_properties: get_audio_effect_properties,
+        field: audio_effect_plugins,
+        trait_type: dyn AudioEffect
     }
 }
 
@@ -175,9 +206,12 @@ impl PluginManager {
                 effector_plugins: PluginRepository::new(),
                 decorator_plugins: PluginRepository::new(),
                 style_plugins: PluginRepository::new(),
+                audio_effect_plugins: PluginRepository::new(),
                 property_evaluators: PropertyEvaluatorRegistry::new(),
                 node_types: HashMap::new(),
                 dynamic_libraries: Vec::new(),
+                plugin_sources: HashMap::new(),
+                load_failures: Vec::new(),
             }),
         }
     }
@@ -246,6 +280,14 @@ impl PluginManager {
         }
     }
 
+    /// Let effect plugins drop state kept for effect nodes that no longer exist.
+    pub fn prune_effect_instances(&self, exists: &dyn Fn(uuid::Uuid) -> bool) {
+        let inner = self.inner.read().unwrap();
+        for plugin in inner.effect_plugins.values() {
+            plugin.prune_instances(exists);
+        }
+    }
+
     pub fn get_effect_definition(&self, effect_id: &str) -> Option<EffectDefinition> {
         let inner = self.inner.read().unwrap();
         inner
@@ -313,6 +355,25 @@ impl PluginManager {
         None
     }
 
+    /// Check whether any load plugin can handle the file, without opening it.
+    pub fn can_load(&self, path: &str) -> bool {
+        let inner = self.inner.read().unwrap();
+        inner
+            .load_plugins
+            .values()
+            .any(|plugin| plugin.probe_format(path))
+    }
+
+    /// Find the highest-priority load plugin that can handle the file.
+    pub fn find_loader(&self, path: &str) -> Option<Arc<dyn LoadPlugin>> {
+        let inner = self.inner.read().unwrap();
+        inner
+            .load_plugins
+            .values()
+            .find(|plugin| plugin.probe_format(path))
+            .cloned()
+    }
+
     pub fn probe_asset_kind(&self, path: &str) -> AssetKind {
         self.get_metadata(path)
             .map(|m| m.kind)
@@ -374,7 +435,7 @@ impl PluginManager {
         )))
     }
 
-    unsafe fn load_plugin_generic<T: ?Sized + 'static>(
+    unsafe fn load_plugin_generic<T: ?Sized + Plugin + 'static>(
         &self,
         path: &Path,
         symbol: &[u8],
@@ -389,11 +450,15 @@ impl PluginManager {
                 String::from_utf8_lossy(symbol)
             )));
         }
-        let plugin = unsafe { Arc::from(Box::from_raw(raw)) };
+        let plugin: Arc<T> = unsafe { Arc::from(Box::from_raw(raw)) };
+        let plugin_id = plugin.id().to_string();
 
         let mut inner = self.inner.write().unwrap();
         register(&mut *inner, plugin);
         inner.dynamic_libraries.push(library);
+        inner
+            .plugin_sources
+            .insert(plugin_id, PluginSource::DynamicLibrary(path.to_path_buf()));
         Ok(())
     }
 
@@ -492,11 +557,12 @@ impl PluginManager {
                     }
                     if let Err(e) = self.load_export_plugin_from_file(&path) {
                         log::debug!("Not an export plugin: {}", e);
-                    } else {
-                        continue;
+                        log::warn!("File is not a recognized plugin type: {}", path.display());
+                        self.record_load_failure(
+                            PluginSource::DynamicLibrary(path),
+                            format!("Not a recognized plugin: {}", e),
+                        );
                     }
-
-                    log::warn!("File is not a recognized plugin type: {}", path.display());
                 }
             }
         }
@@ -533,10 +599,17 @@ impl PluginManager {
                     ) {
                         Ok(plugin) => {
                             log::info!("Successfully registered SkSL plugin: {}", plugin.id());
+                            let plugin_id = plugin.id().to_string();
                             self.register_effect(Arc::new(plugin));
+                            self.inner
+                                .write()
+                                .unwrap()
+                                .plugin_sources
+                                .insert(plugin_id, PluginSource::Sksl(path));
                         }
                         Err(e) => {
                             log::error!("Failed to load SkSL plugin at {}: {}", path.display(), e);
+                            self.record_load_failure(PluginSource::Sksl(path), e.to_string());
                         }
                     }
                 } else {
@@ -592,7 +665,8 @@ impl PluginManager {
             .collect()
     }
 
-    pub fn get_all_plugins(&self) -> Vec<PluginInfo> {
+    /// All registered plugins, followed by plugin files that failed to load.
+    pub fn list_plugins(&self) -> Vec<PluginInfo> {
         let inner = self.inner.read().unwrap();
         let mut plugins = Vec::new();
 
@@ -603,10 +677,16 @@ impl PluginManager {
                     plugins.push(PluginInfo {
                         id: p.id().to_string(),
                         name: p.name(),
-                        plugin_type: p.plugin_type(),
+                        plugin_type: Some(p.plugin_type()),
                         category: p.category(),
                         version: format!("{}.{}.{}", v.0, v.1, v.2),
                         impl_type: p.impl_type(),
+                        loaded_from: inner
+                            .plugin_sources
+                            .get(p.id())
+                            .cloned()
+                            .unwrap_or(PluginSource::Builtin),
+                        status: PluginStatus::Active,
                     });
                 }
             };
@@ -615,11 +695,40 @@ impl PluginManager {
         collect_plugins!(inner.effect_plugins);
         collect_plugins!(inner.load_plugins);
         collect_plugins!(inner.export_plugins);
+        collect_plugins!(inner.audio_effect_plugins);
 
         plugins.sort_by(|a, b| a.id.cmp(&b.id));
+
+        for (source, error) in &inner.load_failures {
+            let name = match source {
+                PluginSource::DynamicLibrary(path) | PluginSource::Sksl(path) => path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| path.display().to_string()),
+                PluginSource::Builtin => String::new(),
+            };
+            plugins.push(PluginInfo {
+                id: name.clone(),
+                name,
+                plugin_type: None,
+                category: String::new(),
+                version: String::new(),
+                impl_type: String::new(),
+                loaded_from: source.clone(),
+                status: PluginStatus::LoadFailed(error.clone()),
+            });
+        }
         plugins
     }
 
+    fn record_load_failure(&self, source: PluginSource, error: String) {
+        self.inner
+            .write()
+            .unwrap()
+            .load_failures
+            .push((source, error));
+    }
+
     // ==================== Node Type Definition Methods ====================
 
     /// Register a node type definition.
@@ -780,13 +889,16 @@ fn property_ui_to_pin_data_type(
     ui_type.pin_data_type()
 }
 
-/// Information about a registered plugin.
+/// Information about a registered plugin or a plugin file that failed to load.
 #[derive(Debug, Clone)]
 pub struct PluginInfo {
     pub id: String,
     pub name: String,
-    pub plugin_type: PluginCategory,
+    /// `None` when loading failed before the plugin type was known.
+    pub plugin_type: Option<PluginCategory>,
     pub category: String,
     pub version: String,
     pub impl_type: String,
+    pub loaded_from: PluginSource,
+    pub status: PluginStatus,
 }
@@ -20,7 +20,9 @@ pub use traits::{Plugin, PropertyPlugin};
 pub use crate::builtin::decorators::DecoratorPlugin;
 pub use crate::builtin::effectors::EffectorPlugin;
 pub use crate::builtin::effects::{EffectDefinition, EffectPlugin, EffectRepository};
-pub use crate::builtin::exporters::{ExportFormat, ExportPlugin, ExportRepository, ExportSettings};
+pub use crate::builtin::exporters::{
+    AudioSource, ExportFormat, ExportPlugin, ExportRepository, ExportSettings, HwAccelBackend,
+};
 pub use crate::builtin::loaders::{
     AssetMetadata, LoadPlugin, LoadRepository, LoadRequest, LoadResponse,
 };
@@ -28,15 +30,26 @@ pub use crate::builtin::styles::StylePlugin;
 
 // Re-export concrete implementations for backward compatibility
 pub use crate::builtin::effects::blur::BlurEffectPlugin;
+pub use crate::builtin::effects::chromatic_aberration::ChromaticAberrationEffectPlugin;
+pub use crate::builtin::effects::color_curves::ColorCurvesEffectPlugin;
 pub use crate::builtin::effects::dilate::DilateEffectPlugin;
 pub use crate::builtin::effects::drop_shadow::DropShadowEffectPlugin;
 pub use crate::builtin::effects::erode::ErodeEffectPlugin;
+pub use crate::builtin::effects::glow::GlowEffectPlugin;
+pub use crate::builtin::effects::lut::LutEffectPlugin;
 pub use crate::builtin::effects::magnifier::MagnifierEffectPlugin;
 pub use crate::builtin::effects::tile::TileEffectPlugin;
+pub use crate::builtin::effects::time_echo::TimeEchoEffectPlugin;
+pub use crate::builtin::effects::vignette::VignetteEffectPlugin;
 pub use crate::builtin::exporters::ffmpeg_export::FfmpegExportPlugin;
+pub use crate::builtin::exporters::gif_export::GifExportPlugin;
 pub use crate::builtin::exporters::png_export::PngExportPlugin;
+pub use crate::builtin::exporters::webp_export::WebpExportPlugin;
+pub use crate::builtin::loaders::exr_loader::ExrLoader;
 pub use crate::builtin::loaders::ffmpeg_video::FfmpegVideoLoader;
 pub use crate::builtin::loaders::native_image::NativeImageLoader;
+pub use crate::builtin::loaders::pdf_loader::PdfLoader;
+pub use crate::builtin::loaders::svg_loader::SvgLoader;
 pub use crate::builtin::properties::{
     ConstantPropertyPlugin, ExpressionPropertyPlugin, KeyframePropertyPlugin,
 };
@@ -51,4 +64,21 @@ pub enum PluginCategory {
     Effector,
     Decorator,
     Style,
+    AudioEffect,
+}
+
+/// Where a registered plugin came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PluginSource {
+    Builtin,
+    DynamicLibrary(std::path::PathBuf),
+    /// SkSL effect loaded from a shader directory.
+    Sksl(std::path::PathBuf),
+}
+
+/// Load state of a plugin.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PluginStatus {
+    Active,
+    LoadFailed(String),
 }
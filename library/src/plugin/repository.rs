@@ -5,13 +5,14 @@ use std::sync::Arc;
 
 use libloading::Library;
 
+use crate::audio::effects::AudioEffect;
 use crate::builtin::effects::EffectPlugin;
 use crate::builtin::exporters::ExportPlugin;
 use crate::builtin::loaders::LoadRepository;
 use crate::plugin::evaluator::PropertyEvaluatorRegistry;
 use crate::plugin::node_types::NodeTypeDefinition;
 use crate::plugin::traits::Plugin;
-use crate::plugin::{DecoratorPlugin, EffectorPlugin, StylePlugin};
+use crate::plugin::{DecoratorPlugin, EffectorPlugin, PluginSource, StylePlugin};
 
 /// Generic container for plugins of a specific type.
 pub struct PluginRepository<T: ?Sized> {
@@ -46,7 +47,12 @@ pub(crate) struct PluginRegistry {
     pub effector_plugins: PluginRepository<dyn EffectorPlugin>,
     pub decorator_plugins: PluginRepository<dyn DecoratorPlugin>,
     pub style_plugins: PluginRepository<dyn StylePlugin>,
+    pub audio_effect_plugins: PluginRepository<dyn AudioEffect>,
     pub property_evaluators: PropertyEvaluatorRegistry,
     pub node_types: HashMap<String, NodeTypeDefinition>,
     pub dynamic_libraries: Vec<Library>,
+    /// Origin of plugins not compiled into the binary, keyed by plugin ID.
+    pub plugin_sources: HashMap<String, PluginSource>,
+    /// Plugin files that could not be loaded, with the reason.
+    pub load_failures: Vec<(PluginSource, String)>,
 }
@@ -5,6 +5,22 @@ use serde::{Deserialize, Serialize};
 use ordered_float::OrderedFloat;
 use std::hash::{Hash, Hasher};
 
+use crate::error::LibraryError;
+
+/// Where the jumps of a `Step` easing happen, as in CSS `steps()`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum StepPosition {
+    /// Jump at the start of each interval (`jump-start`).
+    Start,
+    /// Jump at the end of each interval (`jump-end`).
+    #[default]
+    End,
+    /// Jump at both ends, adding one extra step (`jump-both`).
+    Both,
+    /// Hold both the first and last value (`jump-none`).
+    None,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, Default)] // Removed PartialEq, Eq, Hash, Copy; Added Default
 pub enum EasingFunction {
     #[default]
@@ -71,6 +87,17 @@ pub enum EasingFunction {
         n1: f64,
         d1: f64,
     },
+    // Spring
+    Spring {
+        mass: f64,
+        stiffness: f64,
+        damping: f64,
+    },
+    // Step
+    Step {
+        count: u32,
+        position: StepPosition,
+    },
     // Custom
     SimpleBezier {
         start: (f64, f64),
@@ -86,6 +113,10 @@ pub enum EasingFunction {
 }
 
 impl EasingFunction {
+    /// Smallest spring stiffness used by [`EasingFunction::Spring`]; a spring
+    /// without stiffness never moves towards the end value.
+    pub const MIN_SPRING_STIFFNESS: f64 = 0.01;
+
     pub fn apply(&self, t: f64) -> f64 {
         match self {
             EasingFunction::Linear => t,
@@ -220,6 +251,52 @@ impl EasingFunction {
                     (1.0 + Self::bounce_out(2.0 * t - 1.0, *n1, *d1)) / 2.0
                 }
             }
+            EasingFunction::Spring {
+                mass,
+                stiffness,
+                damping,
+            } => {
+                if *mass <= 0.0 || t >= 1.0 {
+                    return t.min(1.0);
+                }
+                let stiffness = stiffness.max(Self::MIN_SPRING_STIFFNESS);
+                // Dividing by the mass gives the equivalent unit-mass spring
+                let position =
+                    |t: f64| spring_position(0.0, 1.0, stiffness / mass, damping / mass, 0.0, t);
+                // The spring has not always settled at t = 1; rescale so the curve
+                // runs exactly from p(0) to p(1) and ends on the end value
+                let span = position(1.0) - position(0.0);
+                if span.abs() < f64::EPSILON {
+                    return t;
+                }
+                let value = (position(t) - position(0.0)) / span;
+                let d = damping / (2.0 * mass);
+                if stiffness / mass - d * d > 0.0 {
+                    // Underdamped springs may overshoot
+                    value
+                } else {
+                    value.clamp(0.0, 1.0)
+                }
+            }
+            EasingFunction::Step { count, position } => {
+                let count = *count as f64;
+                let jumps = match position {
+                    StepPosition::Start | StepPosition::End => count,
+                    StepPosition::Both => count + 1.0,
+                    StepPosition::None => count - 1.0,
+                };
+                if jumps < 1.0 {
+                    return t;
+                }
+                let mut step = (t * count).floor();
+                if matches!(position, StepPosition::Start | StepPosition::Both) {
+                    step += 1.0;
+                }
+                if (0.0..=1.0).contains(&t) {
+                    step = step.clamp(0.0, jumps);
+                }
+                step / jumps
+            }
             EasingFunction::SimpleBezier { start, end } => {
                 let max_iterations = 16;
                 let epsilon = 1e-6;
@@ -353,6 +430,77 @@ impl EasingFunction {
         }
     }
 
+    /// Convert to a CSS timing function such as `cubic-bezier(0.25, 0.1, 0.25, 1)`.
+    ///
+    /// The Sine to Expo presets map to their usual cubic-bezier approximations.
+    /// Returns `None` for variants that have no CSS equivalent.
+    pub fn to_css_string(&self) -> Option<String> {
+        let (x1, y1, x2, y2) = match self {
+            EasingFunction::Linear => return Some("linear".to_string()),
+            // SimpleBezier stores its control points as (value, time)
+            EasingFunction::SimpleBezier { start, end } => (start.1, start.0, end.1, end.0),
+            EasingFunction::EaseInSine => (0.12, 0.0, 0.39, 0.0),
+            EasingFunction::EaseOutSine => (0.61, 1.0, 0.88, 1.0),
+            EasingFunction::EaseInOutSine => (0.37, 0.0, 0.63, 1.0),
+            EasingFunction::EaseInQuad => (0.11, 0.0, 0.5, 0.0),
+            EasingFunction::EaseOutQuad => (0.5, 1.0, 0.89, 1.0),
+            EasingFunction::EaseInOutQuad => (0.45, 0.0, 0.55, 1.0),
+            EasingFunction::EaseInCubic => (0.32, 0.0, 0.67, 0.0),
+            EasingFunction::EaseOutCubic => (0.33, 1.0, 0.68, 1.0),
+            EasingFunction::EaseInOutCubic => (0.65, 0.0, 0.35, 1.0),
+            EasingFunction::EaseInQuart => (0.5, 0.0, 0.75, 0.0),
+            EasingFunction::EaseOutQuart => (0.25, 1.0, 0.5, 1.0),
+            EasingFunction::EaseInOutQuart => (0.76, 0.0, 0.24, 1.0),
+            EasingFunction::EaseInQuint => (0.64, 0.0, 0.78, 0.0),
+            EasingFunction::EaseOutQuint => (0.22, 1.0, 0.36, 1.0),
+            EasingFunction::EaseInOutQuint => (0.83, 0.0, 0.17, 1.0),
+            EasingFunction::EaseInExpo => (0.7, 0.0, 0.84, 0.0),
+            EasingFunction::EaseOutExpo => (0.16, 1.0, 0.3, 1.0),
+            EasingFunction::EaseInOutExpo => (0.87, 0.0, 0.13, 1.0),
+            _ => return None,
+        };
+        Some(format!("cubic-bezier({}, {}, {}, {})", x1, y1, x2, y2))
+    }
+
+    /// Parse a CSS timing function: `linear`, `ease`, `ease-in`, `ease-out`,
+    /// `ease-in-out` or `cubic-bezier(x1, y1, x2, y2)`.
+    pub fn from_css_string(s: &str) -> Result<Self, LibraryError> {
+        let s = s.trim();
+        let bezier = |x1: f64, y1: f64, x2: f64, y2: f64| EasingFunction::SimpleBezier {
+            start: (y1, x1),
+            end: (y2, x2),
+        };
+        match s.to_ascii_lowercase().as_str() {
+            "linear" => return Ok(EasingFunction::Linear),
+            "ease" => return Ok(bezier(0.25, 0.1, 0.25, 1.0)),
+            "ease-in" => return Ok(bezier(0.42, 0.0, 1.0, 1.0)),
+            "ease-out" => return Ok(bezier(0.0, 0.0, 0.58, 1.0)),
+            "ease-in-out" => return Ok(bezier(0.42, 0.0, 0.58, 1.0)),
+            _ => {}
+        }
+
+        let invalid = || LibraryError::InvalidArgument(format!("Invalid CSS easing: '{}'", s));
+        let args = s
+            .strip_prefix("cubic-bezier(")
+            .and_then(|rest| rest.strip_suffix(')'))
+            .ok_or_else(invalid)?;
+        let values = args
+            .split(',')
+            .map(|v| v.trim().parse::<f64>().map_err(|_| invalid()))
+            .collect::<Result<Vec<f64>, _>>()?;
+        match values[..] {
+            [x1, y1, x2, y2]
+                if (0.0..=1.0).contains(&x1)
+                    && (0.0..=1.0).contains(&x2)
+                    && y1.is_finite()
+                    && y2.is_finite() =>
+            {
+                Ok(bezier(x1, y1, x2, y2))
+            }
+            _ => Err(invalid()),
+        }
+    }
+
     fn bounce_out(t: f64, n1: f64, d1: f64) -> f64 {
         if t < 1.0 / d1 {
             n1 * t * t
@@ -443,6 +591,32 @@ impl PartialEq for EasingFunction {
                 EasingFunction::EaseInOutBounce { n1: a, d1: b },
                 EasingFunction::EaseInOutBounce { n1: c, d1: d },
             ) => OrderedFloat(*a) == OrderedFloat(*c) && OrderedFloat(*b) == OrderedFloat(*d),
+            (
+                EasingFunction::Spring {
+                    mass: m1,
+                    stiffness: k1,
+                    damping: d1,
+                },
+                EasingFunction::Spring {
+                    mass: m2,
+                    stiffness: k2,
+                    damping: d2,
+                },
+            ) => {
+                OrderedFloat(*m1) == OrderedFloat(*m2)
+                    && OrderedFloat(*k1) == OrderedFloat(*k2)
+                    && OrderedFloat(*d1) == OrderedFloat(*d2)
+            }
+            (
+                EasingFunction::Step {
+                    count: c1,
+                    position: p1,
+                },
+                EasingFunction::Step {
+                    count: c2,
+                    position: p2,
+                },
+            ) => c1 == c2 && p1 == p2,
             (
                 EasingFunction::SimpleBezier { start: s1, end: e1 },
                 EasingFunction::SimpleBezier { start: s2, end: e2 },
@@ -489,6 +663,19 @@ impl Hash for EasingFunction {
                 OrderedFloat(*n1).hash(state);
                 OrderedFloat(*d1).hash(state);
             }
+            EasingFunction::Spring {
+                mass,
+                stiffness,
+                damping,
+            } => {
+                OrderedFloat(*mass).hash(state);
+                OrderedFloat(*stiffness).hash(state);
+                OrderedFloat(*damping).hash(state);
+            }
+            EasingFunction::Step { count, position } => {
+                count.hash(state);
+                position.hash(state);
+            }
             EasingFunction::SimpleBezier { start, end } => {
                 OrderedFloat(start.0).hash(state);
                 OrderedFloat(start.1).hash(state);
@@ -508,3 +695,46 @@ impl Hash for EasingFunction {
         }
     }
 }
+
+/// Position of a unit-mass spring at time `t` (seconds).
+///
+/// Underdamped: `x(t) = target + A·exp(-ζω₀t)·cos(ω_d·t + φ)`; critically damped
+/// and overdamped springs use their respective closed forms.
+pub fn spring_position(
+    initial: f64,
+    target: f64,
+    stiffness: f64,
+    damping: f64,
+    initial_velocity: f64,
+    t: f64,
+) -> f64 {
+    if t <= 0.0 || !t.is_finite() || stiffness <= 0.0 {
+        return initial;
+    }
+
+    let x0 = initial - target;
+    let omega0 = stiffness.sqrt();
+    let zeta = damping.max(0.0) / (2.0 * omega0);
+
+    let offset = if (zeta - 1.0).abs() < 1e-9 {
+        // Critically damped
+        (x0 + (initial_velocity + omega0 * x0) * t) * (-omega0 * t).exp()
+    } else if zeta < 1.0 {
+        // Underdamped: A·exp(-ζω₀t)·cos(ω_d·t + φ)
+        let omega_d = omega0 * (1.0 - zeta * zeta).sqrt();
+        let b = (initial_velocity + zeta * omega0 * x0) / omega_d;
+        let amplitude = (x0 * x0 + b * b).sqrt();
+        let phase = (-b).atan2(x0);
+        amplitude * (-zeta * omega0 * t).exp() * (omega_d * t + phase).cos()
+    } else {
+        // Overdamped: sum of two decaying exponentials
+        let root = (zeta * zeta - 1.0).sqrt();
+        let r1 = -omega0 * (zeta - root);
+        let r2 = -omega0 * (zeta + root);
+        let c2 = (initial_velocity - r1 * x0) / (r2 - r1);
+        let c1 = x0 - c2;
+        c1 * (r1 * t).exp() + c2 * (r2 * t).exp()
+    };
+
+    target + offset
+}
@@ -18,6 +18,12 @@ pub struct Asset {
     pub color: Color,
     #[serde(default)]
     pub stream_index: Option<usize>,
+    /// Embedded ICC profile, applied when the asset's frames are composited.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icc_profile: Option<Vec<u8>>,
+    /// Channel layers of multi-layer images (EXR), read once at import.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub channel_layers: Vec<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
@@ -47,6 +53,8 @@ impl Asset {
                 a: 255,
             }, // Default gray
             stream_index: None,
+            icc_profile: None,
+            channel_layers: Vec::new(),
         }
     }
 }
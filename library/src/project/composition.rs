@@ -1,8 +1,27 @@
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
 use uuid::Uuid;
 
 use crate::runtime::color::Color;
 
+/// Audio sample rate used when a composition does not specify one.
+pub const DEFAULT_AUDIO_SAMPLE_RATE: u32 = 48000;
+
+fn default_audio_sample_rate() -> u32 {
+    DEFAULT_AUDIO_SAMPLE_RATE
+}
+
+/// Node editor layout of a composition's graph, saved with the project.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct NodeLayout {
+    /// Graph-space `[x, y]` position per node.
+    pub positions: HashMap<Uuid, [f32; 2]>,
+    /// Subgraphs shown folded into a single node.
+    #[serde(default, skip_serializing_if = "HashSet::is_empty")]
+    pub collapsed_groups: HashSet<Uuid>,
+}
+
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub struct Composition {
     pub id: Uuid,
@@ -13,10 +32,15 @@ pub struct Composition {
     pub duration: f64,
     pub background_color: Color,
     pub color_profile: String,
-    #[serde(default)]
-    pub work_area_in: u64,
-    #[serde(default)]
-    pub work_area_out: u64,
+    /// First frame of the work area. `None` starts at frame 0.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub work_area_in: Option<u64>,
+    /// Frame the work area ends before. `None` runs to the end of the composition.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub work_area_out: Option<u64>,
+    /// Sample rate (Hz) the composition's audio is mixed and exported at.
+    #[serde(default = "default_audio_sample_rate")]
+    pub audio_sample_rate: u32,
     /// Child nodes (tracks) owned by this composition.
     #[serde(default)]
     pub child_ids: Vec<Uuid>,
@@ -24,6 +48,9 @@ pub struct Composition {
     /// On load, `Project::migrate()` converts this into `child_ids`.
     #[serde(default, skip_serializing)]
     pub root_track_id: Option<Uuid>,
+    /// Node positions in the node editor. Not part of the undo history.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node_layout: Option<NodeLayout>,
 }
 
 impl Composition {
@@ -43,10 +70,26 @@ impl Composition {
                 a: 255,
             },
             color_profile: "sRGB".to_string(),
-            work_area_in: 0,
-            work_area_out: (duration * fps).ceil() as u64,
+            work_area_in: None,
+            work_area_out: None,
+            audio_sample_rate: DEFAULT_AUDIO_SAMPLE_RATE,
             child_ids: Vec::new(),
             root_track_id: None,
+            node_layout: None,
         }
     }
+
+    /// Total number of frames in the composition.
+    pub fn total_frames(&self) -> u64 {
+        (self.duration * self.fps).ceil() as u64
+    }
+
+    /// Frames covered by the work area, falling back to the whole composition
+    /// for an unset end. The range is never inverted.
+    pub fn work_area_range(&self) -> Range<u64> {
+        let total = self.total_frames();
+        let end = self.work_area_out.unwrap_or(total);
+        let start = self.work_area_in.unwrap_or(0).min(end);
+        start..end
+    }
 }
@@ -496,7 +496,11 @@ mod tests {
             source_begin_frame: 0,
             duration_frame: None,
             fps: 30.0,
+            speed: 1.0,
             properties: PropertyMap::new(),
+            blend_mode: Default::default(),
+            label_color: None,
+            audio_effects: Vec::new(),
         };
         let clip_id = clip.id;
 
@@ -531,7 +535,11 @@ mod tests {
             source_begin_frame: 0,
             duration_frame: None,
             fps: 30.0,
+            speed: 1.0,
             properties: PropertyMap::new(),
+            blend_mode: Default::default(),
+            label_color: None,
+            audio_effects: Vec::new(),
         };
         let clip_id = clip.id;
 
@@ -594,7 +602,11 @@ mod tests {
             source_begin_frame: 0,
             duration_frame: None,
             fps: 30.0,
+            speed: 1.0,
             properties: PropertyMap::new(),
+            blend_mode: Default::default(),
+            label_color: None,
+            audio_effects: Vec::new(),
         };
         let clip_id = clip.id;
 
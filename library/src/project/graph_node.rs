@@ -1,9 +1,19 @@
 //! Generic graph node for data-flow graph.
 
-use crate::project::property::PropertyMap;
+use crate::project::property::{Property, PropertyMap, PropertyValue};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+/// `type_id` of graph nodes that group other nodes into a subgraph.
+pub const SUBGRAPH_TYPE_ID: &str = "graph.subgraph";
+
+/// `type_id` of reroute nodes: cable junctions that pass their input through unchanged.
+pub const REROUTE_TYPE_ID: &str = "graph.reroute";
+/// Input pin of a reroute node.
+pub const REROUTE_INPUT_PIN: &str = "in";
+/// Output pin of a reroute node.
+pub const REROUTE_OUTPUT_PIN: &str = "out";
+
 /// A generic graph node that can represent any node type in the data-flow graph.
 ///
 /// Instead of having separate types for effects, styles, effectors, etc.,
@@ -17,6 +27,9 @@ pub struct GraphNode {
     /// Examples: "effect.blur", "style.fill", "math.add", "effector.transform"
     pub type_id: String,
     pub properties: PropertyMap,
+    /// Nodes grouped inside this node. Only subgraphs (`SUBGRAPH_TYPE_ID`) have children.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub child_ids: Vec<Uuid>,
 }
 
 impl GraphNode {
@@ -25,14 +38,37 @@ impl GraphNode {
             id: Uuid::new_v4(),
             type_id: type_id.to_string(),
             properties,
+            child_ids: Vec::new(),
+        }
+    }
+
+    /// Create a subgraph named `name` that groups `child_ids`.
+    pub fn new_subgraph(name: &str, child_ids: Vec<Uuid>) -> Self {
+        let mut properties = PropertyMap::new();
+        properties.set(
+            "name".to_string(),
+            Property::constant(PropertyValue::String(name.to_string())),
+        );
+        Self {
+            child_ids,
+            ..Self::new(SUBGRAPH_TYPE_ID, properties)
         }
     }
 
+    pub fn is_subgraph(&self) -> bool {
+        self.type_id == SUBGRAPH_TYPE_ID
+    }
+
+    pub fn is_reroute(&self) -> bool {
+        self.type_id == REROUTE_TYPE_ID
+    }
+
     pub fn new_with_id(id: Uuid, type_id: &str, properties: PropertyMap) -> Self {
         Self {
             id,
             type_id: type_id.to_string(),
             properties,
+            child_ids: Vec::new(),
         }
     }
 }
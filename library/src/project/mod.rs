@@ -10,6 +10,7 @@ pub mod graph_node;
 pub mod layer;
 pub(crate) mod migration;
 pub mod node;
+mod otio;
 pub mod project;
 pub mod property;
 pub mod source;
@@ -0,0 +1,215 @@
+//! OpenTimelineIO JSON export.
+//!
+//! Builds the OTIO schema directly with `serde_json`, so no OTIO library is needed.
+
+use serde_json::{Value, json};
+use uuid::Uuid;
+
+use super::node::Node;
+use super::project::Project;
+use super::source::{SourceData, SourceKind};
+use crate::error::LibraryError;
+
+impl Project {
+    /// Serialize a composition as an OpenTimelineIO timeline (`.otio` JSON).
+    ///
+    /// Every container that directly holds sources becomes a track. Overlapping
+    /// sources within a container are spread over extra tracks, because OTIO
+    /// tracks are strictly sequential. Sources without a media file (text,
+    /// shapes, shaders, nested compositions) are exported with a missing reference.
+    pub fn export_otio_json(&self, comp_id: Uuid) -> Result<String, LibraryError> {
+        let composition = self
+            .get_composition(comp_id)
+            .ok_or_else(|| LibraryError::project(format!("Composition {} not found", comp_id)))?;
+        let fps = composition.fps;
+
+        let mut rows: Vec<(String, Vec<&SourceData>)> = Vec::new();
+        for &child_id in &composition.child_ids {
+            self.collect_otio_rows(child_id, &mut rows);
+        }
+
+        let mut tracks = Vec::new();
+        for (name, mut sources) in rows {
+            sources.sort_by_key(|s| s.in_frame);
+            let mut lanes: Vec<Vec<&SourceData>> = Vec::new();
+            for source in sources {
+                match lanes.iter_mut().find(|lane| {
+                    lane.last()
+                        .is_none_or(|last| last.out_frame <= source.in_frame)
+                }) {
+                    Some(lane) => lane.push(source),
+                    None => lanes.push(vec![source]),
+                }
+            }
+            for lane in lanes {
+                tracks.push(otio_track(&name, &lane, fps));
+            }
+        }
+
+        let timeline = json!({
+            "OTIO_SCHEMA": "Timeline.1",
+            "name": composition.name,
+            "metadata": {},
+            "global_start_time": rational_time(0.0, fps),
+            "tracks": {
+                "OTIO_SCHEMA": "Stack.1",
+                "name": "tracks",
+                "metadata": {},
+                "source_range": null,
+                "effects": [],
+                "markers": [],
+                "children": tracks,
+            },
+        });
+        Ok(serde_json::to_string_pretty(&timeline)?)
+    }
+
+    fn collect_otio_rows<'a>(
+        &'a self,
+        node_id: Uuid,
+        rows: &mut Vec<(String, Vec<&'a SourceData>)>,
+    ) {
+        let (name, child_ids) = match self.nodes.get(&node_id) {
+            Some(Node::Track(t)) => (&t.name, &t.child_ids),
+            Some(Node::Layer(l)) => (&l.name, &l.child_ids),
+            _ => return,
+        };
+        let sources: Vec<&SourceData> = child_ids
+            .iter()
+            .filter_map(|id| self.get_source(*id))
+            .collect();
+        if !sources.is_empty() {
+            rows.push((name.clone(), sources));
+        }
+        for &child_id in child_ids {
+            self.collect_otio_rows(child_id, rows);
+        }
+    }
+}
+
+fn rational_time(value: f64, rate: f64) -> Value {
+    json!({ "OTIO_SCHEMA": "RationalTime.1", "rate": rate, "value": value })
+}
+
+fn time_range(start: f64, duration: f64, rate: f64) -> Value {
+    json!({
+        "OTIO_SCHEMA": "TimeRange.1",
+        "start_time": rational_time(start, rate),
+        "duration": rational_time(duration, rate),
+    })
+}
+
+/// Build a sequential OTIO track, filling the space between sources with gaps.
+fn otio_track(name: &str, sources: &[&SourceData], fps: f64) -> Value {
+    let mut children = Vec::new();
+    let mut cursor = 0u64;
+    for source in sources {
+        if source.in_frame > cursor {
+            children.push(json!({
+                "OTIO_SCHEMA": "Gap.1",
+                "name": "",
+                "metadata": {},
+                "source_range": time_range(0.0, (source.in_frame - cursor) as f64, fps),
+                "effects": [],
+                "markers": [],
+            }));
+        }
+        children.push(otio_clip(source, fps));
+        cursor = source.out_frame.max(source.in_frame);
+    }
+
+    let kind = if sources.iter().all(|s| s.kind == SourceKind::Audio) {
+        "Audio"
+    } else {
+        "Video"
+    };
+    json!({
+        "OTIO_SCHEMA": "Track.1",
+        "name": name,
+        "kind": kind,
+        "metadata": {},
+        "source_range": null,
+        "effects": [],
+        "markers": [],
+        "children": children,
+    })
+}
+
+fn otio_clip(source: &SourceData, fps: f64) -> Value {
+    // Source ranges are expressed in the media's own frame rate
+    let duration_frames = source.out_frame.saturating_sub(source.in_frame) as f64;
+    let source_range = time_range(
+        source.source_begin_frame as f64,
+        duration_frames / fps * source.fps,
+        source.fps,
+    );
+
+    let file_path = source
+        .properties
+        .get_string("file_path")
+        .filter(|path| !path.is_empty());
+    let has_media = matches!(
+        source.kind,
+        SourceKind::Video | SourceKind::Image | SourceKind::Audio
+    );
+    let (name, media_reference) = match file_path {
+        Some(path) if has_media => {
+            let name = std::path::Path::new(&path)
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_else(|| path.clone());
+            let available_range = source
+                .duration_frame
+                .map(|frames| time_range(0.0, frames as f64, source.fps));
+            let reference = json!({
+                "OTIO_SCHEMA": "ExternalReference.1",
+                "name": "",
+                "metadata": {},
+                "available_range": available_range,
+                "target_url": file_url(&path),
+            });
+            (name, reference)
+        }
+        _ => (
+            source.kind.to_string(),
+            json!({
+                "OTIO_SCHEMA": "MissingReference.1",
+                "name": "",
+                "metadata": {},
+                "available_range": null,
+            }),
+        ),
+    };
+
+    // Speed changes map to OTIO's LinearTimeWarp; negative scalars play in reverse.
+    let effects = if source.speed != 1.0 {
+        vec![json!({
+            "OTIO_SCHEMA": "LinearTimeWarp.1",
+            "name": "",
+            "metadata": {},
+            "effect_name": "LinearTimeWarp",
+            "time_scalar": source.speed,
+        })]
+    } else {
+        Vec::new()
+    };
+
+    json!({
+        "OTIO_SCHEMA": "Clip.1",
+        "name": name,
+        "metadata": { "video_editor": { "source_id": source.id.to_string() } },
+        "source_range": source_range,
+        "media_reference": media_reference,
+        "effects": effects,
+        "markers": [],
+    })
+}
+
+fn file_url(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    if path.starts_with('/') {
+        format!("file://{}", path)
+    } else {
+        format!("file:///{}", path)
+    }
+}
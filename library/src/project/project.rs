@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use uuid::Uuid;
 
 use serde::{Deserialize, Serialize};
@@ -31,6 +32,10 @@ pub struct Project {
     pub nodes: HashMap<Uuid, Node>,
     #[serde(default)]
     pub connections: Vec<Connection>,
+    /// Directory of the project file, once loaded from or saved to disk.
+    /// Relative file paths in properties resolve against it.
+    #[serde(skip)]
+    pub file_dir: Option<PathBuf>,
 }
 
 impl Project {
@@ -43,9 +48,32 @@ impl Project {
             export: ExportConfig::default(),
             nodes: HashMap::new(),
             connections: Vec::new(),
+            file_dir: None,
         }
     }
 
+    /// Resolve a file path stored in a property: relative paths are relative
+    /// to the project file. Returned unchanged while the project is unsaved.
+    pub fn resolve_file_path(&self, path: &str) -> String {
+        match &self.file_dir {
+            Some(dir) if !path.is_empty() && Path::new(path).is_relative() => {
+                dir.join(path).to_string_lossy().into_owned()
+            }
+            _ => path.to_string(),
+        }
+    }
+
+    /// How a picked file is stored in a property: relative to the project
+    /// file when it lies in the project's directory, otherwise as given.
+    pub fn store_file_path(&self, path: &Path) -> String {
+        self.file_dir
+            .as_deref()
+            .and_then(|dir| path.strip_prefix(dir).ok())
+            .unwrap_or(path)
+            .to_string_lossy()
+            .into_owned()
+    }
+
     pub fn load(json_str: &str) -> Result<Self, serde_json::Error> {
         let mut project: Project = serde_json::from_str(json_str)?;
         project.migrate();
@@ -100,6 +128,20 @@ impl Project {
             self.nodes.remove(&root_track_id);
         }
 
+        // Older saves always wrote a work area (0..0 or 0..total); treat
+        // those defaults as unset so the composition follows its length.
+        for node in self.nodes.values_mut() {
+            if let Node::Composition(c) = node {
+                let total = c.total_frames();
+                if c.work_area_in.unwrap_or(0) == 0
+                    && matches!(c.work_area_out, Some(out) if out == 0 || out == total)
+                {
+                    c.work_area_in = None;
+                    c.work_area_out = None;
+                }
+            }
+        }
+
         // Migrate is_layer tracks into Node::Layer
         let layer_ids: Vec<Uuid> = self
             .nodes
@@ -299,6 +341,63 @@ impl Project {
         }
     }
 
+    /// Find every clip that uses an asset, as `(composition_id, container_id, source_id)`.
+    ///
+    /// A clip matches when it references the asset directly or when its `file_path`
+    /// property points at the asset's file. Clips referencing another asset of the
+    /// same file (another stream) belong to that asset. `container_id` is the
+    /// track or layer that holds the clip.
+    pub fn find_clips_by_asset(&self, asset_id: Uuid) -> Vec<(Uuid, Uuid, Uuid)> {
+        let asset_path = self
+            .assets
+            .iter()
+            .find(|a| a.id == asset_id)
+            .map(|a| a.path.as_str())
+            .filter(|path| !path.is_empty());
+
+        let mut found = Vec::new();
+        for comp in self.all_compositions() {
+            self.find_clips_by_asset_recursive(comp.id, comp.id, asset_id, asset_path, &mut found);
+        }
+        found
+    }
+
+    fn find_clips_by_asset_recursive(
+        &self,
+        comp_id: Uuid,
+        container_id: Uuid,
+        asset_id: Uuid,
+        asset_path: Option<&str>,
+        found: &mut Vec<(Uuid, Uuid, Uuid)>,
+    ) {
+        let Some(children) = self.get_container_child_ids(container_id) else {
+            return;
+        };
+        for &child_id in children {
+            match self.nodes.get(&child_id) {
+                Some(Node::Source(source)) => {
+                    let other_asset = source
+                        .reference_id
+                        .is_some_and(|id| id != asset_id && self.assets.iter().any(|a| a.id == id));
+                    let uses_asset = source.reference_id == Some(asset_id)
+                        || (!other_asset
+                            && asset_path.is_some_and(|path| {
+                                source.properties.get_string("file_path").as_deref() == Some(path)
+                            }));
+                    if uses_asset {
+                        found.push((comp_id, container_id, child_id));
+                    }
+                }
+                Some(Node::Track(_)) | Some(Node::Layer(_)) => {
+                    self.find_clips_by_asset_recursive(
+                        comp_id, child_id, asset_id, asset_path, found,
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
+
     /// Iterate over all sources in the registry
     pub fn all_sources(&self) -> impl Iterator<Item = &SourceData> {
         self.nodes.values().filter_map(|node| match node {
@@ -315,13 +414,14 @@ impl Project {
         })
     }
 
-    /// Find the parent container (composition, track, or layer) of a given child node
+    /// Find the parent container (composition, track, layer, or subgraph) of a given child node
     pub fn find_parent_container(&self, child_id: Uuid) -> Option<Uuid> {
         for (id, node) in &self.nodes {
             match node {
                 Node::Composition(c) if c.child_ids.contains(&child_id) => return Some(*id),
                 Node::Track(track) if track.child_ids.contains(&child_id) => return Some(*id),
                 Node::Layer(layer) if layer.child_ids.contains(&child_id) => return Some(*id),
+                Node::Graph(g) if g.child_ids.contains(&child_id) => return Some(*id),
                 _ => {}
             }
         }
@@ -333,22 +433,24 @@ impl Project {
         self.find_parent_container(child_id)
     }
 
-    /// Get child_ids from any container node (Composition, Track, or Layer).
+    /// Get child_ids from any container node (Composition, Track, Layer, or subgraph).
     pub fn get_container_child_ids(&self, id: Uuid) -> Option<&Vec<Uuid>> {
         match self.nodes.get(&id)? {
             Node::Composition(c) => Some(&c.child_ids),
             Node::Track(t) => Some(&t.child_ids),
             Node::Layer(l) => Some(&l.child_ids),
+            Node::Graph(g) if g.is_subgraph() => Some(&g.child_ids),
             _ => None,
         }
     }
 
-    /// Get mutable child_ids from any container node (Composition, Track, or Layer).
+    /// Get mutable child_ids from any container node (Composition, Track, Layer, or subgraph).
     pub fn get_container_child_ids_mut(&mut self, id: Uuid) -> Option<&mut Vec<Uuid>> {
         match self.nodes.get_mut(&id)? {
             Node::Composition(c) => Some(&mut c.child_ids),
             Node::Track(t) => Some(&mut t.child_ids),
             Node::Layer(l) => Some(&mut l.child_ids),
+            Node::Graph(g) if g.is_subgraph() => Some(&mut g.child_ids),
             _ => None,
         }
     }
@@ -368,6 +470,23 @@ impl Project {
         false
     }
 
+    /// Whether a track of `composition` is silenced by mute/solo: it is muted,
+    /// or some track is soloed and this one neither is, contains nor sits inside a soloed track.
+    pub fn is_track_silenced(&self, composition: &Composition, track_id: Uuid) -> bool {
+        if self.get_track(track_id).is_some_and(|t| t.muted) {
+            return true;
+        }
+        let soloed: Vec<Uuid> = self
+            .all_tracks()
+            .filter(|t| t.soloed && self.is_node_in_tree(composition.id, t.id))
+            .map(|t| t.id)
+            .collect();
+        !soloed.is_empty()
+            && !soloed.iter().any(|&solo_id| {
+                self.is_node_in_tree(solo_id, track_id) || self.is_node_in_tree(track_id, solo_id)
+            })
+    }
+
     // ==================== GraphNode Accessors ====================
 
     pub fn get_graph_node(&self, id: Uuid) -> Option<&GraphNode> {
@@ -1,3 +1,4 @@
+use indexmap::IndexMap;
 use log;
 use serde::{Deserialize, Serialize};
 use serde_json;
@@ -7,15 +8,20 @@ use ordered_float::OrderedFloat;
 use std::hash::{Hash, Hasher};
 
 use crate::animation::EasingFunction;
+use crate::error::LibraryError;
 use crate::runtime::color::Color;
 
+// Unknown fields are rejected so the untagged PropertyValue does not read a
+// saved Vec3 or Vec4 back as a Vec2 and drop the extra components.
 #[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(deny_unknown_fields)]
 pub struct Vec2 {
     pub x: OrderedFloat<f64>,
     pub y: OrderedFloat<f64>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(deny_unknown_fields)]
 pub struct Vec3 {
     pub x: OrderedFloat<f64>,
     pub y: OrderedFloat<f64>,
@@ -110,6 +116,8 @@ impl PropertyValue {
                 PropertyUiType::Text
                     | PropertyUiType::MultilineText
                     | PropertyUiType::Font
+                    | PropertyUiType::Curve
+                    | PropertyUiType::FilePicker { .. }
                     | PropertyUiType::Dropdown { .. }
             ),
             PropertyValue::Boolean(_) => matches!(ui_type, PropertyUiType::Bool),
@@ -491,12 +499,27 @@ fn default_constant_evaluator() -> String {
     "constant".to_string()
 }
 
+/// A keyframe with independent easing on each side, as in After Effects.
+///
+/// The segment between two keyframes uses the left keyframe's `ease_out` and
+/// the right keyframe's `ease_in`; unset sides are linear.
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug, Hash)]
 pub struct Keyframe {
     pub time: OrderedFloat<f64>,
     pub value: PropertyValue,
-    #[serde(default)]
-    pub easing: EasingFunction, // Assuming EasingFunction implements Hash/Eq, check later
+    /// Easing of the segment arriving at this keyframe.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ease_in: Option<EasingFunction>,
+    /// Easing of the segment leaving this keyframe. Older projects stored it as `easing`.
+    #[serde(default, alias = "easing", skip_serializing_if = "Option::is_none")]
+    pub ease_out: Option<EasingFunction>,
+}
+
+/// Side of a keyframe an easing applies to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EasingHandle {
+    In,
+    Out,
 }
 
 impl Property {
@@ -551,6 +574,31 @@ impl Property {
         }
     }
 
+    /// A spring released from `initial` toward `target`, evaluated by the
+    /// `spring` property plugin (`plugins/spring_property`).
+    pub fn spring(
+        initial: f64,
+        target: f64,
+        stiffness: f64,
+        damping: f64,
+        initial_velocity: f64,
+    ) -> Self {
+        Self {
+            evaluator: "spring".to_string(),
+            properties: HashMap::from([
+                ("value".to_string(), PropertyValue::from(initial)),
+                ("target".to_string(), PropertyValue::from(target)),
+                ("stiffness".to_string(), PropertyValue::from(stiffness)),
+                ("damping".to_string(), PropertyValue::from(damping)),
+                (
+                    "initial_velocity".to_string(),
+                    PropertyValue::from(initial_velocity),
+                ),
+            ]),
+            ..Default::default()
+        }
+    }
+
     pub fn keyframes(&self) -> Vec<Keyframe> {
         match self.properties.get("keyframes") {
             Some(PropertyValue::Array(items)) => items
@@ -599,8 +647,8 @@ impl Property {
     }
 
     /// Add or update a keyframe at the given time.
-    /// If a keyframe already exists at the time, updates its value and optionally its easing.
-    /// If easing is None, preserves the existing easing for updates; uses Linear for new keyframes.
+    /// If a keyframe already exists at the time, updates its value and optionally its outgoing easing.
+    /// If easing is None, preserves the existing easing for updates; new keyframes are left linear.
     /// If this is a constant property, converts it to a keyframe property.
     /// Returns true if successful.
     pub fn upsert_keyframe(
@@ -616,7 +664,8 @@ impl Property {
             let kf = Keyframe {
                 time: OrderedFloat(time),
                 value,
-                easing: easing.unwrap_or(EasingFunction::Linear),
+                ease_in: None,
+                ease_out: easing,
             };
             *self = Property::keyframe(vec![kf]);
             return true;
@@ -631,15 +680,17 @@ impl Property {
                 .position(|k| (k.time.into_inner() - time).abs() < TOLERANCE)
             {
                 // Update existing keyframe, preserving easing if not specified
-                let preserved_easing = kfs[idx].easing.clone();
                 kfs[idx].value = value;
-                kfs[idx].easing = easing.unwrap_or(preserved_easing);
+                if easing.is_some() {
+                    kfs[idx].ease_out = easing;
+                }
             } else {
                 // Add new keyframe
                 kfs.push(Keyframe {
                     time: OrderedFloat(time),
                     value,
-                    easing: easing.unwrap_or(EasingFunction::Linear),
+                    ease_in: None,
+                    ease_out: easing,
                 });
                 kfs.sort_by_key(|k| k.time);
             }
@@ -659,7 +710,7 @@ impl Property {
         false
     }
 
-    /// Update a keyframe at the given index.
+    /// Update a keyframe at the given index. `new_easing` sets its outgoing easing.
     /// Returns true if successful.
     pub fn update_keyframe_at_index(
         &mut self,
@@ -685,7 +736,7 @@ impl Property {
             kf.value = v;
         }
         if let Some(e) = new_easing {
-            kf.easing = e;
+            kf.ease_out = Some(e);
         }
 
         kfs.sort_by_key(|k| k.time);
@@ -701,6 +752,77 @@ impl Property {
         true
     }
 
+    /// Move several keyframes at once, then re-sort by time.
+    /// `updates` are `(index, new_time, new_value)` against the current order; on success
+    /// returns the new index of every keyframe, indexed by its old index.
+    pub fn update_keyframes_at_indices(
+        &mut self,
+        updates: &[(usize, Option<f64>, Option<PropertyValue>)],
+    ) -> Option<Vec<usize>> {
+        if self.evaluator != "keyframe" {
+            return None;
+        }
+
+        let mut kfs: Vec<(usize, Keyframe)> = self.keyframes().into_iter().enumerate().collect();
+        for (index, new_time, new_value) in updates {
+            let (_, kf) = kfs.get_mut(*index)?;
+            if let Some(t) = new_time {
+                kf.time = OrderedFloat(*t);
+            }
+            if let Some(v) = new_value {
+                kf.value = v.clone();
+            }
+        }
+
+        kfs.sort_by_key(|(_, k)| k.time);
+
+        let mut remap = vec![0; kfs.len()];
+        for (new_index, (old_index, _)) in kfs.iter().enumerate() {
+            remap[*old_index] = new_index;
+        }
+
+        let existing_props = self.properties.clone();
+        *self = Property::keyframe(kfs.into_iter().map(|(_, k)| k).collect());
+        for (k, v) in existing_props {
+            if k != "keyframes" && k != "value" {
+                self.properties.insert(k, v);
+            }
+        }
+        Some(remap)
+    }
+
+    /// Set or clear (`None`, i.e. linear) the easing on one side of the keyframe at `index`.
+    /// Returns true if successful.
+    pub fn set_keyframe_easing(
+        &mut self,
+        index: usize,
+        handle: EasingHandle,
+        easing: Option<EasingFunction>,
+    ) -> bool {
+        if self.evaluator != "keyframe" {
+            return false;
+        }
+
+        let mut kfs = self.keyframes();
+        let Some(kf) = kfs.get_mut(index) else {
+            return false;
+        };
+        match handle {
+            EasingHandle::In => kf.ease_in = easing,
+            EasingHandle::Out => kf.ease_out = easing,
+        }
+
+        // Preserve existing property attributes
+        let existing_props = self.properties.clone();
+        *self = Property::keyframe(kfs);
+        for (k, v) in existing_props {
+            if k != "keyframes" && k != "value" {
+                self.properties.insert(k, v);
+            }
+        }
+        true
+    }
+
     /// Remove a keyframe at the given index.
     /// Returns true if successful.
     pub fn remove_keyframe_at_index(&mut self, index: usize) -> bool {
@@ -725,6 +847,29 @@ impl Property {
         }
         true
     }
+
+    /// Rewrite every keyframe time through `f`, keeping the list sorted.
+    /// Does nothing for non-keyframe properties.
+    fn map_keyframe_times(&mut self, f: impl Fn(f64) -> f64) {
+        if self.evaluator != "keyframe" {
+            return;
+        }
+
+        let mut kfs = self.keyframes();
+        for kf in &mut kfs {
+            kf.time = OrderedFloat(f(kf.time.into_inner()));
+        }
+        kfs.sort_by_key(|k| k.time);
+
+        // Preserve existing property attributes
+        let existing_props = self.properties.clone();
+        *self = Property::keyframe(kfs);
+        for (k, v) in existing_props {
+            if k != "keyframes" && k != "value" {
+                self.properties.insert(k, v);
+            }
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -736,12 +881,16 @@ pub enum PropertyTarget {
     Decorator(usize),
     /// References a GraphNode by UUID (for the new graph-based model)
     GraphNode(uuid::Uuid),
+    /// Index into a clip's audio effect chain (`SourceData::audio_effects`)
+    AudioEffect(usize),
 }
 
+/// Properties keyed by name, iterated in insertion order so the inspector
+/// lists them stably.
 #[derive(Serialize, Deserialize, Clone, Default, PartialEq, Eq, Debug)] // Added Debug
 #[serde(transparent)]
 pub struct PropertyMap {
-    properties: HashMap<String, Property>,
+    properties: IndexMap<String, Property>,
 }
 
 impl Hash for PropertyMap {
@@ -758,7 +907,7 @@ impl Hash for PropertyMap {
 impl PropertyMap {
     pub fn new() -> Self {
         Self {
-            properties: HashMap::new(),
+            properties: IndexMap::new(),
         }
     }
 
@@ -790,6 +939,36 @@ impl PropertyMap {
         self.properties.iter()
     }
 
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&String, &mut Property)> {
+        self.properties.iter_mut()
+    }
+
+    /// Move `key` to position `new_index` in iteration order, clamped to the
+    /// last position. Returns false if the key is not in the map.
+    pub fn reorder(&mut self, key: &str, new_index: usize) -> bool {
+        let Some(index) = self.properties.get_index_of(key) else {
+            return false;
+        };
+        let last = self.properties.len() - 1;
+        self.properties.move_index(index, new_index.min(last));
+        true
+    }
+
+    /// Multiply the time of every keyframe in the map by `factor`.
+    /// Useful when retiming a clip (e.g. changing its speed).
+    pub fn scale_keyframe_times(&mut self, factor: f64) {
+        for (_, prop) in self.iter_mut() {
+            prop.map_keyframe_times(|t| t * factor);
+        }
+    }
+
+    /// Shift the time of every keyframe in the map by `delta` seconds.
+    pub fn offset_keyframe_times(&mut self, delta: f64) {
+        for (_, prop) in self.iter_mut() {
+            prop.map_keyframe_times(|t| t + delta);
+        }
+    }
+
     /// Update a property value or upsert a keyframe if the property is keyframed.
     /// This centralizes the logic for property updates.
     pub fn update_property_or_keyframe(
@@ -849,6 +1028,183 @@ impl PropertyMap {
         self.get_constant_value(key)
             .and_then(|pv| pv.get_as::<bool>())
     }
+
+    /// Set constant properties from a JSON object keyed by property name.
+    ///
+    /// Supported values are numbers, strings, booleans, `[x, y]` arrays (Vec2)
+    /// and `[r, g, b, a]` arrays (Color, 0-255 per channel). The map is left
+    /// untouched if any entry cannot be converted.
+    pub fn import_from_json(&mut self, json: &str) -> Result<(), LibraryError> {
+        let root: serde_json::Value = serde_json::from_str(json)?;
+        let serde_json::Value::Object(entries) = root else {
+            return Err(LibraryError::InvalidArgument(
+                "Property JSON must be an object".to_string(),
+            ));
+        };
+
+        let mut parsed = Vec::with_capacity(entries.len());
+        for (key, raw) in entries {
+            let value = json_to_constant_value(&raw).ok_or_else(|| {
+                LibraryError::InvalidArgument(format!(
+                    "Unsupported value for property '{}': {}",
+                    key, raw
+                ))
+            })?;
+            parsed.push((key, value));
+        }
+
+        for (key, value) in parsed {
+            self.set(key, Property::constant(value));
+        }
+        Ok(())
+    }
+
+    /// Compare this map against a newer `other`, returning per-key changes sorted by key.
+    pub fn diff(&self, other: &PropertyMap) -> Vec<PropertyDiff> {
+        let mut diffs: Vec<PropertyDiff> = self
+            .properties
+            .iter()
+            .filter_map(|(key, prop)| {
+                let change_type = match other.properties.get(key) {
+                    None => DiffType::Removed,
+                    Some(other_prop) if other_prop != prop => DiffType::Modified,
+                    Some(_) => return None,
+                };
+                Some(PropertyDiff {
+                    key: key.clone(),
+                    change_type,
+                })
+            })
+            .chain(
+                other
+                    .properties
+                    .keys()
+                    .filter(|key| !self.properties.contains_key(*key))
+                    .map(|key| PropertyDiff {
+                        key: key.clone(),
+                        change_type: DiffType::Added,
+                    }),
+            )
+            .collect();
+        diffs.sort_by(|a, b| a.key.cmp(&b.key));
+        diffs
+    }
+
+    /// Classify the keys of two maps side by side, e.g. for editing several
+    /// selected clips at once. Each list is sorted by key.
+    ///
+    /// Unlike [`PropertyMap::diff`], neither map is treated as the newer one.
+    pub fn compare(&self, other: &PropertyMap) -> PropertyMapDiff {
+        let mut result = PropertyMapDiff::default();
+        for (key, prop) in &self.properties {
+            match other.properties.get(key) {
+                None => result.only_in_self.push(key.clone()),
+                Some(other_prop) if other_prop == prop => result.shared_equal.push(key.clone()),
+                Some(_) => result.shared_differing.push(key.clone()),
+            }
+        }
+        result.only_in_other = other
+            .properties
+            .keys()
+            .filter(|key| !self.properties.contains_key(*key))
+            .cloned()
+            .collect();
+        for keys in [
+            &mut result.only_in_self,
+            &mut result.only_in_other,
+            &mut result.shared_equal,
+            &mut result.shared_differing,
+        ] {
+            keys.sort();
+        }
+        result
+    }
+
+    /// Merge `other` into this map.
+    ///
+    /// Keys missing from this map are copied from `other`; `strategy` decides
+    /// which property wins for keys present in both.
+    pub fn merge(&mut self, other: &PropertyMap, strategy: MergeStrategy) {
+        for (key, other_prop) in &other.properties {
+            let take_other = match self.properties.get(key) {
+                None => true,
+                Some(prop) => match strategy {
+                    MergeStrategy::KeepSelf => false,
+                    MergeStrategy::KeepOther => true,
+                    MergeStrategy::TakeKeyframed => {
+                        other_prop.evaluator == "keyframe" && prop.evaluator != "keyframe"
+                    }
+                },
+            };
+            if take_other {
+                self.properties.insert(key.clone(), other_prop.clone());
+            }
+        }
+    }
+}
+
+/// Result of [`PropertyMap::compare`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PropertyMapDiff {
+    pub only_in_self: Vec<String>,
+    pub only_in_other: Vec<String>,
+    /// Keys whose evaluator and parameters are identical in both maps.
+    pub shared_equal: Vec<String>,
+    pub shared_differing: Vec<String>,
+}
+
+/// Which property [`PropertyMap::merge`] keeps when both maps define a key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeStrategy {
+    KeepSelf,
+    KeepOther,
+    /// Prefer the keyframed property; keep self when both or neither are keyframed.
+    TakeKeyframed,
+}
+
+/// Kind of change reported by [`PropertyMap::diff`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffType {
+    Added,
+    Removed,
+    Modified,
+}
+
+/// A single property change between two PropertyMaps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropertyDiff {
+    pub key: String,
+    pub change_type: DiffType,
+}
+
+/// Convert a scripted JSON value into a constant PropertyValue.
+fn json_to_constant_value(value: &serde_json::Value) -> Option<PropertyValue> {
+    match value {
+        serde_json::Value::Number(n) => n.as_f64().map(PropertyValue::from),
+        serde_json::Value::String(s) => Some(PropertyValue::String(s.clone())),
+        serde_json::Value::Bool(b) => Some(PropertyValue::Boolean(*b)),
+        serde_json::Value::Array(items) => {
+            let numbers: Vec<f64> = items
+                .iter()
+                .map(|v| v.as_f64())
+                .collect::<Option<Vec<f64>>>()?;
+            let channel = |v: f64| v.round().clamp(0.0, 255.0) as u8;
+            match numbers.as_slice() {
+                [x, y] => Some(PropertyValue::Vec2(Vec2 {
+                    x: OrderedFloat(*x),
+                    y: OrderedFloat(*y),
+                })),
+                [r, g, b, a] => Some(PropertyValue::Color(Color {
+                    r: channel(*r),
+                    g: channel(*g),
+                    b: channel(*b),
+                    a: channel(*a),
+                })),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
 }
 
 // === UI Property Definitions ===
@@ -888,9 +1244,37 @@ pub enum PropertyUiType {
         options: Vec<String>,
     },
     Font,
+    /// Tone curve stored as a JSON string (see `runtime::curve::ColorCurve`).
+    Curve,
+    /// Path to a file, edited with a text field and an open-file button.
+    /// `filter` is a semicolon-separated pattern list such as `"*.cube;*.3dl"`
+    /// for the file dialog; empty allows any file. Files picked inside the
+    /// project file's directory are stored relative to it.
+    FilePicker {
+        filter: String,
+    },
 }
 
 impl PropertyUiType {
+    /// Extensions (without dots) accepted by a `FilePicker`'s filter, e.g.
+    /// `["cube", "3dl"]` for `"*.cube;*.3dl"`. Empty for other types.
+    pub fn file_extensions(&self) -> Vec<String> {
+        match self {
+            PropertyUiType::FilePicker { filter } => filter
+                .split(';')
+                .map(|pattern| {
+                    pattern
+                        .trim()
+                        .trim_start_matches('*')
+                        .trim_start_matches('.')
+                })
+                .filter(|ext| !ext.is_empty())
+                .map(str::to_string)
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
     /// Returns the corresponding PinDataType for this UI type.
     pub fn pin_data_type(&self) -> crate::project::connection::PinDataType {
         use crate::project::connection::PinDataType;
@@ -898,7 +1282,11 @@ impl PropertyUiType {
             Self::Float { .. } => PinDataType::Scalar,
             Self::Integer { .. } => PinDataType::Integer,
             Self::Color => PinDataType::Color,
-            Self::Text | Self::MultilineText | Self::Font => PinDataType::String,
+            Self::Text
+            | Self::MultilineText
+            | Self::Font
+            | Self::Curve
+            | Self::FilePicker { .. } => PinDataType::String,
             Self::Bool => PinDataType::Boolean,
             Self::Vec2 { .. } => PinDataType::Vec2,
             Self::Vec3 { .. } => PinDataType::Vec3,
@@ -998,6 +1386,14 @@ mod tests {
         );
         assert_eq!(PropertyUiType::Font.pin_data_type(), PinDataType::String);
         assert_eq!(PropertyUiType::Text.pin_data_type(), PinDataType::String);
+        assert_eq!(PropertyUiType::Curve.pin_data_type(), PinDataType::String);
+        assert_eq!(
+            PropertyUiType::FilePicker {
+                filter: String::new()
+            }
+            .pin_data_type(),
+            PinDataType::String
+        );
         assert_eq!(
             PropertyUiType::Dropdown { options: vec![] }.pin_data_type(),
             PinDataType::Enum
@@ -1014,4 +1410,20 @@ mod tests {
             PinDataType::Integer
         );
     }
+
+    #[test]
+    fn test_file_picker_filter_extensions() {
+        let picker = PropertyUiType::FilePicker {
+            filter: "*.cube; *.3dl;".into(),
+        };
+        assert_eq!(picker.file_extensions(), vec!["cube", "3dl"]);
+        assert!(
+            PropertyUiType::FilePicker {
+                filter: String::new()
+            }
+            .file_extensions()
+            .is_empty()
+        );
+        assert!(PropertyUiType::Text.file_extensions().is_empty());
+    }
 }
@@ -1,7 +1,9 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::effect::EffectConfig;
 use super::property::PropertyMap;
+use crate::runtime::draw_type::BlendMode;
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 #[serde(rename_all = "lowercase")] // Serialize as "video", "image", etc.
@@ -12,6 +14,7 @@ pub enum SourceKind {
     Text,
     Shape,
     SkSL,
+    Gradient,
     Composition,
 }
 
@@ -24,6 +27,7 @@ impl std::fmt::Display for SourceKind {
             SourceKind::Text => "text",
             SourceKind::Shape => "shape",
             SourceKind::SkSL => "sksl",
+            SourceKind::Gradient => "gradient",
             SourceKind::Composition => "composition",
         };
         write!(f, "{}", s)
@@ -48,8 +52,25 @@ pub struct SourceData {
     #[serde(default = "default_fps")]
     pub fps: f64,
 
+    /// Playback rate of the source: 2.0 plays twice as fast, 0.5 in slow
+    /// motion, negative values in reverse.
+    #[serde(default = "default_speed")]
+    pub speed: f64,
+
     #[serde(default)]
     pub properties: PropertyMap,
+
+    /// How the clip's image is composited onto the clips below it.
+    #[serde(default)]
+    pub blend_mode: BlendMode,
+
+    /// RGB label shown on the timeline instead of the kind's color.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_color: Option<[u8; 3]>,
+
+    /// Audio effect chain applied by the mixer (audio sources only).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub audio_effects: Vec<EffectConfig>,
 }
 
 impl SourceData {
@@ -64,6 +85,20 @@ impl SourceData {
         !matches!(self.kind, SourceKind::Audio)
     }
 
+    /// Clip-local source time (seconds) at a composition frame.
+    ///
+    /// Accounts for `in_frame`, `source_begin_frame`, `speed`, and the source's
+    /// own `fps`. Frames before `in_frame` yield times before the source start.
+    pub fn compute_local_time(&self, playback_frame: u64, composition_fps: f64) -> f64 {
+        let time_offset = (playback_frame as f64 - self.in_frame as f64) / composition_fps;
+        self.source_begin_frame as f64 / self.fps + time_offset * self.speed
+    }
+
+    /// Source frame shown at a composition frame. Negative before the source start.
+    pub fn compute_source_frame(&self, playback_frame: u64, composition_fps: f64) -> i64 {
+        (self.compute_local_time(playback_frame, composition_fps) * self.fps).round() as i64
+    }
+
     pub fn new(
         id: Uuid,
         reference_id: Option<Uuid>,
@@ -84,10 +119,28 @@ impl SourceData {
             source_begin_frame,
             duration_frame,
             fps,
+            speed: 1.0,
             properties,
+            blend_mode: BlendMode::default(),
+            label_color: None,
+            audio_effects: Vec::new(),
         }
     }
 
+    /// Clone this source with a fresh ID, also regenerating the IDs of its
+    /// audio effects so the copy shares no identity with the original.
+    ///
+    /// Styles, effectors, decorators and image effects are graph nodes of the
+    /// enclosing layer and are copied by `SourceHandler::duplicate_source`.
+    pub fn clone_with_new_id(&self) -> Self {
+        let mut copy = self.clone();
+        copy.id = Uuid::new_v4();
+        for effect in &mut copy.audio_effects {
+            effect.id = Uuid::new_v4();
+        }
+        copy
+    }
+
     // Helper for consistency with Entity
     pub fn set_constant_property(
         &mut self,
@@ -109,6 +162,10 @@ impl SourceData {
         use crate::project::property::PropertyTarget;
         match target {
             PropertyTarget::Clip => Some(&mut self.properties),
+            PropertyTarget::AudioEffect(index) => self
+                .audio_effects
+                .get_mut(index)
+                .map(|effect| &mut effect.properties),
             PropertyTarget::GraphNode(_) => None,
             _ => None,
         }
@@ -117,7 +174,8 @@ impl SourceData {
     pub fn get_definitions_for_kind(
         kind: &SourceKind,
     ) -> Vec<crate::project::property::PropertyDefinition> {
-        use crate::project::property::{PropertyDefinition, PropertyUiType, PropertyValue};
+        use crate::project::property::{PropertyDefinition, PropertyUiType, PropertyValue, Vec2};
+        use crate::runtime::gradient::GradientStop;
         use ordered_float::OrderedFloat;
 
         let mut defs = vec![];
@@ -199,6 +257,25 @@ impl SourceData {
                     "Height",
                     PropertyValue::Number(OrderedFloat(100.0)),
                 ));
+                defs.push(PropertyDefinition::new(
+                    "boolean_op",
+                    PropertyUiType::Dropdown {
+                        options: vec![
+                            "None".into(),
+                            "Union".into(),
+                            "Subtract".into(),
+                            "Intersect".into(),
+                        ],
+                    },
+                    "Boolean Op",
+                    PropertyValue::String("None".to_string()),
+                ));
+                defs.push(PropertyDefinition::new(
+                    "boolean_op_source_id",
+                    PropertyUiType::Text,
+                    "Boolean Op Source",
+                    PropertyValue::String("".to_string()),
+                ));
             }
             SourceKind::SkSL => {
                 defs.push(PropertyDefinition::new(
@@ -208,6 +285,61 @@ impl SourceData {
                     PropertyValue::String("".to_string()),
                 ));
             }
+            SourceKind::Gradient => {
+                let point = |x: f64, y: f64| {
+                    PropertyValue::Vec2(Vec2 {
+                        x: OrderedFloat(x),
+                        y: OrderedFloat(y),
+                    })
+                };
+                defs.push(PropertyDefinition::new(
+                    "gradient_type",
+                    PropertyUiType::Dropdown {
+                        options: vec!["Linear".into(), "Radial".into(), "Conic".into()],
+                    },
+                    "Gradient Type",
+                    PropertyValue::String("Linear".to_string()),
+                ));
+                defs.push(PropertyDefinition::new(
+                    "start_point",
+                    PropertyUiType::Vec2 {
+                        suffix: "px".into(),
+                    },
+                    "Start Point",
+                    point(0.0, 540.0),
+                ));
+                defs.push(PropertyDefinition::new(
+                    "end_point",
+                    PropertyUiType::Vec2 {
+                        suffix: "px".into(),
+                    },
+                    "End Point",
+                    point(1920.0, 540.0),
+                ));
+                defs.push(PropertyDefinition::new(
+                    "stops",
+                    PropertyUiType::MultilineText,
+                    "Stops (JSON)",
+                    PropertyValue::String(GradientStop::to_json(&GradientStop::default_stops())),
+                ));
+                for (name, label, default) in
+                    [("width", "Width", 1920.0), ("height", "Height", 1080.0)]
+                {
+                    defs.push(PropertyDefinition::new(
+                        name,
+                        PropertyUiType::Float {
+                            min: 0.0,
+                            max: 10000.0,
+                            step: 1.0,
+                            suffix: "px".into(),
+                            min_hard_limit: false,
+                            max_hard_limit: false,
+                        },
+                        label,
+                        PropertyValue::Number(OrderedFloat(default)),
+                    ));
+                }
+            }
             _ => {}
         }
 
@@ -218,3 +350,7 @@ impl SourceData {
 const fn default_fps() -> f64 {
     30.0
 }
+
+const fn default_speed() -> f64 {
+    1.0
+}
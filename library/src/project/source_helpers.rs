@@ -4,8 +4,12 @@ use crate::project::source::SourceKind;
 impl SourceData {
     // --- UI / Display Helpers ---
 
-    /// Returns the display color of the source based on its kind.
+    /// Returns the display color of the source: its label color if set,
+    /// otherwise a color based on its kind.
     pub fn display_color(&self) -> (u8, u8, u8) {
+        if let Some([r, g, b]) = self.label_color {
+            return (r, g, b);
+        }
         match self.kind {
             SourceKind::Video => (100, 150, 255),       // Blue
             SourceKind::Audio => (100, 255, 150),       // Green
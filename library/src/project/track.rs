@@ -15,6 +15,12 @@ pub struct TrackData {
     pub opacity: f64,
     #[serde(default = "default_visible")]
     pub visible: bool,
+    /// Muted tracks are skipped when rendering and mixing audio.
+    #[serde(default)]
+    pub muted: bool,
+    /// While any track is soloed, only soloed tracks (and their sub-tracks) play.
+    #[serde(default)]
+    pub soloed: bool,
     /// Deprecated: kept only for backward-compatible deserialization of old project files.
     /// New code should use `Node::Layer(LayerData)` instead.
     #[serde(default, skip_serializing)]
@@ -38,6 +44,8 @@ impl TrackData {
             blend_mode: BlendMode::default(),
             opacity: 1.0,
             visible: true,
+            muted: false,
+            soloed: false,
             is_layer: false,
         }
     }
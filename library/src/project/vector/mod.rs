@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 #[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq, Hash)]
 pub enum HandleType {
@@ -27,3 +28,521 @@ pub struct VectorPath {
     pub points: Vec<ControlPoint>,
     pub is_closed: bool,
 }
+
+#[derive(Error, Debug, PartialEq)]
+pub enum VectorPathError {
+    #[error("Unsupported path command: {0}")]
+    UnsupportedCommand(char),
+    #[error("Invalid number in path data: {0}")]
+    InvalidNumber(String),
+    #[error("Path data must start with a moveto command")]
+    MissingMoveTo,
+    #[error("Path data contains more than one subpath")]
+    MultipleSubpaths,
+    #[error("Unexpected end of path data")]
+    UnexpectedEnd,
+}
+
+impl VectorPath {
+    /// Serialize the path as SVG path data using `M`, `C` and `Z` commands.
+    pub fn to_svg_path_string(&self) -> String {
+        let Some(first) = self.points.first() else {
+            return String::new();
+        };
+
+        let mut segments = vec![format!("M {},{}", first.position[0], first.position[1])];
+        let segment_count = if self.is_closed {
+            self.points.len()
+        } else {
+            self.points.len() - 1
+        };
+
+        for i in 0..segment_count {
+            let current = &self.points[i];
+            let next = &self.points[(i + 1) % self.points.len()];
+            segments.push(format!(
+                "C {},{} {},{} {},{}",
+                current.position[0] + current.handle_out[0],
+                current.position[1] + current.handle_out[1],
+                next.position[0] + next.handle_in[0],
+                next.position[1] + next.handle_in[1],
+                next.position[0],
+                next.position[1]
+            ));
+        }
+
+        if self.is_closed {
+            segments.push("Z".to_string());
+        }
+        segments.join(" ")
+    }
+
+    /// Parse SVG path data consisting of `M`, `L`, `C` and `Z` commands
+    /// (absolute or relative) describing a single subpath.
+    pub fn from_svg_path_string(s: &str) -> Result<Self, VectorPathError> {
+        let tokens = tokenize_path(s)?;
+        let mut points: Vec<ControlPoint> = Vec::new();
+        let mut is_closed = false;
+        let mut current = [0.0f32, 0.0f32];
+        let mut command: Option<char> = None;
+        let mut i = 0;
+
+        let read_pair = |i: &mut usize| -> Result<[f32; 2], VectorPathError> {
+            match (tokens.get(*i), tokens.get(*i + 1)) {
+                (Some(PathToken::Number(x)), Some(PathToken::Number(y))) => {
+                    *i += 2;
+                    Ok([*x, *y])
+                }
+                _ => Err(VectorPathError::UnexpectedEnd),
+            }
+        };
+
+        while i < tokens.len() {
+            let cmd = match tokens[i] {
+                PathToken::Command(c) => {
+                    i += 1;
+                    c
+                }
+                PathToken::Number(_) => match command {
+                    // Implicit repetition; a repeated moveto becomes lineto
+                    Some('M') => 'L',
+                    Some('m') => 'l',
+                    Some(c) if c != 'Z' && c != 'z' => c,
+                    _ => return Err(VectorPathError::MissingMoveTo),
+                },
+            };
+            let relative = cmd.is_ascii_lowercase();
+            let offset = if relative { current } else { [0.0, 0.0] };
+            let abs = |p: [f32; 2]| [p[0] + offset[0], p[1] + offset[1]];
+
+            match cmd.to_ascii_uppercase() {
+                'M' => {
+                    if is_closed || !points.is_empty() {
+                        return Err(VectorPathError::MultipleSubpaths);
+                    }
+                    current = abs(read_pair(&mut i)?);
+                    points.push(ControlPoint::corner(current));
+                }
+                'L' => {
+                    if points.is_empty() {
+                        return Err(VectorPathError::MissingMoveTo);
+                    }
+                    current = abs(read_pair(&mut i)?);
+                    points.push(ControlPoint::corner(current));
+                }
+                'C' => {
+                    let Some(last) = points.last_mut() else {
+                        return Err(VectorPathError::MissingMoveTo);
+                    };
+                    let c1 = abs(read_pair(&mut i)?);
+                    let c2 = abs(read_pair(&mut i)?);
+                    let end = abs(read_pair(&mut i)?);
+                    last.handle_out = [c1[0] - last.position[0], c1[1] - last.position[1]];
+                    let mut point = ControlPoint::corner(end);
+                    point.handle_in = [c2[0] - end[0], c2[1] - end[1]];
+                    points.push(point);
+                    current = end;
+                }
+                'Z' => {
+                    if points.is_empty() {
+                        return Err(VectorPathError::MissingMoveTo);
+                    }
+                    is_closed = true;
+                    // An explicit closing segment back to the start is folded
+                    // into the first point's incoming handle.
+                    if points.len() > 1 {
+                        let first = points[0].position;
+                        let last = points[points.len() - 1].position;
+                        if (first[0] - last[0]).abs() < 1e-4 && (first[1] - last[1]).abs() < 1e-4 {
+                            let closing = points.pop().unwrap();
+                            points[0].handle_in = closing.handle_in;
+                        }
+                    }
+                    current = points[0].position;
+                }
+                other => return Err(VectorPathError::UnsupportedCommand(other)),
+            }
+            command = Some(cmd);
+        }
+
+        for point in &mut points {
+            point.point_type = infer_point_type(point.handle_in, point.handle_out);
+        }
+
+        Ok(VectorPath { points, is_closed })
+    }
+}
+
+/// Boolean operation used to combine two paths.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq, Hash)]
+pub enum PathBooleanOp {
+    Union,
+    Subtract,
+    Intersect,
+}
+
+impl PathBooleanOp {
+    /// Parse the dropdown value used by the `boolean_op` shape property.
+    /// Returns `None` for "None" or unknown values.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Union" | "union" => Some(Self::Union),
+            "Subtract" | "subtract" => Some(Self::Subtract),
+            "Intersect" | "intersect" => Some(Self::Intersect),
+            _ => None,
+        }
+    }
+
+    fn to_skia(self) -> skia_safe::PathOp {
+        match self {
+            Self::Union => skia_safe::PathOp::Union,
+            Self::Subtract => skia_safe::PathOp::Difference,
+            Self::Intersect => skia_safe::PathOp::Intersect,
+        }
+    }
+}
+
+/// Combine two SVG path data strings with a boolean operation.
+///
+/// Unlike the `VectorPath` methods this keeps every contour of the result,
+/// so it is used when rendering shape sources.
+pub fn boolean_op_svg(a: &str, b: &str, op: PathBooleanOp) -> Option<String> {
+    let path_a = skia_safe::Path::from_svg(a)?;
+    let path_b = skia_safe::Path::from_svg(b)?;
+    skia_safe::op(&path_a, &path_b, op.to_skia()).map(|result| result.to_svg())
+}
+
+impl VectorPath {
+    pub fn union(&self, other: &VectorPath) -> VectorPath {
+        self.boolean_op(other, PathBooleanOp::Union)
+    }
+
+    pub fn subtract(&self, other: &VectorPath) -> VectorPath {
+        self.boolean_op(other, PathBooleanOp::Subtract)
+    }
+
+    pub fn intersect(&self, other: &VectorPath) -> VectorPath {
+        self.boolean_op(other, PathBooleanOp::Intersect)
+    }
+
+    /// Apply a boolean operation through Skia's path ops.
+    ///
+    /// `VectorPath` models a single contour, so only the first contour of the
+    /// result is kept. An empty path is returned if the operation fails.
+    pub fn boolean_op(&self, other: &VectorPath, op: PathBooleanOp) -> VectorPath {
+        let (Some(a), Some(b)) = (
+            skia_safe::Path::from_svg(self.to_svg_path_string()),
+            skia_safe::Path::from_svg(other.to_svg_path_string()),
+        ) else {
+            return VectorPath::default();
+        };
+        skia_safe::op(&a, &b, op.to_skia())
+            .map(|result| Self::from_skia_path(&result))
+            .unwrap_or_default()
+    }
+
+    /// Convert the first contour of a Skia path. Quadratic segments are
+    /// elevated to cubics and conics are approximated by their end point.
+    fn from_skia_path(path: &skia_safe::Path) -> VectorPath {
+        use skia_safe::PathVerb;
+
+        let mut points: Vec<ControlPoint> = Vec::new();
+        let mut is_closed = false;
+        let push_cubic = |points: &mut Vec<ControlPoint>,
+                          c1: skia_safe::Point,
+                          c2: skia_safe::Point,
+                          end: skia_safe::Point| {
+            if let Some(last) = points.last_mut() {
+                last.handle_out = [c1.x - last.position[0], c1.y - last.position[1]];
+            }
+            let mut point = ControlPoint::corner([end.x, end.y]);
+            point.handle_in = [c2.x - end.x, c2.y - end.y];
+            points.push(point);
+        };
+
+        for rec in path.iter() {
+            let pts = rec.points();
+            match rec.verb() {
+                PathVerb::Move => {
+                    if !points.is_empty() {
+                        break;
+                    }
+                    points.push(ControlPoint::corner([pts[0].x, pts[0].y]));
+                }
+                PathVerb::Line => points.push(ControlPoint::corner([pts[1].x, pts[1].y])),
+                PathVerb::Quad => {
+                    let c1 = pts[0] + (pts[1] - pts[0]) * (2.0 / 3.0);
+                    let c2 = pts[2] + (pts[1] - pts[2]) * (2.0 / 3.0);
+                    push_cubic(&mut points, c1, c2, pts[2]);
+                }
+                PathVerb::Conic => {
+                    let end = pts[pts.len() - 1];
+                    points.push(ControlPoint::corner([end.x, end.y]));
+                }
+                PathVerb::Cubic => push_cubic(&mut points, pts[1], pts[2], pts[3]),
+                PathVerb::Close => {
+                    is_closed = true;
+                    break;
+                }
+            }
+        }
+
+        // Fold an explicit closing segment back into the first point
+        if is_closed && points.len() > 1 {
+            let first = points[0].position;
+            let last = points[points.len() - 1].position;
+            if (first[0] - last[0]).abs() < 1e-4 && (first[1] - last[1]).abs() < 1e-4 {
+                let closing = points.pop().unwrap();
+                points[0].handle_in = closing.handle_in;
+            }
+        }
+        for point in &mut points {
+            point.point_type = infer_point_type(point.handle_in, point.handle_out);
+        }
+
+        VectorPath { points, is_closed }
+    }
+}
+
+impl ControlPoint {
+    fn corner(position: [f32; 2]) -> Self {
+        Self {
+            position,
+            handle_in: [0.0, 0.0],
+            handle_out: [0.0, 0.0],
+            point_type: PointType::Corner,
+        }
+    }
+}
+
+enum PathToken {
+    Command(char),
+    Number(f32),
+}
+
+fn tokenize_path(s: &str) -> Result<Vec<PathToken>, VectorPathError> {
+    let mut tokens = Vec::new();
+    let chars: Vec<char> = s.chars().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() || c == ',' {
+            i += 1;
+        } else if c.is_ascii_alphabetic() && c != 'e' && c != 'E' {
+            if !matches!(c.to_ascii_uppercase(), 'M' | 'L' | 'C' | 'Z') {
+                return Err(VectorPathError::UnsupportedCommand(c));
+            }
+            tokens.push(PathToken::Command(c));
+            i += 1;
+        } else {
+            let start = i;
+            let mut seen_dot = false;
+            if chars[i] == '-' || chars[i] == '+' {
+                i += 1;
+            }
+            while i < chars.len() {
+                let ch = chars[i];
+                if ch.is_ascii_digit() {
+                    i += 1;
+                } else if ch == '.' && !seen_dot {
+                    seen_dot = true;
+                    i += 1;
+                } else if (ch == 'e' || ch == 'E') && i > start {
+                    i += 1;
+                    if i < chars.len() && (chars[i] == '-' || chars[i] == '+') {
+                        i += 1;
+                    }
+                } else {
+                    break;
+                }
+            }
+            let text: String = chars[start..i.max(start + 1)].iter().collect();
+            i = i.max(start + 1);
+            let value = text
+                .parse::<f32>()
+                .map_err(|_| VectorPathError::InvalidNumber(text.clone()))?;
+            tokens.push(PathToken::Number(value));
+        }
+    }
+
+    Ok(tokens)
+}
+
+fn infer_point_type(handle_in: [f32; 2], handle_out: [f32; 2]) -> PointType {
+    let len = |v: [f32; 2]| (v[0] * v[0] + v[1] * v[1]).sqrt();
+    let (len_in, len_out) = (len(handle_in), len(handle_out));
+    if len_in < 0.001 || len_out < 0.001 {
+        return PointType::Corner;
+    }
+    let dot = (handle_in[0] * handle_out[0] + handle_in[1] * handle_out[1]) / (len_in * len_out);
+    if (dot + 1.0).abs() >= 0.01 {
+        PointType::Corner
+    } else if (len_in - len_out).abs() < 0.01 {
+        PointType::Symmetric
+    } else {
+        PointType::Smooth
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_paths_close(a: &VectorPath, b: &VectorPath) {
+        let close =
+            |x: [f32; 2], y: [f32; 2]| (x[0] - y[0]).abs() < 1e-3 && (x[1] - y[1]).abs() < 1e-3;
+        assert_eq!(a.is_closed, b.is_closed);
+        assert_eq!(a.points.len(), b.points.len());
+        for (pa, pb) in a.points.iter().zip(&b.points) {
+            assert!(close(pa.position, pb.position));
+            assert!(close(pa.handle_in, pb.handle_in));
+            assert!(close(pa.handle_out, pb.handle_out));
+            assert_eq!(pa.point_type, pb.point_type);
+        }
+    }
+
+    fn point(position: [f32; 2], handle_in: [f32; 2], handle_out: [f32; 2]) -> ControlPoint {
+        ControlPoint {
+            position,
+            handle_in,
+            handle_out,
+            point_type: infer_point_type(handle_in, handle_out),
+        }
+    }
+
+    #[test]
+    fn test_svg_round_trip_closed_curve() {
+        let path = VectorPath {
+            points: vec![
+                point([0.0, 0.0], [-10.0, 0.0], [10.0, 0.0]),
+                point([100.0, 0.0], [0.0, -20.0], [0.0, 25.5]),
+                point([50.0, 80.25], [12.0, 3.0], [-4.0, -7.0]),
+            ],
+            is_closed: true,
+        };
+
+        let svg = path.to_svg_path_string();
+        assert!(svg.starts_with("M 0,0 C"));
+        assert!(svg.ends_with('Z'));
+
+        let parsed = VectorPath::from_svg_path_string(&svg).unwrap();
+        assert_paths_close(&parsed, &path);
+    }
+
+    #[test]
+    fn test_svg_round_trip_open_path() {
+        let path = VectorPath {
+            points: vec![
+                point([-5.5, 3.0], [0.0, 0.0], [1.0e-2, 2.0]),
+                point([40.0, 60.0], [-3.0, 0.0], [0.0, 0.0]),
+            ],
+            is_closed: false,
+        };
+
+        let parsed = VectorPath::from_svg_path_string(&path.to_svg_path_string()).unwrap();
+        assert_paths_close(&parsed, &path);
+    }
+
+    #[test]
+    fn test_parse_lines_and_relative_commands() {
+        let parsed = VectorPath::from_svg_path_string("m10,10 l80,0 L90,90 10 90z").unwrap();
+        assert!(parsed.is_closed);
+        let positions: Vec<[f32; 2]> = parsed.points.iter().map(|p| p.position).collect();
+        assert_eq!(
+            positions,
+            vec![[10.0, 10.0], [90.0, 10.0], [90.0, 90.0], [10.0, 90.0]]
+        );
+        assert!(
+            parsed
+                .points
+                .iter()
+                .all(|p| p.point_type == PointType::Corner)
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            VectorPath::from_svg_path_string("L 10 10").unwrap_err(),
+            VectorPathError::MissingMoveTo
+        );
+        assert_eq!(
+            VectorPath::from_svg_path_string("M 0 0 Q 1 1 2 2").unwrap_err(),
+            VectorPathError::UnsupportedCommand('Q')
+        );
+        assert_eq!(
+            VectorPath::from_svg_path_string("M 0 0 L 1 1 M 5 5").unwrap_err(),
+            VectorPathError::MultipleSubpaths
+        );
+        assert_eq!(
+            VectorPath::from_svg_path_string("M 0 0 C 1 1 2").unwrap_err(),
+            VectorPathError::UnexpectedEnd
+        );
+    }
+
+    #[test]
+    fn test_empty_path_serializes_to_empty_string() {
+        assert_eq!(VectorPath::default().to_svg_path_string(), "");
+        let parsed = VectorPath::from_svg_path_string("").unwrap();
+        assert!(parsed.points.is_empty());
+    }
+
+    fn rect(x: f32, y: f32, w: f32, h: f32) -> VectorPath {
+        VectorPath::from_svg_path_string(&format!(
+            "M {},{} L {},{} L {},{} L {},{} Z",
+            x,
+            y,
+            x + w,
+            y,
+            x + w,
+            y + h,
+            x,
+            y + h
+        ))
+        .unwrap()
+    }
+
+    fn bounds(path: &VectorPath) -> (f32, f32, f32, f32) {
+        path.points.iter().fold(
+            (f32::MAX, f32::MAX, f32::MIN, f32::MIN),
+            |(l, t, r, b), p| {
+                (
+                    l.min(p.position[0]),
+                    t.min(p.position[1]),
+                    r.max(p.position[0]),
+                    b.max(p.position[1]),
+                )
+            },
+        )
+    }
+
+    #[test]
+    fn test_boolean_ops_on_overlapping_rects() {
+        let a = rect(0.0, 0.0, 100.0, 100.0);
+        let b = rect(50.0, 0.0, 100.0, 100.0);
+
+        let union = a.union(&b);
+        assert!(union.is_closed);
+        assert_eq!(bounds(&union), (0.0, 0.0, 150.0, 100.0));
+
+        let intersect = a.intersect(&b);
+        assert_eq!(bounds(&intersect), (50.0, 0.0, 100.0, 100.0));
+
+        let subtract = a.subtract(&b);
+        assert_eq!(bounds(&subtract), (0.0, 0.0, 50.0, 100.0));
+    }
+
+    #[test]
+    fn test_boolean_op_svg_keeps_all_contours() {
+        let a = rect(0.0, 0.0, 10.0, 10.0).to_svg_path_string();
+        let b = rect(20.0, 0.0, 10.0, 10.0).to_svg_path_string();
+        let combined = boolean_op_svg(&a, &b, PathBooleanOp::Union).unwrap();
+        assert_eq!(combined.matches('M').count(), 2);
+        assert_eq!(PathBooleanOp::from_name("None"), None);
+        assert_eq!(
+            PathBooleanOp::from_name("Subtract"),
+            Some(PathBooleanOp::Subtract)
+        );
+    }
+}
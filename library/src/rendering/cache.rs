@@ -1,35 +1,203 @@
+use crate::builtin::loaders::{LoadPlugin, LoadRequest};
+use crate::error::LibraryError;
 use crate::runtime::Image;
 use lru::LruCache;
 use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
 
 const DEFAULT_IMAGE_CACHE_SIZE: usize = 64;
 const DEFAULT_VIDEO_CACHE_SIZE: usize = 128;
+const WAVEFORM_CACHE_SIZE: usize = 256;
 
 pub type SharedCacheManager = Arc<CacheManager>;
 
+/// Capacities of the decoded image and video frame caches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheConfig {
+    /// Maximum number of decoded still images.
+    pub image_capacity: usize,
+    /// Maximum number of decoded video frames.
+    pub video_cache_capacity: usize,
+    /// Upper bound on the pixel bytes held by both caches together.
+    pub max_memory_bytes: Option<usize>,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            image_capacity: DEFAULT_IMAGE_CACHE_SIZE,
+            video_cache_capacity: DEFAULT_VIDEO_CACHE_SIZE,
+            max_memory_bytes: None,
+        }
+    }
+}
+
+impl CacheConfig {
+    pub fn with_image_capacity(mut self, capacity: usize) -> Self {
+        self.image_capacity = capacity;
+        self
+    }
+
+    pub fn with_video_cache_capacity(mut self, capacity: usize) -> Self {
+        self.video_cache_capacity = capacity;
+        self
+    }
+
+    pub fn with_max_memory_bytes(mut self, bytes: usize) -> Self {
+        self.max_memory_bytes = Some(bytes);
+        self
+    }
+
+    /// Capacities as `NonZeroUsize`, treating 0 as 1.
+    fn capacities(&self) -> (NonZeroUsize, NonZeroUsize) {
+        let non_zero = |n: usize| NonZeroUsize::new(n).unwrap_or(NonZeroUsize::MIN);
+        (
+            non_zero(self.image_capacity),
+            non_zero(self.video_cache_capacity),
+        )
+    }
+}
+
+/// Identifies a waveform peak summary. The file's modification time is part of
+/// the key, so summaries of a file that changed on disk are no longer hit.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WaveformKey {
+    pub asset_id: uuid::Uuid,
+    pub modified: Option<SystemTime>,
+    pub start_sample: i64,
+    pub num_samples: usize,
+    pub num_peaks: usize,
+}
+
+/// Decoded samples, their sample rate and the source file's modification time.
+type CachedAudio = (Arc<Vec<f32>>, u32, Option<SystemTime>);
+
+/// Hit and miss counts of the image and video frame caches.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub image_hits: u64,
+    pub image_misses: u64,
+    pub video_hits: u64,
+    pub video_misses: u64,
+}
+
+impl CacheStats {
+    /// Fraction of all lookups served from cache, or `None` before any lookup.
+    pub fn hit_rate(&self) -> Option<f64> {
+        let hits = self.image_hits + self.video_hits;
+        let total = hits + self.image_misses + self.video_misses;
+        (total > 0).then(|| hits as f64 / total as f64)
+    }
+}
+
+#[derive(Default)]
+struct CacheCounters {
+    image_hits: AtomicU64,
+    image_misses: AtomicU64,
+    video_hits: AtomicU64,
+    video_misses: AtomicU64,
+}
+
+impl CacheCounters {
+    fn record(hits: &AtomicU64, misses: &AtomicU64, hit: bool) {
+        let counter = if hit { hits } else { misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
 pub struct CacheManager {
+    config: Mutex<CacheConfig>,
     image_cache: Mutex<LruCache<String, Image>>,
     video_cache: Mutex<LruCache<String, Image>>,
-    audio_cache: Mutex<std::collections::HashMap<uuid::Uuid, Arc<Vec<f32>>>>,
+    /// Decoded interleaved stereo audio per asset, with the sample rate it was decoded at
+    /// and the file's modification time when it was read.
+    audio_cache: Mutex<std::collections::HashMap<uuid::Uuid, CachedAudio>>,
+    /// Min/max peak pairs for drawing audio waveforms.
+    waveform_cache: Mutex<LruCache<WaveformKey, Arc<Vec<(f32, f32)>>>>,
+    /// Most recent evaluation error per node, shown in the node editor.
+    node_errors: Mutex<std::collections::HashMap<uuid::Uuid, String>>,
+    /// Whether `node_errors` is non-empty, so successful evaluations skip its lock.
+    has_node_errors: AtomicBool,
+    /// Most recent evaluated value per output pin, as display text for the node editor.
+    pin_values: Mutex<std::collections::HashMap<(uuid::Uuid, String), String>>,
+    /// Whether evaluations fill `pin_values`; on while the node editor shows live values.
+    record_pin_values: AtomicBool,
+    counters: CacheCounters,
 }
 
 impl CacheManager {
     pub fn new() -> Self {
-        let image_capacity = NonZeroUsize::new(DEFAULT_IMAGE_CACHE_SIZE)
-            .expect("DEFAULT_IMAGE_CACHE_SIZE must be > 0");
-        let video_capacity = NonZeroUsize::new(DEFAULT_VIDEO_CACHE_SIZE)
-            .expect("DEFAULT_VIDEO_CACHE_SIZE must be > 0");
+        Self::with_config(CacheConfig::default())
+    }
 
+    pub fn with_config(config: CacheConfig) -> Self {
+        let (image_capacity, video_capacity) = config.capacities();
         Self {
+            config: Mutex::new(config),
             image_cache: Mutex::new(LruCache::new(image_capacity)),
             video_cache: Mutex::new(LruCache::new(video_capacity)),
             audio_cache: Mutex::new(std::collections::HashMap::new()),
+            waveform_cache: Mutex::new(LruCache::new(
+                NonZeroUsize::new(WAVEFORM_CACHE_SIZE).unwrap(),
+            )),
+            node_errors: Mutex::new(std::collections::HashMap::new()),
+            has_node_errors: AtomicBool::new(false),
+            pin_values: Mutex::new(std::collections::HashMap::new()),
+            record_pin_values: AtomicBool::new(false),
+            counters: CacheCounters::default(),
+        }
+    }
+
+    pub fn config(&self) -> CacheConfig {
+        *self.config.lock().unwrap()
+    }
+
+    /// Apply new capacities to the live caches.
+    ///
+    /// Cached entries are kept; when a cache shrinks, its least recently used
+    /// entries are evicted.
+    pub fn resize(&self, config: CacheConfig) {
+        let (image_capacity, video_capacity) = config.capacities();
+        *self.config.lock().unwrap() = config;
+        self.image_cache.lock().unwrap().resize(image_capacity);
+        self.video_cache.lock().unwrap().resize(video_capacity);
+        self.enforce_memory_limit(&self.video_cache, &self.image_cache);
+        self.enforce_memory_limit(&self.image_cache, &self.video_cache);
+    }
+
+    /// Hit and miss counts since creation or the last `reset_stats`.
+    ///
+    /// Only `get_image` and `get_video_frame` lookups are counted; a `put_*`
+    /// follows a miss and would otherwise be counted twice.
+    pub fn stats(&self) -> CacheStats {
+        let c = &self.counters;
+        CacheStats {
+            image_hits: c.image_hits.load(Ordering::Relaxed),
+            image_misses: c.image_misses.load(Ordering::Relaxed),
+            video_hits: c.video_hits.load(Ordering::Relaxed),
+            video_misses: c.video_misses.load(Ordering::Relaxed),
+        }
+    }
+
+    pub fn reset_stats(&self) {
+        let c = &self.counters;
+        for counter in [
+            &c.image_hits,
+            &c.image_misses,
+            &c.video_hits,
+            &c.video_misses,
+        ] {
+            counter.store(0, Ordering::Relaxed);
         }
     }
 
     pub fn get_image(&self, path: &str) -> Option<Image> {
-        self.image_cache.lock().unwrap().get(path).cloned()
+        let image = self.image_cache.lock().unwrap().get(path).cloned();
+        let c = &self.counters;
+        CacheCounters::record(&c.image_hits, &c.image_misses, image.is_some());
+        image
     }
 
     pub fn put_image(&self, path: &str, image: &Image) {
@@ -37,16 +205,106 @@ impl CacheManager {
             .lock()
             .unwrap()
             .put(path.to_string(), image.clone());
+        self.enforce_memory_limit(&self.image_cache, &self.video_cache);
     }
 
     pub fn get_video_frame(&self, path: &str, frame_number: u64) -> Option<Image> {
         let key = Self::video_key(path, frame_number);
-        self.video_cache.lock().unwrap().get(&key).cloned()
+        let frame = self.video_cache.lock().unwrap().get(&key).cloned();
+        let c = &self.counters;
+        CacheCounters::record(&c.video_hits, &c.video_misses, frame.is_some());
+        frame
+    }
+
+    /// Whether a frame is cached, without touching the LRU order or the stats.
+    fn contains_video_frame(&self, path: &str, frame_number: u64) -> bool {
+        let key = Self::video_key(path, frame_number);
+        self.video_cache.lock().unwrap().contains(&key)
     }
 
     pub fn put_video_frame(&self, path: &str, frame_number: u64, image: &Image) {
         let key = Self::video_key(path, frame_number);
         self.video_cache.lock().unwrap().put(key, image.clone());
+        self.enforce_memory_limit(&self.video_cache, &self.image_cache);
+    }
+
+    /// Evict least recently used entries of `cache` until both caches fit in
+    /// `max_memory_bytes`. The most recent entry of `cache` is always kept.
+    fn enforce_memory_limit(
+        &self,
+        cache: &Mutex<LruCache<String, Image>>,
+        other: &Mutex<LruCache<String, Image>>,
+    ) {
+        let Some(max_bytes) = self.config.lock().unwrap().max_memory_bytes else {
+            return;
+        };
+        // Lock one cache at a time so put_image and put_video_frame cannot deadlock
+        let other_bytes: usize = other
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(_, i)| i.data.len())
+            .sum();
+        let mut cache = cache.lock().unwrap();
+        let mut total = other_bytes + cache.iter().map(|(_, i)| i.data.len()).sum::<usize>();
+        while total > max_bytes && cache.len() > 1 {
+            match cache.pop_lru() {
+                Some((_, evicted)) => total -= evicted.data.len(),
+                None => break,
+            }
+        }
+    }
+
+    /// Decode frames `start_frame..end_frame` of a video into the frame cache ahead of use.
+    ///
+    /// Frames are decoded in order on the calling thread, so the file's single
+    /// reader moves forward without seeking. Frames that are already cached are
+    /// skipped, and remaining frames are skipped once `cancel` is set.
+    pub fn warm_from_frame_range(
+        &self,
+        loader: &dyn LoadPlugin,
+        path: &str,
+        start_frame: u64,
+        end_frame: u64,
+        cancel: &AtomicBool,
+    ) -> Result<(), LibraryError> {
+        for frame_number in start_frame..end_frame {
+            if cancel.load(Ordering::Relaxed) {
+                break;
+            }
+            if self.contains_video_frame(path, frame_number) {
+                continue;
+            }
+            let request = LoadRequest::VideoFrame {
+                path: path.to_string(),
+                frame_number,
+                stream_index: None,
+                input_color_space: None,
+                output_color_space: None,
+            };
+            loader.load(&request, self)?;
+        }
+        Ok(())
+    }
+
+    /// Remove every image and video frame entry decoded from `path`.
+    ///
+    /// Used when a media file changes on disk or is reimported so stale
+    /// decodes are not reused. Video frame keys are `path::frame`, so both
+    /// caches are scanned: O(capacity) in the worst case.
+    pub fn evict_path(&self, path: &str) {
+        let frame_prefix = format!("{}::", path);
+        for cache in [&self.image_cache, &self.video_cache] {
+            let mut cache = cache.lock().unwrap();
+            let stale_keys: Vec<String> = cache
+                .iter()
+                .filter(|(key, _)| key.as_str() == path || key.starts_with(&frame_prefix))
+                .map(|(key, _)| key.clone())
+                .collect();
+            for key in stale_keys {
+                cache.pop(&key);
+            }
+        }
     }
 
     fn video_key(path: &str, frame_number: u64) -> String {
@@ -54,10 +312,124 @@ impl CacheManager {
     }
 
     pub fn get_audio(&self, id: uuid::Uuid) -> Option<Arc<Vec<f32>>> {
-        self.audio_cache.lock().unwrap().get(&id).cloned()
+        self.audio_cache
+            .lock()
+            .unwrap()
+            .get(&id)
+            .map(|(data, _, _)| data.clone())
+    }
+
+    /// Sample rate the cached audio of `id` was decoded at.
+    pub fn get_audio_sample_rate(&self, id: uuid::Uuid) -> Option<u32> {
+        self.audio_cache
+            .lock()
+            .unwrap()
+            .get(&id)
+            .map(|(_, sample_rate, _)| *sample_rate)
+    }
+
+    /// Modification time of the file the cached audio of `id` was decoded from.
+    pub fn get_audio_modified(&self, id: uuid::Uuid) -> Option<SystemTime> {
+        self.audio_cache
+            .lock()
+            .unwrap()
+            .get(&id)
+            .and_then(|(_, _, modified)| *modified)
+    }
+
+    /// Cache decoded audio of `id`. Waveform peaks of a previous decode are dropped.
+    pub fn put_audio(
+        &self,
+        id: uuid::Uuid,
+        data: Vec<f32>,
+        sample_rate: u32,
+        modified: Option<SystemTime>,
+    ) {
+        {
+            let mut waveforms = self.waveform_cache.lock().unwrap();
+            let stale_keys: Vec<WaveformKey> = waveforms
+                .iter()
+                .filter(|(key, _)| key.asset_id == id)
+                .map(|(key, _)| key.clone())
+                .collect();
+            for key in stale_keys {
+                waveforms.pop(&key);
+            }
+        }
+        self.audio_cache
+            .lock()
+            .unwrap()
+            .insert(id, (Arc::new(data), sample_rate, modified));
+    }
+
+    pub fn get_waveform(&self, key: &WaveformKey) -> Option<Arc<Vec<(f32, f32)>>> {
+        self.waveform_cache.lock().unwrap().get(key).cloned()
+    }
+
+    pub fn put_waveform(&self, key: WaveformKey, peaks: Arc<Vec<(f32, f32)>>) {
+        self.waveform_cache.lock().unwrap().put(key, peaks);
     }
 
-    pub fn put_audio(&self, id: uuid::Uuid, data: Vec<f32>) {
-        self.audio_cache.lock().unwrap().insert(id, Arc::new(data));
+    pub fn get_node_error(&self, node_id: uuid::Uuid) -> Option<String> {
+        self.node_errors.lock().unwrap().get(&node_id).cloned()
+    }
+
+    pub fn record_node_error(&self, node_id: uuid::Uuid, message: String) {
+        let mut errors = self.node_errors.lock().unwrap();
+        errors.insert(node_id, message);
+        self.has_node_errors.store(true, Ordering::Release);
+    }
+
+    /// Called after every successful evaluation; only locks while some node has an error.
+    pub fn clear_node_error(&self, node_id: uuid::Uuid) {
+        if !self.has_node_errors.load(Ordering::Acquire) {
+            return;
+        }
+        let mut errors = self.node_errors.lock().unwrap();
+        if errors.remove(&node_id).is_some() {
+            self.has_node_errors
+                .store(!errors.is_empty(), Ordering::Release);
+        }
+    }
+
+    /// Drop per-node state of nodes for which `exists` returns false.
+    pub fn prune_nodes(&self, exists: impl Fn(uuid::Uuid) -> bool) {
+        if self.has_node_errors.load(Ordering::Acquire) {
+            let mut errors = self.node_errors.lock().unwrap();
+            errors.retain(|id, _| exists(*id));
+            self.has_node_errors
+                .store(!errors.is_empty(), Ordering::Release);
+        }
+        self.pin_values
+            .lock()
+            .unwrap()
+            .retain(|(id, _), _| exists(*id));
+    }
+
+    /// Turn recording of live pin values on or off. Turning it off drops the recorded values.
+    pub fn set_pin_value_recording(&self, enabled: bool) {
+        let was_enabled = self.record_pin_values.swap(enabled, Ordering::AcqRel);
+        if was_enabled && !enabled {
+            self.pin_values.lock().unwrap().clear();
+        }
+    }
+
+    pub fn records_pin_values(&self) -> bool {
+        self.record_pin_values.load(Ordering::Acquire)
+    }
+
+    pub fn get_pin_value(&self, node_id: uuid::Uuid, pin_name: &str) -> Option<String> {
+        self.pin_values
+            .lock()
+            .unwrap()
+            .get(&(node_id, pin_name.to_string()))
+            .cloned()
+    }
+
+    pub fn record_pin_value(&self, node_id: uuid::Uuid, pin_name: &str, value: String) {
+        self.pin_values
+            .lock()
+            .unwrap()
+            .insert((node_id, pin_name.to_string()), value);
     }
 }
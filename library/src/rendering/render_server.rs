@@ -1,23 +1,59 @@
 use log::error;
-use std::sync::Arc;
-use std::sync::mpsc::{Receiver, Sender, TryRecvError, channel};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{Receiver, TryRecvError, channel};
+use std::sync::{Arc, Condvar, Mutex};
 use std::thread;
 use uuid::Uuid;
 
-use crate::cache::SharedCacheManager;
+use crate::cache::{CacheManager, SharedCacheManager};
+use crate::error::LibraryError;
 use crate::pipeline::engine::EvalEngine;
 use crate::plugin::PluginManager;
 use crate::rendering::renderer::{RenderOutput, Renderer};
 use crate::rendering::skia_renderer::SkiaRenderer;
+use crate::runtime::Image;
 use crate::runtime::frame::Region;
 
+/// Called on the render thread after each frame with `(frame_number, total_frames)`.
+pub type ProgressCallback = Arc<dyn Fn(u64, u64) + Send + Sync>;
+
 pub struct RenderServer {
-    tx: Sender<RenderRequest>,
+    queue: Arc<RequestQueue>,
     rx_result: Receiver<RenderResult>,
+    rx_node_output: Receiver<NodeOutputResult>,
+    next_node_output_id: AtomicU64,
+    cancel_flag: Arc<AtomicBool>,
+    /// Composition of the render in progress, if any.
+    rendering: Arc<Mutex<Option<Uuid>>>,
+    progress: Arc<Mutex<Option<ProgressCallback>>>,
     #[allow(dead_code)]
     handle: Option<thread::JoinHandle<()>>,
 }
 
+/// Pending requests, shared with the render thread.
+#[derive(Default)]
+struct RequestQueue {
+    pending: Mutex<VecDeque<RenderRequest>>,
+    available: Condvar,
+}
+
+impl RequestQueue {
+    fn push(&self, request: RenderRequest) {
+        self.pending.lock().unwrap().push_back(request);
+        self.available.notify_one();
+    }
+
+    /// Wait until at least one request is queued, then take all of them.
+    fn take_all(&self) -> VecDeque<RenderRequest> {
+        let mut pending = self.pending.lock().unwrap();
+        while pending.is_empty() {
+            pending = self.available.wait(pending).unwrap();
+        }
+        std::mem::take(&mut *pending)
+    }
+}
+
 /// Parameters for composition-based rendering via EvalEngine.
 pub struct CompositionRenderParams {
     pub project: crate::project::project::Project,
@@ -27,10 +63,40 @@ pub struct CompositionRenderParams {
     pub region: Option<Region>,
 }
 
+impl CompositionRenderParams {
+    /// Pixel size of the render target: the whole composition, or only
+    /// `region` when set. The transform node shifts content by the region's
+    /// origin, so a region render matches the same crop of a full render.
+    pub fn target_size(&self, composition: &crate::project::project::Composition) -> (u32, u32) {
+        let scale = self.render_scale;
+        match &self.region {
+            Some(region) => (
+                ((region.width * scale).ceil() as u32).max(1),
+                ((region.height * scale).ceil() as u32).max(1),
+            ),
+            None => (
+                (composition.width as f64 * scale).round() as u32,
+                (composition.height as f64 * scale).round() as u32,
+            ),
+        }
+    }
+}
+
+/// Parameters for rendering a single node output pin (e.g. to pin it in the node editor).
+pub struct NodeOutputRenderParams {
+    pub project: crate::project::project::Project,
+    pub composition_id: Uuid,
+    pub node_id: Uuid,
+    pub pin_name: String,
+    pub frame_number: u64,
+    pub render_scale: f64,
+}
+
 enum RenderRequest {
     RenderComposition(CompositionRenderParams),
+    /// Never dropped by the drain; only a newer request for the same pin replaces it.
+    RenderNodeOutput(u64, NodeOutputRenderParams),
     SetSharingContext(usize, Option<isize>),
-    #[allow(dead_code)]
     Shutdown,
 }
 
@@ -39,10 +105,26 @@ pub struct RenderResult {
     pub region: Option<Region>,
 }
 
+pub struct NodeOutputResult {
+    /// ID returned by [`RenderServer::send_node_output_request`].
+    pub request_id: u64,
+    pub node_id: Uuid,
+    pub pin_name: String,
+    pub result: Result<Image, LibraryError>,
+}
+
 impl RenderServer {
     pub fn new(plugin_manager: Arc<PluginManager>, cache_manager: SharedCacheManager) -> Self {
-        let (tx, rx) = channel::<RenderRequest>();
+        let queue = Arc::new(RequestQueue::default());
+        let thread_queue = Arc::clone(&queue);
         let (tx_result, rx_result) = channel::<RenderResult>();
+        let (tx_node_output, rx_node_output) = channel::<NodeOutputResult>();
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        let thread_cancel_flag = Arc::clone(&cancel_flag);
+        let rendering = Arc::new(Mutex::new(None));
+        let thread_rendering = Arc::clone(&rendering);
+        let progress: Arc<Mutex<Option<ProgressCallback>>> = Arc::new(Mutex::new(None));
+        let thread_progress = Arc::clone(&progress);
 
         let handle = thread::spawn(move || {
             let mut current_background_color = crate::runtime::color::Color {
@@ -56,16 +138,51 @@ impl RenderServer {
             let mut current_width: u32 = 1920;
             let mut current_height: u32 = 1080;
 
-            let eval_engine = EvalEngine::with_default_evaluators();
+            let mut eval_engine = EvalEngine::with_default_evaluators();
+            eval_engine.set_cancel_flag(Arc::clone(&thread_cancel_flag));
+            // A render context of its own, so node output renders at other frames
+            // do not reset the history of stateful effects in the preview
+            let mut node_output_engine = EvalEngine::with_default_evaluators();
+            node_output_engine.set_cancel_flag(Arc::clone(&thread_cancel_flag));
 
             loop {
-                let mut req = match rx.recv() {
-                    Ok(r) => r,
-                    Err(_) => break,
+                let (node_requests, mut pending): (Vec<_>, VecDeque<_>) = thread_queue
+                    .take_all()
+                    .into_iter()
+                    .partition(|r| matches!(r, RenderRequest::RenderNodeOutput(..)));
+
+                let mut node_outputs: Vec<(u64, NodeOutputRenderParams)> = Vec::new();
+                for request in node_requests {
+                    if let RenderRequest::RenderNodeOutput(id, params) = request {
+                        node_outputs.retain(|(_, p)| {
+                            p.node_id != params.node_id || p.pin_name != params.pin_name
+                        });
+                        node_outputs.push((id, params));
+                    }
+                }
+                for (request_id, params) in node_outputs {
+                    thread_cancel_flag.store(false, Ordering::Relaxed);
+                    let result = render_node_output(
+                        &node_output_engine,
+                        &plugin_manager,
+                        &cache_manager,
+                        &params,
+                    );
+                    // Cancelled renders are reported too, so the caller can ask again
+                    let _ = tx_node_output.send(NodeOutputResult {
+                        request_id,
+                        node_id: params.node_id,
+                        pin_name: params.pin_name,
+                        result,
+                    });
+                }
+
+                let Some(mut req) = pending.pop_front() else {
+                    continue;
                 };
 
                 // Drain accumulated requests to jump to the latest state
-                while let Ok(next_req) = rx.try_recv() {
+                for next_req in pending {
                     match next_req {
                         RenderRequest::Shutdown => {
                             req = RenderRequest::Shutdown;
@@ -83,11 +200,16 @@ impl RenderServer {
                             }
                             req = next_req;
                         }
+                        RenderRequest::RenderNodeOutput(..) => {}
                     }
                 }
 
                 match req {
                     RenderRequest::RenderComposition(params) => {
+                        // A cancel only applies to renders already in flight
+                        thread_cancel_flag.store(false, Ordering::Relaxed);
+                        *thread_rendering.lock().unwrap() = Some(params.composition_id);
+
                         let composition =
                             match params.project.get_composition(params.composition_id) {
                                 Some(comp) => comp,
@@ -97,18 +219,7 @@ impl RenderServer {
                                 }
                             };
 
-                        let render_scale = params.render_scale;
-                        let (target_width, target_height) = if let Some(region) = &params.region {
-                            (
-                                (region.width * render_scale).round() as u32,
-                                (region.height * render_scale).round() as u32,
-                            )
-                        } else {
-                            (
-                                (composition.width as f64 * render_scale).round() as u32,
-                                (composition.height as f64 * render_scale).round() as u32,
-                            )
-                        };
+                        let (target_width, target_height) = params.target_size(composition);
 
                         let bg_color = composition.background_color.clone();
 
@@ -143,7 +254,7 @@ impl RenderServer {
 
                         let property_evaluators = plugin_manager.get_property_evaluators();
 
-                        match eval_engine.evaluate_composition(
+                        let result = eval_engine.evaluate_composition(
                             &params.project,
                             composition,
                             &plugin_manager,
@@ -153,12 +264,29 @@ impl RenderServer {
                             params.frame_number,
                             params.render_scale,
                             params.region.clone(),
-                        ) {
+                        );
+                        *thread_rendering.lock().unwrap() = None;
+                        match result {
                             Ok(output) => {
+                                // With a region the target already holds just that region;
+                                // the preview places it at the region's origin.
                                 let _ = tx_result.send(RenderResult {
                                     output,
                                     region: params.region,
                                 });
+
+                                let callback = thread_progress.lock().unwrap().clone();
+                                if let Some(callback) = callback {
+                                    let total_frames =
+                                        (composition.duration * composition.fps).round() as u64;
+                                    callback(params.frame_number, total_frames);
+                                }
+                            }
+                            Err(LibraryError::Cancelled) => {
+                                log::debug!(
+                                    "[RenderServer] Render of frame {} cancelled",
+                                    params.frame_number
+                                );
                             }
                             Err(e) => {
                                 error!("EvalEngine render failed: {}", e);
@@ -168,21 +296,69 @@ impl RenderServer {
                     RenderRequest::SetSharingContext(handle, hwnd) => {
                         renderer.set_sharing_context(handle, hwnd);
                     }
+                    RenderRequest::RenderNodeOutput(..) => {}
                     RenderRequest::Shutdown => break,
                 }
             }
         });
 
         RenderServer {
-            tx,
+            queue,
             rx_result,
+            rx_node_output,
+            next_node_output_id: AtomicU64::new(0),
+            cancel_flag,
+            rendering,
+            progress,
             handle: Some(handle),
         }
     }
 
     /// Send a composition render request using the pull-based EvalEngine.
     pub fn send_composition_request(&self, params: CompositionRenderParams) {
-        let _ = self.tx.send(RenderRequest::RenderComposition(params));
+        self.queue.push(RenderRequest::RenderComposition(params));
+    }
+
+    /// Queue a render of one node output pin. Returns the ID its
+    /// [`NodeOutputResult`] will carry.
+    pub fn send_node_output_request(&self, params: NodeOutputRenderParams) -> u64 {
+        let request_id = self.next_node_output_id.fetch_add(1, Ordering::Relaxed);
+        self.queue
+            .push(RenderRequest::RenderNodeOutput(request_id, params));
+        request_id
+    }
+
+    pub fn poll_node_output(&self) -> Result<NodeOutputResult, TryRecvError> {
+        self.rx_node_output.try_recv()
+    }
+
+    /// Drop every queued render of `composition_id` and abort it if it is in progress.
+    /// Call this when the editor switches away from a composition.
+    pub fn cancel_composition(&self, composition_id: Uuid) {
+        self.queue.pending.lock().unwrap().retain(|request| {
+            !matches!(request, RenderRequest::RenderComposition(params)
+                if params.composition_id == composition_id)
+        });
+        if *self.rendering.lock().unwrap() == Some(composition_id) {
+            self.cancel_current();
+        }
+    }
+
+    /// Install the callback invoked after each rendered frame, replacing any previous one.
+    pub fn set_progress_callback(&self, callback: ProgressCallback) {
+        *self.progress.lock().unwrap() = Some(callback);
+    }
+
+    /// Number of requests waiting for the render thread.
+    /// The render in progress, if any, is not counted.
+    pub fn current_load(&self) -> usize {
+        self.queue.pending.lock().unwrap().len()
+    }
+
+    /// Abort the render currently in progress, if any.
+    /// Call this before sending a request that makes the current render stale.
+    pub fn cancel_current(&self) {
+        self.cancel_flag.store(true, Ordering::Relaxed);
     }
 
     pub fn poll_result(&self) -> Result<RenderResult, TryRecvError> {
@@ -190,6 +366,58 @@ impl RenderServer {
     }
 
     pub fn set_sharing_context(&self, handle: usize, hwnd: Option<isize>) {
-        let _ = self.tx.send(RenderRequest::SetSharingContext(handle, hwnd));
+        self.queue
+            .push(RenderRequest::SetSharingContext(handle, hwnd));
+    }
+}
+
+impl Drop for RenderServer {
+    fn drop(&mut self) {
+        self.cancel_current();
+        self.queue.push(RenderRequest::Shutdown);
+    }
+}
+
+/// Render one node output pin on a CPU surface at the request's render scale.
+fn render_node_output(
+    eval_engine: &EvalEngine,
+    plugin_manager: &PluginManager,
+    cache_manager: &CacheManager,
+    params: &NodeOutputRenderParams,
+) -> Result<Image, LibraryError> {
+    let composition = params
+        .project
+        .get_composition(params.composition_id)
+        .ok_or_else(|| {
+            LibraryError::project(format!("Composition {} not found", params.composition_id))
+        })?;
+    let width = ((composition.width as f64 * params.render_scale).round() as u32).max(1);
+    let height = ((composition.height as f64 * params.render_scale).round() as u32).max(1);
+    let mut renderer = SkiaRenderer::new(
+        width,
+        height,
+        composition.background_color.clone(),
+        false,
+        None,
+    );
+    renderer.clear()?;
+
+    let output = eval_engine.evaluate_node_output(
+        &params.project,
+        composition,
+        plugin_manager,
+        &mut renderer,
+        cache_manager,
+        plugin_manager.get_property_evaluators(),
+        params.node_id,
+        &params.pin_name,
+        params.frame_number,
+        params.render_scale,
+    )?;
+    match output {
+        RenderOutput::Image(img) => Ok(img),
+        RenderOutput::Texture(_) => Err(LibraryError::render(
+            "Node output render received Texture output (unsupported)".to_string(),
+        )),
     }
 }
@@ -4,6 +4,7 @@ use crate::runtime::Image;
 
 use crate::runtime::draw_type::PathEffect;
 use crate::runtime::entity::StyleConfig;
+use crate::runtime::gradient::{GradientStop, GradientType};
 use crate::runtime::transform::Transform;
 
 #[derive(Clone, Debug)]
@@ -20,12 +21,24 @@ pub struct TextureInfo {
 }
 
 /// Blend mode for compositing two images.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+///
+/// Follows the separable and non-separable blend modes of the PDF specification.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum BlendMode {
     Normal,
     Multiply,
     Screen,
     Overlay,
+    SoftLight,
+    HardLight,
+    ColorDodge,
+    ColorBurn,
+    Difference,
+    Exclusion,
+    Hue,
+    Saturation,
+    Color,
+    Luminosity,
 }
 
 pub trait Renderer {
@@ -35,6 +48,17 @@ pub trait Renderer {
         transform: &Transform,
     ) -> Result<(), LibraryError>;
 
+    /// Draw an image tagged with an embedded ICC profile.
+    ///
+    /// The pixels are converted from the profile's color space to sRGB before drawing.
+    /// Without a profile (or with one that cannot be parsed) this behaves like `draw_layer`.
+    fn draw_image_with_color_profile(
+        &mut self,
+        image: &Image,
+        icc_profile: Option<&[u8]>,
+        transform: &Transform,
+    ) -> Result<(), LibraryError>;
+
     fn rasterize_text_layer(
         &mut self,
         text: &str,
@@ -68,6 +92,17 @@ pub trait Renderer {
         transform: &Transform,
     ) -> Result<RenderOutput, LibraryError>;
 
+    /// Fill a `resolution`-sized rect with a gradient. Points are in layer pixels.
+    fn rasterize_gradient_layer(
+        &mut self,
+        gradient_type: GradientType,
+        start: (f32, f32),
+        end: (f32, f32),
+        stops: &[GradientStop],
+        resolution: (f32, f32),
+        transform: &Transform,
+    ) -> Result<RenderOutput, LibraryError>;
+
     fn read_surface(&mut self, output: &RenderOutput) -> Result<Image, LibraryError>;
 
     fn finalize(&mut self) -> Result<RenderOutput, LibraryError>;
@@ -4,24 +4,77 @@ use crate::rendering::renderer::{BlendMode, RenderOutput, Renderer, TextureInfo}
 use crate::rendering::shader_utils::{self, ShaderContext};
 use crate::rendering::skia_utils::{
     GpuContext, create_gpu_context, create_image_from_texture, create_surface, image_to_skia,
-    surface_to_image,
+    image_to_skia_with_icc, surface_to_image,
 };
 use crate::runtime::Image;
 use crate::runtime::color::Color;
-use crate::runtime::draw_type::{DrawStyle, PathEffect};
+use crate::runtime::draw_type::{BlendMode as LayerBlendMode, DrawStyle, PathEffect};
 use crate::runtime::entity::StyleConfig;
+use crate::runtime::frame::Region;
+use crate::runtime::gradient::{GradientStop, GradientType};
 use crate::runtime::transform::Transform;
 use crate::timing::ScopedTimer;
 use log::debug;
 use skia_safe::path_effect::PathEffect as SkPathEffect;
+use std::sync::OnceLock;
 
 use skia_safe::{
-    AlphaType, Canvas, Color as SkColor, ColorType, CubicResampler, ISize, ImageInfo, Paint,
-    PaintStyle, SamplingOptions, Surface,
+    AlphaType, Canvas, Color as SkColor, ColorType, CubicResampler, ISize, Image as SkImage,
+    ImageInfo, Paint, PaintStyle, SamplingOptions, Surface,
 };
 
 use super::{paint_utils, shape_renderer, text_renderer};
 
+/// Skia blend mode for a track/clip blend mode, or `None` for `Subtract`,
+/// which Skia has no built-in mode for.
+fn layer_sk_blend_mode(mode: LayerBlendMode) -> Option<skia_safe::BlendMode> {
+    Some(match mode {
+        LayerBlendMode::Normal => skia_safe::BlendMode::SrcOver,
+        LayerBlendMode::Multiply => skia_safe::BlendMode::Multiply,
+        LayerBlendMode::Screen => skia_safe::BlendMode::Screen,
+        LayerBlendMode::Overlay => skia_safe::BlendMode::Overlay,
+        LayerBlendMode::Add => skia_safe::BlendMode::Plus,
+        LayerBlendMode::SoftLight => skia_safe::BlendMode::SoftLight,
+        LayerBlendMode::HardLight => skia_safe::BlendMode::HardLight,
+        LayerBlendMode::Difference => skia_safe::BlendMode::Difference,
+        LayerBlendMode::Exclusion => skia_safe::BlendMode::Exclusion,
+        LayerBlendMode::Darken => skia_safe::BlendMode::Darken,
+        LayerBlendMode::Lighten => skia_safe::BlendMode::Lighten,
+        LayerBlendMode::ColorDodge => skia_safe::BlendMode::ColorDodge,
+        LayerBlendMode::ColorBurn => skia_safe::BlendMode::ColorBurn,
+        LayerBlendMode::Hue => skia_safe::BlendMode::Hue,
+        LayerBlendMode::Saturation => skia_safe::BlendMode::Saturation,
+        LayerBlendMode::Color => skia_safe::BlendMode::Color,
+        LayerBlendMode::Luminosity => skia_safe::BlendMode::Luminosity,
+        LayerBlendMode::Subtract => return None,
+    })
+}
+
+/// Separable subtract blend (`max(dst - src, 0)`) on premultiplied colors.
+///
+/// Compiled once and shared, since `begin_layer` runs for every subtract layer.
+fn subtract_blender() -> Option<skia_safe::Blender> {
+    static BLENDER: OnceLock<Option<skia_safe::Blender>> = OnceLock::new();
+    BLENDER.get_or_init(compile_subtract_blender).clone()
+}
+
+fn compile_subtract_blender() -> Option<skia_safe::Blender> {
+    const SUBTRACT_SKSL: &str = r#"
+        half4 main(half4 src, half4 dst) {
+            half3 blended = max(dst.rgb * src.a - src.rgb * dst.a, 0.0);
+            half3 rgb = (1.0 - src.a) * dst.rgb + (1.0 - dst.a) * src.rgb + blended;
+            return half4(rgb, src.a + dst.a - src.a * dst.a);
+        }
+    "#;
+    match skia_safe::RuntimeEffect::make_for_blender(SUBTRACT_SKSL, None) {
+        Ok(effect) => effect.make_blender(skia_safe::Data::new_empty(), None),
+        Err(e) => {
+            log::error!("Failed to compile subtract blender: {}", e);
+            None
+        }
+    }
+}
+
 pub struct SkiaRenderer {
     width: u32,
     height: u32,
@@ -91,6 +144,83 @@ impl SkiaRenderer {
         renderer
     }
 
+    /// Read back a rectangular region of the rendered surface as an image.
+    ///
+    /// `region` is in surface pixels and is clamped to the surface bounds.
+    pub fn capture_region(&mut self, region: &Region) -> Result<Image, LibraryError> {
+        let left = region.x.max(0.0).floor() as i32;
+        let top = region.y.max(0.0).floor() as i32;
+        let right = (region.x + region.width).min(self.width as f64).ceil() as i32;
+        let bottom = (region.y + region.height).min(self.height as f64).ceil() as i32;
+        if right <= left || bottom <= top {
+            return Err(LibraryError::render(format!(
+                "Capture region {:?} is outside the {}x{} surface",
+                region, self.width, self.height
+            )));
+        }
+
+        if let Some(context) = self.gpu_context.as_mut() {
+            context.direct_context.flush_and_submit();
+        }
+
+        let width = (right - left) as u32;
+        let height = (bottom - top) as u32;
+        let row_bytes = (width * 4) as usize;
+        let mut buffer = vec![0u8; (height as usize) * row_bytes];
+        let image_info = ImageInfo::new(
+            ISize::new(width as i32, height as i32),
+            ColorType::RGBA8888,
+            AlphaType::Premul,
+            None,
+        );
+        if !self
+            .surface
+            .read_pixels(&image_info, &mut buffer, row_bytes, (left, top))
+        {
+            return Err(LibraryError::render(
+                "Failed to read surface region pixels".to_string(),
+            ));
+        }
+        Ok(Image {
+            width,
+            height,
+            data: buffer,
+        })
+    }
+
+    /// Start an isolated layer: subsequent draws go to an offscreen buffer that
+    /// `end_layer` composites onto the surface as a whole with `opacity` and `blend_mode`.
+    ///
+    /// Overlapping content inside the layer is therefore blended once, instead of
+    /// each draw being faded individually.
+    pub fn begin_layer(&mut self, opacity: f64, blend_mode: Option<LayerBlendMode>) {
+        let canvas: &Canvas = self.surface.canvas();
+        let alpha = opacity.clamp(0.0, 1.0) as f32;
+        match blend_mode.filter(|mode| *mode != LayerBlendMode::Normal) {
+            None => {
+                canvas.save_layer_alpha(None::<skia_safe::Rect>, (alpha * 255.0).round() as u32);
+            }
+            Some(mode) => {
+                let mut paint = Paint::default();
+                paint.set_alpha_f(alpha);
+                match layer_sk_blend_mode(mode) {
+                    Some(sk_mode) => {
+                        paint.set_blend_mode(sk_mode);
+                    }
+                    None => {
+                        paint.set_blender(subtract_blender());
+                    }
+                }
+                canvas.save_layer(&skia_safe::canvas::SaveLayerRec::default().paint(&paint));
+            }
+        }
+    }
+
+    /// Composite the layer started by the matching `begin_layer`.
+    pub fn end_layer(&mut self) {
+        self.surface.canvas().restore();
+    }
+
     fn background_sk_color(&self) -> SkColor {
         SkColor::from_argb(
             self.background_color.a,
@@ -100,6 +230,25 @@ impl SkiaRenderer {
         )
     }
 
+    /// Draw a Skia image onto the main surface with the given transform.
+    fn draw_sk_image(&mut self, src_image: &SkImage, transform: &Transform) {
+        let canvas: &Canvas = self.surface.canvas();
+        let matrix = paint_utils::build_transform_matrix(transform);
+
+        canvas.save();
+        canvas.concat(&matrix);
+
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        paint.set_alpha_f(transform.opacity as f32);
+
+        let cubic_resampler = CubicResampler::mitchell();
+        let sampling = SamplingOptions::from(cubic_resampler);
+        canvas.draw_image_with_sampling_options(src_image, (0, 0), sampling, Some(&paint));
+
+        canvas.restore();
+    }
+
     fn create_layer_surface(&mut self) -> Result<Surface, LibraryError> {
         create_surface(
             self.width,
@@ -146,7 +295,6 @@ impl Renderer for SkiaRenderer {
         transform: &Transform,
     ) -> Result<(), LibraryError> {
         let _timer = ScopedTimer::debug("SkiaRenderer::draw_layer");
-        let canvas: &Canvas = self.surface.canvas();
 
         let src_image = match layer {
             RenderOutput::Image(img) => image_to_skia(img)?,
@@ -166,21 +314,19 @@ impl Renderer for SkiaRenderer {
             }
         };
 
-        let matrix = paint_utils::build_transform_matrix(transform);
-
-        canvas.save();
-        canvas.concat(&matrix);
-
-        let mut paint = Paint::default();
-        paint.set_anti_alias(true);
-        paint.set_alpha_f(transform.opacity as f32);
-
-        let cubic_resampler = CubicResampler::mitchell();
-        let sampling = SamplingOptions::from(cubic_resampler);
-        canvas.draw_image_with_sampling_options(&src_image, (0, 0), sampling, Some(&paint));
-
-        canvas.restore();
+        self.draw_sk_image(&src_image, transform);
+        Ok(())
+    }
 
+    fn draw_image_with_color_profile(
+        &mut self,
+        image: &Image,
+        icc_profile: Option<&[u8]>,
+        transform: &Transform,
+    ) -> Result<(), LibraryError> {
+        let _timer = ScopedTimer::debug("SkiaRenderer::draw_image_with_color_profile");
+        let src_image = image_to_skia_with_icc(image, icc_profile)?;
+        self.draw_sk_image(&src_image, transform);
         Ok(())
     }
 
@@ -246,6 +392,82 @@ impl Renderer for SkiaRenderer {
         paint_utils::snapshot_surface(&mut layer, &mut self.gpu_context, self.width, self.height)
     }
 
+    fn rasterize_gradient_layer(
+        &mut self,
+        gradient_type: GradientType,
+        start: (f32, f32),
+        end: (f32, f32),
+        stops: &[GradientStop],
+        resolution: (f32, f32),
+        transform: &Transform,
+    ) -> Result<RenderOutput, LibraryError> {
+        let mut layer = self.create_layer_surface()?;
+        {
+            let canvas: &Canvas = layer.canvas();
+            canvas.clear(skia_safe::Color::TRANSPARENT);
+
+            let colors: Vec<SkColor> = stops
+                .iter()
+                .map(|stop| {
+                    SkColor::from_argb(stop.color.a, stop.color.r, stop.color.g, stop.color.b)
+                })
+                .collect();
+            let positions: Vec<f32> = stops.iter().map(|stop| stop.offset as f32).collect();
+
+            let shader = match gradient_type {
+                GradientType::Linear => skia_safe::Shader::linear_gradient(
+                    (start, end),
+                    colors.as_slice(),
+                    positions.as_slice(),
+                    skia_safe::TileMode::Clamp,
+                    None,
+                    None,
+                ),
+                GradientType::Radial => {
+                    let radius = (end.0 - start.0).hypot(end.1 - start.1);
+                    skia_safe::Shader::radial_gradient(
+                        start,
+                        radius.max(f32::EPSILON),
+                        colors.as_slice(),
+                        positions.as_slice(),
+                        skia_safe::TileMode::Clamp,
+                        None,
+                        None,
+                    )
+                }
+                GradientType::Conic => {
+                    // Sweep starts at the direction of the end point
+                    let angle = (end.1 - start.1).atan2(end.0 - start.0).to_degrees();
+                    let rotation = skia_safe::Matrix::rotate_deg_pivot(angle, start);
+                    skia_safe::Shader::sweep_gradient(
+                        start,
+                        colors.as_slice(),
+                        positions.as_slice(),
+                        skia_safe::TileMode::Clamp,
+                        None,
+                        None,
+                        &rotation,
+                    )
+                }
+            }
+            .ok_or_else(|| LibraryError::render("Failed to create gradient shader"))?;
+
+            let mut paint = Paint::default();
+            paint.set_anti_alias(true);
+            paint.set_shader(shader);
+            paint.set_alpha_f(transform.opacity as f32);
+
+            let matrix = paint_utils::build_transform_matrix(transform);
+            canvas.save();
+            canvas.concat(&matrix);
+            let rect = skia_safe::Rect::from_wh(resolution.0, resolution.1);
+            canvas.draw_rect(rect, &paint);
+            canvas.restore();
+        }
+
+        paint_utils::snapshot_surface(&mut layer, &mut self.gpu_context, self.width, self.height)
+    }
+
     fn rasterize_text_layer(
         &mut self,
         text: &str,
@@ -767,6 +989,16 @@ impl Renderer for SkiaRenderer {
                 BlendMode::Multiply => skia_safe::BlendMode::Multiply,
                 BlendMode::Screen => skia_safe::BlendMode::Screen,
                 BlendMode::Overlay => skia_safe::BlendMode::Overlay,
+                BlendMode::SoftLight => skia_safe::BlendMode::SoftLight,
+                BlendMode::HardLight => skia_safe::BlendMode::HardLight,
+                BlendMode::ColorDodge => skia_safe::BlendMode::ColorDodge,
+                BlendMode::ColorBurn => skia_safe::BlendMode::ColorBurn,
+                BlendMode::Difference => skia_safe::BlendMode::Difference,
+                BlendMode::Exclusion => skia_safe::BlendMode::Exclusion,
+                BlendMode::Hue => skia_safe::BlendMode::Hue,
+                BlendMode::Saturation => skia_safe::BlendMode::Saturation,
+                BlendMode::Color => skia_safe::BlendMode::Color,
+                BlendMode::Luminosity => skia_safe::BlendMode::Luminosity,
             };
 
             let mut paint = Paint::default();
@@ -9,7 +9,9 @@ use skia_safe::gpu::gl::Interface;
 use skia_safe::gpu::{self, DirectContext, SurfaceOrigin};
 use skia_safe::images::raster_from_data;
 use skia_safe::surfaces;
-use skia_safe::{AlphaType, ColorType, Data, ISize, Image as SkImage, ImageInfo, Surface};
+use skia_safe::{
+    AlphaType, ColorSpace, ColorType, Data, ISize, Image as SkImage, ImageInfo, Surface,
+};
 
 #[cfg(all(feature = "gl", target_os = "windows"))]
 use glutin::config::ConfigSurfaceTypes;
@@ -381,6 +383,32 @@ pub(crate) fn image_to_skia(image: &Image) -> Result<SkImage, LibraryError> {
         .ok_or_else(|| LibraryError::render("Failed to create Skia image".to_string()))
 }
 
+/// Convert an image tagged with an ICC profile into an sRGB Skia image.
+///
+/// Falls back to the untagged conversion when no profile is given or it cannot be parsed.
+pub(crate) fn image_to_skia_with_icc(
+    image: &Image,
+    icc_profile: Option<&[u8]>,
+) -> Result<SkImage, LibraryError> {
+    let src = image_to_skia(image)?;
+    let Some(color_space) = icc_profile.and_then(ColorSpace::new_icc) else {
+        return Ok(src);
+    };
+    let tagged = src
+        .reinterpret_color_space(color_space)
+        .ok_or_else(|| LibraryError::render("Failed to apply ICC profile".to_string()))?;
+
+    // Render surfaces are untagged, so convert into sRGB here rather than at draw time.
+    let info = ImageInfo::new_n32_premul(
+        (image.width as i32, image.height as i32),
+        ColorSpace::new_srgb(),
+    );
+    let mut surface = surfaces::raster(&info, None, None)
+        .ok_or_else(|| LibraryError::render("Cannot create Skia surface".to_string()))?;
+    surface.canvas().draw_image(&tagged, (0, 0), None);
+    Ok(surface.image_snapshot())
+}
+
 pub(crate) fn surface_to_image(
     surface: &mut Surface,
     width: u32,
@@ -1,5 +1,6 @@
 use super::paint_utils::build_transform_matrix;
 use crate::pipeline::ensemble::config::{DecoratorConfig, EffectorConfig, EnsembleData};
+use crate::pipeline::ensemble::decompose::{layout_text, make_font};
 use crate::pipeline::ensemble::decorators::{BackplateShape, BackplateTarget};
 use crate::pipeline::ensemble::types::TransformData;
 use crate::runtime::draw_type::DrawStyle;
@@ -55,15 +56,11 @@ pub(crate) fn render_ensemble_text(
     canvas.concat(&matrix);
 
     // Create font
-    let font_mgr = skia_safe::FontMgr::default();
-    let typeface = font_mgr
-        .match_family_style(font_name, skia_safe::FontStyle::default())
-        .unwrap_or_else(|| {
-            font_mgr
-                .legacy_make_typeface(None, skia_safe::FontStyle::default())
-                .unwrap()
-        });
-    let font = skia_safe::Font::from_typeface(typeface, size as f32);
+    let Some(font) = make_font(font_name, size as f32) else {
+        log::error!("No typeface available for '{}'", font_name);
+        canvas.restore();
+        return;
+    };
 
     // Get font metrics for accurate baseline positioning
     let (_, metrics) = font.metrics();
@@ -85,18 +82,10 @@ pub(crate) fn render_ensemble_text(
         }
     };
 
-    // Text decomposition: measure each character
-    let mut char_data = Vec::new();
-    let mut x_pos = 0.0f32;
-
-    for ch in text.chars() {
-        let ch_str = ch.to_string();
-        let (advance, _bounds) = font.measure_str(&ch_str, None);
-
-        // Store char data
-        char_data.push((ch, x_pos, advance));
-        x_pos += advance;
-    }
+    // Text decomposition: the same per-character layout as TextDecomposer
+    let layout = layout_text(text, &font);
+    let char_data = &layout.chars;
+    let glyph_height = metrics.descent - metrics.ascent;
 
     let total_chars = char_data.len();
 
@@ -235,13 +224,13 @@ pub(crate) fn render_ensemble_text(
                 match target {
                     BackplateTarget::Char => {
                         // Draw backplate for each character individually
-                        for (i, (_ch, base_x, advance)) in char_data.iter().enumerate() {
+                        for (i, c) in char_data.iter().enumerate() {
                             if let Some(ch_transform) = char_transforms.get(i) {
                                 canvas.save();
 
                                 // Apply same transform logic as character rendering
-                                let char_center_x = base_x + (size as f32 / 2.0);
-                                let char_center_y = 0.0; // Baseline is 0 in local coords
+                                let char_center_x = c.x + (size as f32 / 2.0);
+                                let char_center_y = c.top;
 
                                 // Translate to character center
                                 canvas.translate((char_center_x, char_center_y));
@@ -255,15 +244,11 @@ pub(crate) fn render_ensemble_text(
                                 // Translate back
                                 canvas.translate((-char_center_x, -char_center_y));
 
-                                // Calculate bounds relative to baseline
-                                let top = baseline_offset + metrics.ascent;
-                                let bottom = baseline_offset + metrics.descent;
-
                                 let char_rect = skia_safe::Rect::from_xywh(
-                                    *base_x - padding.3,
-                                    top - padding.0,
-                                    *advance + padding.1 + padding.3,
-                                    (bottom - top) + padding.0 + padding.2,
+                                    c.x - padding.3,
+                                    c.top - padding.0,
+                                    c.advance + padding.1 + padding.3,
+                                    glyph_height + padding.0 + padding.2,
                                 );
                                 draw_backplate(canvas, char_rect);
 
@@ -271,32 +256,31 @@ pub(crate) fn render_ensemble_text(
                             }
                         }
                     }
-                    BackplateTarget::Block | BackplateTarget::Line => {
-                        // Draw backplate for entire text
-                        let total_width = x_pos;
-                        let top = baseline_offset + metrics.ascent;
-                        let bottom = baseline_offset + metrics.descent;
-
-                        let backplate_rect = skia_safe::Rect::from_xywh(
-                            -padding.0,
-                            top - padding.0,
-                            total_width + padding.0 + padding.2,
-                            (bottom - top) + padding.0 + padding.2,
-                        );
-                        draw_backplate(canvas, backplate_rect);
+                    BackplateTarget::Line => {
+                        // One backplate per line
+                        for (line, width) in layout.line_widths.iter().enumerate() {
+                            let top = line as f32 * layout.line_height;
+                            let backplate_rect = skia_safe::Rect::from_xywh(
+                                -padding.0,
+                                top - padding.0,
+                                width + padding.0 + padding.2,
+                                glyph_height + padding.0 + padding.2,
+                            );
+                            draw_backplate(canvas, backplate_rect);
+                        }
                     }
-                    BackplateTarget::Parts => {
-                        // TODO: Parts target for advanced word/sentence grouping
-                        // For now, fall back to Block
-                        let total_width = x_pos;
-                        let top = baseline_offset + metrics.ascent;
-                        let bottom = baseline_offset + metrics.descent;
-
+                    // TODO: Parts target for advanced word/sentence grouping;
+                    // for now it falls back to Block
+                    BackplateTarget::Block | BackplateTarget::Parts => {
+                        // One backplate around every line
+                        let total_width = layout.line_widths.iter().copied().fold(0.0, f32::max);
+                        let last_top =
+                            layout.line_widths.len().saturating_sub(1) as f32 * layout.line_height;
                         let backplate_rect = skia_safe::Rect::from_xywh(
                             -padding.0,
-                            top - padding.0,
+                            -padding.0,
                             total_width + padding.0 + padding.2,
-                            (bottom - top) + padding.0 + padding.2,
+                            last_top + glyph_height + padding.0 + padding.2,
                         );
                         draw_backplate(canvas, backplate_rect);
                     }
@@ -306,14 +290,14 @@ pub(crate) fn render_ensemble_text(
     }
 
     // Render each character with its transform
-    for (i, (ch, base_x, _advance)) in char_data.iter().enumerate() {
+    for (i, c) in char_data.iter().enumerate() {
         let ch_transform = &char_transforms[i];
 
         // Apply character transform
         canvas.save();
 
-        let char_center_x = base_x + size as f32 / 2.0;
-        let char_center_y = 0.0;
+        let char_center_x = c.x + size as f32 / 2.0;
+        let char_center_y = c.top;
 
         // Translate to character center
         canvas.translate((char_center_x, char_center_y));
@@ -324,21 +308,18 @@ pub(crate) fn render_ensemble_text(
         // Translate back
         canvas.translate((-char_center_x, -char_center_y));
 
-        // Create paint with opacity
+        // Create paint with the patched color and opacity
         let mut paint = Paint::default();
-        let final_alpha = (base_color.a as f32 * ch_transform.opacity).clamp(0.0, 255.0) as u8;
+        let color = ch_transform.paint_color(&base_color);
         paint.set_color(skia_safe::Color::from_argb(
-            final_alpha,
-            base_color.r,
-            base_color.g,
-            base_color.b,
+            color.a, color.r, color.g, color.b,
         ));
         paint.set_anti_alias(true);
 
         // Draw character
-        let ch_str = ch.to_string();
+        let ch_str = c.ch.to_string();
         // Use baseline_offset for accurate positioning to match standard text rendering
-        canvas.draw_str(&ch_str, (*base_x, baseline_offset), &font, &paint);
+        canvas.draw_str(&ch_str, (c.x, c.top + baseline_offset), &font, &paint);
 
         canvas.restore();
     }
@@ -0,0 +1,129 @@
+//! Tone curves for color grading.
+
+use crate::error::LibraryError;
+
+/// A tone curve through control points in `[0, 1]²`, stored in properties as a
+/// JSON array of `[input, output]` pairs.
+///
+/// Segments are cubic Béziers whose handles sit a third of the way along each
+/// segment, with slopes chosen so the curve never overshoots between points
+/// (monotone cubic interpolation).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColorCurve {
+    points: Vec<(f64, f64)>,
+}
+
+impl Default for ColorCurve {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl ColorCurve {
+    /// The straight line from (0, 0) to (1, 1).
+    pub fn identity() -> Self {
+        Self {
+            points: vec![(0.0, 0.0), (1.0, 1.0)],
+        }
+    }
+
+    /// Build a curve from control points. Points are clamped to `[0, 1]²`, sorted
+    /// by input, and points sharing an input keep only the last one.
+    pub fn new(points: Vec<(f64, f64)>) -> Self {
+        let mut points: Vec<(f64, f64)> = points
+            .into_iter()
+            .filter(|(x, y)| x.is_finite() && y.is_finite())
+            .map(|(x, y)| (x.clamp(0.0, 1.0), y.clamp(0.0, 1.0)))
+            .collect();
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        points.dedup_by(|later, earlier| {
+            let same = later.0 == earlier.0;
+            if same {
+                *earlier = *later;
+            }
+            same
+        });
+        if points.is_empty() {
+            return Self::identity();
+        }
+        Self { points }
+    }
+
+    pub fn points(&self) -> &[(f64, f64)] {
+        &self.points
+    }
+
+    /// Parse the JSON form used by curve properties. An empty string is the identity.
+    pub fn parse(s: &str) -> Result<Self, LibraryError> {
+        if s.trim().is_empty() {
+            return Ok(Self::identity());
+        }
+        let points: Vec<(f64, f64)> = serde_json::from_str(s)?;
+        Ok(Self::new(points))
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(&self.points).unwrap_or_else(|_| "[]".to_string())
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.points == [(0.0, 0.0), (1.0, 1.0)]
+    }
+
+    /// Evaluate the curve at `x`. Inputs outside the first/last point hold that
+    /// point's output.
+    pub fn evaluate(&self, x: f64) -> f64 {
+        let pts = &self.points;
+        let n = pts.len();
+        if n == 1 || x <= pts[0].0 {
+            return pts[0].1;
+        }
+        if x >= pts[n - 1].0 {
+            return pts[n - 1].1;
+        }
+
+        let i = pts.partition_point(|p| p.0 <= x) - 1;
+        let (x0, y0) = pts[i];
+        let (x1, y1) = pts[i + 1];
+        let h = x1 - x0;
+        let t = (x - x0) / h;
+        let (m0, m1) = (self.slope(i), self.slope(i + 1));
+
+        // Bézier control values at t = 1/3 and 2/3 of the segment
+        let c1 = y0 + m0 * h / 3.0;
+        let c2 = y1 - m1 * h / 3.0;
+        let u = 1.0 - t;
+        let y = u * u * u * y0 + 3.0 * u * u * t * c1 + 3.0 * u * t * t * c2 + t * t * t * y1;
+        y.clamp(0.0, 1.0)
+    }
+
+    /// 256-entry lookup table sampled at `i / 255`.
+    pub fn lookup_table(&self) -> [u8; 256] {
+        let mut table = [0u8; 256];
+        for (i, entry) in table.iter_mut().enumerate() {
+            *entry = (self.evaluate(i as f64 / 255.0) * 255.0).round() as u8;
+        }
+        table
+    }
+
+    /// Fritsch–Carlson tangent at point `i`.
+    fn slope(&self, i: usize) -> f64 {
+        let pts = &self.points;
+        let secant = |a: usize| (pts[a + 1].1 - pts[a].1) / (pts[a + 1].0 - pts[a].0);
+        if i == 0 {
+            return secant(0);
+        }
+        if i == pts.len() - 1 {
+            return secant(i - 1);
+        }
+        let (d0, d1) = (secant(i - 1), secant(i));
+        if d0 * d1 <= 0.0 {
+            0.0
+        } else {
+            // Weighted harmonic mean keeps each segment monotone
+            let (h0, h1) = (pts[i].0 - pts[i - 1].0, pts[i + 1].0 - pts[i].0);
+            let (w0, w1) = (2.0 * h1 + h0, h1 + 2.0 * h0);
+            (w0 + w1) / (w0 / d0 + w1 / d1)
+        }
+    }
+}
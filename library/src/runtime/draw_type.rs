@@ -3,7 +3,7 @@ use ordered_float::OrderedFloat;
 use serde::{Deserialize, Serialize};
 use std::hash::{Hash, Hasher};
 
-/// Blend mode for track compositing.
+/// Blend mode for track and clip compositing.
 #[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub enum BlendMode {
     Normal,
@@ -11,6 +11,43 @@ pub enum BlendMode {
     Screen,
     Overlay,
     Add,
+    SoftLight,
+    HardLight,
+    Difference,
+    Exclusion,
+    Subtract,
+    Darken,
+    Lighten,
+    ColorDodge,
+    ColorBurn,
+    Hue,
+    Saturation,
+    Color,
+    Luminosity,
+}
+
+impl BlendMode {
+    /// Every blend mode, in the order shown in the UI.
+    pub const ALL: [BlendMode; 18] = [
+        BlendMode::Normal,
+        BlendMode::Multiply,
+        BlendMode::Screen,
+        BlendMode::Overlay,
+        BlendMode::SoftLight,
+        BlendMode::HardLight,
+        BlendMode::Difference,
+        BlendMode::Exclusion,
+        BlendMode::Add,
+        BlendMode::Subtract,
+        BlendMode::Darken,
+        BlendMode::Lighten,
+        BlendMode::ColorDodge,
+        BlendMode::ColorBurn,
+        BlendMode::Hue,
+        BlendMode::Saturation,
+        BlendMode::Color,
+        BlendMode::Luminosity,
+    ];
 }
 
 impl Default for BlendMode {
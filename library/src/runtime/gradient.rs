@@ -0,0 +1,79 @@
+//! Gradient description shared by gradient sources and the renderer.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::LibraryError;
+use crate::runtime::color::Color;
+
+/// Shape of a gradient fill.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Default, PartialEq, Eq, Hash)]
+pub enum GradientType {
+    /// Colors run along the line from the start point to the end point.
+    #[default]
+    Linear,
+    /// Colors run outward from the start point; the end point sets the radius.
+    Radial,
+    /// Colors sweep around the start point.
+    Conic,
+}
+
+impl GradientType {
+    /// Parse the dropdown value used by the `gradient_type` source property.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Linear" | "linear" => Some(Self::Linear),
+            "Radial" | "radial" => Some(Self::Radial),
+            "Conic" | "conic" => Some(Self::Conic),
+            _ => None,
+        }
+    }
+}
+
+/// A color stop; `offset` runs from 0.0 (start) to 1.0 (end).
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct GradientStop {
+    pub offset: f64,
+    pub color: Color,
+}
+
+impl GradientStop {
+    pub fn new(offset: f64, color: Color) -> Self {
+        Self { offset, color }
+    }
+
+    /// Black-to-white stops used by new gradient sources.
+    pub fn default_stops() -> Vec<GradientStop> {
+        vec![
+            GradientStop::new(0.0, Color::black()),
+            GradientStop::new(1.0, Color::white()),
+        ]
+    }
+
+    /// Parse the JSON array stored in the `stops` source property.
+    ///
+    /// Offsets are clamped to 0.0..=1.0 and the stops are sorted by offset.
+    pub fn parse_list(json: &str) -> Result<Vec<GradientStop>, LibraryError> {
+        let mut stops: Vec<GradientStop> = serde_json::from_str(json)?;
+        if stops.is_empty() {
+            return Err(LibraryError::InvalidArgument(
+                "Gradient needs at least one stop".to_string(),
+            ));
+        }
+        for stop in &mut stops {
+            if !stop.offset.is_finite() {
+                return Err(LibraryError::InvalidArgument(format!(
+                    "Invalid gradient stop offset: {}",
+                    stop.offset
+                )));
+            }
+            stop.offset = stop.offset.clamp(0.0, 1.0);
+        }
+        stops.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+        Ok(stops)
+    }
+
+    /// Serialize stops into the JSON form stored in the `stops` source property.
+    pub fn to_json(stops: &[GradientStop]) -> String {
+        serde_json::to_string(stops).unwrap_or_else(|_| "[]".to_string())
+    }
+}
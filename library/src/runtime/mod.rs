@@ -1,8 +1,10 @@
 pub mod color;
+pub mod curve;
 pub mod draw_type;
 pub mod effect;
 pub mod entity;
 pub mod frame;
+pub mod gradient;
 pub mod image;
 pub mod transform;
 
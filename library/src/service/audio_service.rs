@@ -1,6 +1,7 @@
 use crate::audio::engine::AudioEngine;
+use crate::audio::waveform::compute_peaks;
 use crate::project::project::Project;
-use crate::rendering::cache::CacheManager;
+use crate::rendering::cache::{CacheManager, WaveformKey};
 use std::sync::{Arc, RwLock};
 
 pub struct AudioService {
@@ -122,9 +123,10 @@ impl AudioService {
 
         std::thread::spawn(move || {
             use crate::audio::loader::AudioLoader;
+            let modified = std::fs::metadata(&path).and_then(|m| m.modified()).ok();
             match AudioLoader::load_entire_file(&path, target_sample_rate) {
                 Ok(data) => {
-                    cache_manager.put_audio(asset_id, data);
+                    cache_manager.put_audio(asset_id, data, target_sample_rate, modified);
                     log::info!("Loaded audio for asset {}", asset_id);
                 }
                 Err(e) => {
@@ -134,6 +136,44 @@ impl AudioService {
         });
     }
 
+    /// `(min, max)` peaks of `num_samples` sample frames of a decoded audio
+    /// file, starting at `start_sample`, split into `num_peaks` buckets.
+    /// Samples are counted at the rate the file was decoded at; frames
+    /// before the start or past the end are silent.
+    ///
+    /// Empty until the asset's audio has been loaded. Results are cached per
+    /// file modification time (`CacheManager::get_audio_modified`), so an
+    /// edited file is summarized again once it is reloaded.
+    pub fn get_waveform_peaks(
+        &self,
+        asset_id: uuid::Uuid,
+        modified: Option<std::time::SystemTime>,
+        start_sample: i64,
+        num_samples: usize,
+        num_peaks: usize,
+    ) -> Vec<(f32, f32)> {
+        let key = WaveformKey {
+            asset_id,
+            modified,
+            start_sample,
+            num_samples,
+            num_peaks,
+        };
+        if let Some(peaks) = self.cache_manager.get_waveform(&key) {
+            return peaks.to_vec();
+        }
+
+        let Some(audio_data) = self.cache_manager.get_audio(asset_id) else {
+            return Vec::new();
+        };
+
+        // Decoded audio is cached as interleaved stereo
+        let peaks = compute_peaks(&audio_data, 2, start_sample, num_samples, num_peaks);
+        self.cache_manager
+            .put_waveform(key, Arc::new(peaks.clone()));
+        peaks
+    }
+
     pub fn render_audio(&self, start_time: f64, duration: f64) -> Vec<f32> {
         let sample_rate = self.audio_engine.get_sample_rate();
         let channels = self.audio_engine.get_channels();
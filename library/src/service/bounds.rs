@@ -1,7 +1,7 @@
 //! Source content bounds calculation.
 //!
 //! Provides bounds (x, y, width, height) for source content such as text, shape,
-//! and SkSL, primarily used for gizmo overlay in the preview panel.
+//! SkSL and gradients, primarily used for gizmo overlay in the preview panel.
 
 use crate::plugin::{EvaluationContext, PropertyEvaluatorRegistry};
 use crate::project::property::PropertyMap;
@@ -18,7 +18,7 @@ pub fn get_source_content_bounds(
     frame_number: u64,
     property_evaluators: &PropertyEvaluatorRegistry,
 ) -> Option<(f32, f32, f32, f32)> {
-    let eval_time = source_eval_time(source, comp_fps, frame_number);
+    let eval_time = source.compute_local_time(frame_number, comp_fps);
     let props = &source.properties;
 
     match source.kind {
@@ -52,7 +52,7 @@ pub fn get_source_content_bounds(
                 Some((0.0, 0.0, 100.0, 100.0))
             }
         }
-        SourceKind::SkSL => {
+        SourceKind::SkSL | SourceKind::Gradient => {
             let width = eval_number(
                 props,
                 "width",
@@ -75,13 +75,6 @@ pub fn get_source_content_bounds(
     }
 }
 
-fn source_eval_time(source: &SourceData, comp_fps: f64, frame_number: u64) -> f64 {
-    let delta_frames = frame_number as f64 - source.in_frame as f64;
-    let time_offset = delta_frames / comp_fps;
-    let source_start_time = source.source_begin_frame as f64 / source.fps;
-    source_start_time + time_offset
-}
-
 fn eval_number(
     props: &PropertyMap,
     key: &str,
@@ -94,6 +87,7 @@ fn eval_number(
         let ctx = EvaluationContext {
             property_map: props,
             fps,
+            owner_id: None,
         };
         let val = evaluators.evaluate(prop, time, &ctx);
         val.get_as::<f64>().unwrap_or(default)
@@ -113,6 +107,7 @@ fn eval_string(
         let ctx = EvaluationContext {
             property_map: props,
             fps,
+            owner_id: None,
         };
         let val = evaluators.evaluate(prop, time, &ctx);
         val.get_as::<String>()
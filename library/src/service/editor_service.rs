@@ -60,6 +60,18 @@ impl EditorService {
 
     pub fn set_project(&self, project: Project) {
         let _ = self.project_manager.set_project(project);
+        self.prune_removed_nodes();
+    }
+
+    /// Forget cached per-node state (e.g. evaluation errors, effect history) of
+    /// nodes no longer in the project.
+    pub(crate) fn prune_removed_nodes(&self) {
+        let cache_manager = self.get_cache_manager();
+        let plugin_manager = self.get_plugin_manager();
+        self.with_project(|p| {
+            cache_manager.prune_nodes(|id| p.nodes.contains_key(&id));
+            plugin_manager.prune_effect_instances(&|id| p.nodes.contains_key(&id));
+        });
     }
 
     pub fn get_audio_service(&self) -> Arc<AudioService> {
@@ -1,17 +1,22 @@
+use crate::builtin::loaders::LoadPlugin;
 use crate::cache::SharedCacheManager;
 use crate::error::LibraryError;
 use crate::pipeline::engine::EvalEngine;
 use crate::plugin::{ExportFormat, ExportSettings, PluginManager};
 use crate::project::project::{Composition, Project};
+use crate::project::source::SourceKind;
 use crate::rendering::renderer::{RenderOutput, Renderer};
 use crate::rendering::skia_renderer::SkiaRenderer;
 use crate::runtime::Image;
 use crate::timing::{ScopedTimer, measure_info};
 use log::{error, info};
+use rayon::prelude::*;
 
+use std::collections::HashMap;
 use std::ops::Range;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::{self, SyncSender};
+use std::sync::{Arc, Mutex};
 use std::thread::{self, JoinHandle};
 
 struct SaveTask {
@@ -30,6 +35,9 @@ pub struct ExportService {
     temp_files: Vec<String>,
     eval_engine: EvalEngine,
     plugin_manager: Arc<PluginManager>,
+    /// Decodes ahead for [`Self::warm_video_cache`], with its thread count.
+    /// Rebuilt when a call asks for a different `max_parallel`.
+    warm_pool: Mutex<Option<(usize, Arc<rayon::ThreadPool>)>>,
 }
 
 impl ExportService {
@@ -65,8 +73,13 @@ impl ExportService {
             export_settings,
             exporter_id,
             temp_files: Vec::new(),
-            eval_engine: EvalEngine::with_default_evaluators(),
+            eval_engine: {
+                let mut engine = EvalEngine::with_default_evaluators();
+                engine.set_export(true);
+                engine
+            },
             plugin_manager,
+            warm_pool: Mutex::new(None),
         }
     }
 
@@ -93,7 +106,7 @@ impl ExportService {
         base_template = base_template.replace("{composition}", &composition.name);
         let has_frame_token = base_template.contains("{frame");
 
-        let video_output = if matches!(export_format, ExportFormat::Video) {
+        let video_output = if export_format.is_single_file() {
             let clean_stem = if has_frame_token {
                 Self::format_frame_token_in_string(&base_template, frame_range.start)
             } else {
@@ -149,9 +162,11 @@ impl ExportService {
                         format!("{}_{:03}.png", base_template, frame_index)
                     }
                 }
-                ExportFormat::Video => video_output.clone().unwrap_or_else(|| {
-                    format!("{}.{}", base_template, self.export_settings.container)
-                }),
+                ExportFormat::Video | ExportFormat::WebP | ExportFormat::Gif => {
+                    video_output.clone().unwrap_or_else(|| {
+                        format!("{}.{}", base_template, self.export_settings.container)
+                    })
+                }
             };
             sender
                 .send(SaveTask {
@@ -167,6 +182,107 @@ impl ExportService {
         Ok(())
     }
 
+    /// Pre-decode the video frames the given composition frames will need.
+    ///
+    /// Each file is warmed by one task that walks its ranges in order, since a
+    /// file has a single reader; up to `max_parallel` files decode at once.
+    /// Clips with a color space conversion are left to the render pass, since
+    /// warmed frames are decoded without one.
+    pub fn warm_video_cache(
+        &self,
+        project: &Project,
+        composition: &Composition,
+        cache_manager: &SharedCacheManager,
+        frame_range: Range<u64>,
+        max_parallel: usize,
+        cancel: &AtomicBool,
+    ) -> Result<(), LibraryError> {
+        let mut ranges_by_path: HashMap<String, (Arc<dyn LoadPlugin>, Vec<Range<u64>>)> =
+            HashMap::new();
+        for source in project.collect_sources(composition.id) {
+            if source.kind != SourceKind::Video || cancel.load(Ordering::Relaxed) {
+                continue;
+            }
+            let has_color_conversion =
+                ["input_color_space", "output_color_space"]
+                    .iter()
+                    .any(|key| {
+                        source
+                            .properties
+                            .get_string(key)
+                            .is_some_and(|s| !s.is_empty())
+                    });
+            let Some(path) = source.properties.get_string("file_path") else {
+                continue;
+            };
+            if has_color_conversion || path.is_empty() {
+                continue;
+            }
+
+            let start = frame_range.start.max(source.in_frame);
+            let end = frame_range.end.min(source.out_frame);
+            if start >= end {
+                continue;
+            }
+            let to_source_frame = |frame: u64| {
+                let local_time = source.compute_local_time(frame, composition.fps);
+                (local_time * source.fps).round().max(0.0) as u64
+            };
+
+            let range = to_source_frame(start)..to_source_frame(end);
+            if let Some((_, ranges)) = ranges_by_path.get_mut(&path) {
+                ranges.push(range);
+                continue;
+            }
+            let Some(loader) = self.plugin_manager.find_loader(&path) else {
+                continue;
+            };
+            ranges_by_path.insert(path, (loader, vec![range]));
+        }
+        if ranges_by_path.is_empty() {
+            return Ok(());
+        }
+
+        let num_threads = max_parallel.max(1);
+        let pool = {
+            let mut warm_pool = self.warm_pool.lock().unwrap();
+            match warm_pool.as_ref() {
+                Some((threads, pool)) if *threads == num_threads => pool.clone(),
+                _ => {
+                    let pool = Arc::new(
+                        rayon::ThreadPoolBuilder::new()
+                            .num_threads(num_threads)
+                            .build()
+                            .map_err(|e| {
+                                LibraryError::Runtime(format!(
+                                    "Failed to build warm-up pool: {}",
+                                    e
+                                ))
+                            })?,
+                    );
+                    *warm_pool = Some((num_threads, pool.clone()));
+                    pool
+                }
+            }
+        };
+        pool.install(|| {
+            ranges_by_path
+                .into_par_iter()
+                .try_for_each(|(path, (loader, mut ranges))| {
+                    ranges.sort_by_key(|range| range.start);
+                    ranges.into_iter().try_for_each(|range| {
+                        cache_manager.warm_from_frame_range(
+                            loader.as_ref(),
+                            &path,
+                            range.start,
+                            range.end,
+                            cancel,
+                        )
+                    })
+                })
+        })
+    }
+
     pub fn shutdown(mut self) -> Result<(), LibraryError> {
         self.save_tx.take();
         if let Some(handle) = self.saver_handle.take() {
@@ -15,17 +15,9 @@ impl AssetHandler {
     }
 
     pub fn is_asset_used(project: &Arc<RwLock<Project>>, asset_id: Uuid) -> bool {
-        if let Ok(proj) = super::read_project(project) {
-            // Check all clips in the nodes registry
-            for clip in proj.all_sources() {
-                if let Some(ref r) = clip.reference_id {
-                    if *r == asset_id {
-                        return true;
-                    }
-                }
-            }
-        }
-        false
+        super::read_project(project)
+            .map(|proj| !proj.find_clips_by_asset(asset_id).is_empty())
+            .unwrap_or(false)
     }
 
     pub fn remove_asset(
@@ -32,6 +32,27 @@ impl CompositionHandler {
         Ok(())
     }
 
+    pub fn set_audio_sample_rate(
+        project: &Arc<RwLock<Project>>,
+        id: Uuid,
+        sample_rate: u32,
+    ) -> Result<(), LibraryError> {
+        if sample_rate == 0 {
+            return Err(LibraryError::project(
+                "Audio sample rate must be positive".to_string(),
+            ));
+        }
+        let mut proj = super::write_project(project)?;
+        let comp = proj
+            .get_composition_mut(id)
+            .ok_or(LibraryError::project(format!(
+                "Composition not found: {}",
+                id
+            )))?;
+        comp.audio_sample_rate = sample_rate;
+        Ok(())
+    }
+
     pub fn add_composition(
         project: &Arc<RwLock<Project>>,
         name: &str,
@@ -1,13 +1,23 @@
 use crate::error::LibraryError;
 use crate::plugin::PluginManager;
 use crate::project::connection::{Connection, PinId};
-use crate::project::graph_node::GraphNode;
+use crate::project::graph_node::{
+    GraphNode, REROUTE_INPUT_PIN, REROUTE_OUTPUT_PIN, REROUTE_TYPE_ID,
+};
 use crate::project::node::Node;
 use crate::project::project::Project;
 use crate::project::property::PropertyMap;
+use serde::{Deserialize, Serialize};
 use std::sync::{Arc, RwLock};
 use uuid::Uuid;
 
+/// Graph nodes and the connections among them, as copied to the clipboard.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct GraphNodeSnapshot {
+    pub nodes: Vec<GraphNode>,
+    pub connections: Vec<Connection>,
+}
+
 pub struct GraphHandler;
 
 impl GraphHandler {
@@ -53,6 +63,7 @@ impl GraphHandler {
     ///
     /// For each pin type (image, shape), if the node has both an input and output connection,
     /// the upstream and downstream nodes are bridged together to preserve chain continuity.
+    /// Removing a subgraph also removes every node grouped inside it.
     pub fn remove_graph_node(
         project: &Arc<RwLock<Project>>,
         node_id: Uuid,
@@ -69,7 +80,11 @@ impl GraphHandler {
         // Before removing connections, bridge chains that pass through this node.
         // For each pin type pair (e.g., image_in/image_out, shape_in/shape_out),
         // if this node has an incoming and outgoing connection, reconnect them.
-        let pin_pairs = [("image_in", "image_out"), ("shape_in", "shape_out")];
+        let pin_pairs = [
+            ("image_in", "image_out"),
+            ("shape_in", "shape_out"),
+            (REROUTE_INPUT_PIN, REROUTE_OUTPUT_PIN),
+        ];
         let mut bridge_connections = Vec::new();
 
         for (in_pin, out_pin) in &pin_pairs {
@@ -90,13 +105,14 @@ impl GraphHandler {
             }
         }
 
-        // Remove from parent container's child_ids (Track or Layer)
+        // Remove from parent container's child_ids (Track, Layer, or subgraph)
         let parent_ids: Vec<Uuid> = proj
             .nodes
             .iter()
             .filter_map(|(id, n)| match n {
                 Node::Track(t) if t.child_ids.contains(&node_id) => Some(*id),
                 Node::Layer(l) if l.child_ids.contains(&node_id) => Some(*id),
+                Node::Graph(g) if g.child_ids.contains(&node_id) => Some(*id),
                 _ => None,
             })
             .collect();
@@ -107,11 +123,21 @@ impl GraphHandler {
             }
         }
 
-        // Remove all connections involving this node
-        proj.remove_connections_for_node(node_id);
+        // Grouped nodes go with their subgraph, without bridging
+        let mut removed = vec![node_id];
+        let mut i = 0;
+        while i < removed.len() {
+            if let Some(children) = proj.get_container_child_ids(removed[i]) {
+                removed.extend(children.iter().copied());
+            }
+            i += 1;
+        }
 
-        // Remove the node itself
-        proj.remove_node(node_id);
+        // Remove all connections involving these nodes, then the nodes themselves
+        for id in removed {
+            proj.remove_connections_for_node(id);
+            proj.remove_node(id);
+        }
 
         // Create bridge connections
         for (from_pin, to_pin) in bridge_connections {
@@ -157,6 +183,174 @@ impl GraphHandler {
         Ok(())
     }
 
+    /// Split a connection with a new reroute node in `container_id`.
+    ///
+    /// The connection is replaced by `from → reroute.in` and `reroute.out → to`.
+    /// Returns the reroute's ID.
+    pub fn insert_reroute(
+        project: &Arc<RwLock<Project>>,
+        container_id: Uuid,
+        connection_id: Uuid,
+    ) -> Result<Uuid, LibraryError> {
+        let mut proj = super::write_project(project)?;
+
+        if proj.get_container_child_ids(container_id).is_none() {
+            return Err(LibraryError::project(format!(
+                "Container {} not found",
+                container_id
+            )));
+        }
+        let conn = proj.remove_connection(connection_id).ok_or_else(|| {
+            LibraryError::project(format!("Connection {} not found", connection_id))
+        })?;
+
+        let reroute = GraphNode::new(REROUTE_TYPE_ID, PropertyMap::new());
+        let reroute_id = reroute.id;
+        proj.add_node(Node::Graph(reroute));
+        if let Some(children) = proj.get_container_child_ids_mut(container_id) {
+            children.push(reroute_id);
+        }
+        proj.add_connection(Connection::new(
+            conn.from,
+            PinId::new(reroute_id, REROUTE_INPUT_PIN),
+        ));
+        proj.add_connection(Connection::new(
+            PinId::new(reroute_id, REROUTE_OUTPUT_PIN),
+            conn.to,
+        ));
+
+        Ok(reroute_id)
+    }
+
+    /// Snapshot graph nodes and the connections among them for the clipboard.
+    ///
+    /// Connections to any node outside the set are left out. IDs that are not
+    /// graph nodes, or are subgraphs, are skipped.
+    pub fn copy_graph_nodes(
+        project: &Arc<RwLock<Project>>,
+        node_ids: &[Uuid],
+    ) -> Result<GraphNodeSnapshot, LibraryError> {
+        let proj = super::read_project(project)?;
+
+        let nodes: Vec<GraphNode> = node_ids
+            .iter()
+            .filter_map(|id| proj.get_graph_node(*id))
+            .filter(|node| !node.is_subgraph())
+            .cloned()
+            .collect();
+        let copied = |id: Uuid| nodes.iter().any(|node| node.id == id);
+        let connections = proj
+            .connections
+            .iter()
+            .filter(|c| copied(c.from.node_id) && copied(c.to.node_id))
+            .cloned()
+            .collect();
+
+        Ok(GraphNodeSnapshot { nodes, connections })
+    }
+
+    /// Create the nodes of a snapshot in a container with fresh IDs.
+    ///
+    /// Copies keep their `type_id` and the properties they had when copied, and
+    /// the snapshot's connections are recreated between the copies.
+    /// Returns `(original_id, copy_id)` pairs in snapshot order.
+    pub fn paste_graph_nodes(
+        project: &Arc<RwLock<Project>>,
+        container_id: Uuid,
+        snapshot: &GraphNodeSnapshot,
+    ) -> Result<Vec<(Uuid, Uuid)>, LibraryError> {
+        let mut proj = super::write_project(project)?;
+
+        if proj.get_container_child_ids(container_id).is_none() {
+            return Err(LibraryError::project(format!(
+                "Container {} not found (not a Track or Layer)",
+                container_id
+            )));
+        }
+
+        let copies: Vec<GraphNode> = snapshot
+            .nodes
+            .iter()
+            .map(|node| GraphNode::new(&node.type_id, node.properties.clone()))
+            .collect();
+        let mapping: Vec<(Uuid, Uuid)> = snapshot
+            .nodes
+            .iter()
+            .map(|node| node.id)
+            .zip(copies.iter().map(|node| node.id))
+            .collect();
+        let new_id = |id: Uuid| {
+            mapping
+                .iter()
+                .find(|(old, _)| *old == id)
+                .map(|(_, new)| *new)
+        };
+
+        let connections: Vec<Connection> = snapshot
+            .connections
+            .iter()
+            .filter_map(|c| {
+                Some(Connection::new(
+                    PinId::new(new_id(c.from.node_id)?, &c.from.pin_name),
+                    PinId::new(new_id(c.to.node_id)?, &c.to.pin_name),
+                ))
+            })
+            .collect();
+
+        for node in copies {
+            let node_id = node.id;
+            proj.add_node(Node::Graph(node));
+            if let Some(children) = proj.get_container_child_ids_mut(container_id) {
+                children.push(node_id);
+            }
+        }
+        for conn in connections {
+            proj.add_connection(conn);
+        }
+
+        Ok(mapping)
+    }
+
+    /// Group graph nodes of a container into a new subgraph in that container.
+    ///
+    /// Connections are left untouched: they are still evaluated between the
+    /// grouped nodes, and editors show the ones crossing the group boundary
+    /// as pins of the subgraph. Returns the subgraph's ID.
+    pub fn group_graph_nodes(
+        project: &Arc<RwLock<Project>>,
+        container_id: Uuid,
+        node_ids: &[Uuid],
+        name: &str,
+    ) -> Result<Uuid, LibraryError> {
+        let mut proj = super::write_project(project)?;
+
+        let children = proj.get_container_child_ids(container_id).ok_or_else(|| {
+            LibraryError::project(format!("Container {} not found", container_id))
+        })?;
+        if node_ids.is_empty() {
+            return Err(LibraryError::project("No nodes to group".to_string()));
+        }
+        if let Some(id) = node_ids
+            .iter()
+            .find(|id| !children.contains(id) || proj.get_graph_node(**id).is_none())
+        {
+            return Err(LibraryError::project(format!(
+                "Graph node {} not found in container {}",
+                id, container_id
+            )));
+        }
+
+        let group = GraphNode::new_subgraph(name, node_ids.to_vec());
+        let group_id = group.id;
+        if let Some(children) = proj.get_container_child_ids_mut(container_id) {
+            children.retain(|id| !node_ids.contains(id));
+            children.push(group_id);
+        }
+        proj.add_node(Node::Graph(group));
+
+        Ok(group_id)
+    }
+
     /// Reorder the effect chain for a source.
     ///
     /// Rewires the image chain connections to match the new order.
@@ -1,7 +1,7 @@
 use crate::error::LibraryError;
 
 use crate::project::project::Project;
-use crate::project::property::{PropertyMap, PropertyTarget, PropertyValue};
+use crate::project::property::{EasingHandle, PropertyMap, PropertyTarget, PropertyValue};
 use std::sync::{Arc, RwLock};
 use uuid::Uuid;
 
@@ -86,6 +86,60 @@ impl KeyframeHandler {
         Ok(())
     }
 
+    /// Move several keyframes of one property in a single write, returning the
+    /// new index of every keyframe (indexed by its old index) after re-sorting.
+    pub fn update_keyframes_by_index(
+        project: &Arc<RwLock<Project>>,
+        clip_id: Uuid,
+        target: PropertyTarget,
+        property_key: &str,
+        updates: &[(usize, Option<f64>, Option<PropertyValue>)],
+    ) -> Result<Vec<usize>, LibraryError> {
+        let mut proj = super::write_project(project)?;
+        let prop_map = Self::resolve_property_map_mut(&mut proj, clip_id, target)?;
+
+        let property = prop_map
+            .get_mut(property_key)
+            .ok_or_else(|| LibraryError::project(format!("Property {} not found", property_key)))?;
+
+        property
+            .update_keyframes_at_indices(updates)
+            .ok_or_else(|| {
+                LibraryError::project(format!(
+                    "Failed to update keyframes {:?} for property {}",
+                    updates.iter().map(|(i, _, _)| *i).collect::<Vec<_>>(),
+                    property_key
+                ))
+            })
+    }
+
+    /// Set or clear the easing on one side of a keyframe for any target
+    pub fn set_keyframe_easing(
+        project: &Arc<RwLock<Project>>,
+        clip_id: Uuid,
+        target: PropertyTarget,
+        property_key: &str,
+        keyframe_index: usize,
+        handle: EasingHandle,
+        easing: Option<crate::animation::EasingFunction>,
+    ) -> Result<(), LibraryError> {
+        let mut proj = super::write_project(project)?;
+        let prop_map = Self::resolve_property_map_mut(&mut proj, clip_id, target)?;
+
+        let property = prop_map
+            .get_mut(property_key)
+            .ok_or_else(|| LibraryError::project(format!("Property {} not found", property_key)))?;
+
+        if !property.set_keyframe_easing(keyframe_index, handle, easing) {
+            return Err(LibraryError::project(format!(
+                "Failed to set easing of keyframe at index {} for property {}",
+                keyframe_index, property_key
+            )));
+        }
+
+        Ok(())
+    }
+
     /// Unified method to remove a keyframe by index for any target
     pub fn remove_keyframe_by_index(
         project: &Arc<RwLock<Project>>,
@@ -1,8 +1,10 @@
 use crate::error::LibraryError;
 use crate::plugin::PluginManager;
 use crate::project::project::Project;
-use crate::project::property::{Property, PropertyMap, PropertyValue};
+use crate::project::property::{Property, PropertyMap, PropertyValue, Vec2};
 use crate::project::source::{SourceData, SourceKind};
+use crate::service::NewClipSpec;
+use ordered_float::OrderedFloat;
 use std::sync::{Arc, RwLock};
 use uuid::Uuid;
 
@@ -64,6 +66,43 @@ impl LayerFactory {
         Ok(source_id)
     }
 
+    /// Create several layers on one track as a single transaction.
+    ///
+    /// The project write lock is held for the whole batch. The layers are
+    /// built on a scratch copy that replaces the project only when every clip
+    /// succeeds, so readers never see a partial batch and a failure leaves the
+    /// project untouched. Returns the source node IDs in the order of `clips`.
+    pub fn create_layers(
+        project: &Arc<RwLock<Project>>,
+        plugin_manager: &PluginManager,
+        composition_id: Uuid,
+        track_id: Uuid,
+        clips: Vec<NewClipSpec>,
+    ) -> Result<Vec<Uuid>, LibraryError> {
+        let mut proj = super::write_project(project)?;
+        let scratch = Arc::new(RwLock::new(proj.clone()));
+
+        let mut source_ids = Vec::with_capacity(clips.len());
+        for clip in clips {
+            source_ids.push(Self::create_layer(
+                &scratch,
+                plugin_manager,
+                composition_id,
+                track_id,
+                clip.source,
+                clip.in_frame,
+                clip.out_frame,
+                clip.insert_index,
+            )?);
+        }
+
+        *proj = Arc::try_unwrap(scratch)
+            .ok()
+            .and_then(|lock| lock.into_inner().ok())
+            .ok_or_else(|| LibraryError::Runtime("Lock Poisoned".to_string()))?;
+        Ok(source_ids)
+    }
+
     // --- Source builders (pure data creation, no project mutation) ---
 
     pub fn build_audio_source(
@@ -228,4 +267,47 @@ half4 main(float2 fragCoord) {
             props,
         )
     }
+
+    /// Build a horizontal black-to-white linear gradient covering the canvas.
+    pub fn build_gradient_source(
+        in_frame: u64,
+        out_frame: u64,
+        canvas_width: u32,
+        canvas_height: u32,
+        fps: f64,
+    ) -> SourceData {
+        let defs = SourceData::get_definitions_for_kind(&SourceKind::Gradient);
+        let mut props = PropertyMap::from_definitions(&defs);
+
+        let width = canvas_width as f64;
+        let height = canvas_height as f64;
+        let point = |x: f64, y: f64| {
+            Property::constant(PropertyValue::Vec2(Vec2 {
+                x: OrderedFloat(x),
+                y: OrderedFloat(y),
+            }))
+        };
+        props.set(
+            "width".to_string(),
+            Property::constant(PropertyValue::from(width)),
+        );
+        props.set(
+            "height".to_string(),
+            Property::constant(PropertyValue::from(height)),
+        );
+        props.set("start_point".to_string(), point(0.0, height / 2.0));
+        props.set("end_point".to_string(), point(width, height / 2.0));
+
+        SourceData::new(
+            Uuid::new_v4(),
+            None,
+            SourceKind::Gradient,
+            in_frame,
+            out_frame,
+            0,
+            None,
+            fps,
+            props,
+        )
+    }
 }
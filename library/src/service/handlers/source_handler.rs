@@ -1,11 +1,25 @@
 use crate::error::LibraryError;
+use crate::project::connection::{Connection, PinId};
 use crate::project::node::Node;
 use crate::project::project::Project;
 use crate::project::property::PropertyValue;
 use crate::project::source::{SourceData, SourceKind};
+use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 use uuid::Uuid;
 
+/// One update applied by [`SourceHandler::batch_update_target_properties`].
+#[derive(Debug, Clone)]
+pub struct TargetPropertyUpdate {
+    pub composition_id: Uuid,
+    pub track_id: Uuid,
+    pub source_id: Uuid,
+    pub target: crate::project::property::PropertyTarget,
+    pub property_key: String,
+    pub time: f64,
+    pub value: PropertyValue,
+}
+
 pub struct SourceHandler;
 
 impl SourceHandler {
@@ -82,7 +96,6 @@ impl SourceHandler {
         source_id: Uuid,
         source_kind: &SourceKind,
     ) -> Result<(), LibraryError> {
-        use crate::project::connection::PinId;
         use crate::project::layer::LayerData;
 
         log::info!(
@@ -187,6 +200,112 @@ impl SourceHandler {
         Ok(())
     }
 
+    /// Duplicate a source together with its layer container, graph nodes and the
+    /// connections between them. Every copied node gets a fresh ID.
+    ///
+    /// The copy is inserted right after the original in the same parent track.
+    /// Returns the ID of the new source.
+    pub fn duplicate_source(
+        project: &Arc<RwLock<Project>>,
+        source_id: Uuid,
+    ) -> Result<Uuid, LibraryError> {
+        let mut proj = super::write_project(project)?;
+
+        let source = proj.get_source(source_id).ok_or_else(|| {
+            LibraryError::project(format!("Source with ID {} not found", source_id))
+        })?;
+        let source_copy = source.clone_with_new_id();
+        let new_source_id = source_copy.id;
+        let parent_id = proj.find_parent_track(source_id).ok_or_else(|| {
+            LibraryError::project(format!("Source {} has no parent container", source_id))
+        })?;
+
+        let mut id_map: HashMap<Uuid, Uuid> = HashMap::from([(source_id, new_source_id)]);
+        let mut new_nodes = vec![Node::Source(source_copy)];
+
+        // Graph nodes: the layer's own children plus any associated node living elsewhere
+        let mut graph_ids: Vec<Uuid> = proj
+            .get_layer(parent_id)
+            .map(|layer| layer.child_ids.clone())
+            .unwrap_or_default();
+        for node_id in
+            crate::project::graph_analysis::collect_all_associated_nodes(&proj, source_id)
+        {
+            if !graph_ids.contains(&node_id) {
+                graph_ids.push(node_id);
+            }
+        }
+        let mut outside_layer = Vec::new();
+        for node_id in graph_ids {
+            if let Some(Node::Graph(node)) = proj.get_node(node_id) {
+                let mut copy = node.clone();
+                copy.id = Uuid::new_v4();
+                id_map.insert(node_id, copy.id);
+                new_nodes.push(Node::Graph(copy));
+                if let Some(container) = proj.find_parent_container(node_id) {
+                    if container != parent_id {
+                        outside_layer.push((container, id_map[&node_id]));
+                    }
+                }
+            }
+        }
+
+        // The item placed next to the original: the layer copy, or the bare source
+        let (original_item, new_item, track_id) = match proj.get_layer(parent_id) {
+            Some(layer) => {
+                let mut layer_copy = layer.clone();
+                layer_copy.id = Uuid::new_v4();
+                id_map.insert(layer.id, layer_copy.id);
+                layer_copy.child_ids = layer
+                    .child_ids
+                    .iter()
+                    .filter_map(|id| id_map.get(id).copied())
+                    .collect();
+                let new_layer_id = layer_copy.id;
+                new_nodes.push(Node::Layer(layer_copy));
+                let track_id = proj.find_parent_track(parent_id).ok_or_else(|| {
+                    LibraryError::project(format!("Layer {} has no parent track", parent_id))
+                })?;
+                (parent_id, new_layer_id, track_id)
+            }
+            None => (source_id, new_source_id, parent_id),
+        };
+
+        let new_connections: Vec<Connection> = proj
+            .connections
+            .iter()
+            .filter_map(|c| {
+                let from = *id_map.get(&c.from.node_id)?;
+                let to = *id_map.get(&c.to.node_id)?;
+                Some(Connection::new(
+                    PinId::new(from, &c.from.pin_name),
+                    PinId::new(to, &c.to.pin_name),
+                ))
+            })
+            .collect();
+
+        for node in new_nodes {
+            proj.add_node(node);
+        }
+        for connection in new_connections {
+            proj.add_connection(connection);
+        }
+        for (container, node_id) in outside_layer {
+            if let Some(children) = proj.get_container_child_ids_mut(container) {
+                children.push(node_id);
+            }
+        }
+        if let Some(children) = proj.get_container_child_ids_mut(track_id) {
+            let index = children
+                .iter()
+                .position(|id| *id == original_item)
+                .map_or(children.len(), |i| i + 1);
+            children.insert(index, new_item);
+        }
+
+        Ok(new_source_id)
+    }
+
     /// Remove a layer (source + container) from a track, along with all associated graph nodes
     /// (transform, effects, styles, effectors, decorators).
     ///
@@ -198,58 +317,62 @@ impl SourceHandler {
         source_id: Uuid,
     ) -> Result<(), LibraryError> {
         let mut proj = super::write_project(project)?;
+        if !proj.is_node_in_tree(track_id, source_id) {
+            return Err(LibraryError::project(format!(
+                "Source {} is not in track {}",
+                source_id, track_id
+            )));
+        }
+        Self::remove_source_node(&mut proj, source_id);
+        Ok(())
+    }
 
+    /// Remove a source with its graph nodes, connections and the Layer
+    /// container that holds it.
+    pub(crate) fn remove_source_node(proj: &mut Project, source_id: Uuid) {
         // 1. Collect all associated graph nodes before removing anything
         let associated_nodes =
-            crate::project::graph_analysis::collect_all_associated_nodes(&proj, source_id);
+            crate::project::graph_analysis::collect_all_associated_nodes(proj, source_id);
 
         // 2. Remove associated graph nodes (connections are cleaned up per node)
         for node_id in &associated_nodes {
-            // Remove from parent container's child_ids (Track or Layer)
-            let parent_ids: Vec<Uuid> = proj
-                .nodes
-                .iter()
-                .filter_map(|(id, n)| match n {
-                    Node::Track(t) if t.child_ids.contains(node_id) => Some(*id),
-                    Node::Layer(l) if l.child_ids.contains(node_id) => Some(*id),
-                    _ => None,
-                })
-                .collect();
-
-            for pid in parent_ids {
-                if let Some(children) = proj.get_container_child_ids_mut(pid) {
-                    children.retain(|id| id != node_id);
-                }
-            }
-            proj.remove_connections_for_node(*node_id);
-            proj.remove_node(*node_id);
+            Self::remove_contained_node(proj, *node_id);
         }
 
         // 3. Find the Layer container that holds this source
-        let layer_id = proj.find_parent_track(source_id);
+        let layer_id = proj
+            .find_parent_container(source_id)
+            .filter(|id| proj.get_layer(*id).is_some());
 
         // 4. Remove source connections and node
-        if let Some(lid) = layer_id {
-            if let Some(children) = proj.get_container_child_ids_mut(lid) {
-                children.retain(|id| *id != source_id);
-            }
-        }
-        proj.remove_connections_for_node(source_id);
-        proj.remove_node(source_id);
+        Self::remove_contained_node(proj, source_id);
 
         // 5. Remove the Layer container itself from its parent track
         if let Some(lid) = layer_id {
-            if proj.get_layer(lid).is_some() {
-                // Remove Layer from parent track's child_ids
-                if let Some(track) = proj.get_track_mut(track_id) {
-                    track.remove_child(lid);
-                }
-                proj.remove_connections_for_node(lid);
-                proj.remove_node(lid);
-            }
+            Self::remove_contained_node(proj, lid);
         }
+    }
 
-        Ok(())
+    /// Remove a node from its parent container, together with its connections.
+    /// A subgraph left without children is removed the same way.
+    fn remove_contained_node(proj: &mut Project, node_id: Uuid) {
+        let parent_id = proj.find_parent_container(node_id);
+        if let Some(pid) = parent_id {
+            if let Some(children) = proj.get_container_child_ids_mut(pid) {
+                children.retain(|id| *id != node_id);
+            }
+        }
+        proj.remove_connections_for_node(node_id);
+        proj.remove_node(node_id);
+
+        if let Some(pid) = parent_id {
+            if proj
+                .get_graph_node(pid)
+                .is_some_and(|g| g.is_subgraph() && g.child_ids.is_empty())
+            {
+                Self::remove_contained_node(proj, pid);
+            }
+        }
     }
 
     /// Unified method to update property or keyframe for any target
@@ -262,9 +385,82 @@ impl SourceHandler {
         value: PropertyValue,
         easing: Option<crate::animation::EasingFunction>,
     ) -> Result<(), LibraryError> {
+        let mut proj = super::write_project(project)?;
+        Self::apply_target_property_or_keyframe(
+            &mut proj,
+            source_id,
+            target,
+            property_key,
+            time,
+            value,
+            easing,
+        )
+    }
+
+    /// Apply several property updates under a single write lock.
+    ///
+    /// Each update's source must be in its track, and the track in its
+    /// composition. If any update fails, the project is restored to its state
+    /// before the call.
+    pub fn batch_update_target_properties(
+        project: &Arc<RwLock<Project>>,
+        updates: Vec<TargetPropertyUpdate>,
+    ) -> Result<(), LibraryError> {
+        let mut proj = super::write_project(project)?;
+        let snapshot = proj.clone();
+        for update in updates {
+            let result = if !proj.is_node_in_tree(update.composition_id, update.track_id)
+                || !proj.is_node_in_tree(update.track_id, update.source_id)
+            {
+                Err(LibraryError::project(format!(
+                    "Source {} is not in track {} of composition {}",
+                    update.source_id, update.track_id, update.composition_id
+                )))
+            } else {
+                Self::apply_target_property_or_keyframe(
+                    &mut proj,
+                    update.source_id,
+                    update.target,
+                    &update.property_key,
+                    update.time,
+                    update.value,
+                    None,
+                )
+            };
+            if let Err(e) = result {
+                *proj = snapshot;
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
+    fn apply_target_property_or_keyframe(
+        proj: &mut Project,
+        source_id: Uuid,
+        target: crate::project::property::PropertyTarget,
+        property_key: &str,
+        time: f64,
+        value: PropertyValue,
+        easing: Option<crate::animation::EasingFunction>,
+    ) -> Result<(), LibraryError> {
+        // Effects live in the graph; an index picks the node from the clip's effect chain
+        let target = match target {
+            crate::project::property::PropertyTarget::Effect(index) => {
+                let chain = crate::project::graph_analysis::get_effect_chain(proj, source_id);
+                let node_id = chain.get(index).copied().ok_or_else(|| {
+                    LibraryError::project(format!(
+                        "Effect {} not found on source {}",
+                        index, source_id
+                    ))
+                })?;
+                crate::project::property::PropertyTarget::GraphNode(node_id)
+            }
+            other => other,
+        };
+
         // GraphNode targets are accessed via Project.nodes, not via source
         if let crate::project::property::PropertyTarget::GraphNode(node_id) = target {
-            let mut proj = super::write_project(project)?;
             let node = proj.get_graph_node_mut(node_id).ok_or_else(|| {
                 LibraryError::project(format!("Graph node {} not found", node_id))
             })?;
@@ -273,8 +469,6 @@ impl SourceHandler {
             return Ok(());
         }
 
-        let mut proj = super::write_project(project)?;
-
         let source = proj.get_source_mut(source_id).ok_or_else(|| {
             LibraryError::project(format!("Source with ID {} not found", source_id))
         })?;
@@ -310,6 +504,171 @@ impl SourceHandler {
         Ok(())
     }
 
+    /// Trim a composition clip, keeping its window inside the referenced composition.
+    ///
+    /// `new_source_begin` is in frames of the referenced composition; the window
+    /// `new_source_begin + (new_out_frame - new_in_frame) * |speed|` (converted
+    /// to those frames) must not run past its duration. Validation and all three
+    /// fields are applied under one write lock.
+    pub fn trim_composition_clip(
+        project: &Arc<RwLock<Project>>,
+        composition_id: Uuid,
+        track_id: Uuid,
+        source_id: Uuid,
+        new_in_frame: u64,
+        new_out_frame: u64,
+        new_source_begin: i64,
+    ) -> Result<(), LibraryError> {
+        let mut proj = super::write_project(project)?;
+        {
+            let composition = proj.get_composition(composition_id).ok_or_else(|| {
+                LibraryError::project(format!("Composition with ID {} not found", composition_id))
+            })?;
+            if !proj.is_node_in_tree(composition_id, track_id)
+                || !proj.is_node_in_tree(track_id, source_id)
+            {
+                return Err(LibraryError::project(format!(
+                    "Source {} is not in track {} of composition {}",
+                    source_id, track_id, composition_id
+                )));
+            }
+            let source = proj.get_source(source_id).ok_or_else(|| {
+                LibraryError::project(format!("Source with ID {} not found", source_id))
+            })?;
+            if source.kind != SourceKind::Composition {
+                return Err(LibraryError::project(format!(
+                    "Source {} is not a composition clip",
+                    source_id
+                )));
+            }
+            let referenced = source
+                .reference_id
+                .and_then(|id| proj.get_composition(id))
+                .ok_or_else(|| {
+                    LibraryError::project(format!(
+                        "Composition referenced by source {} not found",
+                        source_id
+                    ))
+                })?;
+
+            if new_in_frame >= new_out_frame || new_source_begin < 0 {
+                return Err(LibraryError::project(format!(
+                    "Invalid trim range {}..{} (source begin {})",
+                    new_in_frame, new_out_frame, new_source_begin
+                )));
+            }
+            let total_frames = (referenced.duration * referenced.fps).round() as i64;
+            let window_frames = ((new_out_frame - new_in_frame) as f64 / composition.fps
+                * referenced.fps
+                * source.speed.abs())
+            .round() as i64;
+            if new_source_begin + window_frames > total_frames {
+                return Err(LibraryError::project(format!(
+                    "Trim exceeds the {} frames of composition {}",
+                    total_frames, referenced.name
+                )));
+            }
+        }
+
+        for (key, value) in [
+            ("in_frame", new_in_frame as f64),
+            ("out_frame", new_out_frame as f64),
+            ("source_begin_frame", new_source_begin as f64),
+        ] {
+            Self::apply_target_property_or_keyframe(
+                &mut proj,
+                source_id,
+                crate::project::property::PropertyTarget::Clip,
+                key,
+                0.0,
+                PropertyValue::Number(ordered_float::OrderedFloat(value)),
+                None,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Shift every clip on a track that starts at or after `pivot_frame` by
+    /// `delta_frames`, moving layer containers together with their sources.
+    ///
+    /// Fails if a shifted clip would start before frame 0.
+    pub fn ripple_shift(
+        project: &Arc<RwLock<Project>>,
+        composition_id: Uuid,
+        track_id: Uuid,
+        pivot_frame: u64,
+        delta_frames: i64,
+    ) -> Result<(), LibraryError> {
+        let mut proj = super::write_project(project)?;
+        let track = proj.get_track(track_id).ok_or_else(|| {
+            LibraryError::project(format!("Track with ID {} not found", track_id))
+        })?;
+        if !proj.is_node_in_tree(composition_id, track_id) {
+            return Err(LibraryError::project(format!(
+                "Track {} does not belong to composition {}",
+                track_id, composition_id
+            )));
+        }
+
+        let mut layer_ids = Vec::new();
+        let mut source_ids = Vec::new();
+        let mut earliest = u64::MAX;
+        for child_id in &track.child_ids {
+            match proj.get_node(*child_id) {
+                Some(Node::Source(source)) if source.in_frame >= pivot_frame => {
+                    earliest = earliest.min(source.in_frame);
+                    source_ids.push(source.id);
+                }
+                Some(Node::Layer(layer)) if layer.in_frame >= pivot_frame => {
+                    earliest = earliest.min(layer.in_frame);
+                    layer_ids.push(layer.id);
+                    source_ids.extend(
+                        layer
+                            .child_ids
+                            .iter()
+                            .filter(|id| proj.get_source(**id).is_some()),
+                    );
+                }
+                _ => {}
+            }
+        }
+        if earliest != u64::MAX && (earliest as i64) + delta_frames < 0 {
+            return Err(LibraryError::project(format!(
+                "Ripple by {} frames would move clips before frame 0",
+                delta_frames
+            )));
+        }
+
+        // Everything moves under this one lock, so no render sees a half-shifted track
+        let shift = |frame: u64| (frame as i64 + delta_frames).max(0) as u64;
+        for layer_id in &layer_ids {
+            if let Some(layer) = proj.get_layer_mut(*layer_id) {
+                layer.in_frame = shift(layer.in_frame);
+                layer.out_frame = shift(layer.out_frame);
+            }
+        }
+        for source_id in source_ids {
+            let (in_frame, out_frame) = {
+                let source = proj.get_source(source_id).ok_or_else(|| {
+                    LibraryError::project(format!("Source with ID {} not found", source_id))
+                })?;
+                (shift(source.in_frame), shift(source.out_frame))
+            };
+            for (key, value) in [("in_frame", in_frame), ("out_frame", out_frame)] {
+                Self::apply_target_property_or_keyframe(
+                    &mut proj,
+                    source_id,
+                    crate::project::property::PropertyTarget::Clip,
+                    key,
+                    0.0,
+                    PropertyValue::Number(ordered_float::OrderedFloat(value as f64)),
+                    None,
+                )?;
+            }
+        }
+        Ok(())
+    }
+
     fn validate_recursion(project: &Arc<RwLock<Project>>, child_id: Uuid, parent_id: Uuid) -> bool {
         if child_id == parent_id {
             return false;
@@ -466,4 +825,36 @@ impl SourceHandler {
             .insert(attribute_key.to_string(), attribute_value);
         Ok(())
     }
+
+    /// Replace a source's properties with constant values parsed from a JSON object.
+    ///
+    /// See `PropertyMap::import_from_json` for the accepted value formats.
+    pub fn set_properties_from_json(
+        project: &Arc<RwLock<Project>>,
+        composition_id: Uuid,
+        track_id: Uuid,
+        source_id: Uuid,
+        json: &str,
+    ) -> Result<(), LibraryError> {
+        let mut proj = super::write_project(project)?;
+
+        if !proj.is_node_in_tree(composition_id, track_id) {
+            return Err(LibraryError::project(format!(
+                "Track {} does not belong to composition {}",
+                track_id, composition_id
+            )));
+        }
+        if !proj.is_node_in_tree(track_id, source_id) {
+            return Err(LibraryError::project(format!(
+                "Source {} not found in track {}",
+                source_id, track_id
+            )));
+        }
+
+        let source = proj.get_source_mut(source_id).ok_or_else(|| {
+            LibraryError::project(format!("Source with ID {} not found", source_id))
+        })?;
+
+        source.properties.import_from_json(json)
+    }
 }
@@ -156,6 +156,34 @@ impl TrackHandler {
         }
     }
 
+    /// Mute or unmute a track
+    pub fn set_track_muted(
+        project: &Arc<RwLock<Project>>,
+        track_id: Uuid,
+        muted: bool,
+    ) -> Result<(), LibraryError> {
+        let mut proj = super::write_project(project)?;
+        let track = proj.get_track_mut(track_id).ok_or_else(|| {
+            LibraryError::project(format!("Track with ID {} not found", track_id))
+        })?;
+        track.muted = muted;
+        Ok(())
+    }
+
+    /// Solo or unsolo a track
+    pub fn set_track_soloed(
+        project: &Arc<RwLock<Project>>,
+        track_id: Uuid,
+        soloed: bool,
+    ) -> Result<(), LibraryError> {
+        let mut proj = super::write_project(project)?;
+        let track = proj.get_track_mut(track_id).ok_or_else(|| {
+            LibraryError::project(format!("Track with ID {} not found", track_id))
+        })?;
+        track.soloed = soloed;
+        Ok(())
+    }
+
     /// Rename a track
     pub fn rename_track(
         project: &Arc<RwLock<Project>>,
@@ -17,4 +17,5 @@ pub use crate::rendering::color::ColorSpaceManager as ColorService;
 pub use audio_service::AudioService;
 pub use editor_service::EditorService;
 pub use export_service::ExportService;
+pub use ops::source_ops::NewClipSpec;
 pub use project_model::ProjectModel;
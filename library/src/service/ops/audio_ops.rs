@@ -0,0 +1,86 @@
+use crate::error::LibraryError;
+use crate::project::effect::EffectConfig;
+use crate::project::property::PropertyMap;
+use crate::project::source::SourceKind;
+use crate::service::editor_service::EditorService;
+use uuid::Uuid;
+
+/// Audio effect chain operations on audio sources.
+impl EditorService {
+    /// Append an audio effect with default properties to an audio source.
+    pub fn add_audio_effect(
+        &self,
+        source_id: Uuid,
+        effect_type: &str,
+    ) -> Result<Uuid, LibraryError> {
+        let plugin_manager = self.get_plugin_manager();
+        if plugin_manager
+            .get_audio_effect_plugin(effect_type)
+            .is_none()
+        {
+            return Err(LibraryError::plugin(format!(
+                "Audio effect '{}' not found",
+                effect_type
+            )));
+        }
+        let defs = plugin_manager.get_audio_effect_properties(effect_type);
+
+        let config = EffectConfig {
+            id: Uuid::new_v4(),
+            effect_type: effect_type.to_string(),
+            properties: PropertyMap::from_definitions(&defs),
+        };
+        let effect_id = config.id;
+
+        self.with_project_mut(|project| {
+            let source = project
+                .get_source_mut(source_id)
+                .ok_or_else(|| LibraryError::project(format!("Source {} not found", source_id)))?;
+            if source.kind != SourceKind::Audio {
+                return Err(LibraryError::InvalidArgument(format!(
+                    "Source {} is not an audio source",
+                    source_id
+                )));
+            }
+            source.audio_effects.push(config);
+            Ok(effect_id)
+        })
+    }
+
+    /// Remove an audio effect from a source's chain.
+    pub fn remove_audio_effect(
+        &self,
+        source_id: Uuid,
+        effect_id: Uuid,
+    ) -> Result<(), LibraryError> {
+        self.with_project_mut(|project| {
+            let source = project
+                .get_source_mut(source_id)
+                .ok_or_else(|| LibraryError::project(format!("Source {} not found", source_id)))?;
+            let before = source.audio_effects.len();
+            source.audio_effects.retain(|e| e.id != effect_id);
+            if source.audio_effects.len() == before {
+                return Err(LibraryError::project(format!(
+                    "Audio effect {} not found on source {}",
+                    effect_id, source_id
+                )));
+            }
+            Ok(())
+        })
+    }
+
+    /// Replace a source's audio effect chain (used for reordering).
+    pub fn set_audio_effects(
+        &self,
+        source_id: Uuid,
+        effects: Vec<EffectConfig>,
+    ) -> Result<(), LibraryError> {
+        self.with_project_mut(|project| {
+            let source = project
+                .get_source_mut(source_id)
+                .ok_or_else(|| LibraryError::project(format!("Source {} not found", source_id)))?;
+            source.audio_effects = effects;
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,68 @@
+use crate::builtin::exporters::PngExportPlugin;
+use crate::error::LibraryError;
+use crate::pipeline::engine::EvalEngine;
+use crate::plugin::{ExportPlugin, ExportSettings};
+use crate::rendering::renderer::{RenderOutput, Renderer};
+use crate::rendering::skia_renderer::SkiaRenderer;
+use crate::service::editor_service::EditorService;
+use uuid::Uuid;
+
+/// Single-frame export operations.
+impl EditorService {
+    /// Render one frame of a composition and write it to `output_path` as PNG.
+    ///
+    /// Runs synchronously on the calling thread with a temporary CPU renderer,
+    /// so it does not interfere with the preview render server.
+    pub fn export_frame_as_image(
+        &self,
+        comp_id: Uuid,
+        frame: u64,
+        output_path: &str,
+    ) -> Result<(), LibraryError> {
+        let project = self.with_project(|p| p.clone());
+        let composition = project
+            .get_composition(comp_id)
+            .ok_or_else(|| LibraryError::project(format!("Composition {} not found", comp_id)))?;
+
+        let mut renderer = SkiaRenderer::new(
+            composition.width as u32,
+            composition.height as u32,
+            composition.background_color.clone(),
+            false,
+            None,
+        );
+        renderer.clear()?;
+
+        let plugin_manager = self.get_plugin_manager();
+        let cache_manager = self.get_cache_manager();
+        let mut engine = EvalEngine::with_default_evaluators();
+        engine.set_export(true);
+        let output = engine.evaluate_composition(
+            &project,
+            composition,
+            &plugin_manager,
+            &mut renderer,
+            &cache_manager,
+            plugin_manager.get_property_evaluators(),
+            frame,
+            1.0,
+            None,
+        )?;
+
+        let image = match output {
+            RenderOutput::Image(img) => img,
+            RenderOutput::Texture(_) => {
+                return Err(LibraryError::render(
+                    "Frame export received Texture output (unsupported)".to_string(),
+                ));
+            }
+        };
+
+        let settings = ExportSettings::for_dimensions(
+            composition.width as u32,
+            composition.height as u32,
+            composition.fps,
+        );
+        PngExportPlugin::new().export_image(output_path, &image, &settings)
+    }
+}
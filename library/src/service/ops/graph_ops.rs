@@ -1,6 +1,7 @@
 use crate::error::LibraryError;
 use crate::project::property::PropertyValue;
 use crate::service::editor_service::EditorService;
+use crate::service::handlers::graph_handler::GraphNodeSnapshot;
 use uuid::Uuid;
 
 /// Graph node operations.
@@ -10,7 +11,9 @@ impl EditorService {
     }
 
     pub fn remove_graph_node(&self, node_id: Uuid) -> Result<(), LibraryError> {
-        self.project_manager.remove_graph_node(node_id)
+        self.project_manager.remove_graph_node(node_id)?;
+        self.prune_removed_nodes();
+        Ok(())
     }
 
     pub fn add_graph_connection(
@@ -25,6 +28,43 @@ impl EditorService {
         self.project_manager.remove_graph_connection(connection_id)
     }
 
+    /// Snapshot graph nodes and the connections among them for a later paste.
+    pub fn copy_graph_nodes(&self, node_ids: &[Uuid]) -> Result<GraphNodeSnapshot, LibraryError> {
+        self.project_manager.copy_graph_nodes(node_ids)
+    }
+
+    /// Paste a snapshot into a container with fresh IDs.
+    /// Returns `(original_id, copy_id)` pairs.
+    pub fn paste_graph_nodes(
+        &self,
+        container_id: Uuid,
+        snapshot: &GraphNodeSnapshot,
+    ) -> Result<Vec<(Uuid, Uuid)>, LibraryError> {
+        self.project_manager
+            .paste_graph_nodes(container_id, snapshot)
+    }
+
+    /// Split a connection with a new reroute node. Returns the reroute's ID.
+    pub fn insert_reroute(
+        &self,
+        container_id: Uuid,
+        connection_id: Uuid,
+    ) -> Result<Uuid, LibraryError> {
+        self.project_manager
+            .insert_reroute(container_id, connection_id)
+    }
+
+    /// Group graph nodes of a container into a new subgraph. Returns the subgraph's ID.
+    pub fn group_graph_nodes(
+        &self,
+        container_id: Uuid,
+        node_ids: &[Uuid],
+        name: &str,
+    ) -> Result<Uuid, LibraryError> {
+        self.project_manager
+            .group_graph_nodes(container_id, node_ids, name)
+    }
+
     pub fn reorder_effect_chain(
         &self,
         clip_id: Uuid,
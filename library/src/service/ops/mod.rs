@@ -1,3 +1,5 @@
+pub(crate) mod audio_ops;
+pub(crate) mod export_ops;
 pub(crate) mod graph_ops;
 pub(crate) mod project_ops;
 pub(crate) mod source_ops;
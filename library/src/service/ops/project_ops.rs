@@ -1,5 +1,6 @@
 use crate::error::LibraryError;
 use crate::project::asset::Asset;
+use crate::project::composition::NodeLayout;
 use crate::project::project::Composition;
 use crate::service::editor_service::EditorService;
 use uuid::Uuid;
@@ -47,10 +48,66 @@ impl EditorService {
         Ok(asset_ids)
     }
 
+    /// Store the node editor layout of a composition, to be written on the next save.
+    /// Layout changes are not recorded in the undo history.
+    pub fn set_node_layout(
+        &self,
+        composition_id: Uuid,
+        layout: NodeLayout,
+    ) -> Result<(), LibraryError> {
+        self.with_project_mut(|project| {
+            let composition = project.get_composition_mut(composition_id).ok_or_else(|| {
+                LibraryError::project(format!("Composition {} not found", composition_id))
+            })?;
+            composition.node_layout = Some(layout);
+            Ok(())
+        })
+    }
+
+    /// Set the work area of a composition. `None` clears that end.
+    pub fn set_work_area(
+        &self,
+        composition_id: Uuid,
+        work_area_in: Option<u64>,
+        work_area_out: Option<u64>,
+    ) -> Result<(), LibraryError> {
+        if let (Some(i), Some(o)) = (work_area_in, work_area_out) {
+            if i >= o {
+                return Err(LibraryError::project(format!(
+                    "Work area in ({}) must be before out ({})",
+                    i, o
+                )));
+            }
+        }
+        self.with_project_mut(|project| {
+            let composition = project.get_composition_mut(composition_id).ok_or_else(|| {
+                LibraryError::project(format!("Composition {} not found", composition_id))
+            })?;
+            composition.work_area_in = work_area_in;
+            composition.work_area_out = work_area_out;
+            Ok(())
+        })
+    }
+
     pub fn load_project_from_path(&self, path: &std::path::Path) -> Result<(), LibraryError> {
         let content = std::fs::read_to_string(path)
             .map_err(|e| LibraryError::Runtime(format!("Failed to read project file: {}", e)))?;
-        self.load_project(&content)
+        self.load_project(&content)?;
+        if let Some(dir) = path.parent() {
+            self.project_manager.set_file_dir(dir)?;
+        }
+        Ok(())
+    }
+
+    /// Write the project to `path` and remember its directory, so picked
+    /// files inside it are stored relative to the project file.
+    pub fn save_project_to_path(&self, path: &std::path::Path) -> Result<(), LibraryError> {
+        if let Some(dir) = path.parent() {
+            self.project_manager.set_file_dir(dir)?;
+        }
+        let json = self.project_manager.save_project()?;
+        std::fs::write(path, json)
+            .map_err(|e| LibraryError::Runtime(format!("Failed to write project file: {}", e)))
     }
 
     // --- Asset Operations ---
@@ -68,13 +125,46 @@ impl EditorService {
     }
 
     pub fn remove_asset_fully(&self, asset_id: Uuid) -> Result<(), LibraryError> {
-        self.project_manager.remove_asset_fully(asset_id)
+        self.project_manager.remove_asset_fully(asset_id)?;
+        self.prune_removed_nodes();
+        Ok(())
     }
 
     pub fn has_asset_with_path(&self, path: &str) -> bool {
         self.project_manager.has_asset_with_path(path)
     }
 
+    /// Drop cached decodes of the given assets so they are re-read from disk.
+    ///
+    /// Loaders re-check file modification times, and audio assets are reloaded.
+    pub fn reload_asset_media(&self, asset_ids: &[Uuid]) -> Result<(), LibraryError> {
+        let assets: Vec<Asset> = self.with_project(|p| {
+            p.assets
+                .iter()
+                .filter(|a| asset_ids.contains(&a.id))
+                .cloned()
+                .collect()
+        });
+        if assets.is_empty() {
+            return Err(LibraryError::project(
+                "No matching assets to reload".to_string(),
+            ));
+        }
+
+        let cache_manager = self.get_cache_manager();
+        let plugin_manager = self.get_plugin_manager();
+        for asset in assets {
+            cache_manager.evict_path(&asset.path);
+            // Re-opening lets loaders detect on-disk changes and drop stale readers
+            let _ = plugin_manager.get_available_streams(&asset.path);
+            if asset.kind == crate::project::asset::AssetKind::Audio {
+                self.audio_service
+                    .trigger_audio_loading(asset.id, asset.path.clone());
+            }
+        }
+        Ok(())
+    }
+
     // --- Composition Operations ---
 
     pub fn add_composition(
@@ -102,6 +192,16 @@ impl EditorService {
             .update_composition(id, name, width, height, fps, duration)
     }
 
+    /// Set the sample rate the composition's audio is mixed and exported at.
+    pub fn set_composition_audio_sample_rate(
+        &self,
+        id: Uuid,
+        sample_rate: u32,
+    ) -> Result<(), LibraryError> {
+        self.project_manager
+            .set_composition_audio_sample_rate(id, sample_rate)
+    }
+
     pub fn get_composition(&self, id: Uuid) -> Result<Composition, LibraryError> {
         self.project_manager.get_composition(id)
     }
@@ -111,6 +211,8 @@ impl EditorService {
     }
 
     pub fn remove_composition_fully(&self, comp_id: Uuid) -> Result<(), LibraryError> {
-        self.project_manager.remove_composition_fully(comp_id)
+        self.project_manager.remove_composition_fully(comp_id)?;
+        self.prune_removed_nodes();
+        Ok(())
     }
 }
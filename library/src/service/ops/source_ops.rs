@@ -2,9 +2,22 @@ use crate::error::LibraryError;
 use crate::project::property::PropertyValue;
 use crate::project::source::SourceData;
 use crate::project::track::TrackData;
+use crate::runtime::draw_type::BlendMode;
 use crate::service::editor_service::EditorService;
+use crate::service::handlers::source_handler::TargetPropertyUpdate;
 use uuid::Uuid;
 
+/// A clip to be added by `EditorService::batch_add_clips`.
+///
+/// Mirrors the arguments of `EditorService::add_layer_to_track`.
+#[derive(Debug, Clone)]
+pub struct NewClipSpec {
+    pub source: SourceData,
+    pub in_frame: u64,
+    pub out_frame: u64,
+    pub insert_index: Option<usize>,
+}
+
 /// Source/Layer factory, track, source CRUD, property, and keyframe operations.
 impl EditorService {
     // --- Source Factory Methods ---
@@ -129,6 +142,23 @@ impl EditorService {
         )
     }
 
+    pub fn build_gradient_source(
+        &self,
+        in_frame: u64,
+        out_frame: u64,
+        canvas_width: u32,
+        canvas_height: u32,
+        fps: f64,
+    ) -> Result<SourceData, LibraryError> {
+        self.project_manager.build_gradient_source(
+            in_frame,
+            out_frame,
+            canvas_width,
+            canvas_height,
+            fps,
+        )
+    }
+
     // --- Track Operations ---
 
     pub fn add_track(&self, composition_id: Uuid, track_name: &str) -> Result<Uuid, LibraryError> {
@@ -154,7 +184,10 @@ impl EditorService {
     }
 
     pub fn remove_track(&self, composition_id: Uuid, track_id: Uuid) -> Result<(), LibraryError> {
-        self.project_manager.remove_track(composition_id, track_id)
+        self.project_manager
+            .remove_track(composition_id, track_id)?;
+        self.prune_removed_nodes();
+        Ok(())
     }
 
     pub fn add_sub_track(
@@ -167,6 +200,14 @@ impl EditorService {
             .add_sub_track(composition_id, parent_track_id, track_name)
     }
 
+    pub fn set_track_muted(&self, track_id: Uuid, muted: bool) -> Result<(), LibraryError> {
+        self.project_manager.set_track_muted(track_id, muted)
+    }
+
+    pub fn set_track_soloed(&self, track_id: Uuid, soloed: bool) -> Result<(), LibraryError> {
+        self.project_manager.set_track_soloed(track_id, soloed)
+    }
+
     pub fn rename_track(&self, track_id: Uuid, new_name: &str) -> Result<(), LibraryError> {
         self.project_manager.rename_track(track_id, new_name)
     }
@@ -192,13 +233,35 @@ impl EditorService {
         )
     }
 
+    /// Add several clips to a track in one transaction.
+    ///
+    /// Returns the new layer IDs in the order of `clips`. The project is
+    /// locked once for the whole batch and left unchanged if any clip fails,
+    /// so callers can record a single history entry for it.
+    pub fn batch_add_clips(
+        &self,
+        composition_id: Uuid,
+        track_id: Uuid,
+        clips: Vec<NewClipSpec>,
+    ) -> Result<Vec<Uuid>, LibraryError> {
+        self.project_manager
+            .add_layers_to_track(composition_id, track_id, clips)
+    }
+
     pub fn remove_layer_from_track(
         &self,
         track_id: Uuid,
         source_id: Uuid,
     ) -> Result<(), LibraryError> {
         self.project_manager
-            .remove_layer_from_track(track_id, source_id)
+            .remove_layer_from_track(track_id, source_id)?;
+        self.prune_removed_nodes();
+        Ok(())
+    }
+
+    /// Duplicate a source with its layer and graph nodes; returns the new source ID.
+    pub fn duplicate_layer(&self, source_id: Uuid) -> Result<Uuid, LibraryError> {
+        self.project_manager.duplicate_source(source_id)
     }
 
     pub fn update_source_property(
@@ -284,6 +347,41 @@ impl EditorService {
         Ok(())
     }
 
+    /// Trim a composition clip without moving its content on the timeline.
+    ///
+    /// Fails if the new window runs outside the referenced composition.
+    pub fn trim_composition_clip(
+        &self,
+        comp_id: Uuid,
+        track_id: Uuid,
+        clip_id: Uuid,
+        new_in_frame: u64,
+        new_out_frame: u64,
+        new_source_begin: i64,
+    ) -> Result<(), LibraryError> {
+        self.project_manager.trim_composition_clip(
+            comp_id,
+            track_id,
+            clip_id,
+            new_in_frame,
+            new_out_frame,
+            new_source_begin,
+        )
+    }
+
+    /// Shift the clips of a track starting at or after `pivot_frame` by
+    /// `delta_frames`, closing or opening a gap after a trim.
+    pub fn ripple_shift(
+        &self,
+        comp_id: Uuid,
+        track_id: Uuid,
+        pivot_frame: u64,
+        delta_frames: i64,
+    ) -> Result<(), LibraryError> {
+        self.project_manager
+            .ripple_shift(comp_id, track_id, pivot_frame, delta_frames)
+    }
+
     pub fn update_source_begin_frame(
         &self,
         source_id: Uuid,
@@ -296,6 +394,50 @@ impl EditorService {
         )
     }
 
+    /// Set how a source is composited onto the sources below it.
+    pub fn set_source_blend_mode(
+        &self,
+        source_id: Uuid,
+        blend_mode: BlendMode,
+    ) -> Result<(), LibraryError> {
+        self.with_project_mut(|project| {
+            let source = project
+                .get_source_mut(source_id)
+                .ok_or_else(|| LibraryError::project(format!("Source {} not found", source_id)))?;
+            source.blend_mode = blend_mode;
+            Ok(())
+        })
+    }
+
+    /// Set or clear the timeline label color of a source.
+    pub fn set_source_label_color(
+        &self,
+        source_id: Uuid,
+        label_color: Option<[u8; 3]>,
+    ) -> Result<(), LibraryError> {
+        self.with_project_mut(|project| {
+            let source = project
+                .get_source_mut(source_id)
+                .ok_or_else(|| LibraryError::project(format!("Source {} not found", source_id)))?;
+            source.label_color = label_color;
+            Ok(())
+        })
+    }
+
+    /// Set the playback rate of a source. See `SourceData::speed`.
+    pub fn set_source_speed(&self, source_id: Uuid, speed: f64) -> Result<(), LibraryError> {
+        if !speed.is_finite() {
+            return Err(LibraryError::project(format!("Invalid speed {}", speed)));
+        }
+        self.with_project_mut(|project| {
+            let source = project
+                .get_source_mut(source_id)
+                .ok_or_else(|| LibraryError::project(format!("Source {} not found", source_id)))?;
+            source.speed = speed;
+            Ok(())
+        })
+    }
+
     pub fn get_inspector_definitions(
         &self,
         comp_id: Uuid,
@@ -349,6 +491,18 @@ impl EditorService {
         )
     }
 
+    /// Set constant clip properties from a JSON object (for scripted / data-driven animation).
+    pub fn set_clip_properties_from_json(
+        &self,
+        comp_id: Uuid,
+        track_id: Uuid,
+        clip_id: Uuid,
+        json: &str,
+    ) -> Result<(), LibraryError> {
+        self.project_manager
+            .set_source_properties_from_json(comp_id, track_id, clip_id, json)
+    }
+
     pub fn add_target_keyframe(
         &self,
         source_id: Uuid,
@@ -389,6 +543,43 @@ impl EditorService {
         )
     }
 
+    /// Move several keyframes of one property at once. Indices refer to the
+    /// current order; returns the new index of every keyframe after re-sorting.
+    pub fn update_target_keyframes_by_index(
+        &self,
+        source_id: Uuid,
+        target: crate::project::property::PropertyTarget,
+        property_key: &str,
+        updates: &[(usize, Option<f64>, Option<PropertyValue>)],
+    ) -> Result<Vec<usize>, LibraryError> {
+        self.project_manager.update_target_keyframes_by_index(
+            source_id,
+            target,
+            property_key,
+            updates,
+        )
+    }
+
+    /// Set or clear the easing on one side of a keyframe.
+    pub fn set_target_keyframe_easing(
+        &self,
+        source_id: Uuid,
+        target: crate::project::property::PropertyTarget,
+        property_key: &str,
+        keyframe_index: usize,
+        handle: crate::project::property::EasingHandle,
+        easing: Option<crate::animation::EasingFunction>,
+    ) -> Result<(), LibraryError> {
+        self.project_manager.set_target_keyframe_easing(
+            source_id,
+            target,
+            property_key,
+            keyframe_index,
+            handle,
+            easing,
+        )
+    }
+
     pub fn remove_target_keyframe_by_index(
         &self,
         source_id: Uuid,
@@ -422,4 +613,61 @@ impl EditorService {
             easing,
         )
     }
+
+    /// Update a clip property on several clips at once, e.g. for a multi-clip
+    /// selection in the inspector.
+    ///
+    /// Each update is `(comp_id, track_id, clip_id, prop_name, time, value)`.
+    /// All updates are applied under one write lock and none are kept if any
+    /// fails, so the caller can record a single history entry for the batch.
+    pub fn batch_update_properties(
+        &self,
+        updates: Vec<(Uuid, Uuid, Uuid, String, f64, PropertyValue)>,
+    ) -> Result<(), LibraryError> {
+        self.project_manager.batch_update_target_properties(
+            updates
+                .into_iter()
+                .map(
+                    |(comp_id, track_id, clip_id, name, time, value)| TargetPropertyUpdate {
+                        composition_id: comp_id,
+                        track_id,
+                        source_id: clip_id,
+                        target: crate::project::property::PropertyTarget::Clip,
+                        property_key: name,
+                        time,
+                        value,
+                    },
+                )
+                .collect(),
+        )
+    }
+
+    /// Like [`Self::batch_update_properties`], for the graph effect nodes in
+    /// each clip's effect chain.
+    ///
+    /// Each update is `(comp_id, track_id, clip_id, effect_index, prop_name, time, value)`,
+    /// where `effect_index` is the position in the clip's effect chain.
+    pub fn batch_update_effect_properties(
+        &self,
+        updates: Vec<(Uuid, Uuid, Uuid, usize, String, f64, PropertyValue)>,
+    ) -> Result<(), LibraryError> {
+        self.project_manager.batch_update_target_properties(
+            updates
+                .into_iter()
+                .map(
+                    |(comp_id, track_id, clip_id, effect_index, name, time, value)| {
+                        TargetPropertyUpdate {
+                            composition_id: comp_id,
+                            track_id,
+                            source_id: clip_id,
+                            target: crate::project::property::PropertyTarget::Effect(effect_index),
+                            property_key: name,
+                            time,
+                            value,
+                        }
+                    },
+                )
+                .collect(),
+        )
+    }
 }
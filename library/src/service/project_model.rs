@@ -26,6 +26,17 @@ impl ProjectModel {
         Self::new(Arc::new(project), composition_index)
     }
 
+    /// Build a model from serialized project JSON without touching the filesystem.
+    pub fn from_json_str(json: &str, composition_index: usize) -> Result<Self, LibraryError> {
+        let project = Project::load(json)?;
+        Self::new(Arc::new(project), composition_index)
+    }
+
+    /// Serialize the model's project to JSON.
+    pub fn to_json_str(&self) -> Result<String, LibraryError> {
+        Ok(self.project.save()?)
+    }
+
     pub fn new(project: Arc<Project>, composition_index: usize) -> Result<Self, LibraryError> {
         if project.all_compositions().nth(composition_index).is_none() {
             return Err(LibraryError::project(format!(
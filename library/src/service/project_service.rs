@@ -1,3 +1,4 @@
+use crate::builtin::loaders::exr_loader::BASE_LAYER;
 use crate::error::LibraryError;
 use crate::plugin::PluginManager;
 use crate::project::asset::Asset;
@@ -33,14 +34,62 @@ impl ProjectManager {
         Arc::clone(&self.plugin_manager)
     }
 
-    pub fn set_project(&self, new_project: Project) -> Result<(), LibraryError> {
+    /// Replace the project. A state without a file location (e.g. one restored
+    /// from history before the first save) keeps the current project's.
+    pub fn set_project(&self, mut new_project: Project) -> Result<(), LibraryError> {
         let mut project_write = self.project.write().map_err(|e| {
             LibraryError::Runtime(format!("Failed to acquire project write lock: {}", e))
         })?;
+        if new_project.file_dir.is_none() {
+            new_project.file_dir = project_write.file_dir.take();
+        }
         *project_write = new_project;
         Ok(())
     }
 
+    /// Record the directory of the project file after loading or saving.
+    ///
+    /// Relative file-picker paths of effects are rebased so they keep pointing
+    /// at the same files: relative to `dir` when inside it, absolute otherwise.
+    pub fn set_file_dir(&self, dir: &std::path::Path) -> Result<(), LibraryError> {
+        let mut proj = handlers::write_project(&self.project)?;
+        if let Some(old_dir) = proj.file_dir.clone().filter(|old| old != dir) {
+            for node in proj.nodes.values_mut() {
+                let crate::project::node::Node::Graph(graph) = node else {
+                    continue;
+                };
+                let Some(effect_id) = graph.type_id.strip_prefix("effect.") else {
+                    continue;
+                };
+                for def in self.plugin_manager.get_effect_properties(effect_id) {
+                    if !matches!(def.ui_type(), PropertyUiType::FilePicker { .. }) {
+                        continue;
+                    }
+                    let Some(path) = graph.properties.get_string(def.name()) else {
+                        continue;
+                    };
+                    if path.is_empty() || std::path::Path::new(&path).is_absolute() {
+                        continue;
+                    }
+                    let absolute = old_dir.join(&path);
+                    let rebased = absolute
+                        .strip_prefix(dir)
+                        .unwrap_or(&absolute)
+                        .to_string_lossy()
+                        .into_owned();
+                    graph.properties.set(
+                        def.name().to_string(),
+                        crate::project::property::Property::constant(PropertyValue::String(
+                            rebased,
+                        )),
+                    );
+                }
+            }
+        }
+        proj.file_dir = Some(dir.to_path_buf());
+        Ok(())
+    }
+
     pub fn load_project(&self, json_str: &str) -> Result<Project, LibraryError> {
         let new_project = Project::load(json_str)?;
         let mut project_write = self.project.write().map_err(|e| {
@@ -165,6 +214,23 @@ impl ProjectManager {
         Ok(LayerFactory::build_sksl_source(in_frame, out_frame, fps))
     }
 
+    pub fn build_gradient_source(
+        &self,
+        in_frame: u64,
+        out_frame: u64,
+        canvas_width: u32,
+        canvas_height: u32,
+        fps: f64,
+    ) -> Result<SourceData, LibraryError> {
+        Ok(LayerFactory::build_gradient_source(
+            in_frame,
+            out_frame,
+            canvas_width,
+            canvas_height,
+            fps,
+        ))
+    }
+
     pub fn save_project(&self) -> Result<String, LibraryError> {
         let project_read = self.project.read().map_err(|e| {
             LibraryError::Runtime(format!("Failed to acquire project read lock: {}", e))
@@ -206,7 +272,12 @@ impl ProjectManager {
 
     pub fn remove_asset_fully(&self, asset_id: Uuid) -> Result<(), LibraryError> {
         let mut project_write = handlers::write_project(&self.project)?;
-        Self::remove_referencing_sources(&mut project_write, asset_id);
+        for (_, _, source_id) in project_write.find_clips_by_asset(asset_id) {
+            handlers::source_handler::SourceHandler::remove_source_node(
+                &mut project_write,
+                source_id,
+            );
+        }
         project_write.assets.retain(|a| a.id != asset_id);
         Ok(())
     }
@@ -244,6 +315,8 @@ impl ProjectManager {
                 asset.width = stream.width;
                 asset.height = stream.height;
                 asset.stream_index = stream.stream_index;
+                asset.icc_profile = stream.icc_profile;
+                asset.channel_layers = stream.channel_layers;
 
                 assets_to_add.push(asset);
             }
@@ -277,7 +350,7 @@ impl ProjectManager {
                     "mp4" | "mov" | "avi" | "mkv" | "webm" => {
                         crate::project::asset::AssetKind::Video
                     }
-                    "png" | "jpg" | "jpeg" | "bmp" | "webp" => {
+                    "png" | "jpg" | "jpeg" | "bmp" | "webp" | "svg" | "pdf" | "exr" | "hdr" => {
                         crate::project::asset::AssetKind::Image
                     }
                     "mp3" | "wav" | "ogg" | "aac" | "flac" => {
@@ -350,6 +423,18 @@ impl ProjectManager {
         )
     }
 
+    pub fn set_composition_audio_sample_rate(
+        &self,
+        id: Uuid,
+        sample_rate: u32,
+    ) -> Result<(), LibraryError> {
+        handlers::composition_handler::CompositionHandler::set_audio_sample_rate(
+            &self.project,
+            id,
+            sample_rate,
+        )
+    }
+
     pub fn is_composition_used(&self, comp_id: Uuid) -> bool {
         handlers::composition_handler::CompositionHandler::is_composition_used(
             &self.project,
@@ -402,6 +487,14 @@ impl ProjectManager {
         )
     }
 
+    pub fn set_track_muted(&self, track_id: Uuid, muted: bool) -> Result<(), LibraryError> {
+        handlers::track_handler::TrackHandler::set_track_muted(&self.project, track_id, muted)
+    }
+
+    pub fn set_track_soloed(&self, track_id: Uuid, soloed: bool) -> Result<(), LibraryError> {
+        handlers::track_handler::TrackHandler::set_track_soloed(&self.project, track_id, soloed)
+    }
+
     pub fn rename_track(&self, track_id: Uuid, new_name: &str) -> Result<(), LibraryError> {
         handlers::track_handler::TrackHandler::rename_track(&self.project, track_id, new_name)
     }
@@ -427,6 +520,21 @@ impl ProjectManager {
         )
     }
 
+    pub fn add_layers_to_track(
+        &self,
+        composition_id: Uuid,
+        track_id: Uuid,
+        clips: Vec<crate::service::NewClipSpec>,
+    ) -> Result<Vec<Uuid>, LibraryError> {
+        LayerFactory::create_layers(
+            &self.project,
+            &self.plugin_manager,
+            composition_id,
+            track_id,
+            clips,
+        )
+    }
+
     pub fn remove_layer_from_track(
         &self,
         track_id: Uuid,
@@ -439,6 +547,48 @@ impl ProjectManager {
         )
     }
 
+    pub fn duplicate_source(&self, source_id: Uuid) -> Result<Uuid, LibraryError> {
+        handlers::source_handler::SourceHandler::duplicate_source(&self.project, source_id)
+    }
+
+    pub fn trim_composition_clip(
+        &self,
+        composition_id: Uuid,
+        track_id: Uuid,
+        source_id: Uuid,
+        new_in_frame: u64,
+        new_out_frame: u64,
+        new_source_begin: i64,
+    ) -> Result<(), LibraryError> {
+        handlers::source_handler::SourceHandler::trim_composition_clip(
+            &self.project,
+            composition_id,
+            track_id,
+            source_id,
+            new_in_frame,
+            new_out_frame,
+            new_source_begin,
+        )?;
+        self.sync_layer_timing(source_id, new_in_frame, new_out_frame);
+        Ok(())
+    }
+
+    pub fn ripple_shift(
+        &self,
+        composition_id: Uuid,
+        track_id: Uuid,
+        pivot_frame: u64,
+        delta_frames: i64,
+    ) -> Result<(), LibraryError> {
+        handlers::source_handler::SourceHandler::ripple_shift(
+            &self.project,
+            composition_id,
+            track_id,
+            pivot_frame,
+            delta_frames,
+        )
+    }
+
     pub fn update_source_property(
         &self,
         source_id: Uuid,
@@ -517,6 +667,42 @@ impl ProjectManager {
         )
     }
 
+    pub fn update_target_keyframes_by_index(
+        &self,
+        source_id: Uuid,
+        target: crate::project::property::PropertyTarget,
+        property_key: &str,
+        updates: &[(usize, Option<f64>, Option<PropertyValue>)],
+    ) -> Result<Vec<usize>, LibraryError> {
+        handlers::keyframe_handler::KeyframeHandler::update_keyframes_by_index(
+            &self.project,
+            source_id,
+            target,
+            property_key,
+            updates,
+        )
+    }
+
+    pub fn set_target_keyframe_easing(
+        &self,
+        source_id: Uuid,
+        target: crate::project::property::PropertyTarget,
+        property_key: &str,
+        keyframe_index: usize,
+        handle: crate::project::property::EasingHandle,
+        easing: Option<crate::animation::EasingFunction>,
+    ) -> Result<(), LibraryError> {
+        handlers::keyframe_handler::KeyframeHandler::set_keyframe_easing(
+            &self.project,
+            source_id,
+            target,
+            property_key,
+            keyframe_index,
+            handle,
+            easing,
+        )
+    }
+
     pub fn remove_target_keyframe_by_index(
         &self,
         source_id: Uuid,
@@ -553,6 +739,16 @@ impl ProjectManager {
         )
     }
 
+    pub fn batch_update_target_properties(
+        &self,
+        updates: Vec<handlers::source_handler::TargetPropertyUpdate>,
+    ) -> Result<(), LibraryError> {
+        handlers::source_handler::SourceHandler::batch_update_target_properties(
+            &self.project,
+            updates,
+        )
+    }
+
     pub fn has_asset_with_path(&self, path: &str) -> bool {
         if let Ok(project) = self.project.read() {
             let path_norm = std::path::Path::new(path).to_string_lossy().to_string();
@@ -577,6 +773,7 @@ impl ProjectManager {
         let eval_ctx = crate::plugin::EvaluationContext {
             property_map: context,
             fps,
+            owner_id: None,
         };
         self.plugin_manager
             .get_property_evaluators()
@@ -651,6 +848,22 @@ impl ProjectManager {
         )
     }
 
+    pub fn set_source_properties_from_json(
+        &self,
+        composition_id: Uuid,
+        track_id: Uuid,
+        source_id: Uuid,
+        json: &str,
+    ) -> Result<(), LibraryError> {
+        handlers::source_handler::SourceHandler::set_properties_from_json(
+            &self.project,
+            composition_id,
+            track_id,
+            source_id,
+            json,
+        )
+    }
+
     // --- Graph Node Operations ---
 
     pub fn add_graph_node(&self, container_id: Uuid, type_id: &str) -> Result<Uuid, LibraryError> {
@@ -678,6 +891,51 @@ impl ProjectManager {
         handlers::graph_handler::GraphHandler::remove_connection(&self.project, connection_id)
     }
 
+    pub fn copy_graph_nodes(
+        &self,
+        node_ids: &[Uuid],
+    ) -> Result<handlers::graph_handler::GraphNodeSnapshot, LibraryError> {
+        handlers::graph_handler::GraphHandler::copy_graph_nodes(&self.project, node_ids)
+    }
+
+    pub fn paste_graph_nodes(
+        &self,
+        container_id: Uuid,
+        snapshot: &handlers::graph_handler::GraphNodeSnapshot,
+    ) -> Result<Vec<(Uuid, Uuid)>, LibraryError> {
+        handlers::graph_handler::GraphHandler::paste_graph_nodes(
+            &self.project,
+            container_id,
+            snapshot,
+        )
+    }
+
+    pub fn group_graph_nodes(
+        &self,
+        container_id: Uuid,
+        node_ids: &[Uuid],
+        name: &str,
+    ) -> Result<Uuid, LibraryError> {
+        handlers::graph_handler::GraphHandler::group_graph_nodes(
+            &self.project,
+            container_id,
+            node_ids,
+            name,
+        )
+    }
+
+    pub fn insert_reroute(
+        &self,
+        container_id: Uuid,
+        connection_id: Uuid,
+    ) -> Result<Uuid, LibraryError> {
+        handlers::graph_handler::GraphHandler::insert_reroute(
+            &self.project,
+            container_id,
+            connection_id,
+        )
+    }
+
     pub fn reorder_effect_chain(
         &self,
         source_id: Uuid,
@@ -746,6 +1004,42 @@ impl ProjectManager {
             }
         }
 
+        if matches!(source.kind, crate::project::source::SourceKind::Image) {
+            // Layers are cached on the asset at import; this runs every repaint
+            let layers = source
+                .reference_id
+                .and_then(|asset_id| project.assets.iter().find(|a| a.id == asset_id))
+                .map(|asset| asset.channel_layers.clone())
+                .unwrap_or_default();
+            if !layers.is_empty() {
+                // The layer the loader shows when none is selected
+                let default_layer = if layers.iter().any(|l| l == BASE_LAYER) {
+                    BASE_LAYER.to_string()
+                } else {
+                    layers[0].clone()
+                };
+                definitions.push(PropertyDefinition::new(
+                    "channel_layer",
+                    PropertyUiType::Dropdown { options: layers },
+                    "Channel Layer",
+                    PropertyValue::String(default_layer),
+                ));
+                definitions.push(PropertyDefinition::new(
+                    "exposure_offset",
+                    PropertyUiType::Float {
+                        min: -10.0,
+                        max: 10.0,
+                        step: 0.1,
+                        suffix: "EV".into(),
+                        min_hard_limit: false,
+                        max_hard_limit: false,
+                    },
+                    "Exposure Offset",
+                    PropertyValue::Number(ordered_float::OrderedFloat(0.0)),
+                ));
+            }
+        }
+
         definitions
     }
 }
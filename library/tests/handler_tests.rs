@@ -7,12 +7,13 @@ use std::sync::{Arc, RwLock};
 use library::plugin::PluginManager;
 use library::project::node::Node;
 use library::project::project::{Composition, Project};
+use library::project::property::PropertyTarget;
 use library::project::source::SourceKind;
 use library::project::track::TrackData;
 
 use library::service::handlers::graph_handler::GraphHandler;
 use library::service::handlers::layer_factory::LayerFactory;
-use library::service::handlers::source_handler::SourceHandler;
+use library::service::handlers::source_handler::{SourceHandler, TargetPropertyUpdate};
 use library::service::handlers::track_handler::TrackHandler;
 
 /// Helper: create a PluginManager (still needed for setup_source_graph_nodes).
@@ -55,6 +56,67 @@ fn test_add_track_to_composition() {
     assert!(comp.child_ids.contains(&track_id));
 }
 
+#[test]
+fn test_create_layers_adds_batch_or_nothing() {
+    use library::service::NewClipSpec;
+
+    // 複数クリップを一括追加し、途中で失敗した場合は何も追加されない
+    let (project, comp_id, _root_track_id) = setup_project();
+    let plugin_manager = make_plugin_manager();
+    let track_id = TrackHandler::add_track(&project, comp_id, "Video Track").unwrap();
+
+    let spec = |source: library::project::source::SourceData, in_frame, out_frame| NewClipSpec {
+        source,
+        in_frame,
+        out_frame,
+        insert_index: None,
+    };
+    let ids = LayerFactory::create_layers(
+        &project,
+        &plugin_manager,
+        comp_id,
+        track_id,
+        vec![
+            spec(LayerFactory::build_text_source("A", 0, 30, 30.0), 0, 30),
+            spec(LayerFactory::build_text_source("B", 30, 60, 30.0), 30, 60),
+        ],
+    )
+    .unwrap();
+    assert_eq!(ids.len(), 2);
+    {
+        let proj = project.read().unwrap();
+        assert_eq!(proj.get_source(ids[0]).unwrap().in_frame, 0);
+        assert_eq!(proj.get_source(ids[1]).unwrap().in_frame, 30);
+    }
+
+    // 自分自身を参照するコンポジションクリップは循環参照で失敗する
+    let node_count = project.read().unwrap().nodes.len();
+    let mut self_ref = library::project::source::SourceData::new(
+        uuid::Uuid::new_v4(),
+        Some(comp_id),
+        SourceKind::Composition,
+        0,
+        30,
+        0,
+        None,
+        30.0,
+        library::project::property::PropertyMap::new(),
+    );
+    self_ref.in_frame = 60;
+    let result = LayerFactory::create_layers(
+        &project,
+        &plugin_manager,
+        comp_id,
+        track_id,
+        vec![
+            spec(LayerFactory::build_text_source("C", 60, 90, 30.0), 60, 90),
+            spec(self_ref, 60, 90),
+        ],
+    );
+    assert!(result.is_err());
+    assert_eq!(project.read().unwrap().nodes.len(), node_count);
+}
+
 #[test]
 fn test_add_text_clip_creates_full_graph() {
     // テキストクリップ追加時にレイヤー + fill + transform + 正しい接続が作成される
@@ -904,3 +966,705 @@ fn test_remove_track_cleans_all_descendants() {
         "All connections should be removed after track deletion"
     );
 }
+
+// ==================== Scripted property injection tests ====================
+
+#[test]
+fn test_set_properties_from_json_updates_clip() {
+    // JSON から読み込んだ値がクリップのプロパティに定数として設定される
+    let (project, comp_id, _root_track_id) = setup_project();
+    let track_id = TrackHandler::add_track(&project, comp_id, "Track").unwrap();
+    let clip = LayerFactory::build_text_source("Hello", 0, 90, 30.0);
+    let clip_id =
+        SourceHandler::add_source_to_track(&project, comp_id, track_id, clip, 0, 90, None).unwrap();
+
+    SourceHandler::set_properties_from_json(
+        &project,
+        comp_id,
+        track_id,
+        clip_id,
+        r#"{"text": "Scripted", "size": 72}"#,
+    )
+    .unwrap();
+
+    let proj = project.read().unwrap();
+    let source = proj.get_source(clip_id).unwrap();
+    assert_eq!(
+        source.properties.get_string("text"),
+        Some("Scripted".to_string())
+    );
+    assert_eq!(source.properties.get_f64("size"), Some(72.0));
+}
+
+#[test]
+fn test_set_properties_from_json_rejects_clip_outside_track() {
+    // 指定トラックに属さないクリップへの設定は失敗すべき
+    let (project, comp_id, _root_track_id) = setup_project();
+    let track_a = TrackHandler::add_track(&project, comp_id, "Track A").unwrap();
+    let track_b = TrackHandler::add_track(&project, comp_id, "Track B").unwrap();
+    let clip = LayerFactory::build_text_source("Hello", 0, 90, 30.0);
+    let clip_id =
+        SourceHandler::add_source_to_track(&project, comp_id, track_a, clip, 0, 90, None).unwrap();
+
+    let result = SourceHandler::set_properties_from_json(
+        &project,
+        comp_id,
+        track_b,
+        clip_id,
+        r#"{"text": "Scripted"}"#,
+    );
+    assert!(result.is_err());
+}
+
+/// Helper: a batch update of `key` at time 0.
+fn property_update(
+    composition_id: uuid::Uuid,
+    track_id: uuid::Uuid,
+    source_id: uuid::Uuid,
+    target: PropertyTarget,
+    key: &str,
+    value: f64,
+) -> TargetPropertyUpdate {
+    TargetPropertyUpdate {
+        composition_id,
+        track_id,
+        source_id,
+        target,
+        property_key: key.to_string(),
+        time: 0.0,
+        value: value.into(),
+    }
+}
+
+#[test]
+fn test_batch_update_target_properties_rolls_back_on_error() {
+    // 途中で失敗したバッチは先に適用した更新も取り消すべき
+    let (project, comp_id, root_track_id) = setup_project();
+    let track_id = TrackHandler::add_track(&project, comp_id, "Track").unwrap();
+    let mut clip_ids = Vec::new();
+    for text in ["A", "B"] {
+        let clip = LayerFactory::build_text_source(text, 0, 90, 30.0);
+        clip_ids.push(
+            SourceHandler::add_source_to_track(&project, comp_id, track_id, clip, 0, 90, None)
+                .unwrap(),
+        );
+    }
+
+    SourceHandler::batch_update_target_properties(
+        &project,
+        clip_ids
+            .iter()
+            .map(|id| property_update(comp_id, track_id, *id, PropertyTarget::Clip, "size", 48.0))
+            .collect(),
+    )
+    .unwrap();
+    for id in &clip_ids {
+        let proj = project.read().unwrap();
+        assert_eq!(
+            proj.get_source(*id).unwrap().properties.get_f64("size"),
+            Some(48.0)
+        );
+    }
+
+    let result = SourceHandler::batch_update_target_properties(
+        &project,
+        vec![
+            property_update(
+                comp_id,
+                track_id,
+                clip_ids[0],
+                PropertyTarget::Clip,
+                "size",
+                96.0,
+            ),
+            property_update(
+                comp_id,
+                track_id,
+                uuid::Uuid::new_v4(),
+                PropertyTarget::Clip,
+                "size",
+                96.0,
+            ),
+        ],
+    );
+    assert!(result.is_err());
+
+    // 指定したトラックに無いクリップへの更新も拒否する
+    let result = SourceHandler::batch_update_target_properties(
+        &project,
+        vec![property_update(
+            comp_id,
+            root_track_id,
+            clip_ids[0],
+            PropertyTarget::Clip,
+            "size",
+            96.0,
+        )],
+    );
+    assert!(result.is_err());
+
+    let proj = project.read().unwrap();
+    assert_eq!(
+        proj.get_source(clip_ids[0])
+            .unwrap()
+            .properties
+            .get_f64("size"),
+        Some(48.0)
+    );
+}
+
+#[test]
+fn test_batch_update_effect_index_updates_graph_effect_node() {
+    // Effect(index) はクリップのエフェクトチェーン上のグラフノードを更新する
+    let (project, comp_id, _) = setup_project();
+    let plugin_manager = make_plugin_manager();
+
+    let track_id = TrackHandler::add_track(&project, comp_id, "Track").unwrap();
+    let image_clip = LayerFactory::build_image_source(None, "/path/to/image.png", 0, 90, 30.0);
+    let clip_kind = image_clip.kind.clone();
+    let clip_id =
+        SourceHandler::add_source_to_track(&project, comp_id, track_id, image_clip, 0, 90, None)
+            .unwrap();
+    SourceHandler::setup_source_graph_nodes(
+        &project,
+        &plugin_manager,
+        track_id,
+        clip_id,
+        &clip_kind,
+    )
+    .unwrap();
+    let layer_id = project
+        .read()
+        .unwrap()
+        .get_track(track_id)
+        .unwrap()
+        .child_ids[0];
+    let blur_id =
+        GraphHandler::add_graph_node(&project, &plugin_manager, layer_id, "effect.blur").unwrap();
+    use library::project::connection::PinId;
+    GraphHandler::add_connection(
+        &project,
+        PinId::new(clip_id, "image_out"),
+        PinId::new(blur_id, "image_in"),
+    )
+    .unwrap();
+
+    SourceHandler::batch_update_target_properties(
+        &project,
+        vec![property_update(
+            comp_id,
+            track_id,
+            clip_id,
+            PropertyTarget::Effect(0),
+            "sigma_x",
+            12.0,
+        )],
+    )
+    .unwrap();
+    {
+        let proj = project.read().unwrap();
+        let blur = proj.get_graph_node(blur_id).unwrap();
+        assert_eq!(blur.properties.get_f64("sigma_x"), Some(12.0));
+        // 音声エフェクトのような別の対象には書き込まない
+        assert!(proj.get_source(clip_id).unwrap().audio_effects.is_empty());
+    }
+
+    // チェーンに無いインデックスはエラー
+    let result = SourceHandler::batch_update_target_properties(
+        &project,
+        vec![property_update(
+            comp_id,
+            track_id,
+            clip_id,
+            PropertyTarget::Effect(1),
+            "sigma_x",
+            4.0,
+        )],
+    );
+    assert!(result.is_err());
+}
+
+// ==================== Composition clip trim tests ====================
+
+#[test]
+fn test_trim_composition_clip_validates_against_nested_duration() {
+    // ネストしたコンポジション(10秒=300フレーム)を参照するクリップをトリムする
+    let (project, comp_id, _root_track_id) = setup_project();
+    let track_id = TrackHandler::add_track(&project, comp_id, "Track").unwrap();
+    let nested = Composition::new("Nested", 1920, 1080, 30.0, 10.0);
+    let nested_id = nested.id;
+    project.write().unwrap().add_composition(nested);
+
+    let clip = library::project::source::SourceData::new(
+        uuid::Uuid::new_v4(),
+        Some(nested_id),
+        SourceKind::Composition,
+        0,
+        300,
+        0,
+        Some(300),
+        30.0,
+        library::project::property::PropertyMap::new(),
+    );
+    let clip_id =
+        SourceHandler::add_source_to_track(&project, comp_id, track_id, clip, 0, 300, None)
+            .unwrap();
+
+    // 左端を詰めるとソース開始位置も進む
+    SourceHandler::trim_composition_clip(&project, comp_id, track_id, clip_id, 30, 300, 30)
+        .unwrap();
+    {
+        let proj = project.read().unwrap();
+        let source = proj.get_source(clip_id).unwrap();
+        assert_eq!(
+            (source.in_frame, source.out_frame, source.source_begin_frame),
+            (30, 300, 30)
+        );
+    }
+
+    // 参照先の長さを超える範囲や負の開始位置は拒否される
+    assert!(
+        SourceHandler::trim_composition_clip(&project, comp_id, track_id, clip_id, 30, 320, 30)
+            .is_err()
+    );
+    assert!(
+        SourceHandler::trim_composition_clip(&project, comp_id, track_id, clip_id, 30, 300, -1)
+            .is_err()
+    );
+
+    // 2倍速では同じ長さでも参照先を2倍消費する
+    project
+        .write()
+        .unwrap()
+        .get_source_mut(clip_id)
+        .unwrap()
+        .speed = 2.0;
+    assert!(
+        SourceHandler::trim_composition_clip(&project, comp_id, track_id, clip_id, 0, 160, 0)
+            .is_err()
+    );
+    SourceHandler::trim_composition_clip(&project, comp_id, track_id, clip_id, 0, 150, 0).unwrap();
+}
+
+// ==================== Track mute/solo tests ====================
+
+#[test]
+fn test_track_mute_and_solo_silence_tracks() {
+    // ミュートしたトラックだけが無音になり、ソロがあるとソロ以外が無音になる
+    let (project, comp_id, root_track_id) = setup_project();
+    let a = TrackHandler::add_track(&project, comp_id, "A").unwrap();
+    let b = TrackHandler::add_track(&project, comp_id, "B").unwrap();
+    let b_child = TrackHandler::add_sub_track(&project, comp_id, b, "B child").unwrap();
+
+    TrackHandler::set_track_muted(&project, a, true).unwrap();
+    {
+        let proj = project.read().unwrap();
+        let comp = proj.get_composition(comp_id).unwrap();
+        assert!(proj.is_track_silenced(comp, a));
+        assert!(!proj.is_track_silenced(comp, b));
+        assert!(proj.get_track(a).unwrap().muted);
+    }
+
+    // サブトラックをソロにすると、その親は残り、他のトラックは無音になる
+    TrackHandler::set_track_muted(&project, a, false).unwrap();
+    TrackHandler::set_track_soloed(&project, b_child, true).unwrap();
+    let proj = project.read().unwrap();
+    let comp = proj.get_composition(comp_id).unwrap();
+    assert!(!proj.is_track_silenced(comp, b_child));
+    assert!(!proj.is_track_silenced(comp, b));
+    assert!(proj.is_track_silenced(comp, root_track_id));
+    assert!(proj.is_track_silenced(comp, a));
+    drop(proj);
+
+    // 存在しないトラックはエラー
+    assert!(TrackHandler::set_track_muted(&project, uuid::Uuid::new_v4(), true).is_err());
+}
+
+// ==================== Ripple edit tests ====================
+
+#[test]
+fn test_ripple_shift_moves_downstream_clips() {
+    // 0-30, 30-60, 90-120 の3クリップ。先頭を 20 まで詰めると後続が 10 フレーム左へ寄る
+    let (project, comp_id, _) = setup_project();
+    let track_id = TrackHandler::add_track(&project, comp_id, "Track").unwrap();
+    let mut clip_ids = Vec::new();
+    for (in_frame, out_frame) in [(0, 30), (30, 60), (90, 120)] {
+        let clip = LayerFactory::build_text_source("Clip", in_frame, out_frame, 30.0);
+        clip_ids.push(
+            SourceHandler::add_source_to_track(
+                &project, comp_id, track_id, clip, in_frame, out_frame, None,
+            )
+            .unwrap(),
+        );
+    }
+
+    SourceHandler::ripple_shift(&project, comp_id, track_id, 30, -10).unwrap();
+
+    let proj = project.read().unwrap();
+    let frames: Vec<(u64, u64)> = clip_ids
+        .iter()
+        .map(|id| {
+            let source = proj.get_source(*id).unwrap();
+            (source.in_frame, source.out_frame)
+        })
+        .collect();
+    // ピボットより前のクリップは動かず、間隔は保たれる
+    assert_eq!(frames, vec![(0, 30), (20, 50), (80, 110)]);
+    assert_eq!(
+        proj.get_source(clip_ids[1])
+            .unwrap()
+            .properties
+            .get_f64("in_frame"),
+        Some(20.0)
+    );
+}
+
+#[test]
+fn test_ripple_shift_rejects_moving_before_zero() {
+    // 0 フレームより前へ押し出すシフトは拒否され、クリップは変わらない
+    let (project, comp_id, _) = setup_project();
+    let track_id = TrackHandler::add_track(&project, comp_id, "Track").unwrap();
+    let clip = LayerFactory::build_text_source("Clip", 30, 60, 30.0);
+    let clip_id =
+        SourceHandler::add_source_to_track(&project, comp_id, track_id, clip, 30, 60, None)
+            .unwrap();
+
+    assert!(SourceHandler::ripple_shift(&project, comp_id, track_id, 10, -40).is_err());
+    // 存在しないトラックも拒否される
+    assert!(SourceHandler::ripple_shift(&project, comp_id, uuid::Uuid::new_v4(), 0, 10).is_err());
+
+    let proj = project.read().unwrap();
+    let source = proj.get_source(clip_id).unwrap();
+    assert_eq!((source.in_frame, source.out_frame), (30, 60));
+}
+
+#[test]
+fn test_duplicate_source_copies_layer_graph_with_new_ids() {
+    // 複製はレイヤー・グラフノード・接続を新しいIDで丸ごとコピーする
+    let (project, comp_id, _) = setup_project();
+    let plugin_manager = make_plugin_manager();
+
+    let track_id = TrackHandler::add_track(&project, comp_id, "Track").unwrap();
+    let text_clip = LayerFactory::build_text_source("Copy me", 0, 30, 30.0);
+    let clip_kind = text_clip.kind.clone();
+    let clip_id =
+        SourceHandler::add_source_to_track(&project, comp_id, track_id, text_clip, 0, 30, None)
+            .unwrap();
+    SourceHandler::setup_source_graph_nodes(
+        &project,
+        &plugin_manager,
+        track_id,
+        clip_id,
+        &clip_kind,
+    )
+    .unwrap();
+    let connection_count = project.read().unwrap().connections.len();
+
+    let copy_id = SourceHandler::duplicate_source(&project, clip_id).unwrap();
+    assert_ne!(copy_id, clip_id);
+
+    let proj = project.read().unwrap();
+    assert_eq!(
+        proj.get_source(copy_id).unwrap().properties,
+        proj.get_source(clip_id).unwrap().properties
+    );
+
+    // 複製レイヤーは元レイヤーの直後に挿入される
+    let layer_id = proj.find_parent_track(clip_id).unwrap();
+    let copy_layer_id = proj.find_parent_track(copy_id).unwrap();
+    assert_ne!(layer_id, copy_layer_id);
+    let track_children = &proj.get_track(track_id).unwrap().child_ids;
+    let index = track_children
+        .iter()
+        .position(|id| *id == layer_id)
+        .unwrap();
+    assert_eq!(track_children[index + 1], copy_layer_id);
+
+    // グラフノードは共有されない
+    let layer_children = &proj.get_layer(layer_id).unwrap().child_ids;
+    let copy_children = &proj.get_layer(copy_layer_id).unwrap().child_ids;
+    assert_eq!(layer_children.len(), copy_children.len());
+    assert!(copy_children.iter().all(|id| !layer_children.contains(id)));
+
+    // 接続も複製側のノード同士で張り直される
+    assert_eq!(proj.connections.len(), connection_count * 2);
+    assert!(
+        proj.connections
+            .iter()
+            .any(|c| c.from.node_id == copy_id && c.from.pin_name == "shape_out")
+    );
+    assert!(
+        proj.connections
+            .iter()
+            .any(|c| c.to.node_id == copy_layer_id && c.to.pin_name == "image_out")
+    );
+}
+
+#[test]
+fn test_copy_paste_graph_nodes_keeps_only_internal_connections() {
+    // 選択ノード間の接続だけが複製側に張り直され、内容はコピー時点のものになる
+    let (project, comp_id, _) = setup_project();
+    let plugin_manager = make_plugin_manager();
+
+    let track_id = TrackHandler::add_track(&project, comp_id, "Track").unwrap();
+    let image_clip = LayerFactory::build_image_source(None, "/path/to/image.png", 0, 90, 30.0);
+    let clip_kind = image_clip.kind.clone();
+    let clip_id =
+        SourceHandler::add_source_to_track(&project, comp_id, track_id, image_clip, 0, 90, None)
+            .unwrap();
+    SourceHandler::setup_source_graph_nodes(
+        &project,
+        &plugin_manager,
+        track_id,
+        clip_id,
+        &clip_kind,
+    )
+    .unwrap();
+    let layer_id = project
+        .read()
+        .unwrap()
+        .get_track(track_id)
+        .unwrap()
+        .child_ids[0];
+
+    let blur_id =
+        GraphHandler::add_graph_node(&project, &plugin_manager, layer_id, "effect.blur").unwrap();
+    let glow_id =
+        GraphHandler::add_graph_node(&project, &plugin_manager, layer_id, "effect.glow").unwrap();
+    use library::project::connection::PinId;
+    GraphHandler::add_connection(
+        &project,
+        PinId::new(clip_id, "image_out"),
+        PinId::new(blur_id, "image_in"),
+    )
+    .unwrap();
+    GraphHandler::add_connection(
+        &project,
+        PinId::new(blur_id, "image_out"),
+        PinId::new(glow_id, "image_in"),
+    )
+    .unwrap();
+    GraphHandler::update_graph_node_property(
+        &project,
+        blur_id,
+        "sigma_x",
+        0.0,
+        library::project::property::PropertyValue::from(4.0),
+        None,
+    )
+    .unwrap();
+
+    let snapshot = GraphHandler::copy_graph_nodes(&project, &[blur_id, glow_id, clip_id]).unwrap();
+
+    // コピー後の変更や削除は貼り付け結果に影響しない
+    GraphHandler::update_graph_node_property(
+        &project,
+        blur_id,
+        "sigma_x",
+        0.0,
+        library::project::property::PropertyValue::from(9.0),
+        None,
+    )
+    .unwrap();
+    GraphHandler::remove_graph_node(&project, glow_id).unwrap();
+    let connection_count = project.read().unwrap().connections.len();
+
+    let mapping = GraphHandler::paste_graph_nodes(&project, layer_id, &snapshot).unwrap();
+
+    // ソースノードはグラフノードではないので複製されない
+    assert_eq!(mapping.len(), 2);
+    let (_, blur_copy) = mapping[0];
+    let (_, glow_copy) = mapping[1];
+
+    let proj = project.read().unwrap();
+    let blur = proj.get_graph_node(blur_copy).unwrap();
+    assert_eq!(blur.type_id, "effect.blur");
+    assert_eq!(blur.properties.get_f64("sigma_x"), Some(4.0));
+    assert_eq!(
+        proj.get_graph_node(glow_copy).unwrap().type_id,
+        "effect.glow"
+    );
+    let layer_children = &proj.get_layer(layer_id).unwrap().child_ids;
+    assert!(layer_children.contains(&blur_copy) && layer_children.contains(&glow_copy));
+
+    // 外部ノード (clip) への接続は落とされる
+    assert_eq!(proj.connections.len(), connection_count + 1);
+    assert!(proj.connections.iter().any(|c| c.from.node_id == blur_copy
+        && c.to.node_id == glow_copy
+        && c.to.pin_name == "image_in"));
+    assert!(!proj.connections.iter().any(|c| c.to.node_id == blur_copy));
+}
+
+#[test]
+fn test_group_graph_nodes_into_subgraph() {
+    // 選択ノードがサブグラフに移り、接続はそのまま残る
+    let (project, comp_id, _) = setup_project();
+    let plugin_manager = make_plugin_manager();
+
+    let track_id = TrackHandler::add_track(&project, comp_id, "Track").unwrap();
+    let blur_id =
+        GraphHandler::add_graph_node(&project, &plugin_manager, track_id, "effect.blur").unwrap();
+    let glow_id =
+        GraphHandler::add_graph_node(&project, &plugin_manager, track_id, "effect.glow").unwrap();
+    use library::project::connection::PinId;
+    GraphHandler::add_connection(
+        &project,
+        PinId::new(blur_id, "image_out"),
+        PinId::new(glow_id, "image_in"),
+    )
+    .unwrap();
+    let connections = project.read().unwrap().connections.clone();
+
+    let group_id =
+        GraphHandler::group_graph_nodes(&project, track_id, &[blur_id, glow_id], "Group").unwrap();
+
+    {
+        let proj = project.read().unwrap();
+        assert_eq!(proj.get_track(track_id).unwrap().child_ids, vec![group_id]);
+        assert_eq!(
+            proj.get_container_child_ids(group_id).unwrap(),
+            &vec![blur_id, glow_id]
+        );
+        assert_eq!(proj.find_parent_container(blur_id), Some(group_id));
+        assert_eq!(proj.connections, connections);
+    }
+
+    // サブグラフを削除すると中のノードも消える
+    GraphHandler::remove_graph_node(&project, group_id).unwrap();
+    let proj = project.read().unwrap();
+    assert!(proj.get_track(track_id).unwrap().child_ids.is_empty());
+    assert!(proj.get_graph_node(blur_id).is_none());
+    assert!(proj.get_graph_node(glow_id).is_none());
+    assert!(
+        !proj
+            .connections
+            .iter()
+            .any(|c| c.from.node_id == blur_id || c.to.node_id == glow_id)
+    );
+}
+
+#[test]
+fn test_remove_source_removes_emptied_subgraph() {
+    // グループ化されたエフェクトもソースと一緒に消え、空になったサブグラフも残らない
+    use library::project::connection::PinId;
+
+    let (project, comp_id, _) = setup_project();
+    let plugin_manager = make_plugin_manager();
+
+    let track_id = TrackHandler::add_track(&project, comp_id, "Track").unwrap();
+    let image_clip = LayerFactory::build_image_source(None, "/path/to/image.png", 0, 90, 30.0);
+    let clip_kind = image_clip.kind.clone();
+    let clip_id =
+        SourceHandler::add_source_to_track(&project, comp_id, track_id, image_clip, 0, 90, None)
+            .unwrap();
+    SourceHandler::setup_source_graph_nodes(
+        &project,
+        &plugin_manager,
+        track_id,
+        clip_id,
+        &clip_kind,
+    )
+    .unwrap();
+    let layer_id = project
+        .read()
+        .unwrap()
+        .get_track(track_id)
+        .unwrap()
+        .child_ids[0];
+
+    let blur_id =
+        GraphHandler::add_graph_node(&project, &plugin_manager, layer_id, "effect.blur").unwrap();
+    GraphHandler::add_connection(
+        &project,
+        PinId::new(clip_id, "image_out"),
+        PinId::new(blur_id, "image_in"),
+    )
+    .unwrap();
+    let group_id =
+        GraphHandler::group_graph_nodes(&project, layer_id, &[blur_id], "Group").unwrap();
+
+    SourceHandler::remove_source_from_track(&project, track_id, clip_id).unwrap();
+
+    let proj = project.read().unwrap();
+    assert!(proj.get_graph_node(blur_id).is_none());
+    assert!(proj.get_graph_node(group_id).is_none());
+    assert!(proj.get_layer(layer_id).is_none());
+    assert!(proj.get_track(track_id).unwrap().child_ids.is_empty());
+}
+
+#[test]
+fn test_insert_reroute_splits_connection_and_bridges_on_removal() {
+    // リルートは接続を二つに分け、削除すると元の接続に戻る
+    use library::project::connection::PinId;
+    use library::project::graph_node::{REROUTE_INPUT_PIN, REROUTE_OUTPUT_PIN};
+
+    let (project, comp_id, _) = setup_project();
+    let plugin_manager = make_plugin_manager();
+
+    let track_id = TrackHandler::add_track(&project, comp_id, "Track").unwrap();
+    let blur_id =
+        GraphHandler::add_graph_node(&project, &plugin_manager, track_id, "effect.blur").unwrap();
+    let glow_id =
+        GraphHandler::add_graph_node(&project, &plugin_manager, track_id, "effect.glow").unwrap();
+    let conn = GraphHandler::add_connection(
+        &project,
+        PinId::new(blur_id, "image_out"),
+        PinId::new(glow_id, "image_in"),
+    )
+    .unwrap();
+
+    let reroute_id = GraphHandler::insert_reroute(&project, track_id, conn.id).unwrap();
+    {
+        let proj = project.read().unwrap();
+        assert!(proj.get_graph_node(reroute_id).unwrap().is_reroute());
+        assert_eq!(proj.find_parent_container(reroute_id), Some(track_id));
+        let pairs: Vec<(PinId, PinId)> = proj
+            .connections
+            .iter()
+            .map(|c| (c.from.clone(), c.to.clone()))
+            .collect();
+        assert_eq!(
+            pairs,
+            vec![
+                (
+                    PinId::new(blur_id, "image_out"),
+                    PinId::new(reroute_id, REROUTE_INPUT_PIN)
+                ),
+                (
+                    PinId::new(reroute_id, REROUTE_OUTPUT_PIN),
+                    PinId::new(glow_id, "image_in")
+                ),
+            ]
+        );
+    }
+
+    GraphHandler::remove_graph_node(&project, reroute_id).unwrap();
+    let proj = project.read().unwrap();
+    assert_eq!(proj.connections.len(), 1);
+    assert_eq!(proj.connections[0].from, PinId::new(blur_id, "image_out"));
+    assert_eq!(proj.connections[0].to, PinId::new(glow_id, "image_in"));
+}
+
+#[test]
+fn test_group_graph_nodes_rejects_nodes_outside_container() {
+    // コンテナ直下にないノードはグループ化できない
+    let (project, comp_id, _) = setup_project();
+    let plugin_manager = make_plugin_manager();
+
+    let track_a = TrackHandler::add_track(&project, comp_id, "A").unwrap();
+    let track_b = TrackHandler::add_track(&project, comp_id, "B").unwrap();
+    let blur_id =
+        GraphHandler::add_graph_node(&project, &plugin_manager, track_b, "effect.blur").unwrap();
+
+    assert!(GraphHandler::group_graph_nodes(&project, track_a, &[blur_id], "Group").is_err());
+    assert!(GraphHandler::group_graph_nodes(&project, track_b, &[], "Group").is_err());
+    assert_eq!(
+        project
+            .read()
+            .unwrap()
+            .get_track(track_b)
+            .unwrap()
+            .child_ids,
+        vec![blur_id]
+    );
+}
@@ -75,6 +75,29 @@ fn test_project_serialization_roundtrip() {
     );
 }
 
+#[test]
+fn test_property_map_keeps_insertion_order() {
+    let mut props = PropertyMap::new();
+    for key in ["size", "color", "align", "opacity"] {
+        props.set(
+            key.to_string(),
+            library::project::property::Property::constant(
+                library::project::property::PropertyValue::Number(OrderedFloat(1.0)),
+            ),
+        );
+    }
+    let keys = |props: &PropertyMap| props.iter().map(|(k, _)| k.clone()).collect::<Vec<_>>();
+    assert_eq!(keys(&props), ["size", "color", "align", "opacity"]);
+
+    // 並べ替えた順序はシリアライズ後も保たれる
+    assert!(props.reorder("opacity", 0));
+    assert!(props.reorder("size", 99));
+    assert!(!props.reorder("missing", 0));
+    let json = serde_json::to_string(&props).unwrap();
+    let loaded: PropertyMap = serde_json::from_str(&json).unwrap();
+    assert_eq!(keys(&loaded), ["opacity", "color", "align", "size"]);
+}
+
 #[test]
 fn test_property_serialization() {
     let mut props = PropertyMap::new();
@@ -183,3 +206,305 @@ fn test_node_based_structure() {
     assert!(project.get_source(clip1_id).is_some());
     assert!(project.get_source(clip2_id).is_some());
 }
+
+#[test]
+fn test_find_clips_by_asset() {
+    let mut project = Project::new("Asset Lookup");
+    let asset = Asset::new("shot", "/media/shot.mp4", AssetKind::Video);
+    let asset_id = asset.id;
+    project.assets.push(asset);
+
+    let comp = Composition::new("Main", 1920, 1080, 30.0, 10.0);
+    let comp_id = comp.id;
+    project.add_composition(comp);
+    let track = TrackData::new("V1");
+    let track_id = track.id;
+    project.add_node(Node::Track(track));
+    project
+        .get_composition_mut(comp_id)
+        .unwrap()
+        .child_ids
+        .push(track_id);
+
+    let new_clip = |reference_id: Option<Uuid>, path: &str| {
+        let mut clip = SourceData::new(
+            Uuid::new_v4(),
+            reference_id,
+            library::project::source::SourceKind::Video,
+            0,
+            30,
+            0,
+            Some(300),
+            30.0,
+            PropertyMap::new(),
+        );
+        clip.set_constant_property(
+            "file_path",
+            library::project::property::PropertyValue::String(path.to_string()),
+        );
+        clip
+    };
+    // 同じファイルの別ストリームとして取り込まれたアセット
+    let other_stream = Asset::new("shot audio", "/media/shot.mp4", AssetKind::Audio);
+    let other_stream_id = other_stream.id;
+    project.assets.push(other_stream);
+
+    // 参照IDで一致するクリップと、ファイルパスだけで一致するクリップ
+    // (同じパスでも別アセットを参照するクリップは含まれない)
+    let by_reference = new_clip(Some(asset_id), "/media/renamed.mp4");
+    let by_path = new_clip(None, "/media/shot.mp4");
+    let other_asset = new_clip(Some(other_stream_id), "/media/shot.mp4");
+    let unrelated = new_clip(None, "/media/other.mp4");
+    let expected = [
+        (comp_id, track_id, by_reference.id),
+        (comp_id, track_id, by_path.id),
+    ];
+    for clip in [by_reference, by_path, other_asset, unrelated] {
+        project.get_track_mut(track_id).unwrap().add_child(clip.id);
+        project.add_node(Node::Source(clip));
+    }
+
+    assert_eq!(project.find_clips_by_asset(asset_id), expected);
+    assert!(project.find_clips_by_asset(Uuid::new_v4()).is_empty());
+}
+
+#[test]
+fn test_source_blend_mode_defaults_to_normal() {
+    use library::runtime::draw_type::BlendMode;
+
+    let mut clip = SourceData::new(
+        Uuid::new_v4(),
+        None,
+        library::project::source::SourceKind::Image,
+        0,
+        30,
+        0,
+        None,
+        30.0,
+        PropertyMap::new(),
+    );
+    assert_eq!(clip.blend_mode, BlendMode::Normal);
+
+    clip.blend_mode = BlendMode::Subtract;
+    let json = serde_json::to_string(&clip).unwrap();
+    let loaded: SourceData = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded.blend_mode, BlendMode::Subtract);
+
+    // blend_mode を持たない古いプロジェクトは Normal として読み込まれる
+    let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    value.as_object_mut().unwrap().remove("blend_mode");
+    let legacy: SourceData = serde_json::from_value(value).unwrap();
+    assert_eq!(legacy.blend_mode, BlendMode::Normal);
+}
+
+#[test]
+fn test_source_clone_with_new_id_regenerates_nested_ids() {
+    use library::project::effect::EffectConfig;
+
+    let mut clip = SourceData::new(
+        Uuid::new_v4(),
+        None,
+        library::project::source::SourceKind::Audio,
+        0,
+        30,
+        0,
+        None,
+        30.0,
+        PropertyMap::new(),
+    );
+    clip.audio_effects.push(EffectConfig {
+        id: Uuid::new_v4(),
+        effect_type: "gain".to_string(),
+        properties: PropertyMap::new(),
+    });
+
+    let copy = clip.clone_with_new_id();
+    assert_ne!(copy.id, clip.id);
+    assert_ne!(copy.audio_effects[0].id, clip.audio_effects[0].id);
+    assert_eq!(copy.audio_effects[0].effect_type, "gain");
+    assert_eq!(copy.in_frame, clip.in_frame);
+}
+
+#[test]
+fn test_audio_effect_target_resolves_audio_effect_chain() {
+    use library::project::effect::EffectConfig;
+    use library::project::property::{Property, PropertyTarget, PropertyValue};
+
+    let mut clip = SourceData::new(
+        Uuid::new_v4(),
+        None,
+        library::project::source::SourceKind::Audio,
+        0,
+        30,
+        0,
+        None,
+        30.0,
+        PropertyMap::new(),
+    );
+    let mut props = PropertyMap::new();
+    props.set(
+        "gain".to_string(),
+        Property::constant(PropertyValue::from(2.0)),
+    );
+    clip.audio_effects.push(EffectConfig {
+        id: Uuid::new_v4(),
+        effect_type: "gain".to_string(),
+        properties: props,
+    });
+
+    // AudioEffect はオーディオエフェクトチェーンを指し、Effect は指さない
+    let map = clip
+        .get_property_map_mut(PropertyTarget::AudioEffect(0))
+        .expect("audio effect properties");
+    assert!(map.get("gain").is_some());
+    assert!(
+        clip.get_property_map_mut(PropertyTarget::Effect(0))
+            .is_none()
+    );
+    assert!(
+        clip.get_property_map_mut(PropertyTarget::AudioEffect(1))
+            .is_none()
+    );
+}
+
+#[test]
+fn test_source_label_color_overrides_display_color() {
+    let mut clip = SourceData::new(
+        Uuid::new_v4(),
+        None,
+        library::project::source::SourceKind::Video,
+        0,
+        30,
+        0,
+        None,
+        30.0,
+        PropertyMap::new(),
+    );
+    let kind_color = clip.display_color();
+
+    // ラベル色が設定されていればそれを表示色に使う
+    clip.label_color = Some([230, 70, 70]);
+    assert_eq!(clip.display_color(), (230, 70, 70));
+    let json = serde_json::to_string(&clip).unwrap();
+    let loaded: SourceData = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded.label_color, Some([230, 70, 70]));
+
+    // 解除すると種類ごとの色に戻る
+    clip.label_color = None;
+    assert_eq!(clip.display_color(), kind_color);
+    assert!(
+        !serde_json::to_string(&clip)
+            .unwrap()
+            .contains("label_color")
+    );
+}
+
+#[test]
+fn test_composition_node_layout_roundtrip() {
+    use library::project::composition::NodeLayout;
+
+    // ノード配置はコンポジションと一緒に保存・復元される
+    let mut project = Project::new("Layout");
+    let comp = Composition::new("Comp", 1920, 1080, 30.0, 10.0);
+    let comp_id = comp.id;
+    project.add_composition(comp);
+    let node_id = Uuid::new_v4();
+    let mut layout = NodeLayout::default();
+    layout.positions.insert(node_id, [120.0, -40.5]);
+    layout.collapsed_groups.insert(node_id);
+    project.get_composition_mut(comp_id).unwrap().node_layout = Some(layout.clone());
+
+    let loaded = Project::load(&project.save().unwrap()).unwrap();
+    assert_eq!(
+        loaded.get_composition(comp_id).unwrap().node_layout,
+        Some(layout)
+    );
+
+    // 配置を持たない古いプロジェクトも読み込める
+    let json = project
+        .save()
+        .unwrap()
+        .replace("\"node_layout\"", "\"unused_layout\"");
+    let legacy = Project::load(&json).unwrap();
+    assert_eq!(legacy.get_composition(comp_id).unwrap().node_layout, None);
+}
+
+#[test]
+fn test_composition_work_area_range() {
+    // 未設定ならコンポジション全体
+    let mut comp = Composition::new("Comp", 1920, 1080, 30.0, 10.0);
+    assert_eq!(comp.work_area_range(), 0..300);
+
+    // 片側だけ設定した場合はもう片側を全体の端で補う
+    comp.work_area_in = Some(30);
+    assert_eq!(comp.work_area_range(), 30..300);
+    comp.work_area_out = Some(90);
+    assert_eq!(comp.work_area_range(), 30..90);
+
+    // 開始が終了を越えても範囲は反転しない
+    comp.work_area_in = Some(120);
+    assert_eq!(comp.work_area_range(), 90..90);
+
+    // 数値で保存された古いワークエリアも読み込める
+    let mut project = Project::new("WorkArea");
+    let comp_id = comp.id;
+    project.add_composition(comp);
+    let json = project
+        .save()
+        .unwrap()
+        .replace("\"work_area_in\":120", "\"work_area_in\":15");
+    let loaded = Project::load(&json).unwrap();
+    assert_eq!(
+        loaded.get_composition(comp_id).unwrap().work_area_range(),
+        15..90
+    );
+
+    // 古い既定値 (0..0, 0..全体) は未設定として読み込む
+    for legacy_out in [0, 300] {
+        let json = project
+            .save()
+            .unwrap()
+            .replace("\"work_area_in\":120", "\"work_area_in\":0")
+            .replace(
+                "\"work_area_out\":90",
+                &format!("\"work_area_out\":{legacy_out}"),
+            );
+        let loaded = Project::load(&json).unwrap();
+        let comp = loaded.get_composition(comp_id).unwrap();
+        assert_eq!(comp.work_area_in, None);
+        assert_eq!(comp.work_area_out, None);
+        assert_eq!(comp.work_area_range(), 0..300);
+    }
+}
+
+#[test]
+fn test_project_file_paths_relative_to_project_file() {
+    let dir = std::env::temp_dir().join("project_file_paths");
+    let mut project = Project::new("Paths");
+
+    // 保存前は選んだパスをそのまま保存・解決する
+    let lut = dir.join("luts").join("film.cube");
+    assert_eq!(project.store_file_path(&lut), lut.to_string_lossy());
+    assert_eq!(
+        project.resolve_file_path("luts/film.cube"),
+        "luts/film.cube"
+    );
+
+    // プロジェクトのフォルダ内のファイルは相対パスで保存し、読み込み時に戻す
+    project.file_dir = Some(dir.clone());
+    let stored = project.store_file_path(&lut);
+    assert_eq!(
+        std::path::Path::new(&stored),
+        std::path::Path::new("luts").join("film.cube")
+    );
+    assert_eq!(project.resolve_file_path(&stored), lut.to_string_lossy());
+
+    // フォルダ外のファイルは絶対パスのまま
+    let outside = std::env::temp_dir().join("other.cube");
+    assert_eq!(project.store_file_path(&outside), outside.to_string_lossy());
+    assert_eq!(project.resolve_file_path(""), "");
+
+    // 保存先のフォルダはシリアライズしない
+    let loaded = Project::load(&project.save().unwrap()).unwrap();
+    assert_eq!(loaded.file_dir, None);
+}
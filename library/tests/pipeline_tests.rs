@@ -196,6 +196,93 @@ fn test_shape_clip_renders_through_pipeline() {
     }
 }
 
+/// Test: rendering only a region matches the same crop of a full render.
+#[test]
+fn test_region_render_matches_crop_of_full_render() {
+    use library::rendering::render_server::CompositionRenderParams;
+    use library::runtime::frame::Region;
+
+    let (project, comp_id, _) = setup_project();
+    let plugin_manager = make_plugin_manager();
+
+    let track_id = TrackHandler::add_track(&project, comp_id, "Track 1").unwrap();
+    let shape_clip = LayerFactory::build_shape_source(0, 90, 30.0);
+    let clip_kind = shape_clip.kind.clone();
+    let clip_id =
+        SourceHandler::add_source_to_track(&project, comp_id, track_id, shape_clip, 0, 90, None)
+            .unwrap();
+    SourceHandler::setup_source_graph_nodes(
+        &project,
+        &plugin_manager,
+        track_id,
+        clip_id,
+        &clip_kind,
+    )
+    .unwrap();
+
+    let proj = project.read().unwrap();
+    let comp = proj.get_composition(comp_id).unwrap();
+    let engine = EvalEngine::with_default_evaluators();
+    let cache_manager = CacheManager::new();
+    let render = |renderer: &mut SkiaRenderer, region: Option<Region>| {
+        engine
+            .evaluate_composition(
+                &proj,
+                comp,
+                &plugin_manager,
+                renderer,
+                &cache_manager,
+                plugin_manager.get_property_evaluators(),
+                0,
+                1.0,
+                region,
+            )
+            .unwrap();
+    };
+
+    // ハート形の一部を含む領域
+    let region = Region {
+        x: 20.0,
+        y: 10.0,
+        width: 60.0,
+        height: 50.0,
+    };
+
+    let mut full = make_renderer();
+    render(&mut full, None);
+    let expected = full.capture_region(&region).unwrap();
+
+    // 領域サイズのターゲットに描画し、原点のずれは一度だけ適用される
+    let params = CompositionRenderParams {
+        project: proj.clone(),
+        composition_id: comp_id,
+        frame_number: 0,
+        render_scale: 1.0,
+        region: Some(region),
+    };
+    let (width, height) = params.target_size(comp);
+    assert_eq!((width, height), (60, 50));
+    let transparent = Color {
+        r: 0,
+        g: 0,
+        b: 0,
+        a: 0,
+    };
+    let mut roi = SkiaRenderer::new(width, height, transparent, false, None);
+    render(&mut roi, Some(region));
+    let actual = roi
+        .capture_region(&Region {
+            x: 0.0,
+            y: 0.0,
+            width: 60.0,
+            height: 50.0,
+        })
+        .unwrap();
+
+    assert!(expected.data.iter().any(|&b| b != 0));
+    assert_eq!(actual.data, expected.data);
+}
+
 /// Test: an SkSL clip should produce an image through the pipeline.
 #[test]
 fn test_sksl_clip_renders_through_pipeline() {
@@ -309,6 +396,76 @@ fn test_basic_skia_rendering_works() {
     }
 }
 
+/// Test: capture_region returns only the requested sub-rectangle of the surface.
+#[test]
+fn test_capture_region_extracts_sub_image() {
+    use library::rendering::renderer::Renderer;
+    use library::runtime::draw_type::DrawStyle;
+    use library::runtime::entity::StyleConfig;
+    use library::runtime::frame::Region;
+    use library::runtime::transform::Transform;
+
+    let mut renderer = make_renderer();
+    renderer.clear().unwrap();
+
+    let style = StyleConfig {
+        id: uuid::Uuid::new_v4(),
+        style: DrawStyle::Fill {
+            color: Color {
+                r: 255,
+                g: 0,
+                b: 0,
+                a: 255,
+            },
+            offset: 0.0,
+        },
+    };
+    let shape = renderer
+        .rasterize_shape_layer(
+            "M 100,100 L 200,100 L 200,200 L 100,200 Z",
+            &[style],
+            &vec![],
+            &Transform::default(),
+        )
+        .unwrap();
+    renderer.draw_layer(&shape, &Transform::default()).unwrap();
+
+    // 図形の内側の領域は赤で埋まっている
+    let inside = renderer
+        .capture_region(&Region {
+            x: 120.0,
+            y: 120.0,
+            width: 40.0,
+            height: 20.0,
+        })
+        .unwrap();
+    assert_eq!((inside.width, inside.height), (40, 20));
+    assert!(inside.data.chunks(4).all(|px| px[0] == 255 && px[3] == 255));
+
+    // 図形の外側の領域は透明
+    let outside = renderer
+        .capture_region(&Region {
+            x: 500.0,
+            y: 500.0,
+            width: 10.0,
+            height: 10.0,
+        })
+        .unwrap();
+    assert!(outside.data.iter().all(|&b| b == 0));
+
+    // サーフェス外の領域はエラー
+    assert!(
+        renderer
+            .capture_region(&Region {
+                x: 5000.0,
+                y: 0.0,
+                width: 10.0,
+                height: 10.0,
+            })
+            .is_err()
+    );
+}
+
 /// Test: check what the TransformEvaluator does with default properties (scale 100%).
 #[test]
 fn test_transform_scale_issue() {
@@ -465,3 +622,365 @@ fn test_project_structure_for_render_server() {
         upstream.unwrap().from.pin_name
     );
 }
+
+/// Test: drawing without a usable ICC profile matches a plain draw_layer.
+#[test]
+fn test_draw_image_with_color_profile_falls_back_without_profile() {
+    use library::rendering::renderer::Renderer;
+    use library::runtime::Image;
+    use library::runtime::frame::Region;
+    use library::runtime::transform::Transform;
+
+    let image = Image::new(4, 4, [0u8, 128, 255, 255].repeat(16));
+    let region = Region {
+        x: 0.0,
+        y: 0.0,
+        width: 4.0,
+        height: 4.0,
+    };
+
+    let mut expected = make_renderer();
+    expected.clear().unwrap();
+    expected
+        .draw_layer(&RenderOutput::Image(image.clone()), &Transform::default())
+        .unwrap();
+    let expected = expected.capture_region(&region).unwrap();
+
+    // プロファイルなし・解析不能なプロファイルはどちらも無変換で描画される
+    for profile in [None, Some(&b"not an icc profile"[..])] {
+        let mut renderer = make_renderer();
+        renderer.clear().unwrap();
+        renderer
+            .draw_image_with_color_profile(&image, profile, &Transform::default())
+            .unwrap();
+        let actual = renderer.capture_region(&region).unwrap();
+        assert_eq!(actual.data, expected.data);
+    }
+}
+
+/// Build a minimal ICC v2 profile with sRGB primaries and linear (identity) curves.
+fn linear_srgb_icc_profile() -> Vec<u8> {
+    fn s15f16(v: f64) -> [u8; 4] {
+        ((v * 65536.0).round() as i32).to_be_bytes()
+    }
+    fn xyz(x: f64, y: f64, z: f64) -> Vec<u8> {
+        [&b"XYZ "[..], &[0; 4], &s15f16(x), &s15f16(y), &s15f16(z)].concat()
+    }
+    // D50 に順応した sRGB の原色と、恒等トーンカーブ
+    let curve = [&b"curv"[..], &[0; 4], &0u32.to_be_bytes()].concat();
+    let tags: Vec<(&[u8; 4], Vec<u8>)> = vec![
+        (b"rXYZ", xyz(0.4361, 0.2225, 0.0139)),
+        (b"gXYZ", xyz(0.3851, 0.7169, 0.0971)),
+        (b"bXYZ", xyz(0.1431, 0.0606, 0.7141)),
+        (b"rTRC", curve.clone()),
+        (b"gTRC", curve.clone()),
+        (b"bTRC", curve),
+    ];
+
+    let mut table = (tags.len() as u32).to_be_bytes().to_vec();
+    let mut data = Vec::new();
+    let mut offset = 128 + 4 + 12 * tags.len();
+    for (signature, bytes) in &tags {
+        table.extend_from_slice(&signature[..]);
+        table.extend_from_slice(&(offset as u32).to_be_bytes());
+        table.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+        data.extend_from_slice(bytes);
+        offset += bytes.len();
+    }
+
+    let mut header = vec![0u8; 128];
+    header[0..4].copy_from_slice(&(offset as u32).to_be_bytes());
+    header[8..12].copy_from_slice(&0x0210_0000u32.to_be_bytes());
+    header[12..16].copy_from_slice(b"mntr");
+    header[16..20].copy_from_slice(b"RGB ");
+    header[20..24].copy_from_slice(b"XYZ ");
+    header[36..40].copy_from_slice(b"acsp");
+    header[68..72].copy_from_slice(&s15f16(0.9642));
+    header[72..76].copy_from_slice(&s15f16(1.0));
+    header[76..80].copy_from_slice(&s15f16(0.8249));
+    [header, table, data].concat()
+}
+
+/// Test: an ICC profile converts the image into sRGB before drawing.
+#[test]
+fn test_draw_image_with_color_profile_converts_to_srgb() {
+    use library::rendering::renderer::Renderer;
+    use library::runtime::Image;
+    use library::runtime::frame::Region;
+    use library::runtime::transform::Transform;
+
+    let image = Image::new(4, 4, [64u8, 64, 64, 255].repeat(16));
+    let region = Region {
+        x: 0.0,
+        y: 0.0,
+        width: 4.0,
+        height: 4.0,
+    };
+    let profile = linear_srgb_icc_profile();
+
+    let mut renderer = make_renderer();
+    renderer.clear().unwrap();
+    renderer
+        .draw_image_with_color_profile(&image, Some(&profile), &Transform::default())
+        .unwrap();
+    let actual = renderer.capture_region(&region).unwrap();
+
+    // リニアな 64/255 は sRGB では約 137/255 になる
+    assert!(
+        actual
+            .data
+            .chunks(4)
+            .all(|px| (px[0] as i32 - 137).abs() <= 3 && px[3] == 255),
+        "unexpected pixel {:?}",
+        &actual.data[..4]
+    );
+}
+
+/// Test: draws inside begin_layer/end_layer are faded as a single layer.
+#[test]
+fn test_begin_layer_fades_overlapping_draws_once() {
+    use library::rendering::renderer::Renderer;
+    use library::runtime::Image;
+    use library::runtime::frame::Region;
+    use library::runtime::transform::Transform;
+
+    let red = RenderOutput::Image(Image::new(4, 4, [255u8, 0, 0, 255].repeat(16)));
+    let region = Region {
+        x: 0.0,
+        y: 0.0,
+        width: 4.0,
+        height: 4.0,
+    };
+
+    let mut renderer = make_renderer();
+    renderer.clear().unwrap();
+    renderer.begin_layer(0.5, None);
+    renderer.draw_layer(&red, &Transform::default()).unwrap();
+    renderer.draw_layer(&red, &Transform::default()).unwrap();
+    renderer.end_layer();
+    let pixels = renderer.capture_region(&region).unwrap();
+
+    // 重なった部分も1枚分の不透明度（約50%）になり、75%にはならない
+    assert!(
+        pixels
+            .data
+            .chunks(4)
+            .all(|px| (px[3] as i32 - 128).abs() <= 1)
+    );
+}
+
+/// Test: a failing node is recorded in the cache manager for the node editor.
+#[test]
+fn test_failed_node_evaluation_records_node_error() {
+    use library::project::graph_node::GraphNode;
+    use library::project::property::PropertyMap;
+
+    let (project, comp_id, _) = setup_project();
+    let node = GraphNode::new("test.unknown", PropertyMap::new());
+    let node_id = node.id;
+    project.write().unwrap().add_node(Node::Graph(node));
+
+    let proj = project.read().unwrap();
+    let comp = proj.get_composition(comp_id).unwrap();
+    let plugin_manager = make_plugin_manager();
+    let engine = EvalEngine::with_default_evaluators();
+    let mut renderer = make_renderer();
+    let cache_manager = CacheManager::new();
+
+    let result = engine.evaluate_node_output(
+        &proj,
+        comp,
+        &plugin_manager,
+        &mut renderer,
+        &cache_manager,
+        plugin_manager.get_property_evaluators(),
+        node_id,
+        "image_out",
+        0,
+        1.0,
+    );
+
+    // 評価に失敗したノードにエラーメッセージが記録される
+    assert!(result.is_err());
+    let message = cache_manager.get_node_error(node_id).unwrap();
+    assert!(message.contains("test.unknown"));
+
+    // 別ノードの成功ではエラーは消えず、削除されたノードのエラーは破棄される
+    cache_manager.clear_node_error(uuid::Uuid::new_v4());
+    assert!(cache_manager.get_node_error(node_id).is_some());
+    cache_manager.prune_nodes(|id| id != node_id);
+    assert!(cache_manager.get_node_error(node_id).is_none());
+}
+
+/// Test: live pin values are recorded only while enabled, and never by export engines.
+#[test]
+fn test_pin_values_recorded_only_when_enabled() {
+    use library::error::LibraryError;
+    use library::pipeline::context::EvalContext;
+    use library::pipeline::evaluator::NodeEvaluator;
+    use library::pipeline::output::PinValue;
+    use library::project::graph_node::GraphNode;
+    use library::project::property::PropertyMap;
+
+    struct HalfEvaluator;
+
+    impl NodeEvaluator for HalfEvaluator {
+        fn handles(&self) -> &[&str] {
+            &["test.half"]
+        }
+
+        fn evaluate(
+            &self,
+            _node_id: uuid::Uuid,
+            _pin_name: &str,
+            _ctx: &mut EvalContext,
+        ) -> Result<PinValue, LibraryError> {
+            Ok(PinValue::Scalar(0.5))
+        }
+    }
+
+    let (project, comp_id, _) = setup_project();
+    let node = GraphNode::new("test.half", PropertyMap::new());
+    let node_id = node.id;
+    project.write().unwrap().add_node(Node::Graph(node));
+
+    let proj = project.read().unwrap();
+    let comp = proj.get_composition(comp_id).unwrap();
+    let plugin_manager = make_plugin_manager();
+    let mut renderer = make_renderer();
+    let cache_manager = CacheManager::new();
+    let evaluate = |engine: &EvalEngine, renderer: &mut SkiaRenderer| {
+        // スカラーは画像ではないので結果はエラーになるが、ピンの値は評価時に記録される
+        let _ = engine.evaluate_node_output(
+            &proj,
+            comp,
+            &plugin_manager,
+            renderer,
+            &cache_manager,
+            plugin_manager.get_property_evaluators(),
+            node_id,
+            "value",
+            0,
+            1.0,
+        );
+    };
+
+    let mut preview = EvalEngine::with_default_evaluators();
+    preview.register(Box::new(HalfEvaluator));
+    let mut export = EvalEngine::with_default_evaluators();
+    export.register(Box::new(HalfEvaluator));
+    export.set_export(true);
+
+    // ノードエディタが有効にするまでは記録しない
+    evaluate(&preview, &mut renderer);
+    assert_eq!(cache_manager.get_pin_value(node_id, "value"), None);
+
+    // エクスポートでは有効でも記録しない
+    cache_manager.set_pin_value_recording(true);
+    evaluate(&export, &mut renderer);
+    assert_eq!(cache_manager.get_pin_value(node_id, "value"), None);
+
+    evaluate(&preview, &mut renderer);
+    assert_eq!(
+        cache_manager.get_pin_value(node_id, "value").as_deref(),
+        Some("0.500")
+    );
+
+    // 削除されたノードの値は破棄される
+    cache_manager.prune_nodes(|id| id != node_id);
+    assert_eq!(cache_manager.get_pin_value(node_id, "value"), None);
+
+    // 無効にすると記録済みの値も消える
+    evaluate(&preview, &mut renderer);
+    cache_manager.set_pin_value_recording(false);
+    assert_eq!(cache_manager.get_pin_value(node_id, "value"), None);
+}
+
+/// Test: setting the cancel flag mid-evaluation stops before the next node runs.
+#[test]
+fn test_cancel_mid_evaluation_stops_upstream_nodes() {
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    use library::error::LibraryError;
+    use library::pipeline::context::EvalContext;
+    use library::pipeline::evaluator::NodeEvaluator;
+    use library::pipeline::output::PinValue;
+    use library::project::connection::{Connection, PinId};
+    use library::project::graph_node::GraphNode;
+    use library::project::property::PropertyMap;
+
+    /// 評価されるとキャンセルを要求し、その後で上流ノードを引くテスト用ノード
+    struct CancellingEvaluator {
+        cancel_flag: Arc<AtomicBool>,
+        runs: Arc<AtomicUsize>,
+    }
+
+    impl NodeEvaluator for CancellingEvaluator {
+        fn handles(&self) -> &[&str] {
+            &["test.cancel"]
+        }
+
+        fn evaluate(
+            &self,
+            node_id: uuid::Uuid,
+            _pin_name: &str,
+            ctx: &mut EvalContext,
+        ) -> Result<PinValue, LibraryError> {
+            self.runs.fetch_add(1, Ordering::SeqCst);
+            self.cancel_flag.store(true, Ordering::SeqCst);
+            ctx.pull_input_value(node_id, "image_in")
+        }
+    }
+
+    let (project, comp_id, _) = setup_project();
+    let mut ids = Vec::new();
+    {
+        let mut proj = project.write().unwrap();
+        for _ in 0..3 {
+            let node = GraphNode::new("test.cancel", PropertyMap::new());
+            ids.push(node.id);
+            proj.add_node(Node::Graph(node));
+        }
+        // ids[0] → ids[1] → ids[2]
+        for pair in ids.windows(2) {
+            proj.add_connection(Connection::new(
+                PinId::new(pair[0], "image_out"),
+                PinId::new(pair[1], "image_in"),
+            ));
+        }
+    }
+
+    let cancel_flag = Arc::new(AtomicBool::new(false));
+    let runs = Arc::new(AtomicUsize::new(0));
+    let mut engine = EvalEngine::with_default_evaluators();
+    engine.set_cancel_flag(Arc::clone(&cancel_flag));
+    engine.register(Box::new(CancellingEvaluator {
+        cancel_flag: Arc::clone(&cancel_flag),
+        runs: Arc::clone(&runs),
+    }));
+
+    let proj = project.read().unwrap();
+    let comp = proj.get_composition(comp_id).unwrap();
+    let plugin_manager = make_plugin_manager();
+    let mut renderer = make_renderer();
+    let cache_manager = CacheManager::new();
+
+    let result = engine.evaluate_node_output(
+        &proj,
+        comp,
+        &plugin_manager,
+        &mut renderer,
+        &cache_manager,
+        plugin_manager.get_property_evaluators(),
+        ids[2],
+        "image_out",
+        0,
+        1.0,
+    );
+
+    // 最初のノードでキャンセルされ、上流のノードは評価されない
+    assert!(matches!(result, Err(LibraryError::Cancelled)));
+    assert_eq!(runs.load(Ordering::SeqCst), 1);
+    // キャンセルはノードエラーとして記録されない
+    assert!(cache_manager.get_node_error(ids[2]).is_none());
+}
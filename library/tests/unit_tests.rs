@@ -47,6 +47,18 @@ mod clip_factory {
         assert!(clip.duration_frame.is_none());
     }
 
+    #[test]
+    fn create_gradient_clip_spans_canvas() {
+        let clip = LayerFactory::build_gradient_source(0, 60, 1280, 720, 30.0);
+        assert_eq!(clip.kind, SourceKind::Gradient);
+        assert_eq!(clip.kind.to_string(), "gradient");
+        assert_eq!(clip.properties.get_f64("width"), Some(1280.0));
+        assert_eq!(
+            clip.properties.get_string("gradient_type").as_deref(),
+            Some("Linear")
+        );
+    }
+
     #[test]
     fn create_shape_clip_has_shape_kind() {
         let clip = LayerFactory::build_shape_source(0, 60, 30.0);
@@ -165,6 +177,78 @@ mod clip_factory {
 mod track_clip {
     use super::*;
 
+    fn video_source(
+        in_frame: u64,
+        out_frame: u64,
+        source_begin_frame: i64,
+        fps: f64,
+    ) -> SourceData {
+        SourceData::new(
+            uuid::Uuid::new_v4(),
+            None,
+            SourceKind::Video,
+            in_frame,
+            out_frame,
+            source_begin_frame,
+            None,
+            fps,
+            PropertyMap::new(),
+        )
+    }
+
+    #[test]
+    fn compute_local_time_at_boundary_frames() {
+        let clip = video_source(30, 90, 0, 30.0);
+        // in_frameでソースの先頭、out_frameで尺の終わり
+        assert_eq!(clip.compute_local_time(30, 30.0), 0.0);
+        assert_eq!(clip.compute_local_time(90, 30.0), 2.0);
+        // in_frameより前は負の時刻になる
+        assert_eq!(clip.compute_local_time(0, 30.0), -1.0);
+    }
+
+    #[test]
+    fn compute_local_time_applies_source_offset() {
+        let clip = video_source(30, 90, 60, 30.0);
+        assert_eq!(clip.compute_local_time(30, 30.0), 2.0);
+        assert_eq!(clip.compute_local_time(45, 30.0), 2.5);
+    }
+
+    #[test]
+    fn compute_local_time_uses_source_fps_for_offset() {
+        // ソース側のfpsでオフセットを、コンポジション側のfpsで経過時間を換算する
+        let clip = video_source(0, 120, 48, 24.0);
+        assert_eq!(clip.compute_local_time(0, 60.0), 2.0);
+        assert_eq!(clip.compute_local_time(30, 60.0), 2.5);
+    }
+
+    #[test]
+    fn compute_source_frame_at_double_speed() {
+        // 2倍速ではコンポジション1フレームごとにソースが2フレーム進む
+        let mut clip = video_source(30, 90, 10, 30.0);
+        clip.speed = 2.0;
+        assert_eq!(clip.compute_source_frame(30, 30.0), 10);
+        assert_eq!(clip.compute_source_frame(45, 30.0), 40);
+        assert_eq!(clip.compute_local_time(60, 30.0), 10.0 / 30.0 + 2.0);
+    }
+
+    #[test]
+    fn compute_source_frame_at_half_speed() {
+        // 0.5倍速では2フレームごとにソースが1フレーム進む
+        let mut clip = video_source(0, 120, 0, 30.0);
+        clip.speed = 0.5;
+        assert_eq!(clip.compute_source_frame(20, 30.0), 10);
+        assert_eq!(clip.compute_source_frame(60, 30.0), 30);
+    }
+
+    #[test]
+    fn compute_source_frame_in_reverse() {
+        // 負の速度ではsource_begin_frameから逆方向に再生する
+        let mut clip = video_source(0, 30, 30, 30.0);
+        clip.speed = -1.0;
+        assert_eq!(clip.compute_source_frame(10, 30.0), 20);
+        assert_eq!(clip.compute_source_frame(30, 30.0), 0);
+    }
+
     #[test]
     fn get_definitions_for_text() {
         let defs = SourceData::get_definitions_for_kind(&SourceKind::Text);
@@ -228,6 +312,39 @@ mod track_clip {
         assert!(!keys.contains(&"position"));
     }
 
+    #[test]
+    fn get_definitions_for_gradient() {
+        let defs = SourceData::get_definitions_for_kind(&SourceKind::Gradient);
+        let keys: Vec<&str> = defs.iter().map(|d| d.name()).collect();
+        assert!(keys.contains(&"gradient_type"));
+        assert!(keys.contains(&"start_point"));
+        assert!(keys.contains(&"end_point"));
+        assert!(keys.contains(&"stops"));
+        assert!(!keys.contains(&"position"));
+    }
+
+    #[test]
+    fn gradient_stops_parse_sorted_and_clamped() {
+        use library::runtime::gradient::GradientStop;
+
+        let json = r#"[
+            {"offset": 1.5, "color": {"r": 255, "g": 0, "b": 0, "a": 255}},
+            {"offset": 0.25, "color": {"r": 0, "g": 0, "b": 255, "a": 255}}
+        ]"#;
+        let stops = GradientStop::parse_list(json).unwrap();
+        assert_eq!(stops.len(), 2);
+        assert_eq!(stops[0].offset, 0.25);
+        assert_eq!(stops[1].offset, 1.0);
+        assert_eq!(stops[1].color.r, 255);
+
+        assert!(GradientStop::parse_list("[]").is_err());
+        assert!(GradientStop::parse_list("not json").is_err());
+
+        let defaults = GradientStop::default_stops();
+        let roundtrip = GradientStop::parse_list(&GradientStop::to_json(&defaults)).unwrap();
+        assert_eq!(roundtrip, defaults);
+    }
+
     #[test]
     fn set_constant_property() {
         let defs = SourceData::get_definitions_for_kind(&SourceKind::Text);
@@ -268,6 +385,28 @@ mod track_clip {
     }
 }
 
+// ===== Waveform Peaks =====
+
+mod waveform_peaks {
+    use library::audio::waveform::compute_peaks;
+
+    #[test]
+    fn peaks_take_min_and_max_across_channels() {
+        // ステレオ4フレームを2つのピークにまとめる
+        let data = [0.1, -0.2, 0.5, 0.0, -0.7, 0.3, 0.2, 0.9];
+        let peaks = compute_peaks(&data, 2, 0, 4, 2);
+        assert_eq!(peaks, vec![(-0.2, 0.5), (-0.7, 0.9)]);
+    }
+
+    #[test]
+    fn peaks_outside_data_are_silent() {
+        // 先頭より前と末尾より後は無音として扱う
+        let data = [0.5, 0.5, -0.5, -0.5];
+        let peaks = compute_peaks(&data, 2, -2, 6, 3);
+        assert_eq!(peaks, vec![(0.0, 0.0), (-0.5, 0.5), (0.0, 0.0)]);
+    }
+}
+
 // ===== Builtin Plugins =====
 
 mod builtin_plugins {
@@ -326,6 +465,292 @@ mod builtin_plugins {
         assert_eq!(props.len(), 9);
     }
 
+    #[test]
+    fn chromatic_aberration_splits_channels() {
+        use library::builtin::effects::{ChromaticAberrationEffectPlugin, EffectPlugin};
+        use library::project::property::PropertyValue;
+        use library::rendering::renderer::RenderOutput;
+        use library::runtime::Image;
+        use std::collections::HashMap;
+
+        let p = ChromaticAberrationEffectPlugin::new();
+        assert_eq!(p.id(), "chromatic_aberration");
+        let keys: Vec<String> = p
+            .properties()
+            .iter()
+            .map(|d| d.name().to_string())
+            .collect();
+        assert_eq!(keys, ["offset_x", "offset_y", "use_radial"]);
+
+        // 中央の白い1列が、赤は右へ・青は左へずれる
+        let (w, h) = (16u32, 4u32);
+        let mut data = vec![0u8; (w * h * 4) as usize];
+        for y in 0..h {
+            let i = ((y * w + 8) * 4) as usize;
+            data[i..i + 4].copy_from_slice(&[255, 255, 255, 255]);
+        }
+        let input = RenderOutput::Image(Image::new(w, h, data));
+        let mut params = HashMap::new();
+        params.insert("offset_x".to_string(), PropertyValue::from(2.0));
+        params.insert("offset_y".to_string(), PropertyValue::from(0.0));
+
+        let RenderOutput::Image(out) = p.apply(&input, &params, None).unwrap() else {
+            panic!("CPU input should produce an image");
+        };
+        let px = |x: u32| {
+            let i = ((w + x) * 4) as usize;
+            [out.data[i], out.data[i + 1], out.data[i + 2]]
+        };
+        assert_eq!(px(10), [255, 0, 0]);
+        assert_eq!(px(8), [0, 255, 0]);
+        assert_eq!(px(6), [0, 0, 255]);
+    }
+
+    #[test]
+    fn vignette_darkens_edges_only() {
+        use library::builtin::effects::{EffectPlugin, VignetteEffectPlugin};
+        use library::project::property::PropertyValue;
+        use library::rendering::renderer::RenderOutput;
+        use library::runtime::Image;
+        use std::collections::HashMap;
+
+        let p = VignetteEffectPlugin::new();
+        assert_eq!(p.id(), "vignette");
+        let keys: Vec<String> = p
+            .properties()
+            .iter()
+            .map(|d| d.name().to_string())
+            .collect();
+        assert_eq!(keys, ["strength", "radius", "feather", "color", "shape"]);
+
+        let (w, h) = (32u32, 32u32);
+        let input = RenderOutput::Image(Image::new(w, h, vec![255u8; (w * h * 4) as usize]));
+        for shape in ["ellipse", "rectangle"] {
+            let mut params = HashMap::new();
+            params.insert("strength".to_string(), PropertyValue::from(1.0));
+            params.insert(
+                "shape".to_string(),
+                PropertyValue::String(shape.to_string()),
+            );
+
+            let RenderOutput::Image(out) = p.apply(&input, &params, None).unwrap() else {
+                panic!("CPU input should produce an image");
+            };
+            let px = |x: u32, y: u32| {
+                let i = ((y * w + x) * 4) as usize;
+                [out.data[i], out.data[i + 3]]
+            };
+            // 中央は元のまま、角は暗くなり不透明度は保たれる
+            assert_eq!(px(16, 16), [255, 255], "{shape}");
+            assert!(px(0, 0)[0] < 64, "{shape}");
+            assert_eq!(px(0, 0)[1], 255, "{shape}");
+        }
+    }
+
+    #[test]
+    fn cube_lut_parse_and_lookup() {
+        use library::builtin::effects::lut::{CubeLut, LutKind};
+
+        // 反転LUT（赤が最も速く変化する順序）
+        let mut text = String::from("TITLE \"invert\"\n# comment\nLUT_3D_SIZE 2\n");
+        for b in [1.0, 0.0] {
+            for g in [1.0, 0.0] {
+                for r in [1.0, 0.0] {
+                    text.push_str(&format!("{r} {g} {b}\n"));
+                }
+            }
+        }
+        let lut = CubeLut::parse(&text).unwrap();
+        assert_eq!(lut.kind, LutKind::ThreeD);
+        assert_eq!(lut.size, 2);
+        let out = lut.lookup([0.25, 0.5, 1.0]);
+        assert!((out[0] - 0.75).abs() < 1e-6);
+        assert!((out[1] - 0.5).abs() < 1e-6);
+        assert!(out[2].abs() < 1e-6);
+
+        let one_d = CubeLut::parse("LUT_1D_SIZE 3\n0 0 0\n0.25 0.5 0.5\n1 1 1\n").unwrap();
+        assert_eq!(one_d.kind, LutKind::OneD);
+        let out = one_d.lookup([0.5, 0.25, 0.75]);
+        assert_eq!(out, [0.25, 0.25, 0.75]);
+
+        assert!(CubeLut::parse("LUT_3D_SIZE 2\n0 0 0\n").is_err());
+        assert!(CubeLut::parse("LUT_3D_SIZE 65\n").is_err());
+        assert!(CubeLut::parse("0 0 0\n").is_err());
+    }
+
+    #[test]
+    fn lut_effect_applies_cube_file() {
+        use library::builtin::effects::{EffectPlugin, LutEffectPlugin};
+        use library::project::property::PropertyValue;
+        use library::rendering::renderer::RenderOutput;
+        use library::runtime::Image;
+        use std::collections::HashMap;
+
+        let path = std::env::temp_dir().join(format!("lut_{}.cube", uuid::Uuid::new_v4()));
+        std::fs::write(&path, "LUT_1D_SIZE 2\n1 1 1\n0 0 0\n").unwrap();
+
+        let p = LutEffectPlugin::new();
+        assert_eq!(p.id(), "lut_color_grade");
+        let input = RenderOutput::Image(Image::new(1, 1, vec![255, 0, 51, 255]));
+        let mut params = HashMap::new();
+        params.insert(
+            "file_path".to_string(),
+            PropertyValue::String(path.to_string_lossy().into_owned()),
+        );
+        let result = p.apply(&input, &params, None);
+
+        let RenderOutput::Image(out) = result.unwrap() else {
+            panic!("CPU input should produce an image");
+        };
+        assert_eq!(out.data, vec![0, 255, 204, 255]);
+
+        // ディスク上で書き換えられたLUTは読み直される
+        std::fs::write(&path, "LUT_1D_SIZE 2\n0 0 0\n1 1 1\n").unwrap();
+        std::fs::File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(std::time::SystemTime::now() + std::time::Duration::from_secs(60))
+            .unwrap();
+        let result = p.apply(&input, &params, None);
+        std::fs::remove_file(&path).unwrap();
+        let RenderOutput::Image(out) = result.unwrap() else {
+            panic!("CPU input should produce an image");
+        };
+        assert_eq!(out.data, vec![255, 0, 51, 255]);
+    }
+
+    fn apply_glow(pixels: Vec<u8>, w: u32, h: u32) -> Vec<u8> {
+        use library::builtin::effects::{EffectPlugin, GlowEffectPlugin};
+        use library::project::property::PropertyValue;
+        use library::rendering::renderer::RenderOutput;
+        use library::runtime::Image;
+        use std::collections::HashMap;
+
+        let mut params = HashMap::new();
+        params.insert("threshold".to_string(), PropertyValue::from(0.5));
+        params.insert("intensity".to_string(), PropertyValue::from(1.0));
+        params.insert("radius".to_string(), PropertyValue::from(6.0));
+        let input = RenderOutput::Image(Image::new(w, h, pixels));
+        match GlowEffectPlugin::new()
+            .apply(&input, &params, None)
+            .unwrap()
+        {
+            RenderOutput::Image(out) => out.data,
+            _ => panic!("CPU input should produce an image"),
+        }
+    }
+
+    #[test]
+    fn glow_below_threshold_leaves_image_unchanged() {
+        // 閾値未満の単色画像にはグローが付かない
+        let pixels: Vec<u8> = [100u8, 80, 60, 255].repeat(16 * 16);
+        assert_eq!(apply_glow(pixels.clone(), 16, 16), pixels);
+    }
+
+    #[test]
+    fn glow_spreads_bright_pixels() {
+        // 黒背景の中央にある白い点の周囲が明るくなる
+        let (w, h) = (16u32, 16u32);
+        let mut pixels: Vec<u8> = [0u8, 0, 0, 255].repeat((w * h) as usize);
+        for (x, y) in [(7, 7), (8, 7), (7, 8), (8, 8)] {
+            let i = ((y * w + x) * 4) as usize;
+            pixels[i..i + 3].copy_from_slice(&[255, 255, 255]);
+        }
+        let out = apply_glow(pixels, w, h);
+        let i = ((7 * w + 10) * 4) as usize;
+        assert!(out[i] > 0, "neighbouring pixel should receive glow");
+        assert_eq!(out[i + 3], 255);
+    }
+
+    #[test]
+    fn time_echo_blends_previous_frames() {
+        use library::builtin::effects::{EffectPlugin, TimeEchoEffectPlugin};
+        use library::project::property::PropertyValue;
+        use library::rendering::renderer::RenderOutput;
+        use library::runtime::Image;
+        use std::collections::HashMap;
+
+        let p = TimeEchoEffectPlugin::new();
+        let node_id = uuid::Uuid::new_v4();
+        let apply = |value: u8, frame: i64, context: i64| {
+            let mut params = HashMap::new();
+            params.insert("decay".to_string(), PropertyValue::from(0.5));
+            params.insert("num_frames".to_string(), PropertyValue::Integer(2));
+            params.insert("u_frame".to_string(), PropertyValue::Integer(frame));
+            params.insert(
+                "u_entity_id".to_string(),
+                PropertyValue::String(node_id.to_string()),
+            );
+            params.insert(
+                "u_render_context".to_string(),
+                PropertyValue::Integer(context),
+            );
+            let input = RenderOutput::Image(Image::new(1, 1, vec![value; 4]));
+            match p.apply(&input, &params, None).unwrap() {
+                RenderOutput::Image(out) => out.data[0],
+                _ => panic!("CPU input should produce an image"),
+            }
+        };
+
+        assert_eq!(apply(0, 0, 1), 0);
+        // (255 * 1 + 0 * 0.5) / 1.5
+        assert_eq!(apply(255, 1, 1), 170);
+        // 別のレンダーコンテキストの履歴は混ざらない
+        assert_eq!(apply(255, 2, 2), 255);
+        // 連続しないフレームへのシークで履歴はリセットされる
+        assert_eq!(apply(0, 5, 1), 0);
+        assert_eq!(apply(255, 0, 1), 255);
+
+        // 削除されたノードの履歴は破棄される
+        p.prune_instances(&|id| id != node_id);
+        assert_eq!(apply(0, 1, 1), 0);
+    }
+
+    #[test]
+    fn color_curve_identity_and_monotone() {
+        use library::runtime::curve::ColorCurve;
+
+        // 空文字列と既定値は恒等カーブ
+        let identity = ColorCurve::parse("").unwrap();
+        assert!(identity.is_identity());
+        assert_eq!(identity.lookup_table()[128], 128);
+
+        // 単調な制御点からはオーバーシュートしない
+        let curve = ColorCurve::parse("[[0,0],[0.25,0.6],[0.5,0.65],[1,1]]").unwrap();
+        let table = curve.lookup_table();
+        assert!(table.windows(2).all(|w| w[0] <= w[1]));
+        assert!((curve.evaluate(0.25) - 0.6).abs() < 1e-9);
+
+        // 並び替えと範囲外の値のクランプ
+        let curve = ColorCurve::new(vec![(1.5, 1.0), (0.0, -0.5)]);
+        assert_eq!(curve.points(), &[(0.0, 0.0), (1.0, 1.0)]);
+    }
+
+    #[test]
+    fn color_curves_compose_master_with_channel() {
+        use library::builtin::effects::{ColorCurvesEffectPlugin, EffectPlugin};
+        use library::project::property::PropertyValue;
+        use library::runtime::curve::ColorCurve;
+        use std::collections::HashMap;
+
+        let plugin = ColorCurvesEffectPlugin::new();
+        assert_eq!(plugin.id(), "color_curves");
+        assert_eq!(plugin.properties().len(), 5);
+
+        // master を反転、red も反転 → red は元に戻り、green は反転のまま
+        let invert = ColorCurve::new(vec![(0.0, 1.0), (1.0, 0.0)]).to_json();
+        let mut params = HashMap::new();
+        params.insert("master".to_string(), PropertyValue::String(invert.clone()));
+        params.insert("red".to_string(), PropertyValue::String(invert));
+
+        let [a, r, g, b] = ColorCurvesEffectPlugin::build_tables(&params);
+        assert_eq!(a[200], 200);
+        assert_eq!(r[200], 200);
+        assert_eq!(g[200], 55);
+        assert_eq!(b[0], 255);
+    }
+
     #[test]
     fn transform_effector_metadata() {
         let p = TransformEffectorPlugin;
@@ -419,6 +844,208 @@ mod builtin_plugins {
         assert!(keys.contains(&"radius"));
         assert_eq!(props.len(), 5);
     }
+
+    #[test]
+    fn export_settings_audio_source_roundtrip() {
+        use library::plugin::{AudioSource, ExportSettings};
+
+        let mut settings = ExportSettings::for_dimensions(1920, 1080, 30.0);
+        assert!(settings.audio_source().is_none());
+
+        // 音声のパスとフォーマットは audio_source パラメータ1つにまとめて保存される
+        let source = AudioSource {
+            path: "out_audio.raw".to_string(),
+            sample_rate: 44100,
+            channels: 2,
+        };
+        settings.set_audio_source(&source);
+        assert_eq!(settings.audio_source(), Some(source));
+        assert_eq!(settings.parameters.len(), 1);
+    }
+
+    #[test]
+    fn export_settings_audio_bitrate_parses_dropdown_values() {
+        use library::plugin::ExportSettings;
+
+        let mut settings = ExportSettings::for_dimensions(1920, 1080, 30.0);
+        assert_eq!(settings.audio_bitrate(), None);
+
+        settings
+            .parameters
+            .insert("audio_bitrate".to_string(), "256".into());
+        assert_eq!(settings.audio_bitrate(), Some(256));
+
+        // "Default" はエンコーダ既定のビットレートを意味する
+        settings
+            .parameters
+            .insert("audio_bitrate".to_string(), "Default".into());
+        assert_eq!(settings.audio_bitrate(), None);
+    }
+
+    #[test]
+    fn hw_accel_backend_maps_software_codecs() {
+        use library::plugin::{ExportSettings, HwAccelBackend};
+
+        // 既定ではソフトウェアエンコード
+        assert_eq!(
+            ExportSettings::for_dimensions(1920, 1080, 30.0).hardware_accel,
+            None
+        );
+
+        assert_eq!(
+            HwAccelBackend::Nvenc.encoder_for("libx264").as_deref(),
+            Some("h264_nvenc")
+        );
+        assert_eq!(
+            HwAccelBackend::VideoToolbox
+                .encoder_for("libx265")
+                .as_deref(),
+            Some("hevc_videotoolbox")
+        );
+        assert_eq!(
+            HwAccelBackend::Qsv.encoder_for("h264").as_deref(),
+            Some("h264_qsv")
+        );
+        // 対応するハードウェアエンコーダがないコーデックはソフトウェアのまま
+        assert_eq!(HwAccelBackend::Vaapi.encoder_for("prores_ks"), None);
+    }
+
+    #[test]
+    fn hw_accel_backend_maps_quality_and_preset() {
+        use library::plugin::HwAccelBackend;
+
+        // ハードウェアエンコーダには -crf を渡さない
+        for backend in HwAccelBackend::ALL {
+            assert!(!backend.quality_args(23.0).contains(&"-crf".to_string()));
+        }
+        assert_eq!(
+            HwAccelBackend::Nvenc.quality_args(23.0),
+            ["-rc", "vbr", "-cq", "23"]
+        );
+        assert_eq!(
+            HwAccelBackend::VideoToolbox.quality_args(0.0),
+            ["-q:v", "100"]
+        );
+
+        // x264 のプリセット名はエンコーダごとの名前に変換される
+        assert_eq!(
+            HwAccelBackend::Nvenc.preset_for("veryslow").as_deref(),
+            Some("p7")
+        );
+        assert_eq!(
+            HwAccelBackend::Qsv.preset_for("ultrafast").as_deref(),
+            Some("veryfast")
+        );
+        assert_eq!(HwAccelBackend::Vaapi.preset_for("medium"), None);
+    }
+
+    #[test]
+    fn webp_export_writes_animated_file() {
+        use library::plugin::{ExportFormat, ExportPlugin, ExportSettings, WebpExportPlugin};
+        use library::runtime::Image;
+
+        let mut settings = ExportSettings::for_dimensions(4, 4, 10.0);
+        settings.container = "webp".to_string();
+        assert_eq!(settings.export_format(), ExportFormat::WebP);
+
+        let plugin = WebpExportPlugin::new();
+        assert_eq!(plugin.id(), "webp_export");
+
+        // フレームは finish_export までメモリに溜められ、1ファイルにまとめて書き出される
+        let path = std::env::temp_dir().join(format!("webp_export_{}.webp", std::process::id()));
+        let path = path.to_str().unwrap();
+        for value in [0u8, 128, 255] {
+            let image = Image::new(4, 4, [value, value, value, 255].repeat(16));
+            plugin.export_image(path, &image, &settings).unwrap();
+        }
+        assert!(!std::path::Path::new(path).exists());
+        plugin.finish_export(path).unwrap();
+
+        let data = std::fs::read(path).unwrap();
+        let _ = std::fs::remove_file(path);
+        assert_eq!(&data[0..4], b"RIFF");
+        assert_eq!(&data[8..12], b"WEBP");
+    }
+
+    #[test]
+    fn gif_export_limits_palette_and_drops_frames_for_fps_override() {
+        use library::plugin::{ExportFormat, ExportPlugin, ExportSettings, GifExportPlugin};
+        use library::runtime::Image;
+
+        let mut settings = ExportSettings::for_dimensions(8, 8, 30.0);
+        settings.container = "gif".to_string();
+        assert_eq!(settings.export_format(), ExportFormat::Gif);
+        settings.parameters.insert("fps".to_string(), 15.into());
+        settings
+            .parameters
+            .insert("palette_size".to_string(), 4.into());
+        settings
+            .parameters
+            .insert("dither".to_string(), "Floyd-Steinberg".into());
+
+        let plugin = GifExportPlugin::new();
+        assert_eq!(plugin.id(), "gif_export");
+
+        // グラデーション画像を4フレーム書き出す（15fps 指定なので2フレームに間引かれる）
+        let path = std::env::temp_dir().join(format!("gif_export_{}.gif", std::process::id()));
+        let path = path.to_str().unwrap();
+        let gradient: Vec<u8> = (0..64u32)
+            .flat_map(|i| [(i * 4) as u8, 255 - (i * 4) as u8, 128, 255])
+            .collect();
+        for _ in 0..4 {
+            let image = Image::new(8, 8, gradient.clone());
+            plugin.export_image(path, &image, &settings).unwrap();
+        }
+        plugin.finish_export(path).unwrap();
+
+        let file = std::fs::File::open(path).unwrap();
+        let mut decoder = gif::DecodeOptions::new().read_info(file).unwrap();
+        let global_palette_len = decoder.global_palette().map(|p| p.len() / 3);
+        let mut frames = 0;
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            // 1/15 秒 ≒ 6.67cs を 7, 6 と丸めて累積誤差を出さない
+            assert_eq!(frame.delay, if frames % 2 == 0 { 7 } else { 6 });
+            assert!(frame.palette.is_none());
+            frames += 1;
+        }
+        let _ = std::fs::remove_file(path);
+
+        assert_eq!(frames, 2);
+        assert!(global_palette_len.unwrap() <= 4);
+    }
+}
+
+mod plugin_listing {
+    use library::plugin::{PluginCategory, PluginManager, PluginSource, PluginStatus};
+
+    #[test]
+    fn list_plugins_reports_builtins_and_load_failures() {
+        let pm = PluginManager::default();
+        let plugins = pm.list_plugins();
+        let blur = plugins
+            .iter()
+            .find(|p| p.id == "blur")
+            .expect("blur effect should be listed");
+        assert_eq!(blur.plugin_type, Some(PluginCategory::Effect));
+        assert_eq!(blur.loaded_from, PluginSource::Builtin);
+        assert_eq!(blur.status, PluginStatus::Active);
+
+        // 設定が壊れたSkSLプラグインは失敗として一覧に残る
+        let dir = std::env::temp_dir().join(format!("sksl_listing_{}", uuid::Uuid::new_v4()));
+        let plugin_dir = dir.join("broken");
+        std::fs::create_dir_all(&plugin_dir).unwrap();
+        std::fs::write(plugin_dir.join("config.toml"), "not = [valid").unwrap();
+        std::fs::write(plugin_dir.join("shader.sksl"), "").unwrap();
+        pm.load_sksl_plugins_from_directory(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let plugins = pm.list_plugins();
+        let failed = plugins.last().unwrap();
+        assert_eq!(failed.name, "broken");
+        assert_eq!(failed.plugin_type, None);
+        assert_eq!(failed.loaded_from, PluginSource::Sksl(plugin_dir));
+        assert!(matches!(failed.status, PluginStatus::LoadFailed(_)));
+    }
 }
 
 // ===== Node Definitions (per-category) =====
@@ -730,13 +1357,7 @@ mod draw_type_tests {
 
     #[test]
     fn blend_mode_serialization_roundtrip() {
-        for mode in [
-            BlendMode::Normal,
-            BlendMode::Multiply,
-            BlendMode::Screen,
-            BlendMode::Overlay,
-            BlendMode::Add,
-        ] {
+        for mode in BlendMode::ALL {
             let json = serde_json::to_string(&mode).unwrap();
             let m2: BlendMode = serde_json::from_str(&json).unwrap();
             assert_eq!(mode, m2);
@@ -877,6 +1498,32 @@ mod property_value_tests {
         let pv2: PropertyValue = serde_json::from_str(&json).unwrap();
         assert_eq!(pv, pv2);
     }
+
+    #[test]
+    fn serialization_roundtrip_vec3_vec4() {
+        use library::project::property::{Vec3, Vec4};
+        // z や w が Vec2 として読まれて失われないこと
+        let v3 = Vec3 {
+            x: OrderedFloat(1.0),
+            y: OrderedFloat(2.0),
+            z: OrderedFloat(3.0),
+        };
+        let pv = PropertyValue::Vec3(v3);
+        let pv2: PropertyValue =
+            serde_json::from_str(&serde_json::to_string(&pv).unwrap()).unwrap();
+        assert_eq!(pv2.get_as::<Vec3>(), Some(v3));
+
+        let v4 = Vec4 {
+            x: OrderedFloat(1.0),
+            y: OrderedFloat(2.0),
+            z: OrderedFloat(3.0),
+            w: OrderedFloat(4.0),
+        };
+        let pv = PropertyValue::Vec4(v4);
+        let pv2: PropertyValue =
+            serde_json::from_str(&serde_json::to_string(&pv).unwrap()).unwrap();
+        assert_eq!(pv2.get_as::<Vec4>(), Some(v4));
+    }
 }
 
 // ===== ShapeData =====
@@ -1438,6 +2085,85 @@ mod project_model {
         assert_eq!(clips.len(), 2);
     }
 
+    #[test]
+    fn export_otio_json_lays_out_clips_and_gaps() {
+        let mut project = Project::new("test");
+        let mut comp = Composition::new("main", 1920, 1080, 30.0, 10.0);
+        let comp_id = comp.id;
+        let mut track = TrackData::new("V1");
+        comp.child_ids.push(track.id);
+
+        let mut props = PropertyMap::new();
+        props.set(
+            "file_path".to_string(),
+            library::project::property::Property::constant(
+                library::project::property::PropertyValue::String("/media/shot.mp4".to_string()),
+            ),
+        );
+        let video = SourceData::new(
+            Uuid::new_v4(),
+            None,
+            SourceKind::Video,
+            30,
+            90,
+            12,
+            Some(300),
+            30.0,
+            props,
+        );
+        let mut overlapping = SourceData::new(
+            Uuid::new_v4(),
+            None,
+            SourceKind::Text,
+            60,
+            120,
+            0,
+            None,
+            30.0,
+            PropertyMap::new(),
+        );
+        overlapping.speed = 2.0;
+        track.add_child(video.id);
+        track.add_child(overlapping.id);
+        project.add_composition(comp);
+        project.add_node(Node::Track(track));
+        project.add_node(Node::Source(video));
+        project.add_node(Node::Source(overlapping));
+
+        let json = project.export_otio_json(comp_id).unwrap();
+        let timeline: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(timeline["OTIO_SCHEMA"], "Timeline.1");
+
+        // 重なるクリップは別トラックに分けられる
+        let tracks = timeline["tracks"]["children"].as_array().unwrap();
+        assert_eq!(tracks.len(), 2);
+
+        // 先頭の空きはGapで埋まり、クリップはメディアのフレームで範囲を持つ
+        let children = tracks[0]["children"].as_array().unwrap();
+        assert_eq!(children[0]["OTIO_SCHEMA"], "Gap.1");
+        assert_eq!(children[0]["source_range"]["duration"]["value"], 30.0);
+        let clip = &children[1];
+        assert_eq!(clip["name"], "shot.mp4");
+        assert_eq!(clip["source_range"]["start_time"]["value"], 12.0);
+        assert_eq!(clip["source_range"]["duration"]["value"], 60.0);
+        assert_eq!(
+            clip["media_reference"]["target_url"],
+            "file:///media/shot.mp4"
+        );
+        assert_eq!(clip["effects"].as_array().unwrap().len(), 0);
+        assert_eq!(
+            tracks[1]["children"][1]["media_reference"]["OTIO_SCHEMA"],
+            "MissingReference.1"
+        );
+
+        // 速度変更はLinearTimeWarpとして書き出される
+        let warp = &tracks[1]["children"][1]["effects"][0];
+        assert_eq!(warp["OTIO_SCHEMA"], "LinearTimeWarp.1");
+        assert_eq!(warp["time_scalar"], 2.0);
+
+        assert!(project.export_otio_json(Uuid::new_v4()).is_err());
+    }
+
     #[test]
     fn all_clips_iterator() {
         let mut project = Project::new("test");
@@ -1537,6 +2263,28 @@ mod project_model {
         assert!(loaded.get_track(track_id).is_some());
     }
 
+    #[test]
+    fn project_model_json_str_roundtrip() {
+        use library::ProjectModel;
+
+        let mut project = Project::new("in-memory");
+        let comp = Composition::new("main", 1280, 720, 24.0, 5.0);
+        let comp_id = comp.id;
+        project.add_composition(comp);
+        let json = project.save().unwrap();
+
+        // ファイルを介さずにJSON文字列から読み込み・書き出しできる
+        let model = ProjectModel::from_json_str(&json, 0).unwrap();
+        assert_eq!(model.composition().id, comp_id);
+        let reloaded = ProjectModel::from_json_str(&model.to_json_str().unwrap(), 0).unwrap();
+        assert_eq!(reloaded.project().name, "in-memory");
+        assert_eq!(reloaded.composition().width, 1280);
+
+        // 範囲外のコンポジション番号と不正なJSONはエラー
+        assert!(ProjectModel::from_json_str(&json, 1).is_err());
+        assert!(ProjectModel::from_json_str("{", 0).is_err());
+    }
+
     #[test]
     fn node_id_accessor() {
         let clip = Node::Source(SourceData::new(
@@ -1662,6 +2410,239 @@ mod property_map_tests {
         let keys: Vec<&String> = map.iter().map(|(k, _)| k).collect();
         assert_eq!(keys.len(), 3);
     }
+
+    #[test]
+    fn import_from_json_sets_constants() {
+        use library::project::property::Vec2;
+        use library::runtime::color::Color;
+
+        let mut map = PropertyMap::new();
+        map.import_from_json(
+            r#"{"opacity": 50, "text": "Hi", "position": [10, 20], "color": [255, 0, 128, 255]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(map.get_f64("opacity"), Some(50.0));
+        assert_eq!(map.get_string("text"), Some("Hi".to_string()));
+        assert_eq!(
+            map.get_constant_value("position"),
+            Some(&PropertyValue::Vec2(Vec2 {
+                x: OrderedFloat(10.0),
+                y: OrderedFloat(20.0),
+            }))
+        );
+        assert_eq!(
+            map.get_constant_value("color"),
+            Some(&PropertyValue::Color(Color {
+                r: 255,
+                g: 0,
+                b: 128,
+                a: 255,
+            }))
+        );
+    }
+
+    #[test]
+    fn import_from_json_rejects_unsupported_values() {
+        let mut map = PropertyMap::new();
+        map.set(
+            "x".to_string(),
+            Property::constant(PropertyValue::from(1.0)),
+        );
+        assert!(
+            map.import_from_json(r#"{"x": 2, "bad": [1, 2, 3]}"#)
+                .is_err()
+        );
+        assert!(map.import_from_json("[1, 2]").is_err());
+        // 失敗時は既存の値が変更されない
+        assert_eq!(map.get_f64("x"), Some(1.0));
+    }
+
+    #[test]
+    fn diff_reports_added_removed_and_modified_keys() {
+        use library::project::property::{DiffType, PropertyDiff};
+
+        let mut before = PropertyMap::new();
+        before.set(
+            "opacity".into(),
+            Property::constant(PropertyValue::from(1.0)),
+        );
+        before.set(
+            "rotation".into(),
+            Property::constant(PropertyValue::from(0.0)),
+        );
+        before.set("scale".into(), Property::constant(PropertyValue::from(1.0)));
+
+        let mut after = PropertyMap::new();
+        after.set(
+            "opacity".into(),
+            Property::constant(PropertyValue::from(0.5)),
+        );
+        after.set("scale".into(), Property::constant(PropertyValue::from(1.0)));
+        after.set("blur".into(), Property::constant(PropertyValue::from(2.0)));
+
+        // キー順にソートされた差分が返る
+        assert_eq!(
+            before.diff(&after),
+            vec![
+                PropertyDiff {
+                    key: "blur".into(),
+                    change_type: DiffType::Added,
+                },
+                PropertyDiff {
+                    key: "opacity".into(),
+                    change_type: DiffType::Modified,
+                },
+                PropertyDiff {
+                    key: "rotation".into(),
+                    change_type: DiffType::Removed,
+                },
+            ]
+        );
+        assert!(before.diff(&before.clone()).is_empty());
+    }
+
+    #[test]
+    fn compare_classifies_keys_of_two_maps() {
+        use library::project::property::PropertyMapDiff;
+
+        let mut a = PropertyMap::new();
+        a.set(
+            "opacity".into(),
+            Property::constant(PropertyValue::from(1.0)),
+        );
+        a.set("scale".into(), Property::constant(PropertyValue::from(1.0)));
+        a.set("text".into(), Property::constant(PropertyValue::from(0.0)));
+        let mut b = PropertyMap::new();
+        b.set(
+            "opacity".into(),
+            Property::constant(PropertyValue::from(0.5)),
+        );
+        b.set("scale".into(), Property::constant(PropertyValue::from(1.0)));
+        b.set("blur".into(), Property::constant(PropertyValue::from(2.0)));
+
+        assert_eq!(
+            a.compare(&b),
+            PropertyMapDiff {
+                only_in_self: vec!["text".into()],
+                only_in_other: vec!["blur".into()],
+                shared_equal: vec!["scale".into()],
+                shared_differing: vec!["opacity".into()],
+            }
+        );
+    }
+
+    #[test]
+    fn merge_applies_strategy_to_shared_keys() {
+        use library::animation::EasingFunction;
+        use library::project::property::{Keyframe, MergeStrategy};
+
+        let keyframed = Property::keyframe(vec![Keyframe {
+            time: OrderedFloat(0.0),
+            value: PropertyValue::from(0.0),
+            ease_in: None,
+            ease_out: Some(EasingFunction::Linear),
+        }]);
+        let mut base = PropertyMap::new();
+        base.set(
+            "opacity".into(),
+            Property::constant(PropertyValue::from(1.0)),
+        );
+        base.set("scale".into(), keyframed.clone());
+        let mut other = PropertyMap::new();
+        other.set("opacity".into(), keyframed.clone());
+        other.set("scale".into(), Property::constant(PropertyValue::from(2.0)));
+        other.set("blur".into(), Property::constant(PropertyValue::from(3.0)));
+
+        let mut keep_self = base.clone();
+        keep_self.merge(&other, MergeStrategy::KeepSelf);
+        assert_eq!(keep_self.get_f64("opacity"), Some(1.0));
+        // 片方にしかないキーはどの戦略でも追加される
+        assert_eq!(keep_self.get_f64("blur"), Some(3.0));
+
+        let mut keep_other = base.clone();
+        keep_other.merge(&other, MergeStrategy::KeepOther);
+        assert_eq!(keep_other.get_f64("scale"), Some(2.0));
+        assert_eq!(keep_other.get("opacity"), Some(&keyframed));
+
+        // キーフレームを持つ側が優先される
+        let mut take_keyframed = base.clone();
+        take_keyframed.merge(&other, MergeStrategy::TakeKeyframed);
+        assert_eq!(take_keyframed.get("opacity"), Some(&keyframed));
+        assert_eq!(take_keyframed.get("scale"), Some(&keyframed));
+    }
+
+    #[test]
+    fn scale_and_offset_keyframe_times() {
+        use library::animation::EasingFunction;
+        use library::project::property::Keyframe;
+        let kf = |time: f64, value: f64| Keyframe {
+            time: OrderedFloat(time),
+            value: PropertyValue::from(value),
+            ease_in: None,
+            ease_out: Some(EasingFunction::Linear),
+        };
+        let mut map = PropertyMap::new();
+        map.set(
+            "opacity".into(),
+            Property::keyframe(vec![kf(1.0, 0.0), kf(2.0, 1.0)]),
+        );
+        map.set("scale".into(), Property::constant(PropertyValue::from(1.0)));
+
+        // 全キーフレームの時刻が倍率分だけ伸びる
+        map.scale_keyframe_times(2.0);
+        let times: Vec<f64> = map
+            .get("opacity")
+            .unwrap()
+            .keyframes()
+            .iter()
+            .map(|k| k.time.into_inner())
+            .collect();
+        assert_eq!(times, vec![2.0, 4.0]);
+
+        // オフセットで平行移動し、定数プロパティは変わらない
+        map.offset_keyframe_times(-0.5);
+        let times: Vec<f64> = map
+            .get("opacity")
+            .unwrap()
+            .keyframes()
+            .iter()
+            .map(|k| k.time.into_inner())
+            .collect();
+        assert_eq!(times, vec![1.5, 3.5]);
+        assert_eq!(map.get_f64("scale"), Some(1.0));
+    }
+
+    #[test]
+    fn update_keyframes_at_indices_moves_together_and_remaps() {
+        use library::animation::EasingFunction;
+        use library::project::property::Keyframe;
+        let kf = |time: f64, value: f64| Keyframe {
+            time: OrderedFloat(time),
+            value: PropertyValue::from(value),
+            ease_in: None,
+            ease_out: Some(EasingFunction::Linear),
+        };
+        let mut p = Property::keyframe(vec![kf(0.0, 0.0), kf(1.0, 1.0), kf(2.0, 2.0)]);
+
+        // 0番と1番を同時に 2.5 秒ずらすと、2番を追い越して並び替わる
+        let remap = p
+            .update_keyframes_at_indices(&[(0, Some(2.5), None), (1, Some(3.5), None)])
+            .unwrap();
+        assert_eq!(remap, vec![1, 2, 0]);
+        let kfs = p.keyframes();
+        let times: Vec<f64> = kfs.iter().map(|k| k.time.into_inner()).collect();
+        assert_eq!(times, vec![2.0, 2.5, 3.5]);
+        // 値は移動元のキーフレームについてくる
+        assert_eq!(kfs[remap[1]].value, PropertyValue::from(1.0));
+
+        // 範囲外のインデックスは何も変えない
+        assert!(
+            p.update_keyframes_at_indices(&[(3, Some(0.0), None)])
+                .is_none()
+        );
+        assert_eq!(p.keyframes().len(), 3);
+    }
 }
 
 // ===== Property (constant/keyframe/expression) =====
@@ -1694,12 +2675,14 @@ mod property_tests {
             Keyframe {
                 time: OrderedFloat(0.0),
                 value: PropertyValue::from(0.0),
-                easing: EasingFunction::Linear,
+                ease_in: None,
+                ease_out: Some(EasingFunction::Linear),
             },
             Keyframe {
                 time: OrderedFloat(1.0),
                 value: PropertyValue::from(100.0),
-                easing: EasingFunction::Linear,
+                ease_in: None,
+                ease_out: Some(EasingFunction::Linear),
             },
         ];
         let p = Property::keyframe(kfs);
@@ -1721,7 +2704,8 @@ mod property_tests {
         let mut p = Property::keyframe(vec![Keyframe {
             time: OrderedFloat(0.0),
             value: PropertyValue::from(0.0),
-            easing: EasingFunction::Linear,
+            ease_in: None,
+            ease_out: Some(EasingFunction::Linear),
         }]);
         // Add new keyframe at time 1.0
         let added = p.upsert_keyframe(
@@ -1739,7 +2723,8 @@ mod property_tests {
         let p = Property::keyframe(vec![Keyframe {
             time: OrderedFloat(0.5),
             value: PropertyValue::from(10.0),
-            easing: EasingFunction::Linear,
+            ease_in: None,
+            ease_out: Some(EasingFunction::Linear),
         }]);
         assert!(p.has_keyframe_at(0.5, 0.001));
         assert!(!p.has_keyframe_at(1.0, 0.001));
@@ -1753,6 +2738,294 @@ mod property_tests {
         assert_eq!(p2.evaluator, "constant");
         assert_eq!(p2.value(), Some(&PropertyValue::Number(OrderedFloat(99.0))));
     }
+
+    #[test]
+    fn evaluate_batch_skips_missing_keys() {
+        use library::plugin::{EvaluationContext, PluginManager};
+        use library::project::property::{Keyframe, PropertyMap};
+
+        let mut props = PropertyMap::new();
+        props.set(
+            "opacity".to_string(),
+            Property::constant(PropertyValue::from(50.0)),
+        );
+        props.set(
+            "rotation".to_string(),
+            Property::keyframe(vec![
+                Keyframe {
+                    time: OrderedFloat(0.0),
+                    value: PropertyValue::from(0.0),
+                    ease_in: None,
+                    ease_out: Some(EasingFunction::Linear),
+                },
+                Keyframe {
+                    time: OrderedFloat(1.0),
+                    value: PropertyValue::from(90.0),
+                    ease_in: None,
+                    ease_out: Some(EasingFunction::Linear),
+                },
+            ]),
+        );
+
+        let evaluators = PluginManager::default().get_property_evaluators();
+        let ctx = EvaluationContext {
+            property_map: &props,
+            fps: 30.0,
+            owner_id: None,
+        };
+        let values =
+            evaluators.evaluate_batch(&props, &["opacity", "rotation", "missing"], 0.5, &ctx);
+
+        assert_eq!(values.len(), 2);
+        assert_eq!(values.get("opacity"), Some(&PropertyValue::from(50.0)));
+        assert_eq!(values.get("rotation"), Some(&PropertyValue::from(45.0)));
+        assert!(!values.contains_key("missing"));
+    }
+
+    #[test]
+    fn segment_uses_ease_out_of_left_and_ease_in_of_right() {
+        use library::plugin::{EvaluationContext, PluginManager};
+        use library::project::property::{Keyframe, PropertyMap};
+
+        let kf = |time: f64, value: f64, ease_in, ease_out| Keyframe {
+            time: OrderedFloat(time),
+            value: PropertyValue::from(value),
+            ease_in,
+            ease_out,
+        };
+        let props = PropertyMap::new();
+        let ctx = EvaluationContext {
+            property_map: &props,
+            fps: 30.0,
+            owner_id: None,
+        };
+        let evaluators = PluginManager::default().get_property_evaluators();
+        let eval = |p: &Property, t: f64| evaluators.evaluate(p, t, &ctx).get_as::<f64>().unwrap();
+
+        // 左キーフレームの ease_out だけが区間全体に効く
+        let out_only = Property::keyframe(vec![
+            kf(
+                0.0,
+                0.0,
+                Some(EasingFunction::EaseOutQuad),
+                Some(EasingFunction::EaseInQuad),
+            ),
+            kf(1.0, 100.0, None, Some(EasingFunction::EaseOutQuad)),
+        ]);
+        assert!((eval(&out_only, 0.5) - 25.0).abs() < 1e-9);
+
+        // 両側が設定されていれば前半が ease_out、後半が ease_in
+        let both = Property::keyframe(vec![
+            kf(0.0, 0.0, None, Some(EasingFunction::EaseInQuad)),
+            kf(1.0, 100.0, Some(EasingFunction::EaseOutQuad), None),
+        ]);
+        assert!((eval(&both, 0.25) - 12.5).abs() < 1e-9);
+        assert!((eval(&both, 0.5) - 50.0).abs() < 1e-9);
+        assert!((eval(&both, 0.75) - 87.5).abs() < 1e-9);
+
+        // 旧形式の easing は ease_out として読み込まれる
+        let legacy: Keyframe =
+            serde_json::from_str(r#"{"time": 0.0, "value": 1.0, "easing": "EaseInQuad"}"#).unwrap();
+        assert_eq!(legacy.ease_in, None);
+        assert_eq!(legacy.ease_out, Some(EasingFunction::EaseInQuad));
+    }
+
+    #[test]
+    fn integer_keyframes_interpolate_to_nearest_integer() {
+        use library::plugin::{EvaluationContext, PluginManager};
+        use library::project::property::{Keyframe, PropertyMap};
+
+        let prop = Property::keyframe(vec![
+            Keyframe {
+                time: OrderedFloat(0.0),
+                value: PropertyValue::Integer(0),
+                ease_in: None,
+                ease_out: Some(EasingFunction::Linear),
+            },
+            Keyframe {
+                time: OrderedFloat(1.0),
+                value: PropertyValue::Integer(10),
+                ease_in: None,
+                ease_out: Some(EasingFunction::Linear),
+            },
+        ]);
+        let props = PropertyMap::new();
+        let ctx = EvaluationContext {
+            property_map: &props,
+            fps: 30.0,
+            owner_id: None,
+        };
+        let evaluators = PluginManager::default().get_property_evaluators();
+
+        // 補間結果は整数のまま最も近い値に丸められる
+        assert_eq!(
+            evaluators.evaluate(&prop, 0.26, &ctx),
+            PropertyValue::Integer(3)
+        );
+        assert_eq!(
+            evaluators.evaluate(&prop, 0.5, &ctx),
+            PropertyValue::Integer(5)
+        );
+    }
+
+    #[test]
+    fn spring_easing_is_normalized_to_endpoints() {
+        // どの減衰比でも0で始まり1で終わり、終端で値が跳ばない
+        for damping in [2.0, 5.0, 20.0, 60.0] {
+            let easing = EasingFunction::Spring {
+                mass: 1.0,
+                stiffness: 100.0,
+                damping,
+            };
+            assert_eq!(easing.apply(0.0), 0.0);
+            assert_eq!(easing.apply(1.0), 1.0);
+            assert!((easing.apply(1.0 - 1e-6) - 1.0).abs() < 1e-3, "{}", damping);
+        }
+    }
+
+    #[test]
+    fn spring_easing_overshoots_only_when_underdamped() {
+        let under = EasingFunction::Spring {
+            mass: 1.0,
+            stiffness: 100.0,
+            damping: 5.0,
+        };
+        assert_eq!(under.apply(0.0), 0.0);
+        assert_eq!(under.apply(1.0), 1.0);
+        let peak = (1..100)
+            .map(|i| under.apply(i as f64 / 100.0))
+            .fold(0.0, f64::max);
+        assert!(peak > 1.0);
+
+        // 質量を2倍にしても剛性と減衰も2倍なら同じ曲線になる
+        let scaled = EasingFunction::Spring {
+            mass: 2.0,
+            stiffness: 200.0,
+            damping: 10.0,
+        };
+        assert!((scaled.apply(0.3) - under.apply(0.3)).abs() < 1e-9);
+        assert_ne!(scaled, under);
+
+        // 臨界減衰・過減衰は0..=1に収まる
+        for damping in [20.0, 60.0] {
+            let easing = EasingFunction::Spring {
+                mass: 1.0,
+                stiffness: 100.0,
+                damping,
+            };
+            for i in 0..=100 {
+                let v = easing.apply(i as f64 / 100.0);
+                assert!((0.0..=1.0).contains(&v));
+            }
+        }
+
+        // 収束しきらない柔らかいばねも終端へ跳ばずに1.0へ近づき、剛性0でも動く
+        for stiffness in [0.0, 1.0] {
+            let soft = EasingFunction::Spring {
+                mass: 1.0,
+                stiffness,
+                damping: 1.0,
+            };
+            assert!((soft.apply(0.999) - 1.0).abs() < 0.01);
+            assert_eq!(soft.apply(1.0), 1.0);
+            assert!(soft.apply(0.5) > 0.0);
+        }
+    }
+
+    #[test]
+    fn step_easing_follows_css_steps() {
+        use library::animation::StepPosition;
+        let step = |count, position| EasingFunction::Step { count, position };
+
+        let end = step(4, StepPosition::End);
+        assert_eq!(end.apply(0.0), 0.0);
+        assert_eq!(end.apply(0.3), 0.25);
+        assert_eq!(end.apply(1.0), 1.0);
+
+        let start = step(4, StepPosition::Start);
+        assert_eq!(start.apply(0.0), 0.25);
+        assert_eq!(start.apply(0.3), 0.5);
+        assert_eq!(start.apply(1.0), 1.0);
+
+        // Both は5段、None は3段に分かれる
+        assert_eq!(step(4, StepPosition::Both).apply(0.0), 0.2);
+        assert_eq!(step(4, StepPosition::Both).apply(1.0), 1.0);
+        assert_eq!(step(4, StepPosition::None).apply(0.0), 0.0);
+        assert_eq!(step(4, StepPosition::None).apply(0.5), 2.0 / 3.0);
+        assert_eq!(step(4, StepPosition::None).apply(1.0), 1.0);
+
+        // 段数0は線形のまま
+        assert_eq!(step(0, StepPosition::End).apply(0.3), 0.3);
+    }
+
+    #[test]
+    fn step_easing_serde_round_trip() {
+        use library::animation::StepPosition;
+        for position in [
+            StepPosition::Start,
+            StepPosition::End,
+            StepPosition::Both,
+            StepPosition::None,
+        ] {
+            let easing = EasingFunction::Step { count: 6, position };
+            let json = serde_json::to_string(&easing).unwrap();
+            let restored: EasingFunction = serde_json::from_str(&json).unwrap();
+            assert_eq!(restored, easing);
+        }
+    }
+
+    #[test]
+    fn easing_css_string_round_trip() {
+        assert_eq!(
+            EasingFunction::Linear.to_css_string().as_deref(),
+            Some("linear")
+        );
+        assert_eq!(
+            EasingFunction::EaseOutCubic.to_css_string().as_deref(),
+            Some("cubic-bezier(0.33, 1, 0.68, 1)")
+        );
+        assert_eq!(
+            EasingFunction::EaseInBack { c1: 1.70158 }.to_css_string(),
+            None
+        );
+
+        let ease = EasingFunction::from_css_string("ease").unwrap();
+        assert_eq!(
+            ease.to_css_string().as_deref(),
+            Some("cubic-bezier(0.25, 0.1, 0.25, 1)")
+        );
+        let parsed =
+            EasingFunction::from_css_string(" cubic-bezier(0.1, -0.5, 0.9, 1.5) ").unwrap();
+        assert_eq!(
+            EasingFunction::from_css_string(&parsed.to_css_string().unwrap()).unwrap(),
+            parsed
+        );
+        // ease-in は入力の前半で出力が線形より小さい
+        assert!(
+            EasingFunction::from_css_string("ease-in")
+                .unwrap()
+                .apply(0.25)
+                < 0.25
+        );
+        assert_eq!(
+            EasingFunction::from_css_string("LINEAR").unwrap(),
+            EasingFunction::Linear
+        );
+    }
+
+    #[test]
+    fn easing_from_css_string_rejects_invalid_input() {
+        // x座標は0..=1の範囲に限られる
+        for css in [
+            "",
+            "bouncy",
+            "cubic-bezier(0.1, 0.2, 0.3)",
+            "cubic-bezier(1.5, 0, 0.5, 1)",
+            "cubic-bezier(a, b, c, d)",
+        ] {
+            assert!(EasingFunction::from_css_string(css).is_err(), "{}", css);
+        }
+    }
 }
 
 // ===== Connection Model =====
@@ -68,3 +68,640 @@ fn test_video_reader_decode_frame() {
     assert_eq!(img30.width, reader.get_dimensions().0);
     assert!(!img30.data.is_empty());
 }
+
+#[test]
+fn test_decoder_state_seeks_only_outside_window() {
+    use library::builtin::loaders::ffmpeg_video::DecoderState;
+
+    // 初回は必ずシーク
+    let mut state = DecoderState::new(8);
+    assert!(state.needs_seek(0));
+
+    // 次フレームから8フレーム先までは順次デコード
+    state.next_frame_number = Some(10);
+    assert!(!state.needs_seek(10));
+    assert!(!state.needs_seek(18));
+    assert!(state.needs_seek(19));
+    // 後方へはシーク
+    assert!(state.needs_seek(9));
+
+    // ウィンドウ0は従来どおり連続フレーム以外でシーク
+    state.seek_window = 0;
+    assert!(!state.needs_seek(10));
+    assert!(state.needs_seek(11));
+}
+
+#[test]
+fn test_video_reader_forward_scrub_stays_sequential() {
+    let path = get_test_file_path("test.mp4");
+    let mut reader =
+        VideoReader::new(path.to_str().unwrap()).expect("Failed to create VideoReader");
+
+    // 数フレーム先へ進めてもシークせずに続きからデコードする
+    reader.decode_frame(0).unwrap();
+    assert_eq!(reader.decoder_state().seek_count, 1);
+    let img4 = reader.decode_frame(4).unwrap();
+    assert_eq!(reader.decoder_state().seek_count, 1);
+    assert_eq!(reader.decoder_state().next_frame_number, Some(5));
+
+    // シークし直してデコードした結果と一致する
+    let mut fresh = VideoReader::new(path.to_str().unwrap()).unwrap();
+    fresh.set_seek_window(0);
+    assert_eq!(fresh.decode_frame(4).unwrap().data, img4.data);
+}
+
+#[test]
+fn test_native_loader_multipage_tiff() {
+    use library::builtin::loaders::{LoadPlugin, LoadRequest, NativeImageLoader};
+    use library::cache::CacheManager;
+    use library::project::asset::AssetKind;
+    use tiff::encoder::{TiffEncoder, colortype};
+
+    // 2ページの TIFF を生成 (1ページ目: 赤, 2ページ目: 緑)
+    let path = std::env::temp_dir().join("native_loader_multipage_test.tiff");
+    {
+        let file = std::fs::File::create(&path).unwrap();
+        let mut encoder = TiffEncoder::new(file).unwrap();
+        let red: Vec<u8> = [255, 0, 0, 255].repeat(4);
+        let green: Vec<u8> = [0, 255, 0, 255].repeat(4);
+        encoder.write_image::<colortype::RGBA8>(2, 2, &red).unwrap();
+        encoder
+            .write_image::<colortype::RGBA8>(2, 2, &green)
+            .unwrap();
+    }
+    let path_str = path.to_str().unwrap();
+
+    let loader = NativeImageLoader::new();
+    let streams = loader.open(path_str).expect("TIFF should open");
+    assert_eq!(streams.len(), 2);
+    assert!(streams.iter().all(|s| s.kind == AssetKind::Video));
+    assert_eq!(streams[1].stream_index, Some(1));
+
+    let cache = CacheManager::new();
+    let request = LoadRequest::VideoFrame {
+        path: path_str.to_string(),
+        frame_number: 0,
+        stream_index: Some(1),
+        input_color_space: None,
+        output_color_space: None,
+    };
+    let page = loader.load(&request, &cache).unwrap().image;
+    assert_eq!((page.width, page.height), (2, 2));
+    assert_eq!(&page.data[0..4], &[0, 255, 0, 255]);
+
+    let _ = std::fs::remove_file(&path);
+}
+
+/// PNG チャンク (長さ + 種類 + データ + CRC) を書き出す
+fn write_png_chunk(out: &mut Vec<u8>, chunk_type: &[u8; 4], data: &[u8]) {
+    let mut crc = 0xFFFF_FFFFu32;
+    for &byte in chunk_type.iter().chain(data) {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    out.extend_from_slice(chunk_type);
+    out.extend_from_slice(data);
+    out.extend_from_slice(&(!crc).to_be_bytes());
+}
+
+/// 1x1 の各色をフレームとする APNG を組み立てる (1フレーム 1/5 秒)
+fn build_apng(colors: &[[u8; 4]]) -> Vec<u8> {
+    use image::ImageEncoder;
+    use image::codecs::png::PngEncoder;
+
+    // 各フレームを通常の PNG としてエンコードし、IHDR と IDAT を取り出す
+    let encoded: Vec<(Vec<u8>, Vec<u8>)> = colors
+        .iter()
+        .map(|color| {
+            let mut png = Vec::new();
+            PngEncoder::new(&mut png)
+                .write_image(color, 1, 1, image::ExtendedColorType::Rgba8)
+                .unwrap();
+            let (mut ihdr, mut idat) = (Vec::new(), Vec::new());
+            let mut pos = 8;
+            while pos < png.len() {
+                let len = u32::from_be_bytes(png[pos..pos + 4].try_into().unwrap()) as usize;
+                let data = &png[pos + 8..pos + 8 + len];
+                match &png[pos + 4..pos + 8] {
+                    b"IHDR" => ihdr = data.to_vec(),
+                    b"IDAT" => idat.extend_from_slice(data),
+                    _ => {}
+                }
+                pos += 12 + len;
+            }
+            (ihdr, idat)
+        })
+        .collect();
+
+    let mut out = b"\x89PNG\r\n\x1a\n".to_vec();
+    write_png_chunk(&mut out, b"IHDR", &encoded[0].0);
+    let mut actl = (colors.len() as u32).to_be_bytes().to_vec();
+    actl.extend_from_slice(&0u32.to_be_bytes());
+    write_png_chunk(&mut out, b"acTL", &actl);
+
+    let mut sequence = 0u32;
+    for (i, (_, idat)) in encoded.iter().enumerate() {
+        let mut fctl = sequence.to_be_bytes().to_vec();
+        for value in [1u32, 1, 0, 0] {
+            fctl.extend_from_slice(&value.to_be_bytes());
+        }
+        fctl.extend_from_slice(&1u16.to_be_bytes());
+        fctl.extend_from_slice(&5u16.to_be_bytes());
+        fctl.extend_from_slice(&[0, 0]);
+        write_png_chunk(&mut out, b"fcTL", &fctl);
+        sequence += 1;
+
+        if i == 0 {
+            write_png_chunk(&mut out, b"IDAT", idat);
+        } else {
+            let mut fdat = sequence.to_be_bytes().to_vec();
+            fdat.extend_from_slice(idat);
+            write_png_chunk(&mut out, b"fdAT", &fdat);
+            sequence += 1;
+        }
+    }
+    write_png_chunk(&mut out, b"IEND", &[]);
+    out
+}
+
+#[test]
+fn test_native_loader_apng_reads_header_and_decodes_requested_frame() {
+    use library::builtin::loaders::native_image::apng_info;
+    use library::builtin::loaders::{LoadPlugin, LoadRequest, NativeImageLoader};
+    use library::cache::CacheManager;
+    use library::project::asset::AssetKind;
+
+    let dir = std::env::temp_dir().join(format!("apng_test_{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("anim.png");
+    let colors = [[255, 0, 0, 255], [0, 255, 0, 255], [0, 0, 255, 255]];
+    std::fs::write(&path, build_apng(&colors)).unwrap();
+    let path_str = path.to_str().unwrap();
+
+    // フレーム数とフレームレートはヘッダーだけから読む
+    assert_eq!(apng_info(path_str).unwrap(), Some((3, 5.0)));
+
+    let loader = NativeImageLoader::new();
+    let streams = loader.open(path_str).unwrap();
+    assert_eq!(streams[0].kind, AssetKind::Video);
+    assert_eq!(streams[0].duration, Some(0.6));
+
+    // 2フレーム目を要求すると、途中までのフレームだけがキャッシュされる
+    let cache = CacheManager::new();
+    let request = LoadRequest::VideoFrame {
+        path: path_str.to_string(),
+        frame_number: 1,
+        stream_index: None,
+        input_color_space: None,
+        output_color_space: None,
+    };
+    let frame = loader.load(&request, &cache).unwrap().image;
+    assert_eq!(&frame.data[0..4], &[0, 255, 0, 255]);
+    assert!(cache.get_video_frame(path_str, 0).is_some());
+    assert!(cache.get_video_frame(path_str, 2).is_none());
+
+    // 最終フレームを越えた要求は最終フレームを返し、要求した番号でもキャッシュする
+    let request = LoadRequest::VideoFrame {
+        path: path_str.to_string(),
+        frame_number: 10,
+        stream_index: None,
+        input_color_space: None,
+        output_color_space: None,
+    };
+    let frame = loader.load(&request, &cache).unwrap().image;
+    assert_eq!(&frame.data[0..4], &[0, 0, 255, 255]);
+    assert!(cache.get_video_frame(path_str, 10).is_some());
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_svg_loader_rasterizes_at_intrinsic_size() {
+    use library::builtin::loaders::{LoadPlugin, LoadRequest, SvgLoader};
+    use library::cache::CacheManager;
+    use library::project::asset::AssetKind;
+
+    // 左半分が赤、右半分が透明な 4x2 の SVG
+    let dir = std::env::temp_dir().join(format!("svg_loader_test_{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("shape.svg");
+    std::fs::write(
+        &path,
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="4" height="2">
+            <rect x="0" y="0" width="2" height="2" fill="#ff0000"/>
+        </svg>"##,
+    )
+    .unwrap();
+    let path_str = path.to_str().unwrap();
+
+    let loader = SvgLoader::new();
+    assert!(loader.probe_format(path_str));
+    assert!(!loader.probe_format("/missing/photo.png"));
+
+    let streams = loader.open(path_str).expect("SVG should open");
+    assert_eq!(streams[0].kind, AssetKind::Image);
+    assert_eq!((streams[0].width, streams[0].height), (Some(4), Some(2)));
+
+    let cache = CacheManager::new();
+    let request = LoadRequest::Image {
+        path: path_str.to_string(),
+        channel_layer: None,
+        exposure_offset: 0.0,
+        scale: 1.0,
+        page: None,
+    };
+    let image = loader.load(&request, &cache).unwrap().image;
+    assert_eq!((image.width, image.height), (4, 2));
+    assert_eq!(&image.data[0..4], &[255, 0, 0, 255]);
+    assert_eq!(image.data[3 * 4 + 3], 0);
+
+    // 拡大してラスタライズするとピクセル数が増える
+    let scaled = loader.rasterize(path_str, 2.0).unwrap();
+    assert_eq!((scaled.width, scaled.height), (8, 4));
+
+    // load もリクエストのスケールでラスタライズし、等倍のキャッシュとは別に保持する
+    let scaled_request = LoadRequest::Image {
+        path: path_str.to_string(),
+        channel_layer: None,
+        exposure_offset: 0.0,
+        scale: 2.0,
+        page: None,
+    };
+    let scaled = loader.load(&scaled_request, &cache).unwrap().image;
+    assert_eq!((scaled.width, scaled.height), (8, 4));
+    assert_eq!(&scaled.data[0..4], &[255, 0, 0, 255]);
+    let image = loader.load(&request, &cache).unwrap().image;
+    assert_eq!((image.width, image.height), (4, 2));
+
+    let invalid_request = LoadRequest::Image {
+        scale: 0.0,
+        ..request.clone()
+    };
+    assert!(loader.load(&invalid_request, &cache).is_err());
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_exr_loader_tone_maps_with_exposure_offset() {
+    use library::builtin::loaders::exr_loader::BASE_LAYER;
+    use library::builtin::loaders::{ExrLoader, LoadPlugin, LoadRequest};
+    use library::cache::CacheManager;
+
+    // 左のピクセルが 0.25、右のピクセルが 2.0 の 2x1 EXR
+    let path = std::env::temp_dir().join("exr_loader_test.exr");
+    exr::prelude::write_rgba_file(&path, 2, 1, |x, _| {
+        let v = if x == 0 { 0.25f32 } else { 2.0 };
+        (v, v, v, 1.0f32)
+    })
+    .unwrap();
+    let path_str = path.to_str().unwrap();
+
+    let loader = ExrLoader::new();
+    let streams = loader.open(path_str).expect("EXR should open");
+    assert_eq!((streams[0].width, streams[0].height), (Some(2), Some(1)));
+    assert_eq!(streams[0].channel_layers, vec![BASE_LAYER.to_string()]);
+
+    let cache = CacheManager::new();
+    let load = |exposure_offset: f64| {
+        let request = LoadRequest::Image {
+            path: path_str.to_string(),
+            channel_layer: None,
+            exposure_offset,
+            scale: 1.0,
+            page: None,
+        };
+        loader.load(&request, &cache).unwrap().image
+    };
+    // 線形に 0..255 へスケールし、1.0 を超える値はクリップする
+    let image = load(0.0);
+    assert_eq!(&image.data[0..4], &[64, 64, 64, 255]);
+    assert_eq!(&image.data[4..8], &[255, 255, 255, 255]);
+
+    // +1 段で 2 倍: 0.25 -> 0.5 -> 128
+    let brighter = load(1.0);
+    assert_eq!(&brighter.data[0..4], &[128, 128, 128, 255]);
+
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn test_pdf_loader_rejects_non_pdf_files() {
+    use library::builtin::loaders::{LoadPlugin, LoadRequest, PdfLoader};
+    use library::cache::CacheManager;
+
+    // 拡張子で弾くので PDFium が無い環境でも動く
+    let loader = PdfLoader::new();
+    assert!(loader.probe_format("/missing/slides.PDF"));
+    assert!(!loader.probe_format("/missing/photo.png"));
+    assert!(loader.open("/missing/photo.png").is_err());
+
+    let cache = CacheManager::new();
+    let request = LoadRequest::VideoFrame {
+        path: "/missing/clip.mp4".to_string(),
+        frame_number: 0,
+        stream_index: None,
+        input_color_space: None,
+        output_color_space: None,
+    };
+    assert!(loader.load(&request, &cache).is_err());
+}
+
+/// 横長 (72x36pt) と縦長 (36x72pt) の 2 ページからなる最小の PDF
+fn write_two_page_pdf(path: &std::path::Path) {
+    let objects = [
+        "<< /Type /Catalog /Pages 2 0 R >>",
+        "<< /Type /Pages /Kids [3 0 R 4 0 R] /Count 2 >>",
+        "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 72 36] >>",
+        "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 36 72] >>",
+    ];
+    let mut pdf = String::from("%PDF-1.4\n");
+    let mut offsets = Vec::new();
+    for (i, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.push_str(&format!("{} 0 obj\n{}\nendobj\n", i + 1, object));
+    }
+    let xref = pdf.len();
+    pdf.push_str(&format!(
+        "xref\n0 {}\n0000000000 65535 f \n",
+        objects.len() + 1
+    ));
+    for offset in offsets {
+        pdf.push_str(&format!("{:010} 00000 n \n", offset));
+    }
+    pdf.push_str(&format!(
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+        objects.len() + 1,
+        xref
+    ));
+    std::fs::write(path, pdf).unwrap();
+}
+
+/// Test: 静止画リクエストの page で 2 ページ目を読み込めること
+#[test]
+fn test_pdf_loader_loads_requested_page() {
+    use library::builtin::loaders::{LoadPlugin, LoadRequest, PdfLoader};
+    use library::cache::CacheManager;
+
+    let dir = std::env::temp_dir().join(format!("pdf_loader_test_{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("slides.pdf");
+    write_two_page_pdf(&path);
+    let path_str = path.to_str().unwrap();
+
+    let loader = PdfLoader::new();
+    // PDFium が見つからない環境ではスキップする
+    let Ok(pages) = loader.open(path_str) else {
+        let _ = std::fs::remove_dir_all(&dir);
+        return;
+    };
+    assert_eq!(pages.len(), 2);
+    assert_eq!(pages[1].stream_index, Some(1));
+
+    // 144 DPI: 1pt = 2px
+    let cache = CacheManager::new();
+    let load = |page: Option<usize>| {
+        let request = LoadRequest::Image {
+            path: path_str.to_string(),
+            channel_layer: None,
+            exposure_offset: 0.0,
+            scale: 1.0,
+            page,
+        };
+        loader.load(&request, &cache).unwrap().image
+    };
+    let second = load(Some(1));
+    assert_eq!((second.width, second.height), (72, 144));
+    let first = load(None);
+    assert_eq!((first.width, first.height), (144, 72));
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_native_loader_tone_maps_hdr_files() {
+    use library::builtin::loaders::native_image::{
+        ToneMapMethod, load_hdr_image, set_default_tone_map,
+    };
+    use library::builtin::loaders::{LoadPlugin, LoadRequest, NativeImageLoader};
+    use library::cache::CacheManager;
+
+    // 輝度 1.0 の Radiance HDR
+    let path = std::env::temp_dir().join("native_loader_test.hdr");
+    image::Rgb32FImage::from_pixel(2, 1, image::Rgb([1.0, 1.0, 1.0]))
+        .save(&path)
+        .unwrap();
+    let path_str = path.to_str().unwrap();
+
+    // Reinhard: 1.0 -> 0.5 (linear) -> 188 (sRGB)
+    let image = load_hdr_image(path_str, ToneMapMethod::Reinhard).unwrap();
+    assert_eq!(&image.data[0..4], &[188, 188, 188, 255]);
+
+    // スレッドの既定値がローダー経由の読み込みに使われる
+    set_default_tone_map(ToneMapMethod::Exposure);
+    let loader = NativeImageLoader::new();
+    assert!(loader.probe_format(path_str));
+    let request = LoadRequest::Image {
+        path: path_str.to_string(),
+        channel_layer: None,
+        exposure_offset: 0.0,
+        scale: 1.0,
+        page: None,
+    };
+    let image = loader.load(&request, &CacheManager::new()).unwrap().image;
+    assert_eq!(&image.data[0..4], &[208, 208, 208, 255]);
+    set_default_tone_map(ToneMapMethod::default());
+
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn test_native_loader_probe_format_checks_extension() {
+    use library::builtin::loaders::{LoadPlugin, NativeImageLoader};
+
+    // 拡張子だけで判定するため、ファイルが存在しなくてもよい
+    let loader = NativeImageLoader::new();
+    assert!(loader.probe_format("/missing/photo.PNG"));
+    assert!(loader.probe_format("/missing/scan.tiff"));
+    assert!(!loader.probe_format("/missing/clip.mp4"));
+    assert!(!loader.probe_format("/missing/no_extension"));
+}
+
+#[test]
+fn test_cache_evict_path_removes_only_matching_entries() {
+    use library::cache::CacheManager;
+    use library::runtime::Image;
+
+    let cache = CacheManager::new();
+    let image = Image::new(1, 1, vec![0, 0, 0, 255]);
+    cache.put_image("/media/a.png", &image);
+    cache.put_video_frame("/media/a.mp4", 0, &image);
+    cache.put_video_frame("/media/a.mp4", 1, &image);
+    cache.put_video_frame("/media/b.mp4", 0, &image);
+    cache.put_video_frame("/media/a.mp4.bak", 0, &image);
+    cache.put_image("/media/a.png.bak", &image);
+
+    // a.mp4 の全フレームだけが破棄される
+    cache.evict_path("/media/a.mp4");
+    assert!(cache.get_video_frame("/media/a.mp4", 0).is_none());
+    assert!(cache.get_video_frame("/media/a.mp4", 1).is_none());
+    assert!(cache.get_video_frame("/media/b.mp4", 0).is_some());
+    assert!(cache.get_video_frame("/media/a.mp4.bak", 0).is_some());
+    assert!(cache.get_image("/media/a.png").is_some());
+
+    // 画像は完全一致したキーだけが破棄される
+    cache.evict_path("/media/a.png");
+    assert!(cache.get_image("/media/a.png").is_none());
+    assert!(cache.get_image("/media/a.png.bak").is_some());
+}
+
+#[test]
+fn test_cache_waveform_key_includes_modification_time() {
+    use library::cache::{CacheManager, WaveformKey};
+    use std::sync::Arc;
+    use std::time::{Duration, SystemTime};
+
+    let cache = CacheManager::new();
+    let asset_id = uuid::Uuid::new_v4();
+    let key = WaveformKey {
+        asset_id,
+        modified: Some(SystemTime::UNIX_EPOCH + Duration::from_secs(100)),
+        start_sample: 0,
+        num_samples: 48000,
+        num_peaks: 200,
+    };
+    cache.put_waveform(key.clone(), Arc::new(vec![(-0.5, 0.5); 200]));
+    assert_eq!(cache.get_waveform(&key).unwrap().len(), 200);
+
+    // ファイルが更新されると別のキーになりヒットしない
+    let edited = WaveformKey {
+        modified: Some(SystemTime::UNIX_EPOCH + Duration::from_secs(200)),
+        ..key.clone()
+    };
+    assert!(cache.get_waveform(&edited).is_none());
+
+    // 音声を読み込み直すと以前のピークは破棄される
+    let modified = Some(SystemTime::UNIX_EPOCH + Duration::from_secs(200));
+    cache.put_audio(asset_id, vec![0.0; 4], 48000, modified);
+    assert!(cache.get_waveform(&key).is_none());
+    assert_eq!(cache.get_audio_modified(asset_id), modified);
+}
+
+#[test]
+fn test_cache_warm_from_frame_range_decodes_each_frame() {
+    use library::builtin::loaders::NativeImageLoader;
+    use library::cache::CacheManager;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use tiff::encoder::{TiffEncoder, colortype};
+
+    let path = std::env::temp_dir().join("cache_warm_frame_range_test.tiff");
+    {
+        let file = std::fs::File::create(&path).unwrap();
+        let mut encoder = TiffEncoder::new(file).unwrap();
+        let pixels: Vec<u8> = [0, 0, 255, 255].repeat(4);
+        encoder
+            .write_image::<colortype::RGBA8>(2, 2, &pixels)
+            .unwrap();
+        encoder
+            .write_image::<colortype::RGBA8>(2, 2, &pixels)
+            .unwrap();
+    }
+    let path_str = path.to_str().unwrap();
+    let loader = NativeImageLoader::new();
+    let cache = CacheManager::new();
+
+    // キャンセル済みなら何もデコードしない
+    let cancel = AtomicBool::new(true);
+    cache
+        .warm_from_frame_range(&loader, path_str, 0, 2, &cancel)
+        .unwrap();
+    assert!(cache.get_video_frame(path_str, 0).is_none());
+
+    // 範囲内の全フレームがキャッシュに入る
+    cancel.store(false, Ordering::Relaxed);
+    cache
+        .warm_from_frame_range(&loader, path_str, 0, 2, &cancel)
+        .unwrap();
+    assert!(cache.get_video_frame(path_str, 0).is_some());
+    assert!(cache.get_video_frame(path_str, 1).is_some());
+
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn test_cache_config_capacity_and_resize() {
+    use library::cache::{CacheConfig, CacheManager};
+    use library::runtime::Image;
+
+    let cache = CacheManager::with_config(CacheConfig::default().with_video_cache_capacity(2));
+    let image = Image::new(1, 1, vec![0, 0, 0, 255]);
+    for frame in 0..3 {
+        cache.put_video_frame("/media/a.mp4", frame, &image);
+    }
+    // 容量を超えた最も古いフレームが追い出される
+    assert!(cache.get_video_frame("/media/a.mp4", 0).is_none());
+    assert!(cache.get_video_frame("/media/a.mp4", 2).is_some());
+
+    // 縮小しても最近使ったフレームは残る
+    cache.resize(CacheConfig::default().with_video_cache_capacity(1));
+    assert_eq!(cache.config().video_cache_capacity, 1);
+    assert!(cache.get_video_frame("/media/a.mp4", 1).is_none());
+    assert!(cache.get_video_frame("/media/a.mp4", 2).is_some());
+}
+
+#[test]
+fn test_cache_max_memory_bytes_evicts_oldest_frames() {
+    use library::cache::{CacheConfig, CacheManager};
+    use library::runtime::Image;
+
+    // 1フレーム4バイトなので、8バイト上限では2フレームまで保持できる
+    let cache = CacheManager::with_config(CacheConfig::default().with_max_memory_bytes(8));
+    let image = Image::new(1, 1, vec![0, 0, 0, 255]);
+    for frame in 0..3 {
+        cache.put_video_frame("/media/a.mp4", frame, &image);
+    }
+    assert!(cache.get_video_frame("/media/a.mp4", 0).is_none());
+    assert!(cache.get_video_frame("/media/a.mp4", 1).is_some());
+    assert!(cache.get_video_frame("/media/a.mp4", 2).is_some());
+}
+
+#[test]
+fn test_cache_stats_counts_hits_and_misses() {
+    use library::cache::{CacheManager, CacheStats};
+    use library::runtime::Image;
+
+    let cache = CacheManager::new();
+    let image = Image::new(1, 1, vec![0, 0, 0, 255]);
+    assert_eq!(cache.stats().hit_rate(), None);
+
+    assert!(cache.get_image("/media/a.png").is_none());
+    cache.put_image("/media/a.png", &image);
+    assert!(cache.get_image("/media/a.png").is_some());
+    assert!(cache.get_video_frame("/media/a.mp4", 0).is_none());
+    cache.put_video_frame("/media/a.mp4", 0, &image);
+    assert!(cache.get_video_frame("/media/a.mp4", 0).is_some());
+    assert!(cache.get_video_frame("/media/a.mp4", 0).is_some());
+
+    // put は件数に含めない
+    let stats = cache.stats();
+    assert_eq!(
+        stats,
+        CacheStats {
+            image_hits: 1,
+            image_misses: 1,
+            video_hits: 2,
+            video_misses: 1,
+        }
+    );
+    assert_eq!(stats.hit_rate(), Some(0.6));
+
+    cache.reset_stats();
+    assert_eq!(cache.stats(), CacheStats::default());
+}
@@ -0,0 +1,292 @@
+use library::plugin::{EvaluationContext, PropertyEvaluator};
+use library::plugin::{Plugin, PropertyPlugin};
+use library::project::property::{Property, PropertyValue};
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex};
+
+/// Integration step in seconds.
+const DT: f64 = 1.0 / 1000.0;
+/// Simulations kept before the cache is cleared, so stale springs do not pile up.
+const MAX_STATES: usize = 1024;
+
+pub struct SpringPropertyPlugin;
+
+impl Plugin for SpringPropertyPlugin {
+    fn id(&self) -> &'static str {
+        "spring"
+    }
+
+    fn name(&self) -> String {
+        "Spring Property".to_string()
+    }
+
+    fn category(&self) -> String {
+        "Property".to_string()
+    }
+
+    fn version(&self) -> (u32, u32, u32) {
+        (0, 1, 0)
+    }
+}
+
+impl PropertyPlugin for SpringPropertyPlugin {
+    fn get_evaluator_instance(&self) -> Arc<dyn PropertyEvaluator> {
+        Arc::new(SpringEvaluator::default())
+    }
+}
+
+/// Parameters of a unit-mass spring pulled toward `target`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpringParams {
+    pub target: f64,
+    pub stiffness: f64,
+    pub damping: f64,
+    pub initial_value: f64,
+    pub initial_velocity: f64,
+}
+
+impl SpringParams {
+    fn from_property(property: &Property) -> Self {
+        let number = |key: &str| property.properties.get(key).and_then(|v| v.get_as::<f64>());
+        // `value` is what `Property::spring` stores the starting point under
+        let initial_value = number("initial_value")
+            .or_else(|| number("value"))
+            .unwrap_or(0.0);
+        Self {
+            target: number("target").unwrap_or(initial_value),
+            stiffness: number("stiffness").unwrap_or(100.0).max(0.0),
+            damping: number("damping").unwrap_or(10.0).max(0.0),
+            initial_value,
+            initial_velocity: number("initial_velocity").unwrap_or(0.0),
+        }
+    }
+
+    fn acceleration(&self, position: f64, velocity: f64) -> f64 {
+        -self.stiffness * (position - self.target) - self.damping * velocity
+    }
+
+    /// Advance `(position, velocity)` by `dt` with one RK4 step.
+    fn step(&self, (x, v): (f64, f64), dt: f64) -> (f64, f64) {
+        let (k1x, k1v) = (v, self.acceleration(x, v));
+        let (k2x, k2v) = (
+            v + 0.5 * dt * k1v,
+            self.acceleration(x + 0.5 * dt * k1x, v + 0.5 * dt * k1v),
+        );
+        let (k3x, k3v) = (
+            v + 0.5 * dt * k2v,
+            self.acceleration(x + 0.5 * dt * k2x, v + 0.5 * dt * k2v),
+        );
+        let (k4x, k4v) = (v + dt * k3v, self.acceleration(x + dt * k3x, v + dt * k3v));
+        (
+            x + dt / 6.0 * (k1x + 2.0 * k2x + 2.0 * k3x + k4x),
+            v + dt / 6.0 * (k1v + 2.0 * k2v + 2.0 * k3v + k4v),
+        )
+    }
+}
+
+/// Simulation state of one spring property, `time` seconds after its release.
+#[derive(Debug, Clone, Copy)]
+struct SpringState {
+    params: SpringParams,
+    time: f64,
+    position: f64,
+    velocity: f64,
+}
+
+impl SpringState {
+    fn released(params: SpringParams) -> Self {
+        Self {
+            params,
+            time: 0.0,
+            position: params.initial_value,
+            velocity: params.initial_velocity,
+        }
+    }
+
+    /// Step forward to `time` in steps of [`DT`], the last one shorter.
+    fn advance(&mut self, time: f64) {
+        while self.time < time {
+            let dt = (time - self.time).min(DT);
+            (self.position, self.velocity) = self.params.step((self.position, self.velocity), dt);
+            self.time = if dt < DT { time } else { self.time + DT };
+        }
+    }
+}
+
+/// Steps a spring ODE forward from the last evaluated time with fixed-step RK4.
+///
+/// State is kept per property, keyed by the owning node and the property's key
+/// in its map. Moving forward resumes from the cached time; going back in time
+/// or editing the spring restarts it from the release.
+#[derive(Default)]
+pub struct SpringEvaluator {
+    /// Each spring has its own lock, so different springs evaluate in parallel.
+    states: Mutex<HashMap<u64, Arc<Mutex<SpringState>>>>,
+}
+
+impl SpringEvaluator {
+    /// Position of the spring `time` seconds after its release.
+    ///
+    /// `identity` selects the cached simulation to resume; without one the
+    /// spring is simulated from its release.
+    pub fn position_at(&self, identity: Option<u64>, params: &SpringParams, time: f64) -> f64 {
+        if !time.is_finite() || time <= 0.0 {
+            return params.initial_value;
+        }
+        let Some(key) = identity else {
+            let mut state = SpringState::released(*params);
+            state.advance(time);
+            return state.position;
+        };
+
+        let state = {
+            let mut states = self.states.lock().unwrap();
+            if states.len() >= MAX_STATES && !states.contains_key(&key) {
+                states.clear();
+            }
+            states
+                .entry(key)
+                .or_insert_with(|| Arc::new(Mutex::new(SpringState::released(*params))))
+                .clone()
+        };
+        let mut state = state.lock().unwrap();
+        if state.params != *params || time < state.time {
+            *state = SpringState::released(*params);
+        }
+        state.advance(time);
+        state.position
+    }
+}
+
+/// Identity of a spring property: the node owning the property map and the
+/// property's key in it. `None` outside a render, where no owner is known.
+fn property_identity(property: &Property, ctx: &EvaluationContext) -> Option<u64> {
+    let owner_id = ctx.owner_id?;
+    let (key, _) = ctx
+        .property_map
+        .iter()
+        .find(|(_, candidate)| std::ptr::eq(*candidate, property))?;
+    let mut hasher = DefaultHasher::new();
+    owner_id.hash(&mut hasher);
+    key.hash(&mut hasher);
+    Some(hasher.finish())
+}
+
+impl PropertyEvaluator for SpringEvaluator {
+    fn evaluate(&self, property: &Property, time: f64, ctx: &EvaluationContext) -> PropertyValue {
+        let params = SpringParams::from_property(property);
+        let identity = property_identity(property, ctx);
+        PropertyValue::Number(ordered_float::OrderedFloat(
+            self.position_at(identity, &params, time),
+        ))
+    }
+}
+
+#[allow(improper_ctypes_definitions)]
+#[no_mangle]
+pub extern "C" fn create_property_plugin() -> *mut dyn PropertyPlugin {
+    let plugin: Box<dyn PropertyPlugin> = Box::new(SpringPropertyPlugin);
+    Box::into_raw(plugin)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use library::animation::spring_position;
+    use library::project::property::PropertyMap;
+
+    fn params(damping: f64) -> SpringParams {
+        SpringParams {
+            target: 100.0,
+            stiffness: 100.0,
+            damping,
+            initial_value: 0.0,
+            initial_velocity: 0.0,
+        }
+    }
+
+    fn closed_form(p: &SpringParams, time: f64) -> f64 {
+        spring_position(
+            p.initial_value,
+            p.target,
+            p.stiffness,
+            p.damping,
+            p.initial_velocity,
+            time,
+        )
+    }
+
+    #[test]
+    fn spring_converges_to_target() {
+        let evaluator = SpringEvaluator::default();
+        for damping in [5.0, 20.0, 40.0] {
+            let p = params(damping);
+            // 減衰があればどの減衰比でも目標値に落ち着く
+            assert!((evaluator.position_at(None, &p, 10.0) - p.target).abs() < 1e-3);
+        }
+        // 時刻 0 では初期値
+        assert_eq!(evaluator.position_at(None, &params(5.0), 0.0), 0.0);
+
+        // 減衰不足だけが目標値を行き過ぎる
+        let peak = |p: &SpringParams| {
+            (1..60)
+                .map(|i| evaluator.position_at(None, p, i as f64 / 30.0))
+                .fold(0.0, f64::max)
+        };
+        assert!(peak(&params(5.0)) > 100.0);
+        assert!(peak(&params(20.0)) <= 100.0 + 1e-9);
+        assert!(peak(&params(60.0)) <= 100.0 + 1e-9);
+    }
+
+    #[test]
+    fn spring_resumes_forward_and_restarts_backward() {
+        let evaluator = SpringEvaluator::default();
+        let p = params(5.0);
+        // 前に進むときは前回の状態から、戻るときは初めからやり直しても値は変わらない
+        for time in [0.25, 0.5, 0.5005, 1.0, 2.0, 0.5, 0.25] {
+            let position = evaluator.position_at(Some(1), &p, time);
+            assert!((position - closed_form(&p, time)).abs() < 1e-6);
+        }
+        // パラメータを変えると同じプロパティでもシミュレーションをやり直す
+        let stiffer = SpringParams {
+            stiffness: 400.0,
+            ..p
+        };
+        let position = evaluator.position_at(Some(1), &stiffer, 1.0);
+        assert!((position - closed_form(&stiffer, 1.0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn springs_of_different_properties_keep_separate_state() {
+        let evaluator = SpringEvaluator::default();
+        let mut props = PropertyMap::new();
+        props.set(
+            "x".to_string(),
+            Property::spring(0.0, 100.0, 100.0, 5.0, 0.0),
+        );
+        props.set(
+            "y".to_string(),
+            Property::spring(0.0, 100.0, 100.0, 5.0, 0.0),
+        );
+        let owner = Some(uuid::Uuid::new_v4());
+        let ctx = EvaluationContext {
+            property_map: &props,
+            fps: 30.0,
+            owner_id: owner,
+        };
+        let x = props.get("x").unwrap();
+        let y = props.get("y").unwrap();
+        assert_ne!(property_identity(x, &ctx), property_identity(y, &ctx));
+
+        // 同じパラメータでも別のプロパティは互いの時刻に影響しない
+        let eval = |p: &Property, t: f64| evaluator.evaluate(p, t, &ctx).get_as::<f64>().unwrap();
+        let p = params(5.0);
+        let x_late = eval(x, 2.0);
+        let y_early = eval(y, 0.5);
+        assert!((x_late - closed_form(&p, 2.0)).abs() < 1e-6);
+        assert!((y_early - closed_form(&p, 0.5)).abs() < 1e-6);
+        assert_eq!(evaluator.states.lock().unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,110 @@
+use library::plugin::{EvaluationContext, PropertyEvaluator};
+use library::plugin::{Plugin, PropertyPlugin};
+use library::project::property::{Property, PropertyValue};
+use noise::{NoiseFn, Perlin};
+use std::sync::Arc;
+
+const MAX_OCTAVES: u32 = 8;
+
+pub struct WigglePropertyPlugin;
+
+impl Plugin for WigglePropertyPlugin {
+    fn id(&self) -> &'static str {
+        "wiggle"
+    }
+
+    fn name(&self) -> String {
+        "Wiggle Property".to_string()
+    }
+
+    fn category(&self) -> String {
+        "Property".to_string()
+    }
+
+    fn version(&self) -> (u32, u32, u32) {
+        (0, 1, 0)
+    }
+}
+
+impl PropertyPlugin for WigglePropertyPlugin {
+    fn get_evaluator_instance(&self) -> Arc<dyn PropertyEvaluator> {
+        Arc::new(WiggleEvaluator)
+    }
+}
+
+/// Offsets `value` by smooth multi-octave Perlin noise.
+///
+/// Unlike the random property, neighbouring times give neighbouring values,
+/// and the same seed always produces the same motion.
+pub struct WiggleEvaluator;
+
+impl PropertyEvaluator for WiggleEvaluator {
+    fn evaluate(&self, property: &Property, time: f64, _ctx: &EvaluationContext) -> PropertyValue {
+        let number = |key: &str, default: f64| {
+            property
+                .properties
+                .get(key)
+                .and_then(|v| v.get_as::<f64>())
+                .unwrap_or(default)
+        };
+
+        let base = number("value", 0.0);
+        let frequency = number("frequency", 2.0).max(0.0);
+        let amplitude = number("amplitude", 10.0);
+        let octaves = number("octaves", 1.0)
+            .round()
+            .clamp(1.0, MAX_OCTAVES as f64) as u32;
+        let seed = number("seed", 0.0) as u32;
+
+        let noise = fractal_noise(&Perlin::new(seed), time * frequency, octaves);
+        PropertyValue::Number(ordered_float::OrderedFloat(base + amplitude * noise))
+    }
+}
+
+/// Sum `octaves` layers of noise, each at double the frequency and half the
+/// weight of the previous one, normalised to roughly -1..1.
+pub fn fractal_noise(perlin: &Perlin, x: f64, octaves: u32) -> f64 {
+    let mut sum = 0.0;
+    let mut weight = 1.0;
+    let mut total_weight = 0.0;
+    let mut scale = 1.0;
+    for _ in 0..octaves {
+        // Perlin noise is zero on integer lattice points, so sample off the lattice
+        sum += perlin.get([x * scale, 0.5]) * weight;
+        total_weight += weight;
+        weight *= 0.5;
+        scale *= 2.0;
+    }
+    sum / total_weight
+}
+
+#[allow(improper_ctypes_definitions)]
+#[no_mangle]
+pub extern "C" fn create_property_plugin() -> *mut dyn PropertyPlugin {
+    let plugin: Box<dyn PropertyPlugin> = Box::new(WigglePropertyPlugin);
+    Box::into_raw(plugin)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fractal_noise_is_smooth_and_bounded() {
+        let perlin = Perlin::new(7);
+        let mut previous = fractal_noise(&perlin, 0.0, 4);
+        for i in 1..1000 {
+            let value = fractal_noise(&perlin, i as f64 * 0.001, 4);
+            // 正規化されているので振幅 1 を超えない
+            assert!(value.abs() <= 1.0);
+            // 隣り合う時刻の値は大きく飛ばない
+            assert!((value - previous).abs() < 0.05);
+            previous = value;
+        }
+        // 同じシードなら同じ値になる
+        assert_eq!(
+            fractal_noise(&Perlin::new(7), 1.25, 4),
+            fractal_noise(&perlin, 1.25, 4)
+        );
+    }
+}